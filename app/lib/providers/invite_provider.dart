@@ -1,11 +1,7 @@
-import 'dart:convert';
-
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:burrow_app/providers/groups_provider.dart';
 import 'package:burrow_app/providers/messages_provider.dart';
 import 'package:burrow_app/src/rust/api/invite.dart';
-import 'package:burrow_app/src/rust/api/group.dart' as rust_group;
-import 'package:burrow_app/src/rust/api/relay.dart' as rust_relay;
 
 class InviteNotifier extends AsyncNotifier<List<WelcomeInfo>> {
   @override
@@ -26,47 +22,12 @@ class InviteNotifier extends AsyncNotifier<List<WelcomeInfo>> {
     required String mlsGroupIdHex,
     required List<String> keyPackageEventsJson,
   }) async {
-    // Clear any stale pending commit
-    try {
-      await rust_group.mergePendingCommit(mlsGroupIdHex: mlsGroupIdHex);
-    } catch (_) {}
-
-    // 1. Create the MLS commit + welcome messages
-    final result = await addMembers(
+    // Add, publish the commit, merge, then send welcomes (MIP-02 order).
+    // A commit no relay accepted is discarded in Rust, so a retry starts clean.
+    await inviteMembersFull(
       mlsGroupIdHex: mlsGroupIdHex,
       keyPackageEventsJson: keyPackageEventsJson,
     );
-
-    // 2. Publish the evolution event (kind 445 commit) to relays
-    if (result.evolutionEventJson.isNotEmpty) {
-      await rust_relay.publishEventJson(eventJson: result.evolutionEventJson);
-    }
-
-    // 3. Merge the pending commit in local MLS state
-    await rust_group.mergePendingCommit(mlsGroupIdHex: mlsGroupIdHex);
-
-    // 4. Gift-wrap and publish each welcome rumor
-    final recipientPubkeys = keyPackageEventsJson.map((json) {
-      final event = jsonDecode(json) as Map<String, dynamic>;
-      return event['pubkey'] as String;
-    }).toList();
-
-    for (var i = 0; i < result.welcomeRumorsJson.length; i++) {
-      final welcomeJson = result.welcomeRumorsJson[i];
-      if (welcomeJson.isEmpty) continue;
-
-      final recipientHex = i < recipientPubkeys.length
-          ? recipientPubkeys[i]
-          : null;
-      if (recipientHex == null) continue;
-
-      final wrappedJson = await giftWrapWelcome(
-        welcomeRumorJson: welcomeJson,
-        recipientPubkeyHex: recipientHex,
-      );
-
-      await rust_relay.publishEventJson(eventJson: wrappedJson);
-    }
   }
 
   Future<String> fetchUserKeyPackage(String pubkeyHex) async {
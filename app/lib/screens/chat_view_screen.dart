@@ -26,6 +26,7 @@ import 'package:burrow_app/src/rust/api/app_state.dart' as rust_app;
 import 'package:burrow_app/src/rust/api/error.dart';
 import 'package:burrow_app/src/rust/api/group.dart' as rust_group;
 import 'package:burrow_app/src/rust/api/message.dart' as rust_message;
+import 'package:burrow_app/utils/call_id.dart';
 
 class ChatViewScreen extends ConsumerStatefulWidget {
   final String groupId;
@@ -1502,7 +1503,7 @@ class _ChatViewScreenState extends ConsumerState<ChatViewScreen> {
       return;
     }
 
-    final callId = DateTime.now().millisecondsSinceEpoch.toRadixString(36);
+    final callId = newCallId();
     ref
         .read(callProvider.notifier)
         .startCall(
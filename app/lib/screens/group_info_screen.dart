@@ -13,6 +13,7 @@ import 'package:burrow_app/src/rust/api/error.dart';
 import 'package:burrow_app/screens/chat_shell_screen.dart';
 import 'package:burrow_app/services/user_service.dart';
 import 'package:burrow_app/providers/call_provider.dart';
+import 'package:burrow_app/utils/call_id.dart';
 
 class GroupInfoScreen extends ConsumerStatefulWidget {
   final String groupId;
@@ -464,7 +465,7 @@ class _GroupInfoScreenState extends ConsumerState<GroupInfoScreen> {
       return;
     }
 
-    final callId = DateTime.now().millisecondsSinceEpoch.toRadixString(36);
+    final callId = newCallId();
     ref.read(callProvider.notifier).startCall(
       remotePubkeyHex: remotePubkey,
       localPubkeyHex: auth.account.pubkeyHex,
@@ -25,14 +25,17 @@ class FrameEncryptionService {
   /// Initialize frame encryption with a key derived from MLS exporter_secret.
   ///
   /// [exporterSecretHex] - MLS exporter_secret (hex-encoded).
+  /// [epoch] - The MLS epoch the exporter_secret belongs to.
   /// [callId] - Unique call identifier for key derivation context.
   Future<void> initialize({
     required String exporterSecretHex,
+    required int epoch,
     required String callId,
   }) async {
     // Derive frame encryption key via Rust
     _currentKeyHex = await rust_webrtc.deriveFrameEncryptionKey(
       exporterSecretHex: exporterSecretHex,
+      epoch: BigInt.from(epoch),
       callId: callId,
     );
 
@@ -91,17 +94,19 @@ class FrameEncryptionService {
 
   /// Rotate the frame encryption key (called when MLS epoch changes).
   ///
+  /// [exporterSecretHex] - MLS exporter_secret of the new epoch (hex-encoded).
   /// [newEpoch] - The new MLS epoch number.
   /// [callId] - Call identifier for context binding.
   Future<void> rotateKey({
+    required String exporterSecretHex,
     required int newEpoch,
     required String callId,
   }) async {
-    if (_keyProvider == null || _currentKeyHex == null) return;
+    if (_keyProvider == null) return;
 
-    // Derive new key via Rust key rotation
+    // Derive the new epoch's key from group state
     _currentKeyHex = await rust_webrtc.rotateFrameKey(
-      currentKeyHex: _currentKeyHex!,
+      exporterSecretHex: exporterSecretHex,
       newEpoch: BigInt.from(newEpoch),
       callId: callId,
     );
@@ -205,6 +205,7 @@ class MediaAttachmentService {
       nonceHex: attachment.nonceHex,
       schemeVersion: attachment.schemeVersion,
       dimensions: attachment.dimensions,
+      mirrorServers: const [],
     );
 
     if (decrypted.isEmpty) {
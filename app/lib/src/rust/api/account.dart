@@ -5,8 +5,10 @@
 
 import '../frb_generated.dart';
 import 'error.dart';
+import 'signer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `read_keyring_entry`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
 /// Create a new identity with a fresh random keypair.
@@ -19,18 +21,38 @@ Future<AccountInfo> createAccount() =>
 Future<AccountInfo> login({required String secretKey}) =>
     RustLib.instance.api.crateApiAccountLogin(secretKey: secretKey);
 
+/// Login through a NIP-46 remote signer (`bunker://...` URI).
+///
+/// The secret key stays in the bunker: events and NIP-44 payloads are signed
+/// and encrypted remotely, so `export_nsec` and `export_state` are
+/// unavailable. `app_secret_key` identifies this app to the bunker; pass the
+/// one from a previous session to reconnect without re-approving, or `None`
+/// to generate a fresh one.
+Future<AccountInfo> loginWithBunker({
+  required String bunkerUri,
+  String? appSecretKey,
+}) => RustLib.instance.api.crateApiAccountLoginWithBunker(
+  bunkerUri: bunkerUri,
+  appSecretKey: appSecretKey,
+);
+
+/// Which signer the current account uses.
+Future<SignerKind> getSignerKind() =>
+    RustLib.instance.api.crateApiAccountGetSignerKind();
+
 /// Save the current secret key to the platform keyring.
 ///
 /// Uses the OS credential store (D-Bus Secret Service on Linux, Keychain on
 /// macOS/iOS, Credential Manager on Android/Windows). The nsec never touches
-/// the filesystem.
+/// the filesystem. For NIP-46 accounts the bunker URI and this app's session
+/// key are saved instead.
 Future<void> saveSecretKeyToKeyring() =>
     RustLib.instance.api.crateApiAccountSaveSecretKeyToKeyring();
 
 /// Load the secret key from the platform keyring and initialize the account.
 ///
-/// Returns the account info if a key was found in the keyring, or an error
-/// if no key is stored or the keyring is unavailable.
+/// Returns the account info if a key (or NIP-46 session) was found in the
+/// keyring, or an error if nothing is stored or the keyring is unavailable.
 Future<AccountInfo> loadAccountFromKeyring() =>
     RustLib.instance.api.crateApiAccountLoadAccountFromKeyring();
 
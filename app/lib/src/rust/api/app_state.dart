@@ -7,8 +7,9 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `with_db`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `epoch_history`, `forget_group_local`, `forgotten_group_epochs`, `forgotten_group_ids`, `group_message_ttl`, `group_message_ttls`, `is_typing`, `outbox_enqueue`, `outbox_pending`, `outbox_remove`, `record_epoch`, `schema_version`, `tracked_group_ids`, `unforget_group`, `with_db`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OutboxEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
 // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `ensure_db_with`, `init_app_state_db`, `is_db_initialized`
 
 /// Store a key-value pair for a group.
@@ -90,6 +91,10 @@ Future<void> importArchivedGroups({required List<String> groupIds}) => RustLib
     .api
     .crateApiAppStateImportArchivedGroups(groupIds: groupIds);
 
+/// Number of sends waiting in the outbox.
+Future<int> getOutboxCount() =>
+    RustLib.instance.api.crateApiAppStateGetOutboxCount();
+
 /// Summary of a group's last message and unread count.
 class GroupSummary {
   final String? lastMessageContent;
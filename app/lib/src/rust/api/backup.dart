@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'account.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_passphrase`, `collect_account_files`, `derive_key`, `safe_relative_path`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BundleFile`, `StateBundle`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `open_bundle`, `seal_bundle`
+
+/// Export the current account as a passphrase-encrypted bundle.
+///
+/// Runs under the state write lock, so the message listener and any other
+/// MDK writer are paused while the databases are checkpointed and copied;
+/// the snapshot can't be torn by a concurrent commit.
+Future<Uint8List> exportState({required String passphrase}) =>
+    RustLib.instance.api.crateApiBackupExportState(passphrase: passphrase);
+
+/// Restore an account from a bundle created by `export_state` and log in.
+///
+/// Must be called while logged out. Refuses to overwrite existing MLS data
+/// for the same account on this device.
+Future<AccountInfo> importState({
+  required List<int> bundle,
+  required String passphrase,
+}) => RustLib.instance.api.crateApiBackupImportState(
+  bundle: bundle,
+  passphrase: passphrase,
+);
@@ -6,8 +6,8 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `score_bitrate`, `score_jitter`, `score_loss`, `score_rtt`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `round_up_10`, `score_bitrate`, `score_jitter`, `score_loss`, `score_rtt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Get audio constraints for the given mode.
 ///
@@ -50,6 +50,20 @@ Future<QualityScore> calculateQualityScore({
   isVideo: isVideo,
 );
 
+/// Recommend jitter buffer sizes from measured jitter and RTT.
+///
+/// Target is ~2× jitter plus one 20ms Opus frame, so low-jitter links stay
+/// snappy while jittery links trade latency for fewer gaps. On high-RTT links
+/// conversation is already delayed, so a little extra smoothing is added.
+/// All values are rounded up to 10ms and kept within 20–1000ms.
+Future<JitterBufferConfig> recommendJitterBuffer({
+  required double measuredJitterMs,
+  required double rttMs,
+}) => RustLib.instance.api.crateApiCallQualityRecommendJitterBuffer(
+  measuredJitterMs: measuredJitterMs,
+  rttMs: rttMs,
+);
+
 /// Get simulcast configuration for SFU group calls.
 ///
 /// Three layers: low (180p), medium (360p), high (720p).
@@ -232,6 +246,42 @@ class CodecPreferences {
           videoCodecs == other.videoCodecs;
 }
 
+/// Jitter buffer sizing for the audio receive path.
+class JitterBufferConfig {
+  /// Buffer depth to aim for, in ms.
+  final int targetMs;
+
+  /// Lower bound the adaptive buffer may shrink to, in ms.
+  final int minMs;
+
+  /// Upper bound the adaptive buffer may grow to, in ms.
+  final int maxMs;
+
+  /// Human-readable reason for the sizing.
+  final String reason;
+
+  const JitterBufferConfig({
+    required this.targetMs,
+    required this.minMs,
+    required this.maxMs,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode =>
+      targetMs.hashCode ^ minMs.hashCode ^ maxMs.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JitterBufferConfig &&
+          runtimeType == other.runtimeType &&
+          targetMs == other.targetMs &&
+          minMs == other.minMs &&
+          maxMs == other.maxMs &&
+          reason == other.reason;
+}
+
 /// Recommended quality preset with reasoning.
 class QualityRecommendation {
   /// Recommended video preset name: "low", "medium", "high", "hd".
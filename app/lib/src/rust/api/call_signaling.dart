@@ -7,14 +7,21 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_gift_wrapped_signaling`, `signaling_tags`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallAnswerPayload`, `CallOfferPayload`, `CallStateUpdatePayload`, `IceCandidatePayload`
+// These functions are ignored because they are not marked as `pub`: `add_fragment`, `build_gift_wrapped_signaling_fragments`, `build_gift_wrapped_signaling`, `call_tags`, `check_call_id`, `fragment_info`, `is_uuid`, `sequence_event_at`, `sequence_event`, `signaling_tags`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallAnswerPayload`, `CallOfferPayload`, `CallSignalingState`, `CallStateUpdatePayload`, `IceCandidatePayload`, `PendingFragments`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `is_renegotiation`
+
+/// Forget the delivered ICE candidates and sequencing of a call; a new call
+/// with the same ID then starts fresh. `end_call`, `reject_call` and an
+/// incoming call end already do this.
+void resetCallSignalingState({required String callId}) => RustLib.instance.api
+    .crateApiCallSignalingResetCallSignalingState(callId: callId);
 
 /// Initiate a call by creating a gift-wrapped call offer event (kind 25050).
 ///
 /// `sdp_offer`: SDP offer string from WebRTC.
-/// `call_id`: Unique call identifier (UUIDv4).
+/// `call_id`: Unique call identifier (UUIDv4); anything else is rejected.
 /// `call_type`: "audio" or "video".
 /// `recipient_pubkey_hex`: Hex-encoded public key of the callee.
 ///
@@ -48,6 +55,63 @@ Future<String> acceptCall({
   callerPubkeyHex: callerPubkeyHex,
 );
 
+/// Like `initiate_call`, but splits a large SDP offer across several
+/// gift-wrapped events instead of failing. Publish every returned event.
+Future<List<String>> initiateCallFragments({
+  required String sdpOffer,
+  required String callId,
+  required String callType,
+  required String recipientPubkeyHex,
+}) => RustLib.instance.api.crateApiCallSignalingInitiateCallFragments(
+  sdpOffer: sdpOffer,
+  callId: callId,
+  callType: callType,
+  recipientPubkeyHex: recipientPubkeyHex,
+);
+
+/// Like `accept_call`, but splits a large SDP answer across several
+/// gift-wrapped events instead of failing. Publish every returned event.
+Future<List<String>> acceptCallFragments({
+  required String sdpAnswer,
+  required String callId,
+  required String callerPubkeyHex,
+}) => RustLib.instance.api.crateApiCallSignalingAcceptCallFragments(
+  sdpAnswer: sdpAnswer,
+  callId: callId,
+  callerPubkeyHex: callerPubkeyHex,
+);
+
+/// Renegotiate an established call with a new SDP offer (kind 25050).
+///
+/// Used for mid-call changes such as adding video or screen share. The
+/// offer carries `renegotiate: true`, so the peer applies it to the existing
+/// connection instead of ringing for a new call. The call type follows the
+/// new SDP: "video" if it has a video m-line, otherwise "audio".
+///
+/// Returns JSON-serialized gift-wrapped Event (kind 1059).
+Future<String> sendRenegotiationOffer({
+  required String callId,
+  required String sdp,
+  required String peerPubkeyHex,
+}) => RustLib.instance.api.crateApiCallSignalingSendRenegotiationOffer(
+  callId: callId,
+  sdp: sdp,
+  peerPubkeyHex: peerPubkeyHex,
+);
+
+/// Answer a renegotiation offer with a new SDP answer (kind 25051).
+///
+/// Returns JSON-serialized gift-wrapped Event (kind 1059).
+Future<String> sendRenegotiationAnswer({
+  required String callId,
+  required String sdp,
+  required String peerPubkeyHex,
+}) => RustLib.instance.api.crateApiCallSignalingSendRenegotiationAnswer(
+  callId: callId,
+  sdp: sdp,
+  peerPubkeyHex: peerPubkeyHex,
+);
+
 /// Reject an incoming call (kind 25053 with rejection reason).
 ///
 /// `call_id`: Call identifier from the received offer.
@@ -138,7 +202,9 @@ Future<String> subscribeCallEvents() =>
 ///
 /// `event_json`: JSON-serialized inner event (kind 25050-25054).
 ///
-/// Returns `None` if the event is not a call signaling event.
+/// Returns `None` if the event is not a call signaling event, has a
+/// malformed `call-id` or `call-type` tag, or is an ICE candidate already
+/// delivered for its call.
 Future<CallSignalingEvent?> processCallEvent({required String eventJson}) =>
     RustLib.instance.api.crateApiCallSignalingProcessCallEvent(
       eventJson: eventJson,
@@ -150,6 +216,11 @@ Future<CallSignalingEvent?> processCallEvent({required String eventJson}) =>
 /// unwraps them using NIP-59, and pushes any call signaling events (kinds 25050-25054)
 /// to the provided stream sink.
 ///
+/// Fragmented offers/answers are buffered per (sender, call, kind) and
+/// forwarded once, reassembled, when the last fragment arrives. Duplicate
+/// ICE candidates are dropped and every event carries a `sequence` for
+/// reordering; call `reset_call_signaling_state` when the call ends.
+///
 /// Runs indefinitely until the stream is closed from the Dart side.
 Stream<CallSignalingEvent> listenForCallEvents() =>
     RustLib.instance.api.crateApiCallSignalingListenForCallEvents();
@@ -197,6 +268,15 @@ class CallSignalingEvent {
   /// Unix timestamp.
   final BigInt createdAt;
 
+  /// For offers/answers: applies to the existing call's connection
+  /// (renegotiation) instead of starting a new call.
+  final bool renegotiate;
+
+  /// Ordering key within the call, increasing with `created_at` (and
+  /// arrival order within a second). Relays don't preserve order, so
+  /// sort buffered events by this before applying them.
+  final BigInt sequence;
+
   const CallSignalingEvent({
     required this.kind,
     required this.senderPubkeyHex,
@@ -204,6 +284,8 @@ class CallSignalingEvent {
     this.callType,
     required this.content,
     required this.createdAt,
+    required this.renegotiate,
+    required this.sequence,
   });
 
   @override
@@ -213,7 +295,9 @@ class CallSignalingEvent {
       callId.hashCode ^
       callType.hashCode ^
       content.hashCode ^
-      createdAt.hashCode;
+      createdAt.hashCode ^
+      renegotiate.hashCode ^
+      sequence.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -225,5 +309,7 @@ class CallSignalingEvent {
           callId == other.callId &&
           callType == other.callType &&
           content == other.content &&
-          createdAt == other.createdAt;
+          createdAt == other.createdAt &&
+          renegotiate == other.renegotiate &&
+          sequence == other.sequence;
 }
@@ -7,28 +7,41 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `compute_quality_score`, `now_secs`, `parse_sdp_internal`, `peer_stats_store`, `peers`
+// These functions are ignored because they are not marked as `pub`: `compute_quality_score`, `now_secs`, `parse_sdp_internal`, `peer_stats_store`, `peers`, `reap_stale_peers_at`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `ice_transport_policy`, `turn_credentials`
 
-/// Generate WebRTC configuration with STUN-only ICE servers.
+/// Generate WebRTC configuration: public STUN servers, plus a TURN server
+/// with ephemeral credentials when one is configured.
 ///
-/// Returns STUN server configuration for creating peer connections.
-/// STUN is sufficient for most NAT traversal scenarios.
+/// TURN credentials follow the TURN REST API scheme (coturn
+/// `use-auth-secret`, metered.ca and most hosted providers): the username
+/// is `{expiry}:{call id prefix}` and the password is the base64
+/// HMAC-SHA1 of the username under the server's shared secret, so the
+/// server can verify them without a lookup and they stop working after
+/// the TTL. The secret comes from `BURROW_TURN_SECRET` and the servers
+/// from `BURROW_TURN_URLS`; without both the config is STUN-only.
+/// `BURROW_TURN_TTL` overrides the default one-hour lifetime.
 ///
-/// TURN relay is NOT included by default because proper TURN authentication
-/// requires server-side credential provisioning (e.g., time-limited HMAC
-/// credentials via a TURN REST API, or an API key from a hosted provider
-/// like metered.ca / Twilio / Xirsys). Client-side credential generation
-/// provides no security — anyone can derive the same credentials.
-///
-/// Users who need TURN relay (e.g., behind symmetric NATs or strict
-/// firewalls) should configure their own TURN server credentials in the
-/// app settings (Settings > TURN Server). The Dart WebRTC service layer
-/// will merge user-configured TURN servers into the ICE server list.
-///
-/// `call_id`: Call identifier (unused currently, reserved for future use).
-Future<WebRtcConfig> generateWebrtcConfig({required String callId}) =>
-    RustLib.instance.api.crateApiCallWebrtcGenerateWebrtcConfig(callId: callId);
+/// `force_relay`: set `iceTransportPolicy` to "relay" so ICE skips host and
+/// server-reflexive candidates and goes straight to TURN. Connects much
+/// faster on symmetric-NAT or corporate networks where direct paths are
+/// doomed, but only works if a TURN server is configured: without one the
+/// policy falls back to "all" rather than leaving ICE with no candidates.
+Future<WebRtcConfig> generateWebrtcConfig({
+  required String callId,
+  required bool forceRelay,
+}) => RustLib.instance.api.crateApiCallWebrtcGenerateWebrtcConfig(
+  callId: callId,
+  forceRelay: forceRelay,
+);
+
+/// Whether to use TURN-only (`force_relay`) for the next call to a peer,
+/// given how many previous direct-connect attempts to that peer failed.
+bool recommendForceRelay({required int previousFailures}) => RustLib
+    .instance
+    .api
+    .crateApiCallWebrtcRecommendForceRelay(previousFailures: previousFailures);
 
 /// Parse and validate an SDP offer string.
 ///
@@ -43,6 +56,7 @@ Future<SdpInfo> parseSdpAnswer({required String sdp}) =>
 /// Create a new peer entry to track a WebRTC peer connection.
 ///
 /// Called when a new participant joins a call or a new P2P connection is established.
+/// Also reaps peers left behind by calls that ended without `remove_call_peers`.
 Future<PeerEntry> createPeerEntry({
   required String callId,
   required String participantPubkeyHex,
@@ -97,39 +111,61 @@ Future<List<PeerEntry>> getCallParticipants({required String callId}) =>
 Future<void> removeCallPeers({required String callId}) =>
     RustLib.instance.api.crateApiCallWebrtcRemoveCallPeers(callId: callId);
 
+/// Remove peer entries with no activity for `max_age_secs`, and their stats.
+///
+/// Activity is the later of the entry's `updated_at` and its last stats
+/// report, so a long call that is still reporting stats is never reaped.
+/// Calls left with no peers are dropped, as are stats older than
+/// `max_age_secs` for peers no longer in any call. Returns the number of
+/// peer entries removed.
+///
+/// `create_peer_entry` reaps on every new peer, but a client that stops
+/// calling never gets there: Dart should also call this periodically
+/// (e.g. every few minutes with `STALE_PEER_MAX_AGE_SECS`) so entries from
+/// calls that ended in a crash or network drop don't leak.
+Future<int> reapStalePeers({required BigInt maxAgeSecs}) => RustLib.instance.api
+    .crateApiCallWebrtcReapStalePeers(maxAgeSecs: maxAgeSecs);
+
 /// Derive a per-call AES-128-GCM frame encryption key from MLS exporter_secret.
 ///
 /// Used for SFU mode where frames must be encrypted end-to-end since DTLS
-/// terminates at the SFU. The key is derived deterministically so all group
-/// members compute the same key from their shared MLS state.
+/// terminates at the SFU. The key depends only on the epoch's exporter
+/// secret, the epoch number and the call ID, so every member in that epoch
+/// (including one who just joined) computes the same key from group state.
 ///
 /// `exporter_secret_hex`: Hex-encoded MLS exporter_secret from the group epoch.
+/// `epoch`: The MLS epoch the exporter secret belongs to.
 /// `call_id`: Unique call identifier used as derivation context.
 ///
 /// Returns 16-byte (128-bit) AES-GCM key as hex string.
 Future<String> deriveFrameEncryptionKey({
   required String exporterSecretHex,
+  required BigInt epoch,
   required String callId,
 }) => RustLib.instance.api.crateApiCallWebrtcDeriveFrameEncryptionKey(
   exporterSecretHex: exporterSecretHex,
+  epoch: epoch,
   callId: callId,
 );
 
-/// Rotate the frame encryption key by deriving a new key from the current key + epoch.
+/// Derive the frame encryption key for a new epoch.
 ///
-/// Called when MLS epoch advances (member join/leave/update) to maintain forward secrecy.
+/// Called when MLS epoch advances (member join/leave/update) to maintain
+/// forward secrecy. The key is derived from the new epoch's exporter secret
+/// rather than chained off the previous key, so a member who missed a
+/// rotation can still compute the current key.
 ///
-/// `current_key_hex`: Current frame encryption key (hex).
+/// `exporter_secret_hex`: Hex-encoded MLS exporter_secret of the new epoch.
 /// `new_epoch`: The new MLS epoch number.
 /// `call_id`: Call identifier for context binding.
 ///
 /// Returns new 16-byte AES-GCM key as hex string.
 Future<String> rotateFrameKey({
-  required String currentKeyHex,
+  required String exporterSecretHex,
   required BigInt newEpoch,
   required String callId,
 }) => RustLib.instance.api.crateApiCallWebrtcRotateFrameKey(
-  currentKeyHex: currentKeyHex,
+  exporterSecretHex: exporterSecretHex,
   newEpoch: newEpoch,
   callId: callId,
 );
@@ -336,6 +372,13 @@ class SdpInfo {
   /// Detected codecs (e.g. "opus", "VP8", "H264").
   final List<String> codecs;
 
+  /// Highest `b=AS:` bandwidth limit across media sections, in kbps.
+  final int? bandwidthKbps;
+
+  /// Format parameters of the Opus payload (e.g.
+  /// "minptime=10;useinbandfec=1;stereo=1;maxaveragebitrate=128000").
+  final String? opusParams;
+
   /// Whether the SDP appears valid.
   final bool isValid;
 
@@ -349,6 +392,8 @@ class SdpInfo {
     required this.mediaCount,
     this.iceUfrag,
     required this.codecs,
+    this.bandwidthKbps,
+    this.opusParams,
     required this.isValid,
     this.error,
   });
@@ -361,6 +406,8 @@ class SdpInfo {
       mediaCount.hashCode ^
       iceUfrag.hashCode ^
       codecs.hashCode ^
+      bandwidthKbps.hashCode ^
+      opusParams.hashCode ^
       isValid.hashCode ^
       error.hashCode;
 
@@ -375,6 +422,8 @@ class SdpInfo {
           mediaCount == other.mediaCount &&
           iceUfrag == other.iceUfrag &&
           codecs == other.codecs &&
+          bandwidthKbps == other.bandwidthKbps &&
+          opusParams == other.opusParams &&
           isValid == other.isValid &&
           error == other.error;
 }
@@ -419,15 +468,22 @@ class WebRtcConfig {
   /// Bundle policy: "max-bundle".
   final String bundlePolicy;
 
+  /// ICE transport policy: "all", or "relay" to use TURN candidates only.
+  final String iceTransportPolicy;
+
   const WebRtcConfig({
     required this.iceServers,
     required this.sdpSemantics,
     required this.bundlePolicy,
+    required this.iceTransportPolicy,
   });
 
   @override
   int get hashCode =>
-      iceServers.hashCode ^ sdpSemantics.hashCode ^ bundlePolicy.hashCode;
+      iceServers.hashCode ^
+      sdpSemantics.hashCode ^
+      bundlePolicy.hashCode ^
+      iceTransportPolicy.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -436,5 +492,6 @@ class WebRtcConfig {
           runtimeType == other.runtimeType &&
           iceServers == other.iceServers &&
           sdpSemantics == other.sdpSemantics &&
-          bundlePolicy == other.bundlePolicy;
+          bundlePolicy == other.bundlePolicy &&
+          iceTransportPolicy == other.iceTransportPolicy;
 }
@@ -7,8 +7,9 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `batch_check_key_packages`, `fetch_follow_list_inner`, `publish_follow_list`, `set_last_synced`, `sync_contacts_inner`
+// These functions are ignored because they are not marked as `pub`: `batch_check_key_packages`, `contact_from_row`, `escape_like`, `fetch_follow_list_inner`, `publish_follow_list`, `set_last_synced`, `store_follow_profile`, `sync_contacts_inner`, `verify_nip05`, `verify_pending_nip05s`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `contacts_list_builder`, `key_package_check_due`, `key_package_recheck_interval`, `new_follows_from_list`, `nip05_check_due`, `nip05_names_pubkey`, `parse_nip05`
 
 /// Debug contacts sync: returns diagnostic info about each step.
 Future<ContactsSyncDebug> debugSyncContacts() =>
@@ -19,13 +20,47 @@ Future<ContactsSyncDebug> debugSyncContacts() =>
 Future<List<ContactInfo>> getCachedContacts() =>
     RustLib.instance.api.crateApiContactsGetCachedContacts();
 
+/// Search cached contacts by display name, NIP-05 or pubkey hex
+/// (case-insensitive substring), ordered by name. An empty query returns
+/// the first `limit` contacts by name. Local only, like `get_cached_contacts`.
+Future<List<ContactInfo>> searchContacts({required String query, int? limit}) =>
+    RustLib.instance.api.crateApiContactsSearchContacts(
+      query: query,
+      limit: limit,
+    );
+
+/// Set how old a follow's key package check may be before sync re-checks it.
+void setContactsStaleness({required BigInt secs}) =>
+    RustLib.instance.api.crateApiContactsSetContactsStaleness(secs: secs);
+
+/// Get the key package staleness window used by `sync_contacts`.
+BigInt getContactsStaleness() =>
+    RustLib.instance.api.crateApiContactsGetContactsStaleness();
+
+/// Enable checking contacts' NIP-05 identifiers against their domain's
+/// `.well-known/nostr.json` during `sync_contacts`. Off by default since it
+/// contacts a web server per identifier.
+void setNip05Verification({required bool enabled}) =>
+    RustLib.instance.api.crateApiContactsSetNip05Verification(enabled: enabled);
+
+/// Whether `sync_contacts` verifies NIP-05 identifiers.
+bool getNip05Verification() =>
+    RustLib.instance.api.crateApiContactsGetNip05Verification();
+
 /// Full sync: fetch NIP-02 follow list, check key packages, resolve profiles,
 /// update local SQLite, and return Marmot-capable contacts.
 ///
+/// Key packages are re-checked for follows last checked longer ago than the
+/// staleness window, backing off for follows that repeatedly have none (see
+/// `key_package_recheck_interval`); `force_refresh` re-checks every follow,
+/// e.g. right after asking a contact to set up Burrow.
+///
 /// On any failure, returns whatever is currently cached rather than propagating
 /// the error — this prevents the UI from showing an error screen.
-Future<List<ContactInfo>> syncContacts() =>
-    RustLib.instance.api.crateApiContactsSyncContacts();
+Future<List<ContactInfo>> syncContacts({required bool forceRefresh}) => RustLib
+    .instance
+    .api
+    .crateApiContactsSyncContacts(forceRefresh: forceRefresh);
 
 /// Get the timestamp of the last contacts sync (epoch seconds), or None.
 Future<PlatformInt64?> getLastContactsSync() =>
@@ -41,17 +76,53 @@ Future<void> followContact({required String pubkeyHex}) =>
 Future<void> unfollowContact({required String pubkeyHex}) =>
     RustLib.instance.api.crateApiContactsUnfollowContact(pubkeyHex: pubkeyHex);
 
+/// Export Marmot-capable contacts as a NIP-51 people list (kind 30000).
+///
+/// Builds a signed list with a `p` tag per cached contact and returns the
+/// event JSON for backup or sharing. When `publish` is true the list is also
+/// sent to connected relays (replacing any previous export).
+Future<String> exportContactsList({required bool publish}) =>
+    RustLib.instance.api.crateApiContactsExportContactsList(publish: publish);
+
+/// Import a NIP-51 people list (kind 30000) and follow any new entries.
+///
+/// Verifies the event signature, then adds every `p` tag not already in the
+/// NIP-02 follow list, publishing the updated follow list once. Key packages
+/// for new follows are checked on the next `sync_contacts`. Returns the
+/// number of follows added.
+Future<int> importContactsList({required String eventJson}) => RustLib
+    .instance
+    .api
+    .crateApiContactsImportContactsList(eventJson: eventJson);
+
 /// A Marmot-capable contact (has published a key package).
 class ContactInfo {
   final String pubkeyHex;
   final String? displayName;
   final String? picture;
 
-  const ContactInfo({required this.pubkeyHex, this.displayName, this.picture});
+  /// NIP-05 identifier from the profile, e.g. "alice@example.com".
+  final String? nip05;
+
+  /// The NIP-05 domain confirmed it maps to this pubkey. Only checked
+  /// when verification is enabled; see `set_nip05_verification`.
+  final bool nip05Verified;
+
+  const ContactInfo({
+    required this.pubkeyHex,
+    this.displayName,
+    this.picture,
+    this.nip05,
+    required this.nip05Verified,
+  });
 
   @override
   int get hashCode =>
-      pubkeyHex.hashCode ^ displayName.hashCode ^ picture.hashCode;
+      pubkeyHex.hashCode ^
+      displayName.hashCode ^
+      picture.hashCode ^
+      nip05.hashCode ^
+      nip05Verified.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -60,7 +131,9 @@ class ContactInfo {
           runtimeType == other.runtimeType &&
           pubkeyHex == other.pubkeyHex &&
           displayName == other.displayName &&
-          picture == other.picture;
+          picture == other.picture &&
+          nip05 == other.nip05 &&
+          nip05Verified == other.nip05Verified;
 }
 
 /// Diagnostic info for debugging contacts sync.
@@ -0,0 +1,205 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `error_kind`, `now_secs`, `record_error`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Collect a diagnostics report.
+///
+/// Works before login (reports `initialized: false`) so it can be attached
+/// to "can't sign in" reports too. Individual sections that fail are left
+/// empty rather than failing the whole report.
+Future<DiagnosticsReport> collectDiagnostics() =>
+    RustLib.instance.api.crateApiDiagnosticsCollectDiagnostics();
+
+/// Snapshot of app state for support triage.
+class DiagnosticsReport {
+  /// Unix timestamp when the report was generated.
+  final BigInt generatedAt;
+
+  /// Crate version of the Rust core.
+  final String coreVersion;
+
+  /// Whether an account is logged in.
+  final bool initialized;
+
+  /// Hex-encoded public key (never the secret key).
+  final String? pubkeyHex;
+  final List<RelayDiagnostics> relays;
+  final int groupCount;
+  final List<GroupDiagnostics> groups;
+  final int pendingWelcomeCount;
+
+  /// Last successful contacts sync (epoch seconds).
+  final PlatformInt64? lastContactsSync;
+  final bool appDbInitialized;
+
+  /// `PRAGMA user_version` of the app state database.
+  final int? appDbSchemaVersion;
+  final List<ErrorCount> errorCounts;
+
+  const DiagnosticsReport({
+    required this.generatedAt,
+    required this.coreVersion,
+    required this.initialized,
+    this.pubkeyHex,
+    required this.relays,
+    required this.groupCount,
+    required this.groups,
+    required this.pendingWelcomeCount,
+    this.lastContactsSync,
+    required this.appDbInitialized,
+    this.appDbSchemaVersion,
+    required this.errorCounts,
+  });
+
+  /// Serialize the report as pretty-printed JSON for attaching to a bug report.
+  String toJson() => RustLib.instance.api
+      .crateApiDiagnosticsDiagnosticsReportToJson(that: this);
+
+  @override
+  int get hashCode =>
+      generatedAt.hashCode ^
+      coreVersion.hashCode ^
+      initialized.hashCode ^
+      pubkeyHex.hashCode ^
+      relays.hashCode ^
+      groupCount.hashCode ^
+      groups.hashCode ^
+      pendingWelcomeCount.hashCode ^
+      lastContactsSync.hashCode ^
+      appDbInitialized.hashCode ^
+      appDbSchemaVersion.hashCode ^
+      errorCounts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiagnosticsReport &&
+          runtimeType == other.runtimeType &&
+          generatedAt == other.generatedAt &&
+          coreVersion == other.coreVersion &&
+          initialized == other.initialized &&
+          pubkeyHex == other.pubkeyHex &&
+          relays == other.relays &&
+          groupCount == other.groupCount &&
+          groups == other.groups &&
+          pendingWelcomeCount == other.pendingWelcomeCount &&
+          lastContactsSync == other.lastContactsSync &&
+          appDbInitialized == other.appDbInitialized &&
+          appDbSchemaVersion == other.appDbSchemaVersion &&
+          errorCounts == other.errorCounts;
+}
+
+/// Error count for one subsystem since app start.
+class ErrorCount {
+  /// Subsystem name, e.g. "contacts_sync", "message_listener".
+  final String subsystem;
+  final int count;
+
+  /// Unix timestamp of the most recent error.
+  final BigInt lastErrorAt;
+
+  /// Category of the most recent error, e.g. "timeout" or "mls". Error
+  /// text can quote message content, so it is never stored.
+  final String lastErrorKind;
+
+  const ErrorCount({
+    required this.subsystem,
+    required this.count,
+    required this.lastErrorAt,
+    required this.lastErrorKind,
+  });
+
+  @override
+  int get hashCode =>
+      subsystem.hashCode ^
+      count.hashCode ^
+      lastErrorAt.hashCode ^
+      lastErrorKind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ErrorCount &&
+          runtimeType == other.runtimeType &&
+          subsystem == other.subsystem &&
+          count == other.count &&
+          lastErrorAt == other.lastErrorAt &&
+          lastErrorKind == other.lastErrorKind;
+}
+
+/// Per-group MLS state, without any key material.
+class GroupDiagnostics {
+  final String mlsGroupIdHex;
+  final String nostrGroupIdHex;
+  final BigInt epoch;
+
+  /// "active", "pending", or "inactive".
+  final String state;
+  final int memberCount;
+  final int relayCount;
+
+  const GroupDiagnostics({
+    required this.mlsGroupIdHex,
+    required this.nostrGroupIdHex,
+    required this.epoch,
+    required this.state,
+    required this.memberCount,
+    required this.relayCount,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupIdHex.hashCode ^
+      nostrGroupIdHex.hashCode ^
+      epoch.hashCode ^
+      state.hashCode ^
+      memberCount.hashCode ^
+      relayCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupDiagnostics &&
+          runtimeType == other.runtimeType &&
+          mlsGroupIdHex == other.mlsGroupIdHex &&
+          nostrGroupIdHex == other.nostrGroupIdHex &&
+          epoch == other.epoch &&
+          state == other.state &&
+          memberCount == other.memberCount &&
+          relayCount == other.relayCount;
+}
+
+/// Connection status of a single relay.
+class RelayDiagnostics {
+  final String url;
+
+  /// nostr-sdk relay status, e.g. "Connected", "Disconnected", "Pending".
+  final String status;
+  final bool connected;
+
+  const RelayDiagnostics({
+    required this.url,
+    required this.status,
+    required this.connected,
+  });
+
+  @override
+  int get hashCode => url.hashCode ^ status.hashCode ^ connected.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelayDiagnostics &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          status == other.status &&
+          connected == other.connected;
+}
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `gift_wrap`, `is_addressed_to`, `next_ephemeral_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EphemeralState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Set the ephemeral key strategy. Always drops the current session key.
+void setEphemeralKeyStrategy({required EphemeralKeyStrategy strategy}) =>
+    RustLib.instance.api.crateApiEphemeralSetEphemeralKeyStrategy(
+      strategy: strategy,
+    );
+
+/// Get the current ephemeral key strategy.
+EphemeralKeyStrategy getEphemeralKeyStrategy() =>
+    RustLib.instance.api.crateApiEphemeralGetEphemeralKeyStrategy();
+
+/// How ephemeral signing keys are chosen for gift wraps.
+enum EphemeralKeyStrategy {
+  /// Fresh key for every event — maximum unlinkability.
+  perEvent,
+
+  /// One key reused for the session — wraps become linkable to each other.
+  perSession;
+
+  static Future<EphemeralKeyStrategy> default_() =>
+      RustLib.instance.api.crateApiEphemeralEphemeralKeyStrategyDefault();
+}
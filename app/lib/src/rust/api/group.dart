@@ -7,8 +7,11 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `base64_encode`, `group_state_str`, `group_to_info`, `sha256_hex`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `base64_encode`, `build_group_info`, `evolution_relays`, `find_dm_group`, `group_state_str`, `group_to_info`, `groups_to_info`, `record_epoch_change`, `sha256_hex`, `snapshot_group`, `update_group_admins`
+// These functions are ignored because they have generic arguments: `create_or_get_dm_using`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GroupSnapshot`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `demoted_admins`, `epoch_events_from_history`, `promoted_admins`, `union_relays`, `validate_admins_are_members`
 
 /// Create a new MLS group (MIP-01).
 ///
@@ -32,6 +35,50 @@ Future<CreateGroupResult> createGroup({
   relayUrls: relayUrls,
 );
 
+/// Find an existing 1:1 group with `peer_pubkey_hex`.
+///
+/// Matches active groups with exactly two members: self and the peer.
+Future<GroupInfo?> findDmWith({required String peerPubkeyHex}) =>
+    RustLib.instance.api.crateApiGroupFindDmWith(peerPubkeyHex: peerPubkeyHex);
+
+/// Create a 1:1 group with a peer, or return the existing one.
+///
+/// Prevents duplicate DM rooms with the same person. When an existing DM is
+/// found, it is returned with empty `welcome_rumors_json` and nothing needs
+/// to be published. Otherwise behaves like `create_group` with the peer as
+/// the only invitee: publish the welcomes and call `merge_pending_commit`.
+Future<CreateGroupResult> createDm({
+  required String peerPubkeyHex,
+  required String peerKeyPackageEventJson,
+  required List<String> relayUrls,
+}) => RustLib.instance.api.crateApiGroupCreateDm(
+  peerPubkeyHex: peerPubkeyHex,
+  peerKeyPackageEventJson: peerKeyPackageEventJson,
+  relayUrls: relayUrls,
+);
+
+/// Return the existing 1:1 group with a peer, or fetch their KeyPackage
+/// and create one.
+///
+/// Unlike `create_dm`, the caller doesn't need the peer's KeyPackage up
+/// front, and relays are only queried when no DM exists yet. When
+/// `reused` is false, publish the welcomes and call `merge_pending_commit`
+/// as after `create_group`.
+Future<CreateOrGetDmResult> createOrGetDm({
+  required String peerPubkeyHex,
+  required List<String> relayUrls,
+}) => RustLib.instance.api.crateApiGroupCreateOrGetDm(
+  peerPubkeyHex: peerPubkeyHex,
+  relayUrls: relayUrls,
+);
+
+/// Discard a pending commit whose evolution event couldn't be published,
+/// leaving the group at its current epoch so the change can be retried.
+Future<void> clearPendingCommit({required String mlsGroupIdHex}) => RustLib
+    .instance
+    .api
+    .crateApiGroupClearPendingCommit(mlsGroupIdHex: mlsGroupIdHex);
+
 /// Merge pending commit after publishing the evolution event to relays.
 ///
 /// MUST be called after successfully publishing a kind 445 commit event.
@@ -41,14 +88,72 @@ Future<void> mergePendingCommit({required String mlsGroupIdHex}) => RustLib
     .api
     .crateApiGroupMergePendingCommit(mlsGroupIdHex: mlsGroupIdHex);
 
-/// List all groups the current user belongs to.
+/// List all groups the current user belongs to. Forgotten groups are
+/// left out.
 Future<List<GroupInfo>> listGroups() =>
     RustLib.instance.api.crateApiGroupListGroups();
 
+/// List inactive groups (ones we were removed from or left) that haven't
+/// been forgotten yet. Preview for `cleanup_inactive_groups`.
+Future<List<GroupInfo>> listInactiveGroups() =>
+    RustLib.instance.api.crateApiGroupListInactiveGroups();
+
+/// Forget an inactive group: purge its local messages and read state and
+/// hide it from `list_groups`. Active groups must be left first.
+Future<void> forgetGroup({required String mlsGroupIdHex}) =>
+    RustLib.instance.api.crateApiGroupForgetGroup(mlsGroupIdHex: mlsGroupIdHex);
+
+/// Forget every inactive group. Returns how many were forgotten.
+Future<int> cleanupInactiveGroups() =>
+    RustLib.instance.api.crateApiGroupCleanupInactiveGroups();
+
+/// Compare MDK's groups with what the app tracks and repair what can be
+/// repaired.
+///
+/// A crash between MDK accepting a welcome or commit and the app updating
+/// its own state can leave the two out of step: an active group still
+/// marked forgotten (so it silently vanishes from `list_groups`), or app
+/// state for a group MDK no longer has (e.g. after its database was reset).
+/// The first is fixed, unless the group is still at the epoch it was
+/// forgotten at (nothing was rejoined); the second is only reported.
+Future<ReconcileReport> reconcileGroups() =>
+    RustLib.instance.api.crateApiGroupReconcileGroups();
+
 /// Get info about a specific group by its MLS group ID.
 Future<GroupInfo> getGroup({required String mlsGroupIdHex}) =>
     RustLib.instance.api.crateApiGroupGetGroup(mlsGroupIdHex: mlsGroupIdHex);
 
+/// Get the full decoded marmot_group_data extension (0xF2EE) as JSON.
+///
+/// For debugging interop (e.g. the app and CLI showing different group names).
+/// Image key and nonce are reported only as presence flags; the secrets
+/// themselves are never returned.
+Future<String> getGroupRawExtension({required String mlsGroupIdHex}) => RustLib
+    .instance
+    .api
+    .crateApiGroupGetGroupRawExtension(mlsGroupIdHex: mlsGroupIdHex);
+
+/// Set the default disappearing-message TTL for a group. None or 0 clears it.
+///
+/// Every message sent to the group through `send_message` (and the long and
+/// media variants) then carries a NIP-40 expiration tag unless the send
+/// overrides it. The marmot_group_data extension has no field for this, so
+/// the policy is stored locally and applies to this device's sends; other
+/// members set their own.
+Future<void> setGroupMessageTtl({
+  required String mlsGroupIdHex,
+  BigInt? ttlSecs,
+}) => RustLib.instance.api.crateApiGroupSetGroupMessageTtl(
+  mlsGroupIdHex: mlsGroupIdHex,
+  ttlSecs: ttlSecs,
+);
+
+/// Get the default disappearing-message TTL for a group, if set.
+Future<BigInt?> getGroupMessageTtl({required String mlsGroupIdHex}) => RustLib
+    .instance
+    .api
+    .crateApiGroupGetGroupMessageTtl(mlsGroupIdHex: mlsGroupIdHex);
+
 /// Get members of a group, enriched with cached profile data.
 Future<List<MemberInfo>> getGroupMembers({required String mlsGroupIdHex}) =>
     RustLib.instance.api.crateApiGroupGetGroupMembers(
@@ -106,14 +211,58 @@ Future<UpdateGroupResult> removeGroupImage({required String mlsGroupIdHex}) =>
 Future<String> defaultBlossomServer() =>
     RustLib.instance.api.crateApiGroupDefaultBlossomServer();
 
+/// Epoch history of a group, oldest first, ending with the current epoch.
+///
+/// Answers questions like "when was Bob removed?" and helps diagnose
+/// desyncs. Only commits applied since this device joined (and since this
+/// was recorded) are listed; see the section comment for what's not known.
+Future<List<EpochInfo>> getGroupEpochHistory({required String mlsGroupIdHex}) =>
+    RustLib.instance.api.crateApiGroupGetGroupEpochHistory(
+      mlsGroupIdHex: mlsGroupIdHex,
+    );
+
+/// Member-change timeline of a group, oldest first.
+///
+/// Derived from the recorded epoch history (see `get_group_epoch_history`),
+/// so only commits observed by this device are included.
+Future<List<GroupEpochEvent>> getGroupEpochEvents({
+  required String mlsGroupIdHex,
+}) => RustLib.instance.api.crateApiGroupGetGroupEpochEvents(
+  mlsGroupIdHex: mlsGroupIdHex,
+);
+
 /// Get the relay URLs configured for a group.
 Future<List<String>> getGroupRelays({required String mlsGroupIdHex}) => RustLib
     .instance
     .api
     .crateApiGroupGetGroupRelays(mlsGroupIdHex: mlsGroupIdHex);
 
+/// Relays an evolution event for the group should reach: its current relays
+/// plus `proposed_relay_urls` (empty when the update doesn't move the group).
+Future<List<String>> relaysForEvolution({
+  required String mlsGroupIdHex,
+  required List<String> proposedRelayUrls,
+}) => RustLib.instance.api.crateApiGroupRelaysForEvolution(
+  mlsGroupIdHex: mlsGroupIdHex,
+  proposedRelayUrls: proposedRelayUrls,
+);
+
+/// Publish an evolution event to the given relays, adding and connecting any
+/// that aren't in the pool yet (e.g. the new relays of a relay update).
+///
+/// Succeeds if at least one relay accepted the event.
+Future<String> publishEvolutionEvent({
+  required String evolutionEventJson,
+  required List<String> relayUrls,
+}) => RustLib.instance.api.crateApiGroupPublishEvolutionEvent(
+  evolutionEventJson: evolutionEventJson,
+  relayUrls: relayUrls,
+);
+
 /// Update the relay URLs for a group. Admin-only.
-/// Returns an evolution event to publish to the old and new relays.
+/// Returns an evolution event to publish to the old and new relays; the
+/// result's `publish_relays` lists both sets, and `publish_evolution_event`
+/// sends to all of them.
 Future<UpdateGroupResult> updateGroupRelays({
   required String mlsGroupIdHex,
   required List<String> relayUrls,
@@ -140,6 +289,25 @@ Future<UpdateGroupResult> updateGroupDescription({
   description: description,
 );
 
+/// Make a member an admin. Admin-only.
+Future<UpdateGroupResult> promoteAdmin({
+  required String mlsGroupIdHex,
+  required String pubkeyHex,
+}) => RustLib.instance.api.crateApiGroupPromoteAdmin(
+  mlsGroupIdHex: mlsGroupIdHex,
+  pubkeyHex: pubkeyHex,
+);
+
+/// Remove a member's admin rights. Admin-only; the last admin can't be
+/// demoted.
+Future<UpdateGroupResult> demoteAdmin({
+  required String mlsGroupIdHex,
+  required String pubkeyHex,
+}) => RustLib.instance.api.crateApiGroupDemoteAdmin(
+  mlsGroupIdHex: mlsGroupIdHex,
+  pubkeyHex: pubkeyHex,
+);
+
 /// Result of creating a group, including welcome events for invited members.
 class CreateGroupResult {
   /// The created group info.
@@ -171,6 +339,144 @@ class CreateGroupResult {
           mlsGroupIdHex == other.mlsGroupIdHex;
 }
 
+/// Result of `create_or_get_dm`.
+class CreateOrGetDmResult {
+  /// The DM group info.
+  final GroupInfo group;
+
+  /// Welcome rumors to gift-wrap and send to the peer. Empty when reused.
+  final List<String> welcomeRumorsJson;
+
+  /// Hex-encoded MLS group ID.
+  final String mlsGroupIdHex;
+
+  /// True if an existing DM was returned and nothing needs publishing.
+  final bool reused;
+
+  const CreateOrGetDmResult({
+    required this.group,
+    required this.welcomeRumorsJson,
+    required this.mlsGroupIdHex,
+    required this.reused,
+  });
+
+  @override
+  int get hashCode =>
+      group.hashCode ^
+      welcomeRumorsJson.hashCode ^
+      mlsGroupIdHex.hashCode ^
+      reused.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreateOrGetDmResult &&
+          runtimeType == other.runtimeType &&
+          group == other.group &&
+          welcomeRumorsJson == other.welcomeRumorsJson &&
+          mlsGroupIdHex == other.mlsGroupIdHex &&
+          reused == other.reused;
+}
+
+/// One MLS epoch of a group and the commit that started it.
+class EpochInfo {
+  final BigInt epoch;
+
+  /// When the commit was applied locally (Unix seconds). None for the
+  /// current epoch if its commit wasn't observed.
+  final BigInt? recordedAt;
+
+  /// Hex pubkey of the member who committed, when known.
+  final String? committerPubkeyHex;
+
+  /// Kind 445 event carrying the commit, when received from a relay.
+  final String? commitEventIdHex;
+  final List<String> addedPubkeys;
+  final List<String> removedPubkeys;
+
+  /// Name, description, admins or relays changed.
+  final bool metadataChanged;
+
+  const EpochInfo({
+    required this.epoch,
+    this.recordedAt,
+    this.committerPubkeyHex,
+    this.commitEventIdHex,
+    required this.addedPubkeys,
+    required this.removedPubkeys,
+    required this.metadataChanged,
+  });
+
+  @override
+  int get hashCode =>
+      epoch.hashCode ^
+      recordedAt.hashCode ^
+      committerPubkeyHex.hashCode ^
+      commitEventIdHex.hashCode ^
+      addedPubkeys.hashCode ^
+      removedPubkeys.hashCode ^
+      metadataChanged.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpochInfo &&
+          runtimeType == other.runtimeType &&
+          epoch == other.epoch &&
+          recordedAt == other.recordedAt &&
+          committerPubkeyHex == other.committerPubkeyHex &&
+          commitEventIdHex == other.commitEventIdHex &&
+          addedPubkeys == other.addedPubkeys &&
+          removedPubkeys == other.removedPubkeys &&
+          metadataChanged == other.metadataChanged;
+}
+
+/// One membership or state change in a group's timeline, for rendering
+/// "Alice joined", "Bob left" and similar system lines.
+class GroupEpochEvent {
+  /// Epoch the change took effect in.
+  final BigInt epoch;
+
+  /// "add", "remove", or "update" (metadata change or key update).
+  final String changeType;
+
+  /// Hex pubkeys joining or leaving; empty for "update".
+  final List<String> pubkeys;
+
+  /// When the commit was applied locally (Unix seconds).
+  final BigInt timestamp;
+
+  /// Hex pubkey of the member who committed, when known.
+  final String? committerPubkeyHex;
+
+  const GroupEpochEvent({
+    required this.epoch,
+    required this.changeType,
+    required this.pubkeys,
+    required this.timestamp,
+    this.committerPubkeyHex,
+  });
+
+  @override
+  int get hashCode =>
+      epoch.hashCode ^
+      changeType.hashCode ^
+      pubkeys.hashCode ^
+      timestamp.hashCode ^
+      committerPubkeyHex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupEpochEvent &&
+          runtimeType == other.runtimeType &&
+          epoch == other.epoch &&
+          changeType == other.changeType &&
+          pubkeys == other.pubkeys &&
+          timestamp == other.timestamp &&
+          committerPubkeyHex == other.committerPubkeyHex;
+}
+
 /// Group information flattened for FFI.
 class GroupInfo {
   /// Hex-encoded MLS group ID (internal protocol identifier).
@@ -215,6 +521,9 @@ class GroupInfo {
   /// Whether this group has an avatar image set.
   final bool hasImage;
 
+  /// Default disappearing-message TTL in seconds. None if messages don't expire.
+  final BigInt? defaultMessageTtlSecs;
+
   const GroupInfo({
     required this.mlsGroupIdHex,
     required this.nostrGroupIdHex,
@@ -230,6 +539,7 @@ class GroupInfo {
     this.dmPeerPubkeyHex,
     this.imageHashHex,
     required this.hasImage,
+    this.defaultMessageTtlSecs,
   });
 
   @override
@@ -247,7 +557,8 @@ class GroupInfo {
       dmPeerPicture.hashCode ^
       dmPeerPubkeyHex.hashCode ^
       imageHashHex.hashCode ^
-      hasImage.hashCode;
+      hasImage.hashCode ^
+      defaultMessageTtlSecs.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -267,7 +578,8 @@ class GroupInfo {
           dmPeerPicture == other.dmPeerPicture &&
           dmPeerPubkeyHex == other.dmPeerPubkeyHex &&
           imageHashHex == other.imageHashHex &&
-          hasImage == other.hasImage;
+          hasImage == other.hasImage &&
+          defaultMessageTtlSecs == other.defaultMessageTtlSecs;
 }
 
 /// Member information for FFI, enriched with cached profile data.
@@ -297,6 +609,40 @@ class MemberInfo {
           picture == other.picture;
 }
 
+/// Outcome of `reconcile_groups`.
+class ReconcileReport {
+  /// Groups MDK holds.
+  final int mdkGroupCount;
+
+  /// Active MDK groups that were hidden as forgotten and are listed again.
+  final List<String> restoredGroupIds;
+
+  /// Groups the app DB has state for that MDK doesn't know. Left in
+  /// place for the caller to inspect or clear.
+  final List<String> orphanedGroupIds;
+
+  const ReconcileReport({
+    required this.mdkGroupCount,
+    required this.restoredGroupIds,
+    required this.orphanedGroupIds,
+  });
+
+  @override
+  int get hashCode =>
+      mdkGroupCount.hashCode ^
+      restoredGroupIds.hashCode ^
+      orphanedGroupIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReconcileReport &&
+          runtimeType == other.runtimeType &&
+          mdkGroupCount == other.mdkGroupCount &&
+          restoredGroupIds == other.restoredGroupIds &&
+          orphanedGroupIds == other.orphanedGroupIds;
+}
+
 /// Result of a group update operation (add/remove members, leave, etc.).
 class UpdateGroupResult {
   /// JSON-serialized kind 445 evolution event to publish to group relays.
@@ -308,17 +654,23 @@ class UpdateGroupResult {
   /// Hex-encoded MLS group ID this update applies to.
   final String mlsGroupIdHex;
 
+  /// Relays to publish the evolution event to. For relay updates this is
+  /// the union of the old and new relay sets.
+  final List<String> publishRelays;
+
   const UpdateGroupResult({
     required this.evolutionEventJson,
     required this.welcomeRumorsJson,
     required this.mlsGroupIdHex,
+    required this.publishRelays,
   });
 
   @override
   int get hashCode =>
       evolutionEventJson.hashCode ^
       welcomeRumorsJson.hashCode ^
-      mlsGroupIdHex.hashCode;
+      mlsGroupIdHex.hashCode ^
+      publishRelays.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -327,7 +679,8 @@ class UpdateGroupResult {
           runtimeType == other.runtimeType &&
           evolutionEventJson == other.evolutionEventJson &&
           welcomeRumorsJson == other.welcomeRumorsJson &&
-          mlsGroupIdHex == other.mlsGroupIdHex;
+          mlsGroupIdHex == other.mlsGroupIdHex &&
+          publishRelays == other.publishRelays;
 }
 
 /// Result of uploading a group image to Blossom.
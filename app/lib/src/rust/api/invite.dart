@@ -8,12 +8,26 @@ import 'error.dart';
 import 'group.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `parse_ciphersuite`, `tag_values`
+// These functions are ignored because they have generic arguments: `invite_members_full_with`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `check_key_package_event`, `validate_key_packages`
+
+/// Set how old a KeyPackage may be before `add_members` refuses it.
+void setKeyPackageMaxAge({required BigInt secs}) =>
+    RustLib.instance.api.crateApiInviteSetKeyPackageMaxAge(secs: secs);
+
+/// Get the KeyPackage max age enforced by `add_members`.
+BigInt getKeyPackageMaxAge() =>
+    RustLib.instance.api.crateApiInviteGetKeyPackageMaxAge();
 
 /// Add members to an existing group. Admin-only.
 ///
 /// `key_package_events_json`: JSON-serialized kind 443 KeyPackage events for each new member.
 ///
+/// KeyPackages are checked first (see `validate_key_packages`); the error
+/// lists each invitee whose package is expired or for another ciphersuite.
+///
 /// Returns an evolution event (commit) to publish and welcome rumors to gift-wrap.
 /// IMPORTANT: Publish the evolution event FIRST, wait for relay ack, then merge_pending_commit,
 /// then send welcome rumors via NIP-59 gift wrap. This prevents state forks per MIP-02.
@@ -25,6 +39,23 @@ Future<UpdateGroupResult> addMembers({
   keyPackageEventsJson: keyPackageEventsJson,
 );
 
+/// Add members and run the whole MIP-02 flow in order: publish the commit
+/// and wait for a relay to accept it, merge it, then gift-wrap and publish
+/// each welcome.
+///
+/// If the commit isn't accepted by any relay it is discarded instead of
+/// merged, so the group stays at its current epoch and the invite can be
+/// retried.
+/// Welcome failures after the merge don't fail the call; see
+/// `failed_welcome_pubkeys_hex`.
+Future<InviteResult> inviteMembersFull({
+  required String mlsGroupIdHex,
+  required List<String> keyPackageEventsJson,
+}) => RustLib.instance.api.crateApiInviteInviteMembersFull(
+  mlsGroupIdHex: mlsGroupIdHex,
+  keyPackageEventsJson: keyPackageEventsJson,
+);
+
 /// Remove members from a group. Admin-only.
 ///
 /// `pubkeys_hex`: Hex-encoded public keys of members to remove.
@@ -100,6 +131,46 @@ Future<String> giftWrapWelcome({
 Future<String> fetchKeyPackage({required String pubkeyHex}) =>
     RustLib.instance.api.crateApiInviteFetchKeyPackage(pubkeyHex: pubkeyHex);
 
+/// Result of `invite_members_full`.
+class InviteResult {
+  /// Hex-encoded MLS group ID.
+  final String mlsGroupIdHex;
+
+  /// ID of the published commit (kind 445).
+  final String evolutionEventIdHex;
+
+  /// IDs of the published gift-wrapped welcomes (kind 1059).
+  final List<String> welcomeEventIdsHex;
+
+  /// Invitees whose welcome couldn't be published. They are already
+  /// members; send their welcome again with `gift_wrap_welcome`.
+  final List<String> failedWelcomePubkeysHex;
+
+  const InviteResult({
+    required this.mlsGroupIdHex,
+    required this.evolutionEventIdHex,
+    required this.welcomeEventIdsHex,
+    required this.failedWelcomePubkeysHex,
+  });
+
+  @override
+  int get hashCode =>
+      mlsGroupIdHex.hashCode ^
+      evolutionEventIdHex.hashCode ^
+      welcomeEventIdsHex.hashCode ^
+      failedWelcomePubkeysHex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InviteResult &&
+          runtimeType == other.runtimeType &&
+          mlsGroupIdHex == other.mlsGroupIdHex &&
+          evolutionEventIdHex == other.evolutionEventIdHex &&
+          welcomeEventIdsHex == other.welcomeEventIdsHex &&
+          failedWelcomePubkeysHex == other.failedWelcomePubkeysHex;
+}
+
 /// Welcome information received from another user.
 class WelcomeInfo {
   /// Hex-encoded welcome event ID.
@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode_html_entities`, `extract_attr`, `extract_domain`, `parse_og_from_html`, `truncate`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Extract URLs from a text string.
+///
+/// Returns a list of URLs found in the text. Uses a simple but effective
+/// regex-free approach to find http:// and https:// URLs.
+Future<List<String>> extractUrls({required String text}) =>
+    RustLib.instance.api.crateApiLinkPreviewExtractUrls(text: text);
+
+/// Fetch Open Graph metadata for a URL.
+///
+/// Makes an HTTP GET request, follows redirects, and parses OG meta tags
+/// from the HTML response. Returns metadata even if some fields are missing.
+///
+/// Timeout: 10 seconds. Only fetches the first 256KB of HTML to avoid
+/// downloading large pages.
+Future<OgMetadata> fetchOgMetadata({required String url}) =>
+    RustLib.instance.api.crateApiLinkPreviewFetchOgMetadata(url: url);
+
+/// Open Graph metadata extracted from a URL.
+class OgMetadata {
+  /// The original URL that was fetched.
+  final String url;
+
+  /// og:title — page title.
+  final String? title;
+
+  /// og:description — page description (truncated to 300 chars).
+  final String? description;
+
+  /// og:image — URL to a preview image/thumbnail.
+  final String? imageUrl;
+
+  /// og:site_name — name of the website.
+  final String? siteName;
+
+  /// Domain name extracted from the URL (e.g. "example.com").
+  final String domain;
+
+  /// og:type — content type (e.g. "article", "website").
+  final String? ogType;
+
+  const OgMetadata({
+    required this.url,
+    this.title,
+    this.description,
+    this.imageUrl,
+    this.siteName,
+    required this.domain,
+    this.ogType,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      title.hashCode ^
+      description.hashCode ^
+      imageUrl.hashCode ^
+      siteName.hashCode ^
+      domain.hashCode ^
+      ogType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OgMetadata &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          title == other.title &&
+          description == other.description &&
+          imageUrl == other.imageUrl &&
+          siteName == other.siteName &&
+          domain == other.domain &&
+          ogType == other.ogType;
+}
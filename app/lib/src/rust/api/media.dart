@@ -7,23 +7,69 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_media_reference`, `parse_blossom_url`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `at_eof`, `build_media_reference`, `canonical_mime`, `chunk_aad`, `chunk_nonce`, `encrypt_file_from_path_chunked`, `fetch_blob`, `media_cache_dir`, `media_cache_entries`, `media_cache_evict`, `media_cache_path`, `media_tmp_dir`, `parse_blossom_url`, `parse_thumb_field`, `read_full`, `sniff_file_mime_type`, `wrapped_key_filename`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `blossom_hash_from_url`, `downscale_to_jpeg`, `media_cache_get`, `media_cache_put`, `media_cache_usage`, `mirror_urls`, `sniff_mime_type`, `verify_blob_hash`
 
 /// Encrypt a file for a group using MIP-04 v2.
 ///
 /// Derives a file-specific key from the group's current MLS exporter secret,
 /// generates a random nonce, and encrypts with ChaCha20-Poly1305 + AAD.
+///
+/// `mime_type` is checked against the file's magic bytes first: on a
+/// mismatch the detected type is used, or with `strict` an error is
+/// returned. See `sniff_mime_type`.
 Future<EncryptedFileResult> encryptFile({
   required String mlsGroupIdHex,
   required List<int> fileData,
   required String mimeType,
   required String filename,
+  required bool strict,
 }) => RustLib.instance.api.crateApiMediaEncryptFile(
   mlsGroupIdHex: mlsGroupIdHex,
   fileData: fileData,
   mimeType: mimeType,
   filename: filename,
+  strict: strict,
+);
+
+/// Encrypt a file on disk for a group, writing the ciphertext under the
+/// data directory instead of returning it.
+///
+/// Files below `CHUNKED_THRESHOLD_BYTES` use whole-buffer MIP-04 v2: MDK
+/// needs the plaintext in memory, but it is dropped as soon as the
+/// ciphertext exists and the file never crosses the FFI boundary. Larger
+/// files are streamed through `encrypt_file_chunked`, so memory stays at
+/// one frame; `scheme_version` says which was used.
+///
+/// `mime_type` and `strict` are checked as in `encrypt_file`.
+Future<EncryptedFileOnDisk> encryptFileFromPath({
+  required String mlsGroupIdHex,
+  required String filePath,
+  required String mimeType,
+  required String filename,
+  required bool strict,
+}) => RustLib.instance.api.crateApiMediaEncryptFileFromPath(
+  mlsGroupIdHex: mlsGroupIdHex,
+  filePath: filePath,
+  mimeType: mimeType,
+  filename: filename,
+  strict: strict,
+);
+
+/// Downscale an image to fit within `max_dimension` and encrypt it as a
+/// JPEG thumbnail (MIP-04 v2).
+///
+/// Upload the result like any other file and reference it from the full
+/// image's imeta tag via `build_imeta_tag`'s `thumb`.
+Future<EncryptedFileResult> encryptThumbnail({
+  required String mlsGroupIdHex,
+  required List<int> fileData,
+  required int maxDimension,
+}) => RustLib.instance.api.crateApiMediaEncryptThumbnail(
+  mlsGroupIdHex: mlsGroupIdHex,
+  fileData: fileData,
+  maxDimension: maxDimension,
 );
 
 /// Decrypt an encrypted file received from a group.
@@ -54,7 +100,8 @@ Future<Uint8List> decryptFile({
 
 /// Upload encrypted media to a Blossom server and return imeta tag data.
 ///
-/// 1. Encrypts the file via MIP-04 v2.
+/// 1. Encrypts the file via MIP-04 v2, correcting a `mime_type` that
+///    doesn't match the content.
 /// 2. Uploads the ciphertext to `blossom_server_url` using HTTP PUT.
 /// 3. Constructs the imeta tag from the upload result + returned URL.
 Future<UploadMediaResult> uploadMedia({
@@ -71,9 +118,28 @@ Future<UploadMediaResult> uploadMedia({
   blossomServerUrl: blossomServerUrl,
 );
 
+/// Encrypt and upload several attachments concurrently.
+///
+/// Runs at most `MEDIA_UPLOAD_CONCURRENCY` uploads at a time. Returns one
+/// outcome per input, in input order, so a partially failed batch can still
+/// send the successful attachments: collect `imeta_tag_values` from the
+/// items with a `result` and pass them to `send_message_with_media`.
+Future<List<BatchUploadItem>> uploadMediaBatch({
+  required String mlsGroupIdHex,
+  required List<FileInput> files,
+  required String blossomServerUrl,
+}) => RustLib.instance.api.crateApiMediaUploadMediaBatch(
+  mlsGroupIdHex: mlsGroupIdHex,
+  files: files,
+  blossomServerUrl: blossomServerUrl,
+);
+
 /// Download encrypted media from a Blossom URL and decrypt it.
 ///
-/// 1. Fetches the ciphertext from `url`.
+/// 1. Fetches the ciphertext from `url`, falling back to `mirror_servers`
+///    in order if that fails. Blossom blobs are addressed by hash, so a
+///    mirror serves `{mirror}/{hash}`; only blobs matching the hash in
+///    `url` are accepted, and mirrors are only tried when `url` has one.
 /// 2. Decrypts using the group's exporter secret + imeta metadata.
 /// 3. Returns the plaintext bytes.
 Future<Uint8List> downloadMedia({
@@ -85,6 +151,7 @@ Future<Uint8List> downloadMedia({
   required String nonceHex,
   required String schemeVersion,
   String? dimensions,
+  required List<String> mirrorServers,
 }) => RustLib.instance.api.crateApiMediaDownloadMedia(
   mlsGroupIdHex: mlsGroupIdHex,
   url: url,
@@ -94,12 +161,14 @@ Future<Uint8List> downloadMedia({
   nonceHex: nonceHex,
   schemeVersion: schemeVersion,
   dimensions: dimensions,
+  mirrorServers: mirrorServers,
 );
 
 /// Build an imeta tag value array from media metadata.
 ///
 /// Returns a flat `Vec<String>` of "key value" pairs suitable for inclusion
-/// in a Nostr event tag: `["imeta", "url ...", "m ...", ...]`.
+/// in a Nostr event tag: `["imeta", "url ...", "m ...", ...]`. `thumb`
+/// references an uploaded `encrypt_thumbnail` result.
 Future<List<String>> buildImetaTag({
   required String url,
   required String mimeType,
@@ -108,6 +177,7 @@ Future<List<String>> buildImetaTag({
   required String nonceHex,
   String? dimensions,
   String? blurhash,
+  ThumbnailReference? thumb,
 }) => RustLib.instance.api.crateApiMediaBuildImetaTag(
   url: url,
   mimeType: mimeType,
@@ -116,6 +186,7 @@ Future<List<String>> buildImetaTag({
   nonceHex: nonceHex,
   dimensions: dimensions,
   blurhash: blurhash,
+  thumb: thumb,
 );
 
 /// Parse an imeta tag (as a flat string array) into a `MediaReferenceInfo`.
@@ -125,6 +196,263 @@ Future<List<String>> buildImetaTag({
 Future<MediaReferenceInfo> parseImetaTag({required List<String> tagValues}) =>
     RustLib.instance.api.crateApiMediaParseImetaTag(tagValues: tagValues);
 
+/// Whether a file of `file_size` bytes should use chunked encryption.
+///
+/// Small files keep the whole-buffer MIP-04 v2 path for compatibility with
+/// clients that don't understand the chunked scheme.
+bool shouldUseChunkedEncryption({required BigInt fileSize}) => RustLib
+    .instance
+    .api
+    .crateApiMediaShouldUseChunkedEncryption(fileSize: fileSize);
+
+/// Encrypt a file for a group in fixed-size frames, streaming from
+/// `input_path` to `output_path` without loading the whole file.
+///
+/// `mime_type` and `strict` are checked as in `encrypt_file`, against the
+/// file's first bytes.
+Future<ChunkedEncryptResult> encryptFileChunked({
+  required String mlsGroupIdHex,
+  required String inputPath,
+  required String outputPath,
+  required String mimeType,
+  required String filename,
+  required bool strict,
+}) => RustLib.instance.api.crateApiMediaEncryptFileChunked(
+  mlsGroupIdHex: mlsGroupIdHex,
+  inputPath: inputPath,
+  outputPath: outputPath,
+  mimeType: mimeType,
+  filename: filename,
+  strict: strict,
+);
+
+/// Decrypt a chunked blob from `input_path` to `output_path`.
+///
+/// Output is written to a `.part` file and renamed only after every frame
+/// authenticates and the plaintext matches `original_hash_hex`.
+/// Returns the number of plaintext bytes written.
+Future<BigInt> decryptFileChunked({
+  required String mlsGroupIdHex,
+  required String inputPath,
+  required String outputPath,
+  required String filename,
+  required String originalHashHex,
+}) => RustLib.instance.api.crateApiMediaDecryptFileChunked(
+  mlsGroupIdHex: mlsGroupIdHex,
+  inputPath: inputPath,
+  outputPath: outputPath,
+  filename: filename,
+  originalHashHex: originalHashHex,
+);
+
+/// Build an imeta tag for chunked media (same fields, `v mip04-v2-chunked`).
+Future<List<String>> buildChunkedImetaTag({
+  required String url,
+  required String mimeType,
+  required String filename,
+  required String originalHashHex,
+  required String nonceHex,
+}) => RustLib.instance.api.crateApiMediaBuildChunkedImetaTag(
+  url: url,
+  mimeType: mimeType,
+  filename: filename,
+  originalHashHex: originalHashHex,
+  nonceHex: nonceHex,
+);
+
+/// Set the maximum on-disk size of the media cache. 0 disables caching.
+///
+/// Evicts least recently used entries right away if the cache is now over
+/// the limit.
+void setMediaCacheLimit({required BigInt bytes}) =>
+    RustLib.instance.api.crateApiMediaSetMediaCacheLimit(bytes: bytes);
+
+/// Get the maximum on-disk size of the media cache.
+BigInt getMediaCacheLimit() =>
+    RustLib.instance.api.crateApiMediaGetMediaCacheLimit();
+
+/// Current size of the media cache in bytes.
+Future<BigInt> getMediaCacheUsage() =>
+    RustLib.instance.api.crateApiMediaGetMediaCacheUsage();
+
+/// Delete every cached attachment.
+Future<void> clearMediaCache() =>
+    RustLib.instance.api.crateApiMediaClearMediaCache();
+
+/// Outcome of one file in `upload_media_batch`. Exactly one of `result` and
+/// `error` is set.
+class BatchUploadItem {
+  /// Filename of the input this outcome belongs to.
+  final String filename;
+  final UploadMediaResult? result;
+  final String? error;
+
+  const BatchUploadItem({required this.filename, this.result, this.error});
+
+  @override
+  int get hashCode => filename.hashCode ^ result.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchUploadItem &&
+          runtimeType == other.runtimeType &&
+          filename == other.filename &&
+          result == other.result &&
+          error == other.error;
+}
+
+/// Result of chunked file encryption. Ciphertext is written to `output_path`.
+class ChunkedEncryptResult {
+  /// Path of the encrypted blob, ready for Blossom upload.
+  final String outputPath;
+
+  /// SHA-256 of the original plaintext file (hex) — the imeta `x` field.
+  final String originalHashHex;
+
+  /// SHA-256 of the encrypted blob (hex) — the Blossom content address.
+  final String encryptedHashHex;
+  final String mimeType;
+  final String filename;
+  final BigInt originalSize;
+  final BigInt encryptedSize;
+
+  /// Key-wrap nonce (hex) — the imeta `n` field.
+  final String nonceHex;
+
+  /// Plaintext bytes per frame.
+  final int frameSize;
+
+  /// Always `CHUNKED_SCHEME_VERSION`.
+  final String schemeVersion;
+
+  const ChunkedEncryptResult({
+    required this.outputPath,
+    required this.originalHashHex,
+    required this.encryptedHashHex,
+    required this.mimeType,
+    required this.filename,
+    required this.originalSize,
+    required this.encryptedSize,
+    required this.nonceHex,
+    required this.frameSize,
+    required this.schemeVersion,
+  });
+
+  @override
+  int get hashCode =>
+      outputPath.hashCode ^
+      originalHashHex.hashCode ^
+      encryptedHashHex.hashCode ^
+      mimeType.hashCode ^
+      filename.hashCode ^
+      originalSize.hashCode ^
+      encryptedSize.hashCode ^
+      nonceHex.hashCode ^
+      frameSize.hashCode ^
+      schemeVersion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChunkedEncryptResult &&
+          runtimeType == other.runtimeType &&
+          outputPath == other.outputPath &&
+          originalHashHex == other.originalHashHex &&
+          encryptedHashHex == other.encryptedHashHex &&
+          mimeType == other.mimeType &&
+          filename == other.filename &&
+          originalSize == other.originalSize &&
+          encryptedSize == other.encryptedSize &&
+          nonceHex == other.nonceHex &&
+          frameSize == other.frameSize &&
+          schemeVersion == other.schemeVersion;
+}
+
+/// `EncryptedFileResult` with the ciphertext written to disk instead of
+/// held in memory.
+class EncryptedFileOnDisk {
+  /// Path of the encrypted bytes, ready for upload. The caller deletes it
+  /// once uploaded.
+  final String encryptedPath;
+
+  /// SHA-256 of the *original* plaintext file (hex).
+  final String originalHashHex;
+
+  /// SHA-256 of the encrypted data (hex).
+  final String encryptedHashHex;
+
+  /// Canonical MIME type.
+  final String mimeType;
+
+  /// Original filename.
+  final String filename;
+
+  /// Original file size in bytes.
+  final BigInt originalSize;
+
+  /// Encrypted file size in bytes.
+  final BigInt encryptedSize;
+
+  /// Image/video dimensions ("widthxheight") if applicable.
+  final String? dimensions;
+
+  /// Blurhash string for progressive image loading.
+  final String? blurhash;
+
+  /// Encryption nonce (hex, 24 chars / 12 bytes).
+  final String nonceHex;
+
+  /// imeta `v` value: "mip04-v2", or `CHUNKED_SCHEME_VERSION` for files
+  /// that were streamed (build their tag with `build_chunked_imeta_tag`).
+  final String schemeVersion;
+
+  const EncryptedFileOnDisk({
+    required this.encryptedPath,
+    required this.originalHashHex,
+    required this.encryptedHashHex,
+    required this.mimeType,
+    required this.filename,
+    required this.originalSize,
+    required this.encryptedSize,
+    this.dimensions,
+    this.blurhash,
+    required this.nonceHex,
+    required this.schemeVersion,
+  });
+
+  @override
+  int get hashCode =>
+      encryptedPath.hashCode ^
+      originalHashHex.hashCode ^
+      encryptedHashHex.hashCode ^
+      mimeType.hashCode ^
+      filename.hashCode ^
+      originalSize.hashCode ^
+      encryptedSize.hashCode ^
+      dimensions.hashCode ^
+      blurhash.hashCode ^
+      nonceHex.hashCode ^
+      schemeVersion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncryptedFileOnDisk &&
+          runtimeType == other.runtimeType &&
+          encryptedPath == other.encryptedPath &&
+          originalHashHex == other.originalHashHex &&
+          encryptedHashHex == other.encryptedHashHex &&
+          mimeType == other.mimeType &&
+          filename == other.filename &&
+          originalSize == other.originalSize &&
+          encryptedSize == other.encryptedSize &&
+          dimensions == other.dimensions &&
+          blurhash == other.blurhash &&
+          nonceHex == other.nonceHex &&
+          schemeVersion == other.schemeVersion;
+}
+
 /// Metadata about an encrypted file, ready for upload or imeta tag creation.
 class EncryptedFileResult {
   /// Encrypted bytes (ciphertext + Poly1305 tag).
@@ -200,6 +528,31 @@ class EncryptedFileResult {
           nonceHex == other.nonceHex;
 }
 
+/// One file to upload as part of a batch.
+class FileInput {
+  final Uint8List fileData;
+  final String mimeType;
+  final String filename;
+
+  const FileInput({
+    required this.fileData,
+    required this.mimeType,
+    required this.filename,
+  });
+
+  @override
+  int get hashCode => fileData.hashCode ^ mimeType.hashCode ^ filename.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileInput &&
+          runtimeType == other.runtimeType &&
+          fileData == other.fileData &&
+          mimeType == other.mimeType &&
+          filename == other.filename;
+}
+
 /// Parsed imeta tag fields for a received encrypted media reference.
 class MediaReferenceInfo {
   /// Blossom storage URL.
@@ -223,6 +576,9 @@ class MediaReferenceInfo {
   /// Nonce (hex, 24 chars).
   final String nonceHex;
 
+  /// Low-res preview, if the sender attached one.
+  final ThumbnailReference? thumb;
+
   const MediaReferenceInfo({
     required this.url,
     required this.originalHashHex,
@@ -231,6 +587,7 @@ class MediaReferenceInfo {
     this.dimensions,
     required this.schemeVersion,
     required this.nonceHex,
+    this.thumb,
   });
 
   @override
@@ -241,7 +598,8 @@ class MediaReferenceInfo {
       filename.hashCode ^
       dimensions.hashCode ^
       schemeVersion.hashCode ^
-      nonceHex.hashCode;
+      nonceHex.hashCode ^
+      thumb.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -254,6 +612,42 @@ class MediaReferenceInfo {
           filename == other.filename &&
           dimensions == other.dimensions &&
           schemeVersion == other.schemeVersion &&
+          nonceHex == other.nonceHex &&
+          thumb == other.thumb;
+}
+
+/// An encrypted thumbnail referenced from an imeta `thumb` field
+/// (`thumb <url>;<hash>;<nonce>`).
+///
+/// Thumbnails are always encrypted as `THUMBNAIL_MIME_TYPE` named
+/// `THUMBNAIL_FILENAME`, so pass those to `decrypt_file` with these fields.
+class ThumbnailReference {
+  /// Blossom storage URL.
+  final String url;
+
+  /// SHA-256 of the thumbnail plaintext (hex).
+  final String originalHashHex;
+
+  /// Nonce (hex, 24 chars).
+  final String nonceHex;
+
+  const ThumbnailReference({
+    required this.url,
+    required this.originalHashHex,
+    required this.nonceHex,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^ originalHashHex.hashCode ^ nonceHex.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThumbnailReference &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          originalHashHex == other.originalHashHex &&
           nonceHex == other.nonceHex;
 }
 
@@ -8,9 +8,14 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'meeting_intelligence.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_action_items_rule_based`, `extract_decisions_rule_based`, `extract_key_points`, `extract_questions_rule_based`, `generate_basic_summary`, `generate_rule_based_notes`, `generate_title`, `intelligence`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActionItem`, `Decision`, `MeetingIntelligence`, `MeetingNotes`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `ai_response_json`, `archive_path`, `archive`, `compile_redact_patterns`, `confident_segments`, `dedupe_action_items`, `extract_action_items_rule_based`, `extract_decisions_rule_based`, `extract_key_points`, `extract_questions_rule_based`, `generate_basic_summary`, `generate_rule_based_notes`, `generate_title`, `intelligence`, `jaccard`, `load_archive`, `normalized_words`, `notes_from_ai_response`, `parse_ai_notes`, `parse_deadline`, `priority_rank`, `redact_notes`, `redact_text`, `request_ai_notes`, `save_archive`, `segment_bounds`, `sorted_live_segments`, `transcript_for_prompt`, `write_archive`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActionItemWithContext`, `ActionItem`, `AiActionItem`, `AiDecision`, `AiNotes`, `Decision`, `MeetingIntelligence`, `MeetingNotes`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// Default redaction patterns: card-like numbers, Nostr secret keys and
+/// common API key / token shapes.
+Future<List<String>> defaultRedactPatterns() =>
+    RustLib.instance.api.crateApiMeetingIntelligenceDefaultRedactPatterns();
 
 /// Configure the AI backend for meeting intelligence.
 Future<void> configureAiBackend({required String backendJson}) => RustLib
@@ -18,10 +23,38 @@ Future<void> configureAiBackend({required String backendJson}) => RustLib
     .api
     .crateApiMeetingIntelligenceConfigureAiBackend(backendJson: backendJson);
 
+/// Set the regexes whose matches are replaced with `[REDACTED]` in
+/// generated notes. Takes a JSON array of patterns; `[]` disables
+/// redaction. Invalid patterns are rejected and the current set is kept.
+Future<void> configureRedaction({required String redactPatternsJson}) =>
+    RustLib.instance.api.crateApiMeetingIntelligenceConfigureRedaction(
+      redactPatternsJson: redactPatternsJson,
+    );
+
+/// Current redaction patterns as a JSON array.
+Future<String> getRedactPatterns() =>
+    RustLib.instance.api.crateApiMeetingIntelligenceGetRedactPatterns();
+
+/// Set how transcript confidence is used when generating notes.
+///
+/// Segments below `min_confidence` are dropped as ASR noise; items
+/// extracted from segments below `review_confidence` are kept but flagged
+/// `needs_review`. Both are 0.0-1.0; the defaults are 0.0 (keep all) and
+/// `DEFAULT_REVIEW_CONFIDENCE`.
+Future<void> configureConfidence({
+  required double minConfidence,
+  required double reviewConfidence,
+}) => RustLib.instance.api.crateApiMeetingIntelligenceConfigureConfidence(
+  minConfidence: minConfidence,
+  reviewConfidence: reviewConfidence,
+);
+
 /// Generate meeting notes from a transcript.
 ///
 /// This is the main entry point after a call ends. It processes the full
-/// transcript and produces structured meeting notes.
+/// transcript and produces structured meeting notes. With an Ollama or
+/// Claude backend the transcript is sent to the model; if the request fails
+/// or its reply can't be parsed, the rule-based notes are used instead.
 Future<String> generateMeetingNotes({
   required String meetingId,
   required String segmentsJson,
@@ -36,6 +69,52 @@ Future<String> generateMeetingNotes({
   endTimeMs: endTimeMs,
 );
 
+/// Buffer a single transcript segment for a live meeting.
+///
+/// Segments are upserted by ID, so an interim result is replaced by its
+/// final version. Returns the number of buffered segments for the meeting.
+Future<int> pushTranscriptSegment({
+  required String meetingId,
+  required String segmentJson,
+}) => RustLib.instance.api.crateApiMeetingIntelligencePushTranscriptSegment(
+  meetingId: meetingId,
+  segmentJson: segmentJson,
+);
+
+/// Generate notes from the segments buffered so far for a live meeting.
+///
+/// Used by the live-notes view to refresh incrementally; the result is
+/// not archived and the buffer is kept. Call `finalize_live_meeting` when
+/// the call ends.
+Future<String> generateLiveMeetingNotes({
+  required String meetingId,
+  required String participantsJson,
+}) => RustLib.instance.api.crateApiMeetingIntelligenceGenerateLiveMeetingNotes(
+  meetingId: meetingId,
+  participantsJson: participantsJson,
+);
+
+/// Generate final notes from a live meeting's buffer, archive them and
+/// evict the buffer.
+Future<String> finalizeLiveMeeting({
+  required String meetingId,
+  required String participantsJson,
+  required PlatformInt64 startTimeMs,
+  required PlatformInt64 endTimeMs,
+}) => RustLib.instance.api.crateApiMeetingIntelligenceFinalizeLiveMeeting(
+  meetingId: meetingId,
+  participantsJson: participantsJson,
+  startTimeMs: startTimeMs,
+  endTimeMs: endTimeMs,
+);
+
+/// Drop a meeting's live buffer without generating notes (e.g. call
+/// cancelled). Returns whether a buffer existed.
+Future<bool> clearTranscriptBuffer({required String meetingId}) => RustLib
+    .instance
+    .api
+    .crateApiMeetingIntelligenceClearTranscriptBuffer(meetingId: meetingId);
+
 /// Build a prompt for LLM-based meeting notes generation.
 ///
 /// Used with Ollama or Claude backends.
@@ -44,6 +123,15 @@ Future<String> buildMeetingNotesPrompt({required String transcriptText}) =>
       transcriptText: transcriptText,
     );
 
+/// Build the LLM prompt from transcript segments, applying the configured
+/// confidence thresholds: low-confidence segments are dropped, uncertain
+/// ones are marked so the model flags what it derives from them.
+Future<String> buildMeetingNotesPromptFromSegments({
+  required String segmentsJson,
+}) => RustLib.instance.api.crateApiMeetingIntelligenceBuildMeetingNotesPromptFromSegments(
+  segmentsJson: segmentsJson,
+);
+
 /// Get all archived meeting notes.
 Future<String> getMeetingArchive() =>
     RustLib.instance.api.crateApiMeetingIntelligenceGetMeetingArchive();
@@ -61,6 +149,22 @@ Future<bool> toggleActionItem({
   actionItemId: actionItemId,
 );
 
+/// Delete a meeting's notes from the archive, in memory and on disk.
+/// Returns whether the meeting existed.
+Future<bool> deleteMeetingNotes({required String meetingId}) => RustLib
+    .instance
+    .api
+    .crateApiMeetingIntelligenceDeleteMeetingNotes(meetingId: meetingId);
+
+/// Get all incomplete action items across the archive as a JSON array of
+/// `ActionItemWithContext`, optionally only those assigned to `assignee_pubkey`.
+///
+/// Sorted by deadline (items without one last), then priority high → low.
+Future<String> getOpenActionItems({String? assigneePubkey}) =>
+    RustLib.instance.api.crateApiMeetingIntelligenceGetOpenActionItems(
+      assigneePubkey: assigneePubkey,
+    );
+
 /// Get meeting notes by ID.
 Future<String> getMeetingNotes({required String meetingId}) => RustLib
     .instance
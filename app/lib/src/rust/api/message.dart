@@ -7,21 +7,98 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `all_group_relays`, `all_stored_messages`, `create_and_fetch`, `custom_emoji_shortcode`, `dispatch_send`, `erase_messages`, `expiration_tag`, `group_id_for_event`, `is_emoji_base`, `is_erased`, `is_single_emoji`, `message_expiration`, `mls_group_id_hex`, `prepare_outbox_entry`, `process_with_history`, `reply_context`, `stored_message_expired`, `stored_to_group_message`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ReadReceipt`, `SendRequest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `aggregate_receipts`, `diff_member_sets`, `edit_target`, `filter_messages_by_query`, `mentions_pubkey`, `reply_target`
+
+/// Set the content size limit enforced by `send_message` (bytes, min 256).
+void setMaxMessageBytes({required int maxBytes}) =>
+    RustLib.instance.api.crateApiMessageSetMaxMessageBytes(maxBytes: maxBytes);
+
+/// Get the content size limit enforced by `send_message`.
+int getMaxMessageBytes() =>
+    RustLib.instance.api.crateApiMessageGetMaxMessageBytes();
 
 /// Send an encrypted message to a group (MIP-03).
 ///
 /// Creates a plaintext rumor, MLS-encrypts it, NIP-44-encrypts with exporter_secret,
 /// signs with an ephemeral key, and returns both the kind 445 event for relay publication
 /// and the local GroupMessage for immediate UI display.
+///
+/// Content over `get_max_message_bytes()` is rejected up front, since relays
+/// drop oversized events with an opaque failure; use `send_long_message`.
+///
+/// `ttl_secs` sets a NIP-40 expiration on this message: None uses the
+/// group's default (`set_group_message_ttl`), Some(0) sends without one.
 Future<SendMessageResult> sendMessage({
   required String mlsGroupIdHex,
   required String content,
+  BigInt? ttlSecs,
 }) => RustLib.instance.api.crateApiMessageSendMessage(
   mlsGroupIdHex: mlsGroupIdHex,
   content: content,
+  ttlSecs: ttlSecs,
+);
+
+/// Send content of any length as ordered chunks, one message per chunk.
+///
+/// Each chunk carries a `["part", "<index>", "<total>", "<id>"]` tag (1-based
+/// index, shared random id) so receivers can rebuild the original with
+/// `reassemble_message`. Content within the limit is sent as one untagged
+/// message. Returns one result per chunk, in order; publish them in order.
+Future<List<SendMessageResult>> sendLongMessage({
+  required String mlsGroupIdHex,
+  required String content,
+}) => RustLib.instance.api.crateApiMessageSendLongMessage(
+  mlsGroupIdHex: mlsGroupIdHex,
+  content: content,
 );
 
+/// Split content into chunks of at most `max_bytes` bytes.
+///
+/// Prefers breaking after a newline or space in the second half of the
+/// window, never splits a UTF-8 character, and concatenating the chunks
+/// reproduces the input exactly.
+List<String> splitMessageContent({
+  required String content,
+  required int maxBytes,
+}) => RustLib.instance.api.crateApiMessageSplitMessageContent(
+  content: content,
+  maxBytes: maxBytes,
+);
+
+/// Rebuild a long message from its parts (see `send_long_message`).
+///
+/// `parts` may arrive in any order but must all share the same part id and
+/// cover every index from 1 to total.
+String reassembleMessage({required List<GroupMessage> parts}) =>
+    RustLib.instance.api.crateApiMessageReassembleMessage(parts: parts);
+
+/// Publish everything still in the outbox, oldest first.
+///
+/// Every send that goes through `send` (text, media, reactions, replies,
+/// edits, deletions) and `send_long_message` is queued when it's encrypted and removed once a
+/// relay accepts it via `publish_event_json`, so a reaction tapped offline
+/// isn't lost. Call on reconnect; `sync_group_messages` also calls it after
+/// catching up.
+///
+/// If the group moved to a newer epoch while offline, the old ciphertext is
+/// undecryptable for members who already dropped that epoch's secrets, so
+/// the stored rumor is re-encrypted under the current epoch first. Stops at
+/// the first publish failure and leaves the rest queued.
+Future<OutboxFlushResult> flushOutbox() =>
+    RustLib.instance.api.crateApiMessageFlushOutbox();
+
+/// Stream every message sent from this process, as soon as it's encrypted.
+///
+/// Relay subscriptions skip our own events, so a second window or a test
+/// harness in the same process would otherwise only see a local send after
+/// a sync. Covers everything sent through `send` and its wrappers, plus
+/// `send_long_message`. Runs until the stream is closed from the Dart side.
+Stream<GroupMessage> listenForLocalSends() =>
+    RustLib.instance.api.crateApiMessageListenForLocalSends();
+
 /// Send an encrypted message with media attachment(s) to a group.
 ///
 /// Same as `send_message` but includes imeta tags for encrypted media references.
@@ -39,28 +116,151 @@ Future<SendMessageResult> sendMessageWithMedia({
   imetaTagsJson: imetaTagsJson,
 );
 
+/// Validate reaction content per NIP-25 / NIP-30.
+///
+/// Accepts `+`, `-`, a single emoji (including ZWJ sequences, skin tones,
+/// flags and keycaps), or a `:shortcode:` paired with a custom emoji URL.
+/// A custom emoji URL is rejected for anything other than a shortcode.
+Future<void> validateReaction({
+  required String content,
+  String? customEmojiUrl,
+}) => RustLib.instance.api.crateApiMessageValidateReaction(
+  content: content,
+  customEmojiUrl: customEmojiUrl,
+);
+
 /// Send an encrypted reaction to a message in a group (NIP-25 over MLS).
 ///
 /// Creates a kind 7 rumor with the emoji as content and an `e` tag referencing
 /// the target message's event ID. The rumor is MLS-encrypted and published
 /// as a kind 445 event, same as regular messages.
 ///
+/// For NIP-30 custom emoji, pass `:shortcode:` as `emoji` and the image URL as
+/// `custom_emoji_url`; an `["emoji", shortcode, url]` tag is added.
+///
 /// Returns the encrypted event JSON and the local GroupMessage for immediate display.
 Future<SendMessageResult> sendReaction({
   required String mlsGroupIdHex,
   required String targetEventIdHex,
   required String emoji,
+  String? customEmojiUrl,
 }) => RustLib.instance.api.crateApiMessageSendReaction(
   mlsGroupIdHex: mlsGroupIdHex,
   targetEventIdHex: targetEventIdHex,
   emoji: emoji,
+  customEmojiUrl: customEmojiUrl,
+);
+
+/// Send a text reply to a message in a group.
+///
+/// Same as `send_message`, plus a NIP-10 `["e", <id>, "", "reply"]` tag
+/// pointing at the message being replied to.
+Future<SendMessageResult> sendReply({
+  required String mlsGroupIdHex,
+  required String replyToEventIdHex,
+  required String content,
+  BigInt? ttlSecs,
+}) => RustLib.instance.api.crateApiMessageSendReply(
+  mlsGroupIdHex: mlsGroupIdHex,
+  replyToEventIdHex: replyToEventIdHex,
+  content: content,
+  ttlSecs: ttlSecs,
+);
+
+/// Edit one of your own messages.
+///
+/// Sends a new kind 1 message carrying `["e", <target>, "", "edit"]` and the
+/// full replacement text. This is a soft-edit convention: the original stays
+/// in every member's MLS history and on relays, and clients that understand
+/// the tag (via `GroupMessage.edited_from`) render the newest edit in its
+/// place. Nothing is deleted. Only messages authored by the current account
+/// and present in local storage can be edited.
+Future<SendMessageResult> editMessage({
+  required String mlsGroupIdHex,
+  required String targetEventIdHex,
+  required String newContent,
+}) => RustLib.instance.api.crateApiMessageEditMessage(
+  mlsGroupIdHex: mlsGroupIdHex,
+  targetEventIdHex: targetEventIdHex,
+  newContent: newContent,
+);
+
+/// Delete one of your own messages.
+///
+/// Sends a NIP-09 kind 5 deletion request with `["e", <target>]` and
+/// `["k", <target kind>]` tags. Like an edit it is a request: the original
+/// stays in every member's MLS history, and clients that honor NIP-09 hide
+/// it. Only messages authored by the current account and present in local
+/// storage can be deleted. Queued in the outbox like any other send.
+Future<SendMessageResult> deleteMessage({
+  required String mlsGroupIdHex,
+  required String targetEventIdHex,
+}) => RustLib.instance.api.crateApiMessageDeleteMessage(
+  mlsGroupIdHex: mlsGroupIdHex,
+  targetEventIdHex: targetEventIdHex,
+);
+
+/// Send any supported message from a single JSON request.
+///
+/// `request_json` is a tagged union on `type`:
+/// - `{"type":"text","mls_group_id_hex":..,"content":..,"ttl_secs"?:..}`
+/// - `{"type":"media","mls_group_id_hex":..,"content":..,"imeta_tags":[[..]]}`
+/// - `{"type":"reaction","mls_group_id_hex":..,"target_event_id_hex":..,"emoji":..,"custom_emoji_url"?:..}`
+/// - `{"type":"reply","mls_group_id_hex":..,"reply_to_event_id_hex":..,"content":..,"ttl_secs"?:..}`
+/// - `{"type":"edit","mls_group_id_hex":..,"target_event_id_hex":..,"content":..}`
+/// - `{"type":"delete","mls_group_id_hex":..,"target_event_id_hex":..}`
+///
+/// A stable entry point for automation; `send_message`, `send_message_with_media`,
+/// `send_reaction`, `send_reply`, `edit_message` and `delete_message` all go
+/// through the same dispatcher.
+Future<SendMessageResult> send({required String requestJson}) =>
+    RustLib.instance.api.crateApiMessageSend(requestJson: requestJson);
+
+/// Get all reactions to a message in a group.
+///
+/// Scans stored kind 7 messages whose target (last `e` tag, per NIP-25) is
+/// `target_event_id_hex`. `:shortcode:` reactions are resolved to their image
+/// URL via the reaction's `emoji` tag (NIP-30). Ordered oldest first.
+Future<List<MessageReaction>> getMessageReactions({
+  required String mlsGroupIdHex,
+  required String targetEventIdHex,
+}) => RustLib.instance.api.crateApiMessageGetMessageReactions(
+  mlsGroupIdHex: mlsGroupIdHex,
+  targetEventIdHex: targetEventIdHex,
+);
+
+/// Send a read receipt for one or more messages in a group (MIP read receipts).
+///
+/// Creates a kind 15 MLS application message with `e` tags referencing
+/// the event IDs of messages that have been read. The receipt is encrypted
+/// via MLS + NIP-44, so relays see only a standard kind 445 event.
+Future<String> sendReadReceipt({
+  required String mlsGroupIdHex,
+  required List<String> messageEventIds,
+}) => RustLib.instance.api.crateApiMessageSendReadReceipt(
+  mlsGroupIdHex: mlsGroupIdHex,
+  messageEventIds: messageEventIds,
+);
+
+/// Aggregate the read receipts stored for a message in a group.
+///
+/// Computed from the kind 15 receipts MDK keeps alongside regular messages.
+/// Receipts from the author themselves are ignored.
+Future<ReceiptSummary> getMessageReceipts({
+  required String mlsGroupIdHex,
+  required String eventIdHex,
+}) => RustLib.instance.api.crateApiMessageGetMessageReceipts(
+  mlsGroupIdHex: mlsGroupIdHex,
+  eventIdHex: eventIdHex,
 );
 
 /// Send a typing indicator to a group.
 ///
 /// Creates a kind 10000 (ephemeral) MLS app message that signals the user is
-/// typing. These are not stored by MDK — recipients surface them as transient
-/// UI state that auto-expires after a few seconds.
+/// typing. MDK stores every application message it decrypts, so receivers
+/// leave these out of history (`get_messages`, group summaries) and surface
+/// them as "typing" notifications from `listen_for_group_messages`, which the
+/// UI expires after a few seconds.
 Future<String> sendTypingIndicator({required String mlsGroupIdHex}) => RustLib
     .instance
     .api
@@ -105,17 +305,58 @@ Future<ProcessMessageResult> processMessage({required String eventJson}) =>
 
 /// Get message history for a group with optional pagination.
 ///
-/// Returns messages ordered by creation time (descending).
+/// Returns messages ordered by creation time (descending). Messages whose
+/// NIP-40 `expiration` tag has passed are dropped unless `include_expired`
+/// is true (default false); purged messages are never returned. `offset`
+/// and `limit` count returned messages, so filtering never shortens a page
+/// that has more history behind it.
 Future<List<GroupMessage>> getMessages({
   required String mlsGroupIdHex,
   int? limit,
   int? offset,
+  bool? includeExpired,
 }) => RustLib.instance.api.crateApiMessageGetMessages(
   mlsGroupIdHex: mlsGroupIdHex,
   limit: limit,
   offset: offset,
+  includeExpired: includeExpired,
 );
 
+/// Search stored messages by content.
+///
+/// Case-insensitive substring match over one group (`mls_group_id_hex`) or,
+/// when None, every group. MDK storage has no search index, so this pages
+/// through the full history; expired, purged and typing messages are
+/// skipped. Results are newest first, capped at `limit` if given.
+Future<List<GroupMessage>> searchMessages({
+  String? mlsGroupIdHex,
+  required String query,
+  int? limit,
+}) => RustLib.instance.api.crateApiMessageSearchMessages(
+  mlsGroupIdHex: mlsGroupIdHex,
+  query: query,
+  limit: limit,
+);
+
+/// Whether a message's NIP-40 `expiration` tag is at or before `now`.
+///
+/// Messages without a (parseable) expiration tag never expire.
+bool isMessageExpired({
+  required List<List<String>> tags,
+  required BigInt now,
+}) =>
+    RustLib.instance.api.crateApiMessageIsMessageExpired(tags: tags, now: now);
+
+/// Purge expired messages from local storage across all groups.
+///
+/// Gives disappearing-message semantics locally even if a relay keeps
+/// serving the event: the plaintext is erased from MDK storage (see
+/// `erase_messages`) and never returned by `get_messages`. Call
+/// periodically from the app (e.g. on resume and on a timer). Returns the
+/// number of newly purged messages.
+Future<int> purgeExpiredMessages() =>
+    RustLib.instance.api.crateApiMessagePurgeExpiredMessages();
+
 /// Get a specific message by its event ID within a group.
 Future<GroupMessage> getMessage({
   required String mlsGroupIdHex,
@@ -139,7 +380,7 @@ Future<String> groupMessageFilter({required String mlsGroupIdHex}) => RustLib
 /// For each group, queries relays for kind 445 events and processes them
 /// through MDK's `process_message`. Returns the count of new messages found.
 /// Call this on app startup before `listen_for_group_messages` to catch
-/// messages sent while the app was offline.
+/// messages sent while the app was offline. Flushes the outbox afterwards.
 Future<int> syncGroupMessages() =>
     RustLib.instance.api.crateApiMessageSyncGroupMessages();
 
@@ -185,6 +426,20 @@ class GroupMessage {
   /// MLS epoch when this message was created.
   final BigInt epoch;
 
+  /// Someone else's message that `p`-tags or names the current user.
+  final bool mentionsMe;
+
+  /// For an edit (see `edit_message`): hex event ID of the message this
+  /// one replaces. The UI shows this content in place of the original.
+  final String? editedFrom;
+
+  /// For a NIP-10 reply: hex event ID of the message replied to.
+  final String? replyToEventIdHex;
+
+  /// Hex pubkey of the replied-to message's author, from local storage
+  /// (or the reply's first `p` tag if the parent isn't stored).
+  final String? replyToAuthorHex;
+
   const GroupMessage({
     required this.eventIdHex,
     required this.authorPubkeyHex,
@@ -195,6 +450,10 @@ class GroupMessage {
     required this.tags,
     required this.wrapperEventIdHex,
     required this.epoch,
+    required this.mentionsMe,
+    this.editedFrom,
+    this.replyToEventIdHex,
+    this.replyToAuthorHex,
   });
 
   @override
@@ -207,7 +466,11 @@ class GroupMessage {
       kind.hashCode ^
       tags.hashCode ^
       wrapperEventIdHex.hashCode ^
-      epoch.hashCode;
+      epoch.hashCode ^
+      mentionsMe.hashCode ^
+      editedFrom.hashCode ^
+      replyToEventIdHex.hashCode ^
+      replyToAuthorHex.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -222,13 +485,18 @@ class GroupMessage {
           kind == other.kind &&
           tags == other.tags &&
           wrapperEventIdHex == other.wrapperEventIdHex &&
-          epoch == other.epoch;
+          epoch == other.epoch &&
+          mentionsMe == other.mentionsMe &&
+          editedFrom == other.editedFrom &&
+          replyToEventIdHex == other.replyToEventIdHex &&
+          replyToAuthorHex == other.replyToAuthorHex;
 }
 
 /// A notification from the group message listener.
 /// Can be a new message or a group state change (commit/proposal).
 class GroupNotification {
-  /// "application_message", "commit", "proposal", or other MLS event type.
+  /// "application_message", "commit", "membership_change", "proposal",
+  /// "typing", or other MLS event type.
   final String notificationType;
 
   /// The decrypted message (only set for "application_message").
@@ -237,15 +505,32 @@ class GroupNotification {
   /// Hex-encoded MLS group ID this notification belongs to.
   final String mlsGroupIdHex;
 
+  /// Hex pubkeys that joined (only set for "membership_change").
+  final List<String> addedPubkeys;
+
+  /// Hex pubkeys that left or were removed (only set for "membership_change").
+  final List<String> removedPubkeys;
+
+  /// Who is typing (only set for "typing"; expire it client-side).
+  final String? senderPubkeyHex;
+
   const GroupNotification({
     required this.notificationType,
     this.message,
     required this.mlsGroupIdHex,
+    required this.addedPubkeys,
+    required this.removedPubkeys,
+    this.senderPubkeyHex,
   });
 
   @override
   int get hashCode =>
-      notificationType.hashCode ^ message.hashCode ^ mlsGroupIdHex.hashCode;
+      notificationType.hashCode ^
+      message.hashCode ^
+      mlsGroupIdHex.hashCode ^
+      addedPubkeys.hashCode ^
+      removedPubkeys.hashCode ^
+      senderPubkeyHex.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -254,7 +539,119 @@ class GroupNotification {
           runtimeType == other.runtimeType &&
           notificationType == other.notificationType &&
           message == other.message &&
-          mlsGroupIdHex == other.mlsGroupIdHex;
+          mlsGroupIdHex == other.mlsGroupIdHex &&
+          addedPubkeys == other.addedPubkeys &&
+          removedPubkeys == other.removedPubkeys &&
+          senderPubkeyHex == other.senderPubkeyHex;
+}
+
+/// A reaction to a message, with NIP-30 custom emoji resolved for rendering.
+class MessageReaction {
+  /// Hex-encoded event ID of the kind 7 reaction rumor.
+  final String eventIdHex;
+
+  /// Hex-encoded public key of the member who reacted.
+  final String reactorPubkeyHex;
+
+  /// Reaction content: an emoji, `+`, `-`, or a `:shortcode:`.
+  final String content;
+
+  /// Image URL for a `:shortcode:` reaction (from its `emoji` tag), if any.
+  final String? customEmojiUrl;
+
+  /// Unix timestamp of the reaction.
+  final BigInt createdAt;
+
+  const MessageReaction({
+    required this.eventIdHex,
+    required this.reactorPubkeyHex,
+    required this.content,
+    this.customEmojiUrl,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      eventIdHex.hashCode ^
+      reactorPubkeyHex.hashCode ^
+      content.hashCode ^
+      customEmojiUrl.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageReaction &&
+          runtimeType == other.runtimeType &&
+          eventIdHex == other.eventIdHex &&
+          reactorPubkeyHex == other.reactorPubkeyHex &&
+          content == other.content &&
+          customEmojiUrl == other.customEmojiUrl &&
+          createdAt == other.createdAt;
+}
+
+/// A member who has read a message.
+class MessageReader {
+  /// Hex-encoded public key of the reader.
+  final String readerPubkeyHex;
+
+  /// Unix timestamp of their first receipt covering the message.
+  final BigInt readAt;
+
+  const MessageReader({required this.readerPubkeyHex, required this.readAt});
+
+  @override
+  int get hashCode => readerPubkeyHex.hashCode ^ readAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageReader &&
+          runtimeType == other.runtimeType &&
+          readerPubkeyHex == other.readerPubkeyHex &&
+          readAt == other.readAt;
+}
+
+/// Outcome of `flush_outbox`.
+class OutboxFlushResult {
+  /// Events accepted by a relay and removed from the outbox.
+  final int published;
+
+  /// Of those, how many had to be re-encrypted for a newer epoch.
+  final int reencrypted;
+
+  /// Entries dropped because their group or message is gone.
+  final int dropped;
+
+  /// Entries still queued (publishing failed, likely still offline).
+  final int remaining;
+
+  const OutboxFlushResult({
+    required this.published,
+    required this.reencrypted,
+    required this.dropped,
+    required this.remaining,
+  });
+
+  static Future<OutboxFlushResult> default_() =>
+      RustLib.instance.api.crateApiMessageOutboxFlushResultDefault();
+
+  @override
+  int get hashCode =>
+      published.hashCode ^
+      reencrypted.hashCode ^
+      dropped.hashCode ^
+      remaining.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutboxFlushResult &&
+          runtimeType == other.runtimeType &&
+          published == other.published &&
+          reencrypted == other.reencrypted &&
+          dropped == other.dropped &&
+          remaining == other.remaining;
 }
 
 /// Result of processing an incoming kind 445 event.
@@ -296,6 +693,45 @@ class ProcessMessageResult {
           evolutionEventJson == other.evolutionEventJson;
 }
 
+/// Who has read a message, for "seen by N" (groups) or a read tick (DMs).
+class ReceiptSummary {
+  /// Hex-encoded event ID of the message.
+  final String eventIdHex;
+
+  /// Members other than the author who read it, earliest first.
+  final List<MessageReader> readers;
+
+  /// Number of current members other than the author.
+  final int recipientCount;
+
+  /// Whether every current member other than the author has read it.
+  final bool readByAll;
+
+  const ReceiptSummary({
+    required this.eventIdHex,
+    required this.readers,
+    required this.recipientCount,
+    required this.readByAll,
+  });
+
+  @override
+  int get hashCode =>
+      eventIdHex.hashCode ^
+      readers.hashCode ^
+      recipientCount.hashCode ^
+      readByAll.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiptSummary &&
+          runtimeType == other.runtimeType &&
+          eventIdHex == other.eventIdHex &&
+          readers == other.readers &&
+          recipientCount == other.recipientCount &&
+          readByAll == other.readByAll;
+}
+
 /// Result of sending a message: the encrypted event JSON and the local message.
 class SendMessageResult {
   /// JSON-serialized signed Event (kind 445) for relay publication.
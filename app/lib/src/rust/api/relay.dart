@@ -7,9 +7,26 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
-
-/// Add a relay and connect to it.
+// These functions are ignored because they are not marked as `pub`: `apply_relay_flags`, `flags_for`, `group_relays_for_event`, `publish_event`, `send_event_to_relays`, `urls`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RelayRoles`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `ensure_group_relays`, `parse_relay_roles`
+
+/// Configure read/write relay roles (NIP-65 outbox model).
+///
+/// `roles_json` is `{"read": [urls], "write": [urls]}`. Listed relays are
+/// added if missing and their pool flags updated: sends, welcomes and key
+/// packages then go only to write relays, and subscriptions and fetches only
+/// to read relays. Relays dropped from the roles go back to read+write.
+/// Pass `{}` to clear. Roles are kept in memory for the session.
+Future<void> setRelayRoles({required String rolesJson}) =>
+    RustLib.instance.api.crateApiRelaySetRelayRoles(rolesJson: rolesJson);
+
+/// Current relay roles as JSON (`{"read": [...], "write": [...]}`).
+Future<String> getRelayRoles() =>
+    RustLib.instance.api.crateApiRelayGetRelayRoles();
+
+/// Add a relay and connect to it. Honors the configured relay roles.
 Future<void> addRelay({required String url}) =>
     RustLib.instance.api.crateApiRelayAddRelay(url: url);
 
@@ -31,6 +48,11 @@ Future<List<RelayInfo>> listRelays() =>
 
 /// Publish a signed event to connected relays.
 /// Takes a JSON-serialized Nostr event string.
+///
+/// Group messages (kind 445) go to the group's relays; everything else goes
+/// to the write relays.
+///
+/// A queued send is removed from the outbox once a relay accepts it.
 Future<String> publishEventJson({required String eventJson}) =>
     RustLib.instance.api.crateApiRelayPublishEventJson(eventJson: eventJson);
 
@@ -60,10 +82,22 @@ class RelayInfo {
   final String url;
   final bool connected;
 
-  const RelayInfo({required this.url, required this.connected});
+  /// Used for subscriptions and fetches.
+  final bool read;
+
+  /// Used for publishing.
+  final bool write;
+
+  const RelayInfo({
+    required this.url,
+    required this.connected,
+    required this.read,
+    required this.write,
+  });
 
   @override
-  int get hashCode => url.hashCode ^ connected.hashCode;
+  int get hashCode =>
+      url.hashCode ^ connected.hashCode ^ read.hashCode ^ write.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -71,5 +105,7 @@ class RelayInfo {
       other is RelayInfo &&
           runtimeType == other.runtimeType &&
           url == other.url &&
-          connected == other.connected;
+          connected == other.connected &&
+          read == other.read &&
+          write == other.write;
 }
@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BurrowSigner`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `kind`, `local`, `nip44_encrypt`, `nip46_session`, `nip46`, `nostr_signer`, `public_key`, `secret_keys`, `sign_unsigned`, `sign`
+
+/// Which kind of signer the current account uses.
+enum SignerKind {
+  /// The secret key is held in memory by this app.
+  local,
+
+  /// Signing is proxied to a NIP-46 bunker; the secret key never leaves it.
+  nip46,
+}
@@ -7,9 +7,10 @@ import '../frb_generated.dart';
 import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_data_dir`, `global`
-// These functions are ignored because they have generic arguments: `with_state_mut`, `with_state`
+// These functions are ignored because they are not marked as `pub`: `get_data_dir`, `global`, `init_test_state`, `mdk_db_key_id`, `open_mdk_storage`, `relay_permit`, `state_entry_count`
+// These functions are ignored because they have generic arguments: `with_state_mut`, `with_state_read`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BurrowState`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `init_state_with_signer`
 
 /// Initialize the platform-specific keyring store (once).
 Future<void> initializeKeyringStore() =>
@@ -19,6 +20,17 @@ Future<void> initializeKeyringStore() =>
 Future<void> setDataDir({required String path}) =>
     RustLib.instance.api.crateApiStateSetDataDir(path: path);
 
+/// Cap how many relay requests the batched fetch/publish paths (profile and
+/// key package batches, media batch uploads, outbox flushes, group sync)
+/// may have in flight at once. Lower it on constrained devices or strict
+/// relays to avoid self-inflicted rate limiting. Clamped to at least 1.
+void setMaxConcurrentRelayOps({required int n}) =>
+    RustLib.instance.api.crateApiStateSetMaxConcurrentRelayOps(n: n);
+
+/// Current relay concurrency cap.
+int getMaxConcurrentRelayOps() =>
+    RustLib.instance.api.crateApiStateGetMaxConcurrentRelayOps();
+
 /// Initialize the global state with a keypair and persistent MLS storage.
 ///
 /// If the existing MLS database can't be opened (e.g., encryption key was lost
@@ -84,6 +84,19 @@ Future<String> getTranscriptText() =>
 Future<List<TranscriptSegment>> searchTranscript({required String query}) =>
     RustLib.instance.api.crateApiTranscriptionSearchTranscript(query: query);
 
+/// Keep only segments with `confidence >= min_confidence`.
+///
+/// Takes and returns a JSON array of segments. Useful before sharing or
+/// summarizing a transcript recorded over a bad connection, where
+/// low-confidence segments are mostly ASR noise.
+Future<String> filterTranscriptByConfidence({
+  required String segmentsJson,
+  required double minConfidence,
+}) => RustLib.instance.api.crateApiTranscriptionFilterTranscriptByConfidence(
+  segmentsJson: segmentsJson,
+  minConfidence: minConfidence,
+);
+
 /// Transcription segment with timing and speaker info.
 class TranscriptSegment {
   /// Unique segment ID.
@@ -5,20 +5,25 @@
 
 import 'api/account.dart';
 import 'api/app_state.dart';
+import 'api/backup.dart';
 import 'api/call_quality.dart';
 import 'api/call_session.dart';
 import 'api/call_signaling.dart';
 import 'api/call_webrtc.dart';
 import 'api/contacts.dart';
+import 'api/diagnostics.dart';
+import 'api/ephemeral.dart';
 import 'api/error.dart';
 import 'api/group.dart';
 import 'api/identity.dart';
 import 'api/invite.dart';
 import 'api/keypackage.dart';
+import 'api/link_preview.dart';
 import 'api/media.dart';
 import 'api/meeting_intelligence.dart';
 import 'api/message.dart';
 import 'api/relay.dart';
+import 'api/signer.dart';
 import 'api/simple.dart';
 import 'api/state.dart';
 import 'api/transcription.dart';
@@ -84,7 +89,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 662584846;
+  int get rustContentHash => 1063729984;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -95,12 +100,22 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  String crateApiDiagnosticsDiagnosticsReportToJson({
+    required DiagnosticsReport that,
+  });
+
   Future<String> crateApiCallSignalingAcceptCall({
     required String sdpAnswer,
     required String callId,
     required String callerPubkeyHex,
   });
 
+  Future<List<String>> crateApiCallSignalingAcceptCallFragments({
+    required String sdpAnswer,
+    required String callId,
+    required String callerPubkeyHex,
+  });
+
   Future<void> crateApiInviteAcceptWelcome({required String welcomeEventIdHex});
 
   Future<UpdateGroupResult> crateApiInviteAddMembers({
@@ -116,6 +131,14 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ProfileData> crateApiIdentityBootstrapIdentity();
 
+  Future<List<String>> crateApiMediaBuildChunkedImetaTag({
+    required String url,
+    required String mimeType,
+    required String filename,
+    required String originalHashHex,
+    required String nonceHex,
+  });
+
   Future<String> crateApiCallSignalingBuildGroupCallSignaling({
     required int kindNum,
     required String content,
@@ -131,12 +154,18 @@ abstract class RustLibApi extends BaseApi {
     required String nonceHex,
     String? dimensions,
     String? blurhash,
+    ThumbnailReference? thumb,
   });
 
   Future<String> crateApiMeetingIntelligenceBuildMeetingNotesPrompt({
     required String transcriptText,
   });
 
+  Future<String>
+  crateApiMeetingIntelligenceBuildMeetingNotesPromptFromSegments({
+    required String segmentsJson,
+  });
+
   Future<QualityScore> crateApiCallQualityCalculateQualityScore({
     required double rttMs,
     required double jitterMs,
@@ -145,14 +174,41 @@ abstract class RustLibApi extends BaseApi {
     required bool isVideo,
   });
 
+  Future<int> crateApiGroupCleanupInactiveGroups();
+
+  Future<void> crateApiMediaClearMediaCache();
+
+  Future<void> crateApiGroupClearPendingCommit({required String mlsGroupIdHex});
+
+  Future<bool> crateApiMeetingIntelligenceClearTranscriptBuffer({
+    required String meetingId,
+  });
+
+  Future<DiagnosticsReport> crateApiDiagnosticsCollectDiagnostics();
+
   Future<void> crateApiMeetingIntelligenceConfigureAiBackend({
     required String backendJson,
   });
 
+  Future<void> crateApiMeetingIntelligenceConfigureConfidence({
+    required double minConfidence,
+    required double reviewConfidence,
+  });
+
+  Future<void> crateApiMeetingIntelligenceConfigureRedaction({
+    required String redactPatternsJson,
+  });
+
   Future<void> crateApiRelayConnectRelays();
 
   Future<AccountInfo> crateApiAccountCreateAccount();
 
+  Future<CreateGroupResult> crateApiGroupCreateDm({
+    required String peerPubkeyHex,
+    required String peerKeyPackageEventJson,
+    required List<String> relayUrls,
+  });
+
   Future<CreateGroupResult> crateApiGroupCreateGroup({
     required String name,
     required String description,
@@ -161,6 +217,11 @@ abstract class RustLibApi extends BaseApi {
     required List<String> relayUrls,
   });
 
+  Future<CreateOrGetDmResult> crateApiGroupCreateOrGetDm({
+    required String peerPubkeyHex,
+    required List<String> relayUrls,
+  });
+
   Future<PeerEntry> crateApiCallWebrtcCreatePeerEntry({
     required String callId,
     required String participantPubkeyHex,
@@ -195,8 +256,18 @@ abstract class RustLibApi extends BaseApi {
     String? dimensions,
   });
 
+  Future<BigInt> crateApiMediaDecryptFileChunked({
+    required String mlsGroupIdHex,
+    required String inputPath,
+    required String outputPath,
+    required String filename,
+    required String originalHashHex,
+  });
+
   Future<String> crateApiGroupDefaultBlossomServer();
 
+  Future<List<String>> crateApiMeetingIntelligenceDefaultRedactPatterns();
+
   List<String> crateApiRelayDefaultRelayUrls();
 
   Future<void> crateApiAppStateDeleteGroupState({
@@ -204,10 +275,25 @@ abstract class RustLibApi extends BaseApi {
     required String key,
   });
 
+  Future<bool> crateApiMeetingIntelligenceDeleteMeetingNotes({
+    required String meetingId,
+  });
+
+  Future<SendMessageResult> crateApiMessageDeleteMessage({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+  });
+
   Future<void> crateApiAccountDeleteSecretKeyFromKeyring();
 
+  Future<UpdateGroupResult> crateApiGroupDemoteAdmin({
+    required String mlsGroupIdHex,
+    required String pubkeyHex,
+  });
+
   Future<String> crateApiCallWebrtcDeriveFrameEncryptionKey({
     required String exporterSecretHex,
+    required BigInt epoch,
     required String callId,
   });
 
@@ -234,6 +320,13 @@ abstract class RustLibApi extends BaseApi {
     required String nonceHex,
     required String schemeVersion,
     String? dimensions,
+    required List<String> mirrorServers,
+  });
+
+  Future<SendMessageResult> crateApiMessageEditMessage({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+    required String newContent,
   });
 
   Future<EncryptedFileResult> crateApiMediaEncryptFile({
@@ -241,6 +334,30 @@ abstract class RustLibApi extends BaseApi {
     required List<int> fileData,
     required String mimeType,
     required String filename,
+    required bool strict,
+  });
+
+  Future<ChunkedEncryptResult> crateApiMediaEncryptFileChunked({
+    required String mlsGroupIdHex,
+    required String inputPath,
+    required String outputPath,
+    required String mimeType,
+    required String filename,
+    required bool strict,
+  });
+
+  Future<EncryptedFileOnDisk> crateApiMediaEncryptFileFromPath({
+    required String mlsGroupIdHex,
+    required String filePath,
+    required String mimeType,
+    required String filename,
+    required bool strict,
+  });
+
+  Future<EncryptedFileResult> crateApiMediaEncryptThumbnail({
+    required String mlsGroupIdHex,
+    required List<int> fileData,
+    required int maxDimension,
   });
 
   Future<String> crateApiCallSignalingEndCall({
@@ -248,6 +365,10 @@ abstract class RustLibApi extends BaseApi {
     required String remotePubkeyHex,
   });
 
+  Future<EphemeralKeyStrategy> crateApiEphemeralEphemeralKeyStrategyDefault();
+
+  Future<String> crateApiContactsExportContactsList({required bool publish});
+
   Future<String> crateApiMeetingIntelligenceExportMeetingMarkdown({
     required String meetingId,
   });
@@ -258,6 +379,10 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiIdentityExportPubkeyHex();
 
+  Future<Uint8List> crateApiBackupExportState({required String passphrase});
+
+  Future<List<String>> crateApiLinkPreviewExtractUrls({required String text});
+
   Future<List<TranscriptSegment>> crateApiTranscriptionFeedAudio({
     required List<double> audioData,
     required String speakerTrackId,
@@ -265,6 +390,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiInviteFetchKeyPackage({required String pubkeyHex});
 
+  Future<OgMetadata> crateApiLinkPreviewFetchOgMetadata({required String url});
+
   Future<ProfileData> crateApiIdentityFetchProfile({
     required String pubkeyHex,
     required bool blockingSync,
@@ -274,12 +401,35 @@ abstract class RustLibApi extends BaseApi {
     required String pubkeyHex,
   });
 
+  Future<String> crateApiTranscriptionFilterTranscriptByConfidence({
+    required String segmentsJson,
+    required double minConfidence,
+  });
+
+  Future<String> crateApiMeetingIntelligenceFinalizeLiveMeeting({
+    required String meetingId,
+    required String participantsJson,
+    required PlatformInt64 startTimeMs,
+    required PlatformInt64 endTimeMs,
+  });
+
+  Future<GroupInfo?> crateApiGroupFindDmWith({required String peerPubkeyHex});
+
+  Future<OutboxFlushResult> crateApiMessageFlushOutbox();
+
   Future<void> crateApiContactsFollowContact({required String pubkeyHex});
 
+  Future<void> crateApiGroupForgetGroup({required String mlsGroupIdHex});
+
   Future<KeyPackageData> crateApiKeypackageGenerateKeyPackage({
     required List<String> relayUrls,
   });
 
+  Future<String> crateApiMeetingIntelligenceGenerateLiveMeetingNotes({
+    required String meetingId,
+    required String participantsJson,
+  });
+
   Future<String> crateApiMeetingIntelligenceGenerateMeetingNotes({
     required String meetingId,
     required String segmentsJson,
@@ -290,6 +440,7 @@ abstract class RustLibApi extends BaseApi {
 
   Future<WebRtcConfig> crateApiCallWebrtcGenerateWebrtcConfig({
     required String callId,
+    required bool forceRelay,
   });
 
   Future<List<CallSession>> crateApiCallSessionGetActiveCalls();
@@ -314,14 +465,34 @@ abstract class RustLibApi extends BaseApi {
 
   Future<CodecPreferences> crateApiCallQualityGetCodecPreferences();
 
+  BigInt crateApiContactsGetContactsStaleness();
+
   Future<AccountInfo> crateApiAccountGetCurrentAccount();
 
+  EphemeralKeyStrategy crateApiEphemeralGetEphemeralKeyStrategy();
+
   Future<GroupInfo> crateApiGroupGetGroup({required String mlsGroupIdHex});
 
+  Future<List<GroupEpochEvent>> crateApiGroupGetGroupEpochEvents({
+    required String mlsGroupIdHex,
+  });
+
+  Future<List<EpochInfo>> crateApiGroupGetGroupEpochHistory({
+    required String mlsGroupIdHex,
+  });
+
   Future<List<MemberInfo>> crateApiGroupGetGroupMembers({
     required String mlsGroupIdHex,
   });
 
+  Future<BigInt?> crateApiGroupGetGroupMessageTtl({
+    required String mlsGroupIdHex,
+  });
+
+  Future<String> crateApiGroupGetGroupRawExtension({
+    required String mlsGroupIdHex,
+  });
+
   Future<List<String>> crateApiGroupGetGroupRelays({
     required String mlsGroupIdHex,
   });
@@ -335,12 +506,22 @@ abstract class RustLibApi extends BaseApi {
     required String mlsGroupIdHex,
   });
 
+  BigInt crateApiInviteGetKeyPackageMaxAge();
+
   Future<PlatformInt64?> crateApiContactsGetLastContactsSync();
 
   Future<PlatformInt64?> crateApiAppStateGetLastReadTimestamp({
     required String groupIdHex,
   });
 
+  int crateApiStateGetMaxConcurrentRelayOps();
+
+  int crateApiMessageGetMaxMessageBytes();
+
+  BigInt crateApiMediaGetMediaCacheLimit();
+
+  Future<BigInt> crateApiMediaGetMediaCacheUsage();
+
   Future<String> crateApiMeetingIntelligenceGetMeetingArchive();
 
   Future<String> crateApiMeetingIntelligenceGetMeetingNotes({
@@ -352,16 +533,39 @@ abstract class RustLibApi extends BaseApi {
     required String eventIdHex,
   });
 
+  Future<List<MessageReaction>> crateApiMessageGetMessageReactions({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+  });
+
+  Future<ReceiptSummary> crateApiMessageGetMessageReceipts({
+    required String mlsGroupIdHex,
+    required String eventIdHex,
+  });
+
   Future<List<GroupMessage>> crateApiMessageGetMessages({
     required String mlsGroupIdHex,
     int? limit,
     int? offset,
+    bool? includeExpired,
+  });
+
+  bool crateApiContactsGetNip05Verification();
+
+  Future<String> crateApiMeetingIntelligenceGetOpenActionItems({
+    String? assigneePubkey,
   });
 
+  Future<int> crateApiAppStateGetOutboxCount();
+
   Future<PeerStats?> crateApiCallWebrtcGetPeerStats({
     required String participantPubkeyHex,
   });
 
+  Future<String> crateApiMeetingIntelligenceGetRedactPatterns();
+
+  Future<String> crateApiRelayGetRelayRoles();
+
   Future<CallSession?> crateApiCallSessionGetSession({required String callId});
 
   Future<SfuConfig> crateApiCallWebrtcGetSfuConfig({
@@ -369,6 +573,8 @@ abstract class RustLibApi extends BaseApi {
     required String localPubkeyHex,
   });
 
+  Future<SignerKind> crateApiAccountGetSignerKind();
+
   Future<SimulcastConfig> crateApiCallQualityGetSimulcastConfig();
 
   Future<List<TranscriptSegment>> crateApiTranscriptionGetTranscriptSegments();
@@ -398,6 +604,13 @@ abstract class RustLibApi extends BaseApi {
     required List<String> groupIds,
   });
 
+  Future<int> crateApiContactsImportContactsList({required String eventJson});
+
+  Future<AccountInfo> crateApiBackupImportState({
+    required List<int> bundle,
+    required String passphrase,
+  });
+
   Future<void> crateApiSimpleInitApp();
 
   Future<void> crateApiStateInitState({required Keys keys});
@@ -418,18 +631,37 @@ abstract class RustLibApi extends BaseApi {
     required String recipientPubkeyHex,
   });
 
+  Future<List<String>> crateApiCallSignalingInitiateCallFragments({
+    required String sdpOffer,
+    required String callId,
+    required String callType,
+    required String recipientPubkeyHex,
+  });
+
+  Future<InviteResult> crateApiInviteInviteMembersFull({
+    required String mlsGroupIdHex,
+    required List<String> keyPackageEventsJson,
+  });
+
   Future<bool> crateApiAppStateIsGroupArchived({required String groupIdHex});
 
   Future<bool> crateApiStateIsInitialized();
 
   Future<bool> crateApiAccountIsLoggedIn();
 
+  bool crateApiMessageIsMessageExpired({
+    required List<List<String>> tags,
+    required BigInt now,
+  });
+
   Future<UpdateGroupResult> crateApiGroupLeaveGroup({
     required String mlsGroupIdHex,
   });
 
   Future<List<GroupInfo>> crateApiGroupListGroups();
 
+  Future<List<GroupInfo>> crateApiGroupListInactiveGroups();
+
   Future<List<WelcomeInfo>> crateApiInviteListPendingWelcomes();
 
   Future<List<RelayInfo>> crateApiRelayListRelays();
@@ -438,10 +670,17 @@ abstract class RustLibApi extends BaseApi {
 
   Stream<GroupNotification> crateApiMessageListenForGroupMessages();
 
+  Stream<GroupMessage> crateApiMessageListenForLocalSends();
+
   Future<AccountInfo> crateApiAccountLoadAccountFromKeyring();
 
   Future<AccountInfo> crateApiAccountLogin({required String secretKey});
 
+  Future<AccountInfo> crateApiAccountLoginWithBunker({
+    required String bunkerUri,
+    String? appSecretKey,
+  });
+
   Future<void> crateApiAccountLogout();
 
   Future<void> crateApiAppStateMarkGroupRead({
@@ -452,6 +691,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiGroupMergePendingCommit({required String mlsGroupIdHex});
 
+  Future<OutboxFlushResult> crateApiMessageOutboxFlushResultDefault();
+
   Future<MediaReferenceInfo> crateApiMediaParseImetaTag({
     required List<String> tagValues,
   });
@@ -477,6 +718,11 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ProfileData> crateApiIdentityProfileDataDefault();
 
+  Future<UpdateGroupResult> crateApiGroupPromoteAdmin({
+    required String mlsGroupIdHex,
+    required String pubkeyHex,
+  });
+
   Future<String> crateApiRelayPublishEventJson({required String eventJson});
 
   Future<String> crateApiRelayPublishEventJsonToRelay({
@@ -484,6 +730,11 @@ abstract class RustLibApi extends BaseApi {
     required String relayUrl,
   });
 
+  Future<String> crateApiGroupPublishEvolutionEvent({
+    required String evolutionEventJson,
+    required List<String> relayUrls,
+  });
+
   Future<String> crateApiKeypackagePublishKeyPackage({
     required List<String> relayUrls,
   });
@@ -492,12 +743,32 @@ abstract class RustLibApi extends BaseApi {
     required List<String> relayUrls,
   });
 
+  Future<int> crateApiMessagePurgeExpiredMessages();
+
+  Future<int> crateApiMeetingIntelligencePushTranscriptSegment({
+    required String meetingId,
+    required String segmentJson,
+  });
+
+  Future<int> crateApiCallWebrtcReapStalePeers({required BigInt maxAgeSecs});
+
+  String crateApiMessageReassembleMessage({required List<GroupMessage> parts});
+
+  bool crateApiCallWebrtcRecommendForceRelay({required int previousFailures});
+
+  Future<JitterBufferConfig> crateApiCallQualityRecommendJitterBuffer({
+    required double measuredJitterMs,
+    required double rttMs,
+  });
+
   Future<QualityRecommendation> crateApiCallQualityRecommendQualityPreset({
     required double qualityScore,
     required double estimatedBandwidthKbps,
     required int participantCount,
   });
 
+  Future<ReconcileReport> crateApiGroupReconcileGroups();
+
   Future<void> crateApiTranscriptionRegisterSpeaker({
     required String trackId,
     required String pubkeyHex,
@@ -510,6 +781,11 @@ abstract class RustLibApi extends BaseApi {
     String? reason,
   });
 
+  Future<List<String>> crateApiGroupRelaysForEvolution({
+    required String mlsGroupIdHex,
+    required List<String> proposedRelayUrls,
+  });
+
   Future<void> crateApiCallWebrtcRemoveCallPeers({required String callId});
 
   Future<UpdateGroupResult> crateApiGroupRemoveGroupImage({
@@ -533,10 +809,12 @@ abstract class RustLibApi extends BaseApi {
     double? incomingBitrateKbps,
   });
 
+  void crateApiCallSignalingResetCallSignalingState({required String callId});
+
   Future<void> crateApiTranscriptionResumeTranscription();
 
   Future<String> crateApiCallWebrtcRotateFrameKey({
-    required String currentKeyHex,
+    required String exporterSecretHex,
     required BigInt newEpoch,
     required String callId,
   });
@@ -545,14 +823,27 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiAccountSaveSecretKeyToKeyring();
 
+  Future<List<ContactInfo>> crateApiContactsSearchContacts({
+    required String query,
+    int? limit,
+  });
+
   Future<String> crateApiMeetingIntelligenceSearchMeetings({
     required String query,
   });
 
+  Future<List<GroupMessage>> crateApiMessageSearchMessages({
+    String? mlsGroupIdHex,
+    required String query,
+    int? limit,
+  });
+
   Future<List<TranscriptSegment>> crateApiTranscriptionSearchTranscript({
     required String query,
   });
 
+  Future<SendMessageResult> crateApiMessageSend({required String requestJson});
+
   Future<String> crateApiCallSignalingSendCallStateUpdate({
     required String callId,
     required String remotePubkeyHex,
@@ -568,9 +859,15 @@ abstract class RustLibApi extends BaseApi {
     required String remotePubkeyHex,
   });
 
+  Future<List<SendMessageResult>> crateApiMessageSendLongMessage({
+    required String mlsGroupIdHex,
+    required String content,
+  });
+
   Future<SendMessageResult> crateApiMessageSendMessage({
     required String mlsGroupIdHex,
     required String content,
+    BigInt? ttlSecs,
   });
 
   Future<SendMessageResult> crateApiMessageSendMessageWithMedia({
@@ -595,34 +892,89 @@ abstract class RustLibApi extends BaseApi {
     required String mlsGroupIdHex,
     required String targetEventIdHex,
     required String emoji,
+    String? customEmojiUrl,
+  });
+
+  Future<String> crateApiMessageSendReadReceipt({
+    required String mlsGroupIdHex,
+    required List<String> messageEventIds,
+  });
+
+  Future<String> crateApiCallSignalingSendRenegotiationAnswer({
+    required String callId,
+    required String sdp,
+    required String peerPubkeyHex,
+  });
+
+  Future<String> crateApiCallSignalingSendRenegotiationOffer({
+    required String callId,
+    required String sdp,
+    required String peerPubkeyHex,
+  });
+
+  Future<SendMessageResult> crateApiMessageSendReply({
+    required String mlsGroupIdHex,
+    required String replyToEventIdHex,
+    required String content,
+    BigInt? ttlSecs,
   });
 
   Future<String> crateApiMessageSendTypingIndicator({
     required String mlsGroupIdHex,
   });
 
+  void crateApiContactsSetContactsStaleness({required BigInt secs});
+
   Future<void> crateApiStateSetDataDir({required String path});
 
+  void crateApiEphemeralSetEphemeralKeyStrategy({
+    required EphemeralKeyStrategy strategy,
+  });
+
+  Future<void> crateApiGroupSetGroupMessageTtl({
+    required String mlsGroupIdHex,
+    BigInt? ttlSecs,
+  });
+
   Future<void> crateApiAppStateSetGroupState({
     required String groupIdHex,
     required String key,
     required String value,
   });
 
+  void crateApiInviteSetKeyPackageMaxAge({required BigInt secs});
+
+  void crateApiStateSetMaxConcurrentRelayOps({required int n});
+
+  void crateApiMessageSetMaxMessageBytes({required int maxBytes});
+
+  void crateApiMediaSetMediaCacheLimit({required BigInt bytes});
+
   Future<CallSession> crateApiCallSessionSetMuted({
     required String callId,
     required bool muted,
   });
 
+  void crateApiContactsSetNip05Verification({required bool enabled});
+
   Future<void> crateApiIdentitySetProfile({required ProfileData profile});
 
+  Future<void> crateApiRelaySetRelayRoles({required String rolesJson});
+
   Future<CallSession> crateApiCallSessionSetVideoEnabled({
     required String callId,
     required bool enabled,
   });
 
+  bool crateApiMediaShouldUseChunkedEncryption({required BigInt fileSize});
+
   Future<bool> crateApiCallWebrtcShouldUseSfu({required int participantCount});
 
+  List<String> crateApiMessageSplitMessageContent({
+    required String content,
+    required int maxBytes,
+  });
+
   Future<void> crateApiTranscriptionStartTranscription({
     required String callId,
   });
@@ -631,7 +983,9 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiCallSignalingSubscribeCallEvents();
 
-  Future<List<ContactInfo>> crateApiContactsSyncContacts();
+  Future<List<ContactInfo>> crateApiContactsSyncContacts({
+    required bool forceRefresh,
+  });
 
   Future<int> crateApiMessageSyncGroupMessages();
 
@@ -689,12 +1043,23 @@ abstract class RustLibApi extends BaseApi {
     required String blossomServerUrl,
   });
 
+  Future<List<BatchUploadItem>> crateApiMediaUploadMediaBatch({
+    required String mlsGroupIdHex,
+    required List<FileInput> files,
+    required String blossomServerUrl,
+  });
+
   Future<String> crateApiIdentityUploadProfilePhoto({
     required List<int> fileData,
     required String mimeType,
     required String blossomServerUrl,
   });
 
+  Future<void> crateApiMessageValidateReaction({
+    required String content,
+    String? customEmojiUrl,
+  });
+
   Future<bool> crateApiRelayVerifyEventPublished({required String eventIdHex});
 
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Keys;
@@ -712,6 +1077,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  String crateApiDiagnosticsDiagnosticsReportToJson({
+    required DiagnosticsReport that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_diagnostics_report(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDiagnosticsDiagnosticsReportToJsonConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDiagnosticsDiagnosticsReportToJsonConstMeta =>
+      const TaskConstMeta(
+        debugName: "DiagnosticsReport_to_json",
+        argNames: ["that"],
+      );
+
   @override
   Future<String> crateApiCallSignalingAcceptCall({
     required String sdpAnswer,
@@ -728,7 +1121,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 1,
+            funcId: 2,
             port: port_,
           );
         },
@@ -750,36 +1143,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiInviteAcceptWelcome({
-    required String welcomeEventIdHex,
+  Future<List<String>> crateApiCallSignalingAcceptCallFragments({
+    required String sdpAnswer,
+    required String callId,
+    required String callerPubkeyHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(welcomeEventIdHex, serializer);
+          sse_encode_String(sdpAnswer, serializer);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(callerPubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 3,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteAcceptWelcomeConstMeta,
-        argValues: [welcomeEventIdHex],
+        constMeta: kCrateApiCallSignalingAcceptCallFragmentsConstMeta,
+        argValues: [sdpAnswer, callId, callerPubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteAcceptWelcomeConstMeta =>
+  TaskConstMeta get kCrateApiCallSignalingAcceptCallFragmentsConstMeta =>
       const TaskConstMeta(
-        debugName: "accept_welcome",
-        argNames: ["welcomeEventIdHex"],
+        debugName: "accept_call_fragments",
+        argNames: ["sdpAnswer", "callId", "callerPubkeyHex"],
+      );
+
+  @override
+  Future<void> crateApiInviteAcceptWelcome({
+    required String welcomeEventIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(welcomeEventIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiInviteAcceptWelcomeConstMeta,
+        argValues: [welcomeEventIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteAcceptWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "accept_welcome",
+        argNames: ["welcomeEventIdHex"],
       );
 
   @override
@@ -796,7 +1226,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 5,
             port: port_,
           );
         },
@@ -826,7 +1256,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 6,
             port: port_,
           );
         },
@@ -853,7 +1283,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 7,
             port: port_,
           );
         },
@@ -881,7 +1311,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 8,
             port: port_,
           );
         },
@@ -908,7 +1338,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 9,
             port: port_,
           );
         },
@@ -926,6 +1356,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiIdentityBootstrapIdentityConstMeta =>
       const TaskConstMeta(debugName: "bootstrap_identity", argNames: []);
 
+  @override
+  Future<List<String>> crateApiMediaBuildChunkedImetaTag({
+    required String url,
+    required String mimeType,
+    required String filename,
+    required String originalHashHex,
+    required String nonceHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_String(mimeType, serializer);
+          sse_encode_String(filename, serializer);
+          sse_encode_String(originalHashHex, serializer);
+          sse_encode_String(nonceHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMediaBuildChunkedImetaTagConstMeta,
+        argValues: [url, mimeType, filename, originalHashHex, nonceHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaBuildChunkedImetaTagConstMeta =>
+      const TaskConstMeta(
+        debugName: "build_chunked_imeta_tag",
+        argNames: [
+          "url",
+          "mimeType",
+          "filename",
+          "originalHashHex",
+          "nonceHex",
+        ],
+      );
+
   @override
   Future<String> crateApiCallSignalingBuildGroupCallSignaling({
     required int kindNum,
@@ -944,7 +1421,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 11,
             port: port_,
           );
         },
@@ -974,6 +1451,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String nonceHex,
     String? dimensions,
     String? blurhash,
+    ThumbnailReference? thumb,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -986,10 +1464,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(nonceHex, serializer);
           sse_encode_opt_String(dimensions, serializer);
           sse_encode_opt_String(blurhash, serializer);
+          sse_encode_opt_box_autoadd_thumbnail_reference(thumb, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 12,
             port: port_,
           );
         },
@@ -1006,6 +1485,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           nonceHex,
           dimensions,
           blurhash,
+          thumb,
         ],
         apiImpl: this,
       ),
@@ -1022,6 +1502,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       "nonceHex",
       "dimensions",
       "blurhash",
+      "thumb",
     ],
   );
 
@@ -1037,7 +1518,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 13,
             port: port_,
           );
         },
@@ -1059,6 +1540,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["transcriptText"],
       );
 
+  @override
+  Future<String>
+  crateApiMeetingIntelligenceBuildMeetingNotesPromptFromSegments({
+    required String segmentsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(segmentsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceBuildMeetingNotesPromptFromSegmentsConstMeta,
+        argValues: [segmentsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiMeetingIntelligenceBuildMeetingNotesPromptFromSegmentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "build_meeting_notes_prompt_from_segments",
+        argNames: ["segmentsJson"],
+      );
+
   @override
   Future<QualityScore> crateApiCallQualityCalculateQualityScore({
     required double rttMs,
@@ -1079,7 +1595,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 15,
             port: port_,
           );
         },
@@ -1107,40 +1623,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiMeetingIntelligenceConfigureAiBackend({
-    required String backendJson,
-  }) {
+  Future<int> crateApiGroupCleanupInactiveGroups() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(backendJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMeetingIntelligenceConfigureAiBackendConstMeta,
-        argValues: [backendJson],
+        constMeta: kCrateApiGroupCleanupInactiveGroupsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMeetingIntelligenceConfigureAiBackendConstMeta =>
-      const TaskConstMeta(
-        debugName: "configure_ai_backend",
-        argNames: ["backendJson"],
-      );
+  TaskConstMeta get kCrateApiGroupCleanupInactiveGroupsConstMeta =>
+      const TaskConstMeta(debugName: "cleanup_inactive_groups", argNames: []);
 
   @override
-  Future<void> crateApiRelayConnectRelays() {
+  Future<void> crateApiMediaClearMediaCache() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1148,7 +1658,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 17,
             port: port_,
           );
         },
@@ -1156,326 +1666,240 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiRelayConnectRelaysConstMeta,
+        constMeta: kCrateApiMediaClearMediaCacheConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayConnectRelaysConstMeta =>
-      const TaskConstMeta(debugName: "connect_relays", argNames: []);
+  TaskConstMeta get kCrateApiMediaClearMediaCacheConstMeta =>
+      const TaskConstMeta(debugName: "clear_media_cache", argNames: []);
 
   @override
-  Future<AccountInfo> crateApiAccountCreateAccount() {
+  Future<void> crateApiGroupClearPendingCommit({
+    required String mlsGroupIdHex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountCreateAccountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupClearPendingCommitConstMeta,
+        argValues: [mlsGroupIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountCreateAccountConstMeta =>
-      const TaskConstMeta(debugName: "create_account", argNames: []);
+  TaskConstMeta get kCrateApiGroupClearPendingCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_pending_commit",
+        argNames: ["mlsGroupIdHex"],
+      );
 
   @override
-  Future<CreateGroupResult> crateApiGroupCreateGroup({
-    required String name,
-    required String description,
-    required List<String> adminPubkeysHex,
-    required List<String> memberKeyPackageEventsJson,
-    required List<String> relayUrls,
+  Future<bool> crateApiMeetingIntelligenceClearTranscriptBuffer({
+    required String meetingId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          sse_encode_String(description, serializer);
-          sse_encode_list_String(adminPubkeysHex, serializer);
-          sse_encode_list_String(memberKeyPackageEventsJson, serializer);
-          sse_encode_list_String(relayUrls, serializer);
+          sse_encode_String(meetingId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_create_group_result,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiGroupCreateGroupConstMeta,
-        argValues: [
-          name,
-          description,
-          adminPubkeysHex,
-          memberKeyPackageEventsJson,
-          relayUrls,
-        ],
+        constMeta: kCrateApiMeetingIntelligenceClearTranscriptBufferConstMeta,
+        argValues: [meetingId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupCreateGroupConstMeta => const TaskConstMeta(
-    debugName: "create_group",
-    argNames: [
-      "name",
-      "description",
-      "adminPubkeysHex",
-      "memberKeyPackageEventsJson",
-      "relayUrls",
-    ],
-  );
+  TaskConstMeta
+  get kCrateApiMeetingIntelligenceClearTranscriptBufferConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_transcript_buffer",
+        argNames: ["meetingId"],
+      );
 
   @override
-  Future<PeerEntry> crateApiCallWebrtcCreatePeerEntry({
-    required String callId,
-    required String participantPubkeyHex,
-    required bool hasAudioTrack,
-    required bool hasVideoTrack,
-  }) {
+  Future<DiagnosticsReport> crateApiDiagnosticsCollectDiagnostics() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(participantPubkeyHex, serializer);
-          sse_encode_bool(hasAudioTrack, serializer);
-          sse_encode_bool(hasVideoTrack, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_peer_entry,
+          decodeSuccessData: sse_decode_diagnostics_report,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcCreatePeerEntryConstMeta,
-        argValues: [callId, participantPubkeyHex, hasAudioTrack, hasVideoTrack],
+        constMeta: kCrateApiDiagnosticsCollectDiagnosticsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcCreatePeerEntryConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_peer_entry",
-        argNames: [
-          "callId",
-          "participantPubkeyHex",
-          "hasAudioTrack",
-          "hasVideoTrack",
-        ],
-      );
+  TaskConstMeta get kCrateApiDiagnosticsCollectDiagnosticsConstMeta =>
+      const TaskConstMeta(debugName: "collect_diagnostics", argNames: []);
 
   @override
-  Future<CallSession> crateApiCallSessionCreateSession({
-    required String callId,
-    required String callType,
-    required String direction,
-    required String localPubkeyHex,
-    required String remotePubkeyHex,
-    String? groupIdHex,
+  Future<void> crateApiMeetingIntelligenceConfigureAiBackend({
+    required String backendJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(callType, serializer);
-          sse_encode_String(direction, serializer);
-          sse_encode_String(localPubkeyHex, serializer);
-          sse_encode_String(remotePubkeyHex, serializer);
-          sse_encode_opt_String(groupIdHex, serializer);
+          sse_encode_String(backendJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 21,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_call_session,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCallSessionCreateSessionConstMeta,
-        argValues: [
-          callId,
-          callType,
-          direction,
-          localPubkeyHex,
-          remotePubkeyHex,
-          groupIdHex,
-        ],
+        constMeta: kCrateApiMeetingIntelligenceConfigureAiBackendConstMeta,
+        argValues: [backendJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSessionCreateSessionConstMeta =>
+  TaskConstMeta get kCrateApiMeetingIntelligenceConfigureAiBackendConstMeta =>
       const TaskConstMeta(
-        debugName: "create_session",
-        argNames: [
-          "callId",
-          "callType",
-          "direction",
-          "localPubkeyHex",
-          "remotePubkeyHex",
-          "groupIdHex",
-        ],
+        debugName: "configure_ai_backend",
+        argNames: ["backendJson"],
       );
 
   @override
-  Future<ContactsSyncDebug> crateApiContactsDebugSyncContacts() {
+  Future<void> crateApiMeetingIntelligenceConfigureConfidence({
+    required double minConfidence,
+    required double reviewConfidence,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_64(minConfidence, serializer);
+          sse_encode_f_64(reviewConfidence, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_contacts_sync_debug,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiContactsDebugSyncContactsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMeetingIntelligenceConfigureConfidenceConstMeta,
+        argValues: [minConfidence, reviewConfidence],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsDebugSyncContactsConstMeta =>
-      const TaskConstMeta(debugName: "debug_sync_contacts", argNames: []);
+  TaskConstMeta get kCrateApiMeetingIntelligenceConfigureConfidenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "configure_confidence",
+        argNames: ["minConfidence", "reviewConfidence"],
+      );
 
   @override
-  Future<void> crateApiInviteDeclineWelcome({
-    required String welcomeEventIdHex,
+  Future<void> crateApiMeetingIntelligenceConfigureRedaction({
+    required String redactPatternsJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(welcomeEventIdHex, serializer);
+          sse_encode_String(redactPatternsJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiInviteDeclineWelcomeConstMeta,
-        argValues: [welcomeEventIdHex],
+        constMeta: kCrateApiMeetingIntelligenceConfigureRedactionConstMeta,
+        argValues: [redactPatternsJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteDeclineWelcomeConstMeta =>
+  TaskConstMeta get kCrateApiMeetingIntelligenceConfigureRedactionConstMeta =>
       const TaskConstMeta(
-        debugName: "decline_welcome",
-        argNames: ["welcomeEventIdHex"],
+        debugName: "configure_redaction",
+        argNames: ["redactPatternsJson"],
       );
 
   @override
-  Future<Uint8List> crateApiMediaDecryptFile({
-    required String mlsGroupIdHex,
-    required List<int> encryptedData,
-    required String url,
-    required String mimeType,
-    required String filename,
-    required String originalHashHex,
-    required String nonceHex,
-    required String schemeVersion,
-    String? dimensions,
-  }) {
+  Future<void> crateApiRelayConnectRelays() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_list_prim_u_8_loose(encryptedData, serializer);
-          sse_encode_String(url, serializer);
-          sse_encode_String(mimeType, serializer);
-          sse_encode_String(filename, serializer);
-          sse_encode_String(originalHashHex, serializer);
-          sse_encode_String(nonceHex, serializer);
-          sse_encode_String(schemeVersion, serializer);
-          sse_encode_opt_String(dimensions, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMediaDecryptFileConstMeta,
-        argValues: [
-          mlsGroupIdHex,
-          encryptedData,
-          url,
-          mimeType,
-          filename,
-          originalHashHex,
-          nonceHex,
-          schemeVersion,
-          dimensions,
-        ],
+        constMeta: kCrateApiRelayConnectRelaysConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaDecryptFileConstMeta => const TaskConstMeta(
-    debugName: "decrypt_file",
-    argNames: [
-      "mlsGroupIdHex",
-      "encryptedData",
-      "url",
-      "mimeType",
-      "filename",
-      "originalHashHex",
-      "nonceHex",
-      "schemeVersion",
-      "dimensions",
-    ],
-  );
+  TaskConstMeta get kCrateApiRelayConnectRelaysConstMeta =>
+      const TaskConstMeta(debugName: "connect_relays", argNames: []);
 
   @override
-  Future<String> crateApiGroupDefaultBlossomServer() {
+  Future<AccountInfo> crateApiAccountCreateAccount() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1483,210 +1907,249 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_account_info,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupDefaultBlossomServerConstMeta,
+        constMeta: kCrateApiAccountCreateAccountConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupDefaultBlossomServerConstMeta =>
-      const TaskConstMeta(debugName: "default_blossom_server", argNames: []);
+  TaskConstMeta get kCrateApiAccountCreateAccountConstMeta =>
+      const TaskConstMeta(debugName: "create_account", argNames: []);
 
   @override
-  List<String> crateApiRelayDefaultRelayUrls() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CreateGroupResult> crateApiGroupCreateDm({
+    required String peerPubkeyHex,
+    required String peerKeyPackageEventJson,
+    required List<String> relayUrls,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          sse_encode_String(peerPubkeyHex, serializer);
+          sse_encode_String(peerKeyPackageEventJson, serializer);
+          sse_encode_list_String(relayUrls, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_create_group_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiRelayDefaultRelayUrlsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupCreateDmConstMeta,
+        argValues: [peerPubkeyHex, peerKeyPackageEventJson, relayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayDefaultRelayUrlsConstMeta =>
-      const TaskConstMeta(debugName: "default_relay_urls", argNames: []);
+  TaskConstMeta get kCrateApiGroupCreateDmConstMeta => const TaskConstMeta(
+    debugName: "create_dm",
+    argNames: ["peerPubkeyHex", "peerKeyPackageEventJson", "relayUrls"],
+  );
 
   @override
-  Future<void> crateApiAppStateDeleteGroupState({
-    required String groupIdHex,
-    required String key,
+  Future<CreateGroupResult> crateApiGroupCreateGroup({
+    required String name,
+    required String description,
+    required List<String> adminPubkeysHex,
+    required List<String> memberKeyPackageEventsJson,
+    required List<String> relayUrls,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
-          sse_encode_String(key, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_String(description, serializer);
+          sse_encode_list_String(adminPubkeysHex, serializer);
+          sse_encode_list_String(memberKeyPackageEventsJson, serializer);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_create_group_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateDeleteGroupStateConstMeta,
-        argValues: [groupIdHex, key],
+        constMeta: kCrateApiGroupCreateGroupConstMeta,
+        argValues: [
+          name,
+          description,
+          adminPubkeysHex,
+          memberKeyPackageEventsJson,
+          relayUrls,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateDeleteGroupStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_group_state",
-        argNames: ["groupIdHex", "key"],
-      );
+  TaskConstMeta get kCrateApiGroupCreateGroupConstMeta => const TaskConstMeta(
+    debugName: "create_group",
+    argNames: [
+      "name",
+      "description",
+      "adminPubkeysHex",
+      "memberKeyPackageEventsJson",
+      "relayUrls",
+    ],
+  );
 
   @override
-  Future<void> crateApiAccountDeleteSecretKeyFromKeyring() {
+  Future<CreateOrGetDmResult> crateApiGroupCreateOrGetDm({
+    required String peerPubkeyHex,
+    required List<String> relayUrls,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(peerPubkeyHex, serializer);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_create_or_get_dm_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountDeleteSecretKeyFromKeyringConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupCreateOrGetDmConstMeta,
+        argValues: [peerPubkeyHex, relayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountDeleteSecretKeyFromKeyringConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_secret_key_from_keyring",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiGroupCreateOrGetDmConstMeta => const TaskConstMeta(
+    debugName: "create_or_get_dm",
+    argNames: ["peerPubkeyHex", "relayUrls"],
+  );
 
   @override
-  Future<String> crateApiCallWebrtcDeriveFrameEncryptionKey({
-    required String exporterSecretHex,
+  Future<PeerEntry> crateApiCallWebrtcCreatePeerEntry({
     required String callId,
+    required String participantPubkeyHex,
+    required bool hasAudioTrack,
+    required bool hasVideoTrack,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(exporterSecretHex, serializer);
           sse_encode_String(callId, serializer);
+          sse_encode_String(participantPubkeyHex, serializer);
+          sse_encode_bool(hasAudioTrack, serializer);
+          sse_encode_bool(hasVideoTrack, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_peer_entry,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcDeriveFrameEncryptionKeyConstMeta,
-        argValues: [exporterSecretHex, callId],
+        constMeta: kCrateApiCallWebrtcCreatePeerEntryConstMeta,
+        argValues: [callId, participantPubkeyHex, hasAudioTrack, hasVideoTrack],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcDeriveFrameEncryptionKeyConstMeta =>
+  TaskConstMeta get kCrateApiCallWebrtcCreatePeerEntryConstMeta =>
       const TaskConstMeta(
-        debugName: "derive_frame_encryption_key",
-        argNames: ["exporterSecretHex", "callId"],
+        debugName: "create_peer_entry",
+        argNames: [
+          "callId",
+          "participantPubkeyHex",
+          "hasAudioTrack",
+          "hasVideoTrack",
+        ],
       );
 
   @override
-  Future<String> crateApiCallSessionDeriveMediaKey({
-    required String exporterSecretHex,
+  Future<CallSession> crateApiCallSessionCreateSession({
     required String callId,
+    required String callType,
+    required String direction,
+    required String localPubkeyHex,
+    required String remotePubkeyHex,
+    String? groupIdHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(exporterSecretHex, serializer);
           sse_encode_String(callId, serializer);
+          sse_encode_String(callType, serializer);
+          sse_encode_String(direction, serializer);
+          sse_encode_String(localPubkeyHex, serializer);
+          sse_encode_String(remotePubkeyHex, serializer);
+          sse_encode_opt_String(groupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 30,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_call_session,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSessionDeriveMediaKeyConstMeta,
-        argValues: [exporterSecretHex, callId],
+        constMeta: kCrateApiCallSessionCreateSessionConstMeta,
+        argValues: [
+          callId,
+          callType,
+          direction,
+          localPubkeyHex,
+          remotePubkeyHex,
+          groupIdHex,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSessionDeriveMediaKeyConstMeta =>
+  TaskConstMeta get kCrateApiCallSessionCreateSessionConstMeta =>
       const TaskConstMeta(
-        debugName: "derive_media_key",
-        argNames: ["exporterSecretHex", "callId"],
+        debugName: "create_session",
+        argNames: [
+          "callId",
+          "callType",
+          "direction",
+          "localPubkeyHex",
+          "remotePubkeyHex",
+          "groupIdHex",
+        ],
       );
 
   @override
-  Future<void> crateApiStateDestroyState() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiStateDestroyStateConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStateDestroyStateConstMeta =>
-      const TaskConstMeta(debugName: "destroy_state", argNames: []);
-
-  @override
-  Future<void> crateApiRelayDisconnectRelays() {
+  Future<ContactsSyncDebug> crateApiContactsDebugSyncContacts() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1694,62 +2157,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 31,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_contacts_sync_debug,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiRelayDisconnectRelaysConstMeta,
+        constMeta: kCrateApiContactsDebugSyncContactsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayDisconnectRelaysConstMeta =>
-      const TaskConstMeta(debugName: "disconnect_relays", argNames: []);
+  TaskConstMeta get kCrateApiContactsDebugSyncContactsConstMeta =>
+      const TaskConstMeta(debugName: "debug_sync_contacts", argNames: []);
 
   @override
-  Future<Uint8List> crateApiGroupDownloadGroupImage({
-    required String mlsGroupIdHex,
-    required String blossomServerUrl,
+  Future<void> crateApiInviteDeclineWelcome({
+    required String welcomeEventIdHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(blossomServerUrl, serializer);
+          sse_encode_String(welcomeEventIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 32,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupDownloadGroupImageConstMeta,
-        argValues: [mlsGroupIdHex, blossomServerUrl],
+        constMeta: kCrateApiInviteDeclineWelcomeConstMeta,
+        argValues: [welcomeEventIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupDownloadGroupImageConstMeta =>
+  TaskConstMeta get kCrateApiInviteDeclineWelcomeConstMeta =>
       const TaskConstMeta(
-        debugName: "download_group_image",
-        argNames: ["mlsGroupIdHex", "blossomServerUrl"],
+        debugName: "decline_welcome",
+        argNames: ["welcomeEventIdHex"],
       );
 
   @override
-  Future<Uint8List> crateApiMediaDownloadMedia({
+  Future<Uint8List> crateApiMediaDecryptFile({
     required String mlsGroupIdHex,
+    required List<int> encryptedData,
     required String url,
     required String mimeType,
     required String filename,
@@ -1763,6 +2225,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_prim_u_8_loose(encryptedData, serializer);
           sse_encode_String(url, serializer);
           sse_encode_String(mimeType, serializer);
           sse_encode_String(filename, serializer);
@@ -1773,7 +2236,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 33,
             port: port_,
           );
         },
@@ -1781,9 +2244,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMediaDownloadMediaConstMeta,
+        constMeta: kCrateApiMediaDecryptFileConstMeta,
         argValues: [
           mlsGroupIdHex,
+          encryptedData,
           url,
           mimeType,
           filename,
@@ -1797,10 +2261,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  TaskConstMeta get kCrateApiMediaDownloadMediaConstMeta => const TaskConstMeta(
-    debugName: "download_media",
+  TaskConstMeta get kCrateApiMediaDecryptFileConstMeta => const TaskConstMeta(
+    debugName: "decrypt_file",
     argNames: [
       "mlsGroupIdHex",
+      "encryptedData",
       "url",
       "mimeType",
       "filename",
@@ -1812,686 +2277,785 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<EncryptedFileResult> crateApiMediaEncryptFile({
+  Future<BigInt> crateApiMediaDecryptFileChunked({
     required String mlsGroupIdHex,
-    required List<int> fileData,
-    required String mimeType,
+    required String inputPath,
+    required String outputPath,
     required String filename,
+    required String originalHashHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_list_prim_u_8_loose(fileData, serializer);
-          sse_encode_String(mimeType, serializer);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(outputPath, serializer);
           sse_encode_String(filename, serializer);
+          sse_encode_String(originalHashHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 34,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_encrypted_file_result,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMediaEncryptFileConstMeta,
-        argValues: [mlsGroupIdHex, fileData, mimeType, filename],
+        constMeta: kCrateApiMediaDecryptFileChunkedConstMeta,
+        argValues: [
+          mlsGroupIdHex,
+          inputPath,
+          outputPath,
+          filename,
+          originalHashHex,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaEncryptFileConstMeta => const TaskConstMeta(
-    debugName: "encrypt_file",
-    argNames: ["mlsGroupIdHex", "fileData", "mimeType", "filename"],
-  );
+  TaskConstMeta get kCrateApiMediaDecryptFileChunkedConstMeta =>
+      const TaskConstMeta(
+        debugName: "decrypt_file_chunked",
+        argNames: [
+          "mlsGroupIdHex",
+          "inputPath",
+          "outputPath",
+          "filename",
+          "originalHashHex",
+        ],
+      );
 
   @override
-  Future<String> crateApiCallSignalingEndCall({
-    required String callId,
-    required String remotePubkeyHex,
-  }) {
+  Future<String> crateApiGroupDefaultBlossomServer() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(remotePubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiCallSignalingEndCallConstMeta,
-        argValues: [callId, remotePubkeyHex],
+        constMeta: kCrateApiGroupDefaultBlossomServerConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingEndCallConstMeta =>
-      const TaskConstMeta(
-        debugName: "end_call",
-        argNames: ["callId", "remotePubkeyHex"],
-      );
+  TaskConstMeta get kCrateApiGroupDefaultBlossomServerConstMeta =>
+      const TaskConstMeta(debugName: "default_blossom_server", argNames: []);
 
   @override
-  Future<String> crateApiMeetingIntelligenceExportMeetingMarkdown({
-    required String meetingId,
-  }) {
+  Future<List<String>> crateApiMeetingIntelligenceDefaultRedactPatterns() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(meetingId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMeetingIntelligenceExportMeetingMarkdownConstMeta,
-        argValues: [meetingId],
+        constMeta: kCrateApiMeetingIntelligenceDefaultRedactPatternsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiMeetingIntelligenceExportMeetingMarkdownConstMeta =>
-      const TaskConstMeta(
-        debugName: "export_meeting_markdown",
-        argNames: ["meetingId"],
-      );
+  get kCrateApiMeetingIntelligenceDefaultRedactPatternsConstMeta =>
+      const TaskConstMeta(debugName: "default_redact_patterns", argNames: []);
 
   @override
-  Future<String> crateApiIdentityExportNpub() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  List<String> crateApiRelayDefaultRelayUrls() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiIdentityExportNpubConstMeta,
+        constMeta: kCrateApiRelayDefaultRelayUrlsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityExportNpubConstMeta =>
-      const TaskConstMeta(debugName: "export_npub", argNames: []);
+  TaskConstMeta get kCrateApiRelayDefaultRelayUrlsConstMeta =>
+      const TaskConstMeta(debugName: "default_relay_urls", argNames: []);
 
   @override
-  Future<String> crateApiIdentityExportNsec() {
+  Future<void> crateApiAppStateDeleteGroupState({
+    required String groupIdHex,
+    required String key,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupIdHex, serializer);
+          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiIdentityExportNsecConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAppStateDeleteGroupStateConstMeta,
+        argValues: [groupIdHex, key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityExportNsecConstMeta =>
-      const TaskConstMeta(debugName: "export_nsec", argNames: []);
+  TaskConstMeta get kCrateApiAppStateDeleteGroupStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_group_state",
+        argNames: ["groupIdHex", "key"],
+      );
 
   @override
-  Future<String> crateApiIdentityExportPubkeyHex() {
+  Future<bool> crateApiMeetingIntelligenceDeleteMeetingNotes({
+    required String meetingId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiIdentityExportPubkeyHexConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMeetingIntelligenceDeleteMeetingNotesConstMeta,
+        argValues: [meetingId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityExportPubkeyHexConstMeta =>
-      const TaskConstMeta(debugName: "export_pubkey_hex", argNames: []);
+  TaskConstMeta get kCrateApiMeetingIntelligenceDeleteMeetingNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_meeting_notes",
+        argNames: ["meetingId"],
+      );
 
   @override
-  Future<List<TranscriptSegment>> crateApiTranscriptionFeedAudio({
-    required List<double> audioData,
-    required String speakerTrackId,
+  Future<SendMessageResult> crateApiMessageDeleteMessage({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_f_32_loose(audioData, serializer);
-          sse_encode_String(speakerTrackId, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(targetEventIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 40,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_transcript_segment,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_send_message_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionFeedAudioConstMeta,
-        argValues: [audioData, speakerTrackId],
+        constMeta: kCrateApiMessageDeleteMessageConstMeta,
+        argValues: [mlsGroupIdHex, targetEventIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionFeedAudioConstMeta =>
+  TaskConstMeta get kCrateApiMessageDeleteMessageConstMeta =>
       const TaskConstMeta(
-        debugName: "feed_audio",
-        argNames: ["audioData", "speakerTrackId"],
+        debugName: "delete_message",
+        argNames: ["mlsGroupIdHex", "targetEventIdHex"],
       );
 
   @override
-  Future<String> crateApiInviteFetchKeyPackage({required String pubkeyHex}) {
+  Future<void> crateApiAccountDeleteSecretKeyFromKeyring() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 41,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteFetchKeyPackageConstMeta,
-        argValues: [pubkeyHex],
+        constMeta: kCrateApiAccountDeleteSecretKeyFromKeyringConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteFetchKeyPackageConstMeta =>
+  TaskConstMeta get kCrateApiAccountDeleteSecretKeyFromKeyringConstMeta =>
       const TaskConstMeta(
-        debugName: "fetch_key_package",
-        argNames: ["pubkeyHex"],
+        debugName: "delete_secret_key_from_keyring",
+        argNames: [],
       );
 
   @override
-  Future<ProfileData> crateApiIdentityFetchProfile({
+  Future<UpdateGroupResult> crateApiGroupDemoteAdmin({
+    required String mlsGroupIdHex,
     required String pubkeyHex,
-    required bool blockingSync,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
           sse_encode_String(pubkeyHex, serializer);
-          sse_encode_bool(blockingSync, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 42,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_profile_data,
+          decodeSuccessData: sse_decode_update_group_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiIdentityFetchProfileConstMeta,
-        argValues: [pubkeyHex, blockingSync],
+        constMeta: kCrateApiGroupDemoteAdminConstMeta,
+        argValues: [mlsGroupIdHex, pubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityFetchProfileConstMeta =>
-      const TaskConstMeta(
-        debugName: "fetch_profile",
-        argNames: ["pubkeyHex", "blockingSync"],
-      );
+  TaskConstMeta get kCrateApiGroupDemoteAdminConstMeta => const TaskConstMeta(
+    debugName: "demote_admin",
+    argNames: ["mlsGroupIdHex", "pubkeyHex"],
+  );
 
   @override
-  Future<List<String>> crateApiIdentityFetchUserRelays({
-    required String pubkeyHex,
+  Future<String> crateApiCallWebrtcDeriveFrameEncryptionKey({
+    required String exporterSecretHex,
+    required BigInt epoch,
+    required String callId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkeyHex, serializer);
+          sse_encode_String(exporterSecretHex, serializer);
+          sse_encode_u_64(epoch, serializer);
+          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 43,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiIdentityFetchUserRelaysConstMeta,
-        argValues: [pubkeyHex],
+        constMeta: kCrateApiCallWebrtcDeriveFrameEncryptionKeyConstMeta,
+        argValues: [exporterSecretHex, epoch, callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityFetchUserRelaysConstMeta =>
+  TaskConstMeta get kCrateApiCallWebrtcDeriveFrameEncryptionKeyConstMeta =>
       const TaskConstMeta(
-        debugName: "fetch_user_relays",
-        argNames: ["pubkeyHex"],
+        debugName: "derive_frame_encryption_key",
+        argNames: ["exporterSecretHex", "epoch", "callId"],
       );
 
   @override
-  Future<void> crateApiContactsFollowContact({required String pubkeyHex}) {
+  Future<String> crateApiCallSessionDeriveMediaKey({
+    required String exporterSecretHex,
+    required String callId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkeyHex, serializer);
+          sse_encode_String(exporterSecretHex, serializer);
+          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 44,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiContactsFollowContactConstMeta,
-        argValues: [pubkeyHex],
+        constMeta: kCrateApiCallSessionDeriveMediaKeyConstMeta,
+        argValues: [exporterSecretHex, callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsFollowContactConstMeta =>
-      const TaskConstMeta(debugName: "follow_contact", argNames: ["pubkeyHex"]);
+  TaskConstMeta get kCrateApiCallSessionDeriveMediaKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "derive_media_key",
+        argNames: ["exporterSecretHex", "callId"],
+      );
 
   @override
-  Future<KeyPackageData> crateApiKeypackageGenerateKeyPackage({
-    required List<String> relayUrls,
-  }) {
+  Future<void> crateApiStateDestroyState() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 45,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_key_package_data,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiKeypackageGenerateKeyPackageConstMeta,
-        argValues: [relayUrls],
+        constMeta: kCrateApiStateDestroyStateConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiKeypackageGenerateKeyPackageConstMeta =>
-      const TaskConstMeta(
-        debugName: "generate_key_package",
-        argNames: ["relayUrls"],
-      );
+  TaskConstMeta get kCrateApiStateDestroyStateConstMeta =>
+      const TaskConstMeta(debugName: "destroy_state", argNames: []);
 
   @override
-  Future<String> crateApiMeetingIntelligenceGenerateMeetingNotes({
-    required String meetingId,
-    required String segmentsJson,
-    required String participantsJson,
-    required PlatformInt64 startTimeMs,
-    required PlatformInt64 endTimeMs,
-  }) {
+  Future<void> crateApiRelayDisconnectRelays() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(meetingId, serializer);
-          sse_encode_String(segmentsJson, serializer);
-          sse_encode_String(participantsJson, serializer);
-          sse_encode_i_64(startTimeMs, serializer);
-          sse_encode_i_64(endTimeMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 46,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMeetingIntelligenceGenerateMeetingNotesConstMeta,
-        argValues: [
-          meetingId,
-          segmentsJson,
-          participantsJson,
-          startTimeMs,
-          endTimeMs,
-        ],
+        constMeta: kCrateApiRelayDisconnectRelaysConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMeetingIntelligenceGenerateMeetingNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "generate_meeting_notes",
-        argNames: [
-          "meetingId",
-          "segmentsJson",
-          "participantsJson",
-          "startTimeMs",
-          "endTimeMs",
-        ],
-      );
+  TaskConstMeta get kCrateApiRelayDisconnectRelaysConstMeta =>
+      const TaskConstMeta(debugName: "disconnect_relays", argNames: []);
 
   @override
-  Future<WebRtcConfig> crateApiCallWebrtcGenerateWebrtcConfig({
-    required String callId,
+  Future<Uint8List> crateApiGroupDownloadGroupImage({
+    required String mlsGroupIdHex,
+    required String blossomServerUrl,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(blossomServerUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 47,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_web_rtc_config,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcGenerateWebrtcConfigConstMeta,
-        argValues: [callId],
+        constMeta: kCrateApiGroupDownloadGroupImageConstMeta,
+        argValues: [mlsGroupIdHex, blossomServerUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcGenerateWebrtcConfigConstMeta =>
+  TaskConstMeta get kCrateApiGroupDownloadGroupImageConstMeta =>
       const TaskConstMeta(
-        debugName: "generate_webrtc_config",
-        argNames: ["callId"],
+        debugName: "download_group_image",
+        argNames: ["mlsGroupIdHex", "blossomServerUrl"],
       );
 
   @override
-  Future<List<CallSession>> crateApiCallSessionGetActiveCalls() {
+  Future<Uint8List> crateApiMediaDownloadMedia({
+    required String mlsGroupIdHex,
+    required String url,
+    required String mimeType,
+    required String filename,
+    required String originalHashHex,
+    required String nonceHex,
+    required String schemeVersion,
+    String? dimensions,
+    required List<String> mirrorServers,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(url, serializer);
+          sse_encode_String(mimeType, serializer);
+          sse_encode_String(filename, serializer);
+          sse_encode_String(originalHashHex, serializer);
+          sse_encode_String(nonceHex, serializer);
+          sse_encode_String(schemeVersion, serializer);
+          sse_encode_opt_String(dimensions, serializer);
+          sse_encode_list_String(mirrorServers, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 48,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_call_session,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSessionGetActiveCallsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaDownloadMediaConstMeta,
+        argValues: [
+          mlsGroupIdHex,
+          url,
+          mimeType,
+          filename,
+          originalHashHex,
+          nonceHex,
+          schemeVersion,
+          dimensions,
+          mirrorServers,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSessionGetActiveCallsConstMeta =>
-      const TaskConstMeta(debugName: "get_active_calls", argNames: []);
+  TaskConstMeta get kCrateApiMediaDownloadMediaConstMeta => const TaskConstMeta(
+    debugName: "download_media",
+    argNames: [
+      "mlsGroupIdHex",
+      "url",
+      "mimeType",
+      "filename",
+      "originalHashHex",
+      "nonceHex",
+      "schemeVersion",
+      "dimensions",
+      "mirrorServers",
+    ],
+  );
 
   @override
-  Future<AdaptiveBitrateConfig> crateApiCallQualityGetAdaptiveBitrateConfig() {
+  Future<SendMessageResult> crateApiMessageEditMessage({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+    required String newContent,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(targetEventIdHex, serializer);
+          sse_encode_String(newContent, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 49,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_adaptive_bitrate_config,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_send_message_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallQualityGetAdaptiveBitrateConfigConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessageEditMessageConstMeta,
+        argValues: [mlsGroupIdHex, targetEventIdHex, newContent],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityGetAdaptiveBitrateConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_adaptive_bitrate_config",
-        argNames: [],
-      );
+  TaskConstMeta get kCrateApiMessageEditMessageConstMeta => const TaskConstMeta(
+    debugName: "edit_message",
+    argNames: ["mlsGroupIdHex", "targetEventIdHex", "newContent"],
+  );
 
   @override
-  Future<List<String>> crateApiAppStateGetArchivedGroupIds() {
+  Future<EncryptedFileResult> crateApiMediaEncryptFile({
+    required String mlsGroupIdHex,
+    required List<int> fileData,
+    required String mimeType,
+    required String filename,
+    required bool strict,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_prim_u_8_loose(fileData, serializer);
+          sse_encode_String(mimeType, serializer);
+          sse_encode_String(filename, serializer);
+          sse_encode_bool(strict, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 50,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_encrypted_file_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateGetArchivedGroupIdsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaEncryptFileConstMeta,
+        argValues: [mlsGroupIdHex, fileData, mimeType, filename, strict],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateGetArchivedGroupIdsConstMeta =>
-      const TaskConstMeta(debugName: "get_archived_group_ids", argNames: []);
+  TaskConstMeta get kCrateApiMediaEncryptFileConstMeta => const TaskConstMeta(
+    debugName: "encrypt_file",
+    argNames: ["mlsGroupIdHex", "fileData", "mimeType", "filename", "strict"],
+  );
 
   @override
-  Future<AudioConstraints> crateApiCallQualityGetAudioConstraints({
-    required AudioMode mode,
+  Future<ChunkedEncryptResult> crateApiMediaEncryptFileChunked({
+    required String mlsGroupIdHex,
+    required String inputPath,
+    required String outputPath,
+    required String mimeType,
+    required String filename,
+    required bool strict,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_audio_mode(mode, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(inputPath, serializer);
+          sse_encode_String(outputPath, serializer);
+          sse_encode_String(mimeType, serializer);
+          sse_encode_String(filename, serializer);
+          sse_encode_bool(strict, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 51,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_audio_constraints,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_chunked_encrypt_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallQualityGetAudioConstraintsConstMeta,
-        argValues: [mode],
+        constMeta: kCrateApiMediaEncryptFileChunkedConstMeta,
+        argValues: [
+          mlsGroupIdHex,
+          inputPath,
+          outputPath,
+          mimeType,
+          filename,
+          strict,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityGetAudioConstraintsConstMeta =>
+  TaskConstMeta get kCrateApiMediaEncryptFileChunkedConstMeta =>
       const TaskConstMeta(
-        debugName: "get_audio_constraints",
-        argNames: ["mode"],
+        debugName: "encrypt_file_chunked",
+        argNames: [
+          "mlsGroupIdHex",
+          "inputPath",
+          "outputPath",
+          "mimeType",
+          "filename",
+          "strict",
+        ],
       );
 
   @override
-  Future<List<ContactInfo>> crateApiContactsGetCachedContacts() {
+  Future<EncryptedFileOnDisk> crateApiMediaEncryptFileFromPath({
+    required String mlsGroupIdHex,
+    required String filePath,
+    required String mimeType,
+    required String filename,
+    required bool strict,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(filePath, serializer);
+          sse_encode_String(mimeType, serializer);
+          sse_encode_String(filename, serializer);
+          sse_encode_bool(strict, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 52,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_contact_info,
+          decodeSuccessData: sse_decode_encrypted_file_on_disk,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiContactsGetCachedContactsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaEncryptFileFromPathConstMeta,
+        argValues: [mlsGroupIdHex, filePath, mimeType, filename, strict],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsGetCachedContactsConstMeta =>
-      const TaskConstMeta(debugName: "get_cached_contacts", argNames: []);
+  TaskConstMeta get kCrateApiMediaEncryptFileFromPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "encrypt_file_from_path",
+        argNames: [
+          "mlsGroupIdHex",
+          "filePath",
+          "mimeType",
+          "filename",
+          "strict",
+        ],
+      );
 
   @override
-  Future<ProfileData> crateApiIdentityGetCachedProfile({
-    required String pubkeyHex,
+  Future<EncryptedFileResult> crateApiMediaEncryptThumbnail({
+    required String mlsGroupIdHex,
+    required List<int> fileData,
+    required int maxDimension,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(pubkeyHex, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_prim_u_8_loose(fileData, serializer);
+          sse_encode_u_32(maxDimension, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 53,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_profile_data,
+          decodeSuccessData: sse_decode_encrypted_file_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiIdentityGetCachedProfileConstMeta,
-        argValues: [pubkeyHex],
+        constMeta: kCrateApiMediaEncryptThumbnailConstMeta,
+        argValues: [mlsGroupIdHex, fileData, maxDimension],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityGetCachedProfileConstMeta =>
+  TaskConstMeta get kCrateApiMediaEncryptThumbnailConstMeta =>
       const TaskConstMeta(
-        debugName: "get_cached_profile",
-        argNames: ["pubkeyHex"],
+        debugName: "encrypt_thumbnail",
+        argNames: ["mlsGroupIdHex", "fileData", "maxDimension"],
       );
 
   @override
-  Future<List<PeerEntry>> crateApiCallWebrtcGetCallParticipants({
+  Future<String> crateApiCallSignalingEndCall({
     required String callId,
+    required String remotePubkeyHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(callId, serializer);
+          sse_encode_String(remotePubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 54,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_peer_entry,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcGetCallParticipantsConstMeta,
-        argValues: [callId],
+        constMeta: kCrateApiCallSignalingEndCallConstMeta,
+        argValues: [callId, remotePubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcGetCallParticipantsConstMeta =>
+  TaskConstMeta get kCrateApiCallSignalingEndCallConstMeta =>
       const TaskConstMeta(
-        debugName: "get_call_participants",
-        argNames: ["callId"],
+        debugName: "end_call",
+        argNames: ["callId", "remotePubkeyHex"],
       );
 
   @override
-  Future<CodecPreferences> crateApiCallQualityGetCodecPreferences() {
+  Future<EphemeralKeyStrategy> crateApiEphemeralEphemeralKeyStrategyDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -2499,528 +3063,3052 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 55,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_codec_preferences,
+          decodeSuccessData: sse_decode_ephemeral_key_strategy,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCallQualityGetCodecPreferencesConstMeta,
+        constMeta: kCrateApiEphemeralEphemeralKeyStrategyDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityGetCodecPreferencesConstMeta =>
-      const TaskConstMeta(debugName: "get_codec_preferences", argNames: []);
+  TaskConstMeta get kCrateApiEphemeralEphemeralKeyStrategyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "ephemeral_key_strategy_default",
+        argNames: [],
+      );
 
   @override
-  Future<AccountInfo> crateApiAccountGetCurrentAccount() {
+  Future<String> crateApiContactsExportContactsList({required bool publish}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(publish, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 56,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountGetCurrentAccountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiContactsExportContactsListConstMeta,
+        argValues: [publish],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountGetCurrentAccountConstMeta =>
-      const TaskConstMeta(debugName: "get_current_account", argNames: []);
+  TaskConstMeta get kCrateApiContactsExportContactsListConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_contacts_list",
+        argNames: ["publish"],
+      );
 
   @override
-  Future<GroupInfo> crateApiGroupGetGroup({required String mlsGroupIdHex}) {
+  Future<String> crateApiMeetingIntelligenceExportMeetingMarkdown({
+    required String meetingId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(meetingId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 57,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_group_info,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiGroupGetGroupConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiMeetingIntelligenceExportMeetingMarkdownConstMeta,
+        argValues: [meetingId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupGetGroupConstMeta =>
-      const TaskConstMeta(debugName: "get_group", argNames: ["mlsGroupIdHex"]);
+  TaskConstMeta
+  get kCrateApiMeetingIntelligenceExportMeetingMarkdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_meeting_markdown",
+        argNames: ["meetingId"],
+      );
 
   @override
-  Future<List<MemberInfo>> crateApiGroupGetGroupMembers({
-    required String mlsGroupIdHex,
-  }) {
+  Future<String> crateApiIdentityExportNpub() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 58,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_member_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupGetGroupMembersConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiIdentityExportNpubConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupGetGroupMembersConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_group_members",
-        argNames: ["mlsGroupIdHex"],
-      );
+  TaskConstMeta get kCrateApiIdentityExportNpubConstMeta =>
+      const TaskConstMeta(debugName: "export_npub", argNames: []);
 
   @override
-  Future<List<String>> crateApiGroupGetGroupRelays({
-    required String mlsGroupIdHex,
-  }) {
+  Future<String> crateApiIdentityExportNsec() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 59,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupGetGroupRelaysConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiIdentityExportNsecConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupGetGroupRelaysConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_group_relays",
-        argNames: ["mlsGroupIdHex"],
-      );
+  TaskConstMeta get kCrateApiIdentityExportNsecConstMeta =>
+      const TaskConstMeta(debugName: "export_nsec", argNames: []);
 
   @override
-  Future<String?> crateApiAppStateGetGroupState({
-    required String groupIdHex,
-    required String key,
-  }) {
+  Future<String> crateApiIdentityExportPubkeyHex() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
-          sse_encode_String(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 60,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_String,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateGetGroupStateConstMeta,
-        argValues: [groupIdHex, key],
+        constMeta: kCrateApiIdentityExportPubkeyHexConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateGetGroupStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_group_state",
-        argNames: ["groupIdHex", "key"],
-      );
+  TaskConstMeta get kCrateApiIdentityExportPubkeyHexConstMeta =>
+      const TaskConstMeta(debugName: "export_pubkey_hex", argNames: []);
 
   @override
-  Future<GroupSummary> crateApiAppStateGetGroupSummary({
-    required String mlsGroupIdHex,
-  }) {
+  Future<Uint8List> crateApiBackupExportState({required String passphrase}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(passphrase, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 61,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_group_summary,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateGetGroupSummaryConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiBackupExportStateConstMeta,
+        argValues: [passphrase],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateGetGroupSummaryConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_group_summary",
-        argNames: ["mlsGroupIdHex"],
-      );
+  TaskConstMeta get kCrateApiBackupExportStateConstMeta =>
+      const TaskConstMeta(debugName: "export_state", argNames: ["passphrase"]);
 
   @override
-  Future<PlatformInt64?> crateApiContactsGetLastContactsSync() {
+  Future<List<String>> crateApiLinkPreviewExtractUrls({required String text}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 62,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiContactsGetLastContactsSyncConstMeta,
-        argValues: [],
+        constMeta: kCrateApiLinkPreviewExtractUrlsConstMeta,
+        argValues: [text],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiContactsGetLastContactsSyncConstMeta =>
-      const TaskConstMeta(debugName: "get_last_contacts_sync", argNames: []);
+  TaskConstMeta get kCrateApiLinkPreviewExtractUrlsConstMeta =>
+      const TaskConstMeta(debugName: "extract_urls", argNames: ["text"]);
 
   @override
-  Future<PlatformInt64?> crateApiAppStateGetLastReadTimestamp({
-    required String groupIdHex,
+  Future<List<TranscriptSegment>> crateApiTranscriptionFeedAudio({
+    required List<double> audioData,
+    required String speakerTrackId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
+          sse_encode_list_prim_f_32_loose(audioData, serializer);
+          sse_encode_String(speakerTrackId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 63,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_list_transcript_segment,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAppStateGetLastReadTimestampConstMeta,
-        argValues: [groupIdHex],
+        constMeta: kCrateApiTranscriptionFeedAudioConstMeta,
+        argValues: [audioData, speakerTrackId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateGetLastReadTimestampConstMeta =>
+  TaskConstMeta get kCrateApiTranscriptionFeedAudioConstMeta =>
       const TaskConstMeta(
-        debugName: "get_last_read_timestamp",
-        argNames: ["groupIdHex"],
+        debugName: "feed_audio",
+        argNames: ["audioData", "speakerTrackId"],
       );
 
   @override
-  Future<String> crateApiMeetingIntelligenceGetMeetingArchive() {
+  Future<String> crateApiInviteFetchKeyPackage({required String pubkeyHex}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 64,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMeetingIntelligenceGetMeetingArchiveConstMeta,
-        argValues: [],
+        constMeta: kCrateApiInviteFetchKeyPackageConstMeta,
+        argValues: [pubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMeetingIntelligenceGetMeetingArchiveConstMeta =>
-      const TaskConstMeta(debugName: "get_meeting_archive", argNames: []);
+  TaskConstMeta get kCrateApiInviteFetchKeyPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_key_package",
+        argNames: ["pubkeyHex"],
+      );
 
   @override
-  Future<String> crateApiMeetingIntelligenceGetMeetingNotes({
-    required String meetingId,
-  }) {
+  Future<OgMetadata> crateApiLinkPreviewFetchOgMetadata({required String url}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(meetingId, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 65,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_og_metadata,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMeetingIntelligenceGetMeetingNotesConstMeta,
-        argValues: [meetingId],
+        constMeta: kCrateApiLinkPreviewFetchOgMetadataConstMeta,
+        argValues: [url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMeetingIntelligenceGetMeetingNotesConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_meeting_notes",
-        argNames: ["meetingId"],
-      );
+  TaskConstMeta get kCrateApiLinkPreviewFetchOgMetadataConstMeta =>
+      const TaskConstMeta(debugName: "fetch_og_metadata", argNames: ["url"]);
 
   @override
-  Future<GroupMessage> crateApiMessageGetMessage({
-    required String mlsGroupIdHex,
-    required String eventIdHex,
+  Future<ProfileData> crateApiIdentityFetchProfile({
+    required String pubkeyHex,
+    required bool blockingSync,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(eventIdHex, serializer);
+          sse_encode_String(pubkeyHex, serializer);
+          sse_encode_bool(blockingSync, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 66,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_group_message,
+          decodeSuccessData: sse_decode_profile_data,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageGetMessageConstMeta,
-        argValues: [mlsGroupIdHex, eventIdHex],
+        constMeta: kCrateApiIdentityFetchProfileConstMeta,
+        argValues: [pubkeyHex, blockingSync],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageGetMessageConstMeta => const TaskConstMeta(
-    debugName: "get_message",
-    argNames: ["mlsGroupIdHex", "eventIdHex"],
-  );
+  TaskConstMeta get kCrateApiIdentityFetchProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_profile",
+        argNames: ["pubkeyHex", "blockingSync"],
+      );
 
   @override
-  Future<List<GroupMessage>> crateApiMessageGetMessages({
-    required String mlsGroupIdHex,
-    int? limit,
-    int? offset,
+  Future<List<String>> crateApiIdentityFetchUserRelays({
+    required String pubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiIdentityFetchUserRelaysConstMeta,
+        argValues: [pubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIdentityFetchUserRelaysConstMeta =>
+      const TaskConstMeta(
+        debugName: "fetch_user_relays",
+        argNames: ["pubkeyHex"],
+      );
+
+  @override
+  Future<String> crateApiTranscriptionFilterTranscriptByConfidence({
+    required String segmentsJson,
+    required double minConfidence,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(segmentsJson, serializer);
+          sse_encode_f_64(minConfidence, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTranscriptionFilterTranscriptByConfidenceConstMeta,
+        argValues: [segmentsJson, minConfidence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiTranscriptionFilterTranscriptByConfidenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "filter_transcript_by_confidence",
+        argNames: ["segmentsJson", "minConfidence"],
+      );
+
+  @override
+  Future<String> crateApiMeetingIntelligenceFinalizeLiveMeeting({
+    required String meetingId,
+    required String participantsJson,
+    required PlatformInt64 startTimeMs,
+    required PlatformInt64 endTimeMs,
   }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
+          sse_encode_String(participantsJson, serializer);
+          sse_encode_i_64(startTimeMs, serializer);
+          sse_encode_i_64(endTimeMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceFinalizeLiveMeetingConstMeta,
+        argValues: [meetingId, participantsJson, startTimeMs, endTimeMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceFinalizeLiveMeetingConstMeta =>
+      const TaskConstMeta(
+        debugName: "finalize_live_meeting",
+        argNames: ["meetingId", "participantsJson", "startTimeMs", "endTimeMs"],
+      );
+
+  @override
+  Future<GroupInfo?> crateApiGroupFindDmWith({required String peerPubkeyHex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(peerPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_group_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupFindDmWithConstMeta,
+        argValues: [peerPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupFindDmWithConstMeta => const TaskConstMeta(
+    debugName: "find_dm_with",
+    argNames: ["peerPubkeyHex"],
+  );
+
+  @override
+  Future<OutboxFlushResult> crateApiMessageFlushOutbox() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_outbox_flush_result,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageFlushOutboxConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageFlushOutboxConstMeta =>
+      const TaskConstMeta(debugName: "flush_outbox", argNames: []);
+
+  @override
+  Future<void> crateApiContactsFollowContact({required String pubkeyHex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiContactsFollowContactConstMeta,
+        argValues: [pubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsFollowContactConstMeta =>
+      const TaskConstMeta(debugName: "follow_contact", argNames: ["pubkeyHex"]);
+
+  @override
+  Future<void> crateApiGroupForgetGroup({required String mlsGroupIdHex}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 73,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_group_message,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageGetMessagesConstMeta,
-        argValues: [mlsGroupIdHex, limit, offset],
+        constMeta: kCrateApiGroupForgetGroupConstMeta,
+        argValues: [mlsGroupIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageGetMessagesConstMeta => const TaskConstMeta(
-    debugName: "get_messages",
-    argNames: ["mlsGroupIdHex", "limit", "offset"],
+  TaskConstMeta get kCrateApiGroupForgetGroupConstMeta => const TaskConstMeta(
+    debugName: "forget_group",
+    argNames: ["mlsGroupIdHex"],
   );
 
   @override
-  Future<PeerStats?> crateApiCallWebrtcGetPeerStats({
-    required String participantPubkeyHex,
+  Future<KeyPackageData> crateApiKeypackageGenerateKeyPackage({
+    required List<String> relayUrls,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(participantPubkeyHex, serializer);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 74,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_peer_stats,
+          decodeSuccessData: sse_decode_key_package_data,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcGetPeerStatsConstMeta,
-        argValues: [participantPubkeyHex],
-        apiImpl: this,
+        constMeta: kCrateApiKeypackageGenerateKeyPackageConstMeta,
+        argValues: [relayUrls],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiKeypackageGenerateKeyPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_key_package",
+        argNames: ["relayUrls"],
+      );
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGenerateLiveMeetingNotes({
+    required String meetingId,
+    required String participantsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
+          sse_encode_String(participantsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGenerateLiveMeetingNotesConstMeta,
+        argValues: [meetingId, participantsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiMeetingIntelligenceGenerateLiveMeetingNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_live_meeting_notes",
+        argNames: ["meetingId", "participantsJson"],
+      );
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGenerateMeetingNotes({
+    required String meetingId,
+    required String segmentsJson,
+    required String participantsJson,
+    required PlatformInt64 startTimeMs,
+    required PlatformInt64 endTimeMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
+          sse_encode_String(segmentsJson, serializer);
+          sse_encode_String(participantsJson, serializer);
+          sse_encode_i_64(startTimeMs, serializer);
+          sse_encode_i_64(endTimeMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGenerateMeetingNotesConstMeta,
+        argValues: [
+          meetingId,
+          segmentsJson,
+          participantsJson,
+          startTimeMs,
+          endTimeMs,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceGenerateMeetingNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_meeting_notes",
+        argNames: [
+          "meetingId",
+          "segmentsJson",
+          "participantsJson",
+          "startTimeMs",
+          "endTimeMs",
+        ],
+      );
+
+  @override
+  Future<WebRtcConfig> crateApiCallWebrtcGenerateWebrtcConfig({
+    required String callId,
+    required bool forceRelay,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(callId, serializer);
+          sse_encode_bool(forceRelay, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_rtc_config,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallWebrtcGenerateWebrtcConfigConstMeta,
+        argValues: [callId, forceRelay],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallWebrtcGenerateWebrtcConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_webrtc_config",
+        argNames: ["callId", "forceRelay"],
+      );
+
+  @override
+  Future<List<CallSession>> crateApiCallSessionGetActiveCalls() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_call_session,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallSessionGetActiveCallsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallSessionGetActiveCallsConstMeta =>
+      const TaskConstMeta(debugName: "get_active_calls", argNames: []);
+
+  @override
+  Future<AdaptiveBitrateConfig> crateApiCallQualityGetAdaptiveBitrateConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_adaptive_bitrate_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallQualityGetAdaptiveBitrateConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallQualityGetAdaptiveBitrateConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_adaptive_bitrate_config",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiAppStateGetArchivedGroupIds() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateGetArchivedGroupIdsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateGetArchivedGroupIdsConstMeta =>
+      const TaskConstMeta(debugName: "get_archived_group_ids", argNames: []);
+
+  @override
+  Future<AudioConstraints> crateApiCallQualityGetAudioConstraints({
+    required AudioMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_audio_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_audio_constraints,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallQualityGetAudioConstraintsConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallQualityGetAudioConstraintsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_audio_constraints",
+        argNames: ["mode"],
+      );
+
+  @override
+  Future<List<ContactInfo>> crateApiContactsGetCachedContacts() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_contact_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiContactsGetCachedContactsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsGetCachedContactsConstMeta =>
+      const TaskConstMeta(debugName: "get_cached_contacts", argNames: []);
+
+  @override
+  Future<ProfileData> crateApiIdentityGetCachedProfile({
+    required String pubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_profile_data,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiIdentityGetCachedProfileConstMeta,
+        argValues: [pubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIdentityGetCachedProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_profile",
+        argNames: ["pubkeyHex"],
+      );
+
+  @override
+  Future<List<PeerEntry>> crateApiCallWebrtcGetCallParticipants({
+    required String callId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(callId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_entry,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallWebrtcGetCallParticipantsConstMeta,
+        argValues: [callId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallWebrtcGetCallParticipantsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_call_participants",
+        argNames: ["callId"],
+      );
+
+  @override
+  Future<CodecPreferences> crateApiCallQualityGetCodecPreferences() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_codec_preferences,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallQualityGetCodecPreferencesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallQualityGetCodecPreferencesConstMeta =>
+      const TaskConstMeta(debugName: "get_codec_preferences", argNames: []);
+
+  @override
+  BigInt crateApiContactsGetContactsStaleness() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiContactsGetContactsStalenessConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsGetContactsStalenessConstMeta =>
+      const TaskConstMeta(debugName: "get_contacts_staleness", argNames: []);
+
+  @override
+  Future<AccountInfo> crateApiAccountGetCurrentAccount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_account_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAccountGetCurrentAccountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountGetCurrentAccountConstMeta =>
+      const TaskConstMeta(debugName: "get_current_account", argNames: []);
+
+  @override
+  EphemeralKeyStrategy crateApiEphemeralGetEphemeralKeyStrategy() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ephemeral_key_strategy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiEphemeralGetEphemeralKeyStrategyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEphemeralGetEphemeralKeyStrategyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_ephemeral_key_strategy",
+        argNames: [],
+      );
+
+  @override
+  Future<GroupInfo> crateApiGroupGetGroup({required String mlsGroupIdHex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupConstMeta =>
+      const TaskConstMeta(debugName: "get_group", argNames: ["mlsGroupIdHex"]);
+
+  @override
+  Future<List<GroupEpochEvent>> crateApiGroupGetGroupEpochEvents({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_group_epoch_event,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupEpochEventsConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupEpochEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_epoch_events",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<List<EpochInfo>> crateApiGroupGetGroupEpochHistory({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_epoch_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupEpochHistoryConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupEpochHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_epoch_history",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<List<MemberInfo>> crateApiGroupGetGroupMembers({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_member_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupMembersConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupMembersConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_members",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<BigInt?> crateApiGroupGetGroupMessageTtl({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupMessageTtlConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupMessageTtlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_message_ttl",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<String> crateApiGroupGetGroupRawExtension({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupRawExtensionConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupRawExtensionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_raw_extension",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<List<String>> crateApiGroupGetGroupRelays({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupGetGroupRelaysConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupGetGroupRelaysConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_relays",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<String?> crateApiAppStateGetGroupState({
+    required String groupIdHex,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupIdHex, serializer);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateGetGroupStateConstMeta,
+        argValues: [groupIdHex, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateGetGroupStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_state",
+        argNames: ["groupIdHex", "key"],
+      );
+
+  @override
+  Future<GroupSummary> crateApiAppStateGetGroupSummary({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_summary,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateGetGroupSummaryConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateGetGroupSummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_summary",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  BigInt crateApiInviteGetKeyPackageMaxAge() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInviteGetKeyPackageMaxAgeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteGetKeyPackageMaxAgeConstMeta =>
+      const TaskConstMeta(debugName: "get_key_package_max_age", argNames: []);
+
+  @override
+  Future<PlatformInt64?> crateApiContactsGetLastContactsSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiContactsGetLastContactsSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsGetLastContactsSyncConstMeta =>
+      const TaskConstMeta(debugName: "get_last_contacts_sync", argNames: []);
+
+  @override
+  Future<PlatformInt64?> crateApiAppStateGetLastReadTimestamp({
+    required String groupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateGetLastReadTimestampConstMeta,
+        argValues: [groupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateGetLastReadTimestampConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_last_read_timestamp",
+        argNames: ["groupIdHex"],
+      );
+
+  @override
+  int crateApiStateGetMaxConcurrentRelayOps() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStateGetMaxConcurrentRelayOpsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStateGetMaxConcurrentRelayOpsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_max_concurrent_relay_ops",
+        argNames: [],
+      );
+
+  @override
+  int crateApiMessageGetMaxMessageBytes() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMessageGetMaxMessageBytesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGetMaxMessageBytesConstMeta =>
+      const TaskConstMeta(debugName: "get_max_message_bytes", argNames: []);
+
+  @override
+  BigInt crateApiMediaGetMediaCacheLimit() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaGetMediaCacheLimitConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaCacheLimitConstMeta =>
+      const TaskConstMeta(debugName: "get_media_cache_limit", argNames: []);
+
+  @override
+  Future<BigInt> crateApiMediaGetMediaCacheUsage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMediaGetMediaCacheUsageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaCacheUsageConstMeta =>
+      const TaskConstMeta(debugName: "get_media_cache_usage", argNames: []);
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGetMeetingArchive() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGetMeetingArchiveConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceGetMeetingArchiveConstMeta =>
+      const TaskConstMeta(debugName: "get_meeting_archive", argNames: []);
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGetMeetingNotes({
+    required String meetingId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGetMeetingNotesConstMeta,
+        argValues: [meetingId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceGetMeetingNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_meeting_notes",
+        argNames: ["meetingId"],
+      );
+
+  @override
+  Future<GroupMessage> crateApiMessageGetMessage({
+    required String mlsGroupIdHex,
+    required String eventIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(eventIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_message,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageGetMessageConstMeta,
+        argValues: [mlsGroupIdHex, eventIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGetMessageConstMeta => const TaskConstMeta(
+    debugName: "get_message",
+    argNames: ["mlsGroupIdHex", "eventIdHex"],
+  );
+
+  @override
+  Future<List<MessageReaction>> crateApiMessageGetMessageReactions({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(targetEventIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message_reaction,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageGetMessageReactionsConstMeta,
+        argValues: [mlsGroupIdHex, targetEventIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGetMessageReactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_message_reactions",
+        argNames: ["mlsGroupIdHex", "targetEventIdHex"],
+      );
+
+  @override
+  Future<ReceiptSummary> crateApiMessageGetMessageReceipts({
+    required String mlsGroupIdHex,
+    required String eventIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(eventIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_receipt_summary,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageGetMessageReceiptsConstMeta,
+        argValues: [mlsGroupIdHex, eventIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGetMessageReceiptsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_message_receipts",
+        argNames: ["mlsGroupIdHex", "eventIdHex"],
+      );
+
+  @override
+  Future<List<GroupMessage>> crateApiMessageGetMessages({
+    required String mlsGroupIdHex,
+    int? limit,
+    int? offset,
+    bool? includeExpired,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_opt_box_autoadd_bool(includeExpired, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_group_message,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageGetMessagesConstMeta,
+        argValues: [mlsGroupIdHex, limit, offset, includeExpired],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGetMessagesConstMeta => const TaskConstMeta(
+    debugName: "get_messages",
+    argNames: ["mlsGroupIdHex", "limit", "offset", "includeExpired"],
+  );
+
+  @override
+  bool crateApiContactsGetNip05Verification() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiContactsGetNip05VerificationConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsGetNip05VerificationConstMeta =>
+      const TaskConstMeta(debugName: "get_nip05_verification", argNames: []);
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGetOpenActionItems({
+    String? assigneePubkey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(assigneePubkey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGetOpenActionItemsConstMeta,
+        argValues: [assigneePubkey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceGetOpenActionItemsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_open_action_items",
+        argNames: ["assigneePubkey"],
+      );
+
+  @override
+  Future<int> crateApiAppStateGetOutboxCount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateGetOutboxCountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateGetOutboxCountConstMeta =>
+      const TaskConstMeta(debugName: "get_outbox_count", argNames: []);
+
+  @override
+  Future<PeerStats?> crateApiCallWebrtcGetPeerStats({
+    required String participantPubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(participantPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_peer_stats,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallWebrtcGetPeerStatsConstMeta,
+        argValues: [participantPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallWebrtcGetPeerStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_peer_stats",
+        argNames: ["participantPubkeyHex"],
+      );
+
+  @override
+  Future<String> crateApiMeetingIntelligenceGetRedactPatterns() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiMeetingIntelligenceGetRedactPatternsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeetingIntelligenceGetRedactPatternsConstMeta =>
+      const TaskConstMeta(debugName: "get_redact_patterns", argNames: []);
+
+  @override
+  Future<String> crateApiRelayGetRelayRoles() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiRelayGetRelayRolesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelayGetRelayRolesConstMeta =>
+      const TaskConstMeta(debugName: "get_relay_roles", argNames: []);
+
+  @override
+  Future<CallSession?> crateApiCallSessionGetSession({required String callId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(callId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_call_session,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallSessionGetSessionConstMeta,
+        argValues: [callId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallSessionGetSessionConstMeta =>
+      const TaskConstMeta(debugName: "get_session", argNames: ["callId"]);
+
+  @override
+  Future<SfuConfig> crateApiCallWebrtcGetSfuConfig({
+    required String callId,
+    required String localPubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(localPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sfu_config,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallWebrtcGetSfuConfigConstMeta,
+        argValues: [callId, localPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallWebrtcGetSfuConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_sfu_config",
+        argNames: ["callId", "localPubkeyHex"],
+      );
+
+  @override
+  Future<SignerKind> crateApiAccountGetSignerKind() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_signer_kind,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAccountGetSignerKindConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountGetSignerKindConstMeta =>
+      const TaskConstMeta(debugName: "get_signer_kind", argNames: []);
+
+  @override
+  Future<SimulcastConfig> crateApiCallQualityGetSimulcastConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_simulcast_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallQualityGetSimulcastConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallQualityGetSimulcastConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_simulcast_config", argNames: []);
+
+  @override
+  Future<List<TranscriptSegment>> crateApiTranscriptionGetTranscriptSegments() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_transcript_segment,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTranscriptionGetTranscriptSegmentsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranscriptionGetTranscriptSegmentsConstMeta =>
+      const TaskConstMeta(debugName: "get_transcript_segments", argNames: []);
+
+  @override
+  Future<String> crateApiTranscriptionGetTranscriptText() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTranscriptionGetTranscriptTextConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranscriptionGetTranscriptTextConstMeta =>
+      const TaskConstMeta(debugName: "get_transcript_text", argNames: []);
+
+  @override
+  Future<String> crateApiTranscriptionGetTranscriptionStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTranscriptionGetTranscriptionStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranscriptionGetTranscriptionStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_transcription_status", argNames: []);
+
+  @override
+  Future<VideoConstraints> crateApiCallQualityGetVideoConstraints({
+    required VideoQualityPreset preset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_video_quality_preset(preset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_video_constraints,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallQualityGetVideoConstraintsConstMeta,
+        argValues: [preset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallQualityGetVideoConstraintsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_video_constraints",
+        argNames: ["preset"],
+      );
+
+  @override
+  Future<String> crateApiInviteGiftWrapWelcome({
+    required String welcomeRumorJson,
+    required String recipientPubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(welcomeRumorJson, serializer);
+          sse_encode_String(recipientPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiInviteGiftWrapWelcomeConstMeta,
+        argValues: [welcomeRumorJson, recipientPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteGiftWrapWelcomeConstMeta =>
+      const TaskConstMeta(
+        debugName: "gift_wrap_welcome",
+        argNames: ["welcomeRumorJson", "recipientPubkeyHex"],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<String> crateApiMessageGroupMessageFilter({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageGroupMessageFilterConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageGroupMessageFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "group_message_filter",
+        argNames: ["mlsGroupIdHex"],
+      );
+
+  @override
+  Future<bool> crateApiAccountHasKeyringAccount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAccountHasKeyringAccountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountHasKeyringAccountConstMeta =>
+      const TaskConstMeta(debugName: "has_keyring_account", argNames: []);
+
+  @override
+  Future<void> crateApiAppStateImportArchivedGroups({
+    required List<String> groupIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(groupIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateImportArchivedGroupsConstMeta,
+        argValues: [groupIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateImportArchivedGroupsConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_archived_groups",
+        argNames: ["groupIds"],
+      );
+
+  @override
+  Future<int> crateApiContactsImportContactsList({required String eventJson}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(eventJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiContactsImportContactsListConstMeta,
+        argValues: [eventJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsImportContactsListConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_contacts_list",
+        argNames: ["eventJson"],
+      );
+
+  @override
+  Future<AccountInfo> crateApiBackupImportState({
+    required List<int> bundle,
+    required String passphrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(bundle, serializer);
+          sse_encode_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_account_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiBackupImportStateConstMeta,
+        argValues: [bundle, passphrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBackupImportStateConstMeta => const TaskConstMeta(
+    debugName: "import_state",
+    argNames: ["bundle", "passphrase"],
+  );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiStateInitState({required Keys keys}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeys(
+            keys,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiStateInitStateConstMeta,
+        argValues: [keys],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStateInitStateConstMeta =>
+      const TaskConstMeta(debugName: "init_state", argNames: ["keys"]);
+
+  @override
+  Future<void> crateApiTranscriptionInitTranscription({
+    required String modelSize,
+    required String language,
+    required bool translateToEnglish,
+    required bool useGpu,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(modelSize, serializer);
+          sse_encode_String(language, serializer);
+          sse_encode_bool(translateToEnglish, serializer);
+          sse_encode_bool(useGpu, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiTranscriptionInitTranscriptionConstMeta,
+        argValues: [modelSize, language, translateToEnglish, useGpu],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranscriptionInitTranscriptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_transcription",
+        argNames: ["modelSize", "language", "translateToEnglish", "useGpu"],
+      );
+
+  @override
+  Future<void> crateApiStateInitializeKeyringStore() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStateInitializeKeyringStoreConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStateInitializeKeyringStoreConstMeta =>
+      const TaskConstMeta(debugName: "initialize_keyring_store", argNames: []);
+
+  @override
+  Future<String> crateApiCallSignalingInitiateCall({
+    required String sdpOffer,
+    required String callId,
+    required String callType,
+    required String recipientPubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sdpOffer, serializer);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(callType, serializer);
+          sse_encode_String(recipientPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallSignalingInitiateCallConstMeta,
+        argValues: [sdpOffer, callId, callType, recipientPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallSignalingInitiateCallConstMeta =>
+      const TaskConstMeta(
+        debugName: "initiate_call",
+        argNames: ["sdpOffer", "callId", "callType", "recipientPubkeyHex"],
+      );
+
+  @override
+  Future<List<String>> crateApiCallSignalingInitiateCallFragments({
+    required String sdpOffer,
+    required String callId,
+    required String callType,
+    required String recipientPubkeyHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sdpOffer, serializer);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(callType, serializer);
+          sse_encode_String(recipientPubkeyHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiCallSignalingInitiateCallFragmentsConstMeta,
+        argValues: [sdpOffer, callId, callType, recipientPubkeyHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallSignalingInitiateCallFragmentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "initiate_call_fragments",
+        argNames: ["sdpOffer", "callId", "callType", "recipientPubkeyHex"],
+      );
+
+  @override
+  Future<InviteResult> crateApiInviteInviteMembersFull({
+    required String mlsGroupIdHex,
+    required List<String> keyPackageEventsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_String(keyPackageEventsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_invite_result,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiInviteInviteMembersFullConstMeta,
+        argValues: [mlsGroupIdHex, keyPackageEventsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteInviteMembersFullConstMeta =>
+      const TaskConstMeta(
+        debugName: "invite_members_full",
+        argNames: ["mlsGroupIdHex", "keyPackageEventsJson"],
+      );
+
+  @override
+  Future<bool> crateApiAppStateIsGroupArchived({required String groupIdHex}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateIsGroupArchivedConstMeta,
+        argValues: [groupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateIsGroupArchivedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_group_archived",
+        argNames: ["groupIdHex"],
+      );
+
+  @override
+  Future<bool> crateApiStateIsInitialized() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStateIsInitializedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStateIsInitializedConstMeta =>
+      const TaskConstMeta(debugName: "is_initialized", argNames: []);
+
+  @override
+  Future<bool> crateApiAccountIsLoggedIn() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAccountIsLoggedInConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountIsLoggedInConstMeta =>
+      const TaskConstMeta(debugName: "is_logged_in", argNames: []);
+
+  @override
+  bool crateApiMessageIsMessageExpired({
+    required List<List<String>> tags,
+    required BigInt now,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_list_String(tags, serializer);
+          sse_encode_u_64(now, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMessageIsMessageExpiredConstMeta,
+        argValues: [tags, now],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageIsMessageExpiredConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_message_expired",
+        argNames: ["tags", "now"],
+      );
+
+  @override
+  Future<UpdateGroupResult> crateApiGroupLeaveGroup({
+    required String mlsGroupIdHex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_update_group_result,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupLeaveGroupConstMeta,
+        argValues: [mlsGroupIdHex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupLeaveGroupConstMeta => const TaskConstMeta(
+    debugName: "leave_group",
+    argNames: ["mlsGroupIdHex"],
+  );
+
+  @override
+  Future<List<GroupInfo>> crateApiGroupListGroups() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_group_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupListGroupsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupListGroupsConstMeta =>
+      const TaskConstMeta(debugName: "list_groups", argNames: []);
+
+  @override
+  Future<List<GroupInfo>> crateApiGroupListInactiveGroups() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_group_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiGroupListInactiveGroupsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGroupListInactiveGroupsConstMeta =>
+      const TaskConstMeta(debugName: "list_inactive_groups", argNames: []);
+
+  @override
+  Future<List<WelcomeInfo>> crateApiInviteListPendingWelcomes() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_welcome_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiInviteListPendingWelcomesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteListPendingWelcomesConstMeta =>
+      const TaskConstMeta(debugName: "list_pending_welcomes", argNames: []);
+
+  @override
+  Future<List<RelayInfo>> crateApiRelayListRelays() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_relay_info,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiRelayListRelaysConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelayListRelaysConstMeta =>
+      const TaskConstMeta(debugName: "list_relays", argNames: []);
+
+  @override
+  Stream<CallSignalingEvent> crateApiCallSignalingListenForCallEvents() {
+    final sink = RustStreamSink<CallSignalingEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_call_signaling_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 148,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_burrow_error,
+          ),
+          constMeta: kCrateApiCallSignalingListenForCallEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCallSignalingListenForCallEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "listen_for_call_events",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<GroupNotification> crateApiMessageListenForGroupMessages() {
+    final sink = RustStreamSink<GroupNotification>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_group_notification_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 149,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_burrow_error,
+          ),
+          constMeta: kCrateApiMessageListenForGroupMessagesConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMessageListenForGroupMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "listen_for_group_messages",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<GroupMessage> crateApiMessageListenForLocalSends() {
+    final sink = RustStreamSink<GroupMessage>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_group_message_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 150,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_burrow_error,
+          ),
+          constMeta: kCrateApiMessageListenForLocalSendsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcGetPeerStatsConstMeta =>
+  TaskConstMeta get kCrateApiMessageListenForLocalSendsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_peer_stats",
-        argNames: ["participantPubkeyHex"],
+        debugName: "listen_for_local_sends",
+        argNames: ["sink"],
       );
 
   @override
-  Future<CallSession?> crateApiCallSessionGetSession({required String callId}) {
+  Future<AccountInfo> crateApiAccountLoadAccountFromKeyring() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 151,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_call_session,
+          decodeSuccessData: sse_decode_account_info,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSessionGetSessionConstMeta,
-        argValues: [callId],
+        constMeta: kCrateApiAccountLoadAccountFromKeyringConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSessionGetSessionConstMeta =>
-      const TaskConstMeta(debugName: "get_session", argNames: ["callId"]);
+  TaskConstMeta get kCrateApiAccountLoadAccountFromKeyringConstMeta =>
+      const TaskConstMeta(debugName: "load_account_from_keyring", argNames: []);
 
   @override
-  Future<SfuConfig> crateApiCallWebrtcGetSfuConfig({
-    required String callId,
-    required String localPubkeyHex,
-  }) {
+  Future<AccountInfo> crateApiAccountLogin({required String secretKey}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(localPubkeyHex, serializer);
+          sse_encode_String(secretKey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 152,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_sfu_config,
+          decodeSuccessData: sse_decode_account_info,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcGetSfuConfigConstMeta,
-        argValues: [callId, localPubkeyHex],
+        constMeta: kCrateApiAccountLoginConstMeta,
+        argValues: [secretKey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcGetSfuConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_sfu_config",
-        argNames: ["callId", "localPubkeyHex"],
-      );
+  TaskConstMeta get kCrateApiAccountLoginConstMeta =>
+      const TaskConstMeta(debugName: "login", argNames: ["secretKey"]);
 
   @override
-  Future<SimulcastConfig> crateApiCallQualityGetSimulcastConfig() {
+  Future<AccountInfo> crateApiAccountLoginWithBunker({
+    required String bunkerUri,
+    String? appSecretKey,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(bunkerUri, serializer);
+          sse_encode_opt_String(appSecretKey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 153,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_simulcast_config,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_account_info,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallQualityGetSimulcastConfigConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAccountLoginWithBunkerConstMeta,
+        argValues: [bunkerUri, appSecretKey],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityGetSimulcastConfigConstMeta =>
-      const TaskConstMeta(debugName: "get_simulcast_config", argNames: []);
+  TaskConstMeta get kCrateApiAccountLoginWithBunkerConstMeta =>
+      const TaskConstMeta(
+        debugName: "login_with_bunker",
+        argNames: ["bunkerUri", "appSecretKey"],
+      );
 
   @override
-  Future<List<TranscriptSegment>> crateApiTranscriptionGetTranscriptSegments() {
+  Future<void> crateApiAccountLogout() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -3028,361 +6116,342 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 154,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_transcript_segment,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionGetTranscriptSegmentsConstMeta,
+        constMeta: kCrateApiAccountLogoutConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionGetTranscriptSegmentsConstMeta =>
-      const TaskConstMeta(debugName: "get_transcript_segments", argNames: []);
+  TaskConstMeta get kCrateApiAccountLogoutConstMeta =>
+      const TaskConstMeta(debugName: "logout", argNames: []);
 
   @override
-  Future<String> crateApiTranscriptionGetTranscriptText() {
+  Future<void> crateApiAppStateMarkGroupRead({
+    required String groupIdHex,
+    required String lastEventIdHex,
+    required PlatformInt64 timestamp,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(groupIdHex, serializer);
+          sse_encode_String(lastEventIdHex, serializer);
+          sse_encode_i_64(timestamp, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 155,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionGetTranscriptTextConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAppStateMarkGroupReadConstMeta,
+        argValues: [groupIdHex, lastEventIdHex, timestamp],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionGetTranscriptTextConstMeta =>
-      const TaskConstMeta(debugName: "get_transcript_text", argNames: []);
+  TaskConstMeta get kCrateApiAppStateMarkGroupReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_group_read",
+        argNames: ["groupIdHex", "lastEventIdHex", "timestamp"],
+      );
 
   @override
-  Future<String> crateApiTranscriptionGetTranscriptionStatus() {
+  Future<void> crateApiGroupMergePendingCommit({
+    required String mlsGroupIdHex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 156,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionGetTranscriptionStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupMergePendingCommitConstMeta,
+        argValues: [mlsGroupIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionGetTranscriptionStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_transcription_status", argNames: []);
+  TaskConstMeta get kCrateApiGroupMergePendingCommitConstMeta =>
+      const TaskConstMeta(
+        debugName: "merge_pending_commit",
+        argNames: ["mlsGroupIdHex"],
+      );
 
   @override
-  Future<VideoConstraints> crateApiCallQualityGetVideoConstraints({
-    required VideoQualityPreset preset,
-  }) {
+  Future<OutboxFlushResult> crateApiMessageOutboxFlushResultDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_video_quality_preset(preset, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 157,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_video_constraints,
+          decodeSuccessData: sse_decode_outbox_flush_result,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCallQualityGetVideoConstraintsConstMeta,
-        argValues: [preset],
+        constMeta: kCrateApiMessageOutboxFlushResultDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityGetVideoConstraintsConstMeta =>
+  TaskConstMeta get kCrateApiMessageOutboxFlushResultDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "get_video_constraints",
-        argNames: ["preset"],
+        debugName: "outbox_flush_result_default",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiInviteGiftWrapWelcome({
-    required String welcomeRumorJson,
-    required String recipientPubkeyHex,
+  Future<MediaReferenceInfo> crateApiMediaParseImetaTag({
+    required List<String> tagValues,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(welcomeRumorJson, serializer);
-          sse_encode_String(recipientPubkeyHex, serializer);
+          sse_encode_list_String(tagValues, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 158,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_media_reference_info,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteGiftWrapWelcomeConstMeta,
-        argValues: [welcomeRumorJson, recipientPubkeyHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInviteGiftWrapWelcomeConstMeta =>
-      const TaskConstMeta(
-        debugName: "gift_wrap_welcome",
-        argNames: ["welcomeRumorJson", "recipientPubkeyHex"],
-      );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiMediaParseImetaTagConstMeta,
+        argValues: [tagValues],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiMediaParseImetaTagConstMeta => const TaskConstMeta(
+    debugName: "parse_imeta_tag",
+    argNames: ["tagValues"],
+  );
 
   @override
-  Future<String> crateApiMessageGroupMessageFilter({
-    required String mlsGroupIdHex,
-  }) {
+  Future<SdpInfo> crateApiCallWebrtcParseSdpAnswer({required String sdp}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(sdp, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 75,
+            funcId: 159,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_sdp_info,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageGroupMessageFilterConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiCallWebrtcParseSdpAnswerConstMeta,
+        argValues: [sdp],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageGroupMessageFilterConstMeta =>
-      const TaskConstMeta(
-        debugName: "group_message_filter",
-        argNames: ["mlsGroupIdHex"],
-      );
+  TaskConstMeta get kCrateApiCallWebrtcParseSdpAnswerConstMeta =>
+      const TaskConstMeta(debugName: "parse_sdp_answer", argNames: ["sdp"]);
 
   @override
-  Future<bool> crateApiAccountHasKeyringAccount() {
+  Future<SdpInfo> crateApiCallWebrtcParseSdpOffer({required String sdp}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sdp, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 76,
+            funcId: 160,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_sdp_info,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountHasKeyringAccountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCallWebrtcParseSdpOfferConstMeta,
+        argValues: [sdp],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountHasKeyringAccountConstMeta =>
-      const TaskConstMeta(debugName: "has_keyring_account", argNames: []);
+  TaskConstMeta get kCrateApiCallWebrtcParseSdpOfferConstMeta =>
+      const TaskConstMeta(debugName: "parse_sdp_offer", argNames: ["sdp"]);
 
   @override
-  Future<void> crateApiAppStateImportArchivedGroups({
-    required List<String> groupIds,
-  }) {
+  Future<void> crateApiTranscriptionPauseTranscription() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(groupIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 161,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiAppStateImportArchivedGroupsConstMeta,
-        argValues: [groupIds],
+        constMeta: kCrateApiTranscriptionPauseTranscriptionConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateImportArchivedGroupsConstMeta =>
-      const TaskConstMeta(
-        debugName: "import_archived_groups",
-        argNames: ["groupIds"],
-      );
-
+  TaskConstMeta get kCrateApiTranscriptionPauseTranscriptionConstMeta =>
+      const TaskConstMeta(debugName: "pause_transcription", argNames: []);
+
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<CallSignalingEvent?> crateApiCallSignalingProcessCallEvent({
+    required String eventJson,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(eventJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 162,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_opt_box_autoadd_call_signaling_event,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCallSignalingProcessCallEventConstMeta,
+        argValues: [eventJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiCallSignalingProcessCallEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_call_event",
+        argNames: ["eventJson"],
+      );
 
   @override
-  Future<void> crateApiStateInitState({required Keys keys}) {
+  Future<ProcessMessageResult> crateApiMessageProcessMessage({
+    required String eventJson,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeys(
-            keys,
-            serializer,
-          );
+          sse_encode_String(eventJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 79,
+            funcId: 163,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_process_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiStateInitStateConstMeta,
-        argValues: [keys],
+        constMeta: kCrateApiMessageProcessMessageConstMeta,
+        argValues: [eventJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStateInitStateConstMeta =>
-      const TaskConstMeta(debugName: "init_state", argNames: ["keys"]);
+  TaskConstMeta get kCrateApiMessageProcessMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_message",
+        argNames: ["eventJson"],
+      );
 
   @override
-  Future<void> crateApiTranscriptionInitTranscription({
-    required String modelSize,
-    required String language,
-    required bool translateToEnglish,
-    required bool useGpu,
+  Future<WelcomeInfo> crateApiInviteProcessWelcome({
+    required String wrapperEventIdHex,
+    required String welcomeRumorJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(modelSize, serializer);
-          sse_encode_String(language, serializer);
-          sse_encode_bool(translateToEnglish, serializer);
-          sse_encode_bool(useGpu, serializer);
+          sse_encode_String(wrapperEventIdHex, serializer);
+          sse_encode_String(welcomeRumorJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 80,
+            funcId: 164,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_welcome_info,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionInitTranscriptionConstMeta,
-        argValues: [modelSize, language, translateToEnglish, useGpu],
+        constMeta: kCrateApiInviteProcessWelcomeConstMeta,
+        argValues: [wrapperEventIdHex, welcomeRumorJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionInitTranscriptionConstMeta =>
+  TaskConstMeta get kCrateApiInviteProcessWelcomeConstMeta =>
       const TaskConstMeta(
-        debugName: "init_transcription",
-        argNames: ["modelSize", "language", "translateToEnglish", "useGpu"],
+        debugName: "process_welcome",
+        argNames: ["wrapperEventIdHex", "welcomeRumorJson"],
       );
 
   @override
-  Future<void> crateApiStateInitializeKeyringStore() {
+  Future<ProfileData> crateApiIdentityProfileDataDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -3390,209 +6459,227 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 81,
+            funcId: 165,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_profile_data,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiStateInitializeKeyringStoreConstMeta,
+        constMeta: kCrateApiIdentityProfileDataDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStateInitializeKeyringStoreConstMeta =>
-      const TaskConstMeta(debugName: "initialize_keyring_store", argNames: []);
+  TaskConstMeta get kCrateApiIdentityProfileDataDefaultConstMeta =>
+      const TaskConstMeta(debugName: "profile_data_default", argNames: []);
 
   @override
-  Future<String> crateApiCallSignalingInitiateCall({
-    required String sdpOffer,
-    required String callId,
-    required String callType,
-    required String recipientPubkeyHex,
+  Future<UpdateGroupResult> crateApiGroupPromoteAdmin({
+    required String mlsGroupIdHex,
+    required String pubkeyHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sdpOffer, serializer);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(callType, serializer);
-          sse_encode_String(recipientPubkeyHex, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(pubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 166,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_update_group_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSignalingInitiateCallConstMeta,
-        argValues: [sdpOffer, callId, callType, recipientPubkeyHex],
+        constMeta: kCrateApiGroupPromoteAdminConstMeta,
+        argValues: [mlsGroupIdHex, pubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingInitiateCallConstMeta =>
-      const TaskConstMeta(
-        debugName: "initiate_call",
-        argNames: ["sdpOffer", "callId", "callType", "recipientPubkeyHex"],
-      );
+  TaskConstMeta get kCrateApiGroupPromoteAdminConstMeta => const TaskConstMeta(
+    debugName: "promote_admin",
+    argNames: ["mlsGroupIdHex", "pubkeyHex"],
+  );
 
   @override
-  Future<bool> crateApiAppStateIsGroupArchived({required String groupIdHex}) {
+  Future<String> crateApiRelayPublishEventJson({required String eventJson}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
+          sse_encode_String(eventJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 83,
+            funcId: 167,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateIsGroupArchivedConstMeta,
-        argValues: [groupIdHex],
+        constMeta: kCrateApiRelayPublishEventJsonConstMeta,
+        argValues: [eventJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateIsGroupArchivedConstMeta =>
+  TaskConstMeta get kCrateApiRelayPublishEventJsonConstMeta =>
       const TaskConstMeta(
-        debugName: "is_group_archived",
-        argNames: ["groupIdHex"],
+        debugName: "publish_event_json",
+        argNames: ["eventJson"],
       );
 
   @override
-  Future<bool> crateApiStateIsInitialized() {
+  Future<String> crateApiRelayPublishEventJsonToRelay({
+    required String eventJson,
+    required String relayUrl,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(eventJson, serializer);
+          sse_encode_String(relayUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 84,
+            funcId: 168,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiStateIsInitializedConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRelayPublishEventJsonToRelayConstMeta,
+        argValues: [eventJson, relayUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStateIsInitializedConstMeta =>
-      const TaskConstMeta(debugName: "is_initialized", argNames: []);
+  TaskConstMeta get kCrateApiRelayPublishEventJsonToRelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "publish_event_json_to_relay",
+        argNames: ["eventJson", "relayUrl"],
+      );
 
   @override
-  Future<bool> crateApiAccountIsLoggedIn() {
+  Future<String> crateApiGroupPublishEvolutionEvent({
+    required String evolutionEventJson,
+    required List<String> relayUrls,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(evolutionEventJson, serializer);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 169,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountIsLoggedInConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupPublishEvolutionEventConstMeta,
+        argValues: [evolutionEventJson, relayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountIsLoggedInConstMeta =>
-      const TaskConstMeta(debugName: "is_logged_in", argNames: []);
+  TaskConstMeta get kCrateApiGroupPublishEvolutionEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "publish_evolution_event",
+        argNames: ["evolutionEventJson", "relayUrls"],
+      );
 
   @override
-  Future<UpdateGroupResult> crateApiGroupLeaveGroup({
-    required String mlsGroupIdHex,
+  Future<String> crateApiKeypackagePublishKeyPackage({
+    required List<String> relayUrls,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 170,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_update_group_result,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupLeaveGroupConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiKeypackagePublishKeyPackageConstMeta,
+        argValues: [relayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupLeaveGroupConstMeta => const TaskConstMeta(
-    debugName: "leave_group",
-    argNames: ["mlsGroupIdHex"],
-  );
+  TaskConstMeta get kCrateApiKeypackagePublishKeyPackageConstMeta =>
+      const TaskConstMeta(
+        debugName: "publish_key_package",
+        argNames: ["relayUrls"],
+      );
 
   @override
-  Future<List<GroupInfo>> crateApiGroupListGroups() {
+  Future<String> crateApiKeypackagePublishKeyPackageRelays({
+    required List<String> relayUrls,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(relayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 87,
+            funcId: 171,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_group_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiGroupListGroupsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiKeypackagePublishKeyPackageRelaysConstMeta,
+        argValues: [relayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupListGroupsConstMeta =>
-      const TaskConstMeta(debugName: "list_groups", argNames: []);
+  TaskConstMeta get kCrateApiKeypackagePublishKeyPackageRelaysConstMeta =>
+      const TaskConstMeta(
+        debugName: "publish_key_package_relays",
+        argNames: ["relayUrls"],
+      );
 
   @override
-  Future<List<WelcomeInfo>> crateApiInviteListPendingWelcomes() {
+  Future<int> crateApiMessagePurgeExpiredMessages() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -3600,595 +6687,639 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 88,
+            funcId: 172,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_welcome_info,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteListPendingWelcomesConstMeta,
+        constMeta: kCrateApiMessagePurgeExpiredMessagesConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteListPendingWelcomesConstMeta =>
-      const TaskConstMeta(debugName: "list_pending_welcomes", argNames: []);
+  TaskConstMeta get kCrateApiMessagePurgeExpiredMessagesConstMeta =>
+      const TaskConstMeta(debugName: "purge_expired_messages", argNames: []);
 
   @override
-  Future<List<RelayInfo>> crateApiRelayListRelays() {
+  Future<int> crateApiMeetingIntelligencePushTranscriptSegment({
+    required String meetingId,
+    required String segmentJson,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(meetingId, serializer);
+          sse_encode_String(segmentJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 89,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_relay_info,
-          decodeErrorData: sse_decode_burrow_error,
-        ),
-        constMeta: kCrateApiRelayListRelaysConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiRelayListRelaysConstMeta =>
-      const TaskConstMeta(debugName: "list_relays", argNames: []);
-
-  @override
-  Stream<CallSignalingEvent> crateApiCallSignalingListenForCallEvents() {
-    final sink = RustStreamSink<CallSignalingEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_call_signaling_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 90,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_burrow_error,
-          ),
-          constMeta: kCrateApiCallSignalingListenForCallEventsConstMeta,
-          argValues: [sink],
-          apiImpl: this,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
         ),
+        constMeta: kCrateApiMeetingIntelligencePushTranscriptSegmentConstMeta,
+        argValues: [meetingId, segmentJson],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCallSignalingListenForCallEventsConstMeta =>
+  TaskConstMeta
+  get kCrateApiMeetingIntelligencePushTranscriptSegmentConstMeta =>
       const TaskConstMeta(
-        debugName: "listen_for_call_events",
-        argNames: ["sink"],
+        debugName: "push_transcript_segment",
+        argNames: ["meetingId", "segmentJson"],
       );
 
   @override
-  Stream<GroupNotification> crateApiMessageListenForGroupMessages() {
-    final sink = RustStreamSink<GroupNotification>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_group_notification_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 91,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_burrow_error,
-          ),
-          constMeta: kCrateApiMessageListenForGroupMessagesConstMeta,
-          argValues: [sink],
-          apiImpl: this,
+  Future<int> crateApiCallWebrtcReapStalePeers({required BigInt maxAgeSecs}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(maxAgeSecs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
         ),
+        constMeta: kCrateApiCallWebrtcReapStalePeersConstMeta,
+        argValues: [maxAgeSecs],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiMessageListenForGroupMessagesConstMeta =>
+  TaskConstMeta get kCrateApiCallWebrtcReapStalePeersConstMeta =>
       const TaskConstMeta(
-        debugName: "listen_for_group_messages",
-        argNames: ["sink"],
+        debugName: "reap_stale_peers",
+        argNames: ["maxAgeSecs"],
       );
 
   @override
-  Future<AccountInfo> crateApiAccountLoadAccountFromKeyring() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiMessageReassembleMessage({required List<GroupMessage> parts}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
+          sse_encode_list_group_message(parts, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 92,
-            port: port_,
-          );
+            funcId: 175,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountLoadAccountFromKeyringConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessageReassembleMessageConstMeta,
+        argValues: [parts],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountLoadAccountFromKeyringConstMeta =>
-      const TaskConstMeta(debugName: "load_account_from_keyring", argNames: []);
+  TaskConstMeta get kCrateApiMessageReassembleMessageConstMeta =>
+      const TaskConstMeta(debugName: "reassemble_message", argNames: ["parts"]);
 
   @override
-  Future<AccountInfo> crateApiAccountLogin({required String secretKey}) {
+  bool crateApiCallWebrtcRecommendForceRelay({required int previousFailures}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(previousFailures, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCallWebrtcRecommendForceRelayConstMeta,
+        argValues: [previousFailures],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCallWebrtcRecommendForceRelayConstMeta =>
+      const TaskConstMeta(
+        debugName: "recommend_force_relay",
+        argNames: ["previousFailures"],
+      );
+
+  @override
+  Future<JitterBufferConfig> crateApiCallQualityRecommendJitterBuffer({
+    required double measuredJitterMs,
+    required double rttMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(secretKey, serializer);
+          sse_encode_f_64(measuredJitterMs, serializer);
+          sse_encode_f_64(rttMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 93,
+            funcId: 177,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_account_info,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_jitter_buffer_config,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAccountLoginConstMeta,
-        argValues: [secretKey],
+        constMeta: kCrateApiCallQualityRecommendJitterBufferConstMeta,
+        argValues: [measuredJitterMs, rttMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountLoginConstMeta =>
-      const TaskConstMeta(debugName: "login", argNames: ["secretKey"]);
+  TaskConstMeta get kCrateApiCallQualityRecommendJitterBufferConstMeta =>
+      const TaskConstMeta(
+        debugName: "recommend_jitter_buffer",
+        argNames: ["measuredJitterMs", "rttMs"],
+      );
 
   @override
-  Future<void> crateApiAccountLogout() {
+  Future<QualityRecommendation> crateApiCallQualityRecommendQualityPreset({
+    required double qualityScore,
+    required double estimatedBandwidthKbps,
+    required int participantCount,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_64(qualityScore, serializer);
+          sse_encode_f_64(estimatedBandwidthKbps, serializer);
+          sse_encode_u_32(participantCount, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 94,
+            funcId: 178,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_quality_recommendation,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAccountLogoutConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCallQualityRecommendQualityPresetConstMeta,
+        argValues: [qualityScore, estimatedBandwidthKbps, participantCount],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountLogoutConstMeta =>
-      const TaskConstMeta(debugName: "logout", argNames: []);
+  TaskConstMeta get kCrateApiCallQualityRecommendQualityPresetConstMeta =>
+      const TaskConstMeta(
+        debugName: "recommend_quality_preset",
+        argNames: [
+          "qualityScore",
+          "estimatedBandwidthKbps",
+          "participantCount",
+        ],
+      );
 
   @override
-  Future<void> crateApiAppStateMarkGroupRead({
-    required String groupIdHex,
-    required String lastEventIdHex,
-    required PlatformInt64 timestamp,
-  }) {
+  Future<ReconcileReport> crateApiGroupReconcileGroups() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
-          sse_encode_String(lastEventIdHex, serializer);
-          sse_encode_i_64(timestamp, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 95,
+            funcId: 179,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_reconcile_report,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAppStateMarkGroupReadConstMeta,
-        argValues: [groupIdHex, lastEventIdHex, timestamp],
+        constMeta: kCrateApiGroupReconcileGroupsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateMarkGroupReadConstMeta =>
-      const TaskConstMeta(
-        debugName: "mark_group_read",
-        argNames: ["groupIdHex", "lastEventIdHex", "timestamp"],
-      );
+  TaskConstMeta get kCrateApiGroupReconcileGroupsConstMeta =>
+      const TaskConstMeta(debugName: "reconcile_groups", argNames: []);
 
   @override
-  Future<void> crateApiGroupMergePendingCommit({
-    required String mlsGroupIdHex,
+  Future<void> crateApiTranscriptionRegisterSpeaker({
+    required String trackId,
+    required String pubkeyHex,
+    required String displayName,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(trackId, serializer);
+          sse_encode_String(pubkeyHex, serializer);
+          sse_encode_String(displayName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 96,
+            funcId: 180,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiGroupMergePendingCommitConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiTranscriptionRegisterSpeakerConstMeta,
+        argValues: [trackId, pubkeyHex, displayName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupMergePendingCommitConstMeta =>
+  TaskConstMeta get kCrateApiTranscriptionRegisterSpeakerConstMeta =>
       const TaskConstMeta(
-        debugName: "merge_pending_commit",
-        argNames: ["mlsGroupIdHex"],
+        debugName: "register_speaker",
+        argNames: ["trackId", "pubkeyHex", "displayName"],
       );
 
   @override
-  Future<MediaReferenceInfo> crateApiMediaParseImetaTag({
-    required List<String> tagValues,
+  Future<String> crateApiCallSignalingRejectCall({
+    required String callId,
+    required String callerPubkeyHex,
+    String? reason,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(tagValues, serializer);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(callerPubkeyHex, serializer);
+          sse_encode_opt_String(reason, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 97,
+            funcId: 181,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_reference_info,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMediaParseImetaTagConstMeta,
-        argValues: [tagValues],
+        constMeta: kCrateApiCallSignalingRejectCallConstMeta,
+        argValues: [callId, callerPubkeyHex, reason],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaParseImetaTagConstMeta => const TaskConstMeta(
-    debugName: "parse_imeta_tag",
-    argNames: ["tagValues"],
-  );
+  TaskConstMeta get kCrateApiCallSignalingRejectCallConstMeta =>
+      const TaskConstMeta(
+        debugName: "reject_call",
+        argNames: ["callId", "callerPubkeyHex", "reason"],
+      );
 
   @override
-  Future<SdpInfo> crateApiCallWebrtcParseSdpAnswer({required String sdp}) {
+  Future<List<String>> crateApiGroupRelaysForEvolution({
+    required String mlsGroupIdHex,
+    required List<String> proposedRelayUrls,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sdp, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_String(proposedRelayUrls, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 98,
+            funcId: 182,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_sdp_info,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcParseSdpAnswerConstMeta,
-        argValues: [sdp],
+        constMeta: kCrateApiGroupRelaysForEvolutionConstMeta,
+        argValues: [mlsGroupIdHex, proposedRelayUrls],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcParseSdpAnswerConstMeta =>
-      const TaskConstMeta(debugName: "parse_sdp_answer", argNames: ["sdp"]);
+  TaskConstMeta get kCrateApiGroupRelaysForEvolutionConstMeta =>
+      const TaskConstMeta(
+        debugName: "relays_for_evolution",
+        argNames: ["mlsGroupIdHex", "proposedRelayUrls"],
+      );
 
   @override
-  Future<SdpInfo> crateApiCallWebrtcParseSdpOffer({required String sdp}) {
+  Future<void> crateApiCallWebrtcRemoveCallPeers({required String callId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(sdp, serializer);
+          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 99,
+            funcId: 183,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_sdp_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcParseSdpOfferConstMeta,
-        argValues: [sdp],
+        constMeta: kCrateApiCallWebrtcRemoveCallPeersConstMeta,
+        argValues: [callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcParseSdpOfferConstMeta =>
-      const TaskConstMeta(debugName: "parse_sdp_offer", argNames: ["sdp"]);
+  TaskConstMeta get kCrateApiCallWebrtcRemoveCallPeersConstMeta =>
+      const TaskConstMeta(debugName: "remove_call_peers", argNames: ["callId"]);
 
   @override
-  Future<void> crateApiTranscriptionPauseTranscription() {
+  Future<UpdateGroupResult> crateApiGroupRemoveGroupImage({
+    required String mlsGroupIdHex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 184,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_update_group_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionPauseTranscriptionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiGroupRemoveGroupImageConstMeta,
+        argValues: [mlsGroupIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionPauseTranscriptionConstMeta =>
-      const TaskConstMeta(debugName: "pause_transcription", argNames: []);
+  TaskConstMeta get kCrateApiGroupRemoveGroupImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_group_image",
+        argNames: ["mlsGroupIdHex"],
+      );
 
   @override
-  Future<CallSignalingEvent?> crateApiCallSignalingProcessCallEvent({
-    required String eventJson,
+  Future<UpdateGroupResult> crateApiInviteRemoveMembers({
+    required String mlsGroupIdHex,
+    required List<String> pubkeysHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(eventJson, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_String(pubkeysHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 101,
+            funcId: 185,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_call_signaling_event,
+          decodeSuccessData: sse_decode_update_group_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSignalingProcessCallEventConstMeta,
-        argValues: [eventJson],
+        constMeta: kCrateApiInviteRemoveMembersConstMeta,
+        argValues: [mlsGroupIdHex, pubkeysHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingProcessCallEventConstMeta =>
+  TaskConstMeta get kCrateApiInviteRemoveMembersConstMeta =>
       const TaskConstMeta(
-        debugName: "process_call_event",
-        argNames: ["eventJson"],
+        debugName: "remove_members",
+        argNames: ["mlsGroupIdHex", "pubkeysHex"],
       );
 
   @override
-  Future<ProcessMessageResult> crateApiMessageProcessMessage({
-    required String eventJson,
-  }) {
+  Future<void> crateApiRelayRemoveRelay({required String url}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(eventJson, serializer);
+          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 102,
+            funcId: 186,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_process_message_result,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageProcessMessageConstMeta,
-        argValues: [eventJson],
+        constMeta: kCrateApiRelayRemoveRelayConstMeta,
+        argValues: [url],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageProcessMessageConstMeta =>
-      const TaskConstMeta(
-        debugName: "process_message",
-        argNames: ["eventJson"],
-      );
+  TaskConstMeta get kCrateApiRelayRemoveRelayConstMeta =>
+      const TaskConstMeta(debugName: "remove_relay", argNames: ["url"]);
 
   @override
-  Future<WelcomeInfo> crateApiInviteProcessWelcome({
-    required String wrapperEventIdHex,
-    required String welcomeRumorJson,
-  }) {
+  Future<void> crateApiCallSessionRemoveSession({required String callId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(wrapperEventIdHex, serializer);
-          sse_encode_String(welcomeRumorJson, serializer);
+          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 103,
+            funcId: 187,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_welcome_info,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteProcessWelcomeConstMeta,
-        argValues: [wrapperEventIdHex, welcomeRumorJson],
+        constMeta: kCrateApiCallSessionRemoveSessionConstMeta,
+        argValues: [callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteProcessWelcomeConstMeta =>
-      const TaskConstMeta(
-        debugName: "process_welcome",
-        argNames: ["wrapperEventIdHex", "welcomeRumorJson"],
-      );
+  TaskConstMeta get kCrateApiCallSessionRemoveSessionConstMeta =>
+      const TaskConstMeta(debugName: "remove_session", argNames: ["callId"]);
 
   @override
-  Future<ProfileData> crateApiIdentityProfileDataDefault() {
+  Future<PeerStats> crateApiCallWebrtcReportPeerStats({
+    required String participantPubkeyHex,
+    double? rttMs,
+    double? packetLossPercent,
+    double? outgoingBitrateKbps,
+    double? incomingBitrateKbps,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(participantPubkeyHex, serializer);
+          sse_encode_opt_box_autoadd_f_64(rttMs, serializer);
+          sse_encode_opt_box_autoadd_f_64(packetLossPercent, serializer);
+          sse_encode_opt_box_autoadd_f_64(outgoingBitrateKbps, serializer);
+          sse_encode_opt_box_autoadd_f_64(incomingBitrateKbps, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 104,
+            funcId: 188,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_profile_data,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_peer_stats,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiIdentityProfileDataDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCallWebrtcReportPeerStatsConstMeta,
+        argValues: [
+          participantPubkeyHex,
+          rttMs,
+          packetLossPercent,
+          outgoingBitrateKbps,
+          incomingBitrateKbps,
+        ],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIdentityProfileDataDefaultConstMeta =>
-      const TaskConstMeta(debugName: "profile_data_default", argNames: []);
+  TaskConstMeta get kCrateApiCallWebrtcReportPeerStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_peer_stats",
+        argNames: [
+          "participantPubkeyHex",
+          "rttMs",
+          "packetLossPercent",
+          "outgoingBitrateKbps",
+          "incomingBitrateKbps",
+        ],
+      );
 
   @override
-  Future<String> crateApiRelayPublishEventJson({required String eventJson}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiCallSignalingResetCallSignalingState({required String callId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(eventJson, serializer);
-          pdeCallFfi(
+          sse_encode_String(callId, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 105,
-            port: port_,
-          );
+            funcId: 189,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiRelayPublishEventJsonConstMeta,
-        argValues: [eventJson],
+        constMeta: kCrateApiCallSignalingResetCallSignalingStateConstMeta,
+        argValues: [callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayPublishEventJsonConstMeta =>
+  TaskConstMeta get kCrateApiCallSignalingResetCallSignalingStateConstMeta =>
       const TaskConstMeta(
-        debugName: "publish_event_json",
-        argNames: ["eventJson"],
+        debugName: "reset_call_signaling_state",
+        argNames: ["callId"],
       );
 
   @override
-  Future<String> crateApiRelayPublishEventJsonToRelay({
-    required String eventJson,
-    required String relayUrl,
-  }) {
+  Future<void> crateApiTranscriptionResumeTranscription() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(eventJson, serializer);
-          sse_encode_String(relayUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 106,
+            funcId: 190,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiRelayPublishEventJsonToRelayConstMeta,
-        argValues: [eventJson, relayUrl],
+        constMeta: kCrateApiTranscriptionResumeTranscriptionConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayPublishEventJsonToRelayConstMeta =>
-      const TaskConstMeta(
-        debugName: "publish_event_json_to_relay",
-        argNames: ["eventJson", "relayUrl"],
-      );
+  TaskConstMeta get kCrateApiTranscriptionResumeTranscriptionConstMeta =>
+      const TaskConstMeta(debugName: "resume_transcription", argNames: []);
 
   @override
-  Future<String> crateApiKeypackagePublishKeyPackage({
-    required List<String> relayUrls,
+  Future<String> crateApiCallWebrtcRotateFrameKey({
+    required String exporterSecretHex,
+    required BigInt newEpoch,
+    required String callId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(relayUrls, serializer);
+          sse_encode_String(exporterSecretHex, serializer);
+          sse_encode_u_64(newEpoch, serializer);
+          sse_encode_String(callId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 107,
+            funcId: 191,
             port: port_,
           );
         },
@@ -4196,571 +7327,598 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiKeypackagePublishKeyPackageConstMeta,
-        argValues: [relayUrls],
+        constMeta: kCrateApiCallWebrtcRotateFrameKeyConstMeta,
+        argValues: [exporterSecretHex, newEpoch, callId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiKeypackagePublishKeyPackageConstMeta =>
+  TaskConstMeta get kCrateApiCallWebrtcRotateFrameKeyConstMeta =>
       const TaskConstMeta(
-        debugName: "publish_key_package",
-        argNames: ["relayUrls"],
+        debugName: "rotate_frame_key",
+        argNames: ["exporterSecretHex", "newEpoch", "callId"],
       );
 
   @override
-  Future<String> crateApiKeypackagePublishKeyPackageRelays({
-    required List<String> relayUrls,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiSimpleRustLibVersion() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(relayUrls, serializer);
-          pdeCallFfi(
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 108,
-            port: port_,
-          );
+            funcId: 192,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiKeypackagePublishKeyPackageRelaysConstMeta,
-        argValues: [relayUrls],
+        constMeta: kCrateApiSimpleRustLibVersionConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiKeypackagePublishKeyPackageRelaysConstMeta =>
-      const TaskConstMeta(
-        debugName: "publish_key_package_relays",
-        argNames: ["relayUrls"],
-      );
+  TaskConstMeta get kCrateApiSimpleRustLibVersionConstMeta =>
+      const TaskConstMeta(debugName: "rust_lib_version", argNames: []);
 
   @override
-  Future<QualityRecommendation> crateApiCallQualityRecommendQualityPreset({
-    required double qualityScore,
-    required double estimatedBandwidthKbps,
-    required int participantCount,
-  }) {
+  Future<void> crateApiAccountSaveSecretKeyToKeyring() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_f_64(qualityScore, serializer);
-          sse_encode_f_64(estimatedBandwidthKbps, serializer);
-          sse_encode_u_32(participantCount, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 109,
+            funcId: 193,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_quality_recommendation,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallQualityRecommendQualityPresetConstMeta,
-        argValues: [qualityScore, estimatedBandwidthKbps, participantCount],
+        constMeta: kCrateApiAccountSaveSecretKeyToKeyringConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallQualityRecommendQualityPresetConstMeta =>
+  TaskConstMeta get kCrateApiAccountSaveSecretKeyToKeyringConstMeta =>
       const TaskConstMeta(
-        debugName: "recommend_quality_preset",
-        argNames: [
-          "qualityScore",
-          "estimatedBandwidthKbps",
-          "participantCount",
-        ],
+        debugName: "save_secret_key_to_keyring",
+        argNames: [],
       );
 
   @override
-  Future<void> crateApiTranscriptionRegisterSpeaker({
-    required String trackId,
-    required String pubkeyHex,
-    required String displayName,
+  Future<List<ContactInfo>> crateApiContactsSearchContacts({
+    required String query,
+    int? limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackId, serializer);
-          sse_encode_String(pubkeyHex, serializer);
-          sse_encode_String(displayName, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 110,
+            funcId: 194,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_list_contact_info,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionRegisterSpeakerConstMeta,
-        argValues: [trackId, pubkeyHex, displayName],
+        constMeta: kCrateApiContactsSearchContactsConstMeta,
+        argValues: [query, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionRegisterSpeakerConstMeta =>
+  TaskConstMeta get kCrateApiContactsSearchContactsConstMeta =>
       const TaskConstMeta(
-        debugName: "register_speaker",
-        argNames: ["trackId", "pubkeyHex", "displayName"],
+        debugName: "search_contacts",
+        argNames: ["query", "limit"],
       );
 
   @override
-  Future<String> crateApiCallSignalingRejectCall({
-    required String callId,
-    required String callerPubkeyHex,
-    String? reason,
+  Future<String> crateApiMeetingIntelligenceSearchMeetings({
+    required String query,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
-          sse_encode_String(callerPubkeyHex, serializer);
-          sse_encode_opt_String(reason, serializer);
+          sse_encode_String(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 111,
+            funcId: 195,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiCallSignalingRejectCallConstMeta,
-        argValues: [callId, callerPubkeyHex, reason],
+        constMeta: kCrateApiMeetingIntelligenceSearchMeetingsConstMeta,
+        argValues: [query],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingRejectCallConstMeta =>
-      const TaskConstMeta(
-        debugName: "reject_call",
-        argNames: ["callId", "callerPubkeyHex", "reason"],
-      );
+  TaskConstMeta get kCrateApiMeetingIntelligenceSearchMeetingsConstMeta =>
+      const TaskConstMeta(debugName: "search_meetings", argNames: ["query"]);
 
   @override
-  Future<void> crateApiCallWebrtcRemoveCallPeers({required String callId}) {
+  Future<List<GroupMessage>> crateApiMessageSearchMessages({
+    String? mlsGroupIdHex,
+    required String query,
+    int? limit,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(callId, serializer);
+          sse_encode_opt_String(mlsGroupIdHex, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 112,
+            funcId: 196,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_group_message,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcRemoveCallPeersConstMeta,
-        argValues: [callId],
+        constMeta: kCrateApiMessageSearchMessagesConstMeta,
+        argValues: [mlsGroupIdHex, query, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcRemoveCallPeersConstMeta =>
-      const TaskConstMeta(debugName: "remove_call_peers", argNames: ["callId"]);
+  TaskConstMeta get kCrateApiMessageSearchMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_messages",
+        argNames: ["mlsGroupIdHex", "query", "limit"],
+      );
 
   @override
-  Future<UpdateGroupResult> crateApiGroupRemoveGroupImage({
-    required String mlsGroupIdHex,
+  Future<List<TranscriptSegment>> crateApiTranscriptionSearchTranscript({
+    required String query,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 113,
+            funcId: 197,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_update_group_result,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_list_transcript_segment,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiGroupRemoveGroupImageConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiTranscriptionSearchTranscriptConstMeta,
+        argValues: [query],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGroupRemoveGroupImageConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_group_image",
-        argNames: ["mlsGroupIdHex"],
-      );
+  TaskConstMeta get kCrateApiTranscriptionSearchTranscriptConstMeta =>
+      const TaskConstMeta(debugName: "search_transcript", argNames: ["query"]);
 
   @override
-  Future<UpdateGroupResult> crateApiInviteRemoveMembers({
-    required String mlsGroupIdHex,
-    required List<String> pubkeysHex,
-  }) {
+  Future<SendMessageResult> crateApiMessageSend({required String requestJson}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_list_String(pubkeysHex, serializer);
+          sse_encode_String(requestJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 114,
+            funcId: 198,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_update_group_result,
+          decodeSuccessData: sse_decode_send_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiInviteRemoveMembersConstMeta,
-        argValues: [mlsGroupIdHex, pubkeysHex],
+        constMeta: kCrateApiMessageSendConstMeta,
+        argValues: [requestJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInviteRemoveMembersConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_members",
-        argNames: ["mlsGroupIdHex", "pubkeysHex"],
-      );
+  TaskConstMeta get kCrateApiMessageSendConstMeta =>
+      const TaskConstMeta(debugName: "send", argNames: ["requestJson"]);
 
   @override
-  Future<void> crateApiRelayRemoveRelay({required String url}) {
+  Future<String> crateApiCallSignalingSendCallStateUpdate({
+    required String callId,
+    required String remotePubkeyHex,
+    bool? isMuted,
+    bool? isVideoEnabled,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
+          sse_encode_String(callId, serializer);
+          sse_encode_String(remotePubkeyHex, serializer);
+          sse_encode_opt_box_autoadd_bool(isMuted, serializer);
+          sse_encode_opt_box_autoadd_bool(isVideoEnabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 115,
+            funcId: 199,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiRelayRemoveRelayConstMeta,
-        argValues: [url],
+        constMeta: kCrateApiCallSignalingSendCallStateUpdateConstMeta,
+        argValues: [callId, remotePubkeyHex, isMuted, isVideoEnabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRelayRemoveRelayConstMeta =>
-      const TaskConstMeta(debugName: "remove_relay", argNames: ["url"]);
+  TaskConstMeta get kCrateApiCallSignalingSendCallStateUpdateConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_call_state_update",
+        argNames: ["callId", "remotePubkeyHex", "isMuted", "isVideoEnabled"],
+      );
 
   @override
-  Future<void> crateApiCallSessionRemoveSession({required String callId}) {
+  Future<String> crateApiCallSignalingSendIceCandidate({
+    required String candidate,
+    String? sdpMid,
+    int? sdpMLineIndex,
+    required String callId,
+    required String remotePubkeyHex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(candidate, serializer);
+          sse_encode_opt_String(sdpMid, serializer);
+          sse_encode_opt_box_autoadd_u_32(sdpMLineIndex, serializer);
           sse_encode_String(callId, serializer);
+          sse_encode_String(remotePubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 116,
+            funcId: 200,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSessionRemoveSessionConstMeta,
-        argValues: [callId],
+        constMeta: kCrateApiCallSignalingSendIceCandidateConstMeta,
+        argValues: [candidate, sdpMid, sdpMLineIndex, callId, remotePubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSessionRemoveSessionConstMeta =>
-      const TaskConstMeta(debugName: "remove_session", argNames: ["callId"]);
+  TaskConstMeta get kCrateApiCallSignalingSendIceCandidateConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_ice_candidate",
+        argNames: [
+          "candidate",
+          "sdpMid",
+          "sdpMLineIndex",
+          "callId",
+          "remotePubkeyHex",
+        ],
+      );
 
   @override
-  Future<PeerStats> crateApiCallWebrtcReportPeerStats({
-    required String participantPubkeyHex,
-    double? rttMs,
-    double? packetLossPercent,
-    double? outgoingBitrateKbps,
-    double? incomingBitrateKbps,
+  Future<List<SendMessageResult>> crateApiMessageSendLongMessage({
+    required String mlsGroupIdHex,
+    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(participantPubkeyHex, serializer);
-          sse_encode_opt_box_autoadd_f_64(rttMs, serializer);
-          sse_encode_opt_box_autoadd_f_64(packetLossPercent, serializer);
-          sse_encode_opt_box_autoadd_f_64(outgoingBitrateKbps, serializer);
-          sse_encode_opt_box_autoadd_f_64(incomingBitrateKbps, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 117,
+            funcId: 201,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_peer_stats,
+          decodeSuccessData: sse_decode_list_send_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcReportPeerStatsConstMeta,
-        argValues: [
-          participantPubkeyHex,
-          rttMs,
-          packetLossPercent,
-          outgoingBitrateKbps,
-          incomingBitrateKbps,
-        ],
+        constMeta: kCrateApiMessageSendLongMessageConstMeta,
+        argValues: [mlsGroupIdHex, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcReportPeerStatsConstMeta =>
+  TaskConstMeta get kCrateApiMessageSendLongMessageConstMeta =>
       const TaskConstMeta(
-        debugName: "report_peer_stats",
-        argNames: [
-          "participantPubkeyHex",
-          "rttMs",
-          "packetLossPercent",
-          "outgoingBitrateKbps",
-          "incomingBitrateKbps",
-        ],
+        debugName: "send_long_message",
+        argNames: ["mlsGroupIdHex", "content"],
       );
 
   @override
-  Future<void> crateApiTranscriptionResumeTranscription() {
+  Future<SendMessageResult> crateApiMessageSendMessage({
+    required String mlsGroupIdHex,
+    required String content,
+    BigInt? ttlSecs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_opt_box_autoadd_u_64(ttlSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 118,
+            funcId: 202,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_send_message_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionResumeTranscriptionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessageSendMessageConstMeta,
+        argValues: [mlsGroupIdHex, content, ttlSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionResumeTranscriptionConstMeta =>
-      const TaskConstMeta(debugName: "resume_transcription", argNames: []);
+  TaskConstMeta get kCrateApiMessageSendMessageConstMeta => const TaskConstMeta(
+    debugName: "send_message",
+    argNames: ["mlsGroupIdHex", "content", "ttlSecs"],
+  );
 
   @override
-  Future<String> crateApiCallWebrtcRotateFrameKey({
-    required String currentKeyHex,
-    required BigInt newEpoch,
-    required String callId,
+  Future<SendMessageResult> crateApiMessageSendMessageWithMedia({
+    required String mlsGroupIdHex,
+    required String content,
+    required List<List<String>> imetaTagsJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(currentKeyHex, serializer);
-          sse_encode_u_64(newEpoch, serializer);
-          sse_encode_String(callId, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_list_list_String(imetaTagsJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 119,
+            funcId: 203,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_send_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallWebrtcRotateFrameKeyConstMeta,
-        argValues: [currentKeyHex, newEpoch, callId],
+        constMeta: kCrateApiMessageSendMessageWithMediaConstMeta,
+        argValues: [mlsGroupIdHex, content, imetaTagsJson],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallWebrtcRotateFrameKeyConstMeta =>
+  TaskConstMeta get kCrateApiMessageSendMessageWithMediaConstMeta =>
       const TaskConstMeta(
-        debugName: "rotate_frame_key",
-        argNames: ["currentKeyHex", "newEpoch", "callId"],
+        debugName: "send_message_with_media",
+        argNames: ["mlsGroupIdHex", "content", "imetaTagsJson"],
       );
 
   @override
-  String crateApiSimpleRustLibVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<SendMessageResult> crateApiMessageSendPoll({
+    required String mlsGroupIdHex,
+    required String question,
+    required List<String> options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(question, serializer);
+          sse_encode_list_String(options, serializer);
+          pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 120,
-          )!;
+            funcId: 204,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_send_message_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiSimpleRustLibVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessageSendPollConstMeta,
+        argValues: [mlsGroupIdHex, question, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleRustLibVersionConstMeta =>
-      const TaskConstMeta(debugName: "rust_lib_version", argNames: []);
+  TaskConstMeta get kCrateApiMessageSendPollConstMeta => const TaskConstMeta(
+    debugName: "send_poll",
+    argNames: ["mlsGroupIdHex", "question", "options"],
+  );
 
   @override
-  Future<void> crateApiAccountSaveSecretKeyToKeyring() {
+  Future<SendMessageResult> crateApiMessageSendPollVote({
+    required String mlsGroupIdHex,
+    required String pollEventIdHex,
+    required int optionIndex,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(pollEventIdHex, serializer);
+          sse_encode_u_32(optionIndex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 121,
+            funcId: 205,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_send_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiAccountSaveSecretKeyToKeyringConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMessageSendPollVoteConstMeta,
+        argValues: [mlsGroupIdHex, pollEventIdHex, optionIndex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountSaveSecretKeyToKeyringConstMeta =>
+  TaskConstMeta get kCrateApiMessageSendPollVoteConstMeta =>
       const TaskConstMeta(
-        debugName: "save_secret_key_to_keyring",
-        argNames: [],
+        debugName: "send_poll_vote",
+        argNames: ["mlsGroupIdHex", "pollEventIdHex", "optionIndex"],
       );
 
   @override
-  Future<String> crateApiMeetingIntelligenceSearchMeetings({
-    required String query,
+  Future<SendMessageResult> crateApiMessageSendReaction({
+    required String mlsGroupIdHex,
+    required String targetEventIdHex,
+    required String emoji,
+    String? customEmojiUrl,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(targetEventIdHex, serializer);
+          sse_encode_String(emoji, serializer);
+          sse_encode_opt_String(customEmojiUrl, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 122,
+            funcId: 206,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_send_message_result,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMeetingIntelligenceSearchMeetingsConstMeta,
-        argValues: [query],
+        constMeta: kCrateApiMessageSendReactionConstMeta,
+        argValues: [mlsGroupIdHex, targetEventIdHex, emoji, customEmojiUrl],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMeetingIntelligenceSearchMeetingsConstMeta =>
-      const TaskConstMeta(debugName: "search_meetings", argNames: ["query"]);
+  TaskConstMeta get kCrateApiMessageSendReactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_reaction",
+        argNames: [
+          "mlsGroupIdHex",
+          "targetEventIdHex",
+          "emoji",
+          "customEmojiUrl",
+        ],
+      );
 
   @override
-  Future<List<TranscriptSegment>> crateApiTranscriptionSearchTranscript({
-    required String query,
+  Future<String> crateApiMessageSendReadReceipt({
+    required String mlsGroupIdHex,
+    required List<String> messageEventIds,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_String(messageEventIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 123,
+            funcId: 207,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_transcript_segment,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiTranscriptionSearchTranscriptConstMeta,
-        argValues: [query],
+        constMeta: kCrateApiMessageSendReadReceiptConstMeta,
+        argValues: [mlsGroupIdHex, messageEventIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscriptionSearchTranscriptConstMeta =>
-      const TaskConstMeta(debugName: "search_transcript", argNames: ["query"]);
+  TaskConstMeta get kCrateApiMessageSendReadReceiptConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_read_receipt",
+        argNames: ["mlsGroupIdHex", "messageEventIds"],
+      );
 
   @override
-  Future<String> crateApiCallSignalingSendCallStateUpdate({
+  Future<String> crateApiCallSignalingSendRenegotiationAnswer({
     required String callId,
-    required String remotePubkeyHex,
-    bool? isMuted,
-    bool? isVideoEnabled,
+    required String sdp,
+    required String peerPubkeyHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(callId, serializer);
-          sse_encode_String(remotePubkeyHex, serializer);
-          sse_encode_opt_box_autoadd_bool(isMuted, serializer);
-          sse_encode_opt_box_autoadd_bool(isVideoEnabled, serializer);
+          sse_encode_String(sdp, serializer);
+          sse_encode_String(peerPubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 124,
+            funcId: 208,
             port: port_,
           );
         },
@@ -4768,40 +7926,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSignalingSendCallStateUpdateConstMeta,
-        argValues: [callId, remotePubkeyHex, isMuted, isVideoEnabled],
+        constMeta: kCrateApiCallSignalingSendRenegotiationAnswerConstMeta,
+        argValues: [callId, sdp, peerPubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingSendCallStateUpdateConstMeta =>
+  TaskConstMeta get kCrateApiCallSignalingSendRenegotiationAnswerConstMeta =>
       const TaskConstMeta(
-        debugName: "send_call_state_update",
-        argNames: ["callId", "remotePubkeyHex", "isMuted", "isVideoEnabled"],
+        debugName: "send_renegotiation_answer",
+        argNames: ["callId", "sdp", "peerPubkeyHex"],
       );
 
   @override
-  Future<String> crateApiCallSignalingSendIceCandidate({
-    required String candidate,
-    String? sdpMid,
-    int? sdpMLineIndex,
+  Future<String> crateApiCallSignalingSendRenegotiationOffer({
     required String callId,
-    required String remotePubkeyHex,
+    required String sdp,
+    required String peerPubkeyHex,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(candidate, serializer);
-          sse_encode_opt_String(sdpMid, serializer);
-          sse_encode_opt_box_autoadd_u_32(sdpMLineIndex, serializer);
           sse_encode_String(callId, serializer);
-          sse_encode_String(remotePubkeyHex, serializer);
+          sse_encode_String(sdp, serializer);
+          sse_encode_String(peerPubkeyHex, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 125,
+            funcId: 209,
             port: port_,
           );
         },
@@ -4809,40 +7963,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiCallSignalingSendIceCandidateConstMeta,
-        argValues: [candidate, sdpMid, sdpMLineIndex, callId, remotePubkeyHex],
+        constMeta: kCrateApiCallSignalingSendRenegotiationOfferConstMeta,
+        argValues: [callId, sdp, peerPubkeyHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCallSignalingSendIceCandidateConstMeta =>
+  TaskConstMeta get kCrateApiCallSignalingSendRenegotiationOfferConstMeta =>
       const TaskConstMeta(
-        debugName: "send_ice_candidate",
-        argNames: [
-          "candidate",
-          "sdpMid",
-          "sdpMLineIndex",
-          "callId",
-          "remotePubkeyHex",
-        ],
+        debugName: "send_renegotiation_offer",
+        argNames: ["callId", "sdp", "peerPubkeyHex"],
       );
 
   @override
-  Future<SendMessageResult> crateApiMessageSendMessage({
+  Future<SendMessageResult> crateApiMessageSendReply({
     required String mlsGroupIdHex,
+    required String replyToEventIdHex,
     required String content,
+    BigInt? ttlSecs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(replyToEventIdHex, serializer);
           sse_encode_String(content, serializer);
+          sse_encode_opt_box_autoadd_u_64(ttlSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 126,
+            funcId: 210,
             port: port_,
           );
         },
@@ -4850,261 +8002,331 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_send_message_result,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageSendMessageConstMeta,
-        argValues: [mlsGroupIdHex, content],
+        constMeta: kCrateApiMessageSendReplyConstMeta,
+        argValues: [mlsGroupIdHex, replyToEventIdHex, content, ttlSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendMessageConstMeta => const TaskConstMeta(
-    debugName: "send_message",
-    argNames: ["mlsGroupIdHex", "content"],
+  TaskConstMeta get kCrateApiMessageSendReplyConstMeta => const TaskConstMeta(
+    debugName: "send_reply",
+    argNames: ["mlsGroupIdHex", "replyToEventIdHex", "content", "ttlSecs"],
   );
 
   @override
-  Future<SendMessageResult> crateApiMessageSendMessageWithMedia({
+  Future<String> crateApiMessageSendTypingIndicator({
     required String mlsGroupIdHex,
-    required String content,
-    required List<List<String>> imetaTagsJson,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(content, serializer);
-          sse_encode_list_list_String(imetaTagsJson, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 127,
+            funcId: 211,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_send_message_result,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageSendMessageWithMediaConstMeta,
-        argValues: [mlsGroupIdHex, content, imetaTagsJson],
+        constMeta: kCrateApiMessageSendTypingIndicatorConstMeta,
+        argValues: [mlsGroupIdHex],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendMessageWithMediaConstMeta =>
+  TaskConstMeta get kCrateApiMessageSendTypingIndicatorConstMeta =>
       const TaskConstMeta(
-        debugName: "send_message_with_media",
-        argNames: ["mlsGroupIdHex", "content", "imetaTagsJson"],
+        debugName: "send_typing_indicator",
+        argNames: ["mlsGroupIdHex"],
       );
 
   @override
-  Future<SendMessageResult> crateApiMessageSendPoll({
-    required String mlsGroupIdHex,
-    required String question,
-    required List<String> options,
-  }) {
+  void crateApiContactsSetContactsStaleness({required BigInt secs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(secs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 212,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiContactsSetContactsStalenessConstMeta,
+        argValues: [secs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsSetContactsStalenessConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_contacts_staleness",
+        argNames: ["secs"],
+      );
+
+  @override
+  Future<void> crateApiStateSetDataDir({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(question, serializer);
-          sse_encode_list_String(options, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 128,
+            funcId: 213,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_send_message_result,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMessageSendPollConstMeta,
-        argValues: [mlsGroupIdHex, question, options],
+        constMeta: kCrateApiStateSetDataDirConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendPollConstMeta => const TaskConstMeta(
-    debugName: "send_poll",
-    argNames: ["mlsGroupIdHex", "question", "options"],
-  );
+  TaskConstMeta get kCrateApiStateSetDataDirConstMeta =>
+      const TaskConstMeta(debugName: "set_data_dir", argNames: ["path"]);
 
   @override
-  Future<SendMessageResult> crateApiMessageSendPollVote({
-    required String mlsGroupIdHex,
-    required String pollEventIdHex,
-    required int optionIndex,
+  void crateApiEphemeralSetEphemeralKeyStrategy({
+    required EphemeralKeyStrategy strategy,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(pollEventIdHex, serializer);
-          sse_encode_u_32(optionIndex, serializer);
-          pdeCallFfi(
+          sse_encode_ephemeral_key_strategy(strategy, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 129,
-            port: port_,
-          );
+            funcId: 214,
+          )!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_send_message_result,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMessageSendPollVoteConstMeta,
-        argValues: [mlsGroupIdHex, pollEventIdHex, optionIndex],
+        constMeta: kCrateApiEphemeralSetEphemeralKeyStrategyConstMeta,
+        argValues: [strategy],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendPollVoteConstMeta =>
+  TaskConstMeta get kCrateApiEphemeralSetEphemeralKeyStrategyConstMeta =>
       const TaskConstMeta(
-        debugName: "send_poll_vote",
-        argNames: ["mlsGroupIdHex", "pollEventIdHex", "optionIndex"],
+        debugName: "set_ephemeral_key_strategy",
+        argNames: ["strategy"],
       );
 
   @override
-  Future<SendMessageResult> crateApiMessageSendReaction({
+  Future<void> crateApiGroupSetGroupMessageTtl({
     required String mlsGroupIdHex,
-    required String targetEventIdHex,
-    required String emoji,
+    BigInt? ttlSecs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mlsGroupIdHex, serializer);
-          sse_encode_String(targetEventIdHex, serializer);
-          sse_encode_String(emoji, serializer);
+          sse_encode_opt_box_autoadd_u_64(ttlSecs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 130,
+            funcId: 215,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_send_message_result,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_burrow_error,
         ),
-        constMeta: kCrateApiMessageSendReactionConstMeta,
-        argValues: [mlsGroupIdHex, targetEventIdHex, emoji],
+        constMeta: kCrateApiGroupSetGroupMessageTtlConstMeta,
+        argValues: [mlsGroupIdHex, ttlSecs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendReactionConstMeta =>
+  TaskConstMeta get kCrateApiGroupSetGroupMessageTtlConstMeta =>
       const TaskConstMeta(
-        debugName: "send_reaction",
-        argNames: ["mlsGroupIdHex", "targetEventIdHex", "emoji"],
+        debugName: "set_group_message_ttl",
+        argNames: ["mlsGroupIdHex", "ttlSecs"],
       );
 
   @override
-  Future<String> crateApiMessageSendTypingIndicator({
-    required String mlsGroupIdHex,
+  Future<void> crateApiAppStateSetGroupState({
+    required String groupIdHex,
+    required String key,
+    required String value,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_String(groupIdHex, serializer);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 131,
+            funcId: 216,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiAppStateSetGroupStateConstMeta,
+        argValues: [groupIdHex, key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAppStateSetGroupStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_group_state",
+        argNames: ["groupIdHex", "key", "value"],
+      );
+
+  @override
+  void crateApiInviteSetKeyPackageMaxAge({required BigInt secs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(secs, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 217,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInviteSetKeyPackageMaxAgeConstMeta,
+        argValues: [secs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInviteSetKeyPackageMaxAgeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_key_package_max_age",
+        argNames: ["secs"],
+      );
+
+  @override
+  void crateApiStateSetMaxConcurrentRelayOps({required int n}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(n, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 218,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMessageSendTypingIndicatorConstMeta,
-        argValues: [mlsGroupIdHex],
+        constMeta: kCrateApiStateSetMaxConcurrentRelayOpsConstMeta,
+        argValues: [n],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMessageSendTypingIndicatorConstMeta =>
+  TaskConstMeta get kCrateApiStateSetMaxConcurrentRelayOpsConstMeta =>
       const TaskConstMeta(
-        debugName: "send_typing_indicator",
-        argNames: ["mlsGroupIdHex"],
+        debugName: "set_max_concurrent_relay_ops",
+        argNames: ["n"],
       );
 
   @override
-  Future<void> crateApiStateSetDataDir({required String path}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiMessageSetMaxMessageBytes({required int maxBytes}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
+          sse_encode_u_32(maxBytes, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 132,
-            port: port_,
-          );
+            funcId: 219,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiStateSetDataDirConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiMessageSetMaxMessageBytesConstMeta,
+        argValues: [maxBytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStateSetDataDirConstMeta =>
-      const TaskConstMeta(debugName: "set_data_dir", argNames: ["path"]);
+  TaskConstMeta get kCrateApiMessageSetMaxMessageBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_max_message_bytes",
+        argNames: ["maxBytes"],
+      );
 
   @override
-  Future<void> crateApiAppStateSetGroupState({
-    required String groupIdHex,
-    required String key,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateApiMediaSetMediaCacheLimit({required BigInt bytes}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(groupIdHex, serializer);
-          sse_encode_String(key, serializer);
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
+          sse_encode_u_64(bytes, serializer);
+          return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 133,
-            port: port_,
-          );
+            funcId: 220,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_burrow_error,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAppStateSetGroupStateConstMeta,
-        argValues: [groupIdHex, key, value],
+        constMeta: kCrateApiMediaSetMediaCacheLimitConstMeta,
+        argValues: [bytes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAppStateSetGroupStateConstMeta =>
+  TaskConstMeta get kCrateApiMediaSetMediaCacheLimitConstMeta =>
       const TaskConstMeta(
-        debugName: "set_group_state",
-        argNames: ["groupIdHex", "key", "value"],
+        debugName: "set_media_cache_limit",
+        argNames: ["bytes"],
       );
 
   @override
@@ -5121,7 +8343,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 134,
+            funcId: 221,
             port: port_,
           );
         },
@@ -5142,6 +8364,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["callId", "muted"],
       );
 
+  @override
+  void crateApiContactsSetNip05Verification({required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 222,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiContactsSetNip05VerificationConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiContactsSetNip05VerificationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_nip05_verification",
+        argNames: ["enabled"],
+      );
+
   @override
   Future<void> crateApiIdentitySetProfile({required ProfileData profile}) {
     return handler.executeNormal(
@@ -5152,7 +8404,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 135,
+            funcId: 223,
             port: port_,
           );
         },
@@ -5170,6 +8422,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiIdentitySetProfileConstMeta =>
       const TaskConstMeta(debugName: "set_profile", argNames: ["profile"]);
 
+  @override
+  Future<void> crateApiRelaySetRelayRoles({required String rolesJson}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(rolesJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 224,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiRelaySetRelayRolesConstMeta,
+        argValues: [rolesJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRelaySetRelayRolesConstMeta => const TaskConstMeta(
+    debugName: "set_relay_roles",
+    argNames: ["rolesJson"],
+  );
+
   @override
   Future<CallSession> crateApiCallSessionSetVideoEnabled({
     required String callId,
@@ -5184,7 +8466,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 136,
+            funcId: 225,
             port: port_,
           );
         },
@@ -5205,6 +8487,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["callId", "enabled"],
       );
 
+  @override
+  bool crateApiMediaShouldUseChunkedEncryption({required BigInt fileSize}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(fileSize, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 226,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaShouldUseChunkedEncryptionConstMeta,
+        argValues: [fileSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaShouldUseChunkedEncryptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "should_use_chunked_encryption",
+        argNames: ["fileSize"],
+      );
+
   @override
   Future<bool> crateApiCallWebrtcShouldUseSfu({required int participantCount}) {
     return handler.executeNormal(
@@ -5215,7 +8527,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 137,
+            funcId: 227,
             port: port_,
           );
         },
@@ -5236,6 +8548,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["participantCount"],
       );
 
+  @override
+  List<String> crateApiMessageSplitMessageContent({
+    required String content,
+    required int maxBytes,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(content, serializer);
+          sse_encode_u_32(maxBytes, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 228,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMessageSplitMessageContentConstMeta,
+        argValues: [content, maxBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageSplitMessageContentConstMeta =>
+      const TaskConstMeta(
+        debugName: "split_message_content",
+        argNames: ["content", "maxBytes"],
+      );
+
   @override
   Future<void> crateApiTranscriptionStartTranscription({
     required String callId,
@@ -5248,7 +8594,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 138,
+            funcId: 229,
             port: port_,
           );
         },
@@ -5278,7 +8624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 139,
+            funcId: 230,
             port: port_,
           );
         },
@@ -5305,7 +8651,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 140,
+            funcId: 231,
             port: port_,
           );
         },
@@ -5324,15 +8670,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "subscribe_call_events", argNames: []);
 
   @override
-  Future<List<ContactInfo>> crateApiContactsSyncContacts() {
+  Future<List<ContactInfo>> crateApiContactsSyncContacts({
+    required bool forceRefresh,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(forceRefresh, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 141,
+            funcId: 232,
             port: port_,
           );
         },
@@ -5341,14 +8690,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_burrow_error,
         ),
         constMeta: kCrateApiContactsSyncContactsConstMeta,
-        argValues: [],
+        argValues: [forceRefresh],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta get kCrateApiContactsSyncContactsConstMeta =>
-      const TaskConstMeta(debugName: "sync_contacts", argNames: []);
+      const TaskConstMeta(
+        debugName: "sync_contacts",
+        argNames: ["forceRefresh"],
+      );
 
   @override
   Future<int> crateApiMessageSyncGroupMessages() {
@@ -5359,7 +8711,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 142,
+            funcId: 233,
             port: port_,
           );
         },
@@ -5386,7 +8738,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 143,
+            funcId: 234,
             port: port_,
           );
         },
@@ -5418,7 +8770,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 144,
+            funcId: 235,
             port: port_,
           );
         },
@@ -5449,7 +8801,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 145,
+            funcId: 236,
             port: port_,
           );
         },
@@ -5480,7 +8832,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 146,
+            funcId: 237,
             port: port_,
           );
         },
@@ -5511,7 +8863,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 147,
+            funcId: 238,
             port: port_,
           );
         },
@@ -5546,7 +8898,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 148,
+            funcId: 239,
             port: port_,
           );
         },
@@ -5581,7 +8933,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 149,
+            funcId: 240,
             port: port_,
           );
         },
@@ -5616,7 +8968,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 150,
+            funcId: 241,
             port: port_,
           );
         },
@@ -5653,7 +9005,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 151,
+            funcId: 242,
             port: port_,
           );
         },
@@ -5688,7 +9040,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 152,
+            funcId: 243,
             port: port_,
           );
         },
@@ -5727,7 +9079,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 153,
+            funcId: 244,
             port: port_,
           );
         },
@@ -5773,7 +9125,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 154,
+            funcId: 245,
             port: port_,
           );
         },
@@ -5805,6 +9157,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     ],
   );
 
+  @override
+  Future<List<BatchUploadItem>> crateApiMediaUploadMediaBatch({
+    required String mlsGroupIdHex,
+    required List<FileInput> files,
+    required String blossomServerUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mlsGroupIdHex, serializer);
+          sse_encode_list_file_input(files, serializer);
+          sse_encode_String(blossomServerUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 246,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_batch_upload_item,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMediaUploadMediaBatchConstMeta,
+        argValues: [mlsGroupIdHex, files, blossomServerUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaUploadMediaBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "upload_media_batch",
+        argNames: ["mlsGroupIdHex", "files", "blossomServerUrl"],
+      );
+
   @override
   Future<String> crateApiIdentityUploadProfilePhoto({
     required List<int> fileData,
@@ -5821,7 +9210,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 155,
+            funcId: 247,
             port: port_,
           );
         },
@@ -5842,6 +9231,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["fileData", "mimeType", "blossomServerUrl"],
       );
 
+  @override
+  Future<void> crateApiMessageValidateReaction({
+    required String content,
+    String? customEmojiUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(content, serializer);
+          sse_encode_opt_String(customEmojiUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 248,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_burrow_error,
+        ),
+        constMeta: kCrateApiMessageValidateReactionConstMeta,
+        argValues: [content, customEmojiUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMessageValidateReactionConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_reaction",
+        argNames: ["content", "customEmojiUrl"],
+      );
+
   @override
   Future<bool> crateApiRelayVerifyEventPublished({required String eventIdHex}) {
     return handler.executeNormal(
@@ -5852,7 +9276,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 156,
+            funcId: 249,
             port: port_,
           );
         },
@@ -5912,6 +9336,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<GroupMessage> dco_decode_StreamSink_group_message_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<GroupNotification>
   dco_decode_StreamSink_group_notification_Sse(dynamic raw) {
@@ -5999,6 +9431,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AudioMode.values[raw as int];
   }
 
+  @protected
+  BatchUploadItem dco_decode_batch_upload_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BatchUploadItem(
+      filename: dco_decode_String(arr[0]),
+      result: dco_decode_opt_box_autoadd_upload_media_result(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6023,12 +9468,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_call_signaling_event(raw);
   }
 
+  @protected
+  DiagnosticsReport dco_decode_box_autoadd_diagnostics_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_diagnostics_report(raw);
+  }
+
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as double;
   }
 
+  @protected
+  GroupInfo dco_decode_box_autoadd_group_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_info(raw);
+  }
+
   @protected
   GroupMessage dco_decode_box_autoadd_group_message(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6053,6 +9510,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_profile_data(raw);
   }
 
+  @protected
+  ThumbnailReference dco_decode_box_autoadd_thumbnail_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thumbnail_reference(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6065,6 +9528,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  UploadMediaResult dco_decode_box_autoadd_upload_media_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_upload_media_result(raw);
+  }
+
   @protected
   BurrowError dco_decode_burrow_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6107,8 +9576,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   CallSignalingEvent dco_decode_call_signaling_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return CallSignalingEvent(
       kind: dco_decode_u_32(arr[0]),
       senderPubkeyHex: dco_decode_String(arr[1]),
@@ -6116,6 +9585,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callType: dco_decode_opt_String(arr[3]),
       content: dco_decode_String(arr[4]),
       createdAt: dco_decode_u_64(arr[5]),
+      renegotiate: dco_decode_bool(arr[6]),
+      sequence: dco_decode_u_64(arr[7]),
     );
   }
 
@@ -6131,6 +9602,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return CallType.values[raw as int];
   }
 
+  @protected
+  ChunkedEncryptResult dco_decode_chunked_encrypt_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ChunkedEncryptResult(
+      outputPath: dco_decode_String(arr[0]),
+      originalHashHex: dco_decode_String(arr[1]),
+      encryptedHashHex: dco_decode_String(arr[2]),
+      mimeType: dco_decode_String(arr[3]),
+      filename: dco_decode_String(arr[4]),
+      originalSize: dco_decode_u_64(arr[5]),
+      encryptedSize: dco_decode_u_64(arr[6]),
+      nonceHex: dco_decode_String(arr[7]),
+      frameSize: dco_decode_u_32(arr[8]),
+      schemeVersion: dco_decode_String(arr[9]),
+    );
+  }
+
   @protected
   CodecPreferences dco_decode_codec_preferences(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6147,12 +9638,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ContactInfo dco_decode_contact_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return ContactInfo(
       pubkeyHex: dco_decode_String(arr[0]),
       displayName: dco_decode_opt_String(arr[1]),
       picture: dco_decode_opt_String(arr[2]),
+      nip05: dco_decode_opt_String(arr[3]),
+      nip05Verified: dco_decode_bool(arr[4]),
     );
   }
 
@@ -6185,6 +9678,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CreateOrGetDmResult dco_decode_create_or_get_dm_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CreateOrGetDmResult(
+      group: dco_decode_group_info(arr[0]),
+      welcomeRumorsJson: dco_decode_list_String(arr[1]),
+      mlsGroupIdHex: dco_decode_String(arr[2]),
+      reused: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  DiagnosticsReport dco_decode_diagnostics_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return DiagnosticsReport(
+      generatedAt: dco_decode_u_64(arr[0]),
+      coreVersion: dco_decode_String(arr[1]),
+      initialized: dco_decode_bool(arr[2]),
+      pubkeyHex: dco_decode_opt_String(arr[3]),
+      relays: dco_decode_list_relay_diagnostics(arr[4]),
+      groupCount: dco_decode_u_32(arr[5]),
+      groups: dco_decode_list_group_diagnostics(arr[6]),
+      pendingWelcomeCount: dco_decode_u_32(arr[7]),
+      lastContactsSync: dco_decode_opt_box_autoadd_i_64(arr[8]),
+      appDbInitialized: dco_decode_bool(arr[9]),
+      appDbSchemaVersion: dco_decode_opt_box_autoadd_u_32(arr[10]),
+      errorCounts: dco_decode_list_error_count(arr[11]),
+    );
+  }
+
+  @protected
+  EncryptedFileOnDisk dco_decode_encrypted_file_on_disk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return EncryptedFileOnDisk(
+      encryptedPath: dco_decode_String(arr[0]),
+      originalHashHex: dco_decode_String(arr[1]),
+      encryptedHashHex: dco_decode_String(arr[2]),
+      mimeType: dco_decode_String(arr[3]),
+      filename: dco_decode_String(arr[4]),
+      originalSize: dco_decode_u_64(arr[5]),
+      encryptedSize: dco_decode_u_64(arr[6]),
+      dimensions: dco_decode_opt_String(arr[7]),
+      blurhash: dco_decode_opt_String(arr[8]),
+      nonceHex: dco_decode_String(arr[9]),
+      schemeVersion: dco_decode_String(arr[10]),
+    );
+  }
+
   @protected
   EncryptedFileResult dco_decode_encrypted_file_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6205,6 +9755,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  EphemeralKeyStrategy dco_decode_ephemeral_key_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EphemeralKeyStrategy.values[raw as int];
+  }
+
+  @protected
+  EpochInfo dco_decode_epoch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EpochInfo(
+      epoch: dco_decode_u_64(arr[0]),
+      recordedAt: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      committerPubkeyHex: dco_decode_opt_String(arr[2]),
+      commitEventIdHex: dco_decode_opt_String(arr[3]),
+      addedPubkeys: dco_decode_list_String(arr[4]),
+      removedPubkeys: dco_decode_list_String(arr[5]),
+      metadataChanged: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  ErrorCount dco_decode_error_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ErrorCount(
+      subsystem: dco_decode_String(arr[0]),
+      count: dco_decode_u_32(arr[1]),
+      lastErrorAt: dco_decode_u_64(arr[2]),
+      lastErrorKind: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   double dco_decode_f_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6217,12 +9804,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  FileInput dco_decode_file_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FileInput(
+      fileData: dco_decode_list_prim_u_8_strict(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+      filename: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  GroupDiagnostics dco_decode_group_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return GroupDiagnostics(
+      mlsGroupIdHex: dco_decode_String(arr[0]),
+      nostrGroupIdHex: dco_decode_String(arr[1]),
+      epoch: dco_decode_u_64(arr[2]),
+      state: dco_decode_String(arr[3]),
+      memberCount: dco_decode_u_32(arr[4]),
+      relayCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  GroupEpochEvent dco_decode_group_epoch_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return GroupEpochEvent(
+      epoch: dco_decode_u_64(arr[0]),
+      changeType: dco_decode_String(arr[1]),
+      pubkeys: dco_decode_list_String(arr[2]),
+      timestamp: dco_decode_u_64(arr[3]),
+      committerPubkeyHex: dco_decode_opt_String(arr[4]),
+    );
+  }
+
   @protected
   GroupInfo dco_decode_group_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return GroupInfo(
       mlsGroupIdHex: dco_decode_String(arr[0]),
       nostrGroupIdHex: dco_decode_String(arr[1]),
@@ -6238,6 +9869,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       dmPeerPubkeyHex: dco_decode_opt_String(arr[11]),
       imageHashHex: dco_decode_opt_String(arr[12]),
       hasImage: dco_decode_bool(arr[13]),
+      defaultMessageTtlSecs: dco_decode_opt_box_autoadd_u_64(arr[14]),
     );
   }
 
@@ -6245,8 +9877,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   GroupMessage dco_decode_group_message(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return GroupMessage(
       eventIdHex: dco_decode_String(arr[0]),
       authorPubkeyHex: dco_decode_String(arr[1]),
@@ -6257,6 +9889,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       tags: dco_decode_list_list_String(arr[6]),
       wrapperEventIdHex: dco_decode_String(arr[7]),
       epoch: dco_decode_u_64(arr[8]),
+      mentionsMe: dco_decode_bool(arr[9]),
+      editedFrom: dco_decode_opt_String(arr[10]),
+      replyToEventIdHex: dco_decode_opt_String(arr[11]),
+      replyToAuthorHex: dco_decode_opt_String(arr[12]),
     );
   }
 
@@ -6264,12 +9900,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   GroupNotification dco_decode_group_notification(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return GroupNotification(
       notificationType: dco_decode_String(arr[0]),
       message: dco_decode_opt_box_autoadd_group_message(arr[1]),
       mlsGroupIdHex: dco_decode_String(arr[2]),
+      addedPubkeys: dco_decode_list_String(arr[3]),
+      removedPubkeys: dco_decode_list_String(arr[4]),
+      senderPubkeyHex: dco_decode_opt_String(arr[5]),
     );
   }
 
@@ -6312,6 +9951,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  InviteResult dco_decode_invite_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return InviteResult(
+      mlsGroupIdHex: dco_decode_String(arr[0]),
+      evolutionEventIdHex: dco_decode_String(arr[1]),
+      welcomeEventIdsHex: dco_decode_list_String(arr[2]),
+      failedWelcomePubkeysHex: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  JitterBufferConfig dco_decode_jitter_buffer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return JitterBufferConfig(
+      targetMs: dco_decode_u_32(arr[0]),
+      minMs: dco_decode_u_32(arr[1]),
+      maxMs: dco_decode_u_32(arr[2]),
+      reason: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   KeyPackageData dco_decode_key_package_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6330,6 +9997,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<BatchUploadItem> dco_decode_list_batch_upload_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_upload_item).toList();
+  }
+
   @protected
   List<CallSession> dco_decode_list_call_session(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6342,6 +10015,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_contact_info).toList();
   }
 
+  @protected
+  List<EpochInfo> dco_decode_list_epoch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_epoch_info).toList();
+  }
+
+  @protected
+  List<ErrorCount> dco_decode_list_error_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_error_count).toList();
+  }
+
+  @protected
+  List<FileInput> dco_decode_list_file_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_file_input).toList();
+  }
+
+  @protected
+  List<GroupDiagnostics> dco_decode_list_group_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_group_diagnostics).toList();
+  }
+
+  @protected
+  List<GroupEpochEvent> dco_decode_list_group_epoch_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_group_epoch_event).toList();
+  }
+
   @protected
   List<GroupInfo> dco_decode_list_group_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6372,6 +10075,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_member_info).toList();
   }
 
+  @protected
+  List<MessageReaction> dco_decode_list_message_reaction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_reaction).toList();
+  }
+
+  @protected
+  List<MessageReader> dco_decode_list_message_reader(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_reader).toList();
+  }
+
   @protected
   List<PeerEntry> dco_decode_list_peer_entry(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6402,12 +10117,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as Uint8List;
   }
 
+  @protected
+  List<RelayDiagnostics> dco_decode_list_relay_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_relay_diagnostics).toList();
+  }
+
   @protected
   List<RelayInfo> dco_decode_list_relay_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_relay_info).toList();
   }
 
+  @protected
+  List<SendMessageResult> dco_decode_list_send_message_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_send_message_result).toList();
+  }
+
   @protected
   List<SimulcastLayer> dco_decode_list_simulcast_layer(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6430,8 +10157,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   MediaReferenceInfo dco_decode_media_reference_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
     return MediaReferenceInfo(
       url: dco_decode_String(arr[0]),
       originalHashHex: dco_decode_String(arr[1]),
@@ -6440,6 +10167,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       dimensions: dco_decode_opt_String(arr[4]),
       schemeVersion: dco_decode_String(arr[5]),
       nonceHex: dco_decode_String(arr[6]),
+      thumb: dco_decode_opt_box_autoadd_thumbnail_reference(arr[7]),
     );
   }
 
@@ -6456,6 +10184,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageReaction dco_decode_message_reaction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MessageReaction(
+      eventIdHex: dco_decode_String(arr[0]),
+      reactorPubkeyHex: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      customEmojiUrl: dco_decode_opt_String(arr[3]),
+      createdAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  MessageReader dco_decode_message_reader(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MessageReader(
+      readerPubkeyHex: dco_decode_String(arr[0]),
+      readAt: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  OgMetadata dco_decode_og_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OgMetadata(
+      url: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      imageUrl: dco_decode_opt_String(arr[3]),
+      siteName: dco_decode_opt_String(arr[4]),
+      domain: dco_decode_String(arr[5]),
+      ogType: dco_decode_opt_String(arr[6]),
+    );
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6490,6 +10262,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
   }
 
+  @protected
+  GroupInfo? dco_decode_opt_box_autoadd_group_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_group_info(raw);
+  }
+
   @protected
   GroupMessage? dco_decode_opt_box_autoadd_group_message(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6508,6 +10286,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_peer_stats(raw);
   }
 
+  @protected
+  ThumbnailReference? dco_decode_opt_box_autoadd_thumbnail_reference(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_thumbnail_reference(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6520,6 +10306,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  UploadMediaResult? dco_decode_opt_box_autoadd_upload_media_result(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_upload_media_result(raw);
+  }
+
+  @protected
+  OutboxFlushResult dco_decode_outbox_flush_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return OutboxFlushResult(
+      published: dco_decode_u_32(arr[0]),
+      reencrypted: dco_decode_u_32(arr[1]),
+      dropped: dco_decode_u_32(arr[2]),
+      remaining: dco_decode_u_32(arr[3]),
+    );
+  }
+
   @protected
   PeerConnectionState dco_decode_peer_connection_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6622,15 +10430,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReceiptSummary dco_decode_receipt_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReceiptSummary(
+      eventIdHex: dco_decode_String(arr[0]),
+      readers: dco_decode_list_message_reader(arr[1]),
+      recipientCount: dco_decode_u_32(arr[2]),
+      readByAll: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ReconcileReport dco_decode_reconcile_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReconcileReport(
+      mdkGroupCount: dco_decode_u_32(arr[0]),
+      restoredGroupIds: dco_decode_list_String(arr[1]),
+      orphanedGroupIds: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  RelayDiagnostics dco_decode_relay_diagnostics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RelayDiagnostics(
+      url: dco_decode_String(arr[0]),
+      status: dco_decode_String(arr[1]),
+      connected: dco_decode_bool(arr[2]),
+    );
+  }
+
   @protected
   RelayInfo dco_decode_relay_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return RelayInfo(
       url: dco_decode_String(arr[0]),
       connected: dco_decode_bool(arr[1]),
+      read: dco_decode_bool(arr[2]),
+      write: dco_decode_bool(arr[3]),
     );
   }
 
@@ -6638,8 +10488,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SdpInfo dco_decode_sdp_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
     return SdpInfo(
       sdpType: dco_decode_String(arr[0]),
       hasAudio: dco_decode_bool(arr[1]),
@@ -6647,8 +10497,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       mediaCount: dco_decode_u_32(arr[3]),
       iceUfrag: dco_decode_opt_String(arr[4]),
       codecs: dco_decode_list_String(arr[5]),
-      isValid: dco_decode_bool(arr[6]),
-      error: dco_decode_opt_String(arr[7]),
+      bandwidthKbps: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      opusParams: dco_decode_opt_String(arr[7]),
+      isValid: dco_decode_bool(arr[8]),
+      error: dco_decode_opt_String(arr[9]),
     );
   }
 
@@ -6677,6 +10529,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SignerKind dco_decode_signer_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SignerKind.values[raw as int];
+  }
+
   @protected
   SimulcastConfig dco_decode_simulcast_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6705,6 +10563,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ThumbnailReference dco_decode_thumbnail_reference(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ThumbnailReference(
+      url: dco_decode_String(arr[0]),
+      originalHashHex: dco_decode_String(arr[1]),
+      nonceHex: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   TranscriptSegment dco_decode_transcript_segment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -6768,12 +10639,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   UpdateGroupResult dco_decode_update_group_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return UpdateGroupResult(
       evolutionEventJson: dco_decode_String(arr[0]),
       welcomeRumorsJson: dco_decode_list_String(arr[1]),
       mlsGroupIdHex: dco_decode_String(arr[2]),
+      publishRelays: dco_decode_list_String(arr[3]),
     );
   }
 
@@ -6836,12 +10708,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   WebRtcConfig dco_decode_web_rtc_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return WebRtcConfig(
       iceServers: dco_decode_list_ice_server(arr[0]),
       sdpSemantics: dco_decode_String(arr[1]),
       bundlePolicy: dco_decode_String(arr[2]),
+      iceTransportPolicy: dco_decode_String(arr[3]),
     );
   }
 
@@ -6901,6 +10774,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<GroupMessage> sse_decode_StreamSink_group_message_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<GroupNotification>
   sse_decode_StreamSink_group_notification_Sse(SseDeserializer deserializer) {
@@ -6997,6 +10878,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AudioMode.values[inner];
   }
 
+  @protected
+  BatchUploadItem sse_decode_batch_upload_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filename = sse_decode_String(deserializer);
+    var var_result = sse_decode_opt_box_autoadd_upload_media_result(
+      deserializer,
+    );
+    var var_error = sse_decode_opt_String(deserializer);
+    return BatchUploadItem(
+      filename: var_filename,
+      result: var_result,
+      error: var_error,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7025,12 +10921,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_call_signaling_event(deserializer));
   }
 
+  @protected
+  DiagnosticsReport sse_decode_box_autoadd_diagnostics_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_diagnostics_report(deserializer));
+  }
+
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_f_64(deserializer));
   }
 
+  @protected
+  GroupInfo sse_decode_box_autoadd_group_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_info(deserializer));
+  }
+
   @protected
   GroupMessage sse_decode_box_autoadd_group_message(
     SseDeserializer deserializer,
@@ -7059,6 +10969,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_profile_data(deserializer));
   }
 
+  @protected
+  ThumbnailReference sse_decode_box_autoadd_thumbnail_reference(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thumbnail_reference(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7071,6 +10989,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  UploadMediaResult sse_decode_box_autoadd_upload_media_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_upload_media_result(deserializer));
+  }
+
   @protected
   BurrowError sse_decode_burrow_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7129,6 +11055,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_callType = sse_decode_opt_String(deserializer);
     var var_content = sse_decode_String(deserializer);
     var var_createdAt = sse_decode_u_64(deserializer);
+    var var_renegotiate = sse_decode_bool(deserializer);
+    var var_sequence = sse_decode_u_64(deserializer);
     return CallSignalingEvent(
       kind: var_kind,
       senderPubkeyHex: var_senderPubkeyHex,
@@ -7136,6 +11064,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callType: var_callType,
       content: var_content,
       createdAt: var_createdAt,
+      renegotiate: var_renegotiate,
+      sequence: var_sequence,
     );
   }
 
@@ -7153,6 +11083,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return CallType.values[inner];
   }
 
+  @protected
+  ChunkedEncryptResult sse_decode_chunked_encrypt_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outputPath = sse_decode_String(deserializer);
+    var var_originalHashHex = sse_decode_String(deserializer);
+    var var_encryptedHashHex = sse_decode_String(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_filename = sse_decode_String(deserializer);
+    var var_originalSize = sse_decode_u_64(deserializer);
+    var var_encryptedSize = sse_decode_u_64(deserializer);
+    var var_nonceHex = sse_decode_String(deserializer);
+    var var_frameSize = sse_decode_u_32(deserializer);
+    var var_schemeVersion = sse_decode_String(deserializer);
+    return ChunkedEncryptResult(
+      outputPath: var_outputPath,
+      originalHashHex: var_originalHashHex,
+      encryptedHashHex: var_encryptedHashHex,
+      mimeType: var_mimeType,
+      filename: var_filename,
+      originalSize: var_originalSize,
+      encryptedSize: var_encryptedSize,
+      nonceHex: var_nonceHex,
+      frameSize: var_frameSize,
+      schemeVersion: var_schemeVersion,
+    );
+  }
+
   @protected
   CodecPreferences sse_decode_codec_preferences(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7170,10 +11129,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_pubkeyHex = sse_decode_String(deserializer);
     var var_displayName = sse_decode_opt_String(deserializer);
     var var_picture = sse_decode_opt_String(deserializer);
+    var var_nip05 = sse_decode_opt_String(deserializer);
+    var var_nip05Verified = sse_decode_bool(deserializer);
     return ContactInfo(
       pubkeyHex: var_pubkeyHex,
       displayName: var_displayName,
       picture: var_picture,
+      nip05: var_nip05,
+      nip05Verified: var_nip05Verified,
     );
   }
 
@@ -7213,6 +11176,87 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CreateOrGetDmResult sse_decode_create_or_get_dm_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_group = sse_decode_group_info(deserializer);
+    var var_welcomeRumorsJson = sse_decode_list_String(deserializer);
+    var var_mlsGroupIdHex = sse_decode_String(deserializer);
+    var var_reused = sse_decode_bool(deserializer);
+    return CreateOrGetDmResult(
+      group: var_group,
+      welcomeRumorsJson: var_welcomeRumorsJson,
+      mlsGroupIdHex: var_mlsGroupIdHex,
+      reused: var_reused,
+    );
+  }
+
+  @protected
+  DiagnosticsReport sse_decode_diagnostics_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_generatedAt = sse_decode_u_64(deserializer);
+    var var_coreVersion = sse_decode_String(deserializer);
+    var var_initialized = sse_decode_bool(deserializer);
+    var var_pubkeyHex = sse_decode_opt_String(deserializer);
+    var var_relays = sse_decode_list_relay_diagnostics(deserializer);
+    var var_groupCount = sse_decode_u_32(deserializer);
+    var var_groups = sse_decode_list_group_diagnostics(deserializer);
+    var var_pendingWelcomeCount = sse_decode_u_32(deserializer);
+    var var_lastContactsSync = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_appDbInitialized = sse_decode_bool(deserializer);
+    var var_appDbSchemaVersion = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_errorCounts = sse_decode_list_error_count(deserializer);
+    return DiagnosticsReport(
+      generatedAt: var_generatedAt,
+      coreVersion: var_coreVersion,
+      initialized: var_initialized,
+      pubkeyHex: var_pubkeyHex,
+      relays: var_relays,
+      groupCount: var_groupCount,
+      groups: var_groups,
+      pendingWelcomeCount: var_pendingWelcomeCount,
+      lastContactsSync: var_lastContactsSync,
+      appDbInitialized: var_appDbInitialized,
+      appDbSchemaVersion: var_appDbSchemaVersion,
+      errorCounts: var_errorCounts,
+    );
+  }
+
+  @protected
+  EncryptedFileOnDisk sse_decode_encrypted_file_on_disk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encryptedPath = sse_decode_String(deserializer);
+    var var_originalHashHex = sse_decode_String(deserializer);
+    var var_encryptedHashHex = sse_decode_String(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_filename = sse_decode_String(deserializer);
+    var var_originalSize = sse_decode_u_64(deserializer);
+    var var_encryptedSize = sse_decode_u_64(deserializer);
+    var var_dimensions = sse_decode_opt_String(deserializer);
+    var var_blurhash = sse_decode_opt_String(deserializer);
+    var var_nonceHex = sse_decode_String(deserializer);
+    var var_schemeVersion = sse_decode_String(deserializer);
+    return EncryptedFileOnDisk(
+      encryptedPath: var_encryptedPath,
+      originalHashHex: var_originalHashHex,
+      encryptedHashHex: var_encryptedHashHex,
+      mimeType: var_mimeType,
+      filename: var_filename,
+      originalSize: var_originalSize,
+      encryptedSize: var_encryptedSize,
+      dimensions: var_dimensions,
+      blurhash: var_blurhash,
+      nonceHex: var_nonceHex,
+      schemeVersion: var_schemeVersion,
+    );
+  }
+
   @protected
   EncryptedFileResult sse_decode_encrypted_file_result(
     SseDeserializer deserializer,
@@ -7242,6 +11286,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  EphemeralKeyStrategy sse_decode_ephemeral_key_strategy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EphemeralKeyStrategy.values[inner];
+  }
+
+  @protected
+  EpochInfo sse_decode_epoch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_recordedAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_committerPubkeyHex = sse_decode_opt_String(deserializer);
+    var var_commitEventIdHex = sse_decode_opt_String(deserializer);
+    var var_addedPubkeys = sse_decode_list_String(deserializer);
+    var var_removedPubkeys = sse_decode_list_String(deserializer);
+    var var_metadataChanged = sse_decode_bool(deserializer);
+    return EpochInfo(
+      epoch: var_epoch,
+      recordedAt: var_recordedAt,
+      committerPubkeyHex: var_committerPubkeyHex,
+      commitEventIdHex: var_commitEventIdHex,
+      addedPubkeys: var_addedPubkeys,
+      removedPubkeys: var_removedPubkeys,
+      metadataChanged: var_metadataChanged,
+    );
+  }
+
+  @protected
+  ErrorCount sse_decode_error_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subsystem = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_lastErrorAt = sse_decode_u_64(deserializer);
+    var var_lastErrorKind = sse_decode_String(deserializer);
+    return ErrorCount(
+      subsystem: var_subsystem,
+      count: var_count,
+      lastErrorAt: var_lastErrorAt,
+      lastErrorKind: var_lastErrorKind,
+    );
+  }
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7254,6 +11343,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FileInput sse_decode_file_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileData = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_filename = sse_decode_String(deserializer);
+    return FileInput(
+      fileData: var_fileData,
+      mimeType: var_mimeType,
+      filename: var_filename,
+    );
+  }
+
+  @protected
+  GroupDiagnostics sse_decode_group_diagnostics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupIdHex = sse_decode_String(deserializer);
+    var var_nostrGroupIdHex = sse_decode_String(deserializer);
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_state = sse_decode_String(deserializer);
+    var var_memberCount = sse_decode_u_32(deserializer);
+    var var_relayCount = sse_decode_u_32(deserializer);
+    return GroupDiagnostics(
+      mlsGroupIdHex: var_mlsGroupIdHex,
+      nostrGroupIdHex: var_nostrGroupIdHex,
+      epoch: var_epoch,
+      state: var_state,
+      memberCount: var_memberCount,
+      relayCount: var_relayCount,
+    );
+  }
+
+  @protected
+  GroupEpochEvent sse_decode_group_epoch_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epoch = sse_decode_u_64(deserializer);
+    var var_changeType = sse_decode_String(deserializer);
+    var var_pubkeys = sse_decode_list_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_committerPubkeyHex = sse_decode_opt_String(deserializer);
+    return GroupEpochEvent(
+      epoch: var_epoch,
+      changeType: var_changeType,
+      pubkeys: var_pubkeys,
+      timestamp: var_timestamp,
+      committerPubkeyHex: var_committerPubkeyHex,
+    );
+  }
+
   @protected
   GroupInfo sse_decode_group_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7271,6 +11409,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_dmPeerPubkeyHex = sse_decode_opt_String(deserializer);
     var var_imageHashHex = sse_decode_opt_String(deserializer);
     var var_hasImage = sse_decode_bool(deserializer);
+    var var_defaultMessageTtlSecs = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
     return GroupInfo(
       mlsGroupIdHex: var_mlsGroupIdHex,
       nostrGroupIdHex: var_nostrGroupIdHex,
@@ -7286,6 +11427,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       dmPeerPubkeyHex: var_dmPeerPubkeyHex,
       imageHashHex: var_imageHashHex,
       hasImage: var_hasImage,
+      defaultMessageTtlSecs: var_defaultMessageTtlSecs,
     );
   }
 
@@ -7301,6 +11443,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_tags = sse_decode_list_list_String(deserializer);
     var var_wrapperEventIdHex = sse_decode_String(deserializer);
     var var_epoch = sse_decode_u_64(deserializer);
+    var var_mentionsMe = sse_decode_bool(deserializer);
+    var var_editedFrom = sse_decode_opt_String(deserializer);
+    var var_replyToEventIdHex = sse_decode_opt_String(deserializer);
+    var var_replyToAuthorHex = sse_decode_opt_String(deserializer);
     return GroupMessage(
       eventIdHex: var_eventIdHex,
       authorPubkeyHex: var_authorPubkeyHex,
@@ -7311,6 +11457,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       tags: var_tags,
       wrapperEventIdHex: var_wrapperEventIdHex,
       epoch: var_epoch,
+      mentionsMe: var_mentionsMe,
+      editedFrom: var_editedFrom,
+      replyToEventIdHex: var_replyToEventIdHex,
+      replyToAuthorHex: var_replyToAuthorHex,
     );
   }
 
@@ -7322,10 +11472,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_notificationType = sse_decode_String(deserializer);
     var var_message = sse_decode_opt_box_autoadd_group_message(deserializer);
     var var_mlsGroupIdHex = sse_decode_String(deserializer);
+    var var_addedPubkeys = sse_decode_list_String(deserializer);
+    var var_removedPubkeys = sse_decode_list_String(deserializer);
+    var var_senderPubkeyHex = sse_decode_opt_String(deserializer);
     return GroupNotification(
       notificationType: var_notificationType,
       message: var_message,
       mlsGroupIdHex: var_mlsGroupIdHex,
+      addedPubkeys: var_addedPubkeys,
+      removedPubkeys: var_removedPubkeys,
+      senderPubkeyHex: var_senderPubkeyHex,
     );
   }
 
@@ -7371,6 +11527,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  InviteResult sse_decode_invite_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mlsGroupIdHex = sse_decode_String(deserializer);
+    var var_evolutionEventIdHex = sse_decode_String(deserializer);
+    var var_welcomeEventIdsHex = sse_decode_list_String(deserializer);
+    var var_failedWelcomePubkeysHex = sse_decode_list_String(deserializer);
+    return InviteResult(
+      mlsGroupIdHex: var_mlsGroupIdHex,
+      evolutionEventIdHex: var_evolutionEventIdHex,
+      welcomeEventIdsHex: var_welcomeEventIdsHex,
+      failedWelcomePubkeysHex: var_failedWelcomePubkeysHex,
+    );
+  }
+
+  @protected
+  JitterBufferConfig sse_decode_jitter_buffer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_targetMs = sse_decode_u_32(deserializer);
+    var var_minMs = sse_decode_u_32(deserializer);
+    var var_maxMs = sse_decode_u_32(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return JitterBufferConfig(
+      targetMs: var_targetMs,
+      minMs: var_minMs,
+      maxMs: var_maxMs,
+      reason: var_reason,
+    );
+  }
+
   @protected
   KeyPackageData sse_decode_key_package_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7394,26 +11582,104 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<BatchUploadItem> sse_decode_list_batch_upload_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchUploadItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_upload_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<CallSession> sse_decode_list_call_session(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CallSession>[];
+    var ans_ = <CallSession>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_call_session(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContactInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_contact_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EpochInfo> sse_decode_list_epoch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EpochInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_epoch_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ErrorCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_error_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FileInput> sse_decode_list_file_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FileInput>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_file_input(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GroupDiagnostics> sse_decode_list_group_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GroupDiagnostics>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_call_session(deserializer));
+      ans_.add(sse_decode_group_diagnostics(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer) {
+  List<GroupEpochEvent> sse_decode_list_group_epoch_event(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ContactInfo>[];
+    var ans_ = <GroupEpochEvent>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_contact_info(deserializer));
+      ans_.add(sse_decode_group_epoch_event(deserializer));
     }
     return ans_;
   }
@@ -7480,6 +11746,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<MessageReaction> sse_decode_list_message_reaction(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageReaction>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_reaction(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageReader> sse_decode_list_message_reader(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageReader>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_reader(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<PeerEntry> sse_decode_list_peer_entry(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7520,6 +11814,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<RelayDiagnostics> sse_decode_list_relay_diagnostics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RelayDiagnostics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_relay_diagnostics(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<RelayInfo> sse_decode_list_relay_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7532,6 +11840,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<SendMessageResult> sse_decode_list_send_message_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SendMessageResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_send_message_result(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<SimulcastLayer> sse_decode_list_simulcast_layer(
     SseDeserializer deserializer,
@@ -7584,6 +11906,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_dimensions = sse_decode_opt_String(deserializer);
     var var_schemeVersion = sse_decode_String(deserializer);
     var var_nonceHex = sse_decode_String(deserializer);
+    var var_thumb = sse_decode_opt_box_autoadd_thumbnail_reference(
+      deserializer,
+    );
     return MediaReferenceInfo(
       url: var_url,
       originalHashHex: var_originalHashHex,
@@ -7592,6 +11917,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       dimensions: var_dimensions,
       schemeVersion: var_schemeVersion,
       nonceHex: var_nonceHex,
+      thumb: var_thumb,
     );
   }
 
@@ -7608,6 +11934,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageReaction sse_decode_message_reaction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventIdHex = sse_decode_String(deserializer);
+    var var_reactorPubkeyHex = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_customEmojiUrl = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    return MessageReaction(
+      eventIdHex: var_eventIdHex,
+      reactorPubkeyHex: var_reactorPubkeyHex,
+      content: var_content,
+      customEmojiUrl: var_customEmojiUrl,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  MessageReader sse_decode_message_reader(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_readerPubkeyHex = sse_decode_String(deserializer);
+    var var_readAt = sse_decode_u_64(deserializer);
+    return MessageReader(
+      readerPubkeyHex: var_readerPubkeyHex,
+      readAt: var_readAt,
+    );
+  }
+
+  @protected
+  OgMetadata sse_decode_og_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_imageUrl = sse_decode_opt_String(deserializer);
+    var var_siteName = sse_decode_opt_String(deserializer);
+    var var_domain = sse_decode_String(deserializer);
+    var var_ogType = sse_decode_opt_String(deserializer);
+    return OgMetadata(
+      url: var_url,
+      title: var_title,
+      description: var_description,
+      imageUrl: var_imageUrl,
+      siteName: var_siteName,
+      domain: var_domain,
+      ogType: var_ogType,
+    );
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7667,6 +12042,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  GroupInfo? sse_decode_opt_box_autoadd_group_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_group_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   GroupMessage? sse_decode_opt_box_autoadd_group_message(
     SseDeserializer deserializer,
@@ -7704,6 +12092,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ThumbnailReference? sse_decode_opt_box_autoadd_thumbnail_reference(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_thumbnail_reference(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7726,6 +12127,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  UploadMediaResult? sse_decode_opt_box_autoadd_upload_media_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_upload_media_result(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutboxFlushResult sse_decode_outbox_flush_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_published = sse_decode_u_32(deserializer);
+    var var_reencrypted = sse_decode_u_32(deserializer);
+    var var_dropped = sse_decode_u_32(deserializer);
+    var var_remaining = sse_decode_u_32(deserializer);
+    return OutboxFlushResult(
+      published: var_published,
+      reencrypted: var_reencrypted,
+      dropped: var_dropped,
+      remaining: var_remaining,
+    );
+  }
+
   @protected
   PeerConnectionState sse_decode_peer_connection_state(
     SseDeserializer deserializer,
@@ -7853,12 +12284,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReceiptSummary sse_decode_receipt_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventIdHex = sse_decode_String(deserializer);
+    var var_readers = sse_decode_list_message_reader(deserializer);
+    var var_recipientCount = sse_decode_u_32(deserializer);
+    var var_readByAll = sse_decode_bool(deserializer);
+    return ReceiptSummary(
+      eventIdHex: var_eventIdHex,
+      readers: var_readers,
+      recipientCount: var_recipientCount,
+      readByAll: var_readByAll,
+    );
+  }
+
+  @protected
+  ReconcileReport sse_decode_reconcile_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mdkGroupCount = sse_decode_u_32(deserializer);
+    var var_restoredGroupIds = sse_decode_list_String(deserializer);
+    var var_orphanedGroupIds = sse_decode_list_String(deserializer);
+    return ReconcileReport(
+      mdkGroupCount: var_mdkGroupCount,
+      restoredGroupIds: var_restoredGroupIds,
+      orphanedGroupIds: var_orphanedGroupIds,
+    );
+  }
+
+  @protected
+  RelayDiagnostics sse_decode_relay_diagnostics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_connected = sse_decode_bool(deserializer);
+    return RelayDiagnostics(
+      url: var_url,
+      status: var_status,
+      connected: var_connected,
+    );
+  }
+
   @protected
   RelayInfo sse_decode_relay_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_url = sse_decode_String(deserializer);
     var var_connected = sse_decode_bool(deserializer);
-    return RelayInfo(url: var_url, connected: var_connected);
+    var var_read = sse_decode_bool(deserializer);
+    var var_write = sse_decode_bool(deserializer);
+    return RelayInfo(
+      url: var_url,
+      connected: var_connected,
+      read: var_read,
+      write: var_write,
+    );
   }
 
   @protected
@@ -7870,6 +12349,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_mediaCount = sse_decode_u_32(deserializer);
     var var_iceUfrag = sse_decode_opt_String(deserializer);
     var var_codecs = sse_decode_list_String(deserializer);
+    var var_bandwidthKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_opusParams = sse_decode_opt_String(deserializer);
     var var_isValid = sse_decode_bool(deserializer);
     var var_error = sse_decode_opt_String(deserializer);
     return SdpInfo(
@@ -7879,6 +12360,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       mediaCount: var_mediaCount,
       iceUfrag: var_iceUfrag,
       codecs: var_codecs,
+      bandwidthKbps: var_bandwidthKbps,
+      opusParams: var_opusParams,
       isValid: var_isValid,
       error: var_error,
     );
@@ -7907,6 +12390,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SignerKind sse_decode_signer_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SignerKind.values[inner];
+  }
+
   @protected
   SimulcastConfig sse_decode_simulcast_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7934,6 +12424,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ThumbnailReference sse_decode_thumbnail_reference(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_originalHashHex = sse_decode_String(deserializer);
+    var var_nonceHex = sse_decode_String(deserializer);
+    return ThumbnailReference(
+      url: var_url,
+      originalHashHex: var_originalHashHex,
+      nonceHex: var_nonceHex,
+    );
+  }
+
   @protected
   TranscriptSegment sse_decode_transcript_segment(
     SseDeserializer deserializer,
@@ -8013,10 +12518,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_evolutionEventJson = sse_decode_String(deserializer);
     var var_welcomeRumorsJson = sse_decode_list_String(deserializer);
     var var_mlsGroupIdHex = sse_decode_String(deserializer);
+    var var_publishRelays = sse_decode_list_String(deserializer);
     return UpdateGroupResult(
       evolutionEventJson: var_evolutionEventJson,
       welcomeRumorsJson: var_welcomeRumorsJson,
       mlsGroupIdHex: var_mlsGroupIdHex,
+      publishRelays: var_publishRelays,
     );
   }
 
@@ -8092,10 +12599,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_iceServers = sse_decode_list_ice_server(deserializer);
     var var_sdpSemantics = sse_decode_String(deserializer);
     var var_bundlePolicy = sse_decode_String(deserializer);
+    var var_iceTransportPolicy = sse_decode_String(deserializer);
     return WebRtcConfig(
       iceServers: var_iceServers,
       sdpSemantics: var_sdpSemantics,
       bundlePolicy: var_bundlePolicy,
+      iceTransportPolicy: var_iceTransportPolicy,
     );
   }
 
@@ -8174,6 +12683,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_group_message_Sse(
+    RustStreamSink<GroupMessage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_group_message,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_group_notification_Sse(
     RustStreamSink<GroupNotification> self,
@@ -8258,6 +12784,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_batch_upload_item(
+    BatchUploadItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filename, serializer);
+    sse_encode_opt_box_autoadd_upload_media_result(self.result, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8288,12 +12825,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_call_signaling_event(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_diagnostics_report(
+    DiagnosticsReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_diagnostics_report(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_group_info(
+    GroupInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_info(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_group_message(
     GroupMessage self,
@@ -8330,6 +12885,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_profile_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_thumbnail_reference(
+    ThumbnailReference self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_thumbnail_reference(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8342,6 +12906,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_upload_media_result(
+    UploadMediaResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_upload_media_result(self, serializer);
+  }
+
   @protected
   void sse_encode_burrow_error(BurrowError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8384,6 +12957,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.callType, serializer);
     sse_encode_String(self.content, serializer);
     sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_bool(self.renegotiate, serializer);
+    sse_encode_u_64(self.sequence, serializer);
   }
 
   @protected
@@ -8398,6 +12973,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_chunked_encrypt_result(
+    ChunkedEncryptResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.outputPath, serializer);
+    sse_encode_String(self.originalHashHex, serializer);
+    sse_encode_String(self.encryptedHashHex, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.filename, serializer);
+    sse_encode_u_64(self.originalSize, serializer);
+    sse_encode_u_64(self.encryptedSize, serializer);
+    sse_encode_String(self.nonceHex, serializer);
+    sse_encode_u_32(self.frameSize, serializer);
+    sse_encode_String(self.schemeVersion, serializer);
+  }
+
   @protected
   void sse_encode_codec_preferences(
     CodecPreferences self,
@@ -8414,31 +13007,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.pubkeyHex, serializer);
     sse_encode_opt_String(self.displayName, serializer);
     sse_encode_opt_String(self.picture, serializer);
+    sse_encode_opt_String(self.nip05, serializer);
+    sse_encode_bool(self.nip05Verified, serializer);
+  }
+
+  @protected
+  void sse_encode_contacts_sync_debug(
+    ContactsSyncDebug self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.connectedRelays, serializer);
+    sse_encode_u_32(self.followCount, serializer);
+    sse_encode_u_32(self.keyPackageCount, serializer);
+    sse_encode_u_32(self.dbFollowCount, serializer);
+    sse_encode_u_32(self.dbKpCount, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_create_group_result(
+    CreateGroupResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_info(self.group, serializer);
+    sse_encode_list_String(self.welcomeRumorsJson, serializer);
+    sse_encode_String(self.mlsGroupIdHex, serializer);
+  }
+
+  @protected
+  void sse_encode_create_or_get_dm_result(
+    CreateOrGetDmResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_info(self.group, serializer);
+    sse_encode_list_String(self.welcomeRumorsJson, serializer);
+    sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_bool(self.reused, serializer);
   }
 
   @protected
-  void sse_encode_contacts_sync_debug(
-    ContactsSyncDebug self,
+  void sse_encode_diagnostics_report(
+    DiagnosticsReport self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.connectedRelays, serializer);
-    sse_encode_u_32(self.followCount, serializer);
-    sse_encode_u_32(self.keyPackageCount, serializer);
-    sse_encode_u_32(self.dbFollowCount, serializer);
-    sse_encode_u_32(self.dbKpCount, serializer);
-    sse_encode_opt_String(self.error, serializer);
+    sse_encode_u_64(self.generatedAt, serializer);
+    sse_encode_String(self.coreVersion, serializer);
+    sse_encode_bool(self.initialized, serializer);
+    sse_encode_opt_String(self.pubkeyHex, serializer);
+    sse_encode_list_relay_diagnostics(self.relays, serializer);
+    sse_encode_u_32(self.groupCount, serializer);
+    sse_encode_list_group_diagnostics(self.groups, serializer);
+    sse_encode_u_32(self.pendingWelcomeCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastContactsSync, serializer);
+    sse_encode_bool(self.appDbInitialized, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.appDbSchemaVersion, serializer);
+    sse_encode_list_error_count(self.errorCounts, serializer);
   }
 
   @protected
-  void sse_encode_create_group_result(
-    CreateGroupResult self,
+  void sse_encode_encrypted_file_on_disk(
+    EncryptedFileOnDisk self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_group_info(self.group, serializer);
-    sse_encode_list_String(self.welcomeRumorsJson, serializer);
-    sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_String(self.encryptedPath, serializer);
+    sse_encode_String(self.originalHashHex, serializer);
+    sse_encode_String(self.encryptedHashHex, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.filename, serializer);
+    sse_encode_u_64(self.originalSize, serializer);
+    sse_encode_u_64(self.encryptedSize, serializer);
+    sse_encode_opt_String(self.dimensions, serializer);
+    sse_encode_opt_String(self.blurhash, serializer);
+    sse_encode_String(self.nonceHex, serializer);
+    sse_encode_String(self.schemeVersion, serializer);
   }
 
   @protected
@@ -8459,6 +13105,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.nonceHex, serializer);
   }
 
+  @protected
+  void sse_encode_ephemeral_key_strategy(
+    EphemeralKeyStrategy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_epoch_info(EpochInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.recordedAt, serializer);
+    sse_encode_opt_String(self.committerPubkeyHex, serializer);
+    sse_encode_opt_String(self.commitEventIdHex, serializer);
+    sse_encode_list_String(self.addedPubkeys, serializer);
+    sse_encode_list_String(self.removedPubkeys, serializer);
+    sse_encode_bool(self.metadataChanged, serializer);
+  }
+
+  @protected
+  void sse_encode_error_count(ErrorCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.subsystem, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_u_64(self.lastErrorAt, serializer);
+    sse_encode_String(self.lastErrorKind, serializer);
+  }
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8471,6 +13147,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_file_input(FileInput self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.fileData, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_String(self.filename, serializer);
+  }
+
+  @protected
+  void sse_encode_group_diagnostics(
+    GroupDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_String(self.nostrGroupIdHex, serializer);
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_String(self.state, serializer);
+    sse_encode_u_32(self.memberCount, serializer);
+    sse_encode_u_32(self.relayCount, serializer);
+  }
+
+  @protected
+  void sse_encode_group_epoch_event(
+    GroupEpochEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.epoch, serializer);
+    sse_encode_String(self.changeType, serializer);
+    sse_encode_list_String(self.pubkeys, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_opt_String(self.committerPubkeyHex, serializer);
+  }
+
   @protected
   void sse_encode_group_info(GroupInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8488,6 +13199,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.dmPeerPubkeyHex, serializer);
     sse_encode_opt_String(self.imageHashHex, serializer);
     sse_encode_bool(self.hasImage, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.defaultMessageTtlSecs, serializer);
   }
 
   @protected
@@ -8502,6 +13214,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_list_String(self.tags, serializer);
     sse_encode_String(self.wrapperEventIdHex, serializer);
     sse_encode_u_64(self.epoch, serializer);
+    sse_encode_bool(self.mentionsMe, serializer);
+    sse_encode_opt_String(self.editedFrom, serializer);
+    sse_encode_opt_String(self.replyToEventIdHex, serializer);
+    sse_encode_opt_String(self.replyToAuthorHex, serializer);
   }
 
   @protected
@@ -8513,6 +13229,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.notificationType, serializer);
     sse_encode_opt_box_autoadd_group_message(self.message, serializer);
     sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_list_String(self.addedPubkeys, serializer);
+    sse_encode_list_String(self.removedPubkeys, serializer);
+    sse_encode_opt_String(self.senderPubkeyHex, serializer);
   }
 
   @protected
@@ -8544,6 +13263,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.credential, serializer);
   }
 
+  @protected
+  void sse_encode_invite_result(InviteResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_String(self.evolutionEventIdHex, serializer);
+    sse_encode_list_String(self.welcomeEventIdsHex, serializer);
+    sse_encode_list_String(self.failedWelcomePubkeysHex, serializer);
+  }
+
+  @protected
+  void sse_encode_jitter_buffer_config(
+    JitterBufferConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.targetMs, serializer);
+    sse_encode_u_32(self.minMs, serializer);
+    sse_encode_u_32(self.maxMs, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
   @protected
   void sse_encode_key_package_data(
     KeyPackageData self,
@@ -8563,6 +13303,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_batch_upload_item(
+    List<BatchUploadItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_upload_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_call_session(
     List<CallSession> self,
@@ -8587,6 +13339,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_epoch_info(
+    List<EpochInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_epoch_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_error_count(
+    List<ErrorCount> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_error_count(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_file_input(
+    List<FileInput> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_file_input(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_group_diagnostics(
+    List<GroupDiagnostics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_group_diagnostics(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_group_epoch_event(
+    List<GroupEpochEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_group_epoch_event(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_group_info(
     List<GroupInfo> self,
@@ -8647,6 +13459,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_message_reaction(
+    List<MessageReaction> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_reaction(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_message_reader(
+    List<MessageReader> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_reader(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_peer_entry(
     List<PeerEntry> self,
@@ -8703,6 +13539,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_relay_diagnostics(
+    List<RelayDiagnostics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_relay_diagnostics(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_relay_info(
     List<RelayInfo> self,
@@ -8715,6 +13563,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_send_message_result(
+    List<SendMessageResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_send_message_result(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_simulcast_layer(
     List<SimulcastLayer> self,
@@ -8764,6 +13624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.dimensions, serializer);
     sse_encode_String(self.schemeVersion, serializer);
     sse_encode_String(self.nonceHex, serializer);
+    sse_encode_opt_box_autoadd_thumbnail_reference(self.thumb, serializer);
   }
 
   @protected
@@ -8774,6 +13635,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.picture, serializer);
   }
 
+  @protected
+  void sse_encode_message_reaction(
+    MessageReaction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.eventIdHex, serializer);
+    sse_encode_String(self.reactorPubkeyHex, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.customEmojiUrl, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_message_reader(MessageReader self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.readerPubkeyHex, serializer);
+    sse_encode_u_64(self.readAt, serializer);
+  }
+
+  @protected
+  void sse_encode_og_metadata(OgMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.imageUrl, serializer);
+    sse_encode_opt_String(self.siteName, serializer);
+    sse_encode_String(self.domain, serializer);
+    sse_encode_opt_String(self.ogType, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8830,6 +13723,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_group_info(
+    GroupInfo? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_group_info(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_group_message(
     GroupMessage? self,
@@ -8869,6 +13775,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_thumbnail_reference(
+    ThumbnailReference? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_thumbnail_reference(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -8889,6 +13808,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_upload_media_result(
+    UploadMediaResult? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_upload_media_result(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_outbox_flush_result(
+    OutboxFlushResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.published, serializer);
+    sse_encode_u_32(self.reencrypted, serializer);
+    sse_encode_u_32(self.dropped, serializer);
+    sse_encode_u_32(self.remaining, serializer);
+  }
+
   @protected
   void sse_encode_peer_connection_state(
     PeerConnectionState self,
@@ -8970,11 +13914,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self.bitrateScore, serializer);
   }
 
+  @protected
+  void sse_encode_receipt_summary(
+    ReceiptSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.eventIdHex, serializer);
+    sse_encode_list_message_reader(self.readers, serializer);
+    sse_encode_u_32(self.recipientCount, serializer);
+    sse_encode_bool(self.readByAll, serializer);
+  }
+
+  @protected
+  void sse_encode_reconcile_report(
+    ReconcileReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.mdkGroupCount, serializer);
+    sse_encode_list_String(self.restoredGroupIds, serializer);
+    sse_encode_list_String(self.orphanedGroupIds, serializer);
+  }
+
+  @protected
+  void sse_encode_relay_diagnostics(
+    RelayDiagnostics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_bool(self.connected, serializer);
+  }
+
   @protected
   void sse_encode_relay_info(RelayInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.url, serializer);
     sse_encode_bool(self.connected, serializer);
+    sse_encode_bool(self.read, serializer);
+    sse_encode_bool(self.write, serializer);
   }
 
   @protected
@@ -8986,6 +13966,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.mediaCount, serializer);
     sse_encode_opt_String(self.iceUfrag, serializer);
     sse_encode_list_String(self.codecs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bandwidthKbps, serializer);
+    sse_encode_opt_String(self.opusParams, serializer);
     sse_encode_bool(self.isValid, serializer);
     sse_encode_opt_String(self.error, serializer);
   }
@@ -9008,6 +13990,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.token, serializer);
   }
 
+  @protected
+  void sse_encode_signer_kind(SignerKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_simulcast_config(
     SimulcastConfig self,
@@ -9032,6 +14020,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self.scaleResolutionDownBy, serializer);
   }
 
+  @protected
+  void sse_encode_thumbnail_reference(
+    ThumbnailReference self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.originalHashHex, serializer);
+    sse_encode_String(self.nonceHex, serializer);
+  }
+
   @protected
   void sse_encode_transcript_segment(
     TranscriptSegment self,
@@ -9095,6 +14094,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.evolutionEventJson, serializer);
     sse_encode_list_String(self.welcomeRumorsJson, serializer);
     sse_encode_String(self.mlsGroupIdHex, serializer);
+    sse_encode_list_String(self.publishRelays, serializer);
   }
 
   @protected
@@ -9155,6 +14155,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_ice_server(self.iceServers, serializer);
     sse_encode_String(self.sdpSemantics, serializer);
     sse_encode_String(self.bundlePolicy, serializer);
+    sse_encode_String(self.iceTransportPolicy, serializer);
   }
 
   @protected
@@ -5,20 +5,25 @@
 
 import 'api/account.dart';
 import 'api/app_state.dart';
+import 'api/backup.dart';
 import 'api/call_quality.dart';
 import 'api/call_session.dart';
 import 'api/call_signaling.dart';
 import 'api/call_webrtc.dart';
 import 'api/contacts.dart';
+import 'api/diagnostics.dart';
+import 'api/ephemeral.dart';
 import 'api/error.dart';
 import 'api/group.dart';
 import 'api/identity.dart';
 import 'api/invite.dart';
 import 'api/keypackage.dart';
+import 'api/link_preview.dart';
 import 'api/media.dart';
 import 'api/meeting_intelligence.dart';
 import 'api/message.dart';
 import 'api/relay.dart';
+import 'api/signer.dart';
 import 'api/simple.dart';
 import 'api/state.dart';
 import 'api/transcription.dart';
@@ -58,6 +63,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<CallSignalingEvent>
   dco_decode_StreamSink_call_signaling_event_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<GroupMessage> dco_decode_StreamSink_group_message_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<GroupNotification>
   dco_decode_StreamSink_group_notification_Sse(dynamic raw);
@@ -80,6 +90,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AudioMode dco_decode_audio_mode(dynamic raw);
 
+  @protected
+  BatchUploadItem dco_decode_batch_upload_item(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -92,9 +105,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CallSignalingEvent dco_decode_box_autoadd_call_signaling_event(dynamic raw);
 
+  @protected
+  DiagnosticsReport dco_decode_box_autoadd_diagnostics_report(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  GroupInfo dco_decode_box_autoadd_group_info(dynamic raw);
+
   @protected
   GroupMessage dco_decode_box_autoadd_group_message(dynamic raw);
 
@@ -107,12 +126,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProfileData dco_decode_box_autoadd_profile_data(dynamic raw);
 
+  @protected
+  ThumbnailReference dco_decode_box_autoadd_thumbnail_reference(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UploadMediaResult dco_decode_box_autoadd_upload_media_result(dynamic raw);
+
   @protected
   BurrowError dco_decode_burrow_error(dynamic raw);
 
@@ -131,6 +156,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CallType dco_decode_call_type(dynamic raw);
 
+  @protected
+  ChunkedEncryptResult dco_decode_chunked_encrypt_result(dynamic raw);
+
   @protected
   CodecPreferences dco_decode_codec_preferences(dynamic raw);
 
@@ -143,15 +171,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CreateGroupResult dco_decode_create_group_result(dynamic raw);
 
+  @protected
+  CreateOrGetDmResult dco_decode_create_or_get_dm_result(dynamic raw);
+
+  @protected
+  DiagnosticsReport dco_decode_diagnostics_report(dynamic raw);
+
+  @protected
+  EncryptedFileOnDisk dco_decode_encrypted_file_on_disk(dynamic raw);
+
   @protected
   EncryptedFileResult dco_decode_encrypted_file_result(dynamic raw);
 
+  @protected
+  EphemeralKeyStrategy dco_decode_ephemeral_key_strategy(dynamic raw);
+
+  @protected
+  EpochInfo dco_decode_epoch_info(dynamic raw);
+
+  @protected
+  ErrorCount dco_decode_error_count(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FileInput dco_decode_file_input(dynamic raw);
+
+  @protected
+  GroupDiagnostics dco_decode_group_diagnostics(dynamic raw);
+
+  @protected
+  GroupEpochEvent dco_decode_group_epoch_event(dynamic raw);
+
   @protected
   GroupInfo dco_decode_group_info(dynamic raw);
 
@@ -173,18 +228,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   IceServer dco_decode_ice_server(dynamic raw);
 
+  @protected
+  InviteResult dco_decode_invite_result(dynamic raw);
+
+  @protected
+  JitterBufferConfig dco_decode_jitter_buffer_config(dynamic raw);
+
   @protected
   KeyPackageData dco_decode_key_package_data(dynamic raw);
 
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BatchUploadItem> dco_decode_list_batch_upload_item(dynamic raw);
+
   @protected
   List<CallSession> dco_decode_list_call_session(dynamic raw);
 
   @protected
   List<ContactInfo> dco_decode_list_contact_info(dynamic raw);
 
+  @protected
+  List<EpochInfo> dco_decode_list_epoch_info(dynamic raw);
+
+  @protected
+  List<ErrorCount> dco_decode_list_error_count(dynamic raw);
+
+  @protected
+  List<FileInput> dco_decode_list_file_input(dynamic raw);
+
+  @protected
+  List<GroupDiagnostics> dco_decode_list_group_diagnostics(dynamic raw);
+
+  @protected
+  List<GroupEpochEvent> dco_decode_list_group_epoch_event(dynamic raw);
+
   @protected
   List<GroupInfo> dco_decode_list_group_info(dynamic raw);
 
@@ -200,6 +279,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MemberInfo> dco_decode_list_member_info(dynamic raw);
 
+  @protected
+  List<MessageReaction> dco_decode_list_message_reaction(dynamic raw);
+
+  @protected
+  List<MessageReader> dco_decode_list_message_reader(dynamic raw);
+
   @protected
   List<PeerEntry> dco_decode_list_peer_entry(dynamic raw);
 
@@ -215,9 +300,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RelayDiagnostics> dco_decode_list_relay_diagnostics(dynamic raw);
+
   @protected
   List<RelayInfo> dco_decode_list_relay_info(dynamic raw);
 
+  @protected
+  List<SendMessageResult> dco_decode_list_send_message_result(dynamic raw);
+
   @protected
   List<SimulcastLayer> dco_decode_list_simulcast_layer(dynamic raw);
 
@@ -233,6 +324,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MemberInfo dco_decode_member_info(dynamic raw);
 
+  @protected
+  MessageReaction dco_decode_message_reaction(dynamic raw);
+
+  @protected
+  MessageReader dco_decode_message_reader(dynamic raw);
+
+  @protected
+  OgMetadata dco_decode_og_metadata(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -250,6 +350,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  GroupInfo? dco_decode_opt_box_autoadd_group_info(dynamic raw);
+
   @protected
   GroupMessage? dco_decode_opt_box_autoadd_group_message(dynamic raw);
 
@@ -259,12 +362,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PeerStats? dco_decode_opt_box_autoadd_peer_stats(dynamic raw);
 
+  @protected
+  ThumbnailReference? dco_decode_opt_box_autoadd_thumbnail_reference(
+    dynamic raw,
+  );
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UploadMediaResult? dco_decode_opt_box_autoadd_upload_media_result(
+    dynamic raw,
+  );
+
+  @protected
+  OutboxFlushResult dco_decode_outbox_flush_result(dynamic raw);
+
   @protected
   PeerConnectionState dco_decode_peer_connection_state(dynamic raw);
 
@@ -286,6 +402,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   QualityScore dco_decode_quality_score(dynamic raw);
 
+  @protected
+  ReceiptSummary dco_decode_receipt_summary(dynamic raw);
+
+  @protected
+  ReconcileReport dco_decode_reconcile_report(dynamic raw);
+
+  @protected
+  RelayDiagnostics dco_decode_relay_diagnostics(dynamic raw);
+
   @protected
   RelayInfo dco_decode_relay_info(dynamic raw);
 
@@ -298,12 +423,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SfuConfig dco_decode_sfu_config(dynamic raw);
 
+  @protected
+  SignerKind dco_decode_signer_kind(dynamic raw);
+
   @protected
   SimulcastConfig dco_decode_simulcast_config(dynamic raw);
 
   @protected
   SimulcastLayer dco_decode_simulcast_layer(dynamic raw);
 
+  @protected
+  ThumbnailReference dco_decode_thumbnail_reference(dynamic raw);
+
   @protected
   TranscriptSegment dco_decode_transcript_segment(dynamic raw);
 
@@ -365,6 +496,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<CallSignalingEvent>
   sse_decode_StreamSink_call_signaling_event_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<GroupMessage> sse_decode_StreamSink_group_message_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<GroupNotification>
   sse_decode_StreamSink_group_notification_Sse(SseDeserializer deserializer);
@@ -389,6 +525,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AudioMode sse_decode_audio_mode(SseDeserializer deserializer);
 
+  @protected
+  BatchUploadItem sse_decode_batch_upload_item(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -403,9 +542,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  DiagnosticsReport sse_decode_box_autoadd_diagnostics_report(
+    SseDeserializer deserializer,
+  );
+
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  GroupInfo sse_decode_box_autoadd_group_info(SseDeserializer deserializer);
+
   @protected
   GroupMessage sse_decode_box_autoadd_group_message(
     SseDeserializer deserializer,
@@ -420,12 +567,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProfileData sse_decode_box_autoadd_profile_data(SseDeserializer deserializer);
 
+  @protected
+  ThumbnailReference sse_decode_box_autoadd_thumbnail_reference(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UploadMediaResult sse_decode_box_autoadd_upload_media_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   BurrowError sse_decode_burrow_error(SseDeserializer deserializer);
 
@@ -446,6 +603,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CallType sse_decode_call_type(SseDeserializer deserializer);
 
+  @protected
+  ChunkedEncryptResult sse_decode_chunked_encrypt_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   CodecPreferences sse_decode_codec_preferences(SseDeserializer deserializer);
 
@@ -462,17 +624,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  CreateOrGetDmResult sse_decode_create_or_get_dm_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DiagnosticsReport sse_decode_diagnostics_report(SseDeserializer deserializer);
+
+  @protected
+  EncryptedFileOnDisk sse_decode_encrypted_file_on_disk(
+    SseDeserializer deserializer,
+  );
+
   @protected
   EncryptedFileResult sse_decode_encrypted_file_result(
     SseDeserializer deserializer,
   );
 
+  @protected
+  EphemeralKeyStrategy sse_decode_ephemeral_key_strategy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EpochInfo sse_decode_epoch_info(SseDeserializer deserializer);
+
+  @protected
+  ErrorCount sse_decode_error_count(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FileInput sse_decode_file_input(SseDeserializer deserializer);
+
+  @protected
+  GroupDiagnostics sse_decode_group_diagnostics(SseDeserializer deserializer);
+
+  @protected
+  GroupEpochEvent sse_decode_group_epoch_event(SseDeserializer deserializer);
+
   @protected
   GroupInfo sse_decode_group_info(SseDeserializer deserializer);
 
@@ -494,18 +689,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   IceServer sse_decode_ice_server(SseDeserializer deserializer);
 
+  @protected
+  InviteResult sse_decode_invite_result(SseDeserializer deserializer);
+
+  @protected
+  JitterBufferConfig sse_decode_jitter_buffer_config(
+    SseDeserializer deserializer,
+  );
+
   @protected
   KeyPackageData sse_decode_key_package_data(SseDeserializer deserializer);
 
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BatchUploadItem> sse_decode_list_batch_upload_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<CallSession> sse_decode_list_call_session(SseDeserializer deserializer);
 
   @protected
   List<ContactInfo> sse_decode_list_contact_info(SseDeserializer deserializer);
 
+  @protected
+  List<EpochInfo> sse_decode_list_epoch_info(SseDeserializer deserializer);
+
+  @protected
+  List<ErrorCount> sse_decode_list_error_count(SseDeserializer deserializer);
+
+  @protected
+  List<FileInput> sse_decode_list_file_input(SseDeserializer deserializer);
+
+  @protected
+  List<GroupDiagnostics> sse_decode_list_group_diagnostics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GroupEpochEvent> sse_decode_list_group_epoch_event(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<GroupInfo> sse_decode_list_group_info(SseDeserializer deserializer);
 
@@ -523,6 +750,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MemberInfo> sse_decode_list_member_info(SseDeserializer deserializer);
 
+  @protected
+  List<MessageReaction> sse_decode_list_message_reaction(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MessageReader> sse_decode_list_message_reader(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<PeerEntry> sse_decode_list_peer_entry(SseDeserializer deserializer);
 
@@ -538,9 +775,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<RelayDiagnostics> sse_decode_list_relay_diagnostics(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<RelayInfo> sse_decode_list_relay_info(SseDeserializer deserializer);
 
+  @protected
+  List<SendMessageResult> sse_decode_list_send_message_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<SimulcastLayer> sse_decode_list_simulcast_layer(
     SseDeserializer deserializer,
@@ -562,6 +809,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MemberInfo sse_decode_member_info(SseDeserializer deserializer);
 
+  @protected
+  MessageReaction sse_decode_message_reaction(SseDeserializer deserializer);
+
+  @protected
+  MessageReader sse_decode_message_reader(SseDeserializer deserializer);
+
+  @protected
+  OgMetadata sse_decode_og_metadata(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -581,6 +837,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  GroupInfo? sse_decode_opt_box_autoadd_group_info(
+    SseDeserializer deserializer,
+  );
+
   @protected
   GroupMessage? sse_decode_opt_box_autoadd_group_message(
     SseDeserializer deserializer,
@@ -594,12 +855,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ThumbnailReference? sse_decode_opt_box_autoadd_thumbnail_reference(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UploadMediaResult? sse_decode_opt_box_autoadd_upload_media_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OutboxFlushResult sse_decode_outbox_flush_result(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PeerConnectionState sse_decode_peer_connection_state(
     SseDeserializer deserializer,
@@ -627,6 +903,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   QualityScore sse_decode_quality_score(SseDeserializer deserializer);
 
+  @protected
+  ReceiptSummary sse_decode_receipt_summary(SseDeserializer deserializer);
+
+  @protected
+  ReconcileReport sse_decode_reconcile_report(SseDeserializer deserializer);
+
+  @protected
+  RelayDiagnostics sse_decode_relay_diagnostics(SseDeserializer deserializer);
+
   @protected
   RelayInfo sse_decode_relay_info(SseDeserializer deserializer);
 
@@ -641,12 +926,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SfuConfig sse_decode_sfu_config(SseDeserializer deserializer);
 
+  @protected
+  SignerKind sse_decode_signer_kind(SseDeserializer deserializer);
+
   @protected
   SimulcastConfig sse_decode_simulcast_config(SseDeserializer deserializer);
 
   @protected
   SimulcastLayer sse_decode_simulcast_layer(SseDeserializer deserializer);
 
+  @protected
+  ThumbnailReference sse_decode_thumbnail_reference(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
 
@@ -725,6 +1018,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_group_message_Sse(
+    RustStreamSink<GroupMessage> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_group_notification_Sse(
     RustStreamSink<GroupNotification> self,
@@ -755,6 +1054,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_audio_mode(AudioMode self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_batch_upload_item(
+    BatchUploadItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -773,9 +1078,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_diagnostics_report(
+    DiagnosticsReport self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_group_info(
+    GroupInfo self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_group_message(
     GroupMessage self,
@@ -800,12 +1117,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_thumbnail_reference(
+    ThumbnailReference self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_upload_media_result(
+    UploadMediaResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_burrow_error(BurrowError self, SseSerializer serializer);
 
@@ -827,6 +1156,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_call_type(CallType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_chunked_encrypt_result(
+    ChunkedEncryptResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_codec_preferences(
     CodecPreferences self,
@@ -848,18 +1183,63 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_create_or_get_dm_result(
+    CreateOrGetDmResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diagnostics_report(
+    DiagnosticsReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_encrypted_file_on_disk(
+    EncryptedFileOnDisk self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_encrypted_file_result(
     EncryptedFileResult self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_ephemeral_key_strategy(
+    EphemeralKeyStrategy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_epoch_info(EpochInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_error_count(ErrorCount self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_file_input(FileInput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_diagnostics(
+    GroupDiagnostics self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_group_epoch_event(
+    GroupEpochEvent self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_group_info(GroupInfo self, SseSerializer serializer);
 
@@ -884,6 +1264,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_ice_server(IceServer self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_invite_result(InviteResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_jitter_buffer_config(
+    JitterBufferConfig self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_key_package_data(
     KeyPackageData self,
@@ -893,6 +1282,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_batch_upload_item(
+    List<BatchUploadItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_call_session(
     List<CallSession> self,
@@ -905,6 +1300,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_epoch_info(
+    List<EpochInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_error_count(
+    List<ErrorCount> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_file_input(
+    List<FileInput> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_group_diagnostics(
+    List<GroupDiagnostics> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_group_epoch_event(
+    List<GroupEpochEvent> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_group_info(
     List<GroupInfo> self,
@@ -935,6 +1360,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message_reaction(
+    List<MessageReaction> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_reader(
+    List<MessageReader> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_peer_entry(
     List<PeerEntry> self,
@@ -962,12 +1399,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_relay_diagnostics(
+    List<RelayDiagnostics> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_relay_info(
     List<RelayInfo> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_send_message_result(
+    List<SendMessageResult> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_simulcast_layer(
     List<SimulcastLayer> self,
@@ -995,6 +1444,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_member_info(MemberInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_message_reaction(
+    MessageReaction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_reader(MessageReader self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_og_metadata(OgMetadata self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -1016,6 +1477,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_group_info(
+    GroupInfo? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_group_message(
     GroupMessage? self,
@@ -1034,12 +1501,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_thumbnail_reference(
+    ThumbnailReference? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_upload_media_result(
+    UploadMediaResult? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_outbox_flush_result(
+    OutboxFlushResult self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_peer_connection_state(
     PeerConnectionState self,
@@ -1070,6 +1555,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_quality_score(QualityScore self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_receipt_summary(
+    ReceiptSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reconcile_report(
+    ReconcileReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_relay_diagnostics(
+    RelayDiagnostics self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_relay_info(RelayInfo self, SseSerializer serializer);
 
@@ -1085,6 +1588,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_sfu_config(SfuConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_signer_kind(SignerKind self, SseSerializer serializer);
+
   @protected
   void sse_encode_simulcast_config(
     SimulcastConfig self,
@@ -1097,6 +1603,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_thumbnail_reference(
+    ThumbnailReference self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_transcript_segment(
     TranscriptSegment self,
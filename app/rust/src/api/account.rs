@@ -4,6 +4,7 @@ use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
 
 use crate::api::error::BurrowError;
+use crate::api::signer::{BurrowSigner, SignerKind};
 use crate::api::state;
 
 /// Information about the current account (FFI-friendly).
@@ -42,48 +43,104 @@ pub async fn login(secret_key: String) -> Result<AccountInfo, BurrowError> {
     Ok(info)
 }
 
+/// Login through a NIP-46 remote signer (`bunker://...` URI).
+///
+/// The secret key stays in the bunker: events and NIP-44 payloads are signed
+/// and encrypted remotely, so `export_nsec` and `export_state` are
+/// unavailable. `app_secret_key` identifies this app to the bunker; pass the
+/// one from a previous session to reconnect without re-approving, or `None`
+/// to generate a fresh one.
+#[frb]
+pub async fn login_with_bunker(
+    bunker_uri: String,
+    app_secret_key: Option<String>,
+) -> Result<AccountInfo, BurrowError> {
+    let app_keys = match app_secret_key {
+        Some(secret) => Keys::parse(&secret).map_err(|e| BurrowError::from(e.to_string()))?,
+        None => Keys::generate(),
+    };
+    let signer = BurrowSigner::nip46(bunker_uri.trim(), app_keys).await?;
+    let info = AccountInfo {
+        pubkey_hex: signer.public_key().to_hex(),
+        npub: signer.public_key().to_bech32().map_err(|e| BurrowError::from(e.to_string()))?,
+    };
+    state::init_state_with_signer(signer).await?;
+    Ok(info)
+}
+
+/// Which signer the current account uses.
+#[frb]
+pub async fn get_signer_kind() -> Result<SignerKind, BurrowError> {
+    state::with_state_read(|s| Ok(s.signer.kind())).await
+}
+
 const KEYRING_SERVICE: &str = "com.burrow.app";
 const KEYRING_NSEC_KEY: &str = "burrow.nsec";
+/// Bunker URI and app nsec of a NIP-46 account, newline separated.
+const KEYRING_BUNKER_KEY: &str = "burrow.bunker";
 
 /// Save the current secret key to the platform keyring.
 ///
 /// Uses the OS credential store (D-Bus Secret Service on Linux, Keychain on
 /// macOS/iOS, Credential Manager on Android/Windows). The nsec never touches
-/// the filesystem.
+/// the filesystem. For NIP-46 accounts the bunker URI and this app's session
+/// key are saved instead.
 #[frb]
 pub async fn save_secret_key_to_keyring() -> Result<(), BurrowError> {
     state::initialize_keyring_store();
-    state::with_state(|s| {
-        let nsec = s
-            .keys
-            .secret_key()
-            .to_bech32()
-            .map_err(|e| BurrowError::from(e.to_string()))?;
-
-        let entry = keyring_core::Entry::new(KEYRING_SERVICE, KEYRING_NSEC_KEY)
+    state::with_state_read(|s| {
+        let (name, secret) = match s.signer.nip46_session() {
+            Some((app_keys, uri)) => {
+                let app_nsec = app_keys
+                    .secret_key()
+                    .to_bech32()
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                (KEYRING_BUNKER_KEY, format!("{uri}\n{app_nsec}"))
+            }
+            None => {
+                let nsec = s
+                    .signer
+                    .secret_keys()?
+                    .secret_key()
+                    .to_bech32()
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                (KEYRING_NSEC_KEY, nsec)
+            }
+        };
+
+        let entry = keyring_core::Entry::new(KEYRING_SERVICE, name)
             .map_err(|e| BurrowError::from(format!("Keyring entry: {e}")))?;
         entry
-            .set_secret(nsec.as_bytes())
+            .set_secret(secret.as_bytes())
             .map_err(|e| BurrowError::from(format!("Keyring save: {e}")))?;
         Ok(())
     })
     .await
 }
 
+fn read_keyring_entry(name: &str) -> Result<String, BurrowError> {
+    let entry = keyring_core::Entry::new(KEYRING_SERVICE, name)
+        .map_err(|e| BurrowError::from(format!("Keyring entry: {e}")))?;
+    let secret_bytes = entry
+        .get_secret()
+        .map_err(|e| BurrowError::from(format!("Keyring load: {e}")))?;
+    String::from_utf8(secret_bytes).map_err(|e| BurrowError::from(format!("Keyring decode: {e}")))
+}
+
 /// Load the secret key from the platform keyring and initialize the account.
 ///
-/// Returns the account info if a key was found in the keyring, or an error
-/// if no key is stored or the keyring is unavailable.
+/// Returns the account info if a key (or NIP-46 session) was found in the
+/// keyring, or an error if nothing is stored or the keyring is unavailable.
 #[frb]
 pub async fn load_account_from_keyring() -> Result<AccountInfo, BurrowError> {
     state::initialize_keyring_store();
-    let entry = keyring_core::Entry::new(KEYRING_SERVICE, KEYRING_NSEC_KEY)
-        .map_err(|e| BurrowError::from(format!("Keyring entry: {e}")))?;
-    let secret_bytes = entry
-        .get_secret()
-        .map_err(|e| BurrowError::from(format!("Keyring load: {e}")))?;
-    let nsec = String::from_utf8(secret_bytes)
-        .map_err(|e| BurrowError::from(format!("Keyring decode: {e}")))?;
+    if let Ok(session) = read_keyring_entry(KEYRING_BUNKER_KEY) {
+        let (uri, app_nsec) = session
+            .split_once('\n')
+            .ok_or_else(|| BurrowError::from("Keyring decode: malformed bunker session".to_string()))?;
+        return login_with_bunker(uri.to_string(), Some(app_nsec.trim().to_string())).await;
+    }
+    let nsec = read_keyring_entry(KEYRING_NSEC_KEY)?;
     login(nsec.trim().to_string()).await
 }
 
@@ -91,8 +148,10 @@ pub async fn load_account_from_keyring() -> Result<AccountInfo, BurrowError> {
 #[frb]
 pub async fn delete_secret_key_from_keyring() -> Result<(), BurrowError> {
     state::initialize_keyring_store();
-    if let Ok(entry) = keyring_core::Entry::new(KEYRING_SERVICE, KEYRING_NSEC_KEY) {
-        let _ = entry.delete_credential(); // Ignore errors (key might not exist)
+    for name in [KEYRING_NSEC_KEY, KEYRING_BUNKER_KEY] {
+        if let Ok(entry) = keyring_core::Entry::new(KEYRING_SERVICE, name) {
+            let _ = entry.delete_credential(); // Ignore errors (key might not exist)
+        }
     }
     Ok(())
 }
@@ -101,11 +160,11 @@ pub async fn delete_secret_key_from_keyring() -> Result<(), BurrowError> {
 #[frb]
 pub async fn has_keyring_account() -> bool {
     state::initialize_keyring_store();
-    if let Ok(entry) = keyring_core::Entry::new(KEYRING_SERVICE, KEYRING_NSEC_KEY) {
-        entry.get_secret().is_ok()
-    } else {
-        false
-    }
+    [KEYRING_NSEC_KEY, KEYRING_BUNKER_KEY].into_iter().any(|name| {
+        keyring_core::Entry::new(KEYRING_SERVICE, name)
+            .map(|entry| entry.get_secret().is_ok())
+            .unwrap_or(false)
+    })
 }
 
 
@@ -113,10 +172,10 @@ pub async fn has_keyring_account() -> bool {
 /// Get the current account info, or error if not logged in.
 #[frb]
 pub async fn get_current_account() -> Result<AccountInfo, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         Ok(AccountInfo {
-            pubkey_hex: s.keys.public_key().to_hex(),
-            npub: s.keys.public_key().to_bech32().map_err(|e| BurrowError::from(e.to_string()))?,
+            pubkey_hex: s.signer.public_key().to_hex(),
+            npub: s.signer.public_key().to_bech32().map_err(|e| BurrowError::from(e.to_string()))?,
         })
     })
     .await
@@ -1,8 +1,10 @@
 //! Persistent app state stored in a SQLite database alongside the MLS data.
 //!
-//! Stores per-group read markers, archive state, and other UI metadata.
+//! Stores per-group read markers, archive state, the outbox of unpublished
+//! sends, and other UI metadata.
 //! Follows the "Rust owns data" principle — Flutter never persists state directly.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{LazyLock, Mutex};
 
@@ -10,10 +12,24 @@ use flutter_rust_bridge::frb;
 use rusqlite::{params, Connection};
 
 use crate::api::error::BurrowError;
+use crate::api::group::EpochInfo;
 use crate::api::state;
 
 static APP_DB: LazyLock<Mutex<Option<Connection>>> = LazyLock::new(|| Mutex::new(None));
 
+/// Schema version recorded in `PRAGMA user_version`. Bump on table changes.
+const APP_DB_SCHEMA_VERSION: u32 = 7;
+
+/// Columns added to `follows` after it was first created, with the schema
+/// version that added them.
+const FOLLOWS_COLUMN_MIGRATIONS: &[(u32, &str)] = &[
+    (5, "key_package_misses INTEGER NOT NULL DEFAULT 0"),
+    (6, "nip05 TEXT"),
+    (6, "nip05_verified INTEGER NOT NULL DEFAULT 0"),
+    (7, "nip05_checked_at INTEGER"),
+    (7, "nip05_misses INTEGER NOT NULL DEFAULT 0"),
+];
+
 /// Initialize (or reinitialize) the app state database.
 /// Called after MdkSqliteStorage::new creates the mls_dir.
 /// `mls_dir` may be a file (MdkSqliteStorage DB) or a directory — we handle
@@ -61,21 +77,68 @@ pub fn init_app_state_db(mls_dir: &PathBuf) -> Result<(), BurrowError> {
                 picture TEXT,
                 has_key_package INTEGER NOT NULL DEFAULT 0,
                 key_package_checked_at INTEGER,
+                key_package_misses INTEGER NOT NULL DEFAULT 0,
+                nip05 TEXT,
+                nip05_verified INTEGER NOT NULL DEFAULT 0,
+                nip05_checked_at INTEGER,
+                nip05_misses INTEGER NOT NULL DEFAULT 0,
                 created_at INTEGER NOT NULL DEFAULT (strftime('%s','now'))
             );
 
             CREATE TABLE IF NOT EXISTS contacts_meta (
                 key TEXT PRIMARY KEY,
                 value TEXT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS outbox (
+                wrapper_event_id_hex TEXT PRIMARY KEY,
+                rumor_id_hex TEXT NOT NULL,
+                group_id_hex TEXT NOT NULL,
+                event_json TEXT NOT NULL,
+                epoch INTEGER NOT NULL,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s','now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS epoch_history (
+                group_id_hex TEXT NOT NULL,
+                epoch INTEGER NOT NULL,
+                recorded_at INTEGER NOT NULL,
+                committer_pubkey_hex TEXT,
+                commit_event_id_hex TEXT,
+                added_json TEXT NOT NULL DEFAULT '[]',
+                removed_json TEXT NOT NULL DEFAULT '[]',
+                metadata_changed INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (group_id_hex, epoch)
             );",
         )
         .map_err(|e| BurrowError::from(format!("contacts schema: {e}")))?;
+        let version: u32 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap_or(0);
+        for (added_in, column) in FOLLOWS_COLUMN_MIGRATIONS {
+            if version < *added_in {
+                // Fails harmlessly when the table was just created with it.
+                let _ = conn.execute(&format!("ALTER TABLE follows ADD COLUMN {column}"), []);
+            }
+        }
+        conn.pragma_update(None, "user_version", APP_DB_SCHEMA_VERSION)
+            .map_err(|e| BurrowError::from(format!("app_state user_version: {e}")))?;
         Ok(())
     });
 
     Ok(())
 }
 
+/// Schema version of the app state DB, or None if it is not initialized.
+#[frb(ignore)]
+pub(crate) fn schema_version() -> Option<u32> {
+    with_db(|conn| {
+        conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|e| BurrowError::from(e.to_string()))
+    })
+    .ok()
+}
+
 #[frb(ignore)]
 pub(crate) fn with_db<F, T>(f: F) -> Result<T, BurrowError>
 where
@@ -254,6 +317,156 @@ pub async fn get_archived_group_ids() -> Result<Vec<String>, BurrowError> {
     })
 }
 
+// ---------------------------------------------------------------------------
+// Forgotten groups
+// ---------------------------------------------------------------------------
+
+/// Forget a group locally: drop its read markers and other per-group
+/// state and mark it forgotten at `epoch` so it no longer shows up in
+/// group lists. Runs in one transaction; the caller erases its messages.
+///
+/// MDK storage has no group delete, so the MLS group record itself stays;
+/// the forgotten epoch is what keeps `reconcile_groups` from listing it
+/// again unless we rejoin (which moves the group past that epoch).
+#[frb(ignore)]
+pub(crate) fn forget_group_local(group_id_hex: &str, epoch: u64) -> Result<(), BurrowError> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    with_db(|conn| {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        tx.execute(
+            "DELETE FROM app_state WHERE group_id_hex = ?1",
+            params![group_id_hex],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        tx.execute(
+            "INSERT INTO app_state (group_id_hex, key, value, updated_at)
+             VALUES (?1, 'forgotten', 'true', ?2), (?1, 'forgotten_epoch', ?3, ?2)",
+            params![group_id_hex, now, epoch.to_string()],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        tx.commit().map_err(|e| BurrowError::from(e.to_string()))
+    })
+}
+
+/// IDs of forgotten groups. Empty if the DB is not initialized.
+#[frb(ignore)]
+pub(crate) fn forgotten_group_ids() -> std::collections::HashSet<String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT group_id_hex FROM app_state WHERE key = 'forgotten' AND value = 'true'")
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(ids)
+    })
+    .unwrap_or_default()
+}
+
+/// Epoch each forgotten group was at when it was forgotten. Groups marked
+/// forgotten without one (older versions) are left out.
+#[frb(ignore)]
+pub(crate) fn forgotten_group_epochs() -> HashMap<String, u64> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT group_id_hex, value FROM app_state WHERE key = 'forgotten_epoch'")
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let epochs = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .filter_map(|(id, epoch)| Some((id, epoch.parse().ok()?)))
+            .collect();
+        Ok(epochs)
+    })
+    .unwrap_or_default()
+}
+
+/// Clear a group's forgotten mark so it shows up in group lists again.
+#[frb(ignore)]
+pub(crate) fn unforget_group(group_id_hex: &str) -> Result<(), BurrowError> {
+    with_db(|conn| {
+        conn.execute(
+            "DELETE FROM app_state WHERE group_id_hex = ?1 AND key IN ('forgotten', 'forgotten_epoch')",
+            params![group_id_hex],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(())
+    })
+}
+
+/// Every group ID the app DB holds state for (per-group settings, outbox,
+/// epoch history). Empty if the DB is not initialized.
+#[frb(ignore)]
+pub(crate) fn tracked_group_ids() -> std::collections::HashSet<String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT group_id_hex FROM app_state
+                 UNION SELECT group_id_hex FROM outbox
+                 UNION SELECT group_id_hex FROM epoch_history",
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(ids)
+    })
+    .unwrap_or_default()
+}
+
+// ---------------------------------------------------------------------------
+// Disappearing messages
+// ---------------------------------------------------------------------------
+
+/// Default message TTLs of all groups that have one, keyed by group ID
+/// hex, in a single query. Empty if the DB is not initialized.
+#[frb(ignore)]
+pub(crate) fn group_message_ttls() -> HashMap<String, u64> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT group_id_hex, value FROM app_state WHERE key = 'message_ttl_secs'")
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(rows
+            .filter_map(|r| r.ok())
+            .filter_map(|(group, value)| Some((group, value.parse::<u64>().ok()?)))
+            .filter(|(_, ttl)| *ttl > 0)
+            .collect())
+    })
+    .unwrap_or_default()
+}
+
+/// Default message TTL for a group in seconds. None if unset, 0 or the DB
+/// is not initialized.
+#[frb(ignore)]
+pub(crate) fn group_message_ttl(group_id_hex: &str) -> Option<u64> {
+    with_db(|conn| {
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM app_state WHERE group_id_hex = ?1 AND key = 'message_ttl_secs'",
+                params![group_id_hex],
+                |row| row.get(0),
+            )
+            .ok();
+        Ok(value.and_then(|v| v.parse::<u64>().ok()))
+    })
+    .ok()
+    .flatten()
+    .filter(|ttl| *ttl > 0)
+}
+
 // ---------------------------------------------------------------------------
 // Group summary (last message + unread count)
 // ---------------------------------------------------------------------------
@@ -268,6 +481,10 @@ pub struct GroupSummary {
     pub unread_count: u32,
 }
 
+fn is_typing(kind: nostr_sdk::Kind) -> bool {
+    kind == nostr_sdk::Kind::Custom(crate::api::message::TYPING_INDICATOR_KIND)
+}
+
 /// Get the last message and unread count for a group.
 ///
 /// Fetches the most recent message from MDK, and counts messages newer
@@ -278,19 +495,19 @@ pub async fn get_group_summary(
 ) -> Result<GroupSummary, BurrowError> {
     let last_read_ts = get_last_read_timestamp(mls_group_id_hex.clone()).await?.unwrap_or(0);
 
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = mdk_core::prelude::GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
 
-        // Get the most recent message
-        let pagination = mdk_storage_traits::groups::Pagination::new(Some(1), Some(0));
+        // Get the most recent message, skipping typing signals and erased messages
+        let pagination = mdk_storage_traits::groups::Pagination::new(Some(20), Some(0));
         let messages = s
             .mdk
             .get_messages(&group_id, Some(pagination))
             .unwrap_or_default();
 
-        let (last_content, last_ts, last_author) = if let Some(msg) = messages.first() {
+        let (last_content, last_ts, last_author) = if let Some(msg) = messages.iter().find(|m| !is_typing(m.kind) && !crate::api::message::is_erased(m)) {
             (
                 Some(msg.content.clone()),
                 Some(msg.created_at.as_secs() as i64),
@@ -314,7 +531,7 @@ pub async fn get_group_summary(
                 if batch.is_empty() {
                     break;
                 }
-                for msg in &batch {
+                for msg in batch.iter().filter(|m| !is_typing(m.kind) && !crate::api::message::is_erased(m)) {
                     if (msg.created_at.as_secs() as i64) > last_read_ts {
                         count += 1;
                     } else {
@@ -358,3 +575,156 @@ pub async fn import_archived_groups(group_ids: Vec<String>) -> Result<(), Burrow
     }
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// Outbox
+// ---------------------------------------------------------------------------
+
+/// A kind 445 event that was created but not yet accepted by a relay.
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+pub(crate) struct OutboxEntry {
+    pub wrapper_event_id_hex: String,
+    pub rumor_id_hex: String,
+    pub group_id_hex: String,
+    pub event_json: String,
+    /// MLS epoch the event was encrypted under.
+    pub epoch: u64,
+}
+
+/// Queue an encrypted event until a relay accepts it.
+#[frb(ignore)]
+pub(crate) fn outbox_enqueue(entry: &OutboxEntry) -> Result<(), BurrowError> {
+    with_db(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO outbox
+                (wrapper_event_id_hex, rumor_id_hex, group_id_hex, event_json, epoch)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                entry.wrapper_event_id_hex,
+                entry.rumor_id_hex,
+                entry.group_id_hex,
+                entry.event_json,
+                entry.epoch as i64
+            ],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(())
+    })
+}
+
+/// Drop a queued event once it's published (or can never be).
+#[frb(ignore)]
+pub(crate) fn outbox_remove(wrapper_event_id_hex: &str) -> Result<(), BurrowError> {
+    with_db(|conn| {
+        conn.execute(
+            "DELETE FROM outbox WHERE wrapper_event_id_hex = ?1",
+            params![wrapper_event_id_hex],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(())
+    })
+}
+
+/// Queued events, oldest first. Empty if the DB is not initialized.
+#[frb(ignore)]
+pub(crate) fn outbox_pending() -> Vec<OutboxEntry> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT wrapper_event_id_hex, rumor_id_hex, group_id_hex, event_json, epoch
+                 FROM outbox ORDER BY created_at, rowid",
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let entries = stmt
+            .query_map([], |row| {
+                Ok(OutboxEntry {
+                    wrapper_event_id_hex: row.get(0)?,
+                    rumor_id_hex: row.get(1)?,
+                    group_id_hex: row.get(2)?,
+                    event_json: row.get(3)?,
+                    epoch: row.get::<_, i64>(4)? as u64,
+                })
+            })
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(entries)
+    })
+    .unwrap_or_default()
+}
+
+/// Number of sends waiting in the outbox.
+#[frb]
+pub async fn get_outbox_count() -> Result<u32, BurrowError> {
+    with_db(|conn| {
+        conn.query_row("SELECT COUNT(*) FROM outbox", [], |row| row.get::<_, u32>(0))
+            .map_err(|e| BurrowError::from(e.to_string()))
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Epoch history
+// ---------------------------------------------------------------------------
+
+/// Record the commit that moved a group into `info.epoch`. A later record
+/// for the same epoch (e.g. after a fork was resolved) replaces it.
+#[frb(ignore)]
+pub(crate) fn record_epoch(group_id_hex: &str, info: &EpochInfo) -> Result<(), BurrowError> {
+    let added = serde_json::to_string(&info.added_pubkeys).unwrap_or_else(|_| "[]".into());
+    let removed = serde_json::to_string(&info.removed_pubkeys).unwrap_or_else(|_| "[]".into());
+    with_db(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO epoch_history
+                (group_id_hex, epoch, recorded_at, committer_pubkey_hex, commit_event_id_hex,
+                 added_json, removed_json, metadata_changed)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                group_id_hex,
+                info.epoch as i64,
+                info.recorded_at.unwrap_or(0) as i64,
+                info.committer_pubkey_hex,
+                info.commit_event_id_hex,
+                added,
+                removed,
+                info.metadata_changed
+            ],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(())
+    })
+}
+
+/// Recorded epochs for a group, oldest first. Empty if the DB is not
+/// initialized.
+#[frb(ignore)]
+pub(crate) fn epoch_history(group_id_hex: &str) -> Vec<EpochInfo> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT epoch, recorded_at, committer_pubkey_hex, commit_event_id_hex,
+                        added_json, removed_json, metadata_changed
+                 FROM epoch_history WHERE group_id_hex = ?1 ORDER BY epoch",
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let rows = stmt
+            .query_map(params![group_id_hex], |row| {
+                let added: String = row.get(4)?;
+                let removed: String = row.get(5)?;
+                Ok(EpochInfo {
+                    epoch: row.get::<_, i64>(0)? as u64,
+                    recorded_at: Some(row.get::<_, i64>(1)? as u64),
+                    committer_pubkey_hex: row.get(2)?,
+                    commit_event_id_hex: row.get(3)?,
+                    added_pubkeys: serde_json::from_str(&added).unwrap_or_default(),
+                    removed_pubkeys: serde_json::from_str(&removed).unwrap_or_default(),
+                    metadata_changed: row.get(6)?,
+                })
+            })
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(rows)
+    })
+    .unwrap_or_default()
+}
@@ -0,0 +1,271 @@
+//! Device migration: export and import the full account state.
+//!
+//! A bundle carries the identity, the MDK SQLite database (with the
+//! keyring-held key needed to open it on another device) and the app state
+//! DB, sealed with a passphrase. Without it, moving to a new device loses
+//! all group membership and message history.
+//!
+//! Bundle layout: `"BURROWSTATE1" | salt [16] | nonce [12] | ciphertext`,
+//! where the key is Argon2id(passphrase, salt) and the ciphertext is
+//! ChaCha20-Poly1305 over a JSON manifest.
+
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use flutter_rust_bridge::frb;
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::api::account::AccountInfo;
+use crate::api::app_state;
+use crate::api::error::BurrowError;
+use crate::api::state;
+
+const BUNDLE_MAGIC: &[u8; 12] = b"BURROWSTATE1";
+const BUNDLE_VERSION: u32 = 1;
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+#[derive(Serialize, Deserialize)]
+struct StateBundle {
+    version: u32,
+    created_at: u64,
+    pubkey_hex: String,
+    nsec: String,
+    /// Keyring secret protecting the MDK database (base64), if one exists.
+    mdk_db_key_b64: Option<String>,
+    /// Files under `<data_dir>/mls`, paths relative to it.
+    files: Vec<BundleFile>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BundleFile {
+    path: String,
+    data_b64: String,
+}
+
+/// Export the current account as a passphrase-encrypted bundle.
+///
+/// Runs under the state write lock, so the message listener and any other
+/// MDK writer are paused while the databases are checkpointed and copied;
+/// the snapshot can't be torn by a concurrent commit.
+#[frb]
+pub async fn export_state(passphrase: String) -> Result<Vec<u8>, BurrowError> {
+    check_passphrase(&passphrase)?;
+    state::initialize_keyring_store();
+    let mls_root = state::get_data_dir()?.join("mls");
+
+    let bundle = state::with_state_mut(|s| {
+        let pubkey_hex = s.signer.public_key().to_hex();
+
+        // Fold the app DB's WAL into the main file before copying it.
+        let _ = app_state::with_db(|conn| {
+            conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+                .map_err(|e| BurrowError::from(e.to_string()))
+        });
+
+        let mdk_db_key_b64 = keyring_core::Entry::new(state::KEYRING_SERVICE_ID, &state::mdk_db_key_id(&s.signer.public_key()))
+            .and_then(|entry| entry.get_secret())
+            .ok()
+            .map(|key| base64::engine::general_purpose::STANDARD.encode(key));
+
+        let mut files = Vec::new();
+        collect_account_files(&mls_root, &mls_root, &pubkey_hex, &mut files)?;
+        if files.is_empty() {
+            return Err(BurrowError::from("No MLS data found to export".to_string()));
+        }
+
+        Ok(StateBundle {
+            version: BUNDLE_VERSION,
+            created_at: Timestamp::now().as_secs(),
+            pubkey_hex,
+            nsec: s
+                .signer
+                .secret_keys()?
+                .secret_key()
+                .to_bech32()
+                .map_err(|e| BurrowError::from(e.to_string()))?,
+            mdk_db_key_b64,
+            files,
+        })
+    })
+    .await?;
+
+    let json = serde_json::to_vec(&bundle).map_err(|e| BurrowError::from(e.to_string()))?;
+    seal_bundle(&json, &passphrase)
+}
+
+/// Restore an account from a bundle created by `export_state` and log in.
+///
+/// Must be called while logged out. Refuses to overwrite existing MLS data
+/// for the same account on this device.
+#[frb]
+pub async fn import_state(bundle: Vec<u8>, passphrase: String) -> Result<AccountInfo, BurrowError> {
+    if state::is_initialized().await {
+        return Err(BurrowError::from("Log out before importing an account".to_string()));
+    }
+    state::initialize_keyring_store();
+
+    let json = open_bundle(&bundle, &passphrase)?;
+    let bundle: StateBundle =
+        serde_json::from_slice(&json).map_err(|e| BurrowError::from(format!("Corrupt bundle: {e}")))?;
+    if bundle.version > BUNDLE_VERSION {
+        return Err(BurrowError::from(format!(
+            "Bundle version {} is newer than this app supports",
+            bundle.version
+        )));
+    }
+
+    let keys = Keys::parse(&bundle.nsec).map_err(|e| BurrowError::from(e.to_string()))?;
+    if keys.public_key().to_hex() != bundle.pubkey_hex {
+        return Err(BurrowError::from("Bundle identity mismatch".to_string()));
+    }
+
+    let mls_root = state::get_data_dir()?.join("mls");
+    if mls_root.join(&bundle.pubkey_hex).exists() {
+        return Err(BurrowError::from(
+            "This account already has data on this device".to_string(),
+        ));
+    }
+
+    // Stage everything first, then move into place, so a failure midway
+    // never leaves a half-written account behind.
+    let staging = mls_root.join(format!(".import-{}", bundle.pubkey_hex));
+    let _ = std::fs::remove_dir_all(&staging);
+    let result = (|| -> Result<(), BurrowError> {
+        for file in &bundle.files {
+            let rel = safe_relative_path(&file.path, &bundle.pubkey_hex)?;
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(&file.data_b64)
+                .map_err(|e| BurrowError::from(format!("Corrupt bundle file: {e}")))?;
+            let dest = staging.join(&rel);
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(dest, data)?;
+        }
+
+        if let Some(key_b64) = &bundle.mdk_db_key_b64 {
+            let key = base64::engine::general_purpose::STANDARD
+                .decode(key_b64)
+                .map_err(|e| BurrowError::from(format!("Corrupt bundle key: {e}")))?;
+            keyring_core::Entry::new(state::KEYRING_SERVICE_ID, &state::mdk_db_key_id(&keys.public_key()))
+                .and_then(|entry| entry.set_secret(&key))
+                .map_err(|e| BurrowError::from(format!("Keyring save: {e}")))?;
+        }
+
+        for entry in std::fs::read_dir(&staging)? {
+            let entry = entry?;
+            std::fs::rename(entry.path(), mls_root.join(entry.file_name()))?;
+        }
+        Ok(())
+    })();
+    let _ = std::fs::remove_dir_all(&staging);
+    result?;
+
+    crate::api::account::login(bundle.nsec).await
+}
+
+/// Encrypt `plaintext` with a key derived from `passphrase`.
+#[frb(ignore)]
+pub fn seal_bundle(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>, BurrowError> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::ChaCha20Poly1305;
+
+    let salt: [u8; 16] = rand::random();
+    let nonce: [u8; 12] = rand::random();
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new_from_slice(&key).map_err(|e| BurrowError::from(e.to_string()))?;
+    let ciphertext = cipher
+        .encrypt(&nonce.into(), plaintext)
+        .map_err(|e| BurrowError::from(format!("Bundle encryption failed: {e}")))?;
+
+    let mut out = Vec::with_capacity(BUNDLE_MAGIC.len() + 28 + ciphertext.len());
+    out.extend_from_slice(BUNDLE_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Decrypt a bundle produced by `seal_bundle`.
+#[frb(ignore)]
+pub fn open_bundle(bundle: &[u8], passphrase: &str) -> Result<Vec<u8>, BurrowError> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::ChaCha20Poly1305;
+
+    let header_len = BUNDLE_MAGIC.len() + 16 + 12;
+    if bundle.len() < header_len || &bundle[..BUNDLE_MAGIC.len()] != BUNDLE_MAGIC {
+        return Err(BurrowError::from("Not a Burrow state bundle".to_string()));
+    }
+    let salt = &bundle[BUNDLE_MAGIC.len()..BUNDLE_MAGIC.len() + 16];
+    let nonce: [u8; 12] = bundle[BUNDLE_MAGIC.len() + 16..header_len].try_into().unwrap();
+    let key = derive_key(passphrase, salt)?;
+    let cipher = ChaCha20Poly1305::new_from_slice(&key).map_err(|e| BurrowError::from(e.to_string()))?;
+    cipher
+        .decrypt(&nonce.into(), &bundle[header_len..])
+        .map_err(|_| BurrowError::from("Wrong passphrase or corrupted bundle".to_string()))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], BurrowError> {
+    let mut key = [0u8; 32];
+    argon2::Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| BurrowError::from(format!("Key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), BurrowError> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(BurrowError::from(format!(
+            "Passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+        )));
+    }
+    Ok(())
+}
+
+/// Collect every file under `dir` that belongs to `pubkey_hex`: the MDK
+/// database (flat file or directory), its WAL/SHM and the app state DB.
+fn collect_account_files(
+    root: &Path,
+    dir: &Path,
+    pubkey_hex: &str,
+    out: &mut Vec<BundleFile>,
+) -> Result<(), BurrowError> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let rel = path.strip_prefix(root).unwrap_or(&path);
+        let owned = rel
+            .components()
+            .next()
+            .map(|c| c.as_os_str().to_string_lossy().starts_with(pubkey_hex))
+            .unwrap_or(false);
+        if !owned {
+            continue;
+        }
+        if path.is_dir() {
+            collect_account_files(root, &path, pubkey_hex, out)?;
+        } else {
+            out.push(BundleFile {
+                path: rel.to_string_lossy().replace('\\', "/"),
+                data_b64: base64::engine::general_purpose::STANDARD.encode(std::fs::read(&path)?),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Reject bundle paths that escape the MLS dir or belong to another account.
+fn safe_relative_path(path: &str, pubkey_hex: &str) -> Result<PathBuf, BurrowError> {
+    let rel = PathBuf::from(path);
+    let ok = rel
+        .components()
+        .all(|c| matches!(c, std::path::Component::Normal(_)))
+        && path.starts_with(pubkey_hex);
+    if !ok {
+        return Err(BurrowError::from(format!("Unsafe path in bundle: {path}")));
+    }
+    Ok(rel)
+}
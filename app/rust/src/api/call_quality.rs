@@ -297,6 +297,60 @@ fn score_bitrate(kbps: f64, is_video: bool) -> f64 {
     }
 }
 
+// ── Jitter Buffer ──────────────────────────────────────────────────────────
+
+/// Jitter buffer sizing for the audio receive path.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JitterBufferConfig {
+    /// Buffer depth to aim for, in ms.
+    pub target_ms: u32,
+    /// Lower bound the adaptive buffer may shrink to, in ms.
+    pub min_ms: u32,
+    /// Upper bound the adaptive buffer may grow to, in ms.
+    pub max_ms: u32,
+    /// Human-readable reason for the sizing.
+    pub reason: String,
+}
+
+/// Recommend jitter buffer sizes from measured jitter and RTT.
+///
+/// Target is ~2× jitter plus one 20ms Opus frame, so low-jitter links stay
+/// snappy while jittery links trade latency for fewer gaps. On high-RTT links
+/// conversation is already delayed, so a little extra smoothing is added.
+/// All values are rounded up to 10ms and kept within 20–1000ms.
+#[frb]
+pub fn recommend_jitter_buffer(measured_jitter_ms: f64, rtt_ms: f64) -> JitterBufferConfig {
+    let jitter = if measured_jitter_ms.is_finite() { measured_jitter_ms.max(0.0) } else { 0.0 };
+    let rtt = if rtt_ms.is_finite() { rtt_ms.max(0.0) } else { 0.0 };
+
+    let rtt_bias = if rtt > 300.0 { (rtt * 0.1).min(40.0) } else { 0.0 };
+    let target = round_up_10(jitter * 2.0 + 20.0 + rtt_bias).clamp(20, 400);
+    let min = round_up_10(jitter).clamp(20, target);
+    let max = round_up_10(target as f64 * 2.5).clamp(target, 1000);
+
+    let reason = if jitter <= 10.0 {
+        "Low jitter: minimal buffering for lowest latency"
+    } else if jitter <= 30.0 {
+        "Moderate jitter: small buffer to absorb variation"
+    } else if jitter <= 50.0 {
+        "High jitter: larger buffer to avoid audio gaps"
+    } else {
+        "Severe jitter: prioritizing smooth playback over latency"
+    };
+
+    JitterBufferConfig {
+        target_ms: target,
+        min_ms: min,
+        max_ms: max,
+        reason: reason.to_string(),
+    }
+}
+
+fn round_up_10(ms: f64) -> u32 {
+    ((ms / 10.0).ceil() * 10.0) as u32
+}
+
 // ── Simulcast Configuration ────────────────────────────────────────────────
 
 /// A single simulcast layer.
@@ -10,11 +10,17 @@
 //! - 25053: Call End/Hangup
 //! - 25054: Call State Update (mute, camera toggle)
 
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::api::diagnostics;
+use crate::api::ephemeral;
 use crate::api::error::BurrowError;
+use crate::api::message;
 use crate::api::state;
 use crate::frb_generated::StreamSink;
 
@@ -26,6 +32,30 @@ const KIND_ICE_CANDIDATE: u16 = 25052;
 const KIND_CALL_END: u16 = 25053;
 const KIND_CALL_STATE_UPDATE: u16 = 25054;
 
+/// Largest signaling payload sent in a single gift wrap.
+///
+/// The payload is NIP-44 encrypted twice (seal + wrap), roughly doubling it,
+/// so 16 KiB keeps the kind 1059 event well under common relay size caps.
+/// Larger payloads (full-BUNDLE SDPs with many codecs) are split into
+/// `["fragment", "<index>", "<total>"]` tagged rumors.
+pub const MAX_SIGNALING_PAYLOAD_BYTES: u32 = 16 * 1024;
+
+/// Fragments older than this are dropped from the reassembly buffer.
+const FRAGMENT_TTL_SECS: u64 = 120;
+
+/// Most parts a signaling message may be split into (512 KiB of payload).
+/// A peer-supplied total above this is rejected before anything is
+/// allocated for it.
+const MAX_FRAGMENTS: usize = 32;
+
+/// Sequence numbers are `created_at * SEQUENCE_PER_SECOND + n`, where `n`
+/// counts earlier events of the same call in the same second.
+const SEQUENCE_PER_SECOND: u64 = 1000;
+
+/// Per-call signaling state with no events for this long is dropped, so
+/// calls that never saw a hangup don't leak.
+const SIGNALING_STATE_MAX_AGE_SECS: u64 = 6 * 3600;
+
 // ── FFI-friendly types ─────────────────────────────────────────────────────
 
 /// Payload for a call offer event.
@@ -33,12 +63,18 @@ const KIND_CALL_STATE_UPDATE: u16 = 25054;
 struct CallOfferPayload {
     sdp: String,
     call_type: String, // "audio" or "video"
+    /// New SDP for an existing call (e.g. adding video), not a new call.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    renegotiate: bool,
 }
 
 /// Payload for a call answer event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CallAnswerPayload {
     sdp: String,
+    /// Answer to a renegotiation offer rather than the initial one.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    renegotiate: bool,
 }
 
 /// Payload for an ICE candidate event.
@@ -72,6 +108,13 @@ pub struct CallSignalingEvent {
     pub content: String,
     /// Unix timestamp.
     pub created_at: u64,
+    /// For offers/answers: applies to the existing call's connection
+    /// (renegotiation) instead of starting a new call.
+    pub renegotiate: bool,
+    /// Ordering key within the call, increasing with `created_at` (and
+    /// arrival order within a second). Relays don't preserve order, so
+    /// sort buffered events by this before applying them.
+    pub sequence: u64,
 }
 
 // ── Helper: build signaling event tags ─────────────────────────────────────
@@ -107,6 +150,8 @@ fn signaling_tags(
 /// Build and gift-wrap a signaling event (NIP-59) for a 1:1 call.
 ///
 /// Returns JSON-serialized gift-wrapped Event (kind 1059) ready for relay publication.
+/// Fails with a clear error if the payload is too large for one event; use
+/// `build_gift_wrapped_signaling_fragments` for payloads that may be large.
 async fn build_gift_wrapped_signaling(
     kind_num: u16,
     content: &str,
@@ -114,37 +159,232 @@ async fn build_gift_wrapped_signaling(
     call_id: &str,
     call_type: Option<&str>,
 ) -> Result<String, BurrowError> {
+    if content.len() > MAX_SIGNALING_PAYLOAD_BYTES as usize {
+        return Err(BurrowError::from(format!(
+            "Signaling payload is {} bytes, over the {} byte single-event limit; \
+             use the fragmenting variant (e.g. initiate_call_fragments)",
+            content.len(),
+            MAX_SIGNALING_PAYLOAD_BYTES
+        )));
+    }
+    let mut events = build_gift_wrapped_signaling_fragments(
+        kind_num,
+        content,
+        recipient_pubkey_hex,
+        call_id,
+        call_type,
+    )
+    .await?;
+    Ok(events.remove(0))
+}
+
+/// Build gift-wrapped signaling events, splitting oversized payloads.
+///
+/// Returns one event for payloads within `MAX_SIGNALING_PAYLOAD_BYTES`,
+/// otherwise one event per fragment in order. Publish all of them.
+async fn build_gift_wrapped_signaling_fragments(
+    kind_num: u16,
+    content: &str,
+    recipient_pubkey_hex: &str,
+    call_id: &str,
+    call_type: Option<&str>,
+) -> Result<Vec<String>, BurrowError> {
     let expiration = Timestamp::now().as_secs() + 60; // 60s TTL
     let tags = signaling_tags(recipient_pubkey_hex, call_id, call_type, expiration)?;
     let recipient_pk = PublicKey::from_hex(recipient_pubkey_hex)
         .map_err(|e| BurrowError::from(e.to_string()))?;
 
-    let keys = state::with_state(|s| Ok(s.keys.clone())).await?;
+    let signer = state::with_state_read(|s| Ok(s.signer.clone())).await?;
+
+    let chunks = if content.len() > MAX_SIGNALING_PAYLOAD_BYTES as usize {
+        message::split_message_content(content.to_string(), MAX_SIGNALING_PAYLOAD_BYTES)
+    } else {
+        vec![content.to_string()]
+    };
+    let total = chunks.len();
+    if total > MAX_FRAGMENTS {
+        return Err(BurrowError::from(format!(
+            "Signaling payload is {} bytes, too large to send even in {} fragments",
+            content.len(),
+            MAX_FRAGMENTS
+        )));
+    }
 
-    // Build the inner rumor as unsigned event
-    let rumor = EventBuilder::new(Kind::from(kind_num), content)
-        .tags(tags)
-        .build(keys.public_key());
+    let mut events = Vec::with_capacity(total);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let mut rumor_tags = tags.clone();
+        if total > 1 {
+            rumor_tags.push(Tag::custom(
+                TagKind::custom("fragment"),
+                vec![(i + 1).to_string(), total.to_string()],
+            ));
+        }
 
-    // Gift wrap using NIP-59
-    let gift_wrap = EventBuilder::gift_wrap(
-        &keys,
-        &recipient_pk,
-        rumor,
-        Vec::<Tag>::new(),
-    )
-    .await
-    .map_err(|e| BurrowError::from(e.to_string()))?;
+        // Build the inner rumor as unsigned event
+        let rumor = EventBuilder::new(Kind::from(kind_num), chunk)
+            .tags(rumor_tags)
+            .build(signer.public_key());
+
+        // Gift wrap using NIP-59
+        let gift_wrap =
+            ephemeral::gift_wrap(&signer, &recipient_pk, rumor, Vec::<Tag>::new()).await?;
+        events.push(serde_json::to_string(&gift_wrap).map_err(|e| BurrowError::from(e.to_string()))?);
+    }
+    Ok(events)
+}
+
+/// `(index, total)` from a rumor's `fragment` tag, 1-based. `None` for an
+/// out-of-range index or a total over `MAX_FRAGMENTS`.
+fn fragment_info(tags: &Tags) -> Option<(usize, usize)> {
+    let tag = tags
+        .iter()
+        .find(|t| t.as_slice().first().map(|v| v == "fragment").unwrap_or(false))?;
+    let index = tag.as_slice().get(1)?.parse::<usize>().ok()?;
+    let total = tag.as_slice().get(2)?.parse::<usize>().ok()?;
+    (index >= 1 && index <= total && total <= MAX_FRAGMENTS).then_some((index, total))
+}
+
+/// Fragments received so far for one signaling message.
+struct PendingFragments {
+    first_seen: u64,
+    parts: Vec<Option<String>>,
+}
+
+/// Add a fragment; returns the reassembled payload once all parts are in.
+/// Fragments with an index or total `fragment_info` would reject are ignored.
+fn add_fragment(
+    buffer: &mut HashMap<(String, String, u16), PendingFragments>,
+    key: (String, String, u16),
+    index: usize,
+    total: usize,
+    content: String,
+) -> Option<String> {
+    if index == 0 || index > total || total > MAX_FRAGMENTS {
+        return None;
+    }
+    let now = Timestamp::now().as_secs();
+    buffer.retain(|_, p| now.saturating_sub(p.first_seen) <= FRAGMENT_TTL_SECS);
+
+    let pending = buffer.entry(key.clone()).or_insert_with(|| PendingFragments {
+        first_seen: now,
+        parts: vec![None; total],
+    });
+    if pending.parts.len() != total {
+        // Sender restarted with a different split; start over
+        *pending = PendingFragments {
+            first_seen: now,
+            parts: vec![None; total],
+        };
+    }
+    pending.parts[index - 1] = Some(content);
+    if pending.parts.iter().all(Option::is_some) {
+        let payload = buffer.remove(&key)?.parts.into_iter().flatten().collect();
+        return Some(payload);
+    }
+    None
+}
+
+/// The `call-id` and optional `call-type` tags of an incoming event, or
+/// `None` if the call ID isn't a UUID or the call type isn't "audio" or
+/// "video". Peers control these strings, so nothing else reaches Dart.
+fn call_tags(tags: &Tags) -> Option<(String, Option<String>)> {
+    let tag_value = |name: &str| {
+        tags.iter()
+            .find(|t| t.as_slice().first().map(|v| v == name).unwrap_or(false))
+            .and_then(|t| t.as_slice().get(1).cloned())
+    };
+    let call_id = tag_value("call-id").filter(|id| is_uuid(id))?;
+    let call_type = tag_value("call-type");
+    if call_type.as_deref().is_some_and(|t| t != "audio" && t != "video") {
+        return None;
+    }
+    Some((call_id, call_type))
+}
+
+/// Whether `s` is a hyphenated UUID (any version).
+fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
 
-    serde_json::to_string(&gift_wrap).map_err(|e| BurrowError::from(e.to_string()))
+/// Reject call IDs the peer's `call_tags` would drop, so a bad ID fails at
+/// the caller instead of as a call that never rings.
+fn check_call_id(call_id: &str) -> Result<(), BurrowError> {
+    if is_uuid(call_id) {
+        Ok(())
+    } else {
+        Err(BurrowError::from(format!("Call ID must be a hyphenated UUID, got '{}'", call_id)))
+    }
+}
+
+/// Per-call state of incoming signaling.
+#[derive(Default)]
+struct CallSignalingState {
+    /// ICE candidate strings already delivered.
+    seen_candidates: HashSet<String>,
+    /// Events sequenced so far in each `created_at` second.
+    per_second: HashMap<u64, u64>,
+    /// Local time of the last event, for pruning.
+    last_seen: u64,
+}
+
+static SIGNALING_STATE: OnceLock<Mutex<HashMap<String, CallSignalingState>>> = OnceLock::new();
+
+/// Sequence number for an incoming event, or `None` for an ICE candidate
+/// this call already delivered (relays redeliver the same gift wrap).
+///
+/// A call end drops the call's state, and state idle for
+/// `SIGNALING_STATE_MAX_AGE_SECS` is pruned on every event.
+fn sequence_event(call_id: &str, kind: u16, content: &str, created_at: u64) -> Option<u64> {
+    sequence_event_at(call_id, kind, content, created_at, Timestamp::now().as_secs())
+}
+
+fn sequence_event_at(call_id: &str, kind: u16, content: &str, created_at: u64, now: u64) -> Option<u64> {
+    let mut calls = SIGNALING_STATE
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    calls.retain(|_, call| now.saturating_sub(call.last_seen) <= SIGNALING_STATE_MAX_AGE_SECS);
+    if kind == KIND_CALL_END {
+        calls.remove(call_id);
+        // Sort after everything else the call sent in that second.
+        return Some(created_at * SEQUENCE_PER_SECOND + SEQUENCE_PER_SECOND - 1);
+    }
+    let call = calls.entry(call_id.to_string()).or_default();
+    call.last_seen = now;
+    if kind == KIND_ICE_CANDIDATE {
+        let candidate = serde_json::from_str::<IceCandidatePayload>(content)
+            .map(|p| p.candidate)
+            .unwrap_or_else(|_| content.to_string());
+        if !call.seen_candidates.insert(candidate) {
+            return None;
+        }
+    }
+    let n = call.per_second.entry(created_at).or_insert(0);
+    let sequence = created_at * SEQUENCE_PER_SECOND + (*n).min(SEQUENCE_PER_SECOND - 1);
+    *n += 1;
+    Some(sequence)
 }
 
 // ── Public API ──────────────────────────────────────────────────────────────
 
+/// Forget the delivered ICE candidates and sequencing of a call; a new call
+/// with the same ID then starts fresh. `end_call`, `reject_call` and an
+/// incoming call end already do this.
+#[frb(sync)]
+pub fn reset_call_signaling_state(call_id: String) {
+    if let Some(calls) = SIGNALING_STATE.get() {
+        calls.lock().unwrap_or_else(PoisonError::into_inner).remove(&call_id);
+    }
+}
+
 /// Initiate a call by creating a gift-wrapped call offer event (kind 25050).
 ///
 /// `sdp_offer`: SDP offer string from WebRTC.
-/// `call_id`: Unique call identifier (UUIDv4).
+/// `call_id`: Unique call identifier (UUIDv4); anything else is rejected.
 /// `call_type`: "audio" or "video".
 /// `recipient_pubkey_hex`: Hex-encoded public key of the callee.
 ///
@@ -156,9 +396,11 @@ pub async fn initiate_call(
     call_type: String,
     recipient_pubkey_hex: String,
 ) -> Result<String, BurrowError> {
+    check_call_id(&call_id)?;
     let payload = serde_json::to_string(&CallOfferPayload {
         sdp: sdp_offer,
         call_type: call_type.clone(),
+        renegotiate: false,
     })
     .map_err(|e| BurrowError::from(e.to_string()))?;
 
@@ -185,8 +427,11 @@ pub async fn accept_call(
     call_id: String,
     caller_pubkey_hex: String,
 ) -> Result<String, BurrowError> {
-    let payload = serde_json::to_string(&CallAnswerPayload { sdp: sdp_answer })
-        .map_err(|e| BurrowError::from(e.to_string()))?;
+    let payload = serde_json::to_string(&CallAnswerPayload {
+        sdp: sdp_answer,
+        renegotiate: false,
+    })
+    .map_err(|e| BurrowError::from(e.to_string()))?;
 
     build_gift_wrapped_signaling(
         KIND_CALL_ANSWER,
@@ -198,6 +443,122 @@ pub async fn accept_call(
     .await
 }
 
+/// Like `initiate_call`, but splits a large SDP offer across several
+/// gift-wrapped events instead of failing. Publish every returned event.
+#[frb]
+pub async fn initiate_call_fragments(
+    sdp_offer: String,
+    call_id: String,
+    call_type: String,
+    recipient_pubkey_hex: String,
+) -> Result<Vec<String>, BurrowError> {
+    check_call_id(&call_id)?;
+    let payload = serde_json::to_string(&CallOfferPayload {
+        sdp: sdp_offer,
+        call_type: call_type.clone(),
+        renegotiate: false,
+    })
+    .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    build_gift_wrapped_signaling_fragments(
+        KIND_CALL_OFFER,
+        &payload,
+        &recipient_pubkey_hex,
+        &call_id,
+        Some(&call_type),
+    )
+    .await
+}
+
+/// Like `accept_call`, but splits a large SDP answer across several
+/// gift-wrapped events instead of failing. Publish every returned event.
+#[frb]
+pub async fn accept_call_fragments(
+    sdp_answer: String,
+    call_id: String,
+    caller_pubkey_hex: String,
+) -> Result<Vec<String>, BurrowError> {
+    let payload = serde_json::to_string(&CallAnswerPayload {
+        sdp: sdp_answer,
+        renegotiate: false,
+    })
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    build_gift_wrapped_signaling_fragments(
+        KIND_CALL_ANSWER,
+        &payload,
+        &caller_pubkey_hex,
+        &call_id,
+        None,
+    )
+    .await
+}
+
+/// Renegotiate an established call with a new SDP offer (kind 25050).
+///
+/// Used for mid-call changes such as adding video or screen share. The
+/// offer carries `renegotiate: true`, so the peer applies it to the existing
+/// connection instead of ringing for a new call. The call type follows the
+/// new SDP: "video" if it has a video m-line, otherwise "audio".
+///
+/// Returns JSON-serialized gift-wrapped Event (kind 1059).
+#[frb]
+pub async fn send_renegotiation_offer(
+    call_id: String,
+    sdp: String,
+    peer_pubkey_hex: String,
+) -> Result<String, BurrowError> {
+    let call_type = if sdp.contains("m=video") { "video" } else { "audio" };
+    let payload = serde_json::to_string(&CallOfferPayload {
+        sdp,
+        call_type: call_type.to_string(),
+        renegotiate: true,
+    })
+    .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    build_gift_wrapped_signaling(
+        KIND_CALL_OFFER,
+        &payload,
+        &peer_pubkey_hex,
+        &call_id,
+        Some(call_type),
+    )
+    .await
+}
+
+/// Answer a renegotiation offer with a new SDP answer (kind 25051).
+///
+/// Returns JSON-serialized gift-wrapped Event (kind 1059).
+#[frb]
+pub async fn send_renegotiation_answer(
+    call_id: String,
+    sdp: String,
+    peer_pubkey_hex: String,
+) -> Result<String, BurrowError> {
+    let payload = serde_json::to_string(&CallAnswerPayload {
+        sdp,
+        renegotiate: true,
+    })
+    .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    build_gift_wrapped_signaling(KIND_CALL_ANSWER, &payload, &peer_pubkey_hex, &call_id, None).await
+}
+
+/// Whether an offer/answer payload is a renegotiation. False for other kinds
+/// and for payloads that don't parse.
+#[frb(ignore)]
+pub fn is_renegotiation(kind: u16, content: &str) -> bool {
+    match kind {
+        KIND_CALL_OFFER => serde_json::from_str::<CallOfferPayload>(content)
+            .map(|p| p.renegotiate)
+            .unwrap_or(false),
+        KIND_CALL_ANSWER => serde_json::from_str::<CallAnswerPayload>(content)
+            .map(|p| p.renegotiate)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 /// Reject an incoming call (kind 25053 with rejection reason).
 ///
 /// `call_id`: Call identifier from the received offer.
@@ -212,6 +573,7 @@ pub async fn reject_call(
     reason: Option<String>,
 ) -> Result<String, BurrowError> {
     let content = reason.unwrap_or_else(|| "declined".to_string());
+    reset_call_signaling_state(call_id.clone());
 
     build_gift_wrapped_signaling(
         KIND_CALL_END,
@@ -268,6 +630,7 @@ pub async fn end_call(
     call_id: String,
     remote_pubkey_hex: String,
 ) -> Result<String, BurrowError> {
+    reset_call_signaling_state(call_id.clone());
     build_gift_wrapped_signaling(
         KIND_CALL_END,
         "hangup",
@@ -317,10 +680,10 @@ pub async fn send_call_state_update(
 /// Returns JSON-serialized Filter.
 #[frb]
 pub async fn subscribe_call_events() -> Result<String, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let filter = Filter::new()
             .kind(Kind::GiftWrap)
-            .pubkey(s.keys.public_key())
+            .pubkey(s.signer.public_key())
             .since(Timestamp::now());
 
         serde_json::to_string(&filter).map_err(|e| BurrowError::from(e.to_string()))
@@ -335,7 +698,9 @@ pub async fn subscribe_call_events() -> Result<String, BurrowError> {
 ///
 /// `event_json`: JSON-serialized inner event (kind 25050-25054).
 ///
-/// Returns `None` if the event is not a call signaling event.
+/// Returns `None` if the event is not a call signaling event, has a
+/// malformed `call-id` or `call-type` tag, or is an ICE candidate already
+/// delivered for its call.
 #[frb]
 pub async fn process_call_event(
     event_json: String,
@@ -350,38 +715,24 @@ pub async fn process_call_event(
         return Ok(None);
     }
 
-    // Extract call-id from tags
-    let call_id = event
-        .tags
-        .iter()
-        .find(|t| {
-            t.as_slice()
-                .first()
-                .map(|v| v == "call-id")
-                .unwrap_or(false)
-        })
-        .and_then(|t| t.as_slice().get(1).cloned())
-        .unwrap_or_default();
+    let Some((call_id, call_type)) = call_tags(&event.tags) else {
+        return Ok(None);
+    };
 
-    // Extract call-type from tags
-    let call_type = event
-        .tags
-        .iter()
-        .find(|t| {
-            t.as_slice()
-                .first()
-                .map(|v| v == "call-type")
-                .unwrap_or(false)
-        })
-        .and_then(|t| t.as_slice().get(1).cloned());
+    let created_at = event.created_at.as_secs();
+    let Some(sequence) = sequence_event(&call_id, kind_num, &event.content, created_at) else {
+        return Ok(None);
+    };
 
     Ok(Some(CallSignalingEvent {
         kind: kind_num as u32,
         sender_pubkey_hex: event.pubkey.to_hex(),
         call_id,
         call_type,
+        renegotiate: is_renegotiation(kind_num, &event.content),
         content: event.content.to_string(),
-        created_at: event.created_at.as_secs(),
+        created_at,
+        sequence,
     }))
 }
 
@@ -391,12 +742,17 @@ pub async fn process_call_event(
 /// unwraps them using NIP-59, and pushes any call signaling events (kinds 25050-25054)
 /// to the provided stream sink.
 ///
+/// Fragmented offers/answers are buffered per (sender, call, kind) and
+/// forwarded once, reassembled, when the last fragment arrives. Duplicate
+/// ICE candidates are dropped and every event carries a `sequence` for
+/// reordering; call `reset_call_signaling_state` when the call ends.
+///
 /// Runs indefinitely until the stream is closed from the Dart side.
 #[frb]
 pub async fn listen_for_call_events(
     sink: StreamSink<CallSignalingEvent>,
 ) -> Result<(), BurrowError> {
-    let (client, keys) = state::with_state(|s| Ok((s.client.clone(), s.keys.clone()))).await?;
+    let (client, my_pubkey) = state::with_state_read(|s| Ok((s.client.clone(), s.signer.public_key()))).await?;
 
     // Subscribe to gift-wrapped events addressed to us.
     // NIP-59 randomizes the outer event timestamp by up to ±2 days,
@@ -404,7 +760,7 @@ pub async fn listen_for_call_events(
     let since = Timestamp::from(Timestamp::now().as_secs().saturating_sub(3 * 86400));
     let filter = Filter::new()
         .kind(Kind::GiftWrap)
-        .pubkey(keys.public_key())
+        .pubkey(my_pubkey)
         .since(since);
 
     client
@@ -412,16 +768,23 @@ pub async fn listen_for_call_events(
         .await
         .map_err(|e| BurrowError::from(e.to_string()))?;
 
+    let fragments: Mutex<HashMap<(String, String, u16), PendingFragments>> =
+        Mutex::new(HashMap::new());
+
     // Listen for notifications
     client
         .handle_notifications(|notification| {
             let sink = &sink;
-            let _keys = &keys;
+            let my_pubkey = &my_pubkey;
             let client = &client;
+            let fragments = &fragments;
             async move {
                 if let nostr_sdk::RelayPoolNotification::Event { event, .. } = notification {
-                    // Only process gift wraps
-                    if event.kind == Kind::GiftWrap {
+                    // Only process gift wraps addressed to us; others are
+                    // expected noise and skipped without unwrapping
+                    if event.kind == Kind::GiftWrap
+                        && ephemeral::is_addressed_to(&event, my_pubkey)
+                    {
                         // Unwrap the gift wrap
                         match client.unwrap_gift_wrap(&event).await {
                             Ok(unwrapped) => {
@@ -438,43 +801,60 @@ pub async fn listen_for_call_events(
                                     if age_secs > 120 {
                                         return Ok(false);
                                     }
-                                    let call_id = rumor
-                                        .tags
-                                        .iter()
-                                        .find(|t| {
-                                            t.as_slice()
-                                                .first()
-                                                .map(|v| v == "call-id")
-                                                .unwrap_or(false)
-                                        })
-                                        .and_then(|t| t.as_slice().get(1).cloned())
-                                        .unwrap_or_default();
-
-                                    let call_type = rumor
-                                        .tags
-                                        .iter()
-                                        .find(|t| {
-                                            t.as_slice()
-                                                .first()
-                                                .map(|v| v == "call-type")
-                                                .unwrap_or(false)
-                                        })
-                                        .and_then(|t| t.as_slice().get(1).cloned());
+                                    let Some((call_id, call_type)) = call_tags(&rumor.tags)
+                                    else {
+                                        return Ok(false); // malformed call-id or call-type
+                                    };
+
+                                    let sender_pubkey_hex = unwrapped.sender.to_hex();
+                                    let content = match fragment_info(&rumor.tags) {
+                                        None => rumor.content.to_string(),
+                                        Some((index, total)) => {
+                                            let key = (
+                                                sender_pubkey_hex.clone(),
+                                                call_id.clone(),
+                                                kind_num,
+                                            );
+                                            let Ok(mut buffer) = fragments.lock() else {
+                                                return Ok(false);
+                                            };
+                                            match add_fragment(
+                                                &mut buffer,
+                                                key,
+                                                index,
+                                                total,
+                                                rumor.content.to_string(),
+                                            ) {
+                                                Some(payload) => payload,
+                                                None => return Ok(false), // wait for the rest
+                                            }
+                                        }
+                                    };
+
+                                    let created_at = rumor.created_at.as_secs();
+                                    let Some(sequence) =
+                                        sequence_event(&call_id, kind_num, &content, created_at)
+                                    else {
+                                        return Ok(false); // duplicate candidate
+                                    };
 
                                     let event = CallSignalingEvent {
                                         kind: kind_num as u32,
-                                        sender_pubkey_hex: unwrapped.sender.to_hex(),
+                                        sender_pubkey_hex,
                                         call_id,
                                         call_type,
-                                        content: rumor.content.to_string(),
-                                        created_at: rumor.created_at.as_secs(),
+                                        renegotiate: is_renegotiation(kind_num, &content),
+                                        content,
+                                        created_at,
+                                        sequence,
                                     };
 
                                     let _ = sink.add(event);
                                 }
                             }
-                            Err(_) => {
-                                // Could not unwrap — not for us or corrupted
+                            Err(e) => {
+                                // Addressed to us but undecryptable or malformed
+                                diagnostics::record_error("call_signaling_unwrap", &e.to_string());
                             }
                         }
                     }
@@ -506,7 +886,7 @@ pub async fn build_group_call_signaling(
     call_id: String,
     call_type: Option<String>,
 ) -> Result<String, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let mut tags = vec![Tag::custom(
             TagKind::custom("call-id"),
             vec![call_id],
@@ -521,9 +901,91 @@ pub async fn build_group_call_signaling(
 
         let event = EventBuilder::new(Kind::from(kind_num as u16), &content)
             .tags(tags)
-            .build(s.keys.public_key());
+            .build(s.signer.public_key());
 
         serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))
     })
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment_tags(index: &str, total: &str) -> Tags {
+        EventBuilder::new(Kind::Custom(KIND_CALL_OFFER), "")
+            .tag(Tag::parse(["fragment", index, total]).unwrap())
+            .build(Keys::generate().public_key())
+            .tags
+    }
+
+    fn key() -> (String, String, u16) {
+        ("sender".into(), "call".into(), KIND_CALL_OFFER)
+    }
+
+    #[test]
+    fn fragment_info_bounds_the_total() {
+        assert_eq!(fragment_info(&fragment_tags("1", "3")), Some((1, 3)));
+        assert_eq!(fragment_info(&fragment_tags("32", "32")), Some((32, 32)));
+        assert_eq!(fragment_info(&fragment_tags("1", "33")), None);
+        assert_eq!(fragment_info(&fragment_tags("1", &usize::MAX.to_string())), None);
+        assert_eq!(fragment_info(&fragment_tags("0", "3")), None);
+        assert_eq!(fragment_info(&fragment_tags("4", "3")), None);
+    }
+
+    #[test]
+    fn fragments_reassemble_in_order() {
+        let mut buffer = HashMap::new();
+        assert_eq!(add_fragment(&mut buffer, key(), 1, 3, "ab".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 2, 3, "cd".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 3, 3, "ef".into()).as_deref(), Some("abcdef"));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn fragments_reassemble_out_of_order() {
+        let mut buffer = HashMap::new();
+        assert_eq!(add_fragment(&mut buffer, key(), 3, 3, "ef".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 1, 3, "ab".into()), None);
+        // A redelivered part doesn't complete the message.
+        assert_eq!(add_fragment(&mut buffer, key(), 1, 3, "ab".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 2, 3, "cd".into()).as_deref(), Some("abcdef"));
+    }
+
+    fn tracked(call_id: &str) -> bool {
+        SIGNALING_STATE.get().is_some_and(|calls| calls.lock().unwrap().contains_key(call_id))
+    }
+
+    #[test]
+    fn signaling_state_is_pruned() {
+        let candidate = r#"{"candidate":"candidate:1 1 udp 1 10.0.0.1 1 typ host","sdp_mid":"0","sdp_m_line_index":0}"#;
+        let now = Timestamp::now().as_secs();
+
+        // A call end drops the call's state.
+        let ended = "1b7c9e2a-4d3f-4a6b-8c5d-9e0f1a2b3c4d";
+        assert!(sequence_event_at(ended, KIND_ICE_CANDIDATE, candidate, now, now).is_some());
+        assert!(tracked(ended));
+        assert!(sequence_event_at(ended, KIND_CALL_END, "hangup", now, now).is_some());
+        assert!(!tracked(ended));
+
+        // A call that never ends is pruned once idle past the max age.
+        let abandoned = "5e6f7a8b-9c0d-4e1f-a2b3-c4d5e6f7a8b9";
+        let active = "c0ffee00-1234-4abc-9def-0123456789ab";
+        assert!(sequence_event_at(abandoned, KIND_ICE_CANDIDATE, candidate, now, now).is_some());
+        let later = now + SIGNALING_STATE_MAX_AGE_SECS + 1;
+        assert!(sequence_event_at(active, KIND_CALL_OFFER, "{}", later, later).is_some());
+        assert!(!tracked(abandoned));
+        assert!(tracked(active));
+        reset_call_signaling_state(active.to_string());
+        assert!(!tracked(active));
+    }
+
+    #[test]
+    fn oversized_totals_are_not_buffered() {
+        let mut buffer = HashMap::new();
+        assert_eq!(add_fragment(&mut buffer, key(), 1, MAX_FRAGMENTS + 1, "x".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 1, usize::MAX, "x".into()), None);
+        assert_eq!(add_fragment(&mut buffer, key(), 0, 2, "x".into()), None);
+        assert!(buffer.is_empty());
+    }
+}
@@ -8,8 +8,11 @@ use std::collections::HashMap;
 use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use base64::Engine;
 use flutter_rust_bridge::frb;
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 
@@ -35,29 +38,43 @@ pub struct WebRtcConfig {
     pub sdp_semantics: String,
     /// Bundle policy: "max-bundle".
     pub bundle_policy: String,
+    /// ICE transport policy: "all", or "relay" to use TURN candidates only.
+    pub ice_transport_policy: String,
 }
 
-/// Generate WebRTC configuration with STUN-only ICE servers.
-///
-/// Returns STUN server configuration for creating peer connections.
-/// STUN is sufficient for most NAT traversal scenarios.
-///
-/// TURN relay is NOT included by default because proper TURN authentication
-/// requires server-side credential provisioning (e.g., time-limited HMAC
-/// credentials via a TURN REST API, or an API key from a hosted provider
-/// like metered.ca / Twilio / Xirsys). Client-side credential generation
-/// provides no security — anyone can derive the same credentials.
+/// Direct-connect failures to a peer after which TURN-only is recommended.
+pub const FORCE_RELAY_AFTER_FAILURES: u32 = 1;
+
+/// Shared secret of the TURN server's REST API (coturn `static-auth-secret`).
+pub const TURN_SECRET_ENV: &str = "BURROW_TURN_SECRET";
+/// Comma-separated TURN server URLs, e.g. `turn:turn.example.com:3478`.
+pub const TURN_URLS_ENV: &str = "BURROW_TURN_URLS";
+/// Lifetime of generated TURN credentials in seconds.
+pub const TURN_TTL_ENV: &str = "BURROW_TURN_TTL";
+pub const DEFAULT_TURN_TTL_SECS: u64 = 3600;
+
+/// Generate WebRTC configuration: public STUN servers, plus a TURN server
+/// with ephemeral credentials when one is configured.
 ///
-/// Users who need TURN relay (e.g., behind symmetric NATs or strict
-/// firewalls) should configure their own TURN server credentials in the
-/// app settings (Settings > TURN Server). The Dart WebRTC service layer
-/// will merge user-configured TURN servers into the ICE server list.
+/// TURN credentials follow the TURN REST API scheme (coturn
+/// `use-auth-secret`, metered.ca and most hosted providers): the username
+/// is `{expiry}:{call id prefix}` and the password is the base64
+/// HMAC-SHA1 of the username under the server's shared secret, so the
+/// server can verify them without a lookup and they stop working after
+/// the TTL. The secret comes from `BURROW_TURN_SECRET` and the servers
+/// from `BURROW_TURN_URLS`; without both the config is STUN-only.
+/// `BURROW_TURN_TTL` overrides the default one-hour lifetime.
 ///
-/// `call_id`: Call identifier (unused currently, reserved for future use).
+/// `force_relay`: set `iceTransportPolicy` to "relay" so ICE skips host and
+/// server-reflexive candidates and goes straight to TURN. Connects much
+/// faster on symmetric-NAT or corporate networks where direct paths are
+/// doomed, but only works if a TURN server is configured: without one the
+/// policy falls back to "all" rather than leaving ICE with no candidates.
 #[frb]
-pub fn generate_webrtc_config(call_id: String) -> Result<WebRtcConfig, BurrowError> {
-    let _ = &call_id; // reserved for future use
-
+pub fn generate_webrtc_config(
+    call_id: String,
+    force_relay: bool,
+) -> Result<WebRtcConfig, BurrowError> {
     // Public STUN servers (free, reliable, no auth needed)
     let stun_servers = vec![
         "stun:stun.l.google.com:19302".to_string(),
@@ -65,19 +82,85 @@ pub fn generate_webrtc_config(call_id: String) -> Result<WebRtcConfig, BurrowErr
         "stun:stun2.l.google.com:19302".to_string(),
     ];
 
-    let ice_servers = vec![IceServer {
+    let mut ice_servers = vec![IceServer {
         urls: stun_servers,
         username: None,
         credential: None,
     }];
 
+    let secret = std::env::var(TURN_SECRET_ENV).ok().filter(|s| !s.is_empty());
+    let turn_urls: Vec<String> = std::env::var(TURN_URLS_ENV)
+        .unwrap_or_default()
+        .split(',')
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+    let mut has_turn = false;
+    if let Some(secret) = secret {
+        if turn_urls.is_empty() {
+            eprintln!("[webrtc] {TURN_SECRET_ENV} is set but {TURN_URLS_ENV} is empty; using STUN only");
+        } else {
+            let ttl_secs = std::env::var(TURN_TTL_ENV)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_TURN_TTL_SECS);
+            let (username, credential) = turn_credentials(&secret, &call_id, ttl_secs, now_secs());
+            ice_servers.push(IceServer {
+                urls: turn_urls,
+                username: Some(username),
+                credential: Some(credential),
+            });
+            has_turn = true;
+        }
+    }
+    if force_relay && !has_turn {
+        eprintln!("[webrtc] force_relay requested but no TURN server is configured; allowing direct paths");
+    }
+
     Ok(WebRtcConfig {
         ice_servers,
         sdp_semantics: "unified-plan".to_string(),
         bundle_policy: "balanced".to_string(),
+        ice_transport_policy: ice_transport_policy(force_relay, has_turn).to_string(),
     })
 }
 
+/// `iceTransportPolicy` for a call: "relay" only when asked for and a TURN
+/// server can provide relay candidates.
+#[frb(ignore)]
+pub fn ice_transport_policy(force_relay: bool, has_turn: bool) -> &'static str {
+    if force_relay && has_turn {
+        "relay"
+    } else {
+        "all"
+    }
+}
+
+/// Ephemeral TURN REST API credentials valid until `now_secs + ttl_secs`:
+/// returns `(username, credential)`.
+#[frb(ignore)]
+pub fn turn_credentials(
+    shared_secret: &str,
+    call_id: &str,
+    ttl_secs: u64,
+    now_secs: u64,
+) -> (String, String) {
+    let call_id_prefix: String = call_id.chars().take(16).collect();
+    let username = format!("{}:{}", now_secs + ttl_secs, call_id_prefix);
+    let mut mac = Hmac::<Sha1>::new_from_slice(shared_secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(username.as_bytes());
+    let credential = base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+    (username, credential)
+}
+
+/// Whether to use TURN-only (`force_relay`) for the next call to a peer,
+/// given how many previous direct-connect attempts to that peer failed.
+#[frb(sync)]
+pub fn recommend_force_relay(previous_failures: u32) -> bool {
+    previous_failures >= FORCE_RELAY_AFTER_FAILURES
+}
+
 // ── SDP Parsing ────────────────────────────────────────────────────────────
 
 /// Extracted information from an SDP offer or answer.
@@ -96,6 +179,11 @@ pub struct SdpInfo {
     pub ice_ufrag: Option<String>,
     /// Detected codecs (e.g. "opus", "VP8", "H264").
     pub codecs: Vec<String>,
+    /// Highest `b=AS:` bandwidth limit across media sections, in kbps.
+    pub bandwidth_kbps: Option<u32>,
+    /// Format parameters of the Opus payload (e.g.
+    /// "minptime=10;useinbandfec=1;stereo=1;maxaveragebitrate=128000").
+    pub opus_params: Option<String>,
     /// Whether the SDP appears valid.
     pub is_valid: bool,
     /// Validation error message, if any.
@@ -125,6 +213,8 @@ fn parse_sdp_internal(sdp_type: &str, sdp: &str) -> Result<SdpInfo, BurrowError>
             media_count: 0,
             ice_ufrag: None,
             codecs: vec![],
+            bandwidth_kbps: None,
+            opus_params: None,
             is_valid: false,
             error: Some("Empty SDP".to_string()),
         });
@@ -139,6 +229,8 @@ fn parse_sdp_internal(sdp_type: &str, sdp: &str) -> Result<SdpInfo, BurrowError>
             media_count: 0,
             ice_ufrag: None,
             codecs: vec![],
+            bandwidth_kbps: None,
+            opus_params: None,
             is_valid: false,
             error: Some("Missing SDP version line (v=0)".to_string()),
         });
@@ -169,6 +261,30 @@ fn parse_sdp_internal(sdp_type: &str, sdp: &str) -> Result<SdpInfo, BurrowError>
     codecs.sort();
     codecs.dedup();
 
+    let bandwidth_kbps = sdp
+        .lines()
+        .filter_map(|l| l.strip_prefix("b=AS:"))
+        .filter_map(|v| v.trim().parse::<u32>().ok())
+        .max();
+
+    // Opus parameters: the a=fmtp line for the payload type mapped to opus.
+    let opus_payload = sdp
+        .lines()
+        .filter_map(|l| l.strip_prefix("a=rtpmap:"))
+        .find_map(|l| {
+            let (payload, codec) = l.split_once(' ')?;
+            let name = codec.split('/').next()?;
+            name.eq_ignore_ascii_case("opus").then(|| payload.to_string())
+        });
+    let opus_params = opus_payload.and_then(|payload| {
+        sdp.lines()
+            .filter_map(|l| l.strip_prefix("a=fmtp:"))
+            .find_map(|l| {
+                let (pt, params) = l.split_once(' ')?;
+                (pt == payload).then(|| params.trim().to_string())
+            })
+    });
+
     Ok(SdpInfo {
         sdp_type: sdp_type.to_string(),
         has_audio,
@@ -176,6 +292,8 @@ fn parse_sdp_internal(sdp_type: &str, sdp: &str) -> Result<SdpInfo, BurrowError>
         media_count,
         ice_ufrag,
         codecs,
+        bandwidth_kbps,
+        opus_params,
         is_valid: true,
         error: None,
     })
@@ -260,9 +378,13 @@ fn now_secs() -> u64 {
         .as_secs()
 }
 
+/// Peers idle longer than this are reaped when a new peer entry is created.
+pub const STALE_PEER_MAX_AGE_SECS: u64 = 6 * 3600;
+
 /// Create a new peer entry to track a WebRTC peer connection.
 ///
 /// Called when a new participant joins a call or a new P2P connection is established.
+/// Also reaps peers left behind by calls that ended without `remove_call_peers`.
 #[frb]
 pub async fn create_peer_entry(
     call_id: String,
@@ -270,6 +392,8 @@ pub async fn create_peer_entry(
     has_audio_track: bool,
     has_video_track: bool,
 ) -> Result<PeerEntry, BurrowError> {
+    reap_stale_peers(STALE_PEER_MAX_AGE_SECS).await;
+
     let now = now_secs();
     let entry = PeerEntry {
         participant_pubkey_hex: participant_pubkey_hex.clone(),
@@ -392,6 +516,55 @@ pub async fn remove_call_peers(call_id: String) -> Result<(), BurrowError> {
     Ok(())
 }
 
+/// Remove peer entries with no activity for `max_age_secs`, and their stats.
+///
+/// Activity is the later of the entry's `updated_at` and its last stats
+/// report, so a long call that is still reporting stats is never reaped.
+/// Calls left with no peers are dropped, as are stats older than
+/// `max_age_secs` for peers no longer in any call. Returns the number of
+/// peer entries removed.
+///
+/// `create_peer_entry` reaps on every new peer, but a client that stops
+/// calling never gets there: Dart should also call this periodically
+/// (e.g. every few minutes with `STALE_PEER_MAX_AGE_SECS`) so entries from
+/// calls that ended in a crash or network drop don't leak.
+#[frb]
+pub async fn reap_stale_peers(max_age_secs: u64) -> u32 {
+    reap_stale_peers_at(max_age_secs, now_secs()).await
+}
+
+async fn reap_stale_peers_at(max_age_secs: u64, now: u64) -> u32 {
+    let mut store = peers().write().await;
+    let mut stats_store = peer_stats_store().write().await;
+
+    let mut removed = Vec::new();
+    for call_peers in store.values_mut() {
+        call_peers.retain(|pubkey, entry| {
+            let last_stats = stats_store.get(pubkey).map(|s| s.timestamp).unwrap_or(0);
+            let last_activity = entry.updated_at.max(last_stats);
+            let stale = now.saturating_sub(last_activity) > max_age_secs;
+            if stale {
+                removed.push(pubkey.clone());
+            }
+            !stale
+        });
+    }
+    store.retain(|_, call_peers| !call_peers.is_empty());
+
+    // Stats are keyed by pubkey only; keep them if the peer is still in another call.
+    for pubkey in &removed {
+        if !store.values().any(|call_peers| call_peers.contains_key(pubkey)) {
+            stats_store.remove(pubkey);
+        }
+    }
+    // Stats reported for peers that were never (or are no longer) tracked.
+    stats_store.retain(|pubkey, stats| {
+        now.saturating_sub(stats.timestamp) <= max_age_secs
+            || store.values().any(|call_peers| call_peers.contains_key(pubkey))
+    });
+    removed.len() as u32
+}
+
 fn compute_quality_score(rtt_ms: Option<f64>, packet_loss_percent: Option<f64>) -> f64 {
     let rtt_score = match rtt_ms {
         Some(rtt) if rtt <= 50.0 => 1.0,
@@ -420,16 +593,19 @@ fn compute_quality_score(rtt_ms: Option<f64>, packet_loss_percent: Option<f64>)
 /// Derive a per-call AES-128-GCM frame encryption key from MLS exporter_secret.
 ///
 /// Used for SFU mode where frames must be encrypted end-to-end since DTLS
-/// terminates at the SFU. The key is derived deterministically so all group
-/// members compute the same key from their shared MLS state.
+/// terminates at the SFU. The key depends only on the epoch's exporter
+/// secret, the epoch number and the call ID, so every member in that epoch
+/// (including one who just joined) computes the same key from group state.
 ///
 /// `exporter_secret_hex`: Hex-encoded MLS exporter_secret from the group epoch.
+/// `epoch`: The MLS epoch the exporter secret belongs to.
 /// `call_id`: Unique call identifier used as derivation context.
 ///
 /// Returns 16-byte (128-bit) AES-GCM key as hex string.
 #[frb]
 pub fn derive_frame_encryption_key(
     exporter_secret_hex: String,
+    epoch: u64,
     call_id: String,
 ) -> Result<String, BurrowError> {
     let secret =
@@ -437,7 +613,8 @@ pub fn derive_frame_encryption_key(
 
     let mut hasher = Sha256::new();
     hasher.update(&secret);
-    hasher.update(b"burrow-frame-encrypt-v1");
+    hasher.update(b"burrow-frame-encrypt-v2");
+    hasher.update(epoch.to_be_bytes());
     hasher.update(call_id.as_bytes());
     let full_key = hasher.finalize();
 
@@ -445,32 +622,25 @@ pub fn derive_frame_encryption_key(
     Ok(hex::encode(&full_key[..16]))
 }
 
-/// Rotate the frame encryption key by deriving a new key from the current key + epoch.
+/// Derive the frame encryption key for a new epoch.
 ///
-/// Called when MLS epoch advances (member join/leave/update) to maintain forward secrecy.
+/// Called when MLS epoch advances (member join/leave/update) to maintain
+/// forward secrecy. The key is derived from the new epoch's exporter secret
+/// rather than chained off the previous key, so a member who missed a
+/// rotation can still compute the current key.
 ///
-/// `current_key_hex`: Current frame encryption key (hex).
+/// `exporter_secret_hex`: Hex-encoded MLS exporter_secret of the new epoch.
 /// `new_epoch`: The new MLS epoch number.
 /// `call_id`: Call identifier for context binding.
 ///
 /// Returns new 16-byte AES-GCM key as hex string.
 #[frb]
 pub fn rotate_frame_key(
-    current_key_hex: String,
+    exporter_secret_hex: String,
     new_epoch: u64,
     call_id: String,
 ) -> Result<String, BurrowError> {
-    let current_key =
-        hex::decode(&current_key_hex).map_err(|e| BurrowError::from(e.to_string()))?;
-
-    let mut hasher = Sha256::new();
-    hasher.update(&current_key);
-    hasher.update(b"burrow-frame-rotate-v1");
-    hasher.update(call_id.as_bytes());
-    hasher.update(&new_epoch.to_be_bytes());
-    let new_key = hasher.finalize();
-
-    Ok(hex::encode(&new_key[..16]))
+    derive_frame_encryption_key(exporter_secret_hex, new_epoch, call_id)
 }
 
 // ── Topology Decision ──────────────────────────────────────────────────────
@@ -530,3 +700,38 @@ pub fn get_sfu_config(
         token: token_placeholder,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reap_backdated_peer() {
+        let call_id = "reap-backdated-call".to_string();
+        create_peer_entry(call_id.clone(), "reap-fresh".into(), true, false).await.unwrap();
+        create_peer_entry(call_id.clone(), "reap-stale".into(), true, false).await.unwrap();
+        report_peer_stats("reap-stale".into(), Some(40.0), None, None, None).await.unwrap();
+        let orphan = report_peer_stats("reap-orphan".into(), None, None, None, None).await.unwrap();
+
+        let now = now_secs();
+        let backdated = now - 2 * STALE_PEER_MAX_AGE_SECS;
+        {
+            let mut store = peers().write().await;
+            store.get_mut(&call_id).unwrap().get_mut("reap-stale").unwrap().updated_at = backdated;
+            let mut stats = peer_stats_store().write().await;
+            stats.get_mut("reap-stale").unwrap().timestamp = backdated;
+            stats.get_mut(&orphan.participant_pubkey_hex).unwrap().timestamp = backdated;
+        }
+
+        assert_eq!(reap_stale_peers_at(STALE_PEER_MAX_AGE_SECS, now).await, 1);
+        let participants = get_call_participants(call_id.clone()).await.unwrap();
+        assert_eq!(participants.len(), 1);
+        assert_eq!(participants[0].participant_pubkey_hex, "reap-fresh");
+        assert!(get_peer_stats("reap-stale".into()).await.unwrap().is_none());
+        assert!(get_peer_stats("reap-orphan".into()).await.unwrap().is_none());
+
+        // Once the last peer goes stale, the call bucket is dropped too.
+        assert_eq!(reap_stale_peers_at(STALE_PEER_MAX_AGE_SECS, now + 2 * STALE_PEER_MAX_AGE_SECS).await, 1);
+        assert!(!peers().read().await.contains_key(&call_id));
+    }
+}
@@ -6,12 +6,14 @@
 //! happen on sync.
 
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::Duration;
 
 use flutter_rust_bridge::frb;
 use nostr_sdk::prelude::*;
 
 use crate::api::app_state;
+use crate::api::diagnostics;
 use crate::api::error::BurrowError;
 use crate::api::identity;
 use crate::api::state;
@@ -23,6 +25,11 @@ pub struct ContactInfo {
     pub pubkey_hex: String,
     pub display_name: Option<String>,
     pub picture: Option<String>,
+    /// NIP-05 identifier from the profile, e.g. "alice@example.com".
+    pub nip05: Option<String>,
+    /// The NIP-05 domain confirmed it maps to this pubkey. Only checked
+    /// when verification is enabled; see `set_nip05_verification`.
+    pub nip05_verified: bool,
 }
 
 /// Diagnostic info for debugging contacts sync.
@@ -40,7 +47,7 @@ pub struct ContactsSyncDebug {
 /// Debug contacts sync: returns diagnostic info about each step.
 #[frb]
 pub async fn debug_sync_contacts() -> Result<ContactsSyncDebug, BurrowError> {
-    let self_pubkey_hex = match state::with_state(|s| Ok(s.keys.public_key().to_hex())).await {
+    let self_pubkey_hex = match state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await {
         Ok(pk) => pk,
         Err(e) => return Ok(ContactsSyncDebug {
             connected_relays: 0,
@@ -52,7 +59,7 @@ pub async fn debug_sync_contacts() -> Result<ContactsSyncDebug, BurrowError> {
         }),
     };
 
-    let client = match state::with_state(|s| Ok(s.client.clone())).await {
+    let client = match state::with_state_read(|s| Ok(s.client.clone())).await {
         Ok(c) => c,
         Err(e) => return Ok(ContactsSyncDebug {
             connected_relays: 0,
@@ -155,20 +162,50 @@ pub async fn get_cached_contacts() -> Result<Vec<ContactInfo>, BurrowError> {
     match app_state::with_db(|conn| {
         let mut stmt = conn
             .prepare(
-                "SELECT pubkey_hex, display_name, picture FROM follows
+                "SELECT pubkey_hex, display_name, picture, nip05, nip05_verified FROM follows
                  WHERE has_key_package = 1
                  ORDER BY COALESCE(display_name, pubkey_hex) COLLATE NOCASE",
             )
             .map_err(|e| BurrowError::from(e.to_string()))?;
 
         let contacts = stmt
-            .query_map([], |row| {
-                Ok(ContactInfo {
-                    pubkey_hex: row.get(0)?,
-                    display_name: row.get(1)?,
-                    picture: row.get(2)?,
-                })
-            })
+            .query_map([], contact_from_row)
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(contacts)
+    }) {
+        Ok(contacts) => Ok(contacts),
+        Err(_) => Ok(vec![]), // DB not initialized yet — return empty
+    }
+}
+
+/// Default number of results from `search_contacts`.
+const DEFAULT_CONTACT_SEARCH_LIMIT: u32 = 50;
+
+/// Search cached contacts by display name, NIP-05 or pubkey hex
+/// (case-insensitive substring), ordered by name. An empty query returns
+/// the first `limit` contacts by name. Local only, like `get_cached_contacts`.
+#[frb]
+pub async fn search_contacts(query: String, limit: Option<u32>) -> Result<Vec<ContactInfo>, BurrowError> {
+    let pattern = format!("%{}%", escape_like(query.trim()));
+    let limit = limit.unwrap_or(DEFAULT_CONTACT_SEARCH_LIMIT);
+    match app_state::with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT pubkey_hex, display_name, picture, nip05, nip05_verified FROM follows
+                 WHERE has_key_package = 1
+                   AND (display_name LIKE ?1 ESCAPE '\\'
+                        OR nip05 LIKE ?1 ESCAPE '\\'
+                        OR pubkey_hex LIKE ?1 ESCAPE '\\')
+                 ORDER BY COALESCE(display_name, pubkey_hex) COLLATE NOCASE
+                 LIMIT ?2",
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+
+        let contacts = stmt
+            .query_map(rusqlite::params![pattern, limit], contact_from_row)
             .map_err(|e| BurrowError::from(e.to_string()))?
             .filter_map(|r| r.ok())
             .collect();
@@ -180,26 +217,149 @@ pub async fn get_cached_contacts() -> Result<Vec<ContactInfo>, BurrowError> {
     }
 }
 
+/// Escape LIKE wildcards so the query matches literally.
+fn escape_like(query: &str) -> String {
+    query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Map a `pubkey_hex, display_name, picture, nip05, nip05_verified` row.
+fn contact_from_row(row: &rusqlite::Row) -> rusqlite::Result<ContactInfo> {
+    Ok(ContactInfo {
+        pubkey_hex: row.get(0)?,
+        display_name: row.get(1)?,
+        picture: row.get(2)?,
+        nip05: row.get(3)?,
+        nip05_verified: row.get::<_, i64>(4)? != 0,
+    })
+}
+
+/// Default age after which a follow's key package status is re-checked (24h).
+pub const DEFAULT_CONTACTS_STALENESS_SECS: u64 = 86400;
+
+static CONTACTS_STALENESS_SECS: AtomicU64 = AtomicU64::new(DEFAULT_CONTACTS_STALENESS_SECS);
+
+/// Set how old a follow's key package check may be before sync re-checks it.
+#[frb(sync)]
+pub fn set_contacts_staleness(secs: u64) {
+    CONTACTS_STALENESS_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// Get the key package staleness window used by `sync_contacts`.
+#[frb(sync)]
+pub fn get_contacts_staleness() -> u64 {
+    CONTACTS_STALENESS_SECS.load(Ordering::Relaxed)
+}
+
+static VERIFY_NIP05: AtomicBool = AtomicBool::new(false);
+
+/// Enable checking contacts' NIP-05 identifiers against their domain's
+/// `.well-known/nostr.json` during `sync_contacts`. Off by default since it
+/// contacts a web server per identifier.
+#[frb(sync)]
+pub fn set_nip05_verification(enabled: bool) {
+    VERIFY_NIP05.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `sync_contacts` verifies NIP-05 identifiers.
+#[frb(sync)]
+pub fn get_nip05_verification() -> bool {
+    VERIFY_NIP05.load(Ordering::Relaxed)
+}
+
+/// Most NIP-05 lookups in flight at once during a sync.
+const MAX_CONCURRENT_NIP05_CHECKS: usize = 4;
+
+/// Timeout for one NIP-05 lookup, connection included.
+const NIP05_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Wait after a first failed NIP-05 lookup before retrying (1 hour); each
+/// further failure doubles it, up to `MAX_NIP05_BACKOFF_SECS`.
+pub const NIP05_RETRY_SECS: u64 = 3600;
+
+/// Longest a failing NIP-05 goes between lookups (7 days).
+pub const MAX_NIP05_BACKOFF_SECS: u64 = 7 * 86400;
+
+/// Whether a NIP-05 last looked up at `checked_at` (never, if None), with
+/// `misses` consecutive failed lookups, is due for another one.
+#[frb(ignore)]
+pub fn nip05_check_due(checked_at: Option<i64>, misses: u32, now_secs: i64) -> bool {
+    let Some(checked_at) = checked_at else {
+        return true;
+    };
+    if misses == 0 {
+        return true;
+    }
+    let wait = NIP05_RETRY_SECS
+        .saturating_mul(1u64 << misses.saturating_sub(1).min(32))
+        .min(MAX_NIP05_BACKOFF_SECS);
+    now_secs.saturating_sub(checked_at) >= wait as i64
+}
+
+/// Longest a follow without a key package goes between re-checks (14 days).
+pub const MAX_KEY_PACKAGE_BACKOFF_SECS: u64 = 14 * 86400;
+
+/// How long after a check a follow's key package is re-checked, given
+/// `misses` consecutive checks that found none.
+///
+/// Follows with a key package (no misses) and first misses use the
+/// staleness window; each further miss doubles it, up to
+/// `MAX_KEY_PACKAGE_BACKOFF_SECS`, so follows who never set up Burrow
+/// aren't queried on every sync.
+#[frb(ignore)]
+pub fn key_package_recheck_interval(misses: u32, staleness_secs: u64) -> u64 {
+    let doublings = misses.saturating_sub(1).min(32);
+    staleness_secs
+        .saturating_mul(1u64 << doublings)
+        .min(MAX_KEY_PACKAGE_BACKOFF_SECS.max(staleness_secs))
+}
+
+/// Whether a follow last checked at `checked_at` (never, if None) is due
+/// for a key package re-check.
+#[frb(ignore)]
+pub fn key_package_check_due(
+    checked_at: Option<i64>,
+    misses: u32,
+    now_secs: i64,
+    staleness_secs: u64,
+) -> bool {
+    match checked_at {
+        None => true,
+        Some(at) => {
+            let interval = key_package_recheck_interval(misses, staleness_secs);
+            now_secs.saturating_sub(at) > interval.min(i64::MAX as u64) as i64
+        }
+    }
+}
+
 /// Full sync: fetch NIP-02 follow list, check key packages, resolve profiles,
 /// update local SQLite, and return Marmot-capable contacts.
 ///
+/// Key packages are re-checked for follows last checked longer ago than the
+/// staleness window, backing off for follows that repeatedly have none (see
+/// `key_package_recheck_interval`); `force_refresh` re-checks every follow,
+/// e.g. right after asking a contact to set up Burrow.
+///
 /// On any failure, returns whatever is currently cached rather than propagating
 /// the error — this prevents the UI from showing an error screen.
 #[frb]
-pub async fn sync_contacts() -> Result<Vec<ContactInfo>, BurrowError> {
-    match sync_contacts_inner().await {
+pub async fn sync_contacts(force_refresh: bool) -> Result<Vec<ContactInfo>, BurrowError> {
+    match sync_contacts_inner(force_refresh).await {
         Ok(contacts) => Ok(contacts),
         Err(e) => {
             // Log the error for debugging, then fall back to cached data
             eprintln!("[contacts] sync_contacts_inner failed: {e}");
+            diagnostics::record_error("contacts_sync", &e.to_string());
             get_cached_contacts().await
         }
     }
 }
 
-async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
-    let self_pubkey_hex = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await?;
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+async fn sync_contacts_inner(force_refresh: bool) -> Result<Vec<ContactInfo>, BurrowError> {
+    let self_pubkey_hex = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     // Ensure the app state DB is initialized before any DB operations.
     let data_dir = state::get_data_dir()?;
@@ -271,21 +431,27 @@ async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
         .as_secs() as i64;
-    let stale_threshold = now_secs - 86400; // 24 hours
+    let staleness = get_contacts_staleness();
 
     let needs_check = app_state::with_db(|conn| {
         let mut stmt = conn
-            .prepare(
-                "SELECT pubkey_hex FROM follows
-                 WHERE has_key_package = 0
-                    OR key_package_checked_at IS NULL
-                    OR key_package_checked_at < ?1",
-            )
+            .prepare("SELECT pubkey_hex, key_package_checked_at, key_package_misses FROM follows")
             .map_err(|e| BurrowError::from(e.to_string()))?;
         let keys: Vec<String> = stmt
-            .query_map([stale_threshold], |row| row.get(0))
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<i64>>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
             .map_err(|e| BurrowError::from(e.to_string()))?
             .filter_map(|r| r.ok())
+            .filter(|(_, checked_at, misses)| {
+                force_refresh
+                    || key_package_check_due(*checked_at, (*misses).max(0) as u32, now_secs, staleness)
+            })
+            .map(|(pk, _, _)| pk)
             .collect();
         Ok(keys)
     })?;
@@ -298,7 +464,8 @@ async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
         app_state::with_db(|conn| {
             let mut update_stmt = conn
                 .prepare(
-                    "UPDATE follows SET has_key_package = ?1, key_package_checked_at = ?2
+                    "UPDATE follows SET has_key_package = ?1, key_package_checked_at = ?2,
+                        key_package_misses = CASE WHEN ?1 = 1 THEN 0 ELSE key_package_misses + 1 END
                      WHERE pubkey_hex = ?3",
                 )
                 .map_err(|e| BurrowError::from(e.to_string()))?;
@@ -338,17 +505,7 @@ async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
         for pk in &needs_profile {
             match identity::fetch_profile(pk.clone(), false).await {
                 Ok(profile) if !profile.is_empty() => {
-                    let best_name = profile.best_name();
-                    let pic = profile.picture.clone();
-                    let _ = app_state::with_db(|conn| {
-                        conn.execute(
-                            "UPDATE follows SET display_name = ?1, picture = ?2
-                             WHERE pubkey_hex = ?3",
-                            rusqlite::params![best_name, pic, pk],
-                        )
-                        .map_err(|e| BurrowError::from(e.to_string()))?;
-                        Ok(())
-                    });
+                    let _ = store_follow_profile(pk, &profile);
                 }
                 _ => still_missing.push(pk.clone()),
             }
@@ -370,23 +527,17 @@ async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
                 .authors(pubkeys)
                 .kind(Kind::Metadata);
 
+            let _permit = state::relay_permit().await;
             if let Ok(events) = client.fetch_events(filter, Duration::from_secs(10)).await {
                 for event in events {
                     let pk_hex = event.pubkey.to_hex();
                     if let Ok(metadata) = Metadata::from_json(&event.content) {
                         let profile = identity::ProfileData::from_metadata(&metadata);
-                        let best_name = profile.best_name();
-                        let pic = profile.picture.clone();
-                        if best_name.is_some() || pic.is_some() {
-                            let _ = app_state::with_db(|conn| {
-                                conn.execute(
-                                    "UPDATE follows SET display_name = ?1, picture = ?2
-                                     WHERE pubkey_hex = ?3",
-                                    rusqlite::params![best_name, pic, pk_hex],
-                                )
-                                .map_err(|e| BurrowError::from(e.to_string()))?;
-                                Ok(())
-                            });
+                        if profile.best_name().is_some()
+                            || profile.picture.is_some()
+                            || profile.nip05.is_some()
+                        {
+                            let _ = store_follow_profile(&pk_hex, &profile);
                         }
                     }
                 }
@@ -394,10 +545,15 @@ async fn sync_contacts_inner() -> Result<Vec<ContactInfo>, BurrowError> {
         }
     }
 
-    // Step 5: Update last_synced timestamp
+    // Step 5: Verify NIP-05 identifiers not yet confirmed, if enabled
+    if get_nip05_verification() {
+        verify_pending_nip05s().await;
+    }
+
+    // Step 6: Update last_synced timestamp
     let _ = set_last_synced();
 
-    // Step 6: Return all Marmot-capable contacts
+    // Step 7: Return all Marmot-capable contacts
     get_cached_contacts().await
 }
 
@@ -422,8 +578,8 @@ pub async fn get_last_contacts_sync() -> Result<Option<i64>, BurrowError> {
 /// Publishes the updated follow list to relays and updates local DB.
 #[frb]
 pub async fn follow_contact(pubkey_hex: String) -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
-    let self_pubkey_hex = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
+    let self_pubkey_hex = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await?;
 
     // Fetch current follow list
     let mut current = fetch_follow_list_inner(&client, &self_pubkey_hex).await?;
@@ -456,8 +612,8 @@ pub async fn follow_contact(pubkey_hex: String) -> Result<(), BurrowError> {
 /// Publishes the updated follow list to relays and removes from local DB.
 #[frb]
 pub async fn unfollow_contact(pubkey_hex: String) -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
-    let self_pubkey_hex = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
+    let self_pubkey_hex = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await?;
 
     // Fetch current follow list
     let mut current = fetch_follow_list_inner(&client, &self_pubkey_hex).await?;
@@ -483,6 +639,127 @@ pub async fn unfollow_contact(pubkey_hex: String) -> Result<(), BurrowError> {
     Ok(())
 }
 
+/// NIP-51 categorized people list kind.
+const PEOPLE_LIST_KIND: u16 = 30000;
+
+/// `d` tag identifier for Burrow's exported contacts list.
+const CONTACTS_LIST_IDENTIFIER: &str = "burrow-contacts";
+
+/// Export Marmot-capable contacts as a NIP-51 people list (kind 30000).
+///
+/// Builds a signed list with a `p` tag per cached contact and returns the
+/// event JSON for backup or sharing. When `publish` is true the list is also
+/// sent to connected relays (replacing any previous export).
+#[frb]
+pub async fn export_contacts_list(publish: bool) -> Result<String, BurrowError> {
+    let (signer, client) = state::with_state_read(|s| Ok((s.signer.clone(), s.client.clone()))).await?;
+    let contacts = get_cached_contacts().await?;
+    let pubkeys: Vec<String> = contacts.into_iter().map(|c| c.pubkey_hex).collect();
+
+    let event = signer.sign(contacts_list_builder(&pubkeys)?).await?;
+
+    if publish {
+        client
+            .send_event(&event)
+            .await
+            .map_err(|e| BurrowError::from(format!("Failed to publish contacts list: {e}")))?;
+    }
+
+    Ok(event.as_json())
+}
+
+/// Import a NIP-51 people list (kind 30000) and follow any new entries.
+///
+/// Verifies the event signature, then adds every `p` tag not already in the
+/// NIP-02 follow list, publishing the updated follow list once. Key packages
+/// for new follows are checked on the next `sync_contacts`. Returns the
+/// number of follows added.
+#[frb]
+pub async fn import_contacts_list(event_json: String) -> Result<u32, BurrowError> {
+    let event = Event::from_json(&event_json).map_err(|e| BurrowError::from(e.to_string()))?;
+
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
+    let self_pubkey_hex = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await?;
+
+    let mut current = fetch_follow_list_inner(&client, &self_pubkey_hex).await?;
+    let known: HashSet<String> = current.iter().cloned().collect();
+    let added = new_follows_from_list(&event, &self_pubkey_hex, &known)?;
+
+    if added.is_empty() {
+        return Ok(0);
+    }
+
+    current.extend(added.iter().cloned());
+    publish_follow_list(&client, &current).await?;
+
+    let data_dir = state::get_data_dir()?;
+    app_state::ensure_db_with(&data_dir, &self_pubkey_hex)?;
+    app_state::with_db(|conn| {
+        let mut stmt = conn
+            .prepare("INSERT OR IGNORE INTO follows (pubkey_hex) VALUES (?1)")
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        for pk in &added {
+            stmt.execute([pk.as_str()])
+                .map_err(|e| BurrowError::from(e.to_string()))?;
+        }
+        Ok(())
+    })?;
+
+    Ok(added.len() as u32)
+}
+
+/// Unsigned NIP-51 people list (kind 30000) with a `p` tag per pubkey, as
+/// exported by `export_contacts_list`. Invalid pubkeys are skipped.
+#[frb(ignore)]
+pub fn contacts_list_builder(pubkeys_hex: &[String]) -> Result<EventBuilder, BurrowError> {
+    let mut tags = vec![
+        Tag::identifier(CONTACTS_LIST_IDENTIFIER),
+        Tag::parse(["title".to_string(), "Burrow contacts".to_string()])
+            .map_err(|e| BurrowError::from(e.to_string()))?,
+    ];
+    for pubkey_hex in pubkeys_hex {
+        if let Ok(pk) = PublicKey::from_hex(pubkey_hex) {
+            tags.push(Tag::public_key(pk));
+        }
+    }
+    Ok(EventBuilder::new(Kind::Custom(PEOPLE_LIST_KIND), "").tags(tags))
+}
+
+/// Pubkeys in a people list that aren't us or already `known`, in list
+/// order without duplicates. Errors if `event` isn't a validly signed
+/// kind 30000 list.
+#[frb(ignore)]
+pub fn new_follows_from_list(
+    event: &Event,
+    self_pubkey_hex: &str,
+    known: &HashSet<String>,
+) -> Result<Vec<String>, BurrowError> {
+    if event.kind != Kind::Custom(PEOPLE_LIST_KIND) {
+        return Err(BurrowError::from(format!(
+            "Expected a kind {} people list, got kind {}",
+            PEOPLE_LIST_KIND,
+            event.kind.as_u16()
+        )));
+    }
+    event
+        .verify()
+        .map_err(|e| BurrowError::from(format!("Invalid contacts list signature: {e}")))?;
+
+    let p_tag = TagKind::single_letter(Alphabet::P, false);
+    let mut added: Vec<String> = Vec::new();
+    for tag in event.tags.iter().filter(|t| t.kind() == p_tag) {
+        let Some(pk) = tag.content().and_then(|c| PublicKey::from_hex(c).ok()) else {
+            continue;
+        };
+        let pk_hex = pk.to_hex();
+        if pk_hex == self_pubkey_hex || known.contains(&pk_hex) || added.contains(&pk_hex) {
+            continue;
+        }
+        added.push(pk_hex);
+    }
+    Ok(added)
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
@@ -547,6 +824,7 @@ async fn batch_check_key_packages(
             .authors(pubkeys)
             .kind(Kind::MlsKeyPackage);
 
+        let _permit = state::relay_permit().await;
         match client.fetch_events(filter, Duration::from_secs(15)).await {
             Ok(events) => {
                 for event in events {
@@ -587,6 +865,132 @@ async fn publish_follow_list(
     Ok(())
 }
 
+/// Save a follow's display name, picture and NIP-05. A changed NIP-05
+/// loses its verified mark.
+fn store_follow_profile(pubkey_hex: &str, profile: &identity::ProfileData) -> Result<(), BurrowError> {
+    app_state::with_db(|conn| {
+        conn.execute(
+            "UPDATE follows SET display_name = ?1, picture = ?2,
+                nip05_verified = CASE WHEN nip05 IS ?3 THEN nip05_verified ELSE 0 END,
+                nip05_misses = CASE WHEN nip05 IS ?3 THEN nip05_misses ELSE 0 END,
+                nip05_checked_at = CASE WHEN nip05 IS ?3 THEN nip05_checked_at ELSE NULL END,
+                nip05 = ?3
+             WHERE pubkey_hex = ?4",
+            rusqlite::params![profile.best_name(), profile.picture, profile.nip05, pubkey_hex],
+        )
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+        Ok(())
+    })
+}
+
+/// Check contacts' unverified NIP-05s against their domains, at most
+/// `MAX_CONCURRENT_NIP05_CHECKS` at a time. A failed lookup leaves the
+/// identifier unverified and backs off that contact; see `nip05_check_due`.
+async fn verify_pending_nip05s() {
+    let now = chrono::Utc::now().timestamp();
+    let pending: Vec<(String, String)> = app_state::with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT pubkey_hex, nip05, nip05_checked_at, nip05_misses FROM follows
+                 WHERE has_key_package = 1 AND nip05 IS NOT NULL AND nip05_verified = 0",
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get::<_, Option<i64>>(2)?, row.get::<_, u32>(3)?))
+            })
+            .map_err(|e| BurrowError::from(e.to_string()))?
+            .filter_map(|r| r.ok())
+            .filter(|(_, _, checked_at, misses)| nip05_check_due(*checked_at, *misses, now))
+            .map(|(pubkey_hex, nip05, _, _)| (pubkey_hex, nip05))
+            .collect();
+        Ok(rows)
+    })
+    .unwrap_or_default();
+    if pending.is_empty() {
+        return;
+    }
+
+    // A domain must answer for itself: following redirects would let it
+    // point us at arbitrary hosts, internal ones included.
+    let Ok(client) = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(NIP05_TIMEOUT)
+        .build()
+    else {
+        return;
+    };
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_NIP05_CHECKS));
+    let mut checks = tokio::task::JoinSet::new();
+    for (pubkey_hex, nip05) in pending {
+        let client = client.clone();
+        let permits = permits.clone();
+        checks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let verified = verify_nip05(&client, &nip05, &pubkey_hex).await;
+            (pubkey_hex, verified)
+        });
+    }
+    while let Some(joined) = checks.join_next().await {
+        let Ok((pubkey_hex, verified)) = joined else {
+            continue;
+        };
+        let _ = app_state::with_db(|conn| {
+            conn.execute(
+                "UPDATE follows SET nip05_verified = ?1, nip05_checked_at = ?2,
+                    nip05_misses = CASE WHEN ?1 = 1 THEN 0 ELSE nip05_misses + 1 END
+                 WHERE pubkey_hex = ?3",
+                rusqlite::params![verified as i64, now, pubkey_hex],
+            )
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+            Ok(())
+        });
+    }
+}
+
+/// Fetch `nip05`'s `.well-known/nostr.json` and check it names `pubkey_hex`.
+async fn verify_nip05(client: &reqwest::Client, nip05: &str, pubkey_hex: &str) -> bool {
+    let Some((name, domain)) = parse_nip05(nip05) else {
+        return false;
+    };
+    let url = format!("https://{domain}/.well-known/nostr.json?name={name}");
+    let body = match client.get(&url).send().await {
+        Ok(resp) if resp.status().is_success() => resp.text().await.unwrap_or_default(),
+        _ => return false,
+    };
+    nip05_names_pubkey(&body, &name, pubkey_hex)
+}
+
+/// Split a NIP-05 identifier into (local name, domain). A bare domain means
+/// the root identifier "_". Names are case-insensitive and returned
+/// lowercase.
+#[frb(ignore)]
+pub fn parse_nip05(nip05: &str) -> Option<(String, String)> {
+    let nip05 = nip05.trim();
+    let (name, domain) = nip05.split_once('@').unwrap_or(("_", nip05));
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    let valid_domain = domain.contains('.')
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':'));
+    if !valid_name || !valid_domain {
+        return None;
+    }
+    Some((name.to_ascii_lowercase(), domain.to_ascii_lowercase()))
+}
+
+/// Whether a `nostr.json` document maps `name` to `pubkey_hex`.
+#[frb(ignore)]
+pub fn nip05_names_pubkey(nostr_json: &str, name: &str, pubkey_hex: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(nostr_json)
+        .ok()
+        .and_then(|doc| doc.get("names")?.get(name)?.as_str().map(str::to_string))
+        .is_some_and(|found| found.eq_ignore_ascii_case(pubkey_hex))
+}
+
 /// Update the last_synced timestamp in contacts_meta.
 fn set_last_synced() -> Result<(), BurrowError> {
     app_state::with_db(|conn| {
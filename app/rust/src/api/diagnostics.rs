@@ -0,0 +1,264 @@
+//! Structured diagnostics for bug reports.
+//!
+//! `collect_diagnostics` gathers a one-call snapshot of identity, relay,
+//! group, welcome and sync state plus recent error counts. The report is
+//! built only from public data: it never contains the secret key, MLS
+//! exporter secrets, group image keys, or message content.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use flutter_rust_bridge::frb;
+use serde::Serialize;
+
+use crate::api::app_state;
+use crate::api::contacts;
+use crate::api::error::BurrowError;
+use crate::api::group;
+use crate::api::state;
+
+/// Connection status of a single relay.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Serialize)]
+pub struct RelayDiagnostics {
+    pub url: String,
+    /// nostr-sdk relay status, e.g. "Connected", "Disconnected", "Pending".
+    pub status: String,
+    pub connected: bool,
+}
+
+/// Per-group MLS state, without any key material.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupDiagnostics {
+    pub mls_group_id_hex: String,
+    pub nostr_group_id_hex: String,
+    pub epoch: u64,
+    /// "active", "pending", or "inactive".
+    pub state: String,
+    pub member_count: u32,
+    pub relay_count: u32,
+}
+
+/// Error count for one subsystem since app start.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorCount {
+    /// Subsystem name, e.g. "contacts_sync", "message_listener".
+    pub subsystem: String,
+    pub count: u32,
+    /// Unix timestamp of the most recent error.
+    pub last_error_at: u64,
+    /// Category of the most recent error, e.g. "timeout" or "mls". Error
+    /// text can quote message content, so it is never stored.
+    pub last_error_kind: String,
+}
+
+/// Snapshot of app state for support triage.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    /// Unix timestamp when the report was generated.
+    pub generated_at: u64,
+    /// Crate version of the Rust core.
+    pub core_version: String,
+    /// Whether an account is logged in.
+    pub initialized: bool,
+    /// Hex-encoded public key (never the secret key).
+    pub pubkey_hex: Option<String>,
+    pub relays: Vec<RelayDiagnostics>,
+    pub group_count: u32,
+    pub groups: Vec<GroupDiagnostics>,
+    pub pending_welcome_count: u32,
+    /// Last successful contacts sync (epoch seconds).
+    pub last_contacts_sync: Option<i64>,
+    pub app_db_initialized: bool,
+    /// `PRAGMA user_version` of the app state database.
+    pub app_db_schema_version: Option<u32>,
+    pub error_counts: Vec<ErrorCount>,
+}
+
+impl DiagnosticsReport {
+    /// Serialize the report as pretty-printed JSON for attaching to a bug report.
+    #[frb(sync)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+static ERROR_COUNTS: LazyLock<Mutex<HashMap<String, ErrorCount>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Record an error for a subsystem so it shows up in diagnostics.
+///
+/// Only the count, time and `error_kind` of `error` are kept.
+#[frb(ignore)]
+pub(crate) fn record_error(subsystem: &str, error: &str) {
+    if let Ok(mut counts) = ERROR_COUNTS.lock() {
+        let entry = counts
+            .entry(subsystem.to_string())
+            .or_insert_with(|| ErrorCount {
+                subsystem: subsystem.to_string(),
+                count: 0,
+                last_error_at: 0,
+                last_error_kind: String::new(),
+            });
+        entry.count += 1;
+        entry.last_error_at = now_secs();
+        entry.last_error_kind = error_kind(error).to_string();
+    }
+}
+
+/// Coarse category of an error string, safe to include in a bug report.
+fn error_kind(error: &str) -> &'static str {
+    let error = error.to_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|n| error.contains(n));
+    if has(&["timeout", "timed out"]) {
+        "timeout"
+    } else if has(&["rejected", "blocked", "rate limit", "rate-limit"]) {
+        "rejected"
+    } else if has(&["connect", "websocket", "relay", "network"]) {
+        "connection"
+    } else if has(&["decrypt"]) {
+        "decryption"
+    } else if has(&["mls", "epoch", "commit", "welcome", "group"]) {
+        "mls"
+    } else if has(&["not found"]) {
+        "not_found"
+    } else if has(&["database", "sqlite", "storage"]) {
+        "storage"
+    } else if has(&["invalid", "parse", "json", "hex", "malformed"]) {
+        "invalid_input"
+    } else {
+        "other"
+    }
+}
+
+/// Collect a diagnostics report.
+///
+/// Works before login (reports `initialized: false`) so it can be attached
+/// to "can't sign in" reports too. Individual sections that fail are left
+/// empty rather than failing the whole report.
+#[frb]
+pub async fn collect_diagnostics() -> Result<DiagnosticsReport, BurrowError> {
+    let initialized = state::is_initialized().await;
+
+    let mut report = DiagnosticsReport {
+        generated_at: now_secs(),
+        core_version: env!("CARGO_PKG_VERSION").to_string(),
+        initialized,
+        pubkey_hex: None,
+        relays: vec![],
+        group_count: 0,
+        groups: vec![],
+        pending_welcome_count: 0,
+        last_contacts_sync: None,
+        app_db_initialized: app_state::is_db_initialized(),
+        app_db_schema_version: app_state::schema_version(),
+        error_counts: vec![],
+    };
+
+    if initialized {
+        let snapshot = state::with_state_read(|s| {
+            let groups = s
+                .mdk
+                .get_groups()
+                .unwrap_or_default()
+                .iter()
+                .map(|g| GroupDiagnostics {
+                    mls_group_id_hex: hex::encode(g.mls_group_id.as_slice()),
+                    nostr_group_id_hex: hex::encode(g.nostr_group_id),
+                    epoch: g.epoch,
+                    state: group::group_state_str(&g.state),
+                    member_count: s
+                        .mdk
+                        .get_members(&g.mls_group_id)
+                        .map(|m| m.len() as u32)
+                        .unwrap_or(0),
+                    relay_count: s
+                        .mdk
+                        .get_relays(&g.mls_group_id)
+                        .map(|r| r.len() as u32)
+                        .unwrap_or(0),
+                })
+                .collect::<Vec<_>>();
+            let pending = s
+                .mdk
+                .get_pending_welcomes(None)
+                .map(|w| w.len() as u32)
+                .unwrap_or(0);
+            Ok((s.signer.public_key().to_hex(), s.client.clone(), groups, pending))
+        })
+        .await;
+
+        if let Ok((pubkey_hex, client, groups, pending)) = snapshot {
+            report.pubkey_hex = Some(pubkey_hex);
+            report.group_count = groups.len() as u32;
+            report.groups = groups;
+            report.pending_welcome_count = pending;
+
+            let mut relays: Vec<RelayDiagnostics> = client
+                .relays()
+                .await
+                .iter()
+                .map(|(url, relay)| RelayDiagnostics {
+                    url: url.to_string(),
+                    status: relay.status().to_string(),
+                    connected: relay.is_connected(),
+                })
+                .collect();
+            relays.sort_by(|a, b| a.url.cmp(&b.url));
+            report.relays = relays;
+        }
+
+        report.last_contacts_sync = contacts::get_last_contacts_sync().await.unwrap_or(None);
+    }
+
+    if let Ok(counts) = ERROR_COUNTS.lock() {
+        let mut errors: Vec<ErrorCount> = counts.values().cloned().collect();
+        errors.sort_by(|a, b| a.subsystem.cmp(&b.subsystem));
+        report.error_counts = errors;
+    }
+
+    Ok(report)
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn recorded_errors_keep_only_their_kind() {
+        let _lock = state::TEST_STATE_LOCK.lock().await;
+        record_error(
+            "diagnostics_test",
+            "Failed to decrypt reply to \"meet me at the harbour at 9\"",
+        );
+
+        let report = collect_diagnostics().await.unwrap();
+        let entry = report
+            .error_counts
+            .iter()
+            .find(|e| e.subsystem == "diagnostics_test")
+            .unwrap();
+        assert_eq!(entry.count, 1);
+        assert_eq!(entry.last_error_kind, "decryption");
+        assert!(!report.to_json().contains("harbour"));
+    }
+
+    #[test]
+    fn error_kinds() {
+        assert_eq!(error_kind("Relay connection timed out"), "timeout");
+        assert_eq!(error_kind("Event was rejected by every relay"), "rejected");
+        assert_eq!(error_kind("Group not found"), "mls");
+        assert_eq!(error_kind("Message to delete not found"), "not_found");
+        assert_eq!(error_kind("something else entirely"), "other");
+    }
+}
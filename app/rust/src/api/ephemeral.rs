@@ -0,0 +1,136 @@
+//! Ephemeral key policy for NIP-59 gift wraps.
+//!
+//! Every gift wrap (kind 1059) is signed by a throwaway key so relays can't
+//! see who sent it. `PerEvent` (the default) generates a fresh key for every
+//! wrap: no two wraps can be linked to each other. `PerSession` reuses one
+//! key until the strategy changes, the account changes, or the app restarts:
+//! cheaper for high-volume agents, but a relay can tell that all wraps signed
+//! by that key came from the same sender, even without knowing who it is.
+//!
+//! The kind 445 wrapper of MIP-03 group messages is signed by MDK with a
+//! fresh key per event and is not affected by this setting.
+
+use std::sync::{LazyLock, Mutex};
+
+use flutter_rust_bridge::frb;
+use nostr_sdk::prelude::*;
+
+use crate::api::error::BurrowError;
+use crate::api::signer::BurrowSigner;
+
+/// How ephemeral signing keys are chosen for gift wraps.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EphemeralKeyStrategy {
+    /// Fresh key for every event — maximum unlinkability.
+    #[default]
+    PerEvent,
+    /// One key reused for the session — wraps become linkable to each other.
+    PerSession,
+}
+
+struct EphemeralState {
+    strategy: EphemeralKeyStrategy,
+    /// Session key and the identity it was generated for.
+    session: Option<(PublicKey, Keys)>,
+}
+
+static EPHEMERAL: LazyLock<Mutex<EphemeralState>> = LazyLock::new(|| {
+    Mutex::new(EphemeralState {
+        strategy: EphemeralKeyStrategy::PerEvent,
+        session: None,
+    })
+});
+
+/// Set the ephemeral key strategy. Always drops the current session key.
+#[frb(sync)]
+pub fn set_ephemeral_key_strategy(strategy: EphemeralKeyStrategy) {
+    if let Ok(mut st) = EPHEMERAL.lock() {
+        st.strategy = strategy;
+        st.session = None;
+    }
+}
+
+/// Get the current ephemeral key strategy.
+#[frb(sync)]
+pub fn get_ephemeral_key_strategy() -> EphemeralKeyStrategy {
+    EPHEMERAL
+        .lock()
+        .map(|st| st.strategy)
+        .unwrap_or_default()
+}
+
+/// Pick the signing key for the next gift wrap sent by `sender`.
+///
+/// Session keys are bound to the sender so switching accounts never links
+/// two identities through a shared wrap key.
+fn next_ephemeral_key(sender: &PublicKey) -> Keys {
+    let Ok(mut st) = EPHEMERAL.lock() else {
+        return Keys::generate();
+    };
+    match st.strategy {
+        EphemeralKeyStrategy::PerEvent => Keys::generate(),
+        EphemeralKeyStrategy::PerSession => match &st.session {
+            Some((owner, keys)) if owner == sender => keys.clone(),
+            _ => {
+                let keys = Keys::generate();
+                st.session = Some((*sender, keys.clone()));
+                keys
+            }
+        },
+    }
+}
+
+/// Whether a gift wrap names `pubkey` as its recipient in a `p` tag.
+///
+/// Listeners check this before unwrapping: wraps for someone else are
+/// expected and skipped silently, while a wrap addressed to us that fails
+/// to unwrap is a real problem worth reporting.
+#[frb(ignore)]
+pub(crate) fn is_addressed_to(event: &Event, pubkey: &PublicKey) -> bool {
+    let hex = pubkey.to_hex();
+    event.tags.iter().any(|t| {
+        let t = t.as_slice();
+        t.first().map(|k| k == "p").unwrap_or(false) && t.get(1) == Some(&hex)
+    })
+}
+
+/// NIP-59 gift wrap honoring the configured ephemeral key strategy.
+///
+/// Same output as `EventBuilder::gift_wrap`, but the outer kind 1059 event
+/// is signed with the key chosen by `next_ephemeral_key`. The seal is
+/// encrypted and signed by `signer`, so this works with remote signers too.
+#[frb(ignore)]
+pub(crate) async fn gift_wrap(
+    signer: &BurrowSigner,
+    receiver: &PublicKey,
+    mut rumor: UnsignedEvent,
+    extra_tags: Vec<Tag>,
+) -> Result<Event, BurrowError> {
+    rumor.ensure_id();
+    let sealed = signer.nip44_encrypt(receiver, &rumor.as_json()).await?;
+    let seal: Event = signer
+        .sign(
+            EventBuilder::new(Kind::Seal, sealed)
+                .custom_created_at(Timestamp::tweaked(nip59::RANGE_RANDOM_TIMESTAMP_TWEAK)),
+        )
+        .await?;
+
+    let ephemeral = next_ephemeral_key(&signer.public_key());
+    let content = nip44::encrypt(
+        ephemeral.secret_key(),
+        receiver,
+        seal.as_json(),
+        nip44::Version::default(),
+    )
+    .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    let mut tags = extra_tags;
+    tags.push(Tag::public_key(*receiver));
+
+    EventBuilder::new(Kind::GiftWrap, content)
+        .tags(tags)
+        .custom_created_at(Timestamp::tweaked(nip59::RANGE_RANDOM_TIMESTAMP_TWEAK))
+        .sign_with_keys(&ephemeral)
+        .map_err(|e| BurrowError::from(e.to_string()))
+}
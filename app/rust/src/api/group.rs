@@ -3,11 +3,16 @@
 //! Implements MIP-01 group construction with marmot_group_data extension (0xF2EE),
 //! random 32-byte Nostr group IDs, and admin management.
 
+use std::collections::HashMap;
+
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::*;
 use nostr_sdk::prelude::*;
 
+use crate::api::app_state;
 use crate::api::error::BurrowError;
+use crate::api::message;
+use crate::api::relay;
 use crate::api::state;
 
 /// Group information flattened for FFI.
@@ -42,6 +47,8 @@ pub struct GroupInfo {
     pub image_hash_hex: Option<String>,
     /// Whether this group has an avatar image set.
     pub has_image: bool,
+    /// Default disappearing-message TTL in seconds. None if messages don't expire.
+    pub default_message_ttl_secs: Option<u64>,
 }
 
 /// Member information for FFI, enriched with cached profile data.
@@ -68,6 +75,20 @@ pub struct CreateGroupResult {
     pub mls_group_id_hex: String,
 }
 
+/// Result of `create_or_get_dm`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct CreateOrGetDmResult {
+    /// The DM group info.
+    pub group: GroupInfo,
+    /// Welcome rumors to gift-wrap and send to the peer. Empty when reused.
+    pub welcome_rumors_json: Vec<String>,
+    /// Hex-encoded MLS group ID.
+    pub mls_group_id_hex: String,
+    /// True if an existing DM was returned and nothing needs publishing.
+    pub reused: bool,
+}
+
 /// Result of a group update operation (add/remove members, leave, etc.).
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
@@ -78,9 +99,12 @@ pub struct UpdateGroupResult {
     pub welcome_rumors_json: Vec<String>,
     /// Hex-encoded MLS group ID this update applies to.
     pub mls_group_id_hex: String,
+    /// Relays to publish the evolution event to. For relay updates this is
+    /// the union of the old and new relay sets.
+    pub publish_relays: Vec<String>,
 }
 
-fn group_state_str(state: &group_types::GroupState) -> String {
+pub(crate) fn group_state_str(state: &group_types::GroupState) -> String {
     match state {
         group_types::GroupState::Active => "active".to_string(),
         group_types::GroupState::Pending => "pending".to_string(),
@@ -89,6 +113,39 @@ fn group_state_str(state: &group_types::GroupState) -> String {
 }
 
 fn group_to_info(group: &group_types::Group, s: &state::BurrowState) -> GroupInfo {
+    let mls_group_id_hex = hex::encode(group.mls_group_id.as_slice());
+    let ttl = app_state::group_message_ttl(&mls_group_id_hex);
+    let mut peers = HashMap::new();
+    build_group_info(group, s, ttl, &mut peers)
+}
+
+/// `group_to_info` for many groups at once, as one pass under a single
+/// state lock: message TTLs come from one query and each DM peer's profile
+/// is looked up once however many groups it appears in. MDK has no bulk
+/// members API, so members are still read per group.
+fn groups_to_info<'a>(
+    groups: impl Iterator<Item = &'a group_types::Group>,
+    s: &state::BurrowState,
+) -> Vec<GroupInfo> {
+    let ttls = app_state::group_message_ttls();
+    let mut peers = HashMap::new();
+    groups
+        .map(|g| {
+            let ttl = ttls.get(&hex::encode(g.mls_group_id.as_slice())).copied();
+            build_group_info(g, s, ttl, &mut peers)
+        })
+        .collect()
+}
+
+/// Display name and picture of a DM peer, memoized in `peers`.
+type PeerProfiles = HashMap<PublicKey, (Option<String>, Option<String>)>;
+
+fn build_group_info(
+    group: &group_types::Group,
+    s: &state::BurrowState,
+    default_message_ttl_secs: Option<u64>,
+    peers: &mut PeerProfiles,
+) -> GroupInfo {
     let members_set = s
         .mdk
         .get_members(&group.mls_group_id)
@@ -96,17 +153,21 @@ fn group_to_info(group: &group_types::Group, s: &state::BurrowState) -> GroupInf
     let members: Vec<PublicKey> = members_set.into_iter().collect();
     let member_count = members.len() as u32;
     let is_dm = member_count == 2;
-    let self_pubkey = s.keys.public_key();
+    let self_pubkey = s.signer.public_key();
 
     let (dm_peer_display_name, dm_peer_picture, dm_peer_pubkey_hex) = if is_dm {
         if let Some(peer) = members.iter().find(|pk| **pk != self_pubkey) {
-            let hex = peer.to_hex();
-            let cached = s.profile_cache.get(&hex);
-            (
-                cached.and_then(|p| p.best_name()),
-                cached.and_then(|p| p.picture.clone()),
-                Some(hex),
-            )
+            let (name, picture) = peers
+                .entry(*peer)
+                .or_insert_with(|| {
+                    let cached = s.profile_cache.get(&peer.to_hex());
+                    (
+                        cached.and_then(|p| p.best_name()),
+                        cached.and_then(|p| p.picture.clone()),
+                    )
+                })
+                .clone();
+            (name, picture, Some(peer.to_hex()))
         } else {
             (None, None, None)
         }
@@ -134,6 +195,7 @@ fn group_to_info(group: &group_types::Group, s: &state::BurrowState) -> GroupInf
         dm_peer_pubkey_hex,
         image_hash_hex,
         has_image,
+        default_message_ttl_secs,
     }
 }
 
@@ -153,7 +215,7 @@ pub async fn create_group(
     member_key_package_events_json: Vec<String>,
     relay_urls: Vec<String>,
 ) -> Result<CreateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         // Parse admin pubkeys
         let admins: Vec<PublicKey> = admin_pubkeys_hex
             .iter()
@@ -174,6 +236,8 @@ pub async fn create_group(
             })
             .collect::<Result<Vec<_>, _>>()?;
 
+        validate_admins_are_members(&admins, &s.signer.public_key(), &kp_events)?;
+
         // Build group config
         let config = mdk_core::groups::NostrGroupConfigData::new(
             name,
@@ -187,7 +251,7 @@ pub async fn create_group(
 
         let result = s
             .mdk
-            .create_group(&s.keys.public_key(), kp_events, config)
+            .create_group(&s.signer.public_key(), kp_events, config)
             .map_err(BurrowError::from)?;
 
         // Serialize welcome rumors to JSON
@@ -209,37 +273,365 @@ pub async fn create_group(
     .await
 }
 
+/// Check that every admin is the creator or one of the invited members.
+///
+/// An admin who isn't in the group can never act, which almost always means
+/// the admin list and the invitee list got out of sync.
+#[frb(ignore)]
+pub fn validate_admins_are_members(
+    admins: &[PublicKey],
+    creator: &PublicKey,
+    member_key_package_events: &[Event],
+) -> Result<(), BurrowError> {
+    let not_members: Vec<String> = admins
+        .iter()
+        .filter(|admin| {
+            *admin != creator && !member_key_package_events.iter().any(|kp| kp.pubkey == **admin)
+        })
+        .map(|admin| admin.to_hex())
+        .collect();
+    if not_members.is_empty() {
+        Ok(())
+    } else {
+        Err(BurrowError::from(format!(
+            "Admins must be the creator or invited members; not invited: {}",
+            not_members.join(", ")
+        )))
+    }
+}
+
+/// Find an existing 1:1 group with `peer_pubkey_hex`.
+///
+/// Matches active groups with exactly two members: self and the peer.
+#[frb]
+pub async fn find_dm_with(peer_pubkey_hex: String) -> Result<Option<GroupInfo>, BurrowError> {
+    let peer = PublicKey::from_hex(&peer_pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+    state::with_state_read(|s| Ok(find_dm_group(s, &peer)?.map(|g| group_to_info(&g, s)))).await
+}
+
+/// Create a 1:1 group with a peer, or return the existing one.
+///
+/// Prevents duplicate DM rooms with the same person. When an existing DM is
+/// found, it is returned with empty `welcome_rumors_json` and nothing needs
+/// to be published. Otherwise behaves like `create_group` with the peer as
+/// the only invitee: publish the welcomes and call `merge_pending_commit`.
+#[frb]
+pub async fn create_dm(
+    peer_pubkey_hex: String,
+    peer_key_package_event_json: String,
+    relay_urls: Vec<String>,
+) -> Result<CreateGroupResult, BurrowError> {
+    let peer = PublicKey::from_hex(&peer_pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+    let kp_event =
+        Event::from_json(&peer_key_package_event_json).map_err(|e| BurrowError::from(e.to_string()))?;
+    if kp_event.pubkey != peer {
+        return Err(BurrowError::from("KeyPackage does not belong to peer".to_string()));
+    }
+
+    state::with_state_mut(|s| {
+        if peer == s.signer.public_key() {
+            return Err(BurrowError::from("Cannot create a DM with yourself".to_string()));
+        }
+
+        if let Some(existing) = find_dm_group(s, &peer)? {
+            return Ok(CreateGroupResult {
+                mls_group_id_hex: hex::encode(existing.mls_group_id.as_slice()),
+                group: group_to_info(&existing, s),
+                welcome_rumors_json: vec![],
+            });
+        }
+
+        let relays: Vec<RelayUrl> = relay_urls
+            .iter()
+            .filter_map(|u| RelayUrl::parse(u).ok())
+            .collect();
+        // DMs are unnamed; the UI shows the peer's profile instead.
+        let config = mdk_core::groups::NostrGroupConfigData::new(
+            String::new(),
+            String::new(),
+            None, // image_hash
+            None, // image_key
+            None, // image_nonce
+            relays,
+            vec![s.signer.public_key()],
+        );
+
+        let result = s
+            .mdk
+            .create_group(&s.signer.public_key(), vec![kp_event], config)
+            .map_err(BurrowError::from)?;
+
+        let welcome_jsons: Vec<String> = result
+            .welcome_rumors
+            .iter()
+            .map(|r| serde_json::to_string(r).unwrap_or_default())
+            .collect();
+
+        Ok(CreateGroupResult {
+            mls_group_id_hex: hex::encode(result.group.mls_group_id.as_slice()),
+            group: group_to_info(&result.group, s),
+            welcome_rumors_json: welcome_jsons,
+        })
+    })
+    .await
+}
+
+fn find_dm_group(
+    s: &state::BurrowState,
+    peer: &PublicKey,
+) -> Result<Option<group_types::Group>, BurrowError> {
+    let self_pubkey = s.signer.public_key();
+    if *peer == self_pubkey {
+        return Ok(None);
+    }
+    let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
+    Ok(groups.into_iter().find(|g| {
+        matches!(g.state, group_types::GroupState::Active)
+            && s.mdk
+                .get_members(&g.mls_group_id)
+                .map(|m| m.len() == 2 && m.contains(&self_pubkey) && m.contains(peer))
+                .unwrap_or(false)
+    }))
+}
+
+/// Return the existing 1:1 group with a peer, or fetch their KeyPackage
+/// and create one.
+///
+/// Unlike `create_dm`, the caller doesn't need the peer's KeyPackage up
+/// front, and relays are only queried when no DM exists yet. When
+/// `reused` is false, publish the welcomes and call `merge_pending_commit`
+/// as after `create_group`.
+#[frb]
+pub async fn create_or_get_dm(
+    peer_pubkey_hex: String,
+    relay_urls: Vec<String>,
+) -> Result<CreateOrGetDmResult, BurrowError> {
+    create_or_get_dm_using(peer_pubkey_hex, relay_urls, crate::api::invite::fetch_key_package).await
+}
+
+/// `create_or_get_dm` with the KeyPackage lookup supplied by the caller.
+#[frb(ignore)]
+pub async fn create_or_get_dm_using<F, Fut>(
+    peer_pubkey_hex: String,
+    relay_urls: Vec<String>,
+    fetch_key_package: F,
+) -> Result<CreateOrGetDmResult, BurrowError>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: std::future::Future<Output = Result<String, BurrowError>>,
+{
+    if let Some(group) = find_dm_with(peer_pubkey_hex.clone()).await? {
+        return Ok(CreateOrGetDmResult {
+            mls_group_id_hex: group.mls_group_id_hex.clone(),
+            group,
+            welcome_rumors_json: vec![],
+            reused: true,
+        });
+    }
+
+    let kp_event_json = fetch_key_package(peer_pubkey_hex.clone()).await?;
+    // create_dm checks for an existing DM again under the write lock, so a
+    // DM created while the KeyPackage was being fetched is still reused.
+    let result = create_dm(peer_pubkey_hex, kp_event_json, relay_urls).await?;
+    Ok(CreateOrGetDmResult {
+        reused: result.welcome_rumors_json.is_empty(),
+        group: result.group,
+        welcome_rumors_json: result.welcome_rumors_json,
+        mls_group_id_hex: result.mls_group_id_hex,
+    })
+}
+
+/// Discard a pending commit whose evolution event couldn't be published,
+/// leaving the group at its current epoch so the change can be retried.
+#[frb]
+pub async fn clear_pending_commit(mls_group_id_hex: String) -> Result<(), BurrowError> {
+    state::with_state_mut(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        s.mdk.clear_pending_commit(&group_id).map_err(BurrowError::from)
+    })
+    .await
+}
+
 /// Merge pending commit after publishing the evolution event to relays.
 ///
 /// MUST be called after successfully publishing a kind 445 commit event.
 /// This prevents state forks per MIP-02.
 #[frb]
 pub async fn merge_pending_commit(mls_group_id_hex: String) -> Result<(), BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
+        let before = snapshot_group(s, &group_id);
         s.mdk
             .merge_pending_commit(&group_id)
-            .map_err(BurrowError::from)
+            .map_err(BurrowError::from)?;
+        if let Some(before) = before {
+            let committer = s.signer.public_key();
+            record_epoch_change(s, &group_id, &before, Some(&committer), None);
+        }
+        Ok(())
     })
     .await
 }
 
-/// List all groups the current user belongs to.
+/// List all groups the current user belongs to. Forgotten groups are
+/// left out.
 #[frb]
 pub async fn list_groups() -> Result<Vec<GroupInfo>, BurrowError> {
-    state::with_state(|s| {
+    let forgotten = app_state::forgotten_group_ids();
+    state::with_state_read(|s| {
+        let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
+        Ok(groups_to_info(
+            groups
+                .iter()
+                .filter(|g| !forgotten.contains(&hex::encode(g.mls_group_id.as_slice()))),
+            s,
+        ))
+    })
+    .await
+}
+
+/// List inactive groups (ones we were removed from or left) that haven't
+/// been forgotten yet. Preview for `cleanup_inactive_groups`.
+#[frb]
+pub async fn list_inactive_groups() -> Result<Vec<GroupInfo>, BurrowError> {
+    let forgotten = app_state::forgotten_group_ids();
+    state::with_state_read(|s| {
         let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
-        Ok(groups.iter().map(|g| group_to_info(g, s)).collect())
+        Ok(groups_to_info(
+            groups
+                .iter()
+                .filter(|g| matches!(g.state, group_types::GroupState::Inactive))
+                .filter(|g| !forgotten.contains(&hex::encode(g.mls_group_id.as_slice()))),
+            s,
+        ))
     })
     .await
 }
 
+/// Forget an inactive group: purge its local messages and read state and
+/// hide it from `list_groups`. Active groups must be left first.
+#[frb]
+pub async fn forget_group(mls_group_id_hex: String) -> Result<(), BurrowError> {
+    let (pubkey, epoch, messages) = state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let group = s
+            .mdk
+            .get_group(&group_id)
+            .map_err(BurrowError::from)?
+            .ok_or_else(|| BurrowError::from("Group not found".to_string()))?;
+        if !matches!(group.state, group_types::GroupState::Inactive) {
+            return Err(BurrowError::from(
+                "Only inactive groups can be forgotten; leave the group first".to_string(),
+            ));
+        }
+        let messages = message::all_stored_messages(s, &group_id)?;
+        Ok((s.signer.public_key(), group.epoch, messages))
+    })
+    .await?;
+    message::erase_messages(&pubkey, messages)?;
+    app_state::forget_group_local(&mls_group_id_hex, epoch)
+}
+
+/// Forget every inactive group. Returns how many were forgotten.
+#[frb]
+pub async fn cleanup_inactive_groups() -> Result<u32, BurrowError> {
+    let forgotten = app_state::forgotten_group_ids();
+    let (pubkey, targets) = state::with_state_read(|s| {
+        let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
+        let targets = groups
+            .iter()
+            .filter(|g| matches!(g.state, group_types::GroupState::Inactive))
+            .map(|g| (hex::encode(g.mls_group_id.as_slice()), g))
+            .filter(|(id_hex, _)| !forgotten.contains(id_hex))
+            .map(|(id_hex, g)| {
+                let messages = message::all_stored_messages(s, &g.mls_group_id)?;
+                Ok((id_hex, g.epoch, messages))
+            })
+            .collect::<Result<Vec<_>, BurrowError>>()?;
+        Ok((s.signer.public_key(), targets))
+    })
+    .await?;
+
+    let count = targets.len() as u32;
+    for (group_id_hex, epoch, messages) in targets {
+        message::erase_messages(&pubkey, messages)?;
+        app_state::forget_group_local(&group_id_hex, epoch)?;
+    }
+    Ok(count)
+}
+
+/// Outcome of `reconcile_groups`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct ReconcileReport {
+    /// Groups MDK holds.
+    pub mdk_group_count: u32,
+    /// Active MDK groups that were hidden as forgotten and are listed again.
+    pub restored_group_ids: Vec<String>,
+    /// Groups the app DB has state for that MDK doesn't know. Left in
+    /// place for the caller to inspect or clear.
+    pub orphaned_group_ids: Vec<String>,
+}
+
+/// Compare MDK's groups with what the app tracks and repair what can be
+/// repaired.
+///
+/// A crash between MDK accepting a welcome or commit and the app updating
+/// its own state can leave the two out of step: an active group still
+/// marked forgotten (so it silently vanishes from `list_groups`), or app
+/// state for a group MDK no longer has (e.g. after its database was reset).
+/// The first is fixed, unless the group is still at the epoch it was
+/// forgotten at (nothing was rejoined); the second is only reported.
+#[frb]
+pub async fn reconcile_groups() -> Result<ReconcileReport, BurrowError> {
+    let mdk_groups = state::with_state_read(|s| {
+        let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
+        Ok(groups
+            .iter()
+            .map(|g| {
+                let active = matches!(g.state, group_types::GroupState::Active);
+                (hex::encode(g.mls_group_id.as_slice()), active, g.epoch)
+            })
+            .collect::<Vec<_>>())
+    })
+    .await?;
+    let known: std::collections::HashSet<&str> =
+        mdk_groups.iter().map(|(id, ..)| id.as_str()).collect();
+
+    let forgotten = app_state::forgotten_group_ids();
+    let forgotten_epochs = app_state::forgotten_group_epochs();
+    let mut restored_group_ids = Vec::new();
+    for (id, active, epoch) in &mdk_groups {
+        let rejoined = forgotten_epochs.get(id).map_or(true, |at| epoch > at);
+        if *active && forgotten.contains(id) && rejoined {
+            app_state::unforget_group(id)?;
+            restored_group_ids.push(id.clone());
+        }
+    }
+
+    let mut orphaned_group_ids: Vec<String> = app_state::tracked_group_ids()
+        .into_iter()
+        .filter(|id| !known.contains(id.as_str()))
+        .collect();
+    orphaned_group_ids.sort();
+
+    Ok(ReconcileReport {
+        mdk_group_count: mdk_groups.len() as u32,
+        restored_group_ids,
+        orphaned_group_ids,
+    })
+}
+
 /// Get info about a specific group by its MLS group ID.
 #[frb]
 pub async fn get_group(mls_group_id_hex: String) -> Result<GroupInfo, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -253,10 +645,84 @@ pub async fn get_group(mls_group_id_hex: String) -> Result<GroupInfo, BurrowErro
     .await
 }
 
+/// Get the full decoded marmot_group_data extension (0xF2EE) as JSON.
+///
+/// For debugging interop (e.g. the app and CLI showing different group names).
+/// Image key and nonce are reported only as presence flags; the secrets
+/// themselves are never returned.
+#[frb]
+pub async fn get_group_raw_extension(mls_group_id_hex: String) -> Result<String, BurrowError> {
+    state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let group = s
+            .mdk
+            .get_group(&group_id)
+            .map_err(BurrowError::from)?
+            .ok_or_else(|| BurrowError::from("Group not found".to_string()))?;
+        let relays: Vec<String> = s
+            .mdk
+            .get_relays(&group_id)
+            .map_err(BurrowError::from)?
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+
+        let extension = serde_json::json!({
+            "extension_type": "0xF2EE",
+            "mls_group_id": hex::encode(group.mls_group_id.as_slice()),
+            "nostr_group_id": hex::encode(group.nostr_group_id),
+            "name": group.name,
+            "description": group.description,
+            "admin_pubkeys": group.admin_pubkeys.iter().map(|pk: &PublicKey| pk.to_hex()).collect::<Vec<_>>(),
+            "relays": relays,
+            "image_hash": group.image_hash.map(hex::encode),
+            "has_image_key": group.image_key.is_some(),
+            "has_image_nonce": group.image_nonce.is_some(),
+            "epoch": group.epoch,
+            "state": group_state_str(&group.state),
+        });
+        serde_json::to_string_pretty(&extension).map_err(|e| BurrowError::from(e.to_string()))
+    })
+    .await
+}
+
+/// Set the default disappearing-message TTL for a group. None or 0 clears it.
+///
+/// Every message sent to the group through `send_message` (and the long and
+/// media variants) then carries a NIP-40 expiration tag unless the send
+/// overrides it. The marmot_group_data extension has no field for this, so
+/// the policy is stored locally and applies to this device's sends; other
+/// members set their own.
+#[frb]
+pub async fn set_group_message_ttl(
+    mls_group_id_hex: String,
+    ttl_secs: Option<u64>,
+) -> Result<(), BurrowError> {
+    match ttl_secs.filter(|ttl| *ttl > 0) {
+        Some(ttl) => {
+            app_state::set_group_state(
+                mls_group_id_hex,
+                "message_ttl_secs".to_string(),
+                ttl.to_string(),
+            )
+            .await
+        }
+        None => app_state::delete_group_state(mls_group_id_hex, "message_ttl_secs".to_string()).await,
+    }
+}
+
+/// Get the default disappearing-message TTL for a group, if set.
+#[frb]
+pub async fn get_group_message_ttl(mls_group_id_hex: String) -> Result<Option<u64>, BurrowError> {
+    Ok(app_state::group_message_ttl(&mls_group_id_hex))
+}
+
 /// Get members of a group, enriched with cached profile data.
 #[frb]
 pub async fn get_group_members(mls_group_id_hex: String) -> Result<Vec<MemberInfo>, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -282,7 +748,7 @@ pub async fn get_group_members(mls_group_id_hex: String) -> Result<Vec<MemberInf
 /// Returns an evolution event (kind 445) to publish to group relays.
 #[frb]
 pub async fn leave_group(mls_group_id_hex: String) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -301,6 +767,7 @@ pub async fn leave_group(mls_group_id_hex: String) -> Result<UpdateGroupResult,
             evolution_event_json: evolution_json,
             welcome_rumors_json: vec![],
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
@@ -383,7 +850,7 @@ pub async fn upload_group_image(
     }
 
     // 4. Update MLS group extension with image metadata
-    let evolution_json = state::with_state(|s| {
+    let evolution_json = state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -425,7 +892,7 @@ pub async fn download_group_image(
     use mdk_core::extension::group_image::decrypt_group_image;
 
     // Get image metadata from group extension
-    let (image_hash, image_key, image_nonce) = state::with_state(|s| {
+    let (image_hash, image_key, image_nonce) = state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -488,7 +955,7 @@ pub async fn download_group_image(
 pub async fn remove_group_image(
     mls_group_id_hex: String,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -509,6 +976,7 @@ pub async fn remove_group_image(
             evolution_event_json: evolution_json,
             welcome_rumors_json: vec![],
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
@@ -532,10 +1000,188 @@ fn base64_encode(data: &str) -> String {
     base64::engine::general_purpose::STANDARD.encode(data.as_bytes())
 }
 
+// ---------------------------------------------------------------------------
+// Epoch history
+// ---------------------------------------------------------------------------
+//
+// MDK keeps only the current MLS state, not past commits, so the history is
+// recorded by Burrow as commits are applied: our own on
+// `merge_pending_commit`, others' as they're processed. Epochs from before
+// we joined, or from before this was recorded, are missing. The committer
+// of a received commit isn't exposed by MDK (and the kind 445 wrapper is
+// signed with an ephemeral key), so it's only known for our own commits.
+
+/// One MLS epoch of a group and the commit that started it.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct EpochInfo {
+    pub epoch: u64,
+    /// When the commit was applied locally (Unix seconds). None for the
+    /// current epoch if its commit wasn't observed.
+    pub recorded_at: Option<u64>,
+    /// Hex pubkey of the member who committed, when known.
+    pub committer_pubkey_hex: Option<String>,
+    /// Kind 445 event carrying the commit, when received from a relay.
+    pub commit_event_id_hex: Option<String>,
+    pub added_pubkeys: Vec<String>,
+    pub removed_pubkeys: Vec<String>,
+    /// Name, description, admins or relays changed.
+    pub metadata_changed: bool,
+}
+
+/// Group state captured before applying a commit.
+#[frb(ignore)]
+pub(crate) struct GroupSnapshot {
+    epoch: u64,
+    members: std::collections::BTreeSet<PublicKey>,
+    metadata: (String, String, Vec<String>, Vec<String>),
+}
+
+/// Capture the parts of a group's state the epoch history compares.
+#[frb(ignore)]
+pub(crate) fn snapshot_group(s: &state::BurrowState, group_id: &GroupId) -> Option<GroupSnapshot> {
+    let group = s.mdk.get_group(group_id).ok()??;
+    let members = s.mdk.get_members(group_id).ok()?;
+    let admins = group.admin_pubkeys.iter().map(|pk| pk.to_hex()).collect();
+    let relays = s
+        .mdk
+        .get_relays(group_id)
+        .map(|r| r.iter().map(|u| u.to_string()).collect())
+        .unwrap_or_default();
+    Some(GroupSnapshot {
+        epoch: group.epoch,
+        members,
+        metadata: (group.name, group.description, admins, relays),
+    })
+}
+
+/// Record the epoch change since `before`, if the epoch advanced.
+/// Returns the (added, removed) member diff when it did.
+#[frb(ignore)]
+pub(crate) fn record_epoch_change(
+    s: &state::BurrowState,
+    group_id: &GroupId,
+    before: &GroupSnapshot,
+    committer: Option<&PublicKey>,
+    commit_event_id: Option<&EventId>,
+) -> Option<(Vec<String>, Vec<String>)> {
+    let after = snapshot_group(s, group_id)?;
+    if after.epoch == before.epoch {
+        return None;
+    }
+    let (added, removed) = crate::api::message::diff_member_sets(&before.members, &after.members);
+    let info = EpochInfo {
+        epoch: after.epoch,
+        recorded_at: Some(Timestamp::now().as_secs()),
+        committer_pubkey_hex: committer.map(|pk| pk.to_hex()),
+        commit_event_id_hex: commit_event_id.map(|id| id.to_hex()),
+        added_pubkeys: added.clone(),
+        removed_pubkeys: removed.clone(),
+        metadata_changed: before.metadata != after.metadata,
+    };
+    let _ = app_state::record_epoch(&hex::encode(group_id.as_slice()), &info);
+    Some((added, removed))
+}
+
+/// Epoch history of a group, oldest first, ending with the current epoch.
+///
+/// Answers questions like "when was Bob removed?" and helps diagnose
+/// desyncs. Only commits applied since this device joined (and since this
+/// was recorded) are listed; see the section comment for what's not known.
+#[frb]
+pub async fn get_group_epoch_history(mls_group_id_hex: String) -> Result<Vec<EpochInfo>, BurrowError> {
+    let current_epoch = state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let group = s
+            .mdk
+            .get_group(&group_id)
+            .map_err(BurrowError::from)?
+            .ok_or_else(|| BurrowError::from("Group not found".to_string()))?;
+        Ok(group.epoch)
+    })
+    .await?;
+
+    let mut history: Vec<EpochInfo> = app_state::epoch_history(&mls_group_id_hex)
+        .into_iter()
+        .filter(|e| e.epoch <= current_epoch)
+        .collect();
+    if history.last().map(|e| e.epoch) != Some(current_epoch) {
+        history.push(EpochInfo {
+            epoch: current_epoch,
+            recorded_at: None,
+            committer_pubkey_hex: None,
+            commit_event_id_hex: None,
+            added_pubkeys: Vec::new(),
+            removed_pubkeys: Vec::new(),
+            metadata_changed: false,
+        });
+    }
+    Ok(history)
+}
+
+/// One membership or state change in a group's timeline, for rendering
+/// "Alice joined", "Bob left" and similar system lines.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupEpochEvent {
+    /// Epoch the change took effect in.
+    pub epoch: u64,
+    /// "add", "remove", or "update" (metadata change or key update).
+    pub change_type: String,
+    /// Hex pubkeys joining or leaving; empty for "update".
+    pub pubkeys: Vec<String>,
+    /// When the commit was applied locally (Unix seconds).
+    pub timestamp: u64,
+    /// Hex pubkey of the member who committed, when known.
+    pub committer_pubkey_hex: Option<String>,
+}
+
+/// Member-change timeline of a group, oldest first.
+///
+/// Derived from the recorded epoch history (see `get_group_epoch_history`),
+/// so only commits observed by this device are included.
+#[frb]
+pub async fn get_group_epoch_events(mls_group_id_hex: String) -> Result<Vec<GroupEpochEvent>, BurrowError> {
+    let history = get_group_epoch_history(mls_group_id_hex).await?;
+    Ok(epoch_events_from_history(&history))
+}
+
+/// Split recorded epochs into timeline events. A commit that both adds and
+/// removes members yields one event for each; one that changes neither is
+/// an "update". Epochs whose commit wasn't observed are skipped.
+#[frb(ignore)]
+pub fn epoch_events_from_history(history: &[EpochInfo]) -> Vec<GroupEpochEvent> {
+    let mut events = Vec::new();
+    for info in history {
+        let Some(timestamp) = info.recorded_at else {
+            continue;
+        };
+        let event = |change_type: &str, pubkeys: &[String]| GroupEpochEvent {
+            epoch: info.epoch,
+            change_type: change_type.to_string(),
+            pubkeys: pubkeys.to_vec(),
+            timestamp,
+            committer_pubkey_hex: info.committer_pubkey_hex.clone(),
+        };
+        if !info.added_pubkeys.is_empty() {
+            events.push(event("add", &info.added_pubkeys));
+        }
+        if !info.removed_pubkeys.is_empty() {
+            events.push(event("remove", &info.removed_pubkeys));
+        }
+        if info.metadata_changed || (info.added_pubkeys.is_empty() && info.removed_pubkeys.is_empty()) {
+            events.push(event("update", &[]));
+        }
+    }
+    events
+}
+
 /// Get the relay URLs configured for a group.
 #[frb]
 pub async fn get_group_relays(mls_group_id_hex: String) -> Result<Vec<String>, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -545,14 +1191,80 @@ pub async fn get_group_relays(mls_group_id_hex: String) -> Result<Vec<String>, B
     .await
 }
 
+/// Relays an evolution event for the group should reach: its current relays
+/// plus `proposed_relay_urls` (empty when the update doesn't move the group).
+#[frb]
+pub async fn relays_for_evolution(
+    mls_group_id_hex: String,
+    proposed_relay_urls: Vec<String>,
+) -> Result<Vec<String>, BurrowError> {
+    state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let proposed: Vec<RelayUrl> = proposed_relay_urls
+            .iter()
+            .filter_map(|u| RelayUrl::parse(u).ok())
+            .collect();
+        Ok(evolution_relays(s, &group_id, &proposed))
+    })
+    .await
+}
+
+/// Publish an evolution event to the given relays, adding and connecting any
+/// that aren't in the pool yet (e.g. the new relays of a relay update).
+///
+/// Succeeds if at least one relay accepted the event.
+#[frb]
+pub async fn publish_evolution_event(
+    evolution_event_json: String,
+    relay_urls: Vec<String>,
+) -> Result<String, BurrowError> {
+    let event: Event =
+        serde_json::from_str(&evolution_event_json).map_err(|e| BurrowError::from(e.to_string()))?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
+    let relays = relay::ensure_group_relays(&client, &relay_urls).await;
+    relay::send_event_to_relays(&client, &event, relays).await
+}
+
+/// Current group relays followed by any proposed relays not already present.
+pub(crate) fn evolution_relays(
+    s: &state::BurrowState,
+    group_id: &GroupId,
+    proposed: &[RelayUrl],
+) -> Vec<String> {
+    let current: Vec<String> = s
+        .mdk
+        .get_relays(group_id)
+        .map(|relays| relays.iter().map(|r| r.to_string()).collect())
+        .unwrap_or_default();
+    let proposed: Vec<String> = proposed.iter().map(|r| r.to_string()).collect();
+    union_relays(&current, &proposed)
+}
+
+/// Order-preserving union of two relay lists, ignoring trailing slashes.
+#[frb(ignore)]
+pub fn union_relays(current: &[String], proposed: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(current.len() + proposed.len());
+    for url in current.iter().chain(proposed) {
+        let key = url.trim_end_matches('/');
+        if !out.iter().any(|u| u.trim_end_matches('/') == key) {
+            out.push(url.clone());
+        }
+    }
+    out
+}
+
 /// Update the relay URLs for a group. Admin-only.
-/// Returns an evolution event to publish to the old and new relays.
+/// Returns an evolution event to publish to the old and new relays; the
+/// result's `publish_relays` lists both sets, and `publish_evolution_event`
+/// sends to all of them.
 #[frb]
 pub async fn update_group_relays(
     mls_group_id_hex: String,
     relay_urls: Vec<String>,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -560,6 +1272,9 @@ pub async fn update_group_relays(
             .iter()
             .filter_map(|u| RelayUrl::parse(u).ok())
             .collect();
+        // Resolve before the update: members still on the old relays must
+        // see the commit that moves the group.
+        let publish_relays = evolution_relays(s, &group_id, &relays);
 
         let update = mdk_core::groups::NostrGroupDataUpdate::new().relays(relays);
         let result = s
@@ -574,6 +1289,7 @@ pub async fn update_group_relays(
             evolution_event_json: evolution_json,
             welcome_rumors_json: vec![],
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays,
         })
     })
     .await
@@ -585,7 +1301,7 @@ pub async fn update_group_name(
     mls_group_id_hex: String,
     name: String,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -602,6 +1318,7 @@ pub async fn update_group_name(
             evolution_event_json: evolution_json,
             welcome_rumors_json: vec![],
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
@@ -613,7 +1330,7 @@ pub async fn update_group_description(
     mls_group_id_hex: String,
     description: String,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -630,7 +1347,197 @@ pub async fn update_group_description(
             evolution_event_json: evolution_json,
             welcome_rumors_json: vec![],
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
 }
+
+/// Make a member an admin. Admin-only.
+#[frb]
+pub async fn promote_admin(
+    mls_group_id_hex: String,
+    pubkey_hex: String,
+) -> Result<UpdateGroupResult, BurrowError> {
+    let pubkey = PublicKey::from_hex(&pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+    update_group_admins(mls_group_id_hex, |admins, members| {
+        promoted_admins(admins, members, &pubkey)
+    })
+    .await
+}
+
+/// Remove a member's admin rights. Admin-only; the last admin can't be
+/// demoted.
+#[frb]
+pub async fn demote_admin(
+    mls_group_id_hex: String,
+    pubkey_hex: String,
+) -> Result<UpdateGroupResult, BurrowError> {
+    let pubkey = PublicKey::from_hex(&pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+    update_group_admins(mls_group_id_hex, |admins, _| demoted_admins(admins, &pubkey)).await
+}
+
+/// Commit a new admin set computed from the current admins and members.
+async fn update_group_admins<F>(
+    mls_group_id_hex: String,
+    new_admins: F,
+) -> Result<UpdateGroupResult, BurrowError>
+where
+    F: FnOnce(&[PublicKey], &[PublicKey]) -> Result<Vec<PublicKey>, BurrowError>,
+{
+    state::with_state_mut(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let group = s
+            .mdk
+            .get_group(&group_id)
+            .map_err(BurrowError::from)?
+            .ok_or_else(|| BurrowError::from("Group not found".to_string()))?;
+        let admins: Vec<PublicKey> = group.admin_pubkeys.iter().copied().collect();
+        let members: Vec<PublicKey> =
+            s.mdk.get_members(&group_id).map_err(BurrowError::from)?.into_iter().collect();
+
+        let update = mdk_core::groups::NostrGroupDataUpdate::new().admins(new_admins(&admins, &members)?);
+        let result = s
+            .mdk
+            .update_group_data(&group_id, update)
+            .map_err(BurrowError::from)?;
+
+        let evolution_json =
+            serde_json::to_string(&result.evolution_event).unwrap_or_default();
+
+        Ok(UpdateGroupResult {
+            evolution_event_json: evolution_json,
+            welcome_rumors_json: vec![],
+            mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
+        })
+    })
+    .await
+}
+
+/// The admin set with `pubkey` added. Only current members can be admins.
+#[frb(ignore)]
+pub fn promoted_admins(
+    admins: &[PublicKey],
+    members: &[PublicKey],
+    pubkey: &PublicKey,
+) -> Result<Vec<PublicKey>, BurrowError> {
+    if !members.contains(pubkey) {
+        return Err(BurrowError::from(format!("{} is not a member of this group", pubkey.to_hex())));
+    }
+    if admins.contains(pubkey) {
+        return Err(BurrowError::from(format!("{} is already an admin", pubkey.to_hex())));
+    }
+    let mut admins = admins.to_vec();
+    admins.push(*pubkey);
+    Ok(admins)
+}
+
+/// The admin set with `pubkey` removed. A group always keeps at least one
+/// admin, or nobody could change it again.
+#[frb(ignore)]
+pub fn demoted_admins(admins: &[PublicKey], pubkey: &PublicKey) -> Result<Vec<PublicKey>, BurrowError> {
+    if !admins.contains(pubkey) {
+        return Err(BurrowError::from(format!("{} is not an admin", pubkey.to_hex())));
+    }
+    if admins.len() == 1 {
+        return Err(BurrowError::from(
+            "Can't demote the last admin; promote another member first".to_string(),
+        ));
+    }
+    Ok(admins.iter().filter(|a| *a != pubkey).copied().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(epoch: u64, recorded_at: u64) -> EpochInfo {
+        EpochInfo {
+            epoch,
+            recorded_at: Some(recorded_at),
+            committer_pubkey_hex: None,
+            commit_event_id_hex: None,
+            added_pubkeys: Vec::new(),
+            removed_pubkeys: Vec::new(),
+            metadata_changed: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn epoch_history_is_ordered_and_deduplicated() {
+        let _lock = state::TEST_STATE_LOCK.lock().await;
+        state::init_test_state().await;
+
+        let created = create_group(
+            "History".into(),
+            String::new(),
+            vec![],
+            vec![],
+            vec!["wss://relay.example.com".into()],
+        )
+        .await
+        .unwrap();
+        let id = created.mls_group_id_hex;
+
+        // Nothing recorded yet: only the current epoch, listed once.
+        let history = get_group_epoch_history(id.clone()).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].epoch, created.group.epoch);
+        assert_eq!(history[0].recorded_at, None);
+
+        for name in ["One", "Two"] {
+            update_group_name(id.clone(), name.into()).await.unwrap();
+            merge_pending_commit(id.clone()).await.unwrap();
+        }
+        let current = get_group(id.clone()).await.unwrap().epoch;
+        assert!(current >= 2);
+
+        // Out of order, the same epoch twice, and one past the current epoch.
+        app_state::record_epoch(&id, &recorded(current, 30)).unwrap();
+        app_state::record_epoch(&id, &recorded(current - 1, 10)).unwrap();
+        app_state::record_epoch(&id, &recorded(current - 1, 20)).unwrap();
+        app_state::record_epoch(&id, &recorded(current + 5, 40)).unwrap();
+
+        let history = get_group_epoch_history(id).await.unwrap();
+        let epochs: Vec<u64> = history.iter().map(|e| e.epoch).collect();
+        let mut sorted = epochs.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(epochs, sorted);
+        let last = history.last().unwrap();
+        assert_eq!((last.epoch, last.recorded_at), (current, Some(30)));
+        let previous = history.iter().find(|e| e.epoch == current - 1).unwrap();
+        assert_eq!(previous.recorded_at, Some(20));
+
+        state::destroy_state().await;
+    }
+
+    #[tokio::test]
+    async fn list_groups_enters_state_once() {
+        let _lock = state::TEST_STATE_LOCK.lock().await;
+        state::init_test_state().await;
+
+        const GROUPS: usize = 50;
+        for i in 0..GROUPS {
+            create_group(
+                format!("Group {i}"),
+                String::new(),
+                vec![],
+                vec![],
+                vec!["wss://relay.example.com".into()],
+            )
+            .await
+            .unwrap();
+        }
+
+        let before = state::state_entry_count();
+        let groups = list_groups().await.unwrap();
+        assert_eq!(state::state_entry_count() - before, 1);
+        assert_eq!(groups.len(), GROUPS);
+
+        state::destroy_state().await;
+    }
+}
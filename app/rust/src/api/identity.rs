@@ -15,8 +15,9 @@ use crate::api::state;
 /// Export the secret key as nsec bech32 string.
 #[frb]
 pub async fn export_nsec() -> Result<String, BurrowError> {
-    state::with_state(|s| {
-        s.keys
+    state::with_state_read(|s| {
+        s.signer
+            .secret_keys()?
             .secret_key()
             .to_bech32()
             .map_err(|e| BurrowError::from(e.to_string()))
@@ -27,8 +28,8 @@ pub async fn export_nsec() -> Result<String, BurrowError> {
 /// Export the public key as npub bech32 string.
 #[frb]
 pub async fn export_npub() -> Result<String, BurrowError> {
-    state::with_state(|s| {
-        s.keys
+    state::with_state_read(|s| {
+        s.signer
             .public_key()
             .to_bech32()
             .map_err(|e| BurrowError::from(e.to_string()))
@@ -39,7 +40,7 @@ pub async fn export_npub() -> Result<String, BurrowError> {
 /// Export the public key as hex string.
 #[frb]
 pub async fn export_pubkey_hex() -> Result<String, BurrowError> {
-    state::with_state(|s| Ok(s.keys.public_key().to_hex())).await
+    state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await
 }
 
 /// Nostr profile metadata (kind 0), FFI-friendly.
@@ -117,7 +118,7 @@ impl ProfileData {
 pub async fn set_profile(profile: ProfileData) -> Result<(), BurrowError> {
     let metadata = profile.to_metadata()?;
     // Clone the client out so we can drop the state lock before awaiting
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     let builder = EventBuilder::metadata(&metadata);
     client
@@ -126,7 +127,7 @@ pub async fn set_profile(profile: ProfileData) -> Result<(), BurrowError> {
         .map_err(|e| BurrowError::from(e.to_string()))?;
 
     // Update cache with our own profile
-    let pubkey_hex = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await?;
+    let pubkey_hex = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await?;
     state::with_state_mut(|s| {
         s.profile_cache.insert(pubkey_hex, profile);
         Ok(())
@@ -147,7 +148,7 @@ pub async fn fetch_profile(
     blocking_sync: bool,
 ) -> Result<ProfileData, BurrowError> {
     // Check cache first
-    let cached = state::with_state(|s| {
+    let cached = state::with_state_read(|s| {
         Ok(s.profile_cache.get(&pubkey_hex).cloned())
     })
     .await?;
@@ -168,7 +169,7 @@ pub async fn fetch_profile(
         PublicKey::parse(&pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
 
     // Clone client out, drop the lock, then do async relay query
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     let filter = Filter::new()
         .kind(Kind::Metadata)
@@ -208,7 +209,7 @@ pub async fn fetch_user_relays(pubkey_hex: String) -> Result<Vec<String>, Burrow
     let pubkey =
         PublicKey::parse(&pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
 
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     let filter = Filter::new()
         .kind(Kind::RelayList)
@@ -237,8 +238,8 @@ pub async fn fetch_user_relays(pubkey_hex: String) -> Result<Vec<String>, Burrow
 /// relays if found.
 #[frb]
 pub async fn bootstrap_identity() -> Result<ProfileData, BurrowError> {
-    let (pubkey_hex, client) = state::with_state(|s| {
-        Ok((s.keys.public_key().to_hex(), s.client.clone()))
+    let (pubkey_hex, client) = state::with_state_read(|s| {
+        Ok((s.signer.public_key().to_hex(), s.client.clone()))
     }).await?;
 
     // Add default relays and connect (non-blocking, nostr-sdk auto-reconnects)
@@ -275,7 +276,7 @@ pub async fn bootstrap_identity() -> Result<ProfileData, BurrowError> {
 /// Look up a cached profile without any relay queries. Returns empty if not cached.
 #[frb]
 pub async fn get_cached_profile(pubkey_hex: String) -> Result<ProfileData, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         Ok(s.profile_cache.get(&pubkey_hex).cloned().unwrap_or_default())
     })
     .await
@@ -291,13 +292,13 @@ pub async fn upload_profile_photo(
 ) -> Result<String, BurrowError> {
     use sha2::{Sha256, Digest};
 
-    let keys = state::with_state(|s| Ok(s.keys.clone())).await?;
+    let signer = state::with_state_read(|s| Ok(s.signer.clone())).await?;
 
     // Hash the file for BUD-02
     let hash_hex = hex::encode(Sha256::digest(&file_data));
 
     // Build BUD-02 auth event
-    let auth_event = nostr_sdk::EventBuilder::new(
+    let auth_event = signer.sign(nostr_sdk::EventBuilder::new(
         nostr_sdk::Kind::Custom(24242),
         "Upload profile photo",
     )
@@ -306,11 +307,8 @@ pub async fn upload_profile_photo(
     .tag(nostr_sdk::Tag::parse([
         "expiration".to_string(),
         (nostr_sdk::Timestamp::now().as_secs() + 300).to_string(),
-    ]).unwrap())
-    .build(keys.public_key())
-    .sign(&keys)
-    .await
-    .map_err(|e| BurrowError::from(format!("Failed to sign auth event: {e}")))?;
+    ]).unwrap()))
+    .await?;
 
     let auth_b64 = {
         use base64::Engine;
@@ -355,8 +353,8 @@ pub async fn upload_profile_photo(
     };
 
     // Fetch current profile, update picture, republish kind 0
-    let pubkey_hex = keys.public_key().to_hex();
-    let current = state::with_state(|s| {
+    let pubkey_hex = signer.public_key().to_hex();
+    let current = state::with_state_read(|s| {
         Ok(s.profile_cache.get(&pubkey_hex).cloned().unwrap_or_default())
     }).await?;
 
@@ -7,12 +7,17 @@
 //!
 //! Also handles receiving and processing incoming Welcome messages.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::*;
 use nostr_sdk::prelude::*;
 
+use crate::api::diagnostics;
+use crate::api::ephemeral;
 use crate::api::error::BurrowError;
-use crate::api::group::UpdateGroupResult;
+use crate::api::group::{self, evolution_relays, UpdateGroupResult};
+use crate::api::relay;
 use crate::api::state;
 
 /// Welcome information received from another user.
@@ -37,10 +42,115 @@ pub struct WelcomeInfo {
     pub state: String,
 }
 
+/// Default maximum age of a KeyPackage we'll invite with (30 days).
+pub const DEFAULT_KEY_PACKAGE_MAX_AGE_SECS: u64 = 30 * 86400;
+
+/// MLS ciphersuite Burrow groups use:
+/// MLS_128_DHKEMX25519_AES128GCM_SHA256_Ed25519 (0x0001).
+pub const EXPECTED_CIPHERSUITE: u16 = 0x0001;
+
+static KEY_PACKAGE_MAX_AGE_SECS: AtomicU64 = AtomicU64::new(DEFAULT_KEY_PACKAGE_MAX_AGE_SECS);
+
+/// Set how old a KeyPackage may be before `add_members` refuses it.
+#[frb(sync)]
+pub fn set_key_package_max_age(secs: u64) {
+    KEY_PACKAGE_MAX_AGE_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// Get the KeyPackage max age enforced by `add_members`.
+#[frb(sync)]
+pub fn get_key_package_max_age() -> u64 {
+    KEY_PACKAGE_MAX_AGE_SECS.load(Ordering::Relaxed)
+}
+
+/// Check a kind 443 event before inviting with it: not older than
+/// `max_age_secs` at `now`, and for our ciphersuite. Returns why not.
+///
+/// An old KeyPackage has likely been rotated away by its owner, who then
+/// can't decrypt the welcome.
+#[frb(ignore)]
+pub fn check_key_package_event(event: &Event, now: Timestamp, max_age_secs: u64) -> Result<(), String> {
+    if event.kind != Kind::MlsKeyPackage {
+        return Err(format!("not a KeyPackage (kind {})", event.kind.as_u16()));
+    }
+    let age = now.as_secs().saturating_sub(event.created_at.as_secs());
+    if age > max_age_secs {
+        return Err(format!(
+            "expired: published {} days ago (max {})",
+            age / 86400,
+            max_age_secs / 86400
+        ));
+    }
+    let ciphersuite = tag_values(event, "mls_ciphersuite")
+        .and_then(|values| values.first().cloned())
+        .ok_or_else(|| "missing mls_ciphersuite tag".to_string())?;
+    match parse_ciphersuite(&ciphersuite) {
+        Some(EXPECTED_CIPHERSUITE) => Ok(()),
+        _ => Err(format!(
+            "unsupported ciphersuite {ciphersuite} (expected 0x{EXPECTED_CIPHERSUITE:04x})"
+        )),
+    }
+}
+
+/// Validate KeyPackages for `add_members`, naming every pubkey whose
+/// package is unusable. Packages that don't list any of `group_relays` are
+/// allowed but logged, since the invitee may not see group traffic.
+#[frb(ignore)]
+pub fn validate_key_packages(
+    events: &[Event],
+    group_relays: &[String],
+    now: Timestamp,
+    max_age_secs: u64,
+) -> Result<(), BurrowError> {
+    let mut problems = Vec::new();
+    for event in events {
+        if let Err(reason) = check_key_package_event(event, now, max_age_secs) {
+            problems.push(format!("{}: {reason}", event.pubkey.to_hex()));
+            continue;
+        }
+        let kp_relays = tag_values(event, "relays").unwrap_or_default();
+        let overlaps = kp_relays
+            .iter()
+            .any(|r| group_relays.iter().any(|g| g.trim_end_matches('/') == r.trim_end_matches('/')));
+        if !group_relays.is_empty() && !overlaps {
+            eprintln!(
+                "[invite] KeyPackage of {} lists none of the group's relays",
+                event.pubkey.to_hex()
+            );
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(BurrowError::from(format!("Invalid KeyPackage: {}", problems.join("; "))))
+    }
+}
+
+/// Values of the first `name` tag of `event`.
+fn tag_values(event: &Event, name: &str) -> Option<Vec<String>> {
+    event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .find(|t| t.first().map(String::as_str) == Some(name))
+        .map(|t| t[1..].to_vec())
+}
+
+/// Parse a ciphersuite tag value, "0x0001" or "1".
+fn parse_ciphersuite(value: &str) -> Option<u16> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u16::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
 /// Add members to an existing group. Admin-only.
 ///
 /// `key_package_events_json`: JSON-serialized kind 443 KeyPackage events for each new member.
 ///
+/// KeyPackages are checked first (see `validate_key_packages`); the error
+/// lists each invitee whose package is expired or for another ciphersuite.
+///
 /// Returns an evolution event (commit) to publish and welcome rumors to gift-wrap.
 /// IMPORTANT: Publish the evolution event FIRST, wait for relay ack, then merge_pending_commit,
 /// then send welcome rumors via NIP-59 gift wrap. This prevents state forks per MIP-02.
@@ -49,7 +159,7 @@ pub async fn add_members(
     mls_group_id_hex: String,
     key_package_events_json: Vec<String>,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -59,6 +169,13 @@ pub async fn add_members(
             .map(|j| Event::from_json(j).map_err(|e| BurrowError::from(e.to_string())))
             .collect::<Result<Vec<_>, _>>()?;
 
+        validate_key_packages(
+            &kp_events,
+            &evolution_relays(s, &group_id, &[]),
+            Timestamp::now(),
+            get_key_package_max_age(),
+        )?;
+
         let result = s
             .mdk
             .add_members(&group_id, &kp_events)
@@ -78,11 +195,110 @@ pub async fn add_members(
             evolution_event_json: evolution_json,
             welcome_rumors_json: welcome_jsons,
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
 }
 
+/// Result of `invite_members_full`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct InviteResult {
+    /// Hex-encoded MLS group ID.
+    pub mls_group_id_hex: String,
+    /// ID of the published commit (kind 445).
+    pub evolution_event_id_hex: String,
+    /// IDs of the published gift-wrapped welcomes (kind 1059).
+    pub welcome_event_ids_hex: Vec<String>,
+    /// Invitees whose welcome couldn't be published. They are already
+    /// members; send their welcome again with `gift_wrap_welcome`.
+    pub failed_welcome_pubkeys_hex: Vec<String>,
+}
+
+/// Add members and run the whole MIP-02 flow in order: publish the commit
+/// and wait for a relay to accept it, merge it, then gift-wrap and publish
+/// each welcome.
+///
+/// If the commit isn't accepted by any relay it is discarded instead of
+/// merged, so the group stays at its current epoch and the invite can be
+/// retried.
+/// Welcome failures after the merge don't fail the call; see
+/// `failed_welcome_pubkeys_hex`.
+#[frb]
+pub async fn invite_members_full(
+    mls_group_id_hex: String,
+    key_package_events_json: Vec<String>,
+) -> Result<InviteResult, BurrowError> {
+    invite_members_full_with(mls_group_id_hex, key_package_events_json, |json, relays| {
+        group::publish_evolution_event(json, relays)
+    })
+    .await
+}
+
+/// `invite_members_full` with the commit publication supplied by the caller.
+#[frb(ignore)]
+pub async fn invite_members_full_with<F, Fut>(
+    mls_group_id_hex: String,
+    key_package_events_json: Vec<String>,
+    publish_commit: F,
+) -> Result<InviteResult, BurrowError>
+where
+    F: FnOnce(String, Vec<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<String, BurrowError>>,
+{
+    // Welcome rumors come back in the order of the KeyPackages.
+    let recipients: Vec<String> = key_package_events_json
+        .iter()
+        .map(|j| {
+            Event::from_json(j)
+                .map(|e| e.pubkey.to_hex())
+                .map_err(|e| BurrowError::from(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let update = add_members(mls_group_id_hex.clone(), key_package_events_json).await?;
+
+    let evolution_event_id_hex = match publish_commit(update.evolution_event_json, update.publish_relays).await {
+        Ok(id) => id,
+        Err(e) => {
+            // Drop the unpublished commit so the next add_members starts clean.
+            if let Err(clear) = group::clear_pending_commit(mls_group_id_hex.clone()).await {
+                diagnostics::record_error("invite", &clear.message);
+            }
+            return Err(BurrowError::from(format!(
+                "Commit was not published, so it was not merged: {}",
+                e.message
+            )));
+        }
+    };
+
+    group::merge_pending_commit(mls_group_id_hex.clone()).await?;
+
+    let mut welcome_event_ids_hex = Vec::new();
+    let mut failed_welcome_pubkeys_hex = Vec::new();
+    for (rumor_json, recipient) in update.welcome_rumors_json.into_iter().zip(recipients) {
+        let published = async {
+            let wrapped = gift_wrap_welcome(rumor_json, recipient.clone()).await?;
+            relay::publish_event_json(wrapped).await
+        };
+        match published.await {
+            Ok(id) => welcome_event_ids_hex.push(id),
+            Err(e) => {
+                diagnostics::record_error("invite", &e.message);
+                failed_welcome_pubkeys_hex.push(recipient);
+            }
+        }
+    }
+
+    Ok(InviteResult {
+        mls_group_id_hex: update.mls_group_id_hex,
+        evolution_event_id_hex,
+        welcome_event_ids_hex,
+        failed_welcome_pubkeys_hex,
+    })
+}
+
 /// Remove members from a group. Admin-only.
 ///
 /// `pubkeys_hex`: Hex-encoded public keys of members to remove.
@@ -91,7 +307,7 @@ pub async fn remove_members(
     mls_group_id_hex: String,
     pubkeys_hex: Vec<String>,
 ) -> Result<UpdateGroupResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -120,6 +336,7 @@ pub async fn remove_members(
             evolution_event_json: evolution_json,
             welcome_rumors_json: welcome_jsons,
             mls_group_id_hex: hex::encode(result.mls_group_id.as_slice()),
+            publish_relays: evolution_relays(s, &group_id, &[]),
         })
     })
     .await
@@ -136,7 +353,7 @@ pub async fn process_welcome(
     wrapper_event_id_hex: String,
     welcome_rumor_json: String,
 ) -> Result<WelcomeInfo, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let wrapper_event_id = EventId::from_hex(&wrapper_event_id_hex)
             .map_err(|e| BurrowError::from(e.to_string()))?;
 
@@ -172,7 +389,7 @@ pub async fn process_welcome(
 /// Accept a pending welcome invitation and join the group.
 #[frb]
 pub async fn accept_welcome(welcome_event_id_hex: String) -> Result<(), BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let event_id = EventId::from_hex(&welcome_event_id_hex)
             .map_err(|e| BurrowError::from(e.to_string()))?;
 
@@ -190,7 +407,7 @@ pub async fn accept_welcome(welcome_event_id_hex: String) -> Result<(), BurrowEr
 /// Decline a pending welcome invitation.
 #[frb]
 pub async fn decline_welcome(welcome_event_id_hex: String) -> Result<(), BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let event_id = EventId::from_hex(&welcome_event_id_hex)
             .map_err(|e| BurrowError::from(e.to_string()))?;
 
@@ -208,7 +425,7 @@ pub async fn decline_welcome(welcome_event_id_hex: String) -> Result<(), BurrowE
 /// List pending welcome invitations.
 #[frb]
 pub async fn list_pending_welcomes() -> Result<Vec<WelcomeInfo>, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let welcomes = s
             .mdk
             .get_pending_welcomes(None)
@@ -249,8 +466,8 @@ pub async fn list_pending_welcomes() -> Result<Vec<WelcomeInfo>, BurrowError> {
 /// welcomes sent while the app was offline.
 #[frb]
 pub async fn sync_welcomes() -> Result<u32, BurrowError> {
-    let (client, keys) = state::with_state(|s| {
-        Ok((s.client.clone(), s.keys.clone()))
+    let (client, my_pubkey) = state::with_state_read(|s| {
+        Ok((s.client.clone(), s.signer.public_key()))
     })
     .await?;
 
@@ -259,7 +476,7 @@ pub async fn sync_welcomes() -> Result<u32, BurrowError> {
         .kind(Kind::GiftWrap)
         .custom_tag(
             SingleLetterTag::lowercase(Alphabet::P),
-            keys.public_key().to_hex(),
+            my_pubkey.to_hex(),
         )
         .limit(100);
 
@@ -271,10 +488,16 @@ pub async fn sync_welcomes() -> Result<u32, BurrowError> {
     let mut welcome_count: u32 = 0;
 
     for event in events.iter() {
-        // Unwrap NIP-59 gift wrap
+        if !ephemeral::is_addressed_to(event, &my_pubkey) {
+            continue;
+        }
+        // Unwrap NIP-59 gift wrap — it's ours, so failure means a broken wrap
         let rumor = match client.unwrap_gift_wrap(event).await {
             Ok(unwrapped) => unwrapped.rumor,
-            Err(_) => continue,
+            Err(e) => {
+                diagnostics::record_error("welcome_unwrap", &e.to_string());
+                continue;
+            }
         };
 
         // Only process kind 444 (MLS Welcome) rumors
@@ -289,7 +512,7 @@ pub async fn sync_welcomes() -> Result<u32, BurrowError> {
         };
 
         // Process through MDK — silently skip already-processed welcomes
-        let result = state::with_state(|s| {
+        let result = state::with_state_mut(|s| {
             let unsigned: UnsignedEvent = serde_json::from_str(&rumor_json)
                 .map_err(|e| BurrowError::from(e.to_string()))?;
             s.mdk
@@ -321,11 +544,9 @@ pub async fn gift_wrap_welcome(
     let recipient = PublicKey::from_hex(&recipient_pubkey_hex)
         .map_err(|e| BurrowError::from(e.to_string()))?;
 
-    let keys = state::with_state(|s| Ok(s.keys.clone())).await?;
+    let signer = state::with_state_read(|s| Ok(s.signer.clone())).await?;
 
-    let gift_wrap = EventBuilder::gift_wrap(&keys, &recipient, rumor, Vec::<Tag>::new())
-        .await
-        .map_err(|e| BurrowError::from(e.to_string()))?;
+    let gift_wrap = ephemeral::gift_wrap(&signer, &recipient, rumor, Vec::<Tag>::new()).await?;
 
     serde_json::to_string(&gift_wrap).map_err(|e| BurrowError::from(e.to_string()))
 }
@@ -343,7 +564,7 @@ pub async fn fetch_key_package(pubkey_hex: String) -> Result<String, BurrowError
     let pubkey =
         PublicKey::from_hex(&pubkey_hex).map_err(|e| BurrowError::from(e.to_string()))?;
 
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     // Fetch ALL key packages for this pubkey — don't use .limit(1) because
     // that doesn't guarantee the newest event is returned, and the local
@@ -368,5 +589,9 @@ pub async fn fetch_key_package(pubkey_hex: String) -> Result<String, BurrowError
             ))
         })?;
 
+    check_key_package_event(&event, Timestamp::now(), get_key_package_max_age()).map_err(|reason| {
+        BurrowError::from(format!("Newest KeyPackage for {pubkey_hex} is unusable: {reason}"))
+    })?;
+
     serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))
 }
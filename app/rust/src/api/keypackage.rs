@@ -20,7 +20,7 @@ pub struct KeyPackageData {
 /// Returns the data needed to create a kind 443 Nostr event.
 #[frb]
 pub async fn generate_key_package(relay_urls: Vec<String>) -> Result<KeyPackageData, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let relays: Vec<RelayUrl> = relay_urls
             .iter()
             .filter_map(|u| RelayUrl::parse(u).ok())
@@ -28,7 +28,7 @@ pub async fn generate_key_package(relay_urls: Vec<String>) -> Result<KeyPackageD
 
         let (kp_base64, tags, _hash_ref) = s
             .mdk
-            .create_key_package_for_event(&s.keys.public_key(), relays)
+            .create_key_package_for_event(&s.signer.public_key(), relays)
             .map_err(BurrowError::from)?;
 
         let tags_flat: Vec<Vec<String>> = tags
@@ -70,7 +70,7 @@ pub async fn publish_key_package(relay_urls: Vec<String>) -> Result<String, Burr
     let builder = EventBuilder::new(Kind::MlsKeyPackage, &kp_data.key_package_base64)
         .tags(tags);
 
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     let output = client
         .send_event_builder(builder)
         .await
@@ -96,7 +96,7 @@ pub async fn publish_key_package_relays(relay_urls: Vec<String>) -> Result<Strin
     let builder = EventBuilder::new(Kind::Custom(10051), "")
         .tags(tags);
 
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     let output = client
         .send_event_builder(builder)
         .await
@@ -4,6 +4,9 @@
 //! Handles encrypt, decrypt, upload (Blossom), download, and imeta tag
 //! construction/parsing per the Marmot protocol MIP-04 v2 spec.
 
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::*;
 use nostr_sdk::prelude::*;
@@ -42,6 +45,37 @@ pub struct EncryptedFileResult {
     pub nonce_hex: String,
 }
 
+/// `EncryptedFileResult` with the ciphertext written to disk instead of
+/// held in memory.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct EncryptedFileOnDisk {
+    /// Path of the encrypted bytes, ready for upload. The caller deletes it
+    /// once uploaded.
+    pub encrypted_path: String,
+    /// SHA-256 of the *original* plaintext file (hex).
+    pub original_hash_hex: String,
+    /// SHA-256 of the encrypted data (hex).
+    pub encrypted_hash_hex: String,
+    /// Canonical MIME type.
+    pub mime_type: String,
+    /// Original filename.
+    pub filename: String,
+    /// Original file size in bytes.
+    pub original_size: u64,
+    /// Encrypted file size in bytes.
+    pub encrypted_size: u64,
+    /// Image/video dimensions ("widthxheight") if applicable.
+    pub dimensions: Option<String>,
+    /// Blurhash string for progressive image loading.
+    pub blurhash: Option<String>,
+    /// Encryption nonce (hex, 24 chars / 12 bytes).
+    pub nonce_hex: String,
+    /// imeta `v` value: "mip04-v2", or `CHUNKED_SCHEME_VERSION` for files
+    /// that were streamed (build their tag with `build_chunked_imeta_tag`).
+    pub scheme_version: String,
+}
+
 /// Parsed imeta tag fields for a received encrypted media reference.
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
@@ -60,6 +94,24 @@ pub struct MediaReferenceInfo {
     pub scheme_version: String,
     /// Nonce (hex, 24 chars).
     pub nonce_hex: String,
+    /// Low-res preview, if the sender attached one.
+    pub thumb: Option<ThumbnailReference>,
+}
+
+/// An encrypted thumbnail referenced from an imeta `thumb` field
+/// (`thumb <url>;<hash>;<nonce>`).
+///
+/// Thumbnails are always encrypted as `THUMBNAIL_MIME_TYPE` named
+/// `THUMBNAIL_FILENAME`, so pass those to `decrypt_file` with these fields.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThumbnailReference {
+    /// Blossom storage URL.
+    pub url: String,
+    /// SHA-256 of the thumbnail plaintext (hex).
+    pub original_hash_hex: String,
+    /// Nonce (hex, 24 chars).
+    pub nonce_hex: String,
 }
 
 /// Result of uploading encrypted media to a Blossom server.
@@ -74,6 +126,32 @@ pub struct UploadMediaResult {
     pub reference: MediaReferenceInfo,
 }
 
+/// One file to upload as part of a batch.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FileInput {
+    pub file_data: Vec<u8>,
+    pub mime_type: String,
+    pub filename: String,
+}
+
+/// Outcome of one file in `upload_media_batch`. Exactly one of `result` and
+/// `error` is set.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct BatchUploadItem {
+    /// Filename of the input this outcome belongs to.
+    pub filename: String,
+    pub result: Option<UploadMediaResult>,
+    pub error: Option<String>,
+}
+
+/// MIME type thumbnails are encoded and encrypted as.
+pub const THUMBNAIL_MIME_TYPE: &str = "image/jpeg";
+/// Filename thumbnails are encrypted under (part of the MIP-04 AAD).
+pub const THUMBNAIL_FILENAME: &str = "thumbnail.jpg";
+const THUMBNAIL_JPEG_QUALITY: u8 = 75;
+
 // ---------------------------------------------------------------------------
 // Public FFI functions
 // ---------------------------------------------------------------------------
@@ -82,14 +160,20 @@ pub struct UploadMediaResult {
 ///
 /// Derives a file-specific key from the group's current MLS exporter secret,
 /// generates a random nonce, and encrypts with ChaCha20-Poly1305 + AAD.
+///
+/// `mime_type` is checked against the file's magic bytes first: on a
+/// mismatch the detected type is used, or with `strict` an error is
+/// returned. See `sniff_mime_type`.
 #[frb]
 pub async fn encrypt_file(
     mls_group_id_hex: String,
     file_data: Vec<u8>,
     mime_type: String,
     filename: String,
+    strict: bool,
 ) -> Result<EncryptedFileResult, BurrowError> {
-    state::with_state(|s| {
+    let mime_type = sniff_mime_type(&file_data, &mime_type, strict)?;
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -117,6 +201,225 @@ pub async fn encrypt_file(
     .await
 }
 
+/// Check a declared MIME type against the file's leading bytes.
+///
+/// Returns the declared type if it matches (aliases like `image/jpg` count)
+/// or the content isn't recognized, e.g. text. Otherwise returns the
+/// detected type, or with `strict` an error naming both.
+#[frb(ignore)]
+pub fn sniff_mime_type(data: &[u8], declared: &str, strict: bool) -> Result<String, BurrowError> {
+    let Some(detected) = infer::get(data).map(|t| t.mime_type()) else {
+        return Ok(declared.to_string());
+    };
+    if canonical_mime(declared) == canonical_mime(detected) {
+        return Ok(declared.to_string());
+    }
+    if strict {
+        return Err(BurrowError::from(format!(
+            "File declared as {} but its content is {}",
+            declared, detected
+        )));
+    }
+    Ok(detected.to_string())
+}
+
+/// Lowercased MIME type with common aliases folded to one name.
+fn canonical_mime(mime_type: &str) -> String {
+    let lower = mime_type.trim().to_ascii_lowercase();
+    match lower.as_str() {
+        "image/jpg" | "image/pjpeg" => "image/jpeg".to_string(),
+        "audio/mp3" | "audio/x-mp3" | "audio/mpeg3" => "audio/mpeg".to_string(),
+        "audio/mp4" | "audio/x-m4a" => "audio/m4a".to_string(),
+        "audio/wav" | "audio/wave" | "audio/vnd.wave" => "audio/x-wav".to_string(),
+        "application/x-zip-compressed" => "application/zip".to_string(),
+        _ => lower,
+    }
+}
+
+/// How much of a file on disk `sniff_file_mime_type` reads: enough for
+/// every signature `infer` knows.
+const MIME_SNIFF_BYTES: u64 = 8192;
+
+/// `sniff_mime_type` for a file on disk, reading only its first bytes.
+fn sniff_file_mime_type(path: &str, declared: &str, strict: bool) -> Result<String, BurrowError> {
+    use std::io::Read;
+
+    let mut head = Vec::with_capacity(MIME_SNIFF_BYTES as usize);
+    std::fs::File::open(path)?.take(MIME_SNIFF_BYTES).read_to_end(&mut head)?;
+    sniff_mime_type(&head, declared, strict)
+}
+
+/// Encrypt a file on disk for a group, writing the ciphertext under the
+/// data directory instead of returning it.
+///
+/// Files below `CHUNKED_THRESHOLD_BYTES` use whole-buffer MIP-04 v2: MDK
+/// needs the plaintext in memory, but it is dropped as soon as the
+/// ciphertext exists and the file never crosses the FFI boundary. Larger
+/// files are streamed through `encrypt_file_chunked`, so memory stays at
+/// one frame; `scheme_version` says which was used.
+///
+/// `mime_type` and `strict` are checked as in `encrypt_file`.
+#[frb]
+pub async fn encrypt_file_from_path(
+    mls_group_id_hex: String,
+    file_path: String,
+    mime_type: String,
+    filename: String,
+    strict: bool,
+) -> Result<EncryptedFileOnDisk, BurrowError> {
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(&file_path)?;
+    let size = file.metadata()?.len();
+    if should_use_chunked_encryption(size) {
+        drop(file);
+        return encrypt_file_from_path_chunked(
+            mls_group_id_hex,
+            file_path,
+            mime_type,
+            filename,
+            strict,
+        )
+        .await;
+    }
+
+    let mime_type = sniff_file_mime_type(&file_path, &mime_type, strict)?;
+    // Size the buffer up front so reading doesn't reallocate (and briefly
+    // double) the file.
+    let mut file_data = Vec::with_capacity(size as usize);
+    file.read_to_end(&mut file_data)?;
+    drop(file);
+
+    let upload = state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let upload = s
+            .mdk
+            .media_manager(group_id)
+            .encrypt_for_upload(&file_data, &mime_type, &filename)
+            .map_err(|e| BurrowError::from(e.to_string()));
+        drop(file_data);
+        upload
+    })
+    .await?;
+
+    let dir = media_tmp_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    let encrypted_hash_hex = hex::encode(upload.encrypted_hash);
+    let path = dir.join(format!("{encrypted_hash_hex}.enc"));
+    std::fs::write(&path, &upload.encrypted_data)?;
+
+    Ok(EncryptedFileOnDisk {
+        encrypted_path: path.to_string_lossy().into_owned(),
+        original_hash_hex: hex::encode(upload.original_hash),
+        encrypted_hash_hex,
+        mime_type: upload.mime_type,
+        filename: upload.filename,
+        original_size: upload.original_size,
+        encrypted_size: upload.encrypted_size,
+        dimensions: upload.dimensions.map(|(w, h)| format!("{}x{}", w, h)),
+        blurhash: upload.blurhash,
+        nonce_hex: hex::encode(upload.nonce),
+        scheme_version: "mip04-v2".to_string(),
+    })
+}
+
+/// The streaming half of `encrypt_file_from_path`.
+async fn encrypt_file_from_path_chunked(
+    mls_group_id_hex: String,
+    file_path: String,
+    mime_type: String,
+    filename: String,
+    strict: bool,
+) -> Result<EncryptedFileOnDisk, BurrowError> {
+    let dir = media_tmp_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    // The ciphertext hash is only known once it's written.
+    let partial = dir.join(format!("{:016x}.enc.part", rand::random::<u64>()));
+    let partial_str = partial.to_string_lossy().into_owned();
+
+    let result = match encrypt_file_chunked(
+        mls_group_id_hex,
+        file_path,
+        partial_str,
+        mime_type,
+        filename,
+        strict,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            return Err(e);
+        }
+    };
+    let path = dir.join(format!("{}.enc", result.encrypted_hash_hex));
+    std::fs::rename(&partial, &path)?;
+
+    Ok(EncryptedFileOnDisk {
+        encrypted_path: path.to_string_lossy().into_owned(),
+        original_hash_hex: result.original_hash_hex,
+        encrypted_hash_hex: result.encrypted_hash_hex,
+        mime_type: result.mime_type,
+        filename: result.filename,
+        original_size: result.original_size,
+        encrypted_size: result.encrypted_size,
+        dimensions: None,
+        blurhash: None,
+        nonce_hex: result.nonce_hex,
+        scheme_version: result.scheme_version,
+    })
+}
+
+/// Downscale an image to fit within `max_dimension` and encrypt it as a
+/// JPEG thumbnail (MIP-04 v2).
+///
+/// Upload the result like any other file and reference it from the full
+/// image's imeta tag via `build_imeta_tag`'s `thumb`.
+#[frb]
+pub async fn encrypt_thumbnail(
+    mls_group_id_hex: String,
+    file_data: Vec<u8>,
+    max_dimension: u32,
+) -> Result<EncryptedFileResult, BurrowError> {
+    let thumbnail = downscale_to_jpeg(&file_data, max_dimension)?;
+    drop(file_data);
+    encrypt_file(
+        mls_group_id_hex,
+        thumbnail,
+        THUMBNAIL_MIME_TYPE.to_string(),
+        THUMBNAIL_FILENAME.to_string(),
+        true,
+    )
+    .await
+}
+
+/// Decode an image and re-encode it as a JPEG no larger than
+/// `max_dimension` on either side, preserving the aspect ratio. Images
+/// that already fit are re-encoded at their own size.
+#[frb(ignore)]
+pub fn downscale_to_jpeg(data: &[u8], max_dimension: u32) -> Result<Vec<u8>, BurrowError> {
+    if max_dimension == 0 {
+        return Err(BurrowError::from("Thumbnail size must be positive".to_string()));
+    }
+    let image = image::load_from_memory(data)
+        .map_err(|e| BurrowError::from(format!("Unsupported image: {}", e)))?;
+    let image = if image.width() > max_dimension || image.height() > max_dimension {
+        image.thumbnail(max_dimension, max_dimension)
+    } else {
+        image
+    };
+    // JPEG has no alpha channel
+    let rgb = image.to_rgb8();
+    let mut out = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, THUMBNAIL_JPEG_QUALITY)
+        .encode_image(&rgb)
+        .map_err(|e| BurrowError::from(format!("Thumbnail encoding failed: {}", e)))?;
+    Ok(out)
+}
+
 /// Decrypt an encrypted file received from a group.
 ///
 /// Uses the imeta tag fields to reconstruct AAD and derive the correct key
@@ -133,7 +436,7 @@ pub async fn decrypt_file(
     scheme_version: String,
     dimensions: Option<String>,
 ) -> Result<Vec<u8>, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -160,7 +463,8 @@ pub async fn decrypt_file(
 
 /// Upload encrypted media to a Blossom server and return imeta tag data.
 ///
-/// 1. Encrypts the file via MIP-04 v2.
+/// 1. Encrypts the file via MIP-04 v2, correcting a `mime_type` that
+///    doesn't match the content.
 /// 2. Uploads the ciphertext to `blossom_server_url` using HTTP PUT.
 /// 3. Constructs the imeta tag from the upload result + returned URL.
 #[frb]
@@ -177,6 +481,7 @@ pub async fn upload_media(
         file_data,
         mime_type,
         filename,
+        false,
     )
     .await?;
 
@@ -186,23 +491,17 @@ pub async fn upload_media(
         blossom_server_url.trim_end_matches('/')
     );
 
-    // Build BUD-02 auth event using the user's keys
-    let (keys,) = state::with_state(|s| {
-        Ok((s.keys.clone(),))
-    })
-    .await?;
+    // Build BUD-02 auth event signed as the user
+    let signer = state::with_state_read(|s| Ok(s.signer.clone())).await?;
 
-    let auth_event = nostr_sdk::EventBuilder::new(
+    let auth_event = signer.sign(nostr_sdk::EventBuilder::new(
         nostr_sdk::Kind::Custom(24242),
         "Upload encrypted media",
     )
     .tag(nostr_sdk::Tag::parse(["t".to_string(), "upload".to_string()]).unwrap())
     .tag(nostr_sdk::Tag::parse(["x".to_string(), enc.encrypted_hash_hex.clone()]).unwrap())
-    .tag(nostr_sdk::Tag::parse(["expiration".to_string(), (nostr_sdk::Timestamp::now().as_secs() + 300).to_string()]).unwrap())
-    .build(keys.public_key())
-    .sign(&keys)
-    .await
-    .map_err(|e| BurrowError::from(format!("Failed to sign auth event: {}", e)))?;
+    .tag(nostr_sdk::Tag::parse(["expiration".to_string(), (nostr_sdk::Timestamp::now().as_secs() + 300).to_string()]).unwrap()))
+    .await?;
 
     let auth_b64 = {
         use base64::Engine;
@@ -248,6 +547,7 @@ pub async fn upload_media(
         enc.nonce_hex.clone(),
         enc.dimensions.clone(),
         enc.blurhash.clone(),
+        None,
     )?;
 
     let reference = MediaReferenceInfo {
@@ -258,6 +558,7 @@ pub async fn upload_media(
         dimensions: enc.dimensions,
         scheme_version: "mip04-v2".to_string(),
         nonce_hex: enc.nonce_hex,
+        thumb: None,
     };
 
     Ok(UploadMediaResult {
@@ -267,9 +568,76 @@ pub async fn upload_media(
     })
 }
 
+/// Maximum number of concurrent uploads in `upload_media_batch`.
+const MEDIA_UPLOAD_CONCURRENCY: usize = 4;
+
+/// Encrypt and upload several attachments concurrently.
+///
+/// Runs at most `MEDIA_UPLOAD_CONCURRENCY` uploads at a time. Returns one
+/// outcome per input, in input order, so a partially failed batch can still
+/// send the successful attachments: collect `imeta_tag_values` from the
+/// items with a `result` and pass them to `send_message_with_media`.
+#[frb]
+pub async fn upload_media_batch(
+    mls_group_id_hex: String,
+    files: Vec<FileInput>,
+    blossom_server_url: String,
+) -> Result<Vec<BatchUploadItem>, BurrowError> {
+    let permits = std::sync::Arc::new(tokio::sync::Semaphore::new(MEDIA_UPLOAD_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+    let filenames: Vec<String> = files.iter().map(|f| f.filename.clone()).collect();
+
+    for (index, file) in files.into_iter().enumerate() {
+        let permits = permits.clone();
+        let group = mls_group_id_hex.clone();
+        let server = blossom_server_url.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let _relay_permit = state::relay_permit().await;
+            let result =
+                upload_media(group, file.file_data, file.mime_type, file.filename, server).await;
+            (index, result)
+        });
+    }
+
+    let mut outcomes: Vec<Option<Result<UploadMediaResult, BurrowError>>> =
+        filenames.iter().map(|_| None).collect();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, result)) => outcomes[index] = Some(result),
+            Err(e) => return Err(BurrowError::from(format!("Upload task failed: {}", e))),
+        }
+    }
+
+    Ok(filenames
+        .into_iter()
+        .zip(outcomes)
+        .map(|(filename, outcome)| match outcome {
+            Some(Ok(result)) => BatchUploadItem {
+                filename,
+                result: Some(result),
+                error: None,
+            },
+            Some(Err(e)) => BatchUploadItem {
+                filename,
+                result: None,
+                error: Some(e.message),
+            },
+            None => BatchUploadItem {
+                filename,
+                result: None,
+                error: Some("Upload did not complete".to_string()),
+            },
+        })
+        .collect())
+}
+
 /// Download encrypted media from a Blossom URL and decrypt it.
 ///
-/// 1. Fetches the ciphertext from `url`.
+/// 1. Fetches the ciphertext from `url`, falling back to `mirror_servers`
+///    in order if that fails. Blossom blobs are addressed by hash, so a
+///    mirror serves `{mirror}/{hash}`; only blobs matching the hash in
+///    `url` are accepted, and mirrors are only tried when `url` has one.
 /// 2. Decrypts using the group's exporter secret + imeta metadata.
 /// 3. Returns the plaintext bytes.
 #[frb]
@@ -282,7 +650,16 @@ pub async fn download_media(
     nonce_hex: String,
     scheme_version: String,
     dimensions: Option<String>,
+    mirror_servers: Vec<String>,
 ) -> Result<Vec<u8>, BurrowError> {
+    // Step 0: Serve from the local cache if we've decrypted this file before
+    let cache_dir = media_cache_dir().ok();
+    if let Some(dir) = &cache_dir {
+        if let Some(data) = media_cache_get(dir, &original_hash_hex) {
+            return Ok(data);
+        }
+    }
+
     // Step 1: Fetch (with timeout to prevent hanging)
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(30))
@@ -290,56 +667,48 @@ pub async fn download_media(
         .build()
         .map_err(|e| BurrowError::from(format!("HTTP client error: {}", e)))?;
 
-    let resp = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| BurrowError::from(format!("Download failed for {}: {}", url, e)))?;
-
-    if !resp.status().is_success() {
-        return Err(BurrowError::from(format!(
-            "Download returned HTTP {}",
-            resp.status()
-        )));
-    }
-
-    let encrypted_data = resp
-        .bytes()
-        .await
-        .map_err(|e| BurrowError::from(format!("Failed to read download body: {}", e)))?
-        .to_vec();
-
-    // Step 1.5: Verify encrypted data hash matches URL hash (Blossom content-addressing)
-    let actual_hash = hex::encode(Sha256::digest(&encrypted_data));
-    // Extract expected hash from URL (last path segment is typically the SHA-256 hash)
-    if let Some(url_hash) = url.split('/').last() {
-        if url_hash.len() == 64 && hex::decode(url_hash).is_ok() && actual_hash != url_hash {
-            return Err(BurrowError::from(format!(
-                "Download integrity check failed: expected hash {}, got {}",
-                url_hash, actual_hash
-            )));
+    let expected_hash = blossom_hash_from_url(&url);
+    let encrypted_data = match fetch_blob(&client, &url, expected_hash.as_deref()).await {
+        Ok(data) => data,
+        Err(primary_err) => {
+            let mut from_mirror = None;
+            for mirror_url in expected_hash.iter().flat_map(|hash| mirror_urls(hash, &mirror_servers)) {
+                if let Ok(data) = fetch_blob(&client, &mirror_url, expected_hash.as_deref()).await {
+                    from_mirror = Some(data);
+                    break;
+                }
+            }
+            // Report the primary's error if every mirror failed too
+            from_mirror.ok_or(primary_err)?
         }
-    }
+    };
 
     // Step 2: Decrypt
-    decrypt_file(
+    let data = decrypt_file(
         mls_group_id_hex,
         encrypted_data,
         url,
         mime_type,
         filename,
-        original_hash_hex,
+        original_hash_hex.clone(),
         nonce_hex,
         scheme_version,
         dimensions,
     )
-    .await
+    .await?;
+
+    // Step 3: Cache (best effort — a full disk shouldn't fail the download)
+    if let Some(dir) = &cache_dir {
+        let _ = media_cache_put(dir, &original_hash_hex, &data, get_media_cache_limit());
+    }
+    Ok(data)
 }
 
 /// Build an imeta tag value array from media metadata.
 ///
 /// Returns a flat `Vec<String>` of "key value" pairs suitable for inclusion
-/// in a Nostr event tag: `["imeta", "url ...", "m ...", ...]`.
+/// in a Nostr event tag: `["imeta", "url ...", "m ...", ...]`. `thumb`
+/// references an uploaded `encrypt_thumbnail` result.
 #[frb]
 pub fn build_imeta_tag(
     url: String,
@@ -349,6 +718,7 @@ pub fn build_imeta_tag(
     nonce_hex: String,
     dimensions: Option<String>,
     blurhash: Option<String>,
+    thumb: Option<ThumbnailReference>,
 ) -> Result<Vec<String>, BurrowError> {
     let mut values = vec![
         format!("url {}", url),
@@ -364,6 +734,10 @@ pub fn build_imeta_tag(
         values.push(format!("blurhash {}", bh));
     }
 
+    if let Some(t) = thumb {
+        values.push(format!("thumb {};{};{}", t.url, t.original_hash_hex, t.nonce_hex));
+    }
+
     values.push(format!("x {}", original_hash_hex));
     values.push(format!("n {}", nonce_hex));
     values.push("v mip04-v2".to_string());
@@ -384,6 +758,7 @@ pub fn parse_imeta_tag(tag_values: Vec<String>) -> Result<MediaReferenceInfo, Bu
     let mut nonce_hex: Option<String> = None;
     let mut dimensions: Option<String> = None;
     let mut version: Option<String> = None;
+    let mut thumb: Option<ThumbnailReference> = None;
 
     for item in &tag_values {
         let parts: Vec<&str> = item.splitn(2, ' ').collect();
@@ -412,13 +787,15 @@ pub fn parse_imeta_tag(tag_values: Vec<String>) -> Result<MediaReferenceInfo, Bu
             }
             "dim" => dimensions = Some(parts[1].to_string()),
             "v" => version = Some(parts[1].to_string()),
+            // A malformed thumbnail just means no preview
+            "thumb" => thumb = parse_thumb_field(parts[1]),
             _ => {} // ignore unknown fields for forward compat
         }
     }
 
     let scheme_version =
         version.ok_or_else(|| BurrowError::from("Missing 'v' (version) in imeta tag".to_string()))?;
-    if scheme_version != "mip04-v2" {
+    if scheme_version != "mip04-v2" && scheme_version != CHUNKED_SCHEME_VERSION {
         return Err(BurrowError::from(format!(
             "Unsupported MIP-04 version: {}",
             scheme_version
@@ -437,7 +814,559 @@ pub fn parse_imeta_tag(tag_values: Vec<String>) -> Result<MediaReferenceInfo, Bu
         scheme_version,
         nonce_hex: nonce_hex
             .ok_or_else(|| BurrowError::from("Missing 'n' (nonce) in imeta tag".to_string()))?,
+        thumb,
+    })
+}
+
+/// `<url>;<hash>;<nonce>`. The URL is split off from the right since it
+/// may itself contain ';'.
+fn parse_thumb_field(value: &str) -> Option<ThumbnailReference> {
+    let mut parts = value.rsplitn(3, ';');
+    let nonce_hex = parts.next()?.trim();
+    let original_hash_hex = parts.next()?.trim();
+    let url = parts.next()?.trim();
+    let valid = !url.is_empty()
+        && hex::decode(original_hash_hex).is_ok_and(|b| b.len() == 32)
+        && hex::decode(nonce_hex).is_ok_and(|b| b.len() == 12);
+    valid.then(|| ThumbnailReference {
+        url: url.to_string(),
+        original_hash_hex: original_hash_hex.to_string(),
+        nonce_hex: nonce_hex.to_string(),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Chunked encryption (large files)
+// ---------------------------------------------------------------------------
+//
+// Whole-buffer MIP-04 v2 holds plaintext and ciphertext in memory at once,
+// which OOMs on large videos. The chunked scheme streams file → file in
+// fixed-size frames so memory stays at ~2 frames regardless of file size.
+//
+// A random 32-byte content key is wrapped with the group's regular MIP-04 v2
+// media encryption (so only group members can unwrap it) and stored in the
+// blob header. Frames are ChaCha20-Poly1305 sealed under the content key with
+// nonce = base_nonce XOR frame counter; the AAD binds the counter and a
+// last-frame flag so frames can't be reordered, dropped or truncated.
+//
+// Blob layout (big-endian):
+//   "BMC1" | frame_size u32 | base_nonce [12] | key_hash [32] |
+//   wrap_nonce [12] | wrapped_key_len u16 | wrapped_key | frames...
+
+/// imeta `v` value for chunked media.
+pub const CHUNKED_SCHEME_VERSION: &str = "mip04-v2-chunked";
+
+/// Files at or above this size should use the chunked scheme.
+pub const CHUNKED_THRESHOLD_BYTES: u64 = 8 * 1024 * 1024;
+
+const CHUNKED_MAGIC: &[u8; 4] = b"BMC1";
+const CHUNKED_FRAME_SIZE: u32 = 1024 * 1024;
+const CHUNKED_TAG_LEN: usize = 16;
+const CHUNKED_AAD_DOMAIN: &[u8] = b"burrow-mip04-chunked-v1";
+const WRAPPED_KEY_MIME: &str = "application/octet-stream";
+
+/// Result of chunked file encryption. Ciphertext is written to `output_path`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct ChunkedEncryptResult {
+    /// Path of the encrypted blob, ready for Blossom upload.
+    pub output_path: String,
+    /// SHA-256 of the original plaintext file (hex) — the imeta `x` field.
+    pub original_hash_hex: String,
+    /// SHA-256 of the encrypted blob (hex) — the Blossom content address.
+    pub encrypted_hash_hex: String,
+    pub mime_type: String,
+    pub filename: String,
+    pub original_size: u64,
+    pub encrypted_size: u64,
+    /// Key-wrap nonce (hex) — the imeta `n` field.
+    pub nonce_hex: String,
+    /// Plaintext bytes per frame.
+    pub frame_size: u32,
+    /// Always `CHUNKED_SCHEME_VERSION`.
+    pub scheme_version: String,
+}
+
+/// Whether a file of `file_size` bytes should use chunked encryption.
+///
+/// Small files keep the whole-buffer MIP-04 v2 path for compatibility with
+/// clients that don't understand the chunked scheme.
+#[frb(sync)]
+pub fn should_use_chunked_encryption(file_size: u64) -> bool {
+    file_size >= CHUNKED_THRESHOLD_BYTES
+}
+
+/// Encrypt a file for a group in fixed-size frames, streaming from
+/// `input_path` to `output_path` without loading the whole file.
+///
+/// `mime_type` and `strict` are checked as in `encrypt_file`, against the
+/// file's first bytes.
+#[frb]
+pub async fn encrypt_file_chunked(
+    mls_group_id_hex: String,
+    input_path: String,
+    output_path: String,
+    mime_type: String,
+    filename: String,
+    strict: bool,
+) -> Result<ChunkedEncryptResult, BurrowError> {
+    use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+    use chacha20poly1305::ChaCha20Poly1305;
+    use rand::RngCore;
+    use std::io::{BufReader, BufWriter, Write};
+
+    let mime_type = sniff_file_mime_type(&input_path, &mime_type, strict)?;
+
+    let mut content_key = [0u8; 32];
+    let mut base_nonce = [0u8; 12];
+    rand::rngs::OsRng.fill_bytes(&mut content_key);
+    rand::rngs::OsRng.fill_bytes(&mut base_nonce);
+
+    // Wrap the content key with the group's MIP-04 v2 media key
+    let wrapped = state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        s.mdk
+            .media_manager(group_id)
+            .encrypt_for_upload(&content_key, WRAPPED_KEY_MIME, &wrapped_key_filename(&filename))
+            .map_err(|e| BurrowError::from(e.to_string()))
+    })
+    .await?;
+
+    let wrapped_len = u16::try_from(wrapped.encrypted_data.len())
+        .map_err(|_| BurrowError::from("Wrapped key too large".to_string()))?;
+
+    let mut header = Vec::with_capacity(64 + wrapped.encrypted_data.len());
+    header.extend_from_slice(CHUNKED_MAGIC);
+    header.extend_from_slice(&CHUNKED_FRAME_SIZE.to_be_bytes());
+    header.extend_from_slice(&base_nonce);
+    header.extend_from_slice(&wrapped.original_hash);
+    header.extend_from_slice(&wrapped.nonce);
+    header.extend_from_slice(&wrapped_len.to_be_bytes());
+    header.extend_from_slice(&wrapped.encrypted_data);
+
+    let cipher = ChaCha20Poly1305::new_from_slice(&content_key)
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    let mut reader = BufReader::new(std::fs::File::open(&input_path)?);
+    let mut writer = BufWriter::new(std::fs::File::create(&output_path)?);
+    let mut plain_hasher = Sha256::new();
+    let mut enc_hasher = Sha256::new();
+
+    writer.write_all(&header)?;
+    enc_hasher.update(&header);
+    let mut encrypted_size = header.len() as u64;
+    let mut original_size = 0u64;
+
+    let mut frame = vec![0u8; CHUNKED_FRAME_SIZE as usize];
+    let mut counter = 0u64;
+    loop {
+        let n = read_full(&mut reader, &mut frame)?;
+        let last = n < frame.len() || at_eof(&mut reader)?;
+        plain_hasher.update(&frame[..n]);
+        original_size += n as u64;
+
+        let aad = chunk_aad(counter, last);
+        let sealed = cipher
+            .encrypt(
+                &chunk_nonce(&base_nonce, counter).into(),
+                Payload { msg: &frame[..n], aad: &aad },
+            )
+            .map_err(|e| BurrowError::from(format!("Frame encryption failed: {e}")))?;
+        writer.write_all(&sealed)?;
+        enc_hasher.update(&sealed);
+        encrypted_size += sealed.len() as u64;
+
+        if last {
+            break;
+        }
+        counter += 1;
+    }
+    writer.flush()?;
+
+    Ok(ChunkedEncryptResult {
+        output_path,
+        original_hash_hex: hex::encode(plain_hasher.finalize()),
+        encrypted_hash_hex: hex::encode(enc_hasher.finalize()),
+        mime_type,
+        filename,
+        original_size,
+        encrypted_size,
+        nonce_hex: hex::encode(wrapped.nonce),
+        frame_size: CHUNKED_FRAME_SIZE,
+        scheme_version: CHUNKED_SCHEME_VERSION.to_string(),
+    })
+}
+
+/// Decrypt a chunked blob from `input_path` to `output_path`.
+///
+/// Output is written to a `.part` file and renamed only after every frame
+/// authenticates and the plaintext matches `original_hash_hex`.
+/// Returns the number of plaintext bytes written.
+#[frb]
+pub async fn decrypt_file_chunked(
+    mls_group_id_hex: String,
+    input_path: String,
+    output_path: String,
+    filename: String,
+    original_hash_hex: String,
+) -> Result<u64, BurrowError> {
+    use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+    use chacha20poly1305::ChaCha20Poly1305;
+    use std::io::{BufReader, BufWriter, Read, Write};
+
+    let mut reader = BufReader::new(std::fs::File::open(&input_path)?);
+
+    let mut fixed = [0u8; 4 + 4 + 12 + 32 + 12 + 2];
+    reader
+        .read_exact(&mut fixed)
+        .map_err(|_| BurrowError::from("Chunked blob header truncated".to_string()))?;
+    if &fixed[0..4] != CHUNKED_MAGIC {
+        return Err(BurrowError::from("Not a chunked media blob".to_string()));
+    }
+    let frame_size = u32::from_be_bytes(fixed[4..8].try_into().unwrap());
+    if frame_size == 0 || frame_size > 16 * CHUNKED_FRAME_SIZE {
+        return Err(BurrowError::from(format!("Invalid frame size {frame_size}")));
+    }
+    let base_nonce: [u8; 12] = fixed[8..20].try_into().unwrap();
+    let key_hash: [u8; 32] = fixed[20..52].try_into().unwrap();
+    let wrap_nonce: [u8; 12] = fixed[52..64].try_into().unwrap();
+    let wrapped_len = u16::from_be_bytes(fixed[64..66].try_into().unwrap()) as usize;
+    let mut wrapped_key = vec![0u8; wrapped_len];
+    reader
+        .read_exact(&mut wrapped_key)
+        .map_err(|_| BurrowError::from("Chunked blob header truncated".to_string()))?;
+
+    // Unwrap the content key with the group's MIP-04 v2 media key
+    let content_key = state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let reference = mdk_core::encrypted_media::MediaReference {
+            url: String::new(),
+            original_hash: key_hash,
+            mime_type: WRAPPED_KEY_MIME.to_string(),
+            filename: wrapped_key_filename(&filename),
+            dimensions: None,
+            scheme_version: "mip04-v2".to_string(),
+            nonce: wrap_nonce,
+        };
+        s.mdk
+            .media_manager(group_id)
+            .decrypt_from_download(&wrapped_key, &reference)
+            .map_err(|e| BurrowError::from(format!("Failed to unwrap media key: {e}")))
     })
+    .await?;
+
+    let cipher = ChaCha20Poly1305::new_from_slice(&content_key)
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+
+    let part_path = format!("{output_path}.part");
+    let result = (|| -> Result<(u64, String), BurrowError> {
+        let mut writer = BufWriter::new(std::fs::File::create(&part_path)?);
+        let mut hasher = Sha256::new();
+        let mut written = 0u64;
+        let mut frame = vec![0u8; frame_size as usize + CHUNKED_TAG_LEN];
+        let mut counter = 0u64;
+        loop {
+            let n = read_full(&mut reader, &mut frame)?;
+            if n < CHUNKED_TAG_LEN {
+                return Err(BurrowError::from("Chunked blob truncated".to_string()));
+            }
+            let last = n < frame.len() || at_eof(&mut reader)?;
+            let aad = chunk_aad(counter, last);
+            let plain = cipher
+                .decrypt(
+                    &chunk_nonce(&base_nonce, counter).into(),
+                    Payload { msg: &frame[..n], aad: &aad },
+                )
+                .map_err(|_| BurrowError::from(format!("Frame {counter} failed authentication")))?;
+            hasher.update(&plain);
+            writer.write_all(&plain)?;
+            written += plain.len() as u64;
+            if last {
+                break;
+            }
+            counter += 1;
+        }
+        writer.flush()?;
+        Ok((written, hex::encode(hasher.finalize())))
+    })();
+
+    match result {
+        Ok((written, actual_hash)) if actual_hash.eq_ignore_ascii_case(&original_hash_hex) => {
+            std::fs::rename(&part_path, &output_path)?;
+            Ok(written)
+        }
+        Ok((_, actual_hash)) => {
+            let _ = std::fs::remove_file(&part_path);
+            Err(BurrowError::from(format!(
+                "Decrypted file hash mismatch: expected {original_hash_hex}, got {actual_hash}"
+            )))
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&part_path);
+            Err(e)
+        }
+    }
+}
+
+/// Build an imeta tag for chunked media (same fields, `v mip04-v2-chunked`).
+#[frb]
+pub fn build_chunked_imeta_tag(
+    url: String,
+    mime_type: String,
+    filename: String,
+    original_hash_hex: String,
+    nonce_hex: String,
+) -> Result<Vec<String>, BurrowError> {
+    let mut values =
+        build_imeta_tag(url, mime_type, filename, original_hash_hex, nonce_hex, None, None, None)?;
+    for v in values.iter_mut() {
+        if v.starts_with("v ") {
+            *v = format!("v {}", CHUNKED_SCHEME_VERSION);
+        }
+    }
+    Ok(values)
+}
+
+fn wrapped_key_filename(filename: &str) -> String {
+    format!("{filename}.key")
+}
+
+fn chunk_nonce(base: &[u8; 12], counter: u64) -> [u8; 12] {
+    let mut nonce = *base;
+    for (b, c) in nonce[4..].iter_mut().zip(counter.to_be_bytes()) {
+        *b ^= c;
+    }
+    nonce
+}
+
+fn chunk_aad(counter: u64, last: bool) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(CHUNKED_AAD_DOMAIN.len() + 9);
+    aad.extend_from_slice(CHUNKED_AAD_DOMAIN);
+    aad.extend_from_slice(&counter.to_be_bytes());
+    aad.push(last as u8);
+    aad
+}
+
+/// Read until `buf` is full or EOF; returns bytes read.
+fn read_full<R: std::io::Read>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+fn at_eof<R: std::io::BufRead>(reader: &mut R) -> std::io::Result<bool> {
+    Ok(reader.fill_buf()?.is_empty())
+}
+
+// ---------------------------------------------------------------------------
+// Download cache
+// ---------------------------------------------------------------------------
+//
+// Decrypted attachments are kept under `<data_dir>/media_cache/<hash>`, keyed
+// by the original (plaintext) SHA-256 from the imeta `x` field. Entries are
+// re-hashed on every read so a corrupted or tampered file is never served.
+// Recency is tracked with the file mtime: hits touch the file and eviction
+// removes the least recently used entries until usage fits the limit.
+
+/// Default cache size limit (256 MiB).
+pub const DEFAULT_MEDIA_CACHE_LIMIT_BYTES: u64 = 256 * 1024 * 1024;
+
+static MEDIA_CACHE_LIMIT: AtomicU64 = AtomicU64::new(DEFAULT_MEDIA_CACHE_LIMIT_BYTES);
+
+/// Set the maximum on-disk size of the media cache. 0 disables caching.
+///
+/// Evicts least recently used entries right away if the cache is now over
+/// the limit.
+#[frb(sync)]
+pub fn set_media_cache_limit(bytes: u64) {
+    MEDIA_CACHE_LIMIT.store(bytes, Ordering::Relaxed);
+    if let Ok(dir) = media_cache_dir() {
+        let _ = media_cache_evict(&dir, bytes);
+    }
+}
+
+/// Get the maximum on-disk size of the media cache.
+#[frb(sync)]
+pub fn get_media_cache_limit() -> u64 {
+    MEDIA_CACHE_LIMIT.load(Ordering::Relaxed)
+}
+
+/// Current size of the media cache in bytes.
+#[frb]
+pub async fn get_media_cache_usage() -> Result<u64, BurrowError> {
+    media_cache_usage(&media_cache_dir()?)
+}
+
+/// Delete every cached attachment.
+#[frb]
+pub async fn clear_media_cache() -> Result<(), BurrowError> {
+    let dir = media_cache_dir()?;
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    Ok(())
+}
+
+fn media_cache_dir() -> Result<PathBuf, BurrowError> {
+    Ok(state::get_data_dir()?.join("media_cache"))
+}
+
+/// Encrypted files waiting for upload.
+fn media_tmp_dir() -> Result<PathBuf, BurrowError> {
+    Ok(state::get_data_dir()?.join("media_tmp"))
+}
+
+/// Fetch a blob and, when `expected_hash_hex` is known, verify it.
+async fn fetch_blob(
+    client: &reqwest::Client,
+    url: &str,
+    expected_hash_hex: Option<&str>,
+) -> Result<Vec<u8>, BurrowError> {
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| BurrowError::from(format!("Download failed for {}: {}", url, e)))?;
+
+    if !resp.status().is_success() {
+        return Err(BurrowError::from(format!(
+            "Download returned HTTP {}",
+            resp.status()
+        )));
+    }
+
+    let data = resp
+        .bytes()
+        .await
+        .map_err(|e| BurrowError::from(format!("Failed to read download body: {}", e)))?
+        .to_vec();
+    if let Some(expected) = expected_hash_hex {
+        verify_blob_hash(&data, expected)?;
+    }
+    Ok(data)
+}
+
+/// The SHA-256 a Blossom URL addresses: its last path segment, minus any
+/// file extension, if that is 64 hex characters.
+#[frb(ignore)]
+pub fn blossom_hash_from_url(url: &str) -> Option<String> {
+    let segment = url.split(['?', '#']).next()?.rsplit('/').next()?;
+    let hash = segment.split('.').next()?;
+    (hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit())).then(|| hash.to_ascii_lowercase())
+}
+
+/// `{mirror}/{hash}` for each mirror server, in order.
+#[frb(ignore)]
+pub fn mirror_urls(hash_hex: &str, mirror_servers: &[String]) -> Vec<String> {
+    mirror_servers
+        .iter()
+        .map(|server| server.trim().trim_end_matches('/'))
+        .filter(|server| !server.is_empty())
+        .map(|server| format!("{}/{}", server, hash_hex))
+        .collect()
+}
+
+/// Check downloaded bytes against the hash they were requested by
+/// (Blossom content addressing).
+#[frb(ignore)]
+pub fn verify_blob_hash(data: &[u8], expected_hash_hex: &str) -> Result<(), BurrowError> {
+    let actual_hash = hex::encode(Sha256::digest(data));
+    if !actual_hash.eq_ignore_ascii_case(expected_hash_hex) {
+        return Err(BurrowError::from(format!(
+            "Download integrity check failed: expected hash {}, got {}",
+            expected_hash_hex, actual_hash
+        )));
+    }
+    Ok(())
+}
+
+/// Cache entry path, or None if `hash_hex` isn't a SHA-256 hex digest.
+fn media_cache_path(dir: &Path, hash_hex: &str) -> Option<PathBuf> {
+    let valid = hash_hex.len() == 64 && hash_hex.bytes().all(|b| b.is_ascii_hexdigit());
+    valid.then(|| dir.join(hash_hex.to_ascii_lowercase()))
+}
+
+/// Look up a cached file and verify it still matches its hash.
+///
+/// Mismatching entries are deleted. A hit refreshes the entry's recency.
+#[frb(ignore)]
+pub fn media_cache_get(dir: &Path, hash_hex: &str) -> Option<Vec<u8>> {
+    let path = media_cache_path(dir, hash_hex)?;
+    let data = std::fs::read(&path).ok()?;
+    if !hex::encode(Sha256::digest(&data)).eq_ignore_ascii_case(hash_hex) {
+        let _ = std::fs::remove_file(&path);
+        return None;
+    }
+    if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+        let _ = file.set_modified(std::time::SystemTime::now());
+    }
+    Some(data)
+}
+
+/// Store a decrypted file and evict old entries to stay within `limit`.
+///
+/// Files larger than the whole limit are not cached.
+#[frb(ignore)]
+pub fn media_cache_put(dir: &Path, hash_hex: &str, data: &[u8], limit: u64) -> Result<(), BurrowError> {
+    let Some(path) = media_cache_path(dir, hash_hex) else {
+        return Ok(());
+    };
+    if data.len() as u64 > limit {
+        return Ok(());
+    }
+    std::fs::create_dir_all(dir)?;
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, &path)?;
+    media_cache_evict(dir, limit)
+}
+
+/// Total size of the cache entries in `dir`.
+#[frb(ignore)]
+pub fn media_cache_usage(dir: &Path) -> Result<u64, BurrowError> {
+    Ok(media_cache_entries(dir)?.iter().map(|(_, size, _)| size).sum())
+}
+
+/// Remove least recently used entries until the cache fits in `limit`.
+fn media_cache_evict(dir: &Path, limit: u64) -> Result<(), BurrowError> {
+    let mut entries = media_cache_entries(dir)?;
+    let mut usage: u64 = entries.iter().map(|(_, size, _)| size).sum();
+    entries.sort_by_key(|(_, _, modified)| *modified);
+    for (path, size, _) in entries {
+        if usage <= limit {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            usage -= size;
+        }
+    }
+    Ok(())
+}
+
+/// (path, size, mtime) of every file in the cache directory.
+fn media_cache_entries(dir: &Path) -> Result<Vec<(PathBuf, u64, std::time::SystemTime)>, BurrowError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+        if meta.is_file() {
+            let modified = meta.modified().unwrap_or(std::time::UNIX_EPOCH);
+            entries.push((entry.path(), meta.len(), modified));
+        }
+    }
+    Ok(entries)
 }
 
 // ---------------------------------------------------------------------------
@@ -3,9 +3,14 @@
 //! Processes transcripts to extract structured meeting insights.
 //! Supports both local LLM (Ollama) and cloud API (Claude) backends.
 
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use crate::api::state;
 use crate::api::transcription::TranscriptSegment;
 
 /// An extracted action item from a meeting.
@@ -25,6 +30,10 @@ pub struct ActionItem {
     pub priority: String,
     /// Whether this item has been completed.
     pub completed: bool,
+    /// Derived from a low-confidence transcript segment; ask the user to
+    /// confirm it.
+    #[serde(default)]
+    pub needs_review: bool,
 }
 
 /// A key decision recorded during the meeting.
@@ -36,6 +45,9 @@ pub struct Decision {
     pub proposed_by: String,
     /// Context: which discussion topic it relates to.
     pub context: String,
+    /// Derived from a low-confidence transcript segment.
+    #[serde(default)]
+    pub needs_review: bool,
 }
 
 /// Complete meeting notes generated from a transcript.
@@ -67,6 +79,16 @@ pub struct MeetingNotes {
     pub generated_at_ms: i64,
 }
 
+/// An open action item with the meeting it came from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionItemWithContext {
+    pub item: ActionItem,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    /// Meeting start time (Unix ms).
+    pub meeting_start_time_ms: i64,
+}
+
 /// Configuration for the AI backend.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AiBackend {
@@ -90,11 +112,58 @@ impl Default for AiBackend {
     }
 }
 
+/// Anthropic Messages API endpoint used by the Claude backend.
+const CLAUDE_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const CLAUDE_API_VERSION: &str = "2023-06-01";
+const CLAUDE_MAX_TOKENS: u32 = 4096;
+/// Local models can take a while on long transcripts.
+const AI_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Archive file under the data directory.
+const ARCHIVE_FILE: &str = "meeting_notes.json";
+
+/// Replacement text for redacted content.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Email addresses. Not redacted by default; add it to the patterns passed
+/// to `configure_redaction` to opt in.
+pub const EMAIL_REDACT_PATTERN: &str = r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b";
+
+/// Default redaction patterns: card-like numbers, Nostr secret keys and
+/// common API key / token shapes.
+pub fn default_redact_patterns() -> Vec<String> {
+    vec![
+        // 13-19 digits, optionally grouped with spaces or dashes.
+        r"\b\d(?:[ -]?\d){12,18}\b".to_string(),
+        r"\bnsec1[02-9ac-hj-np-z]{58}\b".to_string(),
+        r"\b(?:sk|pk|rk)[-_](?:live|test|ant|proj)?[-_]?[A-Za-z0-9_-]{16,}\b".to_string(),
+        r"\b(?:AKIA|ASIA)[A-Z0-9]{16}\b".to_string(),
+        r"\bgh[pousr]_[A-Za-z0-9]{36,}\b".to_string(),
+        r"\bxox[abprs]-[A-Za-z0-9-]{10,}\b".to_string(),
+        r"(?i)\b(?:password|passwd|passcode|pin)\s*(?:is|:|=)\s*\S+".to_string(),
+    ]
+}
+
+/// Segments below this confidence are kept but anything extracted from them
+/// is flagged `needs_review`.
+pub const DEFAULT_REVIEW_CONFIDENCE: f64 = 0.5;
+
 /// Meeting intelligence engine state.
 struct MeetingIntelligence {
     backend: AiBackend,
-    /// Archive of past meeting notes, keyed by meeting_id.
+    /// Segments below this confidence are dropped before generating notes.
+    min_confidence: f64,
+    /// Items extracted from segments below this confidence need review.
+    review_confidence: f64,
+    /// Compiled redaction patterns applied to every generated note.
+    redact_patterns: Vec<Regex>,
+    /// Archive of past meeting notes, keyed by meeting_id. Access it
+    /// through `archive()`, which loads it from disk first.
     archive: Vec<MeetingNotes>,
+    /// The file `archive` was loaded from; `None` until the data dir is set.
+    archive_loaded_from: Option<PathBuf>,
+    /// Live transcript segments per in-progress meeting.
+    live_segments: HashMap<String, Vec<TranscriptSegment>>,
 }
 
 static INTELLIGENCE: OnceLock<Arc<Mutex<MeetingIntelligence>>> = OnceLock::new();
@@ -103,11 +172,91 @@ fn intelligence() -> &'static Arc<Mutex<MeetingIntelligence>> {
     INTELLIGENCE.get_or_init(|| {
         Arc::new(Mutex::new(MeetingIntelligence {
             backend: AiBackend::default(),
+            min_confidence: 0.0,
+            review_confidence: DEFAULT_REVIEW_CONFIDENCE,
+            redact_patterns: compile_redact_patterns(&default_redact_patterns())
+                .unwrap_or_default(),
             archive: Vec::new(),
+            archive_loaded_from: None,
+            live_segments: HashMap::new(),
         }))
     })
 }
 
+impl MeetingIntelligence {
+    /// The archive, loaded from disk on first use after `set_data_dir`.
+    /// Notes generated before the data dir was known are merged into it.
+    fn archive(&mut self) -> &mut Vec<MeetingNotes> {
+        if let Some(path) = archive_path() {
+            if self.archive_loaded_from.as_ref() != Some(&path) {
+                let mut loaded = load_archive(&path);
+                let unsaved = self.archive_loaded_from.is_none() && !self.archive.is_empty();
+                if unsaved {
+                    for notes in self.archive.drain(..) {
+                        if !loaded.iter().any(|n| n.meeting_id == notes.meeting_id) {
+                            loaded.push(notes);
+                        }
+                    }
+                }
+                self.archive = loaded;
+                self.archive_loaded_from = Some(path);
+                if unsaved {
+                    if let Err(e) = self.save_archive() {
+                        eprintln!("[meeting_intelligence] {e}");
+                    }
+                }
+            }
+        }
+        &mut self.archive
+    }
+
+    /// Persist the archive after a mutation. Only the file the archive was
+    /// loaded from is written, so an archive that never saw the file on
+    /// disk can't overwrite it.
+    fn save_archive(&self) -> Result<(), String> {
+        match (&self.archive_loaded_from, archive_path()) {
+            (Some(loaded), Some(path)) if *loaded == path => write_archive(&path, &self.archive),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Where the archive is persisted, or `None` before `set_data_dir` (the
+/// archive is then kept in memory only).
+fn archive_path() -> Option<PathBuf> {
+    state::get_data_dir().ok().map(|dir| dir.join(ARCHIVE_FILE))
+}
+
+/// Load archived notes from `path`. A missing file is an empty archive; a
+/// corrupt one is moved aside so the next save doesn't overwrite it.
+fn load_archive(path: &Path) -> Vec<MeetingNotes> {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            eprintln!("[meeting_intelligence] Failed to read {}: {e}", path.display());
+            return Vec::new();
+        }
+    };
+    serde_json::from_slice(&data).unwrap_or_else(|e| {
+        eprintln!("[meeting_intelligence] Corrupt meeting archive, starting empty: {e}");
+        let _ = std::fs::rename(path, path.with_extension("json.corrupt"));
+        Vec::new()
+    })
+}
+
+/// Write the archive atomically (temp file + rename).
+fn write_archive(path: &Path, archive: &[MeetingNotes]) -> Result<(), String> {
+    let data = serde_json::to_vec(archive).map_err(|e| format!("Serialization error: {e}"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to save meeting archive: {e}"))?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, data)
+        .and_then(|_| std::fs::rename(&tmp, path))
+        .map_err(|e| format!("Failed to save meeting archive: {e}"))
+}
+
 /// Configure the AI backend for meeting intelligence.
 pub fn configure_ai_backend(backend_json: String) -> Result<(), String> {
     let backend: AiBackend =
@@ -117,11 +266,91 @@ pub fn configure_ai_backend(backend_json: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Set the regexes whose matches are replaced with `[REDACTED]` in
+/// generated notes. Takes a JSON array of patterns; `[]` disables
+/// redaction. Invalid patterns are rejected and the current set is kept.
+pub fn configure_redaction(redact_patterns_json: String) -> Result<(), String> {
+    let patterns: Vec<String> = serde_json::from_str(&redact_patterns_json)
+        .map_err(|e| format!("Invalid redaction patterns: {e}"))?;
+    let compiled = compile_redact_patterns(&patterns)?;
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    intel.redact_patterns = compiled;
+    Ok(())
+}
+
+/// Current redaction patterns as a JSON array.
+pub fn get_redact_patterns() -> Result<String, String> {
+    let intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let patterns: Vec<&str> = intel.redact_patterns.iter().map(|r| r.as_str()).collect();
+    serde_json::to_string(&patterns).map_err(|e| format!("Serialization error: {e}"))
+}
+
+/// Set how transcript confidence is used when generating notes.
+///
+/// Segments below `min_confidence` are dropped as ASR noise; items
+/// extracted from segments below `review_confidence` are kept but flagged
+/// `needs_review`. Both are 0.0-1.0; the defaults are 0.0 (keep all) and
+/// `DEFAULT_REVIEW_CONFIDENCE`.
+pub fn configure_confidence(min_confidence: f64, review_confidence: f64) -> Result<(), String> {
+    for value in [min_confidence, review_confidence] {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(format!("Confidence must be between 0.0 and 1.0, got {value}"));
+        }
+    }
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    intel.min_confidence = min_confidence;
+    intel.review_confidence = review_confidence;
+    Ok(())
+}
+
+fn compile_redact_patterns(patterns: &[String]) -> Result<Vec<Regex>, String> {
+    patterns
+        .iter()
+        .map(|p| Regex::new(p).map_err(|e| format!("Invalid redaction pattern {p:?}: {e}")))
+        .collect()
+}
+
+fn redact_text(text: &str, patterns: &[Regex]) -> String {
+    let mut out = text.to_string();
+    for pattern in patterns {
+        if pattern.is_match(&out) {
+            out = pattern.replace_all(&out, REDACTED).into_owned();
+        }
+    }
+    out
+}
+
+/// Redact every free-text field of `notes` in place: title, summary, key
+/// points, action items, decisions and open questions. Runs before notes
+/// are returned or archived, whichever backend produced them.
+fn redact_notes(notes: &mut MeetingNotes, patterns: &[Regex]) {
+    if patterns.is_empty() {
+        return;
+    }
+    notes.title = redact_text(&notes.title, patterns);
+    notes.summary = redact_text(&notes.summary, patterns);
+    for point in notes.key_points.iter_mut() {
+        *point = redact_text(point, patterns);
+    }
+    for item in notes.action_items.iter_mut() {
+        item.description = redact_text(&item.description, patterns);
+    }
+    for decision in notes.decisions.iter_mut() {
+        decision.description = redact_text(&decision.description, patterns);
+        decision.context = redact_text(&decision.context, patterns);
+    }
+    for question in notes.open_questions.iter_mut() {
+        *question = redact_text(question, patterns);
+    }
+}
+
 /// Generate meeting notes from a transcript.
 ///
 /// This is the main entry point after a call ends. It processes the full
-/// transcript and produces structured meeting notes.
-pub fn generate_meeting_notes(
+/// transcript and produces structured meeting notes. With an Ollama or
+/// Claude backend the transcript is sent to the model; if the request fails
+/// or its reply can't be parsed, the rule-based notes are used instead.
+pub async fn generate_meeting_notes(
     meeting_id: String,
     segments_json: String,
     participants_json: String,
@@ -133,42 +362,379 @@ pub fn generate_meeting_notes(
     let participants: Vec<String> =
         serde_json::from_str(&participants_json).map_err(|e| format!("Invalid participants: {e}"))?;
 
+    // Don't hold the lock across the HTTP call.
+    let (backend, min_confidence, review_confidence) = {
+        let intel = intelligence().lock().map_err(|e| e.to_string())?;
+        (intel.backend.clone(), intel.min_confidence, intel.review_confidence)
+    };
+    let segments = confident_segments(segments, min_confidence);
+
+    let response = match &backend {
+        AiBackend::RuleBased => None,
+        AiBackend::Ollama { .. } | AiBackend::Claude { .. } => {
+            let prompt = build_meeting_notes_prompt(transcript_for_prompt(&segments, review_confidence))?;
+            Some(request_ai_notes(&backend, &prompt).await)
+        }
+    };
+    let mut notes = match response {
+        None => generate_rule_based_notes(
+            &meeting_id,
+            &segments,
+            &participants,
+            start_time_ms,
+            end_time_ms,
+            review_confidence,
+        ),
+        Some(response) => notes_from_ai_response(
+            response,
+            &meeting_id,
+            &segments,
+            &participants,
+            start_time_ms,
+            end_time_ms,
+            review_confidence,
+        ),
+    };
+
     let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    redact_notes(&mut notes, &intel.redact_patterns);
+    intel.archive().push(notes.clone());
+    // The meeting is complete — its live buffer is no longer needed.
+    intel.live_segments.remove(&meeting_id);
+    intel.save_archive()?;
+    serde_json::to_string(&notes).map_err(|e| format!("Serialization error: {e}"))
+}
+
+/// Notes parsed from an LLM reply, or the rule-based notes (with the reason
+/// logged) if the request failed or the reply isn't the expected JSON.
+fn notes_from_ai_response(
+    response: Result<String, String>,
+    meeting_id: &str,
+    segments: &[TranscriptSegment],
+    participants: &[String],
+    start_time_ms: i64,
+    end_time_ms: i64,
+    review_confidence: f64,
+) -> MeetingNotes {
+    let parsed = response.and_then(|text| {
+        parse_ai_notes(&text, meeting_id, segments, participants, start_time_ms, end_time_ms)
+    });
+    match parsed {
+        Ok(notes) => notes,
+        Err(e) => {
+            eprintln!("[meeting_intelligence] AI notes unavailable, using rule-based notes: {e}");
+            generate_rule_based_notes(
+                meeting_id,
+                segments,
+                participants,
+                start_time_ms,
+                end_time_ms,
+                review_confidence,
+            )
+        }
+    }
+}
 
-    let notes = match &intel.backend {
-        AiBackend::RuleBased => {
-            generate_rule_based_notes(&meeting_id, &segments, &participants, start_time_ms, end_time_ms)
+/// Send the prompt to the configured LLM and return its text reply.
+async fn request_ai_notes(backend: &AiBackend, prompt: &str) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .timeout(AI_REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP client error: {e}"))?;
+    match backend {
+        AiBackend::Ollama { model, endpoint } => {
+            let url = format!("{}/api/generate", endpoint.trim_end_matches('/'));
+            let resp = client
+                .post(&url)
+                .json(&serde_json::json!({
+                    "model": model,
+                    "prompt": prompt,
+                    "stream": false,
+                    "format": "json",
+                }))
+                .send()
+                .await
+                .map_err(|e| format!("Ollama request failed: {e}"))?;
+            let body = ai_response_json(resp, "Ollama").await?;
+            body.get("response")
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| "Ollama reply has no response text".to_string())
         }
-        AiBackend::Ollama { .. } | AiBackend::Claude { .. } => {
-            // For LLM backends, build the prompt and call the API.
-            // In production, this would make HTTP calls to Ollama or Claude.
-            // Fall back to rule-based for now.
-            generate_rule_based_notes(&meeting_id, &segments, &participants, start_time_ms, end_time_ms)
+        AiBackend::Claude { api_key, model } => {
+            let resp = client
+                .post(CLAUDE_MESSAGES_URL)
+                .header("x-api-key", api_key)
+                .header("anthropic-version", CLAUDE_API_VERSION)
+                .json(&serde_json::json!({
+                    "model": model,
+                    "max_tokens": CLAUDE_MAX_TOKENS,
+                    "messages": [{ "role": "user", "content": prompt }],
+                }))
+                .send()
+                .await
+                .map_err(|e| format!("Claude request failed: {e}"))?;
+            let body = ai_response_json(resp, "Claude").await?;
+            let text: String = body
+                .get("content")
+                .and_then(|c| c.as_array())
+                .map(|blocks| {
+                    blocks
+                        .iter()
+                        .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+                        .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            if text.is_empty() {
+                return Err("Claude reply has no text content".to_string());
+            }
+            Ok(text)
         }
+        AiBackend::RuleBased => Err("No AI backend configured".to_string()),
+    }
+}
+
+async fn ai_response_json(resp: reqwest::Response, backend: &str) -> Result<serde_json::Value, String> {
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("{backend} returned HTTP {status}: {body}"));
+    }
+    resp.json()
+        .await
+        .map_err(|e| format!("Invalid {backend} response: {e}"))
+}
+
+/// The JSON structure requested by `build_meeting_notes_prompt`.
+#[derive(Deserialize)]
+struct AiNotes {
+    title: String,
+    summary: String,
+    #[serde(default)]
+    key_points: Vec<String>,
+    #[serde(default)]
+    action_items: Vec<AiActionItem>,
+    #[serde(default)]
+    decisions: Vec<AiDecision>,
+    #[serde(default)]
+    open_questions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct AiActionItem {
+    #[serde(default)]
+    assignee_name: String,
+    description: String,
+    #[serde(default)]
+    deadline: String,
+    #[serde(default)]
+    priority: String,
+    #[serde(default)]
+    needs_review: bool,
+}
+
+#[derive(Deserialize)]
+struct AiDecision {
+    description: String,
+    #[serde(default)]
+    proposed_by: String,
+    #[serde(default)]
+    context: String,
+    #[serde(default)]
+    needs_review: bool,
+}
+
+/// Parse an LLM reply into meeting notes.
+///
+/// Models often wrap JSON in a code fence or a sentence, so the outermost
+/// `{...}` is parsed. Assignees are matched to transcript speakers by name
+/// to fill in their pubkey.
+fn parse_ai_notes(
+    text: &str,
+    meeting_id: &str,
+    segments: &[TranscriptSegment],
+    participants: &[String],
+    start_time_ms: i64,
+    end_time_ms: i64,
+) -> Result<MeetingNotes, String> {
+    let json = match (text.find('{'), text.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => return Err("AI reply contains no JSON object".to_string()),
     };
+    let ai: AiNotes =
+        serde_json::from_str(json).map_err(|e| format!("Invalid AI notes JSON: {e}"))?;
+
+    let action_items = ai
+        .action_items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let assignee_pubkey = segments
+                .iter()
+                .find(|s| !item.assignee_name.is_empty() && s.speaker_name.eq_ignore_ascii_case(&item.assignee_name))
+                .map(|s| s.speaker_id.clone())
+                .unwrap_or_default();
+            let priority = match item.priority.to_lowercase().as_str() {
+                p @ ("high" | "medium" | "low") => p.to_string(),
+                _ => "medium".to_string(),
+            };
+            ActionItem {
+                id: format!("ai_{}", i + 1),
+                assignee_pubkey,
+                assignee_name: item.assignee_name,
+                description: item.description,
+                deadline: item.deadline,
+                priority,
+                completed: false,
+                needs_review: item.needs_review,
+            }
+        })
+        .collect();
+
+    Ok(MeetingNotes {
+        meeting_id: meeting_id.to_string(),
+        title: ai.title,
+        summary: ai.summary,
+        key_points: ai.key_points,
+        action_items,
+        decisions: ai
+            .decisions
+            .into_iter()
+            .map(|d| Decision {
+                description: d.description,
+                proposed_by: d.proposed_by,
+                context: d.context,
+                needs_review: d.needs_review,
+            })
+            .collect(),
+        open_questions: ai.open_questions,
+        participants: participants.to_vec(),
+        start_time_ms,
+        end_time_ms,
+        duration_seconds: (end_time_ms - start_time_ms) / 1000,
+        generated_at_ms: chrono::Utc::now().timestamp_millis(),
+    })
+}
 
-    intel.archive.push(notes.clone());
+/// Buffer a single transcript segment for a live meeting.
+///
+/// Segments are upserted by ID, so an interim result is replaced by its
+/// final version. Returns the number of buffered segments for the meeting.
+pub fn push_transcript_segment(meeting_id: String, segment_json: String) -> Result<u32, String> {
+    let segment: TranscriptSegment =
+        serde_json::from_str(&segment_json).map_err(|e| format!("Invalid segment: {e}"))?;
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let buffer = intel.live_segments.entry(meeting_id).or_default();
+    match buffer.iter_mut().find(|s| s.id == segment.id) {
+        Some(existing) => *existing = segment,
+        None => buffer.push(segment),
+    }
+    Ok(buffer.len() as u32)
+}
+
+/// Generate notes from the segments buffered so far for a live meeting.
+///
+/// Used by the live-notes view to refresh incrementally; the result is
+/// not archived and the buffer is kept. Call `finalize_live_meeting` when
+/// the call ends.
+pub fn generate_live_meeting_notes(
+    meeting_id: String,
+    participants_json: String,
+) -> Result<String, String> {
+    let participants: Vec<String> =
+        serde_json::from_str(&participants_json).map_err(|e| format!("Invalid participants: {e}"))?;
+    let intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let segments = confident_segments(sorted_live_segments(&intel, &meeting_id)?, intel.min_confidence);
+    let (start_ms, end_ms) = segment_bounds(&segments);
+    let mut notes = generate_rule_based_notes(
+        &meeting_id,
+        &segments,
+        &participants,
+        start_ms,
+        end_ms,
+        intel.review_confidence,
+    );
+    redact_notes(&mut notes, &intel.redact_patterns);
     serde_json::to_string(&notes).map_err(|e| format!("Serialization error: {e}"))
 }
 
+/// Generate final notes from a live meeting's buffer, archive them and
+/// evict the buffer.
+pub async fn finalize_live_meeting(
+    meeting_id: String,
+    participants_json: String,
+    start_time_ms: i64,
+    end_time_ms: i64,
+) -> Result<String, String> {
+    let segments = {
+        let intel = intelligence().lock().map_err(|e| e.to_string())?;
+        sorted_live_segments(&intel, &meeting_id)?
+    };
+    let segments_json =
+        serde_json::to_string(&segments).map_err(|e| format!("Serialization error: {e}"))?;
+    generate_meeting_notes(meeting_id, segments_json, participants_json, start_time_ms, end_time_ms).await
+}
+
+/// Drop a meeting's live buffer without generating notes (e.g. call
+/// cancelled). Returns whether a buffer existed.
+pub fn clear_transcript_buffer(meeting_id: String) -> Result<bool, String> {
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    Ok(intel.live_segments.remove(&meeting_id).is_some())
+}
+
+fn sorted_live_segments(
+    intel: &MeetingIntelligence,
+    meeting_id: &str,
+) -> Result<Vec<TranscriptSegment>, String> {
+    let mut segments = intel
+        .live_segments
+        .get(meeting_id)
+        .cloned()
+        .ok_or_else(|| format!("No live transcript for meeting: {meeting_id}"))?;
+    segments.sort_by_key(|s| s.start_ms);
+    Ok(segments)
+}
+
+fn confident_segments(segments: Vec<TranscriptSegment>, min_confidence: f64) -> Vec<TranscriptSegment> {
+    segments
+        .into_iter()
+        .filter(|s| s.confidence >= min_confidence)
+        .collect()
+}
+
+fn segment_bounds(segments: &[TranscriptSegment]) -> (i64, i64) {
+    let start = segments.iter().map(|s| s.start_ms).min().unwrap_or(0);
+    let end = segments.iter().map(|s| s.end_ms).max().unwrap_or(start);
+    (start, end)
+}
+
 /// Rule-based meeting notes generation (no LLM required).
 ///
 /// Extracts action items by keyword detection, generates a basic summary,
-/// and identifies discussion topics by speaker transitions.
+/// and identifies discussion topics by speaker transitions. Action items
+/// and decisions from segments below `review_confidence` need review, and
+/// those segments don't pick the title.
 fn generate_rule_based_notes(
     meeting_id: &str,
     segments: &[TranscriptSegment],
     participants: &[String],
     start_time_ms: i64,
     end_time_ms: i64,
+    review_confidence: f64,
 ) -> MeetingNotes {
-    let full_text: String = segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>().join(" ");
+    let full_text: String = segments
+        .iter()
+        .filter(|s| s.confidence >= review_confidence)
+        .map(|s| s.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
 
     // Extract action items from keyword patterns.
-    let action_items = extract_action_items_rule_based(segments);
+    let action_items = extract_action_items_rule_based(segments, start_time_ms, review_confidence);
 
     // Extract decisions.
-    let decisions = extract_decisions_rule_based(segments);
+    let decisions = extract_decisions_rule_based(segments, review_confidence);
 
     // Extract open questions.
     let open_questions = extract_questions_rule_based(segments);
@@ -205,8 +771,13 @@ fn generate_rule_based_notes(
     }
 }
 
-/// Extract action items using keyword patterns.
-fn extract_action_items_rule_based(segments: &[TranscriptSegment]) -> Vec<ActionItem> {
+/// Extract action items using keyword patterns. Deadlines are resolved
+/// relative to the meeting start.
+fn extract_action_items_rule_based(
+    segments: &[TranscriptSegment],
+    start_time_ms: i64,
+    review_confidence: f64,
+) -> Vec<ActionItem> {
     let action_keywords = [
         "action item",
         "todo",
@@ -246,17 +817,144 @@ fn extract_action_items_rule_based(segments: &[TranscriptSegment]) -> Vec<Action
                 assignee_pubkey: seg.speaker_id.clone(),
                 assignee_name: seg.speaker_name.clone(),
                 description: seg.text.clone(),
-                deadline: String::new(),
+                deadline: parse_deadline(&lower, start_time_ms)
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
                 priority: priority.to_string(),
                 completed: false,
+                needs_review: seg.confidence < review_confidence,
             });
         }
     }
-    items
+    dedupe_action_items(items)
+}
+
+/// Resolve a relative deadline phrase in lowercased `text` to a date, using
+/// the meeting start (UTC) as "today".
+///
+/// Understands "the 15th" (this month, or next if already past), weekday
+/// names (the next such day, a week out if it is today), "today",
+/// "tomorrow", "end of the week" (Friday), "end of the month" and
+/// "next week" (its Monday).
+fn parse_deadline(text: &str, start_time_ms: i64) -> Option<chrono::NaiveDate> {
+    use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+    static DAY_OF_MONTH: OnceLock<Regex> = OnceLock::new();
+    let today = chrono::DateTime::from_timestamp_millis(start_time_ms)?.date_naive();
+
+    let day_of_month = DAY_OF_MONTH.get_or_init(|| Regex::new(r"\bthe (\d{1,2})(?:st|nd|rd|th)\b").unwrap());
+    if let Some(day) = day_of_month
+        .captures(text)
+        .and_then(|c| c[1].parse::<u32>().ok())
+    {
+        let this_month = NaiveDate::from_ymd_opt(today.year(), today.month(), day);
+        if let Some(date) = this_month.filter(|d| *d >= today) {
+            return Some(date);
+        }
+        let (year, month) = if today.month() == 12 { (today.year() + 1, 1) } else { (today.year(), today.month() + 1) };
+        return NaiveDate::from_ymd_opt(year, month, day);
+    }
+
+    let weekdays = [
+        ("monday", Weekday::Mon),
+        ("tuesday", Weekday::Tue),
+        ("wednesday", Weekday::Wed),
+        ("thursday", Weekday::Thu),
+        ("friday", Weekday::Fri),
+        ("saturday", Weekday::Sat),
+        ("sunday", Weekday::Sun),
+    ];
+    let words: Vec<&str> = text.split(|c: char| !c.is_alphanumeric()).collect();
+    if let Some((_, weekday)) = weekdays.iter().find(|(name, _)| words.contains(name)) {
+        let ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        let ahead = if ahead == 0 { 7 } else { ahead };
+        return Some(today + Duration::days(ahead as i64));
+    }
+
+    if words.contains(&"tomorrow") {
+        return Some(today + Duration::days(1));
+    }
+    if words.contains(&"today") {
+        return Some(today);
+    }
+    if text.contains("end of the week") || text.contains("end of week") {
+        let ahead = (Weekday::Fri.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        return Some(today + Duration::days(ahead as i64));
+    }
+    if text.contains("end of the month") || text.contains("end of month") {
+        let (year, month) = if today.month() == 12 { (today.year() + 1, 1) } else { (today.year(), today.month() + 1) };
+        return NaiveDate::from_ymd_opt(year, month, 1).map(|d| d - Duration::days(1));
+    }
+    if text.contains("next week") {
+        let ahead = 7 - today.weekday().num_days_from_monday();
+        return Some(today + Duration::days(ahead as i64));
+    }
+    None
+}
+
+/// Word-set similarity above which two items from the same assignee are
+/// considered the same action.
+const DUPLICATE_ITEM_SIMILARITY: f64 = 0.8;
+
+/// Merge near-identical items from the same assignee (e.g. a phrase
+/// repeated across segments, or "I'll" vs "I will"), keeping the longer
+/// description. The merged item needs review only if both did.
+fn dedupe_action_items(items: Vec<ActionItem>) -> Vec<ActionItem> {
+    let mut kept: Vec<(ActionItem, std::collections::HashSet<String>)> = Vec::new();
+    for item in items {
+        let words = normalized_words(&item.description);
+        let duplicate = kept.iter_mut().find(|(k, k_words)| {
+            k.assignee_pubkey == item.assignee_pubkey
+                && jaccard(k_words, &words) > DUPLICATE_ITEM_SIMILARITY
+        });
+        match duplicate {
+            Some((k, k_words)) => {
+                let needs_review = k.needs_review && item.needs_review;
+                if item.description.len() > k.description.len() {
+                    *k = ActionItem { id: k.id.clone(), ..item };
+                    *k_words = words;
+                }
+                k.needs_review = needs_review;
+            }
+            None => kept.push((item, words)),
+        }
+    }
+    kept.into_iter()
+        .enumerate()
+        .map(|(i, (item, _))| ActionItem { id: format!("ai_{}", i + 1), ..item })
+        .collect()
+}
+
+/// Lowercased words with common contractions expanded.
+fn normalized_words(text: &str) -> std::collections::HashSet<String> {
+    let lower = text.to_lowercase().replace('’', "'");
+    let expanded = lower
+        .replace("n't", " not")
+        .replace("'ll", " will")
+        .replace("'re", " are")
+        .replace("'ve", " have")
+        .replace("'m", " am")
+        .replace("'d", " would");
+    expanded
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &std::collections::HashSet<String>, b: &std::collections::HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
 }
 
 /// Extract decisions using keyword patterns.
-fn extract_decisions_rule_based(segments: &[TranscriptSegment]) -> Vec<Decision> {
+fn extract_decisions_rule_based(
+    segments: &[TranscriptSegment],
+    review_confidence: f64,
+) -> Vec<Decision> {
     let decision_keywords = [
         "decided",
         "agreed",
@@ -276,6 +974,7 @@ fn extract_decisions_rule_based(segments: &[TranscriptSegment]) -> Vec<Decision>
                 description: seg.text.clone(),
                 proposed_by: seg.speaker_name.clone(),
                 context: String::new(),
+                needs_review: seg.confidence < review_confidence,
             });
         }
     }
@@ -410,36 +1109,64 @@ Produce a JSON response with this exact structure:
       "assignee_name": "Person name",
       "description": "What needs to be done",
       "deadline": "YYYY-MM-DD or empty string",
-      "priority": "high|medium|low"
+      "priority": "high|medium|low",
+      "needs_review": false
     }}
   ],
   "decisions": [
     {{
       "description": "What was decided",
       "proposed_by": "Person name",
-      "context": "Related discussion topic"
+      "context": "Related discussion topic",
+      "needs_review": false
     }}
   ],
   "open_questions": ["question 1", "question 2", ...]
 }}
 
-Be concise but thorough. Extract ALL action items mentioned. Identify who is responsible."#,
+Be concise but thorough. Extract ALL action items mentioned. Identify who is responsible.
+Lines marked [uncertain] were transcribed with low confidence: set "needs_review" to true for anything based on them."#,
         transcript_text
     ))
 }
 
+/// Build the LLM prompt from transcript segments, applying the configured
+/// confidence thresholds: low-confidence segments are dropped, uncertain
+/// ones are marked so the model flags what it derives from them.
+pub fn build_meeting_notes_prompt_from_segments(segments_json: String) -> Result<String, String> {
+    let segments: Vec<TranscriptSegment> =
+        serde_json::from_str(&segments_json).map_err(|e| format!("Invalid segments: {e}"))?;
+    let (min_confidence, review_confidence) = {
+        let intel = intelligence().lock().map_err(|e| e.to_string())?;
+        (intel.min_confidence, intel.review_confidence)
+    };
+    let segments = confident_segments(segments, min_confidence);
+    build_meeting_notes_prompt(transcript_for_prompt(&segments, review_confidence))
+}
+
+/// One `speaker: text` line per segment, marking uncertain ones.
+fn transcript_for_prompt(segments: &[TranscriptSegment], review_confidence: f64) -> String {
+    segments
+        .iter()
+        .map(|s| {
+            let marker = if s.confidence < review_confidence { "[uncertain] " } else { "" };
+            format!("{}{}: {}\n", marker, s.speaker_name, s.text)
+        })
+        .collect()
+}
+
 /// Get all archived meeting notes.
 pub fn get_meeting_archive() -> Result<String, String> {
-    let intel = intelligence().lock().map_err(|e| e.to_string())?;
-    serde_json::to_string(&intel.archive).map_err(|e| format!("Serialization error: {e}"))
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    serde_json::to_string(intel.archive()).map_err(|e| format!("Serialization error: {e}"))
 }
 
 /// Search meeting notes archive by query.
 pub fn search_meetings(query: String) -> Result<String, String> {
-    let intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
     let query_lower = query.to_lowercase();
     let results: Vec<&MeetingNotes> = intel
-        .archive
+        .archive()
         .iter()
         .filter(|n| {
             n.title.to_lowercase().contains(&query_lower)
@@ -454,24 +1181,81 @@ pub fn search_meetings(query: String) -> Result<String, String> {
 /// Toggle action item completion status.
 pub fn toggle_action_item(meeting_id: String, action_item_id: String) -> Result<bool, String> {
     let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
-    for notes in intel.archive.iter_mut() {
-        if notes.meeting_id == meeting_id {
-            for item in notes.action_items.iter_mut() {
-                if item.id == action_item_id {
-                    item.completed = !item.completed;
-                    return Ok(item.completed);
-                }
-            }
-        }
+    let completed = intel
+        .archive()
+        .iter_mut()
+        .filter(|notes| notes.meeting_id == meeting_id)
+        .flat_map(|notes| notes.action_items.iter_mut())
+        .find(|item| item.id == action_item_id)
+        .map(|item| {
+            item.completed = !item.completed;
+            item.completed
+        })
+        .ok_or_else(|| "Action item not found".to_string())?;
+    intel.save_archive()?;
+    Ok(completed)
+}
+
+/// Delete a meeting's notes from the archive, in memory and on disk.
+/// Returns whether the meeting existed.
+pub fn delete_meeting_notes(meeting_id: String) -> Result<bool, String> {
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let archive = intel.archive();
+    let before = archive.len();
+    archive.retain(|n| n.meeting_id != meeting_id);
+    if archive.len() == before {
+        return Ok(false);
+    }
+    intel.save_archive()?;
+    Ok(true)
+}
+
+/// Get all incomplete action items across the archive as a JSON array of
+/// `ActionItemWithContext`, optionally only those assigned to `assignee_pubkey`.
+///
+/// Sorted by deadline (items without one last), then priority high → low.
+pub fn get_open_action_items(assignee_pubkey: Option<String>) -> Result<String, String> {
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let mut items: Vec<ActionItemWithContext> = intel
+        .archive()
+        .iter()
+        .flat_map(|notes| {
+            notes.action_items.iter().map(move |item| ActionItemWithContext {
+                item: item.clone(),
+                meeting_id: notes.meeting_id.clone(),
+                meeting_title: notes.title.clone(),
+                meeting_start_time_ms: notes.start_time_ms,
+            })
+        })
+        .filter(|a| !a.item.completed)
+        .filter(|a| {
+            assignee_pubkey
+                .as_ref()
+                .map_or(true, |pk| &a.item.assignee_pubkey == pk)
+        })
+        .collect();
+
+    items.sort_by(|a, b| {
+        (a.item.deadline.is_empty(), &a.item.deadline, priority_rank(&a.item.priority))
+            .cmp(&(b.item.deadline.is_empty(), &b.item.deadline, priority_rank(&b.item.priority)))
+    });
+    serde_json::to_string(&items).map_err(|e| format!("Serialization error: {e}"))
+}
+
+fn priority_rank(priority: &str) -> u8 {
+    match priority {
+        "high" => 0,
+        "medium" => 1,
+        "low" => 2,
+        _ => 3,
     }
-    Err("Action item not found".to_string())
 }
 
 /// Get meeting notes by ID.
 pub fn get_meeting_notes(meeting_id: String) -> Result<String, String> {
-    let intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
     let notes = intel
-        .archive
+        .archive()
         .iter()
         .find(|n| n.meeting_id == meeting_id)
         .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
@@ -480,9 +1264,9 @@ pub fn get_meeting_notes(meeting_id: String) -> Result<String, String> {
 
 /// Export meeting notes as markdown.
 pub fn export_meeting_markdown(meeting_id: String) -> Result<String, String> {
-    let intel = intelligence().lock().map_err(|e| e.to_string())?;
+    let mut intel = intelligence().lock().map_err(|e| e.to_string())?;
     let notes = intel
-        .archive
+        .archive()
         .iter()
         .find(|n| n.meeting_id == meeting_id)
         .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
@@ -507,9 +1291,10 @@ pub fn export_meeting_markdown(meeting_id: String) -> Result<String, String> {
         md.push_str("## Action Items\n\n");
         for item in &notes.action_items {
             let check = if item.completed { "x" } else { " " };
+            let review = if item.needs_review { " ⚠️ needs review" } else { "" };
             md.push_str(&format!(
-                "- [{}] **{}** — {} (Priority: {})\n",
-                check, item.assignee_name, item.description, item.priority
+                "- [{}] **{}** — {} (Priority: {}){}\n",
+                check, item.assignee_name, item.description, item.priority, review
             ));
         }
         md.push_str("\n");
@@ -518,7 +1303,11 @@ pub fn export_meeting_markdown(meeting_id: String) -> Result<String, String> {
     if !notes.decisions.is_empty() {
         md.push_str("## Decisions\n\n");
         for dec in &notes.decisions {
-            md.push_str(&format!("- {} (proposed by {})\n", dec.description, dec.proposed_by));
+            let review = if dec.needs_review { " ⚠️ needs review" } else { "" };
+            md.push_str(&format!(
+                "- {} (proposed by {}){}\n",
+                dec.description, dec.proposed_by, review
+            ));
         }
         md.push_str("\n");
     }
@@ -558,23 +1347,86 @@ mod tests {
             make_segment("Bob", "Sounds good, the weather is nice", 3000),
             make_segment("Alice", "This is urgent, I'll fix the bug ASAP", 6000),
         ];
-        let items = extract_action_items_rule_based(&segments);
+        let items = extract_action_items_rule_based(&segments, 0, 0.0);
         assert_eq!(items.len(), 2);
         assert_eq!(items[0].assignee_name, "Alice");
         assert_eq!(items[1].priority, "high"); // "urgent" + "ASAP"
     }
 
+    #[test]
+    fn test_duplicate_action_items_collapse() {
+        let segments = vec![
+            make_segment("Alice", "I need to, I need to review the doc", 0),
+            make_segment("Alice", "I need to review the doc", 3000),
+            make_segment("Bob", "I'll do it", 6000),
+            make_segment("Bob", "I will do it", 9000),
+            make_segment("Alice", "I'll do it", 12000),
+        ];
+        let items = extract_action_items_rule_based(&segments, 0, 0.0);
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].description, "I need to, I need to review the doc");
+        assert_eq!(items[1].assignee_name, "Bob");
+        assert_eq!(items[2].assignee_name, "Alice");
+        assert_eq!(items[2].id, "ai_3");
+    }
+
+    /// Wednesday 2025-01-15, 10:00 UTC.
+    const WEDNESDAY_MS: i64 = 1_736_935_200_000;
+
+    fn deadline(text: &str) -> Option<String> {
+        parse_deadline(text, WEDNESDAY_MS).map(|d| d.format("%Y-%m-%d").to_string())
+    }
+
+    #[test]
+    fn test_deadline_weekdays() {
+        assert_eq!(deadline("i'll send it by friday").as_deref(), Some("2025-01-17"));
+        assert_eq!(deadline("need to finish by monday").as_deref(), Some("2025-01-20"));
+        // Naming today's weekday means next week's.
+        assert_eq!(deadline("due wednesday").as_deref(), Some("2025-01-22"));
+        assert_eq!(deadline("i'll do it tomorrow").as_deref(), Some("2025-01-16"));
+    }
+
+    #[test]
+    fn test_deadline_next_week_and_dates() {
+        assert_eq!(deadline("by next week").as_deref(), Some("2025-01-20"));
+        assert_eq!(deadline("by end of the week").as_deref(), Some("2025-01-17"));
+        assert_eq!(deadline("by end of the month").as_deref(), Some("2025-01-31"));
+        assert_eq!(deadline("by the 20th").as_deref(), Some("2025-01-20"));
+        assert_eq!(deadline("by the 1st").as_deref(), Some("2025-02-01"));
+        assert_eq!(deadline("i need to review the doc"), None);
+    }
+
+    #[test]
+    fn test_action_item_deadline() {
+        let segments = vec![make_segment("Alice", "I need to review the design doc by Friday", 0)];
+        let items = extract_action_items_rule_based(&segments, WEDNESDAY_MS, 0.0);
+        assert_eq!(items[0].deadline, "2025-01-17");
+    }
+
     #[test]
     fn test_decision_extraction() {
         let segments = vec![
             make_segment("Alice", "Let's go with option B for the architecture", 0),
             make_segment("Bob", "I think we should discuss more", 3000),
         ];
-        let decisions = extract_decisions_rule_based(&segments);
+        let decisions = extract_decisions_rule_based(&segments, 0.0);
         assert_eq!(decisions.len(), 1);
         assert!(decisions[0].description.contains("option B"));
     }
 
+    #[test]
+    fn test_low_confidence_items_need_review() {
+        let mut shaky = make_segment("Bob", "I need to call the vendor", 3000);
+        shaky.confidence = 0.2;
+        let segments = vec![make_segment("Alice", "We agreed to ship on Monday", 0), shaky];
+        let items = extract_action_items_rule_based(&segments, 0, DEFAULT_REVIEW_CONFIDENCE);
+        assert_eq!(items.len(), 1);
+        assert!(items[0].needs_review);
+        let decisions = extract_decisions_rule_based(&segments, DEFAULT_REVIEW_CONFIDENCE);
+        assert_eq!(decisions.len(), 1);
+        assert!(!decisions[0].needs_review);
+    }
+
     #[test]
     fn test_question_extraction() {
         let segments = vec![
@@ -586,6 +1438,78 @@ mod tests {
         assert!(questions[0].contains("timeline"));
     }
 
+    #[test]
+    fn test_parse_ai_notes() {
+        let segments = vec![make_segment("Alice", "I'll send the report", 0)];
+        let reply = r#"Here are the notes:
+```json
+{"title": "Report sync", "summary": "Short call.", "key_points": ["Report"],
+ "action_items": [{"assignee_name": "alice", "description": "Send the report", "deadline": "", "priority": "HIGH"}],
+ "decisions": [{"description": "Ship Friday"}], "open_questions": []}
+```"#;
+        let notes = parse_ai_notes(reply, "ai-meeting", &segments, &[], 0, 60_000).unwrap();
+        assert_eq!(notes.title, "Report sync");
+        assert_eq!(notes.duration_seconds, 60);
+        assert_eq!(notes.action_items[0].assignee_pubkey, "Alice");
+        assert_eq!(notes.action_items[0].priority, "high");
+        assert_eq!(notes.decisions[0].description, "Ship Friday");
+    }
+
+    #[test]
+    fn test_unparseable_ai_reply_falls_back_to_rule_based() {
+        let segments = vec![
+            make_segment("Alice", "I need to review the design doc by Friday", 0),
+            make_segment("Bob", "Agreed, let's go with the Rust approach", 3000),
+        ];
+        let rule_based =
+            generate_rule_based_notes("fallback-meeting", &segments, &[], 0, 6000, DEFAULT_REVIEW_CONFIDENCE);
+        for response in [
+            Ok("Sorry, I can't help with that.".to_string()),
+            Ok(r#"{"title": "Missing summary"}"#.to_string()),
+            Err("Ollama request failed: connection refused".to_string()),
+        ] {
+            let notes = notes_from_ai_response(
+                response,
+                "fallback-meeting",
+                &segments,
+                &[],
+                0,
+                6000,
+                DEFAULT_REVIEW_CONFIDENCE,
+            );
+            assert_eq!(notes.meeting_id, "fallback-meeting");
+            assert_eq!(notes.summary, rule_based.summary);
+            assert_eq!(notes.action_items.len(), 1);
+            assert_eq!(notes.decisions.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_archive_round_trip_and_corrupt_file() {
+        let dir = std::env::temp_dir().join(format!("burrow-meeting-archive-{}", std::process::id()));
+        let path = dir.join(ARCHIVE_FILE);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(load_archive(&path).is_empty());
+
+        let notes = generate_rule_based_notes(
+            "archived-meeting",
+            &[make_segment("Alice", "I need to file the report", 0)],
+            &[],
+            0,
+            3000,
+            DEFAULT_REVIEW_CONFIDENCE,
+        );
+        write_archive(&path, &[notes]).unwrap();
+        let loaded = load_archive(&path);
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].meeting_id, "archived-meeting");
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(load_archive(&path).is_empty());
+        assert!(path.with_extension("json.corrupt").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_generate_title_empty() {
         assert_eq!(generate_title("", 3), "Meeting (3 participants)");
@@ -611,6 +1535,7 @@ mod tests {
             &["alice_pub".to_string(), "bob_pub".to_string()],
             0,
             12_000,
+            DEFAULT_REVIEW_CONFIDENCE,
         );
         assert_eq!(notes.meeting_id, "test-meeting-1");
         assert!(!notes.summary.is_empty());
@@ -619,6 +1544,29 @@ mod tests {
         assert!(!notes.open_questions.is_empty()); // "?" triggers
     }
 
+    #[tokio::test]
+    async fn test_live_segments_upsert_and_evict() {
+        let meeting_id = "live-test-meeting".to_string();
+        let mut interim = make_segment("Alice", "I need to", 0);
+        interim.is_final = false;
+        let seg_json = |s: &TranscriptSegment| serde_json::to_string(s).unwrap();
+
+        assert_eq!(push_transcript_segment(meeting_id.clone(), seg_json(&interim)), Ok(1));
+        let final_seg = make_segment("Alice", "I need to update the roadmap", 0);
+        assert_eq!(push_transcript_segment(meeting_id.clone(), seg_json(&final_seg)), Ok(1));
+        let next = make_segment("Bob", "Sounds good", 3000);
+        assert_eq!(push_transcript_segment(meeting_id.clone(), seg_json(&next)), Ok(2));
+
+        let live = generate_live_meeting_notes(meeting_id.clone(), "[]".to_string()).unwrap();
+        let notes: MeetingNotes = serde_json::from_str(&live).unwrap();
+        assert_eq!(notes.end_time_ms, 6000);
+        assert!(notes.action_items.iter().any(|a| a.description.contains("roadmap")));
+
+        finalize_live_meeting(meeting_id.clone(), "[]".to_string(), 0, 6000).await.unwrap();
+        assert_eq!(clear_transcript_buffer(meeting_id.clone()), Ok(false));
+        assert!(generate_live_meeting_notes(meeting_id, "[]".to_string()).is_err());
+    }
+
     #[test]
     fn test_export_markdown() {
         let notes = MeetingNotes {
@@ -634,6 +1582,7 @@ mod tests {
                 deadline: "2026-02-20".to_string(),
                 priority: "high".to_string(),
                 completed: false,
+                needs_review: false,
             }],
             decisions: vec![],
             open_questions: vec![],
@@ -646,7 +1595,7 @@ mod tests {
 
         // Store it in archive and test export.
         let mut intel = intelligence().lock().unwrap();
-        intel.archive.push(notes);
+        intel.archive().push(notes);
         drop(intel);
 
         let md = export_meeting_markdown("test-1".to_string()).unwrap();
@@ -8,13 +8,22 @@
 //! 1. Receive kind 445 event → decrypt NIP-44 with exporter_secret →
 //!    MLS decrypt → extract rumor → verify author binding → store message
 
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::LazyLock;
+
 use flutter_rust_bridge::frb;
 use mdk_core::prelude::*;
 use nostr_sdk::prelude::*;
+use serde::Deserialize;
+use tokio::sync::broadcast;
 
 use crate::frb_generated::StreamSink;
 
+use crate::api::app_state;
+use crate::api::diagnostics;
 use crate::api::error::BurrowError;
+use crate::api::group;
+use crate::api::relay;
 use crate::api::state;
 
 /// A decrypted group message, flattened for FFI.
@@ -39,6 +48,16 @@ pub struct GroupMessage {
     pub wrapper_event_id_hex: String,
     /// MLS epoch when this message was created.
     pub epoch: u64,
+    /// Someone else's message that `p`-tags or names the current user.
+    pub mentions_me: bool,
+    /// For an edit (see `edit_message`): hex event ID of the message this
+    /// one replaces. The UI shows this content in place of the original.
+    pub edited_from: Option<String>,
+    /// For a NIP-10 reply: hex event ID of the message replied to.
+    pub reply_to_event_id_hex: Option<String>,
+    /// Hex pubkey of the replied-to message's author, from local storage
+    /// (or the reply's first `p` tag if the parent isn't stored).
+    pub reply_to_author_hex: Option<String>,
 }
 
 /// A notification from the group message listener.
@@ -46,12 +65,19 @@ pub struct GroupMessage {
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
 pub struct GroupNotification {
-    /// "application_message", "commit", "proposal", or other MLS event type.
+    /// "application_message", "commit", "membership_change", "proposal",
+    /// "typing", or other MLS event type.
     pub notification_type: String,
     /// The decrypted message (only set for "application_message").
     pub message: Option<GroupMessage>,
     /// Hex-encoded MLS group ID this notification belongs to.
     pub mls_group_id_hex: String,
+    /// Hex pubkeys that joined (only set for "membership_change").
+    pub added_pubkeys: Vec<String>,
+    /// Hex pubkeys that left or were removed (only set for "membership_change").
+    pub removed_pubkeys: Vec<String>,
+    /// Who is typing (only set for "typing"; expire it client-side).
+    pub sender_pubkey_hex: Option<String>,
 }
 
 /// Result of processing an incoming kind 445 event.
@@ -78,67 +104,369 @@ pub struct SendMessageResult {
     pub message: GroupMessage,
 }
 
+/// Default plaintext size limit for a single message.
+///
+/// MLS framing, NIP-44 and base64 grow the payload by roughly 1.5x, so 32 KiB
+/// of content stays under the common 64 KiB relay event cap.
+pub const DEFAULT_MAX_MESSAGE_BYTES: u32 = 32 * 1024;
+
+static MAX_MESSAGE_BYTES: AtomicU32 = AtomicU32::new(DEFAULT_MAX_MESSAGE_BYTES);
+
+/// Set the content size limit enforced by `send_message` (bytes, min 256).
+#[frb(sync)]
+pub fn set_max_message_bytes(max_bytes: u32) {
+    MAX_MESSAGE_BYTES.store(max_bytes.max(256), Ordering::Relaxed);
+}
+
+/// Get the content size limit enforced by `send_message`.
+#[frb(sync)]
+pub fn get_max_message_bytes() -> u32 {
+    MAX_MESSAGE_BYTES.load(Ordering::Relaxed)
+}
+
 /// Send an encrypted message to a group (MIP-03).
 ///
 /// Creates a plaintext rumor, MLS-encrypts it, NIP-44-encrypts with exporter_secret,
 /// signs with an ephemeral key, and returns both the kind 445 event for relay publication
 /// and the local GroupMessage for immediate UI display.
+///
+/// Content over `get_max_message_bytes()` is rejected up front, since relays
+/// drop oversized events with an opaque failure; use `send_long_message`.
+///
+/// `ttl_secs` sets a NIP-40 expiration on this message: None uses the
+/// group's default (`set_group_message_ttl`), Some(0) sends without one.
 #[frb]
 pub async fn send_message(
     mls_group_id_hex: String,
     content: String,
+    ttl_secs: Option<u64>,
 ) -> Result<SendMessageResult, BurrowError> {
-    state::with_state(|s| {
+    dispatch_send(SendRequest::Text {
+        mls_group_id_hex,
+        content,
+        ttl_secs,
+    })
+    .await
+}
+
+/// Send content of any length as ordered chunks, one message per chunk.
+///
+/// Each chunk carries a `["part", "<index>", "<total>", "<id>"]` tag (1-based
+/// index, shared random id) so receivers can rebuild the original with
+/// `reassemble_message`. Content within the limit is sent as one untagged
+/// message. Returns one result per chunk, in order; publish them in order.
+#[frb]
+pub async fn send_long_message(
+    mls_group_id_hex: String,
+    content: String,
+) -> Result<Vec<SendMessageResult>, BurrowError> {
+    let max_bytes = get_max_message_bytes();
+    if content.len() <= max_bytes as usize {
+        return Ok(vec![send_message(mls_group_id_hex, content, None).await?]);
+    }
+
+    let chunks = split_message_content(content, max_bytes);
+    let total = chunks.len().to_string();
+    let part_id = hex::encode(rand::random::<[u8; 16]>());
+
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
 
-        // Build an unsigned rumor event with kind 1 (text note) content
-        let rumor = EventBuilder::new(Kind::TextNote, &content)
-            .build(s.keys.public_key());
+        let mut results = Vec::with_capacity(chunks.len());
+        for (i, chunk) in chunks.iter().enumerate() {
+            let part_tag = Tag::parse([
+                "part".to_string(),
+                (i + 1).to_string(),
+                total.clone(),
+                part_id.clone(),
+            ])
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+            let rumor = EventBuilder::new(Kind::TextNote, chunk)
+                .tag(part_tag)
+                .tags(expiration_tag(&mls_group_id_hex, None))
+                .build(s.signer.public_key());
+            results.push(create_and_fetch(s, &group_id, rumor)?);
+        }
+        Ok(results)
+    })
+    .await
+}
 
-        // Get the rumor's event ID before MLS encryption so we can retrieve
-        // the stored message immediately after create_message
-        let rumor_id = rumor.id
-            .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
+/// Split content into chunks of at most `max_bytes` bytes.
+///
+/// Prefers breaking after a newline or space in the second half of the
+/// window, never splits a UTF-8 character, and concatenating the chunks
+/// reproduces the input exactly.
+#[frb(sync)]
+pub fn split_message_content(content: String, max_bytes: u32) -> Vec<String> {
+    let max = (max_bytes as usize).max(4);
+    let mut chunks = Vec::new();
+    let mut rest = content.as_str();
+    while rest.len() > max {
+        let mut end = max;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let window = &rest[..end];
+        if let Some(pos) = window.rfind(['\n', ' ']) {
+            if pos + 1 > end / 2 {
+                end = pos + 1;
+            }
+        }
+        chunks.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() || chunks.is_empty() {
+        chunks.push(rest.to_string());
+    }
+    chunks
+}
 
-        let event = s
-            .mdk
-            .create_message(&group_id, rumor)
-            .map_err(BurrowError::from)?;
+/// Rebuild a long message from its parts (see `send_long_message`).
+///
+/// `parts` may arrive in any order but must all share the same part id and
+/// cover every index from 1 to total.
+#[frb(sync)]
+pub fn reassemble_message(parts: Vec<GroupMessage>) -> Result<String, BurrowError> {
+    let mut indexed: Vec<(usize, usize, String, &GroupMessage)> = Vec::with_capacity(parts.len());
+    for msg in &parts {
+        let tag = msg
+            .tags
+            .iter()
+            .find(|t| t.first().map(String::as_str) == Some("part") && t.len() >= 4)
+            .ok_or_else(|| BurrowError::from(format!("Message {} has no part tag", msg.event_id_hex)))?;
+        let index = tag[1].parse::<usize>().map_err(|_| BurrowError::from("Invalid part index".to_string()))?;
+        let total = tag[2].parse::<usize>().map_err(|_| BurrowError::from("Invalid part total".to_string()))?;
+        indexed.push((index, total, tag[3].clone(), msg));
+    }
 
-        let event_json =
-            serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))?;
+    let Some((_, total, part_id, _)) = indexed.first().cloned() else {
+        return Err(BurrowError::from("No parts given".to_string()));
+    };
+    if indexed.iter().any(|(_, t, id, _)| *t != total || *id != part_id) {
+        return Err(BurrowError::from("Parts belong to different messages".to_string()));
+    }
+    indexed.sort_by_key(|(i, ..)| *i);
+    indexed.dedup_by_key(|(i, ..)| *i);
+    for expected in 1..=total {
+        if indexed.get(expected - 1).map(|(i, ..)| *i) != Some(expected) {
+            return Err(BurrowError::from(format!("Missing part {expected} of {total}")));
+        }
+    }
+    if indexed.len() != total {
+        return Err(BurrowError::from("Unexpected extra parts".to_string()));
+    }
+    Ok(indexed.iter().map(|(.., m)| m.content.as_str()).collect())
+}
 
-        // Retrieve the message from MDK storage for immediate UI display
-        let msg = s
-            .mdk
-            .get_message(&group_id, &rumor_id)
-            .map_err(BurrowError::from)?
-            .ok_or_else(|| BurrowError::from("Sent message not found in local storage".to_string()))?;
+/// MLS-encrypt a rumor and return the event plus the locally stored copy.
+fn create_and_fetch(
+    s: &state::BurrowState,
+    group_id: &GroupId,
+    rumor: UnsignedEvent,
+) -> Result<SendMessageResult, BurrowError> {
+    // Get the rumor's event ID before MLS encryption so we can retrieve
+    // the stored message immediately after create_message
+    let rumor_id = rumor.id
+        .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
+
+    let event = s
+        .mdk
+        .create_message(group_id, rumor)
+        .map_err(BurrowError::from)?;
+
+    let event_json =
+        serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))?;
+
+    // Retrieve the message from MDK storage for immediate UI display
+    let msg = s
+        .mdk
+        .get_message(group_id, &rumor_id)
+        .map_err(BurrowError::from)?
+        .ok_or_else(|| BurrowError::from("Sent message not found in local storage".to_string()))?;
+
+    // Held in the outbox until a relay accepts it; see `flush_outbox`. A
+    // send that can't be queued would be lost if publishing fails, so fail
+    // it here instead.
+    app_state::outbox_enqueue(&app_state::OutboxEntry {
+        wrapper_event_id_hex: event.id.to_hex(),
+        rumor_id_hex: rumor_id.to_hex(),
+        group_id_hex: hex::encode(group_id.as_slice()),
+        event_json: event_json.clone(),
+        epoch: msg.epoch.unwrap_or(0),
+    })?;
+
+    let (reply_to_event_id_hex, reply_to_author_hex) =
+        reply_context(s, &msg.mls_group_id, msg.kind, &msg.tags);
+    let group_message = GroupMessage {
+        event_id_hex: msg.id.to_hex(),
+        author_pubkey_hex: msg.pubkey.to_hex(),
+        content: msg.content.clone(),
+        created_at: msg.created_at.as_secs(),
+        mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
+        kind: msg.kind.as_u16() as u64,
+        tags: msg
+            .tags
+            .iter()
+            .map(|t| t.as_slice().to_vec())
+            .collect(),
+        wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
+        epoch: msg.epoch.unwrap_or(0),
+        edited_from: edit_target(&msg.tags),
+        reply_to_event_id_hex,
+        reply_to_author_hex,
+        mentions_me: false,
+    };
+
+    // Let other in-process listeners (e.g. a second window) show it right away
+    let _ = LOCAL_SENDS.send(group_message.clone());
+
+    Ok(SendMessageResult {
+        event_json,
+        message: group_message,
+    })
+}
 
-        let group_message = GroupMessage {
-            event_id_hex: msg.id.to_hex(),
-            author_pubkey_hex: msg.pubkey.to_hex(),
-            content: msg.content.clone(),
-            created_at: msg.created_at.as_secs(),
-            mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
-            kind: msg.kind.as_u16() as u64,
-            tags: msg
-                .tags
-                .iter()
-                .map(|t| t.as_slice().to_vec())
-                .collect(),
-            wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
-            epoch: msg.epoch.unwrap_or(0),
+// ---------------------------------------------------------------------------
+// Outbox
+// ---------------------------------------------------------------------------
+
+/// Outcome of `flush_outbox`.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Default)]
+pub struct OutboxFlushResult {
+    /// Events accepted by a relay and removed from the outbox.
+    pub published: u32,
+    /// Of those, how many had to be re-encrypted for a newer epoch.
+    pub reencrypted: u32,
+    /// Entries dropped because their group or message is gone.
+    pub dropped: u32,
+    /// Entries still queued (publishing failed, likely still offline).
+    pub remaining: u32,
+}
+
+/// Publish everything still in the outbox, oldest first.
+///
+/// Every send that goes through `send` (text, media, reactions, replies,
+/// edits, deletions) and `send_long_message` is queued when it's encrypted and removed once a
+/// relay accepts it via `publish_event_json`, so a reaction tapped offline
+/// isn't lost. Call on reconnect; `sync_group_messages` also calls it after
+/// catching up.
+///
+/// If the group moved to a newer epoch while offline, the old ciphertext is
+/// undecryptable for members who already dropped that epoch's secrets, so
+/// the stored rumor is re-encrypted under the current epoch first. Stops at
+/// the first publish failure and leaves the rest queued.
+#[frb]
+pub async fn flush_outbox() -> Result<OutboxFlushResult, BurrowError> {
+    let mut result = OutboxFlushResult::default();
+    let pending = app_state::outbox_pending();
+
+    for (index, entry) in pending.iter().enumerate() {
+        let prepared = state::with_state_mut(|s| prepare_outbox_entry(s, entry)).await?;
+        let Some((event, reencrypted)) = prepared else {
+            let _ = app_state::outbox_remove(&entry.wrapper_event_id_hex);
+            result.dropped += 1;
+            continue;
         };
 
-        Ok(SendMessageResult {
-            event_json,
-            message: group_message,
-        })
-    })
-    .await
+        match relay::publish_event(&event).await {
+            Ok(_) => {
+                result.published += 1;
+                if reencrypted {
+                    result.reencrypted += 1;
+                }
+            }
+            Err(e) => {
+                diagnostics::record_error("outbox_flush", &e.message);
+                result.remaining = (pending.len() - index) as u32;
+                break;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// The event to publish for an outbox entry, re-encrypting it if the group's
+/// epoch advanced. None if the entry can never be sent.
+fn prepare_outbox_entry(
+    s: &state::BurrowState,
+    entry: &app_state::OutboxEntry,
+) -> Result<Option<(Event, bool)>, BurrowError> {
+    let Ok(group_bytes) = hex::decode(&entry.group_id_hex) else {
+        return Ok(None);
+    };
+    let group_id = GroupId::from_slice(&group_bytes);
+    let Some(group) = s.mdk.get_group(&group_id).map_err(BurrowError::from)? else {
+        return Ok(None);
+    };
+    if !matches!(group.state, group_types::GroupState::Active) {
+        return Ok(None);
+    }
+
+    if group.epoch == entry.epoch {
+        return Ok(Event::from_json(&entry.event_json).ok().map(|e| (e, false)));
+    }
+
+    let Ok(rumor_id) = EventId::from_hex(&entry.rumor_id_hex) else {
+        return Ok(None);
+    };
+    let Some(msg) = s.mdk.get_message(&group_id, &rumor_id).map_err(BurrowError::from)? else {
+        return Ok(None);
+    };
+    let mut rumor = UnsignedEvent::new(
+        msg.pubkey,
+        msg.created_at,
+        msg.kind,
+        msg.tags.clone(),
+        msg.content.clone(),
+    );
+    rumor.ensure_id();
+
+    let event = s
+        .mdk
+        .create_message(&group_id, rumor)
+        .map_err(BurrowError::from)?;
+    let event_json =
+        serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))?;
+    app_state::outbox_remove(&entry.wrapper_event_id_hex)?;
+    app_state::outbox_enqueue(&app_state::OutboxEntry {
+        wrapper_event_id_hex: event.id.to_hex(),
+        event_json,
+        epoch: group.epoch,
+        ..entry.clone()
+    })?;
+    Ok(Some((event, true)))
+}
+
+/// Messages sent from this process, fanned out to `listen_for_local_sends`.
+static LOCAL_SENDS: LazyLock<broadcast::Sender<GroupMessage>> =
+    LazyLock::new(|| broadcast::channel(256).0);
+
+/// Stream every message sent from this process, as soon as it's encrypted.
+///
+/// Relay subscriptions skip our own events, so a second window or a test
+/// harness in the same process would otherwise only see a local send after
+/// a sync. Covers everything sent through `send` and its wrappers, plus
+/// `send_long_message`. Runs until the stream is closed from the Dart side.
+#[frb]
+pub async fn listen_for_local_sends(sink: StreamSink<GroupMessage>) -> Result<(), BurrowError> {
+    let mut rx = LOCAL_SENDS.subscribe();
+    loop {
+        match rx.recv().await {
+            Ok(msg) => {
+                if sink.add(msg).is_err() {
+                    break;
+                }
+            }
+            // A slow listener missed some sends; they're still in storage.
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+    Ok(())
 }
 
 /// Send an encrypted message with media attachment(s) to a group.
@@ -154,63 +482,64 @@ pub async fn send_message_with_media(
     content: String,
     imeta_tags_json: Vec<Vec<String>>,
 ) -> Result<SendMessageResult, BurrowError> {
-    state::with_state(|s| {
-        let group_id = GroupId::from_slice(
-            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
-        );
-
-        // Build event with imeta tags
-        let mut builder = EventBuilder::new(Kind::TextNote, &content);
-        for tag_values in &imeta_tags_json {
-            let tag_strings: Vec<String> =
-                std::iter::once("imeta".to_string())
-                    .chain(tag_values.iter().cloned())
-                    .collect();
-            if let Ok(tag) = Tag::parse(tag_strings) {
-                builder = builder.tag(tag);
-            }
-        }
-
-        let rumor = builder.build(s.keys.public_key());
-        let rumor_id = rumor.id
-            .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
-
-        let event = s
-            .mdk
-            .create_message(&group_id, rumor)
-            .map_err(BurrowError::from)?;
+    dispatch_send(SendRequest::Media {
+        mls_group_id_hex,
+        content,
+        imeta_tags: imeta_tags_json,
+    })
+    .await
+}
 
-        let event_json =
-            serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))?;
+/// A reaction to a message, with NIP-30 custom emoji resolved for rendering.
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct MessageReaction {
+    /// Hex-encoded event ID of the kind 7 reaction rumor.
+    pub event_id_hex: String,
+    /// Hex-encoded public key of the member who reacted.
+    pub reactor_pubkey_hex: String,
+    /// Reaction content: an emoji, `+`, `-`, or a `:shortcode:`.
+    pub content: String,
+    /// Image URL for a `:shortcode:` reaction (from its `emoji` tag), if any.
+    pub custom_emoji_url: Option<String>,
+    /// Unix timestamp of the reaction.
+    pub created_at: u64,
+}
 
-        let msg = s
-            .mdk
-            .get_message(&group_id, &rumor_id)
-            .map_err(BurrowError::from)?
-            .ok_or_else(|| BurrowError::from("Sent message not found in local storage".to_string()))?;
+/// Validate reaction content per NIP-25 / NIP-30.
+///
+/// Accepts `+`, `-`, a single emoji (including ZWJ sequences, skin tones,
+/// flags and keycaps), or a `:shortcode:` paired with a custom emoji URL.
+/// A custom emoji URL is rejected for anything other than a shortcode.
+#[frb]
+pub fn validate_reaction(
+    content: String,
+    custom_emoji_url: Option<String>,
+) -> Result<(), BurrowError> {
+    if let Some(_shortcode) = custom_emoji_shortcode(&content) {
+        let url = custom_emoji_url.ok_or_else(|| {
+            BurrowError::from(format!("Custom emoji {} requires an image URL", content))
+        })?;
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(BurrowError::from(format!("Invalid custom emoji URL: {}", url)));
+        }
+        return Ok(());
+    }
 
-        let group_message = GroupMessage {
-            event_id_hex: msg.id.to_hex(),
-            author_pubkey_hex: msg.pubkey.to_hex(),
-            content: msg.content.clone(),
-            created_at: msg.created_at.as_secs(),
-            mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
-            kind: msg.kind.as_u16() as u64,
-            tags: msg
-                .tags
-                .iter()
-                .map(|t| t.as_slice().to_vec())
-                .collect(),
-            wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
-            epoch: msg.epoch.unwrap_or(0),
-        };
+    if custom_emoji_url.is_some() {
+        return Err(BurrowError::from(
+            "Custom emoji URL requires a :shortcode: reaction".to_string(),
+        ));
+    }
 
-        Ok(SendMessageResult {
-            event_json,
-            message: group_message,
-        })
-    })
-    .await
+    if content == "+" || content == "-" || is_single_emoji(&content) {
+        Ok(())
+    } else {
+        Err(BurrowError::from(format!(
+            "Reaction must be a single emoji, '+', '-', or a :shortcode: (got {:?})",
+            content
+        )))
+    }
 }
 
 /// Send an encrypted reaction to a message in a group (NIP-25 over MLS).
@@ -219,65 +548,433 @@ pub async fn send_message_with_media(
 /// the target message's event ID. The rumor is MLS-encrypted and published
 /// as a kind 445 event, same as regular messages.
 ///
+/// For NIP-30 custom emoji, pass `:shortcode:` as `emoji` and the image URL as
+/// `custom_emoji_url`; an `["emoji", shortcode, url]` tag is added.
+///
 /// Returns the encrypted event JSON and the local GroupMessage for immediate display.
 #[frb]
 pub async fn send_reaction(
     mls_group_id_hex: String,
     target_event_id_hex: String,
     emoji: String,
+    custom_emoji_url: Option<String>,
 ) -> Result<SendMessageResult, BurrowError> {
-    state::with_state(|s| {
+    dispatch_send(SendRequest::Reaction {
+        mls_group_id_hex,
+        target_event_id_hex,
+        emoji,
+        custom_emoji_url,
+    })
+    .await
+}
+
+/// Send a text reply to a message in a group.
+///
+/// Same as `send_message`, plus a NIP-10 `["e", <id>, "", "reply"]` tag
+/// pointing at the message being replied to.
+#[frb]
+pub async fn send_reply(
+    mls_group_id_hex: String,
+    reply_to_event_id_hex: String,
+    content: String,
+    ttl_secs: Option<u64>,
+) -> Result<SendMessageResult, BurrowError> {
+    dispatch_send(SendRequest::Reply {
+        mls_group_id_hex,
+        reply_to_event_id_hex,
+        content,
+        ttl_secs,
+    })
+    .await
+}
+
+/// Edit one of your own messages.
+///
+/// Sends a new kind 1 message carrying `["e", <target>, "", "edit"]` and the
+/// full replacement text. This is a soft-edit convention: the original stays
+/// in every member's MLS history and on relays, and clients that understand
+/// the tag (via `GroupMessage.edited_from`) render the newest edit in its
+/// place. Nothing is deleted. Only messages authored by the current account
+/// and present in local storage can be edited.
+#[frb]
+pub async fn edit_message(
+    mls_group_id_hex: String,
+    target_event_id_hex: String,
+    new_content: String,
+) -> Result<SendMessageResult, BurrowError> {
+    dispatch_send(SendRequest::Edit {
+        mls_group_id_hex,
+        target_event_id_hex,
+        content: new_content,
+    })
+    .await
+}
+
+/// Delete one of your own messages.
+///
+/// Sends a NIP-09 kind 5 deletion request with `["e", <target>]` and
+/// `["k", <target kind>]` tags. Like an edit it is a request: the original
+/// stays in every member's MLS history, and clients that honor NIP-09 hide
+/// it. Only messages authored by the current account and present in local
+/// storage can be deleted. Queued in the outbox like any other send.
+#[frb]
+pub async fn delete_message(
+    mls_group_id_hex: String,
+    target_event_id_hex: String,
+) -> Result<SendMessageResult, BurrowError> {
+    dispatch_send(SendRequest::Delete {
+        mls_group_id_hex,
+        target_event_id_hex,
+    })
+    .await
+}
+
+// ---------------------------------------------------------------------------
+// Unified send
+// ---------------------------------------------------------------------------
+
+/// Any message `send` can deliver, as a JSON tagged union on `type`.
+///
+/// Unknown fields are ignored so callers can send newer shapes to older
+/// builds without breaking; unknown `type`s are rejected.
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SendRequest {
+    Text {
+        mls_group_id_hex: String,
+        content: String,
+        #[serde(default)]
+        ttl_secs: Option<u64>,
+    },
+    Media {
+        mls_group_id_hex: String,
+        content: String,
+        /// imeta tag values without the leading "imeta".
+        imeta_tags: Vec<Vec<String>>,
+    },
+    Reaction {
+        mls_group_id_hex: String,
+        target_event_id_hex: String,
+        emoji: String,
+        #[serde(default)]
+        custom_emoji_url: Option<String>,
+    },
+    Reply {
+        mls_group_id_hex: String,
+        reply_to_event_id_hex: String,
+        content: String,
+        #[serde(default)]
+        ttl_secs: Option<u64>,
+    },
+    Edit {
+        mls_group_id_hex: String,
+        target_event_id_hex: String,
+        content: String,
+    },
+    Delete {
+        mls_group_id_hex: String,
+        target_event_id_hex: String,
+    },
+}
+
+impl SendRequest {
+    fn mls_group_id_hex(&self) -> &str {
+        match self {
+            SendRequest::Text { mls_group_id_hex, .. }
+            | SendRequest::Media { mls_group_id_hex, .. }
+            | SendRequest::Reaction { mls_group_id_hex, .. }
+            | SendRequest::Reply { mls_group_id_hex, .. }
+            | SendRequest::Edit { mls_group_id_hex, .. }
+            | SendRequest::Delete { mls_group_id_hex, .. } => mls_group_id_hex,
+        }
+    }
+}
+
+/// Send any supported message from a single JSON request.
+///
+/// `request_json` is a tagged union on `type`:
+/// - `{"type":"text","mls_group_id_hex":..,"content":..,"ttl_secs"?:..}`
+/// - `{"type":"media","mls_group_id_hex":..,"content":..,"imeta_tags":[[..]]}`
+/// - `{"type":"reaction","mls_group_id_hex":..,"target_event_id_hex":..,"emoji":..,"custom_emoji_url"?:..}`
+/// - `{"type":"reply","mls_group_id_hex":..,"reply_to_event_id_hex":..,"content":..,"ttl_secs"?:..}`
+/// - `{"type":"edit","mls_group_id_hex":..,"target_event_id_hex":..,"content":..}`
+/// - `{"type":"delete","mls_group_id_hex":..,"target_event_id_hex":..}`
+///
+/// A stable entry point for automation; `send_message`, `send_message_with_media`,
+/// `send_reaction`, `send_reply`, `edit_message` and `delete_message` all go
+/// through the same dispatcher.
+#[frb]
+pub async fn send(request_json: String) -> Result<SendMessageResult, BurrowError> {
+    let request: SendRequest = serde_json::from_str(&request_json)
+        .map_err(|e| BurrowError::from(format!("Invalid send request: {e}")))?;
+    dispatch_send(request).await
+}
+
+async fn dispatch_send(request: SendRequest) -> Result<SendMessageResult, BurrowError> {
+    match &request {
+        SendRequest::Text { content, .. }
+        | SendRequest::Reply { content, .. }
+        | SendRequest::Edit { content, .. } => {
+            let max_bytes = get_max_message_bytes();
+            if content.len() > max_bytes as usize {
+                return Err(BurrowError::from(format!(
+                    "Message is {} bytes, over the {} byte limit; use send_long_message",
+                    content.len(),
+                    max_bytes
+                )));
+            }
+        }
+        SendRequest::Reaction { emoji, custom_emoji_url, .. } => {
+            validate_reaction(emoji.clone(), custom_emoji_url.clone())?;
+        }
+        SendRequest::Media { .. } | SendRequest::Delete { .. } => {}
+    }
+
+    state::with_state_mut(|s| {
+        let mls_group_id_hex = request.mls_group_id_hex();
+        let group_id = GroupId::from_slice(
+            &hex::decode(mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+
+        let builder = match &request {
+            // Kind 1 text; the NIP-40 expiration follows the group default
+            SendRequest::Text { content, ttl_secs, .. } => EventBuilder::new(Kind::TextNote, content)
+                .tags(expiration_tag(mls_group_id_hex, *ttl_secs)),
+            SendRequest::Media { content, imeta_tags, .. } => {
+                let mut builder = EventBuilder::new(Kind::TextNote, content)
+                    .tags(expiration_tag(mls_group_id_hex, None));
+                for tag_values in imeta_tags {
+                    let tag_strings: Vec<String> = std::iter::once("imeta".to_string())
+                        .chain(tag_values.iter().cloned())
+                        .collect();
+                    if let Ok(tag) = Tag::parse(tag_strings) {
+                        builder = builder.tag(tag);
+                    }
+                }
+                builder
+            }
+            // Kind 7 = Reaction (NIP-25)
+            SendRequest::Reaction {
+                target_event_id_hex,
+                emoji,
+                custom_emoji_url,
+                ..
+            } => {
+                let target_id = EventId::from_hex(target_event_id_hex)
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                let mut builder = EventBuilder::new(Kind::Reaction, emoji).tag(Tag::event(target_id));
+                if let (Some(shortcode), Some(url)) = (custom_emoji_shortcode(emoji), custom_emoji_url) {
+                    builder = builder.tag(
+                        Tag::parse(["emoji".to_string(), shortcode.to_string(), url.clone()])
+                            .map_err(|e| BurrowError::from(e.to_string()))?,
+                    );
+                }
+                builder
+            }
+            SendRequest::Reply {
+                reply_to_event_id_hex,
+                content,
+                ttl_secs,
+                ..
+            } => {
+                EventId::from_hex(reply_to_event_id_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+                let reply_tag = Tag::parse([
+                    "e".to_string(),
+                    reply_to_event_id_hex.clone(),
+                    String::new(),
+                    "reply".to_string(),
+                ])
+                .map_err(|e| BurrowError::from(e.to_string()))?;
+                EventBuilder::new(Kind::TextNote, content)
+                    .tag(reply_tag)
+                    .tags(expiration_tag(mls_group_id_hex, *ttl_secs))
+            }
+            SendRequest::Edit {
+                target_event_id_hex,
+                content,
+                ..
+            } => {
+                let target_id = EventId::from_hex(target_event_id_hex)
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                let target = s
+                    .mdk
+                    .get_message(&group_id, &target_id)
+                    .map_err(BurrowError::from)?
+                    .ok_or_else(|| BurrowError::from("Message to edit not found".to_string()))?;
+                if target.pubkey != s.signer.public_key() {
+                    return Err(BurrowError::from("Only your own messages can be edited".to_string()));
+                }
+                // Edits of edits point at the original so they chain to one message
+                let original = edit_target(&target.tags).unwrap_or_else(|| target_event_id_hex.clone());
+                let edit_tag = Tag::parse(["e".to_string(), original, String::new(), "edit".to_string()])
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                EventBuilder::new(Kind::TextNote, content)
+                    .tag(edit_tag)
+                    .tags(expiration_tag(mls_group_id_hex, None))
+            }
+            // Kind 5 = Event deletion request (NIP-09)
+            SendRequest::Delete { target_event_id_hex, .. } => {
+                let target_id = EventId::from_hex(target_event_id_hex)
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                let target = s
+                    .mdk
+                    .get_message(&group_id, &target_id)
+                    .map_err(BurrowError::from)?
+                    .ok_or_else(|| BurrowError::from("Message to delete not found".to_string()))?;
+                if target.pubkey != s.signer.public_key() {
+                    return Err(BurrowError::from("Only your own messages can be deleted".to_string()));
+                }
+                let kind_tag = Tag::parse(["k".to_string(), target.kind.as_u16().to_string()])
+                    .map_err(|e| BurrowError::from(e.to_string()))?;
+                EventBuilder::new(Kind::EventDeletion, "")
+                    .tag(Tag::event(target_id))
+                    .tag(kind_tag)
+            }
+        };
+
+        let rumor = builder.build(s.signer.public_key());
+        create_and_fetch(s, &group_id, rumor)
+    })
+    .await
+}
+
+/// Get all reactions to a message in a group.
+///
+/// Scans stored kind 7 messages whose target (last `e` tag, per NIP-25) is
+/// `target_event_id_hex`. `:shortcode:` reactions are resolved to their image
+/// URL via the reaction's `emoji` tag (NIP-30). Ordered oldest first.
+#[frb]
+pub async fn get_message_reactions(
+    mls_group_id_hex: String,
+    target_event_id_hex: String,
+) -> Result<Vec<MessageReaction>, BurrowError> {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
 
-        let target_id = EventId::from_hex(&target_event_id_hex)
-            .map_err(|e| BurrowError::from(e.to_string()))?;
+        let messages = all_stored_messages(s, &group_id)?;
 
-        // Kind 7 = Reaction (NIP-25)
-        let rumor = EventBuilder::new(Kind::Reaction, &emoji)
-            .tag(Tag::event(target_id))
-            .build(s.keys.public_key());
+        let mut reactions: Vec<MessageReaction> = messages
+            .iter()
+            .filter(|msg| msg.kind == Kind::Reaction)
+            .filter(|msg| {
+                msg.tags
+                    .iter()
+                    .filter(|t| t.as_slice().first().map(|k| k.as_str()) == Some("e"))
+                    .last()
+                    .and_then(|t| t.as_slice().get(1))
+                    .map_or(false, |id| id.eq_ignore_ascii_case(&target_event_id_hex))
+            })
+            .map(|msg| {
+                let custom_emoji_url = custom_emoji_shortcode(&msg.content).and_then(|code| {
+                    msg.tags.iter().find_map(|t| {
+                        let v = t.as_slice();
+                        if v.len() >= 3 && v[0] == "emoji" && v[1] == code {
+                            Some(v[2].clone())
+                        } else {
+                            None
+                        }
+                    })
+                });
+                MessageReaction {
+                    event_id_hex: msg.id.to_hex(),
+                    reactor_pubkey_hex: msg.pubkey.to_hex(),
+                    content: msg.content.clone(),
+                    custom_emoji_url,
+                    created_at: msg.created_at.as_secs(),
+                }
+            })
+            .collect();
 
-        let rumor_id = rumor.id
-            .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
+        reactions.sort_by_key(|r| r.created_at);
+        Ok(reactions)
+    })
+    .await
+}
 
-        let event = s
-            .mdk
-            .create_message(&group_id, rumor)
-            .map_err(BurrowError::from)?;
+/// Extract the shortcode from NIP-30 `:shortcode:` content.
+///
+/// Shortcodes are restricted to alphanumerics and underscores.
+fn custom_emoji_shortcode(content: &str) -> Option<&str> {
+    let inner = content.strip_prefix(':')?.strip_suffix(':')?;
+    if !inner.is_empty() && inner.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Some(inner)
+    } else {
+        None
+    }
+}
 
-        let event_json =
-            serde_json::to_string(&event).map_err(|e| BurrowError::from(e.to_string()))?;
+/// Whether `s` is exactly one emoji.
+///
+/// Covers single pictographs, ZWJ sequences (👨‍👩‍👧), skin-tone and variation
+/// modifiers, regional-indicator flags and keycaps (1️⃣). A heuristic over
+/// code point ranges rather than full UTS #51 grapheme segmentation.
+fn is_single_emoji(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    if chars.is_empty() || chars.len() > 16 {
+        return false;
+    }
 
-        let msg = s
-            .mdk
-            .get_message(&group_id, &rumor_id)
-            .map_err(BurrowError::from)?
-            .ok_or_else(|| BurrowError::from("Sent reaction not found in local storage".to_string()))?;
+    // Flags: a pair of regional indicator symbols
+    let is_regional = |c: char| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c);
+    if chars.iter().all(|c| is_regional(*c)) {
+        return chars.len() == 2;
+    }
 
-        let group_message = GroupMessage {
-            event_id_hex: msg.id.to_hex(),
-            author_pubkey_hex: msg.pubkey.to_hex(),
-            content: msg.content.clone(),
-            created_at: msg.created_at.as_secs(),
-            mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
-            kind: msg.kind.as_u16() as u64,
-            tags: msg
-                .tags
-                .iter()
-                .map(|t| t.as_slice().to_vec())
-                .collect(),
-            wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
-            epoch: msg.epoch.unwrap_or(0),
+    // Keycaps: [0-9#*] FE0F? 20E3
+    if chars.last() == Some(&'\u{20E3}') {
+        return match chars.as_slice() {
+            [base, '\u{20E3}'] | [base, '\u{FE0F}', '\u{20E3}'] => {
+                base.is_ascii_digit() || *base == '#' || *base == '*'
+            }
+            _ => false,
         };
+    }
 
-        Ok(SendMessageResult {
-            event_json,
-            message: group_message,
-        })
-    })
-    .await
+    let mut bases = 0;
+    let mut after_zwj = false;
+    for (i, c) in chars.iter().enumerate() {
+        match *c {
+            '\u{200D}' => {
+                if i == 0 || after_zwj {
+                    return false;
+                }
+                after_zwj = true;
+            }
+            // Variation selectors, skin-tone modifiers, tag sequences
+            '\u{FE0E}' | '\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}' => {
+                if i == 0 {
+                    return false;
+                }
+            }
+            c if is_emoji_base(c) => {
+                if !after_zwj {
+                    bases += 1;
+                }
+                after_zwj = false;
+            }
+            _ => return false,
+        }
+    }
+    bases == 1 && !after_zwj
+}
+
+/// Code point ranges that contain emoji presentation characters.
+fn is_emoji_base(c: char) -> bool {
+    matches!(c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+        | 0x2190..=0x21FF
+        | 0x2300..=0x23FF
+        | 0x24C2
+        | 0x25A0..=0x25FF
+        | 0x2600..=0x27BF
+        | 0x2934 | 0x2935
+        | 0x2B00..=0x2BFF
+        | 0x3030 | 0x303D | 0x3297 | 0x3299
+        | 0x1F000..=0x1F2FF
+        | 0x1F300..=0x1FAFF)
 }
 
 /// Kind used for read receipt signals (MIP read receipts spec).
@@ -305,7 +1002,7 @@ pub async fn send_read_receipt(
     mls_group_id_hex: String,
     message_event_ids: Vec<String>,
 ) -> Result<String, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -317,7 +1014,7 @@ pub async fn send_read_receipt(
             builder = builder.tag(Tag::event(event_id));
         }
 
-        let rumor = builder.build(s.keys.public_key());
+        let rumor = builder.build(s.signer.public_key());
 
         let event = s
             .mdk
@@ -329,25 +1026,150 @@ pub async fn send_read_receipt(
     .await
 }
 
+/// A member who has read a message.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageReader {
+    /// Hex-encoded public key of the reader.
+    pub reader_pubkey_hex: String,
+    /// Unix timestamp of their first receipt covering the message.
+    pub read_at: u64,
+}
+
+/// Who has read a message, for "seen by N" (groups) or a read tick (DMs).
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct ReceiptSummary {
+    /// Hex-encoded event ID of the message.
+    pub event_id_hex: String,
+    /// Members other than the author who read it, earliest first.
+    pub readers: Vec<MessageReader>,
+    /// Number of current members other than the author.
+    pub recipient_count: u32,
+    /// Whether every current member other than the author has read it.
+    pub read_by_all: bool,
+}
+
+/// Aggregate the read receipts stored for a message in a group.
+///
+/// Computed from the kind 15 receipts MDK keeps alongside regular messages.
+/// Receipts from the author themselves are ignored.
+#[frb]
+pub async fn get_message_receipts(
+    mls_group_id_hex: String,
+    event_id_hex: String,
+) -> Result<ReceiptSummary, BurrowError> {
+    state::with_state_read(|s| {
+        let group_id = GroupId::from_slice(
+            &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
+        );
+        let target = EventId::from_hex(&event_id_hex).map_err(|e| BurrowError::from(e.to_string()))?;
+
+        let author_hex = s
+            .mdk
+            .get_message(&group_id, &target)
+            .map_err(BurrowError::from)?
+            .map(|m| m.pubkey.to_hex())
+            .ok_or_else(|| BurrowError::from("Message not found".to_string()))?;
+
+        let members: Vec<String> = s
+            .mdk
+            .get_members(&group_id)
+            .map_err(BurrowError::from)?
+            .iter()
+            .map(|pk| pk.to_hex())
+            .collect();
+
+        let receipts: Vec<ReadReceipt> = all_stored_messages(s, &group_id)?
+            .iter()
+            .filter(|msg| msg.kind == Kind::Custom(READ_RECEIPT_KIND))
+            .map(|msg| ReadReceipt {
+                reader_pubkey_hex: msg.pubkey.to_hex(),
+                read_at: msg.created_at.as_secs(),
+                message_event_ids: msg
+                    .tags
+                    .iter()
+                    .filter(|t| t.as_slice().first().map(|k| k.as_str()) == Some("e"))
+                    .filter_map(|t| t.as_slice().get(1).cloned())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(aggregate_receipts(&target.to_hex(), &author_hex, &members, &receipts))
+    })
+    .await
+}
+
+/// Fold read receipts into a summary for one message.
+#[frb(ignore)]
+pub fn aggregate_receipts(
+    event_id_hex: &str,
+    author_pubkey_hex: &str,
+    member_pubkeys_hex: &[String],
+    receipts: &[ReadReceipt],
+) -> ReceiptSummary {
+    let mut first_read: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
+    for receipt in receipts {
+        if receipt.reader_pubkey_hex == author_pubkey_hex
+            || !receipt
+                .message_event_ids
+                .iter()
+                .any(|id| id.eq_ignore_ascii_case(event_id_hex))
+        {
+            continue;
+        }
+        first_read
+            .entry(receipt.reader_pubkey_hex.as_str())
+            .and_modify(|at| *at = (*at).min(receipt.read_at))
+            .or_insert(receipt.read_at);
+    }
+
+    let mut readers: Vec<MessageReader> = first_read
+        .into_iter()
+        .map(|(reader, read_at)| MessageReader {
+            reader_pubkey_hex: reader.to_string(),
+            read_at,
+        })
+        .collect();
+    readers.sort_by(|a, b| a.read_at.cmp(&b.read_at).then_with(|| a.reader_pubkey_hex.cmp(&b.reader_pubkey_hex)));
+
+    let recipients: Vec<&String> = member_pubkeys_hex
+        .iter()
+        .filter(|pk| pk.as_str() != author_pubkey_hex)
+        .collect();
+    let read_by_all = recipients
+        .iter()
+        .all(|pk| readers.iter().any(|r| &r.reader_pubkey_hex == *pk));
+
+    ReceiptSummary {
+        event_id_hex: event_id_hex.to_string(),
+        readers,
+        recipient_count: recipients.len() as u32,
+        read_by_all,
+    }
+}
+
 /// Kind used for typing indicator signals (ephemeral, not stored).
-const TYPING_INDICATOR_KIND: u16 = 10000;
+pub(crate) const TYPING_INDICATOR_KIND: u16 = 10000;
 
 /// Send a typing indicator to a group.
 ///
 /// Creates a kind 10000 (ephemeral) MLS app message that signals the user is
-/// typing. These are not stored by MDK — recipients surface them as transient
-/// UI state that auto-expires after a few seconds.
+/// typing. MDK stores every application message it decrypts, so receivers
+/// leave these out of history (`get_messages`, group summaries) and surface
+/// them as "typing" notifications from `listen_for_group_messages`, which the
+/// UI expires after a few seconds.
 #[frb]
 pub async fn send_typing_indicator(
     mls_group_id_hex: String,
 ) -> Result<String, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
 
         let rumor = EventBuilder::new(Kind::Custom(TYPING_INDICATOR_KIND), "typing")
-            .build(s.keys.public_key());
+            .build(s.signer.public_key());
 
         let event = s
             .mdk
@@ -374,7 +1196,7 @@ pub async fn send_poll(
     question: String,
     options: Vec<String>,
 ) -> Result<SendMessageResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -387,7 +1209,7 @@ pub async fn send_poll(
             );
         }
 
-        let rumor = builder.build(s.keys.public_key());
+        let rumor = builder.build(s.signer.public_key());
         let rumor_id = rumor.id
             .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
 
@@ -409,6 +1231,10 @@ pub async fn send_poll(
                 tags: msg.tags.iter().map(|t| t.as_slice().to_vec()).collect(),
                 wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
                 epoch: msg.epoch.unwrap_or(0),
+                edited_from: edit_target(&msg.tags),
+                reply_to_event_id_hex: None,
+                reply_to_author_hex: None,
+                mentions_me: false,
             },
         })
     })
@@ -425,7 +1251,7 @@ pub async fn send_poll_vote(
     poll_event_id_hex: String,
     option_index: u32,
 ) -> Result<SendMessageResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -435,7 +1261,7 @@ pub async fn send_poll_vote(
 
         let rumor = EventBuilder::new(Kind::Custom(POLL_VOTE_KIND), &option_index.to_string())
             .tag(Tag::event(poll_id))
-            .build(s.keys.public_key());
+            .build(s.signer.public_key());
 
         let rumor_id = rumor.id
             .ok_or_else(|| BurrowError::from("Rumor event ID not set".to_string()))?;
@@ -458,6 +1284,10 @@ pub async fn send_poll_vote(
                 tags: msg.tags.iter().map(|t| t.as_slice().to_vec()).collect(),
                 wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
                 epoch: msg.epoch.unwrap_or(0),
+                edited_from: edit_target(&msg.tags),
+                reply_to_event_id_hex: None,
+                reply_to_author_hex: None,
+                mentions_me: false,
             },
         })
     })
@@ -472,17 +1302,21 @@ pub async fn send_poll_vote(
 /// `event_json`: JSON-serialized kind 445 Event received from a relay.
 #[frb]
 pub async fn process_message(event_json: String) -> Result<ProcessMessageResult, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_mut(|s| {
         let event: Event =
             Event::from_json(&event_json).map_err(|e| BurrowError::from(e.to_string()))?;
 
-        let result = s
-            .mdk
-            .process_message(&event)
-            .map_err(BurrowError::from)?;
+        let (result, _) = process_with_history(s, &event)?;
 
         match result {
             mdk_core::messages::MessageProcessingResult::ApplicationMessage(msg) => {
+                let result_type = if msg.kind == Kind::Custom(TYPING_INDICATOR_KIND) {
+                    "typing"
+                } else {
+                    "application_message"
+                };
+                let (reply_to_event_id_hex, reply_to_author_hex) =
+                    reply_context(s, &msg.mls_group_id, msg.kind, &msg.tags);
                 let group_message = GroupMessage {
                     event_id_hex: msg.id.to_hex(),
                     author_pubkey_hex: msg.pubkey.to_hex(),
@@ -497,9 +1331,18 @@ pub async fn process_message(event_json: String) -> Result<ProcessMessageResult,
                         .collect(),
                     wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
                     epoch: msg.epoch.unwrap_or(0),
+                    edited_from: edit_target(&msg.tags),
+                    reply_to_event_id_hex,
+                    reply_to_author_hex,
+                    mentions_me: mentions_pubkey(
+                        &msg.pubkey,
+                        &msg.tags,
+                        &msg.content,
+                        &s.signer.public_key(),
+                    ),
                 };
                 Ok(ProcessMessageResult {
-                    result_type: "application_message".to_string(),
+                    result_type: result_type.to_string(),
                     message: Some(group_message),
                     mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
                     evolution_event_json: None,
@@ -571,53 +1414,363 @@ pub async fn process_message(event_json: String) -> Result<ProcessMessageResult,
 
 /// Get message history for a group with optional pagination.
 ///
-/// Returns messages ordered by creation time (descending).
+/// Returns messages ordered by creation time (descending). Messages whose
+/// NIP-40 `expiration` tag has passed are dropped unless `include_expired`
+/// is true (default false); purged messages are never returned. `offset`
+/// and `limit` count returned messages, so filtering never shortens a page
+/// that has more history behind it.
 #[frb]
 pub async fn get_messages(
     mls_group_id_hex: String,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_expired: Option<bool>,
 ) -> Result<Vec<GroupMessage>, BurrowError> {
-    state::with_state(|s| {
+    let include_expired = include_expired.unwrap_or(false);
+    let now = Timestamp::now().as_secs();
+    let skip = offset.unwrap_or(0) as usize;
+    let limit = limit.map(|l| l as usize);
+
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
-
-        let pagination = match (limit, offset) {
-            (Some(l), Some(o)) => {
-                Some(mdk_storage_traits::groups::Pagination::new(Some(l as usize), Some(o as usize)))
+        let my_pubkey = s.signer.public_key();
+
+        // MDK can only page over everything it stores, so page through it
+        // and apply offset and limit to what's left after filtering.
+        let mut messages = Vec::new();
+        let mut skipped = 0usize;
+        let mut raw_offset = 0usize;
+        loop {
+            let page = mdk_storage_traits::groups::Pagination::new(Some(SEARCH_PAGE_SIZE), Some(raw_offset));
+            let batch = s.mdk.get_messages(&group_id, Some(page)).map_err(BurrowError::from)?;
+            if batch.is_empty() {
+                break;
             }
-            (Some(l), None) => {
-                Some(mdk_storage_traits::groups::Pagination::new(Some(l as usize), Some(0)))
+            raw_offset += batch.len();
+            let visible = batch
+                .iter()
+                .filter(|msg| !is_erased(msg))
+                .filter(|msg| msg.kind != Kind::Custom(TYPING_INDICATOR_KIND))
+                .filter(|msg| include_expired || !stored_message_expired(msg, now));
+            for msg in visible {
+                if skipped < skip {
+                    skipped += 1;
+                    continue;
+                }
+                messages.push(stored_to_group_message(s, msg, &my_pubkey));
+                if limit.is_some_and(|l| messages.len() >= l) {
+                    return Ok(messages);
+                }
             }
-            _ => None,
+        }
+        Ok(messages)
+    })
+    .await
+}
+
+/// Flatten a message from MDK storage for FFI.
+fn stored_to_group_message(
+    s: &state::BurrowState,
+    msg: &mdk_storage_traits::messages::types::Message,
+    my_pubkey: &PublicKey,
+) -> GroupMessage {
+    let (reply_to_event_id_hex, reply_to_author_hex) =
+        reply_context(s, &msg.mls_group_id, msg.kind, &msg.tags);
+    GroupMessage {
+        event_id_hex: msg.id.to_hex(),
+        author_pubkey_hex: msg.pubkey.to_hex(),
+        content: msg.content.clone(),
+        created_at: msg.created_at.as_secs(),
+        mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
+        kind: msg.kind.as_u16() as u64,
+        tags: msg
+            .tags
+            .iter()
+            .map(|t| t.as_slice().to_vec())
+            .collect(),
+        wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
+        epoch: msg.epoch.unwrap_or(0),
+        edited_from: edit_target(&msg.tags),
+        reply_to_event_id_hex,
+        reply_to_author_hex,
+        mentions_me: mentions_pubkey(&msg.pubkey, &msg.tags, &msg.content, my_pubkey),
+    }
+}
+
+/// Page size used when scanning a group's whole history.
+const SEARCH_PAGE_SIZE: usize = 200;
+
+/// Search stored messages by content.
+///
+/// Case-insensitive substring match over one group (`mls_group_id_hex`) or,
+/// when None, every group. MDK storage has no search index, so this pages
+/// through the full history; expired, purged and typing messages are
+/// skipped. Results are newest first, capped at `limit` if given.
+#[frb]
+pub async fn search_messages(
+    mls_group_id_hex: Option<String>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<GroupMessage>, BurrowError> {
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let now = Timestamp::now().as_secs();
+
+    state::with_state_read(|s| {
+        let group_ids: Vec<GroupId> = match &mls_group_id_hex {
+            Some(hex_id) => vec![GroupId::from_slice(
+                &hex::decode(hex_id).map_err(|e| BurrowError::from(e.to_string()))?,
+            )],
+            None => s
+                .mdk
+                .get_groups()
+                .map_err(BurrowError::from)?
+                .into_iter()
+                .map(|g| g.mls_group_id)
+                .collect(),
         };
+        let my_pubkey = s.signer.public_key();
+
+        let mut candidates = Vec::new();
+        for group_id in &group_ids {
+            let mut offset = 0usize;
+            loop {
+                let page = mdk_storage_traits::groups::Pagination::new(Some(SEARCH_PAGE_SIZE), Some(offset));
+                let batch = s.mdk.get_messages(group_id, Some(page)).map_err(BurrowError::from)?;
+                if batch.is_empty() {
+                    break;
+                }
+                offset += batch.len();
+                candidates.extend(
+                    batch
+                        .iter()
+                        .filter(|msg| !is_erased(msg))
+                        .filter(|msg| msg.kind != Kind::Custom(TYPING_INDICATOR_KIND))
+                        .map(|msg| stored_to_group_message(s, msg, &my_pubkey))
+                        .filter(|m| !is_message_expired(m.tags.clone(), now)),
+                );
+            }
+        }
+        Ok(filter_messages_by_query(candidates, &query, limit))
+    })
+    .await
+}
 
-        let messages = s
-            .mdk
-            .get_messages(&group_id, pagination)
-            .map_err(BurrowError::from)?;
+/// Messages whose content contains `query` (case-insensitive), newest first,
+/// truncated to `limit`.
+#[frb(ignore)]
+pub fn filter_messages_by_query(
+    messages: Vec<GroupMessage>,
+    query: &str,
+    limit: Option<u32>,
+) -> Vec<GroupMessage> {
+    let needle = query.to_lowercase();
+    let mut matches: Vec<GroupMessage> = messages
+        .into_iter()
+        .filter(|m| m.content.to_lowercase().contains(&needle))
+        .collect();
+    matches.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    if let Some(limit) = limit {
+        matches.truncate(limit as usize);
+    }
+    matches
+}
 
-        Ok(messages
+/// Whether someone other than `me` authored a message that mentions `me`:
+/// a `p` tag with our pubkey, or our hex pubkey or npub (bare or as a
+/// `nostr:` URI) in the content.
+#[frb(ignore)]
+pub fn mentions_pubkey(author: &PublicKey, tags: &Tags, content: &str, me: &PublicKey) -> bool {
+    if author == me {
+        return false;
+    }
+    let hex = me.to_hex();
+    if tags.iter().any(|t| {
+        let t = t.as_slice();
+        t.first().map(|k| k == "p").unwrap_or(false) && t.get(1) == Some(&hex)
+    }) {
+        return true;
+    }
+    content.contains(&hex) || me.to_bech32().map(|npub| content.contains(&npub)).unwrap_or(false)
+}
+
+/// NIP-10 reply target in a rumor's tags: the `e` tag marked "reply", else
+/// a lone "root" marker (a direct reply to the thread root), else the last
+/// unmarked `e` tag (deprecated positional form).
+#[frb(ignore)]
+pub fn reply_target(tags: &Tags) -> Option<String> {
+    let e_tags: Vec<&[String]> = tags
+        .iter()
+        .map(|t| t.as_slice())
+        .filter(|t| t.first().map(|k| k == "e").unwrap_or(false) && t.len() >= 2)
+        .collect();
+    let marked = |marker: &str| {
+        e_tags
             .iter()
-            .map(|msg| GroupMessage {
-                event_id_hex: msg.id.to_hex(),
-                author_pubkey_hex: msg.pubkey.to_hex(),
-                content: msg.content.clone(),
-                created_at: msg.created_at.as_secs(),
-                mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
-                kind: msg.kind.as_u16() as u64,
-                tags: msg
-                    .tags
-                    .iter()
-                    .map(|t| t.as_slice().to_vec())
-                    .collect(),
-                wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
-                epoch: msg.epoch.unwrap_or(0),
-            })
-            .collect())
+            .find(|t| t.get(3).map(|m| m == marker).unwrap_or(false))
+            .map(|t| t[1].clone())
+    };
+    marked("reply").or_else(|| marked("root")).or_else(|| {
+        e_tags
+            .iter()
+            .rev()
+            .find(|t| t.get(3).map(|m| m.is_empty()).unwrap_or(true))
+            .map(|t| t[1].clone())
     })
-    .await
+}
+
+/// `(reply_to_event_id_hex, reply_to_author_hex)` for a stored message.
+///
+/// Only text notes are replies; reactions and receipts also carry `e` tags.
+fn reply_context(
+    s: &state::BurrowState,
+    group_id: &GroupId,
+    kind: Kind,
+    tags: &Tags,
+) -> (Option<String>, Option<String>) {
+    if kind != Kind::TextNote {
+        return (None, None);
+    }
+    let Some(target) = reply_target(tags) else {
+        return (None, None);
+    };
+    let author = EventId::from_hex(&target)
+        .ok()
+        .and_then(|id| s.mdk.get_message(group_id, &id).ok().flatten())
+        .map(|parent| parent.pubkey.to_hex())
+        .or_else(|| tags.public_keys().next().map(|pk| pk.to_hex()));
+    (Some(target), author)
+}
+
+/// Target of an `["e", <id>, <relay>, "edit"]` tag, if the message is an edit.
+#[frb(ignore)]
+pub fn edit_target(tags: &Tags) -> Option<String> {
+    tags.iter().find_map(|t| {
+        let t = t.as_slice();
+        let is_edit = t.first().map(|k| k == "e").unwrap_or(false)
+            && t.get(3).map(|m| m == "edit").unwrap_or(false);
+        if is_edit {
+            t.get(1).cloned()
+        } else {
+            None
+        }
+    })
+}
+
+/// Whether a message's NIP-40 `expiration` tag is at or before `now`.
+///
+/// Messages without a (parseable) expiration tag never expire.
+#[frb(sync)]
+pub fn is_message_expired(tags: Vec<Vec<String>>, now: u64) -> bool {
+    message_expiration(&tags).is_some_and(|exp| exp <= now)
+}
+
+/// NIP-40 expiration tag for an outgoing message, if it should have one.
+///
+/// `ttl_override` wins over the group default; a TTL of 0 means no expiration.
+fn expiration_tag(mls_group_id_hex: &str, ttl_override: Option<u64>) -> Option<Tag> {
+    let ttl = ttl_override.or_else(|| app_state::group_message_ttl(mls_group_id_hex))?;
+    (ttl > 0).then(|| Tag::expiration(Timestamp::from(Timestamp::now().as_secs() + ttl)))
+}
+
+fn message_expiration(tags: &[Vec<String>]) -> Option<u64> {
+    tags.iter()
+        .find(|t| t.first().map(String::as_str) == Some("expiration"))
+        .and_then(|t| t.get(1))
+        .and_then(|v| v.parse::<u64>().ok())
+}
+
+/// Whether a stored message's NIP-40 expiration has passed at `now`.
+fn stored_message_expired(msg: &mdk_storage_traits::messages::types::Message, now: u64) -> bool {
+    let tags: Vec<Vec<String>> = msg.tags.iter().map(|t| t.as_slice().to_vec()).collect();
+    is_message_expired(tags, now)
+}
+
+/// Every message MDK stores for a group, newest first.
+///
+/// `get_messages` without pagination only returns MDK's default page (the
+/// newest ~1000), so anything that has to see the whole history, like
+/// erasing or aggregating, pages through it here.
+pub(crate) fn all_stored_messages(
+    s: &state::BurrowState,
+    group_id: &GroupId,
+) -> Result<Vec<mdk_storage_traits::messages::types::Message>, BurrowError> {
+    let mut messages = Vec::new();
+    loop {
+        let page = mdk_storage_traits::groups::Pagination::new(Some(SEARCH_PAGE_SIZE), Some(messages.len()));
+        let batch = s.mdk.get_messages(group_id, Some(page)).map_err(BurrowError::from)?;
+        let last_page = batch.len() < SEARCH_PAGE_SIZE;
+        messages.extend(batch);
+        if last_page {
+            return Ok(messages);
+        }
+    }
+}
+
+/// Whether a message was erased by `erase_messages`.
+pub(crate) fn is_erased(msg: &mdk_storage_traits::messages::types::Message) -> bool {
+    matches!(msg.state, mdk_storage_traits::messages::types::MessageState::Deleted)
+}
+
+/// Delete messages' content from MDK storage.
+///
+/// MDK has no per-message delete, so each message is overwritten with its
+/// content and tags cleared and marked `Deleted`; reads skip it from then
+/// on. Returns how many were erased.
+#[frb(ignore)]
+pub(crate) fn erase_messages(
+    pubkey: &PublicKey,
+    messages: Vec<mdk_storage_traits::messages::types::Message>,
+) -> Result<u32, BurrowError> {
+    use mdk_storage_traits::messages::MessageStorage;
+
+    if messages.is_empty() {
+        return Ok(0);
+    }
+    let storage = state::open_mdk_storage(pubkey)?;
+    let mut erased = 0u32;
+    for mut msg in messages {
+        msg.content.clear();
+        msg.tags = Tags::new();
+        msg.event.content.clear();
+        msg.event.tags = Tags::new();
+        msg.state = mdk_storage_traits::messages::types::MessageState::Deleted;
+        storage
+            .save_message(msg)
+            .map_err(|e| BurrowError::from(format!("Failed to erase message: {e}")))?;
+        erased += 1;
+    }
+    Ok(erased)
+}
+
+/// Purge expired messages from local storage across all groups.
+///
+/// Gives disappearing-message semantics locally even if a relay keeps
+/// serving the event: the plaintext is erased from MDK storage (see
+/// `erase_messages`) and never returned by `get_messages`. Call
+/// periodically from the app (e.g. on resume and on a timer). Returns the
+/// number of newly purged messages.
+#[frb]
+pub async fn purge_expired_messages() -> Result<u32, BurrowError> {
+    let now = Timestamp::now().as_secs();
+    let (pubkey, expired) = state::with_state_read(|s| {
+        let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
+        let mut expired = Vec::new();
+        for group in &groups {
+            expired.extend(
+                all_stored_messages(s, &group.mls_group_id)?
+                    .into_iter()
+                    .filter(|msg| !is_erased(msg) && stored_message_expired(msg, now)),
+            );
+        }
+        Ok((s.signer.public_key(), expired))
+    })
+    .await?;
+
+    erase_messages(&pubkey, expired)
 }
 
 /// Get a specific message by its event ID within a group.
@@ -626,7 +1779,7 @@ pub async fn get_message(
     mls_group_id_hex: String,
     event_id_hex: String,
 ) -> Result<GroupMessage, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -639,6 +1792,8 @@ pub async fn get_message(
             .map_err(BurrowError::from)?
             .ok_or_else(|| BurrowError::from("Message not found".to_string()))?;
 
+        let (reply_to_event_id_hex, reply_to_author_hex) =
+            reply_context(s, &msg.mls_group_id, msg.kind, &msg.tags);
         Ok(GroupMessage {
             event_id_hex: msg.id.to_hex(),
             author_pubkey_hex: msg.pubkey.to_hex(),
@@ -653,6 +1808,15 @@ pub async fn get_message(
                 .collect(),
             wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
             epoch: msg.epoch.unwrap_or(0),
+            edited_from: edit_target(&msg.tags),
+            reply_to_event_id_hex,
+            reply_to_author_hex,
+            mentions_me: mentions_pubkey(
+                &msg.pubkey,
+                &msg.tags,
+                &msg.content,
+                &s.signer.public_key(),
+            ),
         })
     })
     .await
@@ -664,7 +1828,7 @@ pub async fn get_message(
 /// Use this with the Nostr client to subscribe to real-time group messages.
 #[frb]
 pub async fn group_message_filter(mls_group_id_hex: String) -> Result<String, BurrowError> {
-    state::with_state(|s| {
+    state::with_state_read(|s| {
         let group_id = GroupId::from_slice(
             &hex::decode(&mls_group_id_hex).map_err(|e| BurrowError::from(e.to_string()))?,
         );
@@ -691,14 +1855,17 @@ pub async fn group_message_filter(mls_group_id_hex: String) -> Result<String, Bu
 /// For each group, queries relays for kind 445 events and processes them
 /// through MDK's `process_message`. Returns the count of new messages found.
 /// Call this on app startup before `listen_for_group_messages` to catch
-/// messages sent while the app was offline.
+/// messages sent while the app was offline. Flushes the outbox afterwards.
 #[frb]
 pub async fn sync_group_messages() -> Result<u32, BurrowError> {
-    let (client, groups) = state::with_state(|s| {
+    let (client, groups, group_relays) = state::with_state_read(|s| {
         let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
-        Ok((s.client.clone(), groups))
+        let group_relays = all_group_relays(s, &groups);
+        Ok((s.client.clone(), groups, group_relays))
     })
     .await?;
+    // Group relays serve group traffic whatever the read/write roles are.
+    relay::ensure_group_relays(&client, &group_relays).await;
 
     if groups.is_empty() {
         return Ok(0);
@@ -716,15 +1883,20 @@ pub async fn sync_group_messages() -> Result<u32, BurrowError> {
             )
             .limit(100);
 
+        let permit = state::relay_permit().await;
         let events = client
             .fetch_events(filter, std::time::Duration::from_secs(10))
             .await
-            .map_err(|e| BurrowError::from(e.to_string()))?;
+            .map_err(|e| {
+                diagnostics::record_error("message_sync", &e.to_string());
+                BurrowError::from(e.to_string())
+            })?;
+        drop(permit);
 
         // Process each event through MDK (sorts by timestamp internally)
         for event in events.iter() {
-            let result = state::with_state(|s| {
-                s.mdk.process_message(event).map_err(BurrowError::from)
+            let result = state::with_state_mut(|s| {
+                process_with_history(s, event).map(|(result, _)| result)
             })
             .await;
 
@@ -736,6 +1908,11 @@ pub async fn sync_group_messages() -> Result<u32, BurrowError> {
         }
     }
 
+    // Now on the latest epoch: send anything queued while offline.
+    if let Err(e) = flush_outbox().await {
+        diagnostics::record_error("outbox_flush", &e.message);
+    }
+
     Ok(new_message_count)
 }
 
@@ -753,11 +1930,14 @@ pub async fn sync_group_messages() -> Result<u32, BurrowError> {
 pub async fn listen_for_group_messages(
     sink: StreamSink<GroupNotification>,
 ) -> Result<(), BurrowError> {
-    let (client, groups) = state::with_state(|s| {
+    let (client, groups, group_relays, my_pubkey) = state::with_state_read(|s| {
         let groups = s.mdk.get_groups().map_err(BurrowError::from)?;
-        Ok((s.client.clone(), groups))
+        let group_relays = all_group_relays(s, &groups);
+        Ok((s.client.clone(), groups, group_relays, s.signer.public_key()))
     })
     .await?;
+    // Group relays serve group traffic whatever the read/write roles are.
+    relay::ensure_group_relays(&client, &group_relays).await;
 
     if groups.is_empty() {
         // No groups — still listen so the stream stays open; will get no events.
@@ -792,24 +1972,50 @@ pub async fn listen_for_group_messages(
     client
         .handle_notifications(|notification| {
             let sink = &sink;
+            let my_pubkey = &my_pubkey;
             async move {
                 if let nostr_sdk::RelayPoolNotification::Event { event, .. } = notification {
                     if event.kind == Kind::MlsGroupMessage {
                         let event_json = event.as_json();
-                        // Process through MDK (decrypt NIP-44 + MLS)
-                        let result = state::with_state(|s| {
+                        // Process through MDK (decrypt NIP-44 + MLS), snapshotting
+                        // the group on both sides of a commit.
+                        let mut membership = None;
+                        let result = state::with_state_mut(|s| {
                             let evt: Event = Event::from_json(&event_json)
                                 .map_err(|e| BurrowError::from(e.to_string()))?;
-                            s.mdk
-                                .process_message(&evt)
-                                .map_err(BurrowError::from)
+                            let (result, diff) = process_with_history(s, &evt)?;
+                            membership = diff;
+                            Ok(result)
                         })
                         .await;
 
                         match result {
+                            Ok(mdk_core::messages::MessageProcessingResult::ApplicationMessage(
+                                msg,
+                            )) if msg.kind == Kind::Custom(TYPING_INDICATOR_KIND) => {
+                                // Transient: forwarded but never shown as a message
+                                if msg.pubkey != *my_pubkey {
+                                    let _ = sink.add(GroupNotification {
+                                        notification_type: "typing".to_string(),
+                                        message: None,
+                                        mls_group_id_hex: hex::encode(
+                                            msg.mls_group_id.as_slice(),
+                                        ),
+                                        added_pubkeys: Vec::new(),
+                                        removed_pubkeys: Vec::new(),
+                                        sender_pubkey_hex: Some(msg.pubkey.to_hex()),
+                                    });
+                                }
+                            }
                             Ok(mdk_core::messages::MessageProcessingResult::ApplicationMessage(
                                 msg,
                             )) => {
+                                let (reply_to_event_id_hex, reply_to_author_hex) =
+                                    state::with_state_read(|s| {
+                                        Ok(reply_context(s, &msg.mls_group_id, msg.kind, &msg.tags))
+                                    })
+                                    .await
+                                    .unwrap_or_default();
                                 let group_message = GroupMessage {
                                     event_id_hex: msg.id.to_hex(),
                                     author_pubkey_hex: msg.pubkey.to_hex(),
@@ -826,6 +2032,15 @@ pub async fn listen_for_group_messages(
                                         .collect(),
                                     wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
                                     epoch: msg.epoch.unwrap_or(0),
+                                    edited_from: edit_target(&msg.tags),
+                                    reply_to_event_id_hex,
+                                    reply_to_author_hex,
+                                    mentions_me: mentions_pubkey(
+                                        &msg.pubkey,
+                                        &msg.tags,
+                                        &msg.content,
+                                        my_pubkey,
+                                    ),
                                 };
                                 let _ = sink.add(GroupNotification {
                                     notification_type: "application_message".to_string(),
@@ -833,6 +2048,9 @@ pub async fn listen_for_group_messages(
                                     mls_group_id_hex: hex::encode(
                                         msg.mls_group_id.as_slice(),
                                     ),
+                                    added_pubkeys: Vec::new(),
+                                    removed_pubkeys: Vec::new(),
+                                    sender_pubkey_hex: None,
                                 });
                             }
                             Ok(mdk_core::messages::MessageProcessingResult::Commit {
@@ -843,7 +2061,23 @@ pub async fn listen_for_group_messages(
                                     notification_type: "commit".to_string(),
                                     message: None,
                                     mls_group_id_hex: hex::encode(mls_group_id.as_slice()),
+                                    added_pubkeys: Vec::new(),
+                                    removed_pubkeys: Vec::new(),
+                                    sender_pubkey_hex: None,
                                 });
+                                // Who joined or left, for "Alice joined" system messages
+                                if let Some((added, removed)) = membership {
+                                    if !added.is_empty() || !removed.is_empty() {
+                                        let _ = sink.add(GroupNotification {
+                                            notification_type: "membership_change".to_string(),
+                                            message: None,
+                                            mls_group_id_hex: hex::encode(mls_group_id.as_slice()),
+                                            added_pubkeys: added,
+                                            removed_pubkeys: removed,
+                                            sender_pubkey_hex: None,
+                                        });
+                                    }
+                                }
                             }
                             Ok(mdk_core::messages::MessageProcessingResult::Proposal(
                                 update_result,
@@ -855,10 +2089,21 @@ pub async fn listen_for_group_messages(
                                     mls_group_id_hex: hex::encode(
                                         update_result.mls_group_id.as_slice(),
                                     ),
+                                    added_pubkeys: Vec::new(),
+                                    removed_pubkeys: Vec::new(),
+                                    sender_pubkey_hex: None,
                                 });
                             }
+                            Err(e) => {
+                                diagnostics::record_error("message_listener", &e.message);
+                            }
                             _ => {
-                                // Other results (pending proposals, unprocessable, etc.)
+                                // Other results (pending proposals, unprocessable, etc.).
+                                // Unprocessable carries only the group ID, not the
+                                // rumor, so a typing signal that fails to decrypt
+                                // (e.g. our own echo, or one from a stale epoch)
+                                // can't be told apart from any other message and
+                                // is dropped; the indicator simply doesn't show.
                             }
                         }
                     }
@@ -871,3 +2116,159 @@ pub async fn listen_for_group_messages(
 
     Ok(())
 }
+
+/// Distinct relay URLs across all of `groups`.
+fn all_group_relays(s: &state::BurrowState, groups: &[group_types::Group]) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for group in groups {
+        for url in s.mdk.get_relays(&group.mls_group_id).unwrap_or_default() {
+            let url = url.to_string();
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+    }
+    urls
+}
+
+/// MLS group ID of the group a kind 445 event is addressed to, found via
+/// its `h` tag. None if the group isn't one of ours.
+fn group_id_for_event(mdk: &MDK<state::MdkSqliteStorage>, event: &Event) -> Option<GroupId> {
+    let nostr_group_id = event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .find(|t| t.first().map(|k| k == "h").unwrap_or(false))
+        .and_then(|t| t.get(1).cloned())?;
+    let group = mdk
+        .get_groups()
+        .ok()?
+        .into_iter()
+        .find(|g| hex::encode(g.nostr_group_id) == nostr_group_id)?;
+    Some(group.mls_group_id)
+}
+
+/// Run a kind 445 event through MDK and, if it was a commit, record the
+/// epoch change. Returns the result plus the (added, removed) member diff
+/// for commits.
+fn process_with_history(
+    s: &state::BurrowState,
+    event: &Event,
+) -> Result<
+    (
+        mdk_core::messages::MessageProcessingResult,
+        Option<(Vec<String>, Vec<String>)>,
+    ),
+    BurrowError,
+> {
+    let before = group_id_for_event(&s.mdk, event)
+        .and_then(|group_id| group::snapshot_group(s, &group_id));
+    let result = s.mdk.process_message(event).map_err(BurrowError::from)?;
+    let diff = match (&result, before) {
+        (mdk_core::messages::MessageProcessingResult::Commit { mls_group_id }, Some(before)) => {
+            group::record_epoch_change(s, mls_group_id, &before, None, Some(&event.id))
+        }
+        _ => None,
+    };
+    Ok((result, diff))
+}
+
+/// Diff two member sets into (added, removed) hex pubkeys, each sorted.
+#[frb(ignore)]
+pub fn diff_member_sets(
+    before: &std::collections::BTreeSet<PublicKey>,
+    after: &std::collections::BTreeSet<PublicKey>,
+) -> (Vec<String>, Vec<String>) {
+    let added = after.difference(before).map(|pk| pk.to_hex()).collect();
+    let removed = before.difference(after).map(|pk| pk.to_hex()).collect();
+    (added, removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::group;
+
+    #[tokio::test]
+    async fn outbox_entry_is_reencrypted_after_an_epoch_change() {
+        let _lock = state::TEST_STATE_LOCK.lock().await;
+        state::init_test_state().await;
+
+        let created = group::create_group(
+            "Outbox".into(),
+            String::new(),
+            vec![],
+            vec![],
+            vec!["wss://relay.example.com".into()],
+        )
+        .await
+        .unwrap();
+        let id = created.mls_group_id_hex;
+        let sent = send_message(id.clone(), "queued while offline".into(), Some(0)).await.unwrap();
+        let queued = app_state::outbox_pending();
+        assert_eq!(queued.len(), 1);
+        let sent_event = Event::from_json(&sent.event_json).unwrap();
+        assert_eq!(queued[0].wrapper_event_id_hex, sent_event.id.to_hex());
+
+        // Same epoch: the queued ciphertext goes out as is.
+        let (event, reencrypted) = state::with_state_mut(|s| prepare_outbox_entry(s, &queued[0]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!reencrypted);
+        assert_eq!(event.id, sent_event.id);
+
+        group::update_group_name(id.clone(), "Outbox 2".into()).await.unwrap();
+        group::merge_pending_commit(id.clone()).await.unwrap();
+        let epoch = group::get_group(id.clone()).await.unwrap().epoch;
+        assert!(epoch > queued[0].epoch);
+
+        let (event, reencrypted) = state::with_state_mut(|s| prepare_outbox_entry(s, &queued[0]))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(reencrypted);
+        assert_ne!(event.id, sent_event.id);
+        let requeued = app_state::outbox_pending();
+        assert_eq!(requeued.len(), 1);
+        assert_eq!(requeued[0].wrapper_event_id_hex, event.id.to_hex());
+        assert_eq!(requeued[0].rumor_id_hex, queued[0].rumor_id_hex);
+        assert_eq!(requeued[0].epoch, epoch);
+
+        state::destroy_state().await;
+    }
+
+    #[tokio::test]
+    async fn deletion_is_queued_in_the_outbox() {
+        let _lock = state::TEST_STATE_LOCK.lock().await;
+        state::init_test_state().await;
+
+        let created = group::create_group(
+            "Outbox".into(),
+            String::new(),
+            vec![],
+            vec![],
+            vec!["wss://relay.example.com".into()],
+        )
+        .await
+        .unwrap();
+        let id = created.mls_group_id_hex;
+        let sent = send_message(id.clone(), "oops".into(), Some(0)).await.unwrap();
+        let target = sent.message.event_id_hex;
+
+        let deleted = delete_message(id.clone(), target.clone()).await.unwrap();
+        assert_eq!(deleted.message.kind, 5);
+        assert!(deleted.message.tags.contains(&vec!["e".to_string(), target]));
+        assert!(deleted.message.tags.contains(&vec!["k".to_string(), "1".to_string()]));
+
+        let wrapper = Event::from_json(&deleted.event_json).unwrap().id.to_hex();
+        let queued = app_state::outbox_pending();
+        assert_eq!(queued.len(), 2);
+        assert!(queued.iter().any(|e| e.wrapper_event_id_hex == wrapper));
+
+        let missing = delete_message(id, "ab".repeat(32)).await.unwrap_err();
+        assert!(missing.message.contains("not found"));
+
+        state::destroy_state().await;
+    }
+}
@@ -1,6 +1,7 @@
 pub mod simple;
 pub mod error;
 pub mod state;
+pub mod signer;
 pub mod app_state;
 pub mod account;
 pub mod identity;
@@ -18,3 +19,6 @@ pub mod call_quality;
 pub mod transcription;
 pub mod meeting_intelligence;
 pub mod link_preview;
+pub mod diagnostics;
+pub mod ephemeral;
+pub mod backup;
@@ -1,8 +1,15 @@
 //! Relay management: connect to Nostr relays, publish and subscribe to events.
+//!
+//! Relays can be given NIP-65 roles (outbox model): general publishes go to
+//! write relays and subscriptions/fetches to read relays. Group messages
+//! (kind 445) always use the group's own relays from the MLS extension.
 
 use flutter_rust_bridge::frb;
+use mdk_core::prelude::*;
 use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::api::app_state;
 use crate::api::error::BurrowError;
 use crate::api::state;
 
@@ -12,23 +19,191 @@ use crate::api::state;
 pub struct RelayInfo {
     pub url: String,
     pub connected: bool,
+    /// Used for subscriptions and fetches.
+    pub read: bool,
+    /// Used for publishing.
+    pub write: bool,
 }
 
-/// Add a relay and connect to it.
+/// Read/write relay roles. A relay listed in both does both; relays in
+/// neither list keep the default (read and write).
+#[frb(ignore)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelayRoles {
+    #[serde(default)]
+    pub read: Vec<String>,
+    #[serde(default)]
+    pub write: Vec<String>,
+}
+
+impl RelayRoles {
+    /// Service flags for `url`, or None if the roles don't mention it.
+    fn flags_for(&self, url: &str) -> Option<RelayServiceFlags> {
+        let matches = |list: &[String]| {
+            list.iter()
+                .any(|u| u.trim_end_matches('/') == url.trim_end_matches('/'))
+        };
+        match (matches(&self.read), matches(&self.write)) {
+            (false, false) => None,
+            (true, false) => Some(RelayServiceFlags::READ),
+            (false, true) => Some(RelayServiceFlags::WRITE),
+            (true, true) => Some(RelayServiceFlags::READ | RelayServiceFlags::WRITE),
+        }
+    }
+
+    fn urls(&self) -> Vec<String> {
+        let mut urls: Vec<String> = Vec::new();
+        for url in self.read.iter().chain(&self.write) {
+            if !urls.contains(url) {
+                urls.push(url.clone());
+            }
+        }
+        urls
+    }
+}
+
+/// Parse a `set_relay_roles` payload: `{"read": [urls], "write": [urls]}`.
+#[frb(ignore)]
+pub fn parse_relay_roles(json: &str) -> Result<RelayRoles, BurrowError> {
+    let roles: RelayRoles = serde_json::from_str(json)
+        .map_err(|e| BurrowError::from(format!("Invalid relay roles: {e}")))?;
+    for url in roles.read.iter().chain(&roles.write) {
+        RelayUrl::parse(url).map_err(|e| BurrowError::from(format!("Invalid relay URL {url}: {e}")))?;
+    }
+    Ok(roles)
+}
+
+/// Configure read/write relay roles (NIP-65 outbox model).
+///
+/// `roles_json` is `{"read": [urls], "write": [urls]}`. Listed relays are
+/// added if missing and their pool flags updated: sends, welcomes and key
+/// packages then go only to write relays, and subscriptions and fetches only
+/// to read relays. Relays dropped from the roles go back to read+write.
+/// Pass `{}` to clear. Roles are kept in memory for the session.
 #[frb]
-pub async fn add_relay(url: String) -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
-    client
-        .add_relay(&url)
+pub async fn set_relay_roles(roles_json: String) -> Result<(), BurrowError> {
+    let roles = parse_relay_roles(&roles_json)?;
+    let (client, previous) = state::with_state_mut(|s| {
+        let previous = std::mem::replace(&mut s.relay_roles, roles.clone());
+        Ok((s.client.clone(), previous))
+    })
+    .await?;
+
+    for url in previous.urls() {
+        if roles.flags_for(&url).is_none() {
+            if let Ok(relay) = client.relay(&url).await {
+                relay
+                    .flags()
+                    .add(RelayServiceFlags::READ | RelayServiceFlags::WRITE);
+            }
+        }
+    }
+    for url in roles.urls() {
+        if let Some(flags) = roles.flags_for(&url) {
+            apply_relay_flags(&client, &url, flags).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Current relay roles as JSON (`{"read": [...], "write": [...]}`).
+#[frb]
+pub async fn get_relay_roles() -> Result<String, BurrowError> {
+    let roles = state::with_state_read(|s| Ok(s.relay_roles.clone())).await?;
+    serde_json::to_string(&roles).map_err(|e| BurrowError::from(e.to_string()))
+}
+
+/// Add `url` to the pool if needed and set exactly `flags` for its
+/// read/write roles, then connect.
+async fn apply_relay_flags(
+    client: &Client,
+    url: &str,
+    flags: RelayServiceFlags,
+) -> Result<(), BurrowError> {
+    match client.relay(url).await {
+        Ok(relay) => {
+            relay
+                .flags()
+                .remove(RelayServiceFlags::READ | RelayServiceFlags::WRITE);
+            relay.flags().add(flags);
+        }
+        Err(_) => {
+            let added = if flags == RelayServiceFlags::READ {
+                client.add_read_relay(url).await
+            } else if flags == RelayServiceFlags::WRITE {
+                client.add_write_relay(url).await
+            } else {
+                client.add_relay(url).await
+            };
+            added.map_err(|e| BurrowError::from(e.to_string()))?;
+        }
+    }
+    let _ = client.connect_relay(url).await;
+    Ok(())
+}
+
+/// Make sure a group's relays are in the pool. Missing relays are added
+/// with both roles; relays already in the pool keep the read/write roles
+/// they were given. Returns the relays that are usable.
+#[frb(ignore)]
+pub async fn ensure_group_relays(client: &Client, urls: &[String]) -> Vec<RelayUrl> {
+    let mut usable = Vec::new();
+    for url in urls {
+        let Ok(parsed) = RelayUrl::parse(url) else {
+            continue;
+        };
+        if client.relay(url).await.is_err() && client.add_relay(url).await.is_err() {
+            continue;
+        }
+        let _ = client.connect_relay(url).await;
+        usable.push(parsed);
+    }
+    usable
+}
+
+/// Send `event` to the given relays only. Succeeds if at least one relay
+/// accepted it.
+#[frb(ignore)]
+pub(crate) async fn send_event_to_relays(
+    client: &Client,
+    event: &Event,
+    relays: Vec<RelayUrl>,
+) -> Result<String, BurrowError> {
+    if relays.is_empty() {
+        return Err(BurrowError::from("No valid relays to publish to".to_string()));
+    }
+    let _permit = state::relay_permit().await;
+    let output = client
+        .send_event_to(relays, event)
         .await
-        .map(|_| ())
-        .map_err(|e| BurrowError::from(e.to_string()))
+        .map_err(|e| BurrowError::from(e.to_string()))?;
+    if output.success.is_empty() {
+        return Err(BurrowError::from(
+            "Event was rejected by every relay".to_string(),
+        ));
+    }
+    Ok(output.id().to_hex())
+}
+
+/// Add a relay and connect to it. Honors the configured relay roles.
+#[frb]
+pub async fn add_relay(url: String) -> Result<(), BurrowError> {
+    let (client, flags) =
+        state::with_state_read(|s| Ok((s.client.clone(), s.relay_roles.flags_for(&url)))).await?;
+    match flags {
+        Some(flags) => apply_relay_flags(&client, &url, flags).await,
+        None => client
+            .add_relay(&url)
+            .await
+            .map(|_| ())
+            .map_err(|e| BurrowError::from(e.to_string())),
+    }
 }
 
 /// Remove a relay.
 #[frb]
 pub async fn remove_relay(url: String) -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     client
         .remove_relay(&url)
         .await
@@ -38,7 +213,7 @@ pub async fn remove_relay(url: String) -> Result<(), BurrowError> {
 /// Connect to all added relays.
 #[frb]
 pub async fn connect_relays() -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     client.connect().await;
     Ok(())
 }
@@ -46,7 +221,7 @@ pub async fn connect_relays() -> Result<(), BurrowError> {
 /// Disconnect from all relays.
 #[frb]
 pub async fn disconnect_relays() -> Result<(), BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     client.disconnect().await;
     Ok(())
 }
@@ -54,29 +229,64 @@ pub async fn disconnect_relays() -> Result<(), BurrowError> {
 /// List all configured relays and their connection status.
 #[frb]
 pub async fn list_relays() -> Result<Vec<RelayInfo>, BurrowError> {
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
     let relays = client.relays().await;
     Ok(relays
         .iter()
         .map(|(url, relay)| RelayInfo {
             url: url.to_string(),
             connected: relay.is_connected(),
+            read: relay.flags().has_read(),
+            write: relay.flags().has_write(),
         })
         .collect())
 }
 
 /// Publish a signed event to connected relays.
 /// Takes a JSON-serialized Nostr event string.
+///
+/// Group messages (kind 445) go to the group's relays; everything else goes
+/// to the write relays.
+///
+/// A queued send is removed from the outbox once a relay accepts it.
 #[frb]
 pub async fn publish_event_json(event_json: String) -> Result<String, BurrowError> {
     let event: Event =
         serde_json::from_str(&event_json).map_err(|e| BurrowError::from(e.to_string()))?;
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
-    let output = client
-        .send_event(&event)
-        .await
-        .map_err(|e| BurrowError::from(e.to_string()))?;
-    Ok(output.id().to_hex())
+    publish_event(&event).await
+}
+
+/// Publish with the same routing as `publish_event_json` and ack the outbox.
+#[frb(ignore)]
+pub(crate) async fn publish_event(event: &Event) -> Result<String, BurrowError> {
+    let (client, group_relays) = state::with_state_read(|s| {
+        let group_relays = if event.kind == Kind::MlsGroupMessage {
+            group_relays_for_event(s, event)
+        } else {
+            Vec::new()
+        };
+        Ok((s.client.clone(), group_relays))
+    })
+    .await?;
+
+    let id = if !group_relays.is_empty() {
+        let relays = ensure_group_relays(&client, &group_relays).await;
+        send_event_to_relays(&client, event, relays).await?
+    } else {
+        let _permit = state::relay_permit().await;
+        let output = client
+            .send_event(event)
+            .await
+            .map_err(|e| BurrowError::from(e.to_string()))?;
+        if output.success.is_empty() {
+            return Err(BurrowError::from(
+                "Event was rejected by every relay".to_string(),
+            ));
+        }
+        output.id().to_hex()
+    };
+    let _ = app_state::outbox_remove(&id);
+    Ok(id)
 }
 
 /// Verify that an event has been published to at least one relay.
@@ -85,7 +295,7 @@ pub async fn publish_event_json(event_json: String) -> Result<String, BurrowErro
 pub async fn verify_event_published(event_id_hex: String) -> Result<bool, BurrowError> {
     let event_id =
         EventId::from_hex(&event_id_hex).map_err(|e| BurrowError::from(e.to_string()))?;
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     let filter = Filter::new().id(event_id).limit(1);
 
@@ -106,7 +316,7 @@ pub async fn publish_event_json_to_relay(
 ) -> Result<String, BurrowError> {
     let event: Event =
         serde_json::from_str(&event_json).map_err(|e| BurrowError::from(e.to_string()))?;
-    let client = state::with_state(|s| Ok(s.client.clone())).await?;
+    let client = state::with_state_read(|s| Ok(s.client.clone())).await?;
 
     let relay_url_parsed: Url =
         Url::parse(&relay_url).map_err(|e| BurrowError::from(e.to_string()))?;
@@ -117,6 +327,28 @@ pub async fn publish_event_json_to_relay(
     Ok(output.id().to_hex())
 }
 
+/// Relays of the group a kind 445 event belongs to, found via its `h` tag.
+fn group_relays_for_event(s: &state::BurrowState, event: &Event) -> Vec<String> {
+    let Some(nostr_group_id) = event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .find(|t| t.first().map(|k| k == "h").unwrap_or(false))
+        .and_then(|t| t.get(1).cloned())
+    else {
+        return Vec::new();
+    };
+    let Ok(groups) = s.mdk.get_groups() else {
+        return Vec::new();
+    };
+    groups
+        .iter()
+        .find(|g| hex::encode(g.nostr_group_id) == nostr_group_id)
+        .and_then(|g| s.mdk.get_relays(&g.mls_group_id).ok())
+        .map(|relays| relays.iter().map(|r| r.to_string()).collect())
+        .unwrap_or_default()
+}
+
 /// Default relays for the Marmot/Burrow network.
 #[frb(sync)]
 pub fn default_relay_urls() -> Vec<String> {
@@ -0,0 +1,124 @@
+//! Event signing behind a pluggable signer.
+//!
+//! Everything that signs or NIP-44 encrypts as the user goes through
+//! `BurrowSigner`, which wraps either the local `Keys` or a NIP-46 remote
+//! signer ("bunker"). MLS itself never needs the Nostr secret key: MDK only
+//! uses our public key for credentials, and gift wraps are unwrapped by the
+//! client's signer, so a bunker account can do everything except export its
+//! nsec or a state backup.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+use nostr_connect::prelude::NostrConnect;
+use nostr_sdk::prelude::*;
+
+use crate::api::error::BurrowError;
+
+/// How long to wait for a bunker to answer a single request.
+const NIP46_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Which kind of signer the current account uses.
+#[frb(non_opaque)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerKind {
+    /// The secret key is held in memory by this app.
+    Local,
+    /// Signing is proxied to a NIP-46 bunker; the secret key never leaves it.
+    Nip46,
+}
+
+/// The signer for the logged-in account.
+#[frb(ignore)]
+#[derive(Clone)]
+pub struct BurrowSigner {
+    public_key: PublicKey,
+    /// Present only for local accounts.
+    keys: Option<Keys>,
+    /// Client keys and bunker URI of a NIP-46 session, for persisting it.
+    nip46: Option<(Keys, String)>,
+    inner: Arc<dyn NostrSigner>,
+}
+
+impl BurrowSigner {
+    /// Sign with a secret key held in memory.
+    pub fn local(keys: Keys) -> Self {
+        Self {
+            public_key: keys.public_key(),
+            inner: Arc::new(keys.clone()),
+            keys: Some(keys),
+            nip46: None,
+        }
+    }
+
+    /// Connect to a NIP-46 bunker (`bunker://...` or `nostrconnect://...`).
+    ///
+    /// `app_keys` identify this app to the bunker; reuse the same keys when
+    /// restoring a session so the bunker recognizes it. Resolves the user's
+    /// public key up front, which also confirms the bunker is reachable.
+    pub async fn nip46(uri: &str, app_keys: Keys) -> Result<Self, BurrowError> {
+        let parsed = NostrConnectURI::parse(uri)
+            .map_err(|e| BurrowError::from(format!("Invalid bunker URI: {e}")))?;
+        let connect = NostrConnect::new(parsed, app_keys.clone(), NIP46_TIMEOUT, None)
+            .map_err(|e| BurrowError::from(format!("Failed to start NIP-46 session: {e}")))?;
+        let public_key = connect
+            .get_public_key()
+            .await
+            .map_err(|e| BurrowError::from(format!("Bunker did not respond: {e}")))?;
+        Ok(Self {
+            public_key,
+            keys: None,
+            nip46: Some((app_keys, uri.to_string())),
+            inner: Arc::new(connect),
+        })
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    pub fn kind(&self) -> SignerKind {
+        if self.keys.is_some() {
+            SignerKind::Local
+        } else {
+            SignerKind::Nip46
+        }
+    }
+
+    /// The local secret key, or an error for remote signers.
+    pub fn secret_keys(&self) -> Result<&Keys, BurrowError> {
+        self.keys.as_ref().ok_or_else(|| {
+            BurrowError::from("The secret key is held by a remote signer and can't be exported".to_string())
+        })
+    }
+
+    /// App keys and bunker URI of a NIP-46 session, if this is one.
+    pub fn nip46_session(&self) -> Option<(&Keys, &str)> {
+        self.nip46.as_ref().map(|(keys, uri)| (keys, uri.as_str()))
+    }
+
+    /// The underlying signer, for handing to the Nostr client.
+    pub fn nostr_signer(&self) -> Arc<dyn NostrSigner> {
+        self.inner.clone()
+    }
+
+    /// Build `builder` as the user and sign it.
+    pub async fn sign(&self, builder: EventBuilder) -> Result<Event, BurrowError> {
+        self.sign_unsigned(builder.build(self.public_key)).await
+    }
+
+    pub async fn sign_unsigned(&self, unsigned: UnsignedEvent) -> Result<Event, BurrowError> {
+        self.inner
+            .sign_event(unsigned)
+            .await
+            .map_err(|e| BurrowError::from(format!("Failed to sign event: {e}")))
+    }
+
+    pub async fn nip44_encrypt(&self, receiver: &PublicKey, content: &str) -> Result<String, BurrowError> {
+        self.inner
+            .nip44_encrypt(receiver, content)
+            .await
+            .map_err(|e| BurrowError::from(e.to_string()))
+    }
+}
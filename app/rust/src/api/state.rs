@@ -5,8 +5,10 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::{Arc, OnceLock};
-use tokio::sync::RwLock;
+#[cfg(test)]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 
 use flutter_rust_bridge::frb;
 
@@ -16,17 +18,21 @@ pub use nostr_sdk::prelude::*;
 
 use crate::api::error::BurrowError;
 use crate::api::identity::ProfileData;
+use crate::api::relay::RelayRoles;
+use crate::api::signer::BurrowSigner;
 
-const KEYRING_SERVICE_ID: &str = "com.burrow.app";
+pub(crate) const KEYRING_SERVICE_ID: &str = "com.burrow.app";
 
-/// Global app state holding the MDK instance and Nostr keys.
+/// Global app state holding the MDK instance and Nostr signer.
 #[frb(ignore)]
 pub struct BurrowState {
     pub mdk: MDK<MdkSqliteStorage>,
-    pub keys: Keys,
+    pub signer: BurrowSigner,
     pub client: Client,
     /// In-memory cache of Nostr profile metadata (kind 0), keyed by pubkey hex.
     pub profile_cache: HashMap<String, ProfileData>,
+    /// NIP-65 read/write relay roles. Empty means every relay does both.
+    pub relay_roles: RelayRoles,
 }
 
 static INSTANCE: OnceLock<Arc<RwLock<Option<BurrowState>>>> = OnceLock::new();
@@ -35,6 +41,17 @@ fn global() -> &'static Arc<RwLock<Option<BurrowState>>> {
     INSTANCE.get_or_init(|| Arc::new(RwLock::new(None)))
 }
 
+/// Times `with_state_read` or `with_state_mut` has been entered.
+#[cfg(test)]
+static STATE_ENTRIES: AtomicU64 = AtomicU64::new(0);
+
+/// Number of times the global state has been entered, so unit tests can
+/// check that list queries take the lock once rather than per item.
+#[cfg(test)]
+pub(crate) fn state_entry_count() -> u64 {
+    STATE_ENTRIES.load(Ordering::Relaxed)
+}
+
 /// Initialize the platform-specific keyring store (once).
 pub fn initialize_keyring_store() {
     static KEYRING_INIT: OnceLock<()> = OnceLock::new();
@@ -88,17 +105,65 @@ pub(crate) fn get_data_dir() -> Result<PathBuf, BurrowError> {
         .ok_or_else(|| BurrowError::from("Data directory not set. Call set_data_dir first.".to_string()))
 }
 
+/// Default cap on simultaneous relay requests from batched paths.
+pub const DEFAULT_MAX_CONCURRENT_RELAY_OPS: usize = 8;
+
+/// Shared limit for batched relay fetches and publishes. Replaced wholesale
+/// by `set_max_concurrent_relay_ops`; permits already handed out keep
+/// counting against the old semaphore until they're dropped.
+static RELAY_OPS: LazyLock<Mutex<(usize, Arc<Semaphore>)>> = LazyLock::new(|| {
+    Mutex::new((
+        DEFAULT_MAX_CONCURRENT_RELAY_OPS,
+        Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_RELAY_OPS)),
+    ))
+});
+
+/// Cap how many relay requests the batched fetch/publish paths (profile and
+/// key package batches, media batch uploads, outbox flushes, group sync)
+/// may have in flight at once. Lower it on constrained devices or strict
+/// relays to avoid self-inflicted rate limiting. Clamped to at least 1.
+#[frb(sync)]
+pub fn set_max_concurrent_relay_ops(n: u32) {
+    let n = (n as usize).max(1);
+    if let Ok(mut ops) = RELAY_OPS.lock() {
+        *ops = (n, Arc::new(Semaphore::new(n)));
+    }
+}
+
+/// Current relay concurrency cap.
+#[frb(sync)]
+pub fn get_max_concurrent_relay_ops() -> u32 {
+    RELAY_OPS
+        .lock()
+        .map(|ops| ops.0 as u32)
+        .unwrap_or(DEFAULT_MAX_CONCURRENT_RELAY_OPS as u32)
+}
+
+/// Wait for a relay operation slot. Hold the permit for the duration of the
+/// request.
+#[frb(ignore)]
+pub(crate) async fn relay_permit() -> Option<OwnedSemaphorePermit> {
+    let semaphore = RELAY_OPS.lock().ok()?.1.clone();
+    semaphore.acquire_owned().await.ok()
+}
+
 /// Initialize the global state with a keypair and persistent MLS storage.
 ///
 /// If the existing MLS database can't be opened (e.g., encryption key was lost
 /// due to a keyring backend change), the stale database is removed and a fresh
 /// one is created.
 pub async fn init_state(keys: Keys) -> Result<(), BurrowError> {
+    init_state_with_signer(BurrowSigner::local(keys)).await
+}
+
+/// Like `init_state`, for any signer (e.g. a NIP-46 bunker).
+#[frb(ignore)]
+pub async fn init_state_with_signer(signer: BurrowSigner) -> Result<(), BurrowError> {
     initialize_keyring_store();
 
     let data_dir = get_data_dir()?;
-    let mls_dir = data_dir.join("mls").join(keys.public_key().to_hex());
-    let db_key_id = format!("mdk.db.key.{}", keys.public_key().to_hex());
+    let mls_dir = data_dir.join("mls").join(signer.public_key().to_hex());
+    let db_key_id = mdk_db_key_id(&signer.public_key());
 
     let storage = match MdkSqliteStorage::new(mls_dir.clone(), KEYRING_SERVICE_ID, &db_key_id) {
         Ok(s) => s,
@@ -123,24 +188,44 @@ pub async fn init_state(keys: Keys) -> Result<(), BurrowError> {
     let _ = crate::api::app_state::init_app_state_db(&mls_dir);
 
     let mdk = MDK::new(storage);
-    let client = Client::builder().signer(keys.clone()).build();
+    let client = Client::builder().signer(signer.nostr_signer()).build();
 
     let state = BurrowState {
         mdk,
-        keys,
+        signer,
         client,
         profile_cache: HashMap::new(),
+        relay_roles: RelayRoles::default(),
     };
     let mut guard = global().write().await;
     *guard = Some(state);
     Ok(())
 }
 
-/// Get a read lock on the global state. Returns error if not initialized.
-pub async fn with_state<F, T>(f: F) -> Result<T, BurrowError>
+/// A second handle on an account's MDK database (same file and key as
+/// `BurrowState::mdk`), for the writes MDK itself has no API for.
+pub(crate) fn open_mdk_storage(pubkey: &PublicKey) -> Result<MdkSqliteStorage, BurrowError> {
+    let mls_dir = get_data_dir()?.join("mls").join(pubkey.to_hex());
+    MdkSqliteStorage::new(mls_dir, KEYRING_SERVICE_ID, &mdk_db_key_id(pubkey))
+        .map_err(|e| BurrowError::from(format!("Failed to open MLS storage: {e}")))
+}
+
+/// Keyring entry name of the MDK database encryption key for an account.
+pub(crate) fn mdk_db_key_id(pubkey: &PublicKey) -> String {
+    format!("mdk.db.key.{}", pubkey.to_hex())
+}
+
+/// Run `f` under a shared read lock on the global state.
+///
+/// For pure queries (message history, group lists, cached keys/client).
+/// Readers never block each other, so UI queries proceed while the
+/// listener is idle between events. Returns error if not initialized.
+pub async fn with_state_read<F, T>(f: F) -> Result<T, BurrowError>
 where
     F: FnOnce(&BurrowState) -> Result<T, BurrowError>,
 {
+    #[cfg(test)]
+    STATE_ENTRIES.fetch_add(1, Ordering::Relaxed);
     let guard = global().read().await;
     let state = guard
         .as_ref()
@@ -148,11 +233,18 @@ where
     f(state)
 }
 
-/// Get a write lock on the global state.
+/// Run `f` under an exclusive write lock on the global state.
+///
+/// Required for anything that advances MLS state through MDK — encrypting
+/// or processing messages, commits, welcomes, key packages, group data
+/// updates — and for profile cache writes. MDK takes `&self`, but these
+/// calls must not interleave for the same group.
 pub async fn with_state_mut<F, T>(f: F) -> Result<T, BurrowError>
 where
     F: FnOnce(&mut BurrowState) -> Result<T, BurrowError>,
 {
+    #[cfg(test)]
+    STATE_ENTRIES.fetch_add(1, Ordering::Relaxed);
     let mut guard = global().write().await;
     let state = guard
         .as_mut()
@@ -171,3 +263,17 @@ pub async fn destroy_state() {
     let mut guard = global().write().await;
     *guard = None;
 }
+
+/// Unit tests that drive the global state hold this so they don't overlap.
+#[cfg(test)]
+pub(crate) static TEST_STATE_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Replace the global state with a fresh identity's, for unit tests.
+#[cfg(test)]
+pub(crate) async fn init_test_state() {
+    destroy_state().await;
+    let tmp = std::env::temp_dir().join("burrow_unit_tests");
+    std::fs::create_dir_all(&tmp).unwrap();
+    set_data_dir(tmp.to_string_lossy().to_string());
+    init_state(Keys::generate()).await.unwrap();
+}
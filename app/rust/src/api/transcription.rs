@@ -327,6 +327,24 @@ pub fn search_transcript(query: String) -> Result<Vec<TranscriptSegment>, String
         .collect())
 }
 
+/// Keep only segments with `confidence >= min_confidence`.
+///
+/// Takes and returns a JSON array of segments. Useful before sharing or
+/// summarizing a transcript recorded over a bad connection, where
+/// low-confidence segments are mostly ASR noise.
+pub fn filter_transcript_by_confidence(
+    segments_json: String,
+    min_confidence: f64,
+) -> Result<String, String> {
+    let segments: Vec<TranscriptSegment> =
+        serde_json::from_str(&segments_json).map_err(|e| format!("Invalid segments: {e}"))?;
+    let kept: Vec<TranscriptSegment> = segments
+        .into_iter()
+        .filter(|s| s.confidence >= min_confidence)
+        .collect();
+    serde_json::to_string(&kept).map_err(|e| format!("Serialization error: {e}"))
+}
+
 fn format_timestamp(ms: i64) -> String {
     let total_seconds = ms / 1000;
     let hours = total_seconds / 3600;
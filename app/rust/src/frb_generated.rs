@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 662584846;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1063729984;
 
 // Section: executor
 
@@ -46,6 +46,39 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__diagnostics__DiagnosticsReport_to_json_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DiagnosticsReport_to_json",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::diagnostics::DiagnosticsReport>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::diagnostics::DiagnosticsReport::to_json(&api_that),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__call_signaling__accept_call_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -89,6 +122,49 @@ fn wire__crate__api__call_signaling__accept_call_impl(
         },
     )
 }
+fn wire__crate__api__call_signaling__accept_call_fragments_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "accept_call_fragments",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sdp_answer = <String>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_caller_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::accept_call_fragments(
+                            api_sdp_answer,
+                            api_call_id,
+                            api_caller_pubkey_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__invite__accept_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -309,6 +385,49 @@ fn wire__crate__api__identity__bootstrap_identity_impl(
         },
     )
 }
+fn wire__crate__api__media__build_chunked_imeta_tag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_chunked_imeta_tag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_original_hash_hex = <String>::sse_decode(&mut deserializer);
+            let api_nonce_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::media::build_chunked_imeta_tag(
+                        api_url,
+                        api_mime_type,
+                        api_filename,
+                        api_original_hash_hex,
+                        api_nonce_hex,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__call_signaling__build_group_call_signaling_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -383,6 +502,8 @@ fn wire__crate__api__media__build_imeta_tag_impl(
             let api_nonce_hex = <String>::sse_decode(&mut deserializer);
             let api_dimensions = <Option<String>>::sse_decode(&mut deserializer);
             let api_blurhash = <Option<String>>::sse_decode(&mut deserializer);
+            let api_thumb =
+                <Option<crate::api::media::ThumbnailReference>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::api::error::BurrowError>((move || {
@@ -394,6 +515,7 @@ fn wire__crate__api__media__build_imeta_tag_impl(
                         api_nonce_hex,
                         api_dimensions,
                         api_blurhash,
+                        api_thumb,
                     )?;
                     Ok(output_ok)
                 })())
@@ -436,7 +558,7 @@ fn wire__crate__api__meeting_intelligence__build_meeting_notes_prompt_impl(
         },
     )
 }
-fn wire__crate__api__call_quality__calculate_quality_score_impl(
+fn wire__crate__api__meeting_intelligence__build_meeting_notes_prompt_from_segments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -444,7 +566,7 @@ fn wire__crate__api__call_quality__calculate_quality_score_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calculate_quality_score",
+            debug_name: "build_meeting_notes_prompt_from_segments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -458,29 +580,21 @@ fn wire__crate__api__call_quality__calculate_quality_score_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_rtt_ms = <f64>::sse_decode(&mut deserializer);
-            let api_jitter_ms = <f64>::sse_decode(&mut deserializer);
-            let api_packet_loss_percent = <f64>::sse_decode(&mut deserializer);
-            let api_bitrate_kbps = <f64>::sse_decode(&mut deserializer);
-            let api_is_video = <bool>::sse_decode(&mut deserializer);
+            let api_segments_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::call_quality::calculate_quality_score(
-                            api_rtt_ms,
-                            api_jitter_ms,
-                            api_packet_loss_percent,
-                            api_bitrate_kbps,
-                            api_is_video,
-                        ))?;
+                        crate::api::meeting_intelligence::build_meeting_notes_prompt_from_segments(
+                            api_segments_json,
+                        )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
+fn wire__crate__api__call_quality__calculate_quality_score_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -488,7 +602,7 @@ fn wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "configure_ai_backend",
+            debug_name: "calculate_quality_score",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -502,19 +616,29 @@ fn wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_backend_json = <String>::sse_decode(&mut deserializer);
+            let api_rtt_ms = <f64>::sse_decode(&mut deserializer);
+            let api_jitter_ms = <f64>::sse_decode(&mut deserializer);
+            let api_packet_loss_percent = <f64>::sse_decode(&mut deserializer);
+            let api_bitrate_kbps = <f64>::sse_decode(&mut deserializer);
+            let api_is_video = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        crate::api::meeting_intelligence::configure_ai_backend(api_backend_json)?;
+                        Result::<_, ()>::Ok(crate::api::call_quality::calculate_quality_score(
+                            api_rtt_ms,
+                            api_jitter_ms,
+                            api_packet_loss_percent,
+                            api_bitrate_kbps,
+                            api_is_video,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relay__connect_relays_impl(
+fn wire__crate__api__group__cleanup_inactive_groups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -522,7 +646,7 @@ fn wire__crate__api__relay__connect_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect_relays",
+            debug_name: "cleanup_inactive_groups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -540,7 +664,7 @@ fn wire__crate__api__relay__connect_relays_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::relay::connect_relays().await?;
+                        let output_ok = crate::api::group::cleanup_inactive_groups().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -549,7 +673,7 @@ fn wire__crate__api__relay__connect_relays_impl(
         },
     )
 }
-fn wire__crate__api__account__create_account_impl(
+fn wire__crate__api__media__clear_media_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -557,7 +681,7 @@ fn wire__crate__api__account__create_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_account",
+            debug_name: "clear_media_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -575,7 +699,7 @@ fn wire__crate__api__account__create_account_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::account::create_account().await?;
+                        let output_ok = crate::api::media::clear_media_cache().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -584,7 +708,7 @@ fn wire__crate__api__account__create_account_impl(
         },
     )
 }
-fn wire__crate__api__group__create_group_impl(
+fn wire__crate__api__group__clear_pending_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -592,7 +716,7 @@ fn wire__crate__api__group__create_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_group",
+            debug_name: "clear_pending_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -606,23 +730,13 @@ fn wire__crate__api__group__create_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
-            let api_description = <String>::sse_decode(&mut deserializer);
-            let api_admin_pubkeys_hex = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_member_key_package_events_json = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::create_group(
-                            api_name,
-                            api_description,
-                            api_admin_pubkeys_hex,
-                            api_member_key_package_events_json,
-                            api_relay_urls,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::group::clear_pending_commit(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -631,15 +745,15 @@ fn wire__crate__api__group__create_group_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__create_peer_entry_impl(
+fn wire__crate__api__meeting_intelligence__clear_transcript_buffer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_peer_entry",
+            debug_name: "clear_transcript_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -653,30 +767,19 @@ fn wire__crate__api__call_webrtc__create_peer_entry_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_has_audio_track = <bool>::sse_decode(&mut deserializer);
-            let api_has_video_track = <bool>::sse_decode(&mut deserializer);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::call_webrtc::create_peer_entry(
-                            api_call_id,
-                            api_participant_pubkey_hex,
-                            api_has_audio_track,
-                            api_has_video_track,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::meeting_intelligence::clear_transcript_buffer(api_meeting_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__call_session__create_session_impl(
+fn wire__crate__api__diagnostics__collect_diagnostics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -684,7 +787,7 @@ fn wire__crate__api__call_session__create_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_session",
+            debug_name: "collect_diagnostics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -698,25 +801,11 @@ fn wire__crate__api__call_session__create_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_call_type = <String>::sse_decode(&mut deserializer);
-            let api_direction = <String>::sse_decode(&mut deserializer);
-            let api_local_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_group_id_hex = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_session::create_session(
-                            api_call_id,
-                            api_call_type,
-                            api_direction,
-                            api_local_pubkey_hex,
-                            api_remote_pubkey_hex,
-                            api_group_id_hex,
-                        )
-                        .await?;
+                        let output_ok = crate::api::diagnostics::collect_diagnostics().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -725,15 +814,15 @@ fn wire__crate__api__call_session__create_session_impl(
         },
     )
 }
-fn wire__crate__api__contacts__debug_sync_contacts_impl(
+fn wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "debug_sync_contacts",
+            debug_name: "configure_ai_backend",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -747,28 +836,27 @@ fn wire__crate__api__contacts__debug_sync_contacts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_backend_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::contacts::debug_sync_contacts().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::meeting_intelligence::configure_ai_backend(api_backend_json)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__invite__decline_welcome_impl(
+fn wire__crate__api__meeting_intelligence__configure_confidence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decline_welcome",
+            debug_name: "configure_confidence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -782,30 +870,30 @@ fn wire__crate__api__invite__decline_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_welcome_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_min_confidence = <f64>::sse_decode(&mut deserializer);
+            let api_review_confidence = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::invite::decline_welcome(api_welcome_event_id_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::configure_confidence(
+                        api_min_confidence,
+                        api_review_confidence,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__media__decrypt_file_impl(
+fn wire__crate__api__meeting_intelligence__configure_redaction_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "decrypt_file",
+            debug_name: "configure_redaction",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -819,48 +907,28 @@ fn wire__crate__api__media__decrypt_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_encrypted_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_filename = <String>::sse_decode(&mut deserializer);
-            let api_original_hash_hex = <String>::sse_decode(&mut deserializer);
-            let api_nonce_hex = <String>::sse_decode(&mut deserializer);
-            let api_scheme_version = <String>::sse_decode(&mut deserializer);
-            let api_dimensions = <Option<String>>::sse_decode(&mut deserializer);
+            let api_redact_patterns_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::media::decrypt_file(
-                            api_mls_group_id_hex,
-                            api_encrypted_data,
-                            api_url,
-                            api_mime_type,
-                            api_filename,
-                            api_original_hash_hex,
-                            api_nonce_hex,
-                            api_scheme_version,
-                            api_dimensions,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::configure_redaction(
+                        api_redact_patterns_json,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__group__default_blossom_server_impl(
+fn wire__crate__api__relay__connect_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "default_blossom_server",
+            debug_name: "connect_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -875,26 +943,29 @@ fn wire__crate__api__group__default_blossom_server_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::group::default_blossom_server())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::connect_relays().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__relay__default_relay_urls_impl(
+fn wire__crate__api__account__create_account_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "default_relay_urls",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "create_account",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -907,14 +978,19 @@ fn wire__crate__api__relay__default_relay_urls_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::relay::default_relay_urls())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::account::create_account().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__app_state__delete_group_state_impl(
+fn wire__crate__api__group__create_dm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -922,7 +998,7 @@ fn wire__crate__api__app_state__delete_group_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_group_state",
+            debug_name: "create_dm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -936,15 +1012,19 @@ fn wire__crate__api__app_state__delete_group_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_peer_key_package_event_json = <String>::sse_decode(&mut deserializer);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::app_state::delete_group_state(api_group_id_hex, api_key)
-                                .await?;
+                        let output_ok = crate::api::group::create_dm(
+                            api_peer_pubkey_hex,
+                            api_peer_key_package_event_json,
+                            api_relay_urls,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -953,7 +1033,7 @@ fn wire__crate__api__app_state__delete_group_state_impl(
         },
     )
 }
-fn wire__crate__api__account__delete_secret_key_from_keyring_impl(
+fn wire__crate__api__group__create_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -961,7 +1041,7 @@ fn wire__crate__api__account__delete_secret_key_from_keyring_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_secret_key_from_keyring",
+            debug_name: "create_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -975,12 +1055,23 @@ fn wire__crate__api__account__delete_secret_key_from_keyring_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_description = <String>::sse_decode(&mut deserializer);
+            let api_admin_pubkeys_hex = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_member_key_package_events_json = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::account::delete_secret_key_from_keyring().await?;
+                        let output_ok = crate::api::group::create_group(
+                            api_name,
+                            api_description,
+                            api_admin_pubkeys_hex,
+                            api_member_key_package_events_json,
+                            api_relay_urls,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -989,15 +1080,15 @@ fn wire__crate__api__account__delete_secret_key_from_keyring_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__derive_frame_encryption_key_impl(
+fn wire__crate__api__group__create_or_get_dm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "derive_frame_encryption_key",
+            debug_name: "create_or_get_dm",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1011,30 +1102,34 @@ fn wire__crate__api__call_webrtc__derive_frame_encryption_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_exporter_secret_hex = <String>::sse_decode(&mut deserializer);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_webrtc::derive_frame_encryption_key(
-                        api_exporter_secret_hex,
-                        api_call_id,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::create_or_get_dm(
+                            api_peer_pubkey_hex,
+                            api_relay_urls,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_session__derive_media_key_impl(
+fn wire__crate__api__call_webrtc__create_peer_entry_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "derive_media_key",
+            debug_name: "create_peer_entry",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1048,22 +1143,30 @@ fn wire__crate__api__call_session__derive_media_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_exporter_secret_hex = <String>::sse_decode(&mut deserializer);
             let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_has_audio_track = <bool>::sse_decode(&mut deserializer);
+            let api_has_video_track = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_session::derive_media_key(
-                        api_exporter_secret_hex,
-                        api_call_id,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_webrtc::create_peer_entry(
+                            api_call_id,
+                            api_participant_pubkey_hex,
+                            api_has_audio_track,
+                            api_has_video_track,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__state__destroy_state_impl(
+fn wire__crate__api__call_session__create_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1071,7 +1174,7 @@ fn wire__crate__api__state__destroy_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "destroy_state",
+            debug_name: "create_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1085,13 +1188,25 @@ fn wire__crate__api__state__destroy_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_call_type = <String>::sse_decode(&mut deserializer);
+            let api_direction = <String>::sse_decode(&mut deserializer);
+            let api_local_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_group_id_hex = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::state::destroy_state().await;
-                        })?;
+                        let output_ok = crate::api::call_session::create_session(
+                            api_call_id,
+                            api_call_type,
+                            api_direction,
+                            api_local_pubkey_hex,
+                            api_remote_pubkey_hex,
+                            api_group_id_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1100,7 +1215,7 @@ fn wire__crate__api__state__destroy_state_impl(
         },
     )
 }
-fn wire__crate__api__relay__disconnect_relays_impl(
+fn wire__crate__api__contacts__debug_sync_contacts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1108,7 +1223,7 @@ fn wire__crate__api__relay__disconnect_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "disconnect_relays",
+            debug_name: "debug_sync_contacts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1126,7 +1241,7 @@ fn wire__crate__api__relay__disconnect_relays_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::relay::disconnect_relays().await?;
+                        let output_ok = crate::api::contacts::debug_sync_contacts().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1135,7 +1250,7 @@ fn wire__crate__api__relay__disconnect_relays_impl(
         },
     )
 }
-fn wire__crate__api__group__download_group_image_impl(
+fn wire__crate__api__invite__decline_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1143,7 +1258,7 @@ fn wire__crate__api__group__download_group_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_group_image",
+            debug_name: "decline_welcome",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1157,17 +1272,13 @@ fn wire__crate__api__group__download_group_image_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            let api_welcome_event_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::download_group_image(
-                            api_mls_group_id_hex,
-                            api_blossom_server_url,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::invite::decline_welcome(api_welcome_event_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1176,7 +1287,7 @@ fn wire__crate__api__group__download_group_image_impl(
         },
     )
 }
-fn wire__crate__api__media__download_media_impl(
+fn wire__crate__api__media__decrypt_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1184,7 +1295,7 @@ fn wire__crate__api__media__download_media_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "download_media",
+            debug_name: "decrypt_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1199,6 +1310,7 @@ fn wire__crate__api__media__download_media_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_encrypted_data = <Vec<u8>>::sse_decode(&mut deserializer);
             let api_url = <String>::sse_decode(&mut deserializer);
             let api_mime_type = <String>::sse_decode(&mut deserializer);
             let api_filename = <String>::sse_decode(&mut deserializer);
@@ -1210,8 +1322,9 @@ fn wire__crate__api__media__download_media_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::media::download_media(
+                        let output_ok = crate::api::media::decrypt_file(
                             api_mls_group_id_hex,
+                            api_encrypted_data,
                             api_url,
                             api_mime_type,
                             api_filename,
@@ -1229,7 +1342,7 @@ fn wire__crate__api__media__download_media_impl(
         },
     )
 }
-fn wire__crate__api__media__encrypt_file_impl(
+fn wire__crate__api__media__decrypt_file_chunked_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1237,7 +1350,7 @@ fn wire__crate__api__media__encrypt_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encrypt_file",
+            debug_name: "decrypt_file_chunked",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1252,18 +1365,20 @@ fn wire__crate__api__media__encrypt_file_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_output_path = <String>::sse_decode(&mut deserializer);
             let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_original_hash_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::media::encrypt_file(
+                        let output_ok = crate::api::media::decrypt_file_chunked(
                             api_mls_group_id_hex,
-                            api_file_data,
-                            api_mime_type,
+                            api_input_path,
+                            api_output_path,
                             api_filename,
+                            api_original_hash_hex,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1274,15 +1389,15 @@ fn wire__crate__api__media__encrypt_file_impl(
         },
     )
 }
-fn wire__crate__api__call_signaling__end_call_impl(
+fn wire__crate__api__group__default_blossom_server_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "end_call",
+            debug_name: "default_blossom_server",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1296,26 +1411,18 @@ fn wire__crate__api__call_signaling__end_call_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::call_signaling::end_call(
-                            api_call_id,
-                            api_remote_pubkey_hex,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::group::default_blossom_server())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
+fn wire__crate__api__meeting_intelligence__default_redact_patterns_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1323,7 +1430,7 @@ fn wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_meeting_markdown",
+            debug_name: "default_redact_patterns",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1337,29 +1444,28 @@ fn wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_meeting_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::meeting_intelligence::export_meeting_markdown(api_meeting_id)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::meeting_intelligence::default_redact_patterns(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__identity__export_npub_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__relay__default_relay_urls_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_npub",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "default_relay_urls",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1372,19 +1478,14 @@ fn wire__crate__api__identity__export_npub_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::identity::export_npub().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::relay::default_relay_urls())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__identity__export_nsec_impl(
+fn wire__crate__api__app_state__delete_group_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1392,7 +1493,7 @@ fn wire__crate__api__identity__export_nsec_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_nsec",
+            debug_name: "delete_group_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1406,11 +1507,15 @@ fn wire__crate__api__identity__export_nsec_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::identity::export_nsec().await?;
+                        let output_ok =
+                            crate::api::app_state::delete_group_state(api_group_id_hex, api_key)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1419,15 +1524,15 @@ fn wire__crate__api__identity__export_nsec_impl(
         },
     )
 }
-fn wire__crate__api__identity__export_pubkey_hex_impl(
+fn wire__crate__api__meeting_intelligence__delete_meeting_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_pubkey_hex",
+            debug_name: "delete_meeting_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1441,28 +1546,27 @@ fn wire__crate__api__identity__export_pubkey_hex_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::identity::export_pubkey_hex().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::meeting_intelligence::delete_meeting_notes(api_meeting_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__transcription__feed_audio_impl(
+fn wire__crate__api__message__delete_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "feed_audio",
+            debug_name: "delete_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1476,22 +1580,26 @@ fn wire__crate__api__transcription__feed_audio_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_audio_data = <Vec<f32>>::sse_decode(&mut deserializer);
-            let api_speaker_track_id = <String>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_target_event_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::feed_audio(
-                        api_audio_data,
-                        api_speaker_track_id,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::delete_message(
+                            api_mls_group_id_hex,
+                            api_target_event_id_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__invite__fetch_key_package_impl(
+fn wire__crate__api__account__delete_secret_key_from_keyring_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1499,7 +1607,7 @@ fn wire__crate__api__invite__fetch_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_key_package",
+            debug_name: "delete_secret_key_from_keyring",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1513,13 +1621,12 @@ fn wire__crate__api__invite__fetch_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::invite::fetch_key_package(api_pubkey_hex).await?;
+                            crate::api::account::delete_secret_key_from_keyring().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1528,7 +1635,7 @@ fn wire__crate__api__invite__fetch_key_package_impl(
         },
     )
 }
-fn wire__crate__api__identity__fetch_profile_impl(
+fn wire__crate__api__group__demote_admin_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1536,7 +1643,7 @@ fn wire__crate__api__identity__fetch_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_profile",
+            debug_name: "demote_admin",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1550,14 +1657,14 @@ fn wire__crate__api__identity__fetch_profile_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_blocking_sync = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::identity::fetch_profile(api_pubkey_hex, api_blocking_sync)
+                            crate::api::group::demote_admin(api_mls_group_id_hex, api_pubkey_hex)
                                 .await?;
                         Ok(output_ok)
                     })()
@@ -1567,15 +1674,15 @@ fn wire__crate__api__identity__fetch_profile_impl(
         },
     )
 }
-fn wire__crate__api__identity__fetch_user_relays_impl(
+fn wire__crate__api__call_webrtc__derive_frame_encryption_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "fetch_user_relays",
+            debug_name: "derive_frame_encryption_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1589,30 +1696,32 @@ fn wire__crate__api__identity__fetch_user_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_exporter_secret_hex = <String>::sse_decode(&mut deserializer);
+            let api_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::identity::fetch_user_relays(api_pubkey_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_webrtc::derive_frame_encryption_key(
+                        api_exporter_secret_hex,
+                        api_epoch,
+                        api_call_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__contacts__follow_contact_impl(
+fn wire__crate__api__call_session__derive_media_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "follow_contact",
+            debug_name: "derive_media_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1626,22 +1735,22 @@ fn wire__crate__api__contacts__follow_contact_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_exporter_secret_hex = <String>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::contacts::follow_contact(api_pubkey_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_session::derive_media_key(
+                        api_exporter_secret_hex,
+                        api_call_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__keypackage__generate_key_package_impl(
+fn wire__crate__api__state__destroy_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1649,7 +1758,7 @@ fn wire__crate__api__keypackage__generate_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_key_package",
+            debug_name: "destroy_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1663,13 +1772,13 @@ fn wire__crate__api__keypackage__generate_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::keypackage::generate_key_package(api_relay_urls).await?;
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::state::destroy_state().await;
+                        })?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1678,15 +1787,15 @@ fn wire__crate__api__keypackage__generate_key_package_impl(
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__generate_meeting_notes_impl(
+fn wire__crate__api__relay__disconnect_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_meeting_notes",
+            debug_name: "disconnect_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1700,36 +1809,28 @@ fn wire__crate__api__meeting_intelligence__generate_meeting_notes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_meeting_id = <String>::sse_decode(&mut deserializer);
-            let api_segments_json = <String>::sse_decode(&mut deserializer);
-            let api_participants_json = <String>::sse_decode(&mut deserializer);
-            let api_start_time_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_time_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::meeting_intelligence::generate_meeting_notes(
-                        api_meeting_id,
-                        api_segments_json,
-                        api_participants_json,
-                        api_start_time_ms,
-                        api_end_time_ms,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::disconnect_relays().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_webrtc__generate_webrtc_config_impl(
+fn wire__crate__api__group__download_group_image_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_webrtc_config",
+            debug_name: "download_group_image",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1743,18 +1844,26 @@ fn wire__crate__api__call_webrtc__generate_webrtc_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_webrtc::generate_webrtc_config(api_call_id)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::download_group_image(
+                            api_mls_group_id_hex,
+                            api_blossom_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_session__get_active_calls_impl(
+fn wire__crate__api__media__download_media_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1762,7 +1871,7 @@ fn wire__crate__api__call_session__get_active_calls_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_active_calls",
+            debug_name: "download_media",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1776,11 +1885,31 @@ fn wire__crate__api__call_session__get_active_calls_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_original_hash_hex = <String>::sse_decode(&mut deserializer);
+            let api_nonce_hex = <String>::sse_decode(&mut deserializer);
+            let api_scheme_version = <String>::sse_decode(&mut deserializer);
+            let api_dimensions = <Option<String>>::sse_decode(&mut deserializer);
+            let api_mirror_servers = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_session::get_active_calls().await?;
+                        let output_ok = crate::api::media::download_media(
+                            api_mls_group_id_hex,
+                            api_url,
+                            api_mime_type,
+                            api_filename,
+                            api_original_hash_hex,
+                            api_nonce_hex,
+                            api_scheme_version,
+                            api_dimensions,
+                            api_mirror_servers,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1789,15 +1918,15 @@ fn wire__crate__api__call_session__get_active_calls_impl(
         },
     )
 }
-fn wire__crate__api__call_quality__get_adaptive_bitrate_config_impl(
+fn wire__crate__api__message__edit_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_adaptive_bitrate_config",
+            debug_name: "edit_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1811,19 +1940,28 @@ fn wire__crate__api__call_quality__get_adaptive_bitrate_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_target_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_new_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::call_quality::get_adaptive_bitrate_config(),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__app_state__get_archived_group_ids_impl(
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::edit_message(
+                            api_mls_group_id_hex,
+                            api_target_event_id_hex,
+                            api_new_content,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__encrypt_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1831,7 +1969,7 @@ fn wire__crate__api__app_state__get_archived_group_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_archived_group_ids",
+            debug_name: "encrypt_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1845,11 +1983,23 @@ fn wire__crate__api__app_state__get_archived_group_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_strict = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::app_state::get_archived_group_ids().await?;
+                        let output_ok = crate::api::media::encrypt_file(
+                            api_mls_group_id_hex,
+                            api_file_data,
+                            api_mime_type,
+                            api_filename,
+                            api_strict,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1858,15 +2008,15 @@ fn wire__crate__api__app_state__get_archived_group_ids_impl(
         },
     )
 }
-fn wire__crate__api__call_quality__get_audio_constraints_impl(
+fn wire__crate__api__media__encrypt_file_chunked_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_audio_constraints",
+            debug_name: "encrypt_file_chunked",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1880,20 +2030,34 @@ fn wire__crate__api__call_quality__get_audio_constraints_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mode = <crate::api::call_quality::AudioMode>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_input_path = <String>::sse_decode(&mut deserializer);
+            let api_output_path = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_strict = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::call_quality::get_audio_constraints(api_mode),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::media::encrypt_file_chunked(
+                            api_mls_group_id_hex,
+                            api_input_path,
+                            api_output_path,
+                            api_mime_type,
+                            api_filename,
+                            api_strict,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__contacts__get_cached_contacts_impl(
+fn wire__crate__api__media__encrypt_file_from_path_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1901,7 +2065,7 @@ fn wire__crate__api__contacts__get_cached_contacts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cached_contacts",
+            debug_name: "encrypt_file_from_path",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1915,11 +2079,23 @@ fn wire__crate__api__contacts__get_cached_contacts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_strict = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::contacts::get_cached_contacts().await?;
+                        let output_ok = crate::api::media::encrypt_file_from_path(
+                            api_mls_group_id_hex,
+                            api_file_path,
+                            api_mime_type,
+                            api_filename,
+                            api_strict,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1928,7 +2104,7 @@ fn wire__crate__api__contacts__get_cached_contacts_impl(
         },
     )
 }
-fn wire__crate__api__identity__get_cached_profile_impl(
+fn wire__crate__api__media__encrypt_thumbnail_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1936,7 +2112,7 @@ fn wire__crate__api__identity__get_cached_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cached_profile",
+            debug_name: "encrypt_thumbnail",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1950,13 +2126,19 @@ fn wire__crate__api__identity__get_cached_profile_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_dimension = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::identity::get_cached_profile(api_pubkey_hex).await?;
+                        let output_ok = crate::api::media::encrypt_thumbnail(
+                            api_mls_group_id_hex,
+                            api_file_data,
+                            api_max_dimension,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1965,7 +2147,7 @@ fn wire__crate__api__identity__get_cached_profile_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__get_call_participants_impl(
+fn wire__crate__api__call_signaling__end_call_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1973,7 +2155,7 @@ fn wire__crate__api__call_webrtc__get_call_participants_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_call_participants",
+            debug_name: "end_call",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1988,12 +2170,16 @@ fn wire__crate__api__call_webrtc__get_call_participants_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::call_webrtc::get_call_participants(api_call_id).await?;
+                        let output_ok = crate::api::call_signaling::end_call(
+                            api_call_id,
+                            api_remote_pubkey_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2002,7 +2188,7 @@ fn wire__crate__api__call_webrtc__get_call_participants_impl(
         },
     )
 }
-fn wire__crate__api__call_quality__get_codec_preferences_impl(
+fn wire__crate__api__ephemeral__ephemeral_key_strategy_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2010,7 +2196,7 @@ fn wire__crate__api__call_quality__get_codec_preferences_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_codec_preferences",
+            debug_name: "ephemeral_key_strategy_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2027,15 +2213,16 @@ fn wire__crate__api__call_quality__get_codec_preferences_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::call_quality::get_codec_preferences())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::ephemeral::EphemeralKeyStrategy::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__account__get_current_account_impl(
+fn wire__crate__api__contacts__export_contacts_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2043,7 +2230,7 @@ fn wire__crate__api__account__get_current_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_current_account",
+            debug_name: "export_contacts_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2057,11 +2244,13 @@ fn wire__crate__api__account__get_current_account_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publish = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::account::get_current_account().await?;
+                        let output_ok =
+                            crate::api::contacts::export_contacts_list(api_publish).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2070,15 +2259,15 @@ fn wire__crate__api__account__get_current_account_impl(
         },
     )
 }
-fn wire__crate__api__group__get_group_impl(
+fn wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group",
+            debug_name: "export_meeting_markdown",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2092,21 +2281,19 @@ fn wire__crate__api__group__get_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::group::get_group(api_mls_group_id_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::meeting_intelligence::export_meeting_markdown(api_meeting_id)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__group__get_group_members_impl(
+fn wire__crate__api__identity__export_npub_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2114,7 +2301,7 @@ fn wire__crate__api__group__get_group_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_members",
+            debug_name: "export_npub",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2128,13 +2315,11 @@ fn wire__crate__api__group__get_group_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::group::get_group_members(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::identity::export_npub().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2143,7 +2328,7 @@ fn wire__crate__api__group__get_group_members_impl(
         },
     )
 }
-fn wire__crate__api__group__get_group_relays_impl(
+fn wire__crate__api__identity__export_nsec_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2151,7 +2336,7 @@ fn wire__crate__api__group__get_group_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_relays",
+            debug_name: "export_nsec",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2165,13 +2350,11 @@ fn wire__crate__api__group__get_group_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::group::get_group_relays(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::identity::export_nsec().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2180,7 +2363,7 @@ fn wire__crate__api__group__get_group_relays_impl(
         },
     )
 }
-fn wire__crate__api__app_state__get_group_state_impl(
+fn wire__crate__api__identity__export_pubkey_hex_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2188,7 +2371,7 @@ fn wire__crate__api__app_state__get_group_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_state",
+            debug_name: "export_pubkey_hex",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2202,15 +2385,11 @@ fn wire__crate__api__app_state__get_group_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::app_state::get_group_state(api_group_id_hex, api_key)
-                                .await?;
+                        let output_ok = crate::api::identity::export_pubkey_hex().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2219,7 +2398,7 @@ fn wire__crate__api__app_state__get_group_state_impl(
         },
     )
 }
-fn wire__crate__api__app_state__get_group_summary_impl(
+fn wire__crate__api__backup__export_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2227,7 +2406,7 @@ fn wire__crate__api__app_state__get_group_summary_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_group_summary",
+            debug_name: "export_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2241,13 +2420,12 @@ fn wire__crate__api__app_state__get_group_summary_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::app_state::get_group_summary(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::backup::export_state(api_passphrase).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2256,15 +2434,15 @@ fn wire__crate__api__app_state__get_group_summary_impl(
         },
     )
 }
-fn wire__crate__api__contacts__get_last_contacts_sync_impl(
+fn wire__crate__api__link_preview__extract_urls_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_last_contacts_sync",
+            debug_name: "extract_urls",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2278,28 +2456,27 @@ fn wire__crate__api__contacts__get_last_contacts_sync_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::contacts::get_last_contacts_sync().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::link_preview::extract_urls(api_text))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__app_state__get_last_read_timestamp_impl(
+fn wire__crate__api__transcription__feed_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_last_read_timestamp",
+            debug_name: "feed_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2313,31 +2490,30 @@ fn wire__crate__api__app_state__get_last_read_timestamp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_audio_data = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_speaker_track_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::app_state::get_last_read_timestamp(api_group_id_hex)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::feed_audio(
+                        api_audio_data,
+                        api_speaker_track_id,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__get_meeting_archive_impl(
+fn wire__crate__api__invite__fetch_key_package_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_meeting_archive",
+            debug_name: "fetch_key_package",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2351,25 +2527,30 @@ fn wire__crate__api__meeting_intelligence__get_meeting_archive_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::meeting_intelligence::get_meeting_archive()?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::invite::fetch_key_package(api_pubkey_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__get_meeting_notes_impl(
+fn wire__crate__api__link_preview__fetch_og_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_meeting_notes",
+            debug_name: "fetch_og_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2383,19 +2564,22 @@ fn wire__crate__api__meeting_intelligence__get_meeting_notes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::meeting_intelligence::get_meeting_notes(api_meeting_id)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::link_preview::fetch_og_metadata(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__message__get_message_impl(
+fn wire__crate__api__identity__fetch_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2403,7 +2587,7 @@ fn wire__crate__api__message__get_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_message",
+            debug_name: "fetch_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2417,17 +2601,15 @@ fn wire__crate__api__message__get_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_blocking_sync = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::get_message(
-                            api_mls_group_id_hex,
-                            api_event_id_hex,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::identity::fetch_profile(api_pubkey_hex, api_blocking_sync)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2436,7 +2618,7 @@ fn wire__crate__api__message__get_message_impl(
         },
     )
 }
-fn wire__crate__api__message__get_messages_impl(
+fn wire__crate__api__identity__fetch_user_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2444,7 +2626,7 @@ fn wire__crate__api__message__get_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_messages",
+            debug_name: "fetch_user_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2458,19 +2640,13 @@ fn wire__crate__api__message__get_messages_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::get_messages(
-                            api_mls_group_id_hex,
-                            api_limit,
-                            api_offset,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::identity::fetch_user_relays(api_pubkey_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2479,15 +2655,15 @@ fn wire__crate__api__message__get_messages_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__get_peer_stats_impl(
+fn wire__crate__api__transcription__filter_transcript_by_confidence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_peer_stats",
+            debug_name: "filter_transcript_by_confidence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2501,23 +2677,22 @@ fn wire__crate__api__call_webrtc__get_peer_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_segments_json = <String>::sse_decode(&mut deserializer);
+            let api_min_confidence = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::call_webrtc::get_peer_stats(api_participant_pubkey_hex)
-                                .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::filter_transcript_by_confidence(
+                        api_segments_json,
+                        api_min_confidence,
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__call_session__get_session_impl(
+fn wire__crate__api__meeting_intelligence__finalize_live_meeting_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2525,7 +2700,7 @@ fn wire__crate__api__call_session__get_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_session",
+            debug_name: "finalize_live_meeting",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2539,12 +2714,21 @@ fn wire__crate__api__call_session__get_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_participants_json = <String>::sse_decode(&mut deserializer);
+            let api_start_time_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_time_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::call_session::get_session(api_call_id).await?;
+                        let output_ok = crate::api::meeting_intelligence::finalize_live_meeting(
+                            api_meeting_id,
+                            api_participants_json,
+                            api_start_time_ms,
+                            api_end_time_ms,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2553,15 +2737,15 @@ fn wire__crate__api__call_session__get_session_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__get_sfu_config_impl(
+fn wire__crate__api__group__find_dm_with_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_sfu_config",
+            debug_name: "find_dm_with",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2575,28 +2759,30 @@ fn wire__crate__api__call_webrtc__get_sfu_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_local_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok =
-                        crate::api::call_webrtc::get_sfu_config(api_call_id, api_local_pubkey_hex)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::group::find_dm_with(api_peer_pubkey_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_quality__get_simulcast_config_impl(
+fn wire__crate__api__message__flush_outbox_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_simulcast_config",
+            debug_name: "flush_outbox",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2611,25 +2797,27 @@ fn wire__crate__api__call_quality__get_simulcast_config_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::call_quality::get_simulcast_config())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::flush_outbox().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__get_transcript_segments_impl(
+fn wire__crate__api__contacts__follow_contact_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_transcript_segments",
+            debug_name: "follow_contact",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2643,25 +2831,30 @@ fn wire__crate__api__transcription__get_transcript_segments_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::get_transcript_segments()?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::contacts::follow_contact(api_pubkey_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__get_transcript_text_impl(
+fn wire__crate__api__group__forget_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_transcript_text",
+            debug_name: "forget_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2675,25 +2868,30 @@ fn wire__crate__api__transcription__get_transcript_text_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::get_transcript_text()?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::group::forget_group(api_mls_group_id_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__get_transcription_status_impl(
+fn wire__crate__api__keypackage__generate_key_package_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_transcription_status",
+            debug_name: "generate_key_package",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2707,17 +2905,22 @@ fn wire__crate__api__transcription__get_transcription_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::get_transcription_status()?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::keypackage::generate_key_package(api_relay_urls).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_quality__get_video_constraints_impl(
+fn wire__crate__api__meeting_intelligence__generate_live_meeting_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2725,7 +2928,7 @@ fn wire__crate__api__call_quality__get_video_constraints_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_video_constraints",
+            debug_name: "generate_live_meeting_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2739,13 +2942,14 @@ fn wire__crate__api__call_quality__get_video_constraints_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_preset =
-                <crate::api::call_quality::VideoQualityPreset>::sse_decode(&mut deserializer);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_participants_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::call_quality::get_video_constraints(api_preset),
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::generate_live_meeting_notes(
+                        api_meeting_id,
+                        api_participants_json,
                     )?;
                     Ok(output_ok)
                 })())
@@ -2753,7 +2957,7 @@ fn wire__crate__api__call_quality__get_video_constraints_impl(
         },
     )
 }
-fn wire__crate__api__invite__gift_wrap_welcome_impl(
+fn wire__crate__api__meeting_intelligence__generate_meeting_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2761,7 +2965,7 @@ fn wire__crate__api__invite__gift_wrap_welcome_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "gift_wrap_welcome",
+            debug_name: "generate_meeting_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2775,15 +2979,21 @@ fn wire__crate__api__invite__gift_wrap_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_welcome_rumor_json = <String>::sse_decode(&mut deserializer);
-            let api_recipient_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_segments_json = <String>::sse_decode(&mut deserializer);
+            let api_participants_json = <String>::sse_decode(&mut deserializer);
+            let api_start_time_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_time_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = crate::api::invite::gift_wrap_welcome(
-                            api_welcome_rumor_json,
-                            api_recipient_pubkey_hex,
+                        let output_ok = crate::api::meeting_intelligence::generate_meeting_notes(
+                            api_meeting_id,
+                            api_segments_json,
+                            api_participants_json,
+                            api_start_time_ms,
+                            api_end_time_ms,
                         )
                         .await?;
                         Ok(output_ok)
@@ -2794,16 +3004,17 @@ fn wire__crate__api__invite__gift_wrap_welcome_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__call_webrtc__generate_webrtc_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "generate_webrtc_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2815,16 +3026,22 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_force_relay = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_webrtc::generate_webrtc_config(
+                        api_call_id,
+                        api_force_relay,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__message__group_message_filter_impl(
+fn wire__crate__api__call_session__get_active_calls_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2832,7 +3049,7 @@ fn wire__crate__api__message__group_message_filter_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "group_message_filter",
+            debug_name: "get_active_calls",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2846,13 +3063,11 @@ fn wire__crate__api__message__group_message_filter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::message::group_message_filter(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::call_session::get_active_calls().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2861,15 +3076,15 @@ fn wire__crate__api__message__group_message_filter_impl(
         },
     )
 }
-fn wire__crate__api__account__has_keyring_account_impl(
+fn wire__crate__api__call_quality__get_adaptive_bitrate_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "has_keyring_account",
+            debug_name: "get_adaptive_bitrate_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2884,20 +3099,18 @@ fn wire__crate__api__account__has_keyring_account_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::account::has_keyring_account().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::call_quality::get_adaptive_bitrate_config(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__app_state__import_archived_groups_impl(
+fn wire__crate__api__app_state__get_archived_group_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2905,7 +3118,7 @@ fn wire__crate__api__app_state__import_archived_groups_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "import_archived_groups",
+            debug_name: "get_archived_group_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2919,13 +3132,11 @@ fn wire__crate__api__app_state__import_archived_groups_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::app_state::import_archived_groups(api_group_ids).await?;
+                        let output_ok = crate::api::app_state::get_archived_group_ids().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2934,7 +3145,7 @@ fn wire__crate__api__app_state__import_archived_groups_impl(
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__call_quality__get_audio_constraints_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2942,7 +3153,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_audio_constraints",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2956,19 +3167,20 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode = <crate::api::call_quality::AudioMode>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::call_quality::get_audio_constraints(api_mode),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__state__init_state_impl(
+fn wire__crate__api__contacts__get_cached_contacts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2976,7 +3188,7 @@ fn wire__crate__api__state__init_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_state",
+            debug_name: "get_cached_contacts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2990,12 +3202,11 @@ fn wire__crate__api__state__init_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_keys = <Keys>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::state::init_state(api_keys).await?;
+                        let output_ok = crate::api::contacts::get_cached_contacts().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3004,15 +3215,15 @@ fn wire__crate__api__state__init_state_impl(
         },
     )
 }
-fn wire__crate__api__transcription__init_transcription_impl(
+fn wire__crate__api__identity__get_cached_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_transcription",
+            debug_name: "get_cached_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3026,34 +3237,30 @@ fn wire__crate__api__transcription__init_transcription_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_size = <String>::sse_decode(&mut deserializer);
-            let api_language = <String>::sse_decode(&mut deserializer);
-            let api_translate_to_english = <bool>::sse_decode(&mut deserializer);
-            let api_use_gpu = <bool>::sse_decode(&mut deserializer);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::init_transcription(
-                        api_model_size,
-                        api_language,
-                        api_translate_to_english,
-                        api_use_gpu,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::identity::get_cached_profile(api_pubkey_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__state__initialize_keyring_store_impl(
+fn wire__crate__api__call_webrtc__get_call_participants_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_keyring_store",
+            debug_name: "get_call_participants",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3067,27 +3274,30 @@ fn wire__crate__api__state__initialize_keyring_store_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::state::initialize_keyring_store();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_webrtc::get_call_participants(api_call_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__call_signaling__initiate_call_impl(
+fn wire__crate__api__call_quality__get_codec_preferences_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initiate_call",
+            debug_name: "get_codec_preferences",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3101,40 +3311,27 @@ fn wire__crate__api__call_signaling__initiate_call_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sdp_offer = <String>::sse_decode(&mut deserializer);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_call_type = <String>::sse_decode(&mut deserializer);
-            let api_recipient_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::call_signaling::initiate_call(
-                            api_sdp_offer,
-                            api_call_id,
-                            api_call_type,
-                            api_recipient_pubkey_hex,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::call_quality::get_codec_preferences())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__app_state__is_group_archived_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__contacts__get_contacts_staleness_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_group_archived",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_contacts_staleness",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3146,22 +3343,16 @@ fn wire__crate__api__app_state__is_group_archived_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::app_state::is_group_archived(api_group_id_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::contacts::get_contacts_staleness())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__state__is_initialized_impl(
+fn wire__crate__api__account__get_current_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3169,7 +3360,7 @@ fn wire__crate__api__state__is_initialized_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_initialized",
+            debug_name: "get_current_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3185,10 +3376,9 @@ fn wire__crate__api__state__is_initialized_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::state::is_initialized().await)?;
+                        let output_ok = crate::api::account::get_current_account().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3197,17 +3387,16 @@ fn wire__crate__api__state__is_initialized_impl(
         },
     )
 }
-fn wire__crate__api__account__is_logged_in_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__ephemeral__get_ephemeral_key_strategy_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_logged_in",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_ephemeral_key_strategy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3220,20 +3409,15 @@ fn wire__crate__api__account__is_logged_in_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::account::is_logged_in().await)?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::ephemeral::get_ephemeral_key_strategy())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__group__leave_group_impl(
+fn wire__crate__api__group__get_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3241,7 +3425,7 @@ fn wire__crate__api__group__leave_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "leave_group",
+            debug_name: "get_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3260,8 +3444,7 @@ fn wire__crate__api__group__leave_group_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::group::leave_group(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::group::get_group(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3270,7 +3453,7 @@ fn wire__crate__api__group__leave_group_impl(
         },
     )
 }
-fn wire__crate__api__group__list_groups_impl(
+fn wire__crate__api__group__get_group_epoch_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3278,7 +3461,7 @@ fn wire__crate__api__group__list_groups_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_groups",
+            debug_name: "get_group_epoch_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3292,11 +3475,13 @@ fn wire__crate__api__group__list_groups_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::list_groups().await?;
+                        let output_ok =
+                            crate::api::group::get_group_epoch_events(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3305,7 +3490,7 @@ fn wire__crate__api__group__list_groups_impl(
         },
     )
 }
-fn wire__crate__api__invite__list_pending_welcomes_impl(
+fn wire__crate__api__group__get_group_epoch_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3313,7 +3498,7 @@ fn wire__crate__api__invite__list_pending_welcomes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_pending_welcomes",
+            debug_name: "get_group_epoch_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3327,11 +3512,14 @@ fn wire__crate__api__invite__list_pending_welcomes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::invite::list_pending_welcomes().await?;
+                        let output_ok =
+                            crate::api::group::get_group_epoch_history(api_mls_group_id_hex)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3340,7 +3528,7 @@ fn wire__crate__api__invite__list_pending_welcomes_impl(
         },
     )
 }
-fn wire__crate__api__relay__list_relays_impl(
+fn wire__crate__api__group__get_group_members_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3348,7 +3536,7 @@ fn wire__crate__api__relay__list_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "list_relays",
+            debug_name: "get_group_members",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3362,11 +3550,13 @@ fn wire__crate__api__relay__list_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::relay::list_relays().await?;
+                        let output_ok =
+                            crate::api::group::get_group_members(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3375,7 +3565,7 @@ fn wire__crate__api__relay__list_relays_impl(
         },
     )
 }
-fn wire__crate__api__call_signaling__listen_for_call_events_impl(
+fn wire__crate__api__group__get_group_message_ttl_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3383,7 +3573,7 @@ fn wire__crate__api__call_signaling__listen_for_call_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "listen_for_call_events",
+            debug_name: "get_group_message_ttl",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3397,16 +3587,13 @@ fn wire__crate__api__call_signaling__listen_for_call_events_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::call_signaling::CallSignalingEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::call_signaling::listen_for_call_events(api_sink).await?;
+                            crate::api::group::get_group_message_ttl(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3415,7 +3602,7 @@ fn wire__crate__api__call_signaling__listen_for_call_events_impl(
         },
     )
 }
-fn wire__crate__api__message__listen_for_group_messages_impl(
+fn wire__crate__api__group__get_group_raw_extension_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3423,7 +3610,7 @@ fn wire__crate__api__message__listen_for_group_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "listen_for_group_messages",
+            debug_name: "get_group_raw_extension",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3437,16 +3624,14 @@ fn wire__crate__api__message__listen_for_group_messages_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::message::GroupNotification,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::message::listen_for_group_messages(api_sink).await?;
+                            crate::api::group::get_group_raw_extension(api_mls_group_id_hex)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3455,7 +3640,7 @@ fn wire__crate__api__message__listen_for_group_messages_impl(
         },
     )
 }
-fn wire__crate__api__account__load_account_from_keyring_impl(
+fn wire__crate__api__group__get_group_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3463,7 +3648,7 @@ fn wire__crate__api__account__load_account_from_keyring_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_account_from_keyring",
+            debug_name: "get_group_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3477,11 +3662,13 @@ fn wire__crate__api__account__load_account_from_keyring_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::account::load_account_from_keyring().await?;
+                        let output_ok =
+                            crate::api::group::get_group_relays(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3490,7 +3677,7 @@ fn wire__crate__api__account__load_account_from_keyring_impl(
         },
     )
 }
-fn wire__crate__api__account__login_impl(
+fn wire__crate__api__app_state__get_group_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3498,7 +3685,7 @@ fn wire__crate__api__account__login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "login",
+            debug_name: "get_group_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3512,12 +3699,15 @@ fn wire__crate__api__account__login_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_secret_key = <String>::sse_decode(&mut deserializer);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::account::login(api_secret_key).await?;
+                        let output_ok =
+                            crate::api::app_state::get_group_state(api_group_id_hex, api_key)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3526,7 +3716,7 @@ fn wire__crate__api__account__login_impl(
         },
     )
 }
-fn wire__crate__api__account__logout_impl(
+fn wire__crate__api__app_state__get_group_summary_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3534,7 +3724,7 @@ fn wire__crate__api__account__logout_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "logout",
+            debug_name: "get_group_summary",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3548,11 +3738,13 @@ fn wire__crate__api__account__logout_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::account::logout().await?;
+                        let output_ok =
+                            crate::api::app_state::get_group_summary(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3561,17 +3753,16 @@ fn wire__crate__api__account__logout_impl(
         },
     )
 }
-fn wire__crate__api__app_state__mark_group_read_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__invite__get_key_package_max_age_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mark_group_read",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_key_package_max_age",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3583,28 +3774,15 @@ fn wire__crate__api__app_state__mark_group_read_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_last_event_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_timestamp = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::app_state::mark_group_read(
-                            api_group_id_hex,
-                            api_last_event_id_hex,
-                            api_timestamp,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::invite::get_key_package_max_age())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__group__merge_pending_commit_impl(
+fn wire__crate__api__contacts__get_last_contacts_sync_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3612,7 +3790,7 @@ fn wire__crate__api__group__merge_pending_commit_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "merge_pending_commit",
+            debug_name: "get_last_contacts_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3626,13 +3804,11 @@ fn wire__crate__api__group__merge_pending_commit_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::group::merge_pending_commit(api_mls_group_id_hex).await?;
+                        let output_ok = crate::api::contacts::get_last_contacts_sync().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3641,15 +3817,15 @@ fn wire__crate__api__group__merge_pending_commit_impl(
         },
     )
 }
-fn wire__crate__api__media__parse_imeta_tag_impl(
+fn wire__crate__api__app_state__get_last_read_timestamp_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_imeta_tag",
+            debug_name: "get_last_read_timestamp",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3663,61 +3839,32 @@ fn wire__crate__api__media__parse_imeta_tag_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag_values = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::media::parse_imeta_tag(api_tag_values)?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__call_webrtc__parse_sdp_answer_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_sdp_answer",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::app_state::get_last_read_timestamp(api_group_id_hex)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
                 )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sdp = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_webrtc::parse_sdp_answer(api_sdp)?;
-                    Ok(output_ok)
-                })())
             }
         },
     )
 }
-fn wire__crate__api__call_webrtc__parse_sdp_offer_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__state__get_max_concurrent_relay_ops_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_sdp_offer",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_max_concurrent_relay_ops",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3729,28 +3876,25 @@ fn wire__crate__api__call_webrtc__parse_sdp_offer_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sdp = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_webrtc::parse_sdp_offer(api_sdp)?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::state::get_max_concurrent_relay_ops())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__transcription__pause_transcription_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__message__get_max_message_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pause_transcription",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_max_message_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3763,26 +3907,23 @@ fn wire__crate__api__transcription__pause_transcription_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::pause_transcription()?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::message::get_max_message_bytes())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__call_signaling__process_call_event_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__media__get_media_cache_limit_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_call_event",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_media_cache_limit",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3794,22 +3935,15 @@ fn wire__crate__api__call_signaling__process_call_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::call_signaling::process_call_event(api_event_json).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::media::get_media_cache_limit())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__message__process_message_impl(
+fn wire__crate__api__media__get_media_cache_usage_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3817,7 +3951,7 @@ fn wire__crate__api__message__process_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_message",
+            debug_name: "get_media_cache_usage",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3831,13 +3965,11 @@ fn wire__crate__api__message__process_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::message::process_message(api_event_json).await?;
+                        let output_ok = crate::api::media::get_media_cache_usage().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3846,15 +3978,15 @@ fn wire__crate__api__message__process_message_impl(
         },
     )
 }
-fn wire__crate__api__invite__process_welcome_impl(
+fn wire__crate__api__meeting_intelligence__get_meeting_archive_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "process_welcome",
+            debug_name: "get_meeting_archive",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3868,26 +4000,17 @@ fn wire__crate__api__invite__process_welcome_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_wrapper_event_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_welcome_rumor_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::invite::process_welcome(
-                            api_wrapper_event_id_hex,
-                            api_welcome_rumor_json,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::get_meeting_archive()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__identity__profile_data_default_impl(
+fn wire__crate__api__meeting_intelligence__get_meeting_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3895,7 +4018,7 @@ fn wire__crate__api__identity__profile_data_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "profile_data_default",
+            debug_name: "get_meeting_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3909,18 +4032,19 @@ fn wire__crate__api__identity__profile_data_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, String>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::identity::ProfileData::default())?;
+                        crate::api::meeting_intelligence::get_meeting_notes(api_meeting_id)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__relay__publish_event_json_impl(
+fn wire__crate__api__message__get_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3928,7 +4052,7 @@ fn wire__crate__api__relay__publish_event_json_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "publish_event_json",
+            debug_name: "get_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3942,13 +4066,17 @@ fn wire__crate__api__relay__publish_event_json_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_json = <String>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_event_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::relay::publish_event_json(api_event_json).await?;
+                        let output_ok = crate::api::message::get_message(
+                            api_mls_group_id_hex,
+                            api_event_id_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3957,7 +4085,7 @@ fn wire__crate__api__relay__publish_event_json_impl(
         },
     )
 }
-fn wire__crate__api__relay__publish_event_json_to_relay_impl(
+fn wire__crate__api__message__get_message_reactions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3965,7 +4093,7 @@ fn wire__crate__api__relay__publish_event_json_to_relay_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "publish_event_json_to_relay",
+            debug_name: "get_message_reactions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3979,15 +4107,15 @@ fn wire__crate__api__relay__publish_event_json_to_relay_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_json = <String>::sse_decode(&mut deserializer);
-            let api_relay_url = <String>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_target_event_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::relay::publish_event_json_to_relay(
-                            api_event_json,
-                            api_relay_url,
+                        let output_ok = crate::api::message::get_message_reactions(
+                            api_mls_group_id_hex,
+                            api_target_event_id_hex,
                         )
                         .await?;
                         Ok(output_ok)
@@ -3998,7 +4126,7 @@ fn wire__crate__api__relay__publish_event_json_to_relay_impl(
         },
     )
 }
-fn wire__crate__api__keypackage__publish_key_package_impl(
+fn wire__crate__api__message__get_message_receipts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4006,7 +4134,7 @@ fn wire__crate__api__keypackage__publish_key_package_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "publish_key_package",
+            debug_name: "get_message_receipts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4020,13 +4148,17 @@ fn wire__crate__api__keypackage__publish_key_package_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_event_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::keypackage::publish_key_package(api_relay_urls).await?;
+                        let output_ok = crate::api::message::get_message_receipts(
+                            api_mls_group_id_hex,
+                            api_event_id_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4035,7 +4167,7 @@ fn wire__crate__api__keypackage__publish_key_package_impl(
         },
     )
 }
-fn wire__crate__api__keypackage__publish_key_package_relays_impl(
+fn wire__crate__api__message__get_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4043,7 +4175,7 @@ fn wire__crate__api__keypackage__publish_key_package_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "publish_key_package_relays",
+            debug_name: "get_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4057,14 +4189,21 @@ fn wire__crate__api__keypackage__publish_key_package_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_expired = <Option<bool>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::keypackage::publish_key_package_relays(api_relay_urls)
-                                .await?;
+                        let output_ok = crate::api::message::get_messages(
+                            api_mls_group_id_hex,
+                            api_limit,
+                            api_offset,
+                            api_include_expired,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4073,17 +4212,16 @@ fn wire__crate__api__keypackage__publish_key_package_relays_impl(
         },
     )
 }
-fn wire__crate__api__call_quality__recommend_quality_preset_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__contacts__get_nip05_verification_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "recommend_quality_preset",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_nip05_verification",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -4095,25 +4233,16 @@ fn wire__crate__api__call_quality__recommend_quality_preset_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_quality_score = <f64>::sse_decode(&mut deserializer);
-            let api_estimated_bandwidth_kbps = <f64>::sse_decode(&mut deserializer);
-            let api_participant_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::call_quality::recommend_quality_preset(
-                            api_quality_score,
-                            api_estimated_bandwidth_kbps,
-                            api_participant_count,
-                        ))?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::contacts::get_nip05_verification())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__transcription__register_speaker_impl(
+fn wire__crate__api__meeting_intelligence__get_open_action_items_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4121,7 +4250,7 @@ fn wire__crate__api__transcription__register_speaker_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "register_speaker",
+            debug_name: "get_open_action_items",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4135,16 +4264,12 @@ fn wire__crate__api__transcription__register_speaker_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_track_id = <String>::sse_decode(&mut deserializer);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_display_name = <String>::sse_decode(&mut deserializer);
+            let api_assignee_pubkey = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::register_speaker(
-                        api_track_id,
-                        api_pubkey_hex,
-                        api_display_name,
+                    let output_ok = crate::api::meeting_intelligence::get_open_action_items(
+                        api_assignee_pubkey,
                     )?;
                     Ok(output_ok)
                 })())
@@ -4152,7 +4277,7 @@ fn wire__crate__api__transcription__register_speaker_impl(
         },
     )
 }
-fn wire__crate__api__call_signaling__reject_call_impl(
+fn wire__crate__api__app_state__get_outbox_count_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4160,7 +4285,7 @@ fn wire__crate__api__call_signaling__reject_call_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reject_call",
+            debug_name: "get_outbox_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4174,19 +4299,11 @@ fn wire__crate__api__call_signaling__reject_call_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_caller_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_signaling::reject_call(
-                            api_call_id,
-                            api_caller_pubkey_hex,
-                            api_reason,
-                        )
-                        .await?;
+                        let output_ok = crate::api::app_state::get_outbox_count().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4195,7 +4312,7 @@ fn wire__crate__api__call_signaling__reject_call_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__remove_call_peers_impl(
+fn wire__crate__api__call_webrtc__get_peer_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4203,7 +4320,7 @@ fn wire__crate__api__call_webrtc__remove_call_peers_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_call_peers",
+            debug_name: "get_peer_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4217,13 +4334,14 @@ fn wire__crate__api__call_webrtc__remove_call_peers_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::call_webrtc::remove_call_peers(api_call_id).await?;
+                            crate::api::call_webrtc::get_peer_stats(api_participant_pubkey_hex)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4232,15 +4350,15 @@ fn wire__crate__api__call_webrtc__remove_call_peers_impl(
         },
     )
 }
-fn wire__crate__api__group__remove_group_image_impl(
+fn wire__crate__api__meeting_intelligence__get_redact_patterns_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_group_image",
+            debug_name: "get_redact_patterns",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4254,22 +4372,17 @@ fn wire__crate__api__group__remove_group_image_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::group::remove_group_image(api_mls_group_id_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::get_redact_patterns()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__invite__remove_members_impl(
+fn wire__crate__api__relay__get_relay_roles_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4277,7 +4390,7 @@ fn wire__crate__api__invite__remove_members_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_members",
+            debug_name: "get_relay_roles",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4291,17 +4404,11 @@ fn wire__crate__api__invite__remove_members_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_pubkeys_hex = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::invite::remove_members(
-                            api_mls_group_id_hex,
-                            api_pubkeys_hex,
-                        )
-                        .await?;
+                        let output_ok = crate::api::relay::get_relay_roles().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4310,7 +4417,7 @@ fn wire__crate__api__invite__remove_members_impl(
         },
     )
 }
-fn wire__crate__api__relay__remove_relay_impl(
+fn wire__crate__api__call_session__get_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4318,7 +4425,7 @@ fn wire__crate__api__relay__remove_relay_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_relay",
+            debug_name: "get_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4332,12 +4439,12 @@ fn wire__crate__api__relay__remove_relay_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::relay::remove_relay(api_url).await?;
+                        let output_ok = crate::api::call_session::get_session(api_call_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4346,15 +4453,15 @@ fn wire__crate__api__relay__remove_relay_impl(
         },
     )
 }
-fn wire__crate__api__call_session__remove_session_impl(
+fn wire__crate__api__call_webrtc__get_sfu_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_session",
+            debug_name: "get_sfu_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4369,21 +4476,19 @@ fn wire__crate__api__call_session__remove_session_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_local_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::call_session::remove_session(api_call_id).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok =
+                        crate::api::call_webrtc::get_sfu_config(api_call_id, api_local_pubkey_hex)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__call_webrtc__report_peer_stats_impl(
+fn wire__crate__api__account__get_signer_kind_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4391,7 +4496,7 @@ fn wire__crate__api__call_webrtc__report_peer_stats_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "report_peer_stats",
+            debug_name: "get_signer_kind",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4405,23 +4510,11 @@ fn wire__crate__api__call_webrtc__report_peer_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_rtt_ms = <Option<f64>>::sse_decode(&mut deserializer);
-            let api_packet_loss_percent = <Option<f64>>::sse_decode(&mut deserializer);
-            let api_outgoing_bitrate_kbps = <Option<f64>>::sse_decode(&mut deserializer);
-            let api_incoming_bitrate_kbps = <Option<f64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_webrtc::report_peer_stats(
-                            api_participant_pubkey_hex,
-                            api_rtt_ms,
-                            api_packet_loss_percent,
-                            api_outgoing_bitrate_kbps,
-                            api_incoming_bitrate_kbps,
-                        )
-                        .await?;
+                        let output_ok = crate::api::account::get_signer_kind().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4430,7 +4523,7 @@ fn wire__crate__api__call_webrtc__report_peer_stats_impl(
         },
     )
 }
-fn wire__crate__api__transcription__resume_transcription_impl(
+fn wire__crate__api__call_quality__get_simulcast_config_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4438,7 +4531,7 @@ fn wire__crate__api__transcription__resume_transcription_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "resume_transcription",
+            debug_name: "get_simulcast_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4454,15 +4547,16 @@ fn wire__crate__api__transcription__resume_transcription_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::resume_transcription()?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::call_quality::get_simulcast_config())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__call_webrtc__rotate_frame_key_impl(
+fn wire__crate__api__transcription__get_transcript_segments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4470,7 +4564,7 @@ fn wire__crate__api__call_webrtc__rotate_frame_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rotate_frame_key",
+            debug_name: "get_transcript_segments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4484,61 +4578,25 @@ fn wire__crate__api__call_webrtc__rotate_frame_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_current_key_hex = <String>::sse_decode(&mut deserializer);
-            let api_new_epoch = <u64>::sse_decode(&mut deserializer);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
-                    let output_ok = crate::api::call_webrtc::rotate_frame_key(
-                        api_current_key_hex,
-                        api_new_epoch,
-                        api_call_id,
-                    )?;
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::get_transcript_segments()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__simple__rust_lib_version_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rust_lib_version",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::rust_lib_version())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__account__save_secret_key_to_keyring_impl(
+fn wire__crate__api__transcription__get_transcript_text_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_secret_key_to_keyring",
+            debug_name: "get_transcript_text",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4553,19 +4611,16 @@ fn wire__crate__api__account__save_secret_key_to_keyring_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::account::save_secret_key_to_keyring().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::get_transcript_text()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__search_meetings_impl(
+fn wire__crate__api__transcription__get_transcription_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4573,7 +4628,7 @@ fn wire__crate__api__meeting_intelligence__search_meetings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_meetings",
+            debug_name: "get_transcription_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4587,18 +4642,17 @@ fn wire__crate__api__meeting_intelligence__search_meetings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::meeting_intelligence::search_meetings(api_query)?;
+                    let output_ok = crate::api::transcription::get_transcription_status()?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__transcription__search_transcript_impl(
+fn wire__crate__api__call_quality__get_video_constraints_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4606,7 +4660,7 @@ fn wire__crate__api__transcription__search_transcript_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_transcript",
+            debug_name: "get_video_constraints",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4620,18 +4674,21 @@ fn wire__crate__api__transcription__search_transcript_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_preset =
+                <crate::api::call_quality::VideoQualityPreset>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::search_transcript(api_query)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::call_quality::get_video_constraints(api_preset),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__call_signaling__send_call_state_update_impl(
+fn wire__crate__api__invite__gift_wrap_welcome_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4639,7 +4696,7 @@ fn wire__crate__api__call_signaling__send_call_state_update_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_call_state_update",
+            debug_name: "gift_wrap_welcome",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4653,19 +4710,15 @@ fn wire__crate__api__call_signaling__send_call_state_update_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_is_muted = <Option<bool>>::sse_decode(&mut deserializer);
-            let api_is_video_enabled = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_welcome_rumor_json = <String>::sse_decode(&mut deserializer);
+            let api_recipient_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_signaling::send_call_state_update(
-                            api_call_id,
-                            api_remote_pubkey_hex,
-                            api_is_muted,
-                            api_is_video_enabled,
+                        let output_ok = crate::api::invite::gift_wrap_welcome(
+                            api_welcome_rumor_json,
+                            api_recipient_pubkey_hex,
                         )
                         .await?;
                         Ok(output_ok)
@@ -4676,17 +4729,16 @@ fn wire__crate__api__call_signaling__send_call_state_update_impl(
         },
     )
 }
-fn wire__crate__api__call_signaling__send_ice_candidate_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__simple__greet_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_ice_candidate",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -4698,32 +4750,16 @@ fn wire__crate__api__call_signaling__send_ice_candidate_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_candidate = <String>::sse_decode(&mut deserializer);
-            let api_sdp_mid = <Option<String>>::sse_decode(&mut deserializer);
-            let api_sdp_m_line_index = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::call_signaling::send_ice_candidate(
-                            api_candidate,
-                            api_sdp_mid,
-                            api_sdp_m_line_index,
-                            api_call_id,
-                            api_remote_pubkey_hex,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__message__send_message_impl(
+fn wire__crate__api__message__group_message_filter_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4731,7 +4767,7 @@ fn wire__crate__api__message__send_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message",
+            debug_name: "group_message_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4746,14 +4782,12 @@ fn wire__crate__api__message__send_message_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::message::send_message(api_mls_group_id_hex, api_content)
-                                .await?;
+                            crate::api::message::group_message_filter(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4762,7 +4796,7 @@ fn wire__crate__api__message__send_message_impl(
         },
     )
 }
-fn wire__crate__api__message__send_message_with_media_impl(
+fn wire__crate__api__account__has_keyring_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4770,7 +4804,7 @@ fn wire__crate__api__message__send_message_with_media_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message_with_media",
+            debug_name: "has_keyring_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4784,19 +4818,12 @@ fn wire__crate__api__message__send_message_with_media_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_imeta_tags_json = <Vec<Vec<String>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::message::send_message_with_media(
-                            api_mls_group_id_hex,
-                            api_content,
-                            api_imeta_tags_json,
-                        )
-                        .await?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::account::has_keyring_account().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4805,7 +4832,7 @@ fn wire__crate__api__message__send_message_with_media_impl(
         },
     )
 }
-fn wire__crate__api__message__send_poll_impl(
+fn wire__crate__api__app_state__import_archived_groups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4813,7 +4840,7 @@ fn wire__crate__api__message__send_poll_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_poll",
+            debug_name: "import_archived_groups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4827,19 +4854,13 @@ fn wire__crate__api__message__send_poll_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_question = <String>::sse_decode(&mut deserializer);
-            let api_options = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_group_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::send_poll(
-                            api_mls_group_id_hex,
-                            api_question,
-                            api_options,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::app_state::import_archived_groups(api_group_ids).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4848,7 +4869,7 @@ fn wire__crate__api__message__send_poll_impl(
         },
     )
 }
-fn wire__crate__api__message__send_poll_vote_impl(
+fn wire__crate__api__contacts__import_contacts_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4856,7 +4877,7 @@ fn wire__crate__api__message__send_poll_vote_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_poll_vote",
+            debug_name: "import_contacts_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4870,19 +4891,13 @@ fn wire__crate__api__message__send_poll_vote_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_poll_event_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_option_index = <u32>::sse_decode(&mut deserializer);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::send_poll_vote(
-                            api_mls_group_id_hex,
-                            api_poll_event_id_hex,
-                            api_option_index,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::contacts::import_contacts_list(api_event_json).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4891,7 +4906,7 @@ fn wire__crate__api__message__send_poll_vote_impl(
         },
     )
 }
-fn wire__crate__api__message__send_reaction_impl(
+fn wire__crate__api__backup__import_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4899,7 +4914,7 @@ fn wire__crate__api__message__send_reaction_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_reaction",
+            debug_name: "import_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4913,19 +4928,14 @@ fn wire__crate__api__message__send_reaction_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_target_event_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_emoji = <String>::sse_decode(&mut deserializer);
+            let api_bundle = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::send_reaction(
-                            api_mls_group_id_hex,
-                            api_target_event_id_hex,
-                            api_emoji,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::backup::import_state(api_bundle, api_passphrase).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4934,7 +4944,41 @@ fn wire__crate__api__message__send_reaction_impl(
         },
     )
 }
-fn wire__crate__api__message__send_typing_indicator_impl(
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__state__init_state_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4942,7 +4986,7 @@ fn wire__crate__api__message__send_typing_indicator_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_typing_indicator",
+            debug_name: "init_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4956,14 +5000,12 @@ fn wire__crate__api__message__send_typing_indicator_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_keys = <Keys>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::message::send_typing_indicator(api_mls_group_id_hex)
-                                .await?;
+                        let output_ok = crate::api::state::init_state(api_keys).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4972,7 +5014,7 @@ fn wire__crate__api__message__send_typing_indicator_impl(
         },
     )
 }
-fn wire__crate__api__state__set_data_dir_impl(
+fn wire__crate__api__transcription__init_transcription_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4980,7 +5022,48 @@ fn wire__crate__api__state__set_data_dir_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_data_dir",
+            debug_name: "init_transcription",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_size = <String>::sse_decode(&mut deserializer);
+            let api_language = <String>::sse_decode(&mut deserializer);
+            let api_translate_to_english = <bool>::sse_decode(&mut deserializer);
+            let api_use_gpu = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::init_transcription(
+                        api_model_size,
+                        api_language,
+                        api_translate_to_english,
+                        api_use_gpu,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__state__initialize_keyring_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_keyring_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4994,12 +5077,11 @@ fn wire__crate__api__state__set_data_dir_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::state::set_data_dir(api_path);
+                        crate::api::state::initialize_keyring_store();
                     })?;
                     Ok(output_ok)
                 })())
@@ -5007,7 +5089,7 @@ fn wire__crate__api__state__set_data_dir_impl(
         },
     )
 }
-fn wire__crate__api__app_state__set_group_state_impl(
+fn wire__crate__api__call_signaling__initiate_call_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5015,7 +5097,7 @@ fn wire__crate__api__app_state__set_group_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_group_state",
+            debug_name: "initiate_call",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5029,17 +5111,19 @@ fn wire__crate__api__app_state__set_group_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_key = <String>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
+            let api_sdp_offer = <String>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_call_type = <String>::sse_decode(&mut deserializer);
+            let api_recipient_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::app_state::set_group_state(
-                            api_group_id_hex,
-                            api_key,
-                            api_value,
+                        let output_ok = crate::api::call_signaling::initiate_call(
+                            api_sdp_offer,
+                            api_call_id,
+                            api_call_type,
+                            api_recipient_pubkey_hex,
                         )
                         .await?;
                         Ok(output_ok)
@@ -5050,7 +5134,7 @@ fn wire__crate__api__app_state__set_group_state_impl(
         },
     )
 }
-fn wire__crate__api__call_session__set_muted_impl(
+fn wire__crate__api__call_signaling__initiate_call_fragments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5058,7 +5142,7 @@ fn wire__crate__api__call_session__set_muted_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_muted",
+            debug_name: "initiate_call_fragments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5072,14 +5156,21 @@ fn wire__crate__api__call_session__set_muted_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sdp_offer = <String>::sse_decode(&mut deserializer);
             let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_muted = <bool>::sse_decode(&mut deserializer);
+            let api_call_type = <String>::sse_decode(&mut deserializer);
+            let api_recipient_pubkey_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::call_session::set_muted(api_call_id, api_muted).await?;
+                        let output_ok = crate::api::call_signaling::initiate_call_fragments(
+                            api_sdp_offer,
+                            api_call_id,
+                            api_call_type,
+                            api_recipient_pubkey_hex,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5088,7 +5179,7 @@ fn wire__crate__api__call_session__set_muted_impl(
         },
     )
 }
-fn wire__crate__api__identity__set_profile_impl(
+fn wire__crate__api__invite__invite_members_full_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5096,7 +5187,7 @@ fn wire__crate__api__identity__set_profile_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_profile",
+            debug_name: "invite_members_full",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5110,12 +5201,17 @@ fn wire__crate__api__identity__set_profile_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_profile = <crate::api::identity::ProfileData>::sse_decode(&mut deserializer);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_key_package_events_json = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::identity::set_profile(api_profile).await?;
+                        let output_ok = crate::api::invite::invite_members_full(
+                            api_mls_group_id_hex,
+                            api_key_package_events_json,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5124,7 +5220,7 @@ fn wire__crate__api__identity__set_profile_impl(
         },
     )
 }
-fn wire__crate__api__call_session__set_video_enabled_impl(
+fn wire__crate__api__app_state__is_group_archived_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5132,7 +5228,7 @@ fn wire__crate__api__call_session__set_video_enabled_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_video_enabled",
+            debug_name: "is_group_archived",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5146,15 +5242,13 @@ fn wire__crate__api__call_session__set_video_enabled_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::call_session::set_video_enabled(api_call_id, api_enabled)
-                                .await?;
+                            crate::api::app_state::is_group_archived(api_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5163,15 +5257,15 @@ fn wire__crate__api__call_session__set_video_enabled_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__should_use_sfu_impl(
+fn wire__crate__api__state__is_initialized_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "should_use_sfu",
+            debug_name: "is_initialized",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5185,28 +5279,29 @@ fn wire__crate__api__call_webrtc__should_use_sfu_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_participant_count = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::call_webrtc::should_use_sfu(
-                        api_participant_count,
-                    ))?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::state::is_initialized().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__start_transcription_impl(
+fn wire__crate__api__account__is_logged_in_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_transcription",
+            debug_name: "is_logged_in",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5220,28 +5315,30 @@ fn wire__crate__api__transcription__start_transcription_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::start_transcription(api_call_id)?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::account::is_logged_in().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__stop_transcription_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__message__is_message_expired_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_transcription",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "is_message_expired",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -5253,17 +5350,19 @@ fn wire__crate__api__transcription__stop_transcription_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tags = <Vec<Vec<String>>>::sse_decode(&mut deserializer);
+            let api_now = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::transcription::stop_transcription()?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::message::is_message_expired(
+                    api_tags, api_now,
+                ))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__call_signaling__subscribe_call_events_impl(
+fn wire__crate__api__group__leave_group_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5271,7 +5370,7 @@ fn wire__crate__api__call_signaling__subscribe_call_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "subscribe_call_events",
+            debug_name: "leave_group",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5285,11 +5384,13 @@ fn wire__crate__api__call_signaling__subscribe_call_events_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_signaling::subscribe_call_events().await?;
+                        let output_ok =
+                            crate::api::group::leave_group(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5298,7 +5399,7 @@ fn wire__crate__api__call_signaling__subscribe_call_events_impl(
         },
     )
 }
-fn wire__crate__api__contacts__sync_contacts_impl(
+fn wire__crate__api__group__list_groups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5306,7 +5407,7 @@ fn wire__crate__api__contacts__sync_contacts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_contacts",
+            debug_name: "list_groups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5324,7 +5425,7 @@ fn wire__crate__api__contacts__sync_contacts_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::contacts::sync_contacts().await?;
+                        let output_ok = crate::api::group::list_groups().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5333,7 +5434,7 @@ fn wire__crate__api__contacts__sync_contacts_impl(
         },
     )
 }
-fn wire__crate__api__message__sync_group_messages_impl(
+fn wire__crate__api__group__list_inactive_groups_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5341,7 +5442,7 @@ fn wire__crate__api__message__sync_group_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_group_messages",
+            debug_name: "list_inactive_groups",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5359,7 +5460,7 @@ fn wire__crate__api__message__sync_group_messages_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::message::sync_group_messages().await?;
+                        let output_ok = crate::api::group::list_inactive_groups().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5368,7 +5469,7 @@ fn wire__crate__api__message__sync_group_messages_impl(
         },
     )
 }
-fn wire__crate__api__invite__sync_welcomes_impl(
+fn wire__crate__api__invite__list_pending_welcomes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5376,7 +5477,7 @@ fn wire__crate__api__invite__sync_welcomes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_welcomes",
+            debug_name: "list_pending_welcomes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5394,7 +5495,7 @@ fn wire__crate__api__invite__sync_welcomes_impl(
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::invite::sync_welcomes().await?;
+                        let output_ok = crate::api::invite::list_pending_welcomes().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5403,15 +5504,15 @@ fn wire__crate__api__invite__sync_welcomes_impl(
         },
     )
 }
-fn wire__crate__api__meeting_intelligence__toggle_action_item_impl(
+fn wire__crate__api__relay__list_relays_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "toggle_action_item",
+            debug_name: "list_relays",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5425,30 +5526,28 @@ fn wire__crate__api__meeting_intelligence__toggle_action_item_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_meeting_id = <String>::sse_decode(&mut deserializer);
-            let api_action_item_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::meeting_intelligence::toggle_action_item(
-                        api_meeting_id,
-                        api_action_item_id,
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::list_relays().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__transcription__transcription_config_default_impl(
+fn wire__crate__api__call_signaling__listen_for_call_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "transcription_config_default",
+            debug_name: "listen_for_call_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5462,19 +5561,25 @@ fn wire__crate__api__transcription__transcription_config_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::call_signaling::CallSignalingEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::transcription::TranscriptionConfig::default(),
-                    )?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_signaling::listen_for_call_events(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__app_state__unarchive_group_impl(
+fn wire__crate__api__message__listen_for_group_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5482,7 +5587,7 @@ fn wire__crate__api__app_state__unarchive_group_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unarchive_group",
+            debug_name: "listen_for_group_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5496,13 +5601,16 @@ fn wire__crate__api__app_state__unarchive_group_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::message::GroupNotification,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::app_state::unarchive_group(api_group_id_hex).await?;
+                            crate::api::message::listen_for_group_messages(api_sink).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5511,7 +5619,7 @@ fn wire__crate__api__app_state__unarchive_group_impl(
         },
     )
 }
-fn wire__crate__api__contacts__unfollow_contact_impl(
+fn wire__crate__api__message__listen_for_local_sends_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5519,7 +5627,7 @@ fn wire__crate__api__contacts__unfollow_contact_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unfollow_contact",
+            debug_name: "listen_for_local_sends",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5533,13 +5641,16 @@ fn wire__crate__api__contacts__unfollow_contact_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::message::GroupMessage,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::contacts::unfollow_contact(api_pubkey_hex).await?;
+                            crate::api::message::listen_for_local_sends(api_sink).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5548,7 +5659,7 @@ fn wire__crate__api__contacts__unfollow_contact_impl(
         },
     )
 }
-fn wire__crate__api__group__update_group_description_impl(
+fn wire__crate__api__account__load_account_from_keyring_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5556,7 +5667,7 @@ fn wire__crate__api__group__update_group_description_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_group_description",
+            debug_name: "load_account_from_keyring",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5570,17 +5681,11 @@ fn wire__crate__api__group__update_group_description_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_description = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::update_group_description(
-                            api_mls_group_id_hex,
-                            api_description,
-                        )
-                        .await?;
+                        let output_ok = crate::api::account::load_account_from_keyring().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5589,7 +5694,7 @@ fn wire__crate__api__group__update_group_description_impl(
         },
     )
 }
-fn wire__crate__api__group__update_group_name_impl(
+fn wire__crate__api__account__login_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5597,7 +5702,7 @@ fn wire__crate__api__group__update_group_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_group_name",
+            debug_name: "login",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5611,15 +5716,12 @@ fn wire__crate__api__group__update_group_name_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_secret_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::group::update_group_name(api_mls_group_id_hex, api_name)
-                                .await?;
+                        let output_ok = crate::api::account::login(api_secret_key).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5628,7 +5730,7 @@ fn wire__crate__api__group__update_group_name_impl(
         },
     )
 }
-fn wire__crate__api__group__update_group_relays_impl(
+fn wire__crate__api__account__login_with_bunker_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5636,7 +5738,7 @@ fn wire__crate__api__group__update_group_relays_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_group_relays",
+            debug_name: "login_with_bunker",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5650,15 +5752,15 @@ fn wire__crate__api__group__update_group_relays_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_bunker_uri = <String>::sse_decode(&mut deserializer);
+            let api_app_secret_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::update_group_relays(
-                            api_mls_group_id_hex,
-                            api_relay_urls,
+                        let output_ok = crate::api::account::login_with_bunker(
+                            api_bunker_uri,
+                            api_app_secret_key,
                         )
                         .await?;
                         Ok(output_ok)
@@ -5669,7 +5771,7 @@ fn wire__crate__api__group__update_group_relays_impl(
         },
     )
 }
-fn wire__crate__api__call_webrtc__update_peer_state_impl(
+fn wire__crate__api__account__logout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5677,7 +5779,7 @@ fn wire__crate__api__call_webrtc__update_peer_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_peer_state",
+            debug_name: "logout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5691,19 +5793,11 @@ fn wire__crate__api__call_webrtc__update_peer_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
-            let api_state = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::call_webrtc::update_peer_state(
-                            api_call_id,
-                            api_participant_pubkey_hex,
-                            api_state,
-                        )
-                        .await?;
+                        let output_ok = crate::api::account::logout().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5712,7 +5806,7 @@ fn wire__crate__api__call_webrtc__update_peer_state_impl(
         },
     )
 }
-fn wire__crate__api__call_session__update_session_state_impl(
+fn wire__crate__api__app_state__mark_group_read_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5720,7 +5814,7 @@ fn wire__crate__api__call_session__update_session_state_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_session_state",
+            debug_name: "mark_group_read",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5734,15 +5828,19 @@ fn wire__crate__api__call_session__update_session_state_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_call_id = <String>::sse_decode(&mut deserializer);
-            let api_state = <String>::sse_decode(&mut deserializer);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_last_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_timestamp = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::call_session::update_session_state(api_call_id, api_state)
-                                .await?;
+                        let output_ok = crate::api::app_state::mark_group_read(
+                            api_group_id_hex,
+                            api_last_event_id_hex,
+                            api_timestamp,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5751,7 +5849,7 @@ fn wire__crate__api__call_session__update_session_state_impl(
         },
     )
 }
-fn wire__crate__api__group__upload_group_image_impl(
+fn wire__crate__api__group__merge_pending_commit_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5759,7 +5857,7 @@ fn wire__crate__api__group__upload_group_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_group_image",
+            debug_name: "merge_pending_commit",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5774,20 +5872,12 @@ fn wire__crate__api__group__upload_group_image_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_image_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::api::error::BurrowError>(
                     (move || async move {
-                        let output_ok = crate::api::group::upload_group_image(
-                            api_mls_group_id_hex,
-                            api_image_data,
-                            api_mime_type,
-                            api_blossom_server_url,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::group::merge_pending_commit(api_mls_group_id_hex).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5796,15 +5886,15 @@ fn wire__crate__api__group__upload_group_image_impl(
         },
     )
 }
-fn wire__crate__api__media__upload_media_impl(
+fn wire__crate__api__message__outbox_flush_result_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_media",
+            debug_name: "outbox_flush_result_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5818,40 +5908,26 @@ fn wire__crate__api__media__upload_media_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
-            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_filename = <String>::sse_decode(&mut deserializer);
-            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::media::upload_media(
-                            api_mls_group_id_hex,
-                            api_file_data,
-                            api_mime_type,
-                            api_filename,
-                            api_blossom_server_url,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::message::OutboxFlushResult::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__identity__upload_profile_photo_impl(
+fn wire__crate__api__media__parse_imeta_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "upload_profile_photo",
+            debug_name: "parse_imeta_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5865,36 +5941,26 @@ fn wire__crate__api__identity__upload_profile_photo_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            let api_tag_values = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok = crate::api::identity::upload_profile_photo(
-                            api_file_data,
-                            api_mime_type,
-                            api_blossom_server_url,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::media::parse_imeta_tag(api_tag_values)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__relay__verify_event_published_impl(
+fn wire__crate__api__call_webrtc__parse_sdp_answer_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "verify_event_published",
+            debug_name: "parse_sdp_answer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5908,43 +5974,3437 @@ fn wire__crate__api__relay__verify_event_published_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_sdp = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::api::error::BurrowError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::relay::verify_event_published(api_event_id_hex).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_webrtc::parse_sdp_answer(api_sdp)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Keys>
-);
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for Keys {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Keys>,
+fn wire__crate__api__call_webrtc__parse_sdp_offer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_sdp_offer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sdp = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_webrtc::parse_sdp_offer(api_sdp)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transcription__pause_transcription_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_transcription",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::pause_transcription()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__process_call_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_call_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_signaling::process_call_event(api_event_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__process_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::message::process_message(api_event_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invite__process_welcome_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_welcome",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wrapper_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_welcome_rumor_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::invite::process_welcome(
+                            api_wrapper_event_id_hex,
+                            api_welcome_rumor_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__identity__profile_data_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "profile_data_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::identity::ProfileData::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__group__promote_admin_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "promote_admin",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::group::promote_admin(api_mls_group_id_hex, api_pubkey_hex)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relay__publish_event_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_event_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relay::publish_event_json(api_event_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relay__publish_event_json_to_relay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_event_json_to_relay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_json = <String>::sse_decode(&mut deserializer);
+            let api_relay_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::publish_event_json_to_relay(
+                            api_event_json,
+                            api_relay_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__publish_evolution_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_evolution_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_evolution_event_json = <String>::sse_decode(&mut deserializer);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::publish_evolution_event(
+                            api_evolution_event_json,
+                            api_relay_urls,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__keypackage__publish_key_package_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_key_package",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::keypackage::publish_key_package(api_relay_urls).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__keypackage__publish_key_package_relays_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "publish_key_package_relays",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::keypackage::publish_key_package_relays(api_relay_urls)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__purge_expired_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "purge_expired_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::purge_expired_messages().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__meeting_intelligence__push_transcript_segment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "push_transcript_segment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_segment_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::push_transcript_segment(
+                        api_meeting_id,
+                        api_segment_json,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__reap_stale_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reap_stale_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_age_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::call_webrtc::reap_stale_peers(api_max_age_secs).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__reassemble_message_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reassemble_message",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parts = <Vec<crate::api::message::GroupMessage>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                let output_ok = crate::api::message::reassemble_message(api_parts)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__recommend_force_relay_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recommend_force_relay",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_previous_failures = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::call_webrtc::recommend_force_relay(api_previous_failures),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__call_quality__recommend_jitter_buffer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recommend_jitter_buffer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_measured_jitter_ms = <f64>::sse_decode(&mut deserializer);
+            let api_rtt_ms = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::call_quality::recommend_jitter_buffer(
+                            api_measured_jitter_ms,
+                            api_rtt_ms,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_quality__recommend_quality_preset_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recommend_quality_preset",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_quality_score = <f64>::sse_decode(&mut deserializer);
+            let api_estimated_bandwidth_kbps = <f64>::sse_decode(&mut deserializer);
+            let api_participant_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::call_quality::recommend_quality_preset(
+                            api_quality_score,
+                            api_estimated_bandwidth_kbps,
+                            api_participant_count,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__group__reconcile_groups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reconcile_groups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::reconcile_groups().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__transcription__register_speaker_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_speaker",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_track_id = <String>::sse_decode(&mut deserializer);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_display_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::register_speaker(
+                        api_track_id,
+                        api_pubkey_hex,
+                        api_display_name,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__reject_call_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reject_call",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_caller_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_reason = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::reject_call(
+                            api_call_id,
+                            api_caller_pubkey_hex,
+                            api_reason,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__relays_for_evolution_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "relays_for_evolution",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_proposed_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::relays_for_evolution(
+                            api_mls_group_id_hex,
+                            api_proposed_relay_urls,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__remove_call_peers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_call_peers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_webrtc::remove_call_peers(api_call_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__remove_group_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_group_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::group::remove_group_image(api_mls_group_id_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invite__remove_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_pubkeys_hex = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::invite::remove_members(
+                            api_mls_group_id_hex,
+                            api_pubkeys_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relay__remove_relay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_relay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::remove_relay(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_session__remove_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_session::remove_session(api_call_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__report_peer_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_peer_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_rtt_ms = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_packet_loss_percent = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_outgoing_bitrate_kbps = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_incoming_bitrate_kbps = <Option<f64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_webrtc::report_peer_stats(
+                            api_participant_pubkey_hex,
+                            api_rtt_ms,
+                            api_packet_loss_percent,
+                            api_outgoing_bitrate_kbps,
+                            api_incoming_bitrate_kbps,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__reset_call_signaling_state_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_call_signaling_state",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::call_signaling::reset_call_signaling_state(api_call_id);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__transcription__resume_transcription_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_transcription",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::resume_transcription()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__rotate_frame_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rotate_frame_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_exporter_secret_hex = <String>::sse_decode(&mut deserializer);
+            let api_new_epoch = <u64>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok = crate::api::call_webrtc::rotate_frame_key(
+                        api_exporter_secret_hex,
+                        api_new_epoch,
+                        api_call_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__simple__rust_lib_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rust_lib_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::rust_lib_version())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__account__save_secret_key_to_keyring_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_secret_key_to_keyring",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::account::save_secret_key_to_keyring().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__search_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::contacts::search_contacts(api_query, api_limit).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__meeting_intelligence__search_meetings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_meetings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::search_meetings(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__message__search_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <Option<String>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::search_messages(
+                            api_mls_group_id_hex,
+                            api_query,
+                            api_limit,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__transcription__search_transcript_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_transcript",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::search_transcript(api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send(api_request_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__send_call_state_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_call_state_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_is_muted = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_is_video_enabled = <Option<bool>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::send_call_state_update(
+                            api_call_id,
+                            api_remote_pubkey_hex,
+                            api_is_muted,
+                            api_is_video_enabled,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__send_ice_candidate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_ice_candidate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_candidate = <String>::sse_decode(&mut deserializer);
+            let api_sdp_mid = <Option<String>>::sse_decode(&mut deserializer);
+            let api_sdp_m_line_index = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_remote_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::send_ice_candidate(
+                            api_candidate,
+                            api_sdp_mid,
+                            api_sdp_m_line_index,
+                            api_call_id,
+                            api_remote_pubkey_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_long_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_long_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_long_message(
+                            api_mls_group_id_hex,
+                            api_content,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_ttl_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_message(
+                            api_mls_group_id_hex,
+                            api_content,
+                            api_ttl_secs,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_message_with_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_with_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_imeta_tags_json = <Vec<Vec<String>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_message_with_media(
+                            api_mls_group_id_hex,
+                            api_content,
+                            api_imeta_tags_json,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_poll_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_poll",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_question = <String>::sse_decode(&mut deserializer);
+            let api_options = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_poll(
+                            api_mls_group_id_hex,
+                            api_question,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_poll_vote_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_poll_vote",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_poll_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_option_index = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_poll_vote(
+                            api_mls_group_id_hex,
+                            api_poll_event_id_hex,
+                            api_option_index,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_reaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_reaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_target_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_emoji = <String>::sse_decode(&mut deserializer);
+            let api_custom_emoji_url = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_reaction(
+                            api_mls_group_id_hex,
+                            api_target_event_id_hex,
+                            api_emoji,
+                            api_custom_emoji_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_read_receipt_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_read_receipt",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_message_event_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_read_receipt(
+                            api_mls_group_id_hex,
+                            api_message_event_ids,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__send_renegotiation_answer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_renegotiation_answer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_sdp = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::send_renegotiation_answer(
+                            api_call_id,
+                            api_sdp,
+                            api_peer_pubkey_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__send_renegotiation_offer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_renegotiation_offer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_sdp = <String>::sse_decode(&mut deserializer);
+            let api_peer_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::send_renegotiation_offer(
+                            api_call_id,
+                            api_sdp,
+                            api_peer_pubkey_hex,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_reply_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_reply",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_reply_to_event_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_ttl_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::send_reply(
+                            api_mls_group_id_hex,
+                            api_reply_to_event_id_hex,
+                            api_content,
+                            api_ttl_secs,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__send_typing_indicator_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_typing_indicator",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::message::send_typing_indicator(api_mls_group_id_hex)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__set_contacts_staleness_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_contacts_staleness",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::contacts::set_contacts_staleness(api_secs);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__state__set_data_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_data_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::state::set_data_dir(api_path);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__ephemeral__set_ephemeral_key_strategy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_ephemeral_key_strategy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_strategy =
+                <crate::api::ephemeral::EphemeralKeyStrategy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::ephemeral::set_ephemeral_key_strategy(api_strategy);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__group__set_group_message_ttl_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_group_message_ttl",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_ttl_secs = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::set_group_message_ttl(
+                            api_mls_group_id_hex,
+                            api_ttl_secs,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_state__set_group_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_group_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            let api_value = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::app_state::set_group_state(
+                            api_group_id_hex,
+                            api_key,
+                            api_value,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invite__set_key_package_max_age_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_key_package_max_age",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::invite::set_key_package_max_age(api_secs);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__state__set_max_concurrent_relay_ops_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_max_concurrent_relay_ops",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_n = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::state::set_max_concurrent_relay_ops(api_n);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__message__set_max_message_bytes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_max_message_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_bytes = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::message::set_max_message_bytes(api_max_bytes);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__media__set_media_cache_limit_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_media_cache_limit",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::media::set_media_cache_limit(api_bytes);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__call_session__set_muted_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_muted",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_muted = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_session::set_muted(api_call_id, api_muted).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__set_nip05_verification_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_nip05_verification",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::contacts::set_nip05_verification(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__identity__set_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile = <crate::api::identity::ProfileData>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::identity::set_profile(api_profile).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__relay__set_relay_roles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_relay_roles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_roles_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::relay::set_relay_roles(api_roles_json).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_session__set_video_enabled_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_video_enabled",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_session::set_video_enabled(api_call_id, api_enabled)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__should_use_chunked_encryption_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "should_use_chunked_encryption",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_size = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::media::should_use_chunked_encryption(api_file_size),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__should_use_sfu_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "should_use_sfu",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_participant_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::call_webrtc::should_use_sfu(
+                        api_participant_count,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__message__split_message_content_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "split_message_content",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_max_bytes = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::message::split_message_content(
+                    api_content,
+                    api_max_bytes,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__transcription__start_transcription_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_transcription",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::start_transcription(api_call_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transcription__stop_transcription_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_transcription",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::transcription::stop_transcription()?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__call_signaling__subscribe_call_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_call_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_signaling::subscribe_call_events().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__sync_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_force_refresh = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::contacts::sync_contacts(api_force_refresh).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__sync_group_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_group_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::message::sync_group_messages().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__invite__sync_welcomes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_welcomes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::invite::sync_welcomes().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__meeting_intelligence__toggle_action_item_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "toggle_action_item",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_meeting_id = <String>::sse_decode(&mut deserializer);
+            let api_action_item_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::meeting_intelligence::toggle_action_item(
+                        api_meeting_id,
+                        api_action_item_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__transcription__transcription_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transcription_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::transcription::TranscriptionConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__app_state__unarchive_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unarchive_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_group_id_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::app_state::unarchive_group(api_group_id_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__contacts__unfollow_contact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unfollow_contact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::contacts::unfollow_contact(api_pubkey_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__update_group_description_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_group_description",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_description = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::update_group_description(
+                            api_mls_group_id_hex,
+                            api_description,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__update_group_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_group_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::group::update_group_name(api_mls_group_id_hex, api_name)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__update_group_relays_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_group_relays",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_relay_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::update_group_relays(
+                            api_mls_group_id_hex,
+                            api_relay_urls,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_webrtc__update_peer_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_peer_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_participant_pubkey_hex = <String>::sse_decode(&mut deserializer);
+            let api_state = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::call_webrtc::update_peer_state(
+                            api_call_id,
+                            api_participant_pubkey_hex,
+                            api_state,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__call_session__update_session_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_session_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_call_id = <String>::sse_decode(&mut deserializer);
+            let api_state = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::call_session::update_session_state(api_call_id, api_state)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__group__upload_group_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_group_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_image_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::group::upload_group_image(
+                            api_mls_group_id_hex,
+                            api_image_data,
+                            api_mime_type,
+                            api_blossom_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__upload_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_filename = <String>::sse_decode(&mut deserializer);
+            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::media::upload_media(
+                            api_mls_group_id_hex,
+                            api_file_data,
+                            api_mime_type,
+                            api_filename,
+                            api_blossom_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__upload_media_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_media_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mls_group_id_hex = <String>::sse_decode(&mut deserializer);
+            let api_files = <Vec<crate::api::media::FileInput>>::sse_decode(&mut deserializer);
+            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::media::upload_media_batch(
+                            api_mls_group_id_hex,
+                            api_files,
+                            api_blossom_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__identity__upload_profile_photo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_profile_photo",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_blossom_server_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok = crate::api::identity::upload_profile_photo(
+                            api_file_data,
+                            api_mime_type,
+                            api_blossom_server_url,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__message__validate_reaction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_reaction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_custom_emoji_url = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::error::BurrowError>((move || {
+                    let output_ok =
+                        crate::api::message::validate_reaction(api_content, api_custom_emoji_url)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__relay__verify_event_published_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_event_published",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_event_id_hex = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::error::BurrowError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::relay::verify_event_published(api_event_id_hex).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Keys>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for Keys {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Keys>,
         >>::sse_decode(deserializer);
         return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
     }
@@ -5971,6 +9431,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<crate::api::message::GroupMessage, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::message::GroupNotification,
@@ -6093,6 +9563,21 @@ impl SseDecode for crate::api::call_quality::AudioMode {
     }
 }
 
+impl SseDecode for crate::api::media::BatchUploadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filename = <String>::sse_decode(deserializer);
+        let mut var_result =
+            <Option<crate::api::media::UploadMediaResult>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::media::BatchUploadItem {
+            filename: var_filename,
+            result: var_result,
+            error: var_error,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6165,6 +9650,8 @@ impl SseDecode for crate::api::call_signaling::CallSignalingEvent {
         let mut var_callType = <Option<String>>::sse_decode(deserializer);
         let mut var_content = <String>::sse_decode(deserializer);
         let mut var_createdAt = <u64>::sse_decode(deserializer);
+        let mut var_renegotiate = <bool>::sse_decode(deserializer);
+        let mut var_sequence = <u64>::sse_decode(deserializer);
         return crate::api::call_signaling::CallSignalingEvent {
             kind: var_kind,
             sender_pubkey_hex: var_senderPubkeyHex,
@@ -6172,6 +9659,8 @@ impl SseDecode for crate::api::call_signaling::CallSignalingEvent {
             call_type: var_callType,
             content: var_content,
             created_at: var_createdAt,
+            renegotiate: var_renegotiate,
+            sequence: var_sequence,
         };
     }
 }
@@ -6206,6 +9695,34 @@ impl SseDecode for crate::api::call_session::CallType {
     }
 }
 
+impl SseDecode for crate::api::media::ChunkedEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_originalHashHex = <String>::sse_decode(deserializer);
+        let mut var_encryptedHashHex = <String>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_filename = <String>::sse_decode(deserializer);
+        let mut var_originalSize = <u64>::sse_decode(deserializer);
+        let mut var_encryptedSize = <u64>::sse_decode(deserializer);
+        let mut var_nonceHex = <String>::sse_decode(deserializer);
+        let mut var_frameSize = <u32>::sse_decode(deserializer);
+        let mut var_schemeVersion = <String>::sse_decode(deserializer);
+        return crate::api::media::ChunkedEncryptResult {
+            output_path: var_outputPath,
+            original_hash_hex: var_originalHashHex,
+            encrypted_hash_hex: var_encryptedHashHex,
+            mime_type: var_mimeType,
+            filename: var_filename,
+            original_size: var_originalSize,
+            encrypted_size: var_encryptedSize,
+            nonce_hex: var_nonceHex,
+            frame_size: var_frameSize,
+            scheme_version: var_schemeVersion,
+        };
+    }
+}
+
 impl SseDecode for crate::api::call_quality::CodecPreferences {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6224,10 +9741,14 @@ impl SseDecode for crate::api::contacts::ContactInfo {
         let mut var_pubkeyHex = <String>::sse_decode(deserializer);
         let mut var_displayName = <Option<String>>::sse_decode(deserializer);
         let mut var_picture = <Option<String>>::sse_decode(deserializer);
+        let mut var_nip05 = <Option<String>>::sse_decode(deserializer);
+        let mut var_nip05Verified = <bool>::sse_decode(deserializer);
         return crate::api::contacts::ContactInfo {
             pubkey_hex: var_pubkeyHex,
             display_name: var_displayName,
             picture: var_picture,
+            nip05: var_nip05,
+            nip05_verified: var_nip05Verified,
         };
     }
 }
@@ -6266,6 +9787,87 @@ impl SseDecode for crate::api::group::CreateGroupResult {
     }
 }
 
+impl SseDecode for crate::api::group::CreateOrGetDmResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_group = <crate::api::group::GroupInfo>::sse_decode(deserializer);
+        let mut var_welcomeRumorsJson = <Vec<String>>::sse_decode(deserializer);
+        let mut var_mlsGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_reused = <bool>::sse_decode(deserializer);
+        return crate::api::group::CreateOrGetDmResult {
+            group: var_group,
+            welcome_rumors_json: var_welcomeRumorsJson,
+            mls_group_id_hex: var_mlsGroupIdHex,
+            reused: var_reused,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::DiagnosticsReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_generatedAt = <u64>::sse_decode(deserializer);
+        let mut var_coreVersion = <String>::sse_decode(deserializer);
+        let mut var_initialized = <bool>::sse_decode(deserializer);
+        let mut var_pubkeyHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_relays =
+            <Vec<crate::api::diagnostics::RelayDiagnostics>>::sse_decode(deserializer);
+        let mut var_groupCount = <u32>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<crate::api::diagnostics::GroupDiagnostics>>::sse_decode(deserializer);
+        let mut var_pendingWelcomeCount = <u32>::sse_decode(deserializer);
+        let mut var_lastContactsSync = <Option<i64>>::sse_decode(deserializer);
+        let mut var_appDbInitialized = <bool>::sse_decode(deserializer);
+        let mut var_appDbSchemaVersion = <Option<u32>>::sse_decode(deserializer);
+        let mut var_errorCounts =
+            <Vec<crate::api::diagnostics::ErrorCount>>::sse_decode(deserializer);
+        return crate::api::diagnostics::DiagnosticsReport {
+            generated_at: var_generatedAt,
+            core_version: var_coreVersion,
+            initialized: var_initialized,
+            pubkey_hex: var_pubkeyHex,
+            relays: var_relays,
+            group_count: var_groupCount,
+            groups: var_groups,
+            pending_welcome_count: var_pendingWelcomeCount,
+            last_contacts_sync: var_lastContactsSync,
+            app_db_initialized: var_appDbInitialized,
+            app_db_schema_version: var_appDbSchemaVersion,
+            error_counts: var_errorCounts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::media::EncryptedFileOnDisk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_encryptedPath = <String>::sse_decode(deserializer);
+        let mut var_originalHashHex = <String>::sse_decode(deserializer);
+        let mut var_encryptedHashHex = <String>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_filename = <String>::sse_decode(deserializer);
+        let mut var_originalSize = <u64>::sse_decode(deserializer);
+        let mut var_encryptedSize = <u64>::sse_decode(deserializer);
+        let mut var_dimensions = <Option<String>>::sse_decode(deserializer);
+        let mut var_blurhash = <Option<String>>::sse_decode(deserializer);
+        let mut var_nonceHex = <String>::sse_decode(deserializer);
+        let mut var_schemeVersion = <String>::sse_decode(deserializer);
+        return crate::api::media::EncryptedFileOnDisk {
+            encrypted_path: var_encryptedPath,
+            original_hash_hex: var_originalHashHex,
+            encrypted_hash_hex: var_encryptedHashHex,
+            mime_type: var_mimeType,
+            filename: var_filename,
+            original_size: var_originalSize,
+            encrypted_size: var_encryptedSize,
+            dimensions: var_dimensions,
+            blurhash: var_blurhash,
+            nonce_hex: var_nonceHex,
+            scheme_version: var_schemeVersion,
+        };
+    }
+}
+
 impl SseDecode for crate::api::media::EncryptedFileResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6294,6 +9896,56 @@ impl SseDecode for crate::api::media::EncryptedFileResult {
     }
 }
 
+impl SseDecode for crate::api::ephemeral::EphemeralKeyStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::ephemeral::EphemeralKeyStrategy::PerEvent,
+            1 => crate::api::ephemeral::EphemeralKeyStrategy::PerSession,
+            _ => unreachable!("Invalid variant for EphemeralKeyStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::group::EpochInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_recordedAt = <Option<u64>>::sse_decode(deserializer);
+        let mut var_committerPubkeyHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_commitEventIdHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_addedPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removedPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_metadataChanged = <bool>::sse_decode(deserializer);
+        return crate::api::group::EpochInfo {
+            epoch: var_epoch,
+            recorded_at: var_recordedAt,
+            committer_pubkey_hex: var_committerPubkeyHex,
+            commit_event_id_hex: var_commitEventIdHex,
+            added_pubkeys: var_addedPubkeys,
+            removed_pubkeys: var_removedPubkeys,
+            metadata_changed: var_metadataChanged,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::ErrorCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_subsystem = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        let mut var_lastErrorAt = <u64>::sse_decode(deserializer);
+        let mut var_lastErrorKind = <String>::sse_decode(deserializer);
+        return crate::api::diagnostics::ErrorCount {
+            subsystem: var_subsystem,
+            count: var_count,
+            last_error_at: var_lastErrorAt,
+            last_error_kind: var_lastErrorKind,
+        };
+    }
+}
+
 impl SseDecode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6308,6 +9960,58 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::api::media::FileInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fileData = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_filename = <String>::sse_decode(deserializer);
+        return crate::api::media::FileInput {
+            file_data: var_fileData,
+            mime_type: var_mimeType,
+            filename: var_filename,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::GroupDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_nostrGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_memberCount = <u32>::sse_decode(deserializer);
+        let mut var_relayCount = <u32>::sse_decode(deserializer);
+        return crate::api::diagnostics::GroupDiagnostics {
+            mls_group_id_hex: var_mlsGroupIdHex,
+            nostr_group_id_hex: var_nostrGroupIdHex,
+            epoch: var_epoch,
+            state: var_state,
+            member_count: var_memberCount,
+            relay_count: var_relayCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::group::GroupEpochEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_changeType = <String>::sse_decode(deserializer);
+        let mut var_pubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_committerPubkeyHex = <Option<String>>::sse_decode(deserializer);
+        return crate::api::group::GroupEpochEvent {
+            epoch: var_epoch,
+            change_type: var_changeType,
+            pubkeys: var_pubkeys,
+            timestamp: var_timestamp,
+            committer_pubkey_hex: var_committerPubkeyHex,
+        };
+    }
+}
+
 impl SseDecode for crate::api::group::GroupInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6325,6 +10029,7 @@ impl SseDecode for crate::api::group::GroupInfo {
         let mut var_dmPeerPubkeyHex = <Option<String>>::sse_decode(deserializer);
         let mut var_imageHashHex = <Option<String>>::sse_decode(deserializer);
         let mut var_hasImage = <bool>::sse_decode(deserializer);
+        let mut var_defaultMessageTtlSecs = <Option<u64>>::sse_decode(deserializer);
         return crate::api::group::GroupInfo {
             mls_group_id_hex: var_mlsGroupIdHex,
             nostr_group_id_hex: var_nostrGroupIdHex,
@@ -6340,6 +10045,7 @@ impl SseDecode for crate::api::group::GroupInfo {
             dm_peer_pubkey_hex: var_dmPeerPubkeyHex,
             image_hash_hex: var_imageHashHex,
             has_image: var_hasImage,
+            default_message_ttl_secs: var_defaultMessageTtlSecs,
         };
     }
 }
@@ -6356,6 +10062,10 @@ impl SseDecode for crate::api::message::GroupMessage {
         let mut var_tags = <Vec<Vec<String>>>::sse_decode(deserializer);
         let mut var_wrapperEventIdHex = <String>::sse_decode(deserializer);
         let mut var_epoch = <u64>::sse_decode(deserializer);
+        let mut var_mentionsMe = <bool>::sse_decode(deserializer);
+        let mut var_editedFrom = <Option<String>>::sse_decode(deserializer);
+        let mut var_replyToEventIdHex = <Option<String>>::sse_decode(deserializer);
+        let mut var_replyToAuthorHex = <Option<String>>::sse_decode(deserializer);
         return crate::api::message::GroupMessage {
             event_id_hex: var_eventIdHex,
             author_pubkey_hex: var_authorPubkeyHex,
@@ -6366,6 +10076,10 @@ impl SseDecode for crate::api::message::GroupMessage {
             tags: var_tags,
             wrapper_event_id_hex: var_wrapperEventIdHex,
             epoch: var_epoch,
+            mentions_me: var_mentionsMe,
+            edited_from: var_editedFrom,
+            reply_to_event_id_hex: var_replyToEventIdHex,
+            reply_to_author_hex: var_replyToAuthorHex,
         };
     }
 }
@@ -6376,10 +10090,16 @@ impl SseDecode for crate::api::message::GroupNotification {
         let mut var_notificationType = <String>::sse_decode(deserializer);
         let mut var_message = <Option<crate::api::message::GroupMessage>>::sse_decode(deserializer);
         let mut var_mlsGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_addedPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removedPubkeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_senderPubkeyHex = <Option<String>>::sse_decode(deserializer);
         return crate::api::message::GroupNotification {
             notification_type: var_notificationType,
             message: var_message,
             mls_group_id_hex: var_mlsGroupIdHex,
+            added_pubkeys: var_addedPubkeys,
+            removed_pubkeys: var_removedPubkeys,
+            sender_pubkey_hex: var_senderPubkeyHex,
         };
     }
 }
@@ -6428,6 +10148,38 @@ impl SseDecode for crate::api::call_webrtc::IceServer {
     }
 }
 
+impl SseDecode for crate::api::invite::InviteResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mlsGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_evolutionEventIdHex = <String>::sse_decode(deserializer);
+        let mut var_welcomeEventIdsHex = <Vec<String>>::sse_decode(deserializer);
+        let mut var_failedWelcomePubkeysHex = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::invite::InviteResult {
+            mls_group_id_hex: var_mlsGroupIdHex,
+            evolution_event_id_hex: var_evolutionEventIdHex,
+            welcome_event_ids_hex: var_welcomeEventIdsHex,
+            failed_welcome_pubkeys_hex: var_failedWelcomePubkeysHex,
+        };
+    }
+}
+
+impl SseDecode for crate::api::call_quality::JitterBufferConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_targetMs = <u32>::sse_decode(deserializer);
+        let mut var_minMs = <u32>::sse_decode(deserializer);
+        let mut var_maxMs = <u32>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::call_quality::JitterBufferConfig {
+            target_ms: var_targetMs,
+            min_ms: var_minMs,
+            max_ms: var_maxMs,
+            reason: var_reason,
+        };
+    }
+}
+
 impl SseDecode for crate::api::keypackage::KeyPackageData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6452,6 +10204,20 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::media::BatchUploadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::BatchUploadItem>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::call_session::CallSession> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6480,6 +10246,72 @@ impl SseDecode for Vec<crate::api::contacts::ContactInfo> {
     }
 }
 
+impl SseDecode for Vec<crate::api::group::EpochInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::group::EpochInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::diagnostics::ErrorCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::diagnostics::ErrorCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::media::FileInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::media::FileInput>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::diagnostics::GroupDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::diagnostics::GroupDiagnostics>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::group::GroupEpochEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::group::GroupEpochEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::group::GroupInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6544,6 +10376,34 @@ impl SseDecode for Vec<crate::api::group::MemberInfo> {
     }
 }
 
+impl SseDecode for Vec<crate::api::message::MessageReaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::message::MessageReaction>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::message::MessageReader> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::message::MessageReader>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::call_webrtc::PeerEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6558,37 +10418,65 @@ impl SseDecode for Vec<crate::api::call_webrtc::PeerEntry> {
     }
 }
 
-impl SseDecode for Vec<f32> {
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::diagnostics::RelayDiagnostics> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
+            ans_.push(<crate::api::diagnostics::RelayDiagnostics>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::api::relay::RelayInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::api::relay::RelayInfo>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::relay::RelayInfo> {
+impl SseDecode for Vec<crate::api::message::SendMessageResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::api::relay::RelayInfo>::sse_decode(deserializer));
+            ans_.push(<crate::api::message::SendMessageResult>::sse_decode(
+                deserializer,
+            ));
         }
         return ans_;
     }
@@ -6644,6 +10532,8 @@ impl SseDecode for crate::api::media::MediaReferenceInfo {
         let mut var_dimensions = <Option<String>>::sse_decode(deserializer);
         let mut var_schemeVersion = <String>::sse_decode(deserializer);
         let mut var_nonceHex = <String>::sse_decode(deserializer);
+        let mut var_thumb =
+            <Option<crate::api::media::ThumbnailReference>>::sse_decode(deserializer);
         return crate::api::media::MediaReferenceInfo {
             url: var_url,
             original_hash_hex: var_originalHashHex,
@@ -6652,6 +10542,7 @@ impl SseDecode for crate::api::media::MediaReferenceInfo {
             dimensions: var_dimensions,
             scheme_version: var_schemeVersion,
             nonce_hex: var_nonceHex,
+            thumb: var_thumb,
         };
     }
 }
@@ -6670,6 +10561,58 @@ impl SseDecode for crate::api::group::MemberInfo {
     }
 }
 
+impl SseDecode for crate::api::message::MessageReaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventIdHex = <String>::sse_decode(deserializer);
+        let mut var_reactorPubkeyHex = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_customEmojiUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <u64>::sse_decode(deserializer);
+        return crate::api::message::MessageReaction {
+            event_id_hex: var_eventIdHex,
+            reactor_pubkey_hex: var_reactorPubkeyHex,
+            content: var_content,
+            custom_emoji_url: var_customEmojiUrl,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::message::MessageReader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_readerPubkeyHex = <String>::sse_decode(deserializer);
+        let mut var_readAt = <u64>::sse_decode(deserializer);
+        return crate::api::message::MessageReader {
+            reader_pubkey_hex: var_readerPubkeyHex,
+            read_at: var_readAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::link_preview::OgMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_siteName = <Option<String>>::sse_decode(deserializer);
+        let mut var_domain = <String>::sse_decode(deserializer);
+        let mut var_ogType = <Option<String>>::sse_decode(deserializer);
+        return crate::api::link_preview::OgMetadata {
+            url: var_url,
+            title: var_title,
+            description: var_description,
+            image_url: var_imageUrl,
+            site_name: var_siteName,
+            domain: var_domain,
+            og_type: var_ogType,
+        };
+    }
+}
+
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6729,6 +10672,17 @@ impl SseDecode for Option<f64> {
     }
 }
 
+impl SseDecode for Option<crate::api::group::GroupInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::group::GroupInfo>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::message::GroupMessage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6766,6 +10720,19 @@ impl SseDecode for Option<crate::api::call_webrtc::PeerStats> {
     }
 }
 
+impl SseDecode for Option<crate::api::media::ThumbnailReference> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::ThumbnailReference>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6788,6 +10755,35 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for Option<crate::api::media::UploadMediaResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::media::UploadMediaResult>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::message::OutboxFlushResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_published = <u32>::sse_decode(deserializer);
+        let mut var_reencrypted = <u32>::sse_decode(deserializer);
+        let mut var_dropped = <u32>::sse_decode(deserializer);
+        let mut var_remaining = <u32>::sse_decode(deserializer);
+        return crate::api::message::OutboxFlushResult {
+            published: var_published,
+            reencrypted: var_reencrypted,
+            dropped: var_dropped,
+            remaining: var_remaining,
+        };
+    }
+}
+
 impl SseDecode for crate::api::call_webrtc::PeerConnectionState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6925,14 +10921,62 @@ impl SseDecode for crate::api::call_quality::QualityScore {
     }
 }
 
+impl SseDecode for crate::api::message::ReceiptSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_eventIdHex = <String>::sse_decode(deserializer);
+        let mut var_readers = <Vec<crate::api::message::MessageReader>>::sse_decode(deserializer);
+        let mut var_recipientCount = <u32>::sse_decode(deserializer);
+        let mut var_readByAll = <bool>::sse_decode(deserializer);
+        return crate::api::message::ReceiptSummary {
+            event_id_hex: var_eventIdHex,
+            readers: var_readers,
+            recipient_count: var_recipientCount,
+            read_by_all: var_readByAll,
+        };
+    }
+}
+
+impl SseDecode for crate::api::group::ReconcileReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mdkGroupCount = <u32>::sse_decode(deserializer);
+        let mut var_restoredGroupIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_orphanedGroupIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::group::ReconcileReport {
+            mdk_group_count: var_mdkGroupCount,
+            restored_group_ids: var_restoredGroupIds,
+            orphaned_group_ids: var_orphanedGroupIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::diagnostics::RelayDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_connected = <bool>::sse_decode(deserializer);
+        return crate::api::diagnostics::RelayDiagnostics {
+            url: var_url,
+            status: var_status,
+            connected: var_connected,
+        };
+    }
+}
+
 impl SseDecode for crate::api::relay::RelayInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_url = <String>::sse_decode(deserializer);
         let mut var_connected = <bool>::sse_decode(deserializer);
+        let mut var_read = <bool>::sse_decode(deserializer);
+        let mut var_write = <bool>::sse_decode(deserializer);
         return crate::api::relay::RelayInfo {
             url: var_url,
             connected: var_connected,
+            read: var_read,
+            write: var_write,
         };
     }
 }
@@ -6946,6 +10990,8 @@ impl SseDecode for crate::api::call_webrtc::SdpInfo {
         let mut var_mediaCount = <u32>::sse_decode(deserializer);
         let mut var_iceUfrag = <Option<String>>::sse_decode(deserializer);
         let mut var_codecs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_bandwidthKbps = <Option<u32>>::sse_decode(deserializer);
+        let mut var_opusParams = <Option<String>>::sse_decode(deserializer);
         let mut var_isValid = <bool>::sse_decode(deserializer);
         let mut var_error = <Option<String>>::sse_decode(deserializer);
         return crate::api::call_webrtc::SdpInfo {
@@ -6955,6 +11001,8 @@ impl SseDecode for crate::api::call_webrtc::SdpInfo {
             media_count: var_mediaCount,
             ice_ufrag: var_iceUfrag,
             codecs: var_codecs,
+            bandwidth_kbps: var_bandwidthKbps,
+            opus_params: var_opusParams,
             is_valid: var_isValid,
             error: var_error,
         };
@@ -6987,6 +11035,18 @@ impl SseDecode for crate::api::call_webrtc::SfuConfig {
     }
 }
 
+impl SseDecode for crate::api::signer::SignerKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::signer::SignerKind::Local,
+            1 => crate::api::signer::SignerKind::Nip46,
+            _ => unreachable!("Invalid variant for SignerKind: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::call_quality::SimulcastConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7020,6 +11080,20 @@ impl SseDecode for crate::api::call_quality::SimulcastLayer {
     }
 }
 
+impl SseDecode for crate::api::media::ThumbnailReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_originalHashHex = <String>::sse_decode(deserializer);
+        let mut var_nonceHex = <String>::sse_decode(deserializer);
+        return crate::api::media::ThumbnailReference {
+            url: var_url,
+            original_hash_hex: var_originalHashHex,
+            nonce_hex: var_nonceHex,
+        };
+    }
+}
+
 impl SseDecode for crate::api::transcription::TranscriptSegment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7098,10 +11172,12 @@ impl SseDecode for crate::api::group::UpdateGroupResult {
         let mut var_evolutionEventJson = <String>::sse_decode(deserializer);
         let mut var_welcomeRumorsJson = <Vec<String>>::sse_decode(deserializer);
         let mut var_mlsGroupIdHex = <String>::sse_decode(deserializer);
+        let mut var_publishRelays = <Vec<String>>::sse_decode(deserializer);
         return crate::api::group::UpdateGroupResult {
             evolution_event_json: var_evolutionEventJson,
             welcome_rumors_json: var_welcomeRumorsJson,
             mls_group_id_hex: var_mlsGroupIdHex,
+            publish_relays: var_publishRelays,
         };
     }
 }
@@ -7184,10 +11260,12 @@ impl SseDecode for crate::api::call_webrtc::WebRtcConfig {
             <Vec<crate::api::call_webrtc::IceServer>>::sse_decode(deserializer);
         let mut var_sdpSemantics = <String>::sse_decode(deserializer);
         let mut var_bundlePolicy = <String>::sse_decode(deserializer);
+        let mut var_iceTransportPolicy = <String>::sse_decode(deserializer);
         return crate::api::call_webrtc::WebRtcConfig {
             ice_servers: var_iceServers,
             sdp_semantics: var_sdpSemantics,
             bundle_policy: var_bundlePolicy,
+            ice_transport_policy: var_iceTransportPolicy,
         };
     }
 }
@@ -7225,482 +11303,715 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        1 => wire__crate__api__call_signaling__accept_call_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__invite__accept_welcome_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__invite__add_members_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__relay__add_relay_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__meeting_intelligence__ai_backend_default_impl(
+        2 => wire__crate__api__call_signaling__accept_call_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__call_signaling__accept_call_fragments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        6 => wire__crate__api__app_state__archive_group_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__identity__bootstrap_identity_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__call_signaling__build_group_call_signaling_impl(
+        4 => wire__crate__api__invite__accept_welcome_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__invite__add_members_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__relay__add_relay_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__meeting_intelligence__ai_backend_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        9 => wire__crate__api__media__build_imeta_tag_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__meeting_intelligence__build_meeting_notes_prompt_impl(
+        8 => wire__crate__api__app_state__archive_group_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__identity__bootstrap_identity_impl(port, ptr, rust_vec_len, data_len),
+        10 => {
+            wire__crate__api__media__build_chunked_imeta_tag_impl(port, ptr, rust_vec_len, data_len)
+        }
+        11 => wire__crate__api__call_signaling__build_group_call_signaling_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        11 => wire__crate__api__call_quality__calculate_quality_score_impl(
+        12 => wire__crate__api__media__build_imeta_tag_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__meeting_intelligence__build_meeting_notes_prompt_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        12 => wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
+        14 => {
+            wire__crate__api__meeting_intelligence__build_meeting_notes_prompt_from_segments_impl(
+                port,
+                ptr,
+                rust_vec_len,
+                data_len,
+            )
+        }
+        15 => wire__crate__api__call_quality__calculate_quality_score_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        13 => wire__crate__api__relay__connect_relays_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__account__create_account_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__group__create_group_impl(port, ptr, rust_vec_len, data_len),
         16 => {
+            wire__crate__api__group__cleanup_inactive_groups_impl(port, ptr, rust_vec_len, data_len)
+        }
+        17 => wire__crate__api__media__clear_media_cache_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__group__clear_pending_commit_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__meeting_intelligence__clear_transcript_buffer_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__diagnostics__collect_diagnostics_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__meeting_intelligence__configure_ai_backend_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__meeting_intelligence__configure_confidence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__meeting_intelligence__configure_redaction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__relay__connect_relays_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__account__create_account_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__group__create_dm_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__group__create_group_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__group__create_or_get_dm_impl(port, ptr, rust_vec_len, data_len),
+        29 => {
             wire__crate__api__call_webrtc__create_peer_entry_impl(port, ptr, rust_vec_len, data_len)
         }
-        17 => {
+        30 => {
             wire__crate__api__call_session__create_session_impl(port, ptr, rust_vec_len, data_len)
         }
-        18 => {
+        31 => {
             wire__crate__api__contacts__debug_sync_contacts_impl(port, ptr, rust_vec_len, data_len)
         }
-        19 => wire__crate__api__invite__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__media__decrypt_file_impl(port, ptr, rust_vec_len, data_len),
-        21 => {
+        32 => wire__crate__api__invite__decline_welcome_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__media__decrypt_file_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__media__decrypt_file_chunked_impl(port, ptr, rust_vec_len, data_len),
+        35 => {
             wire__crate__api__group__default_blossom_server_impl(port, ptr, rust_vec_len, data_len)
         }
-        23 => {
+        36 => wire__crate__api__meeting_intelligence__default_redact_patterns_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => {
             wire__crate__api__app_state__delete_group_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        24 => wire__crate__api__account__delete_secret_key_from_keyring_impl(
+        39 => wire__crate__api__meeting_intelligence__delete_meeting_notes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__message__delete_message_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__account__delete_secret_key_from_keyring_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__call_webrtc__derive_frame_encryption_key_impl(
+        42 => wire__crate__api__group__demote_admin_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__call_webrtc__derive_frame_encryption_key_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => {
+        44 => {
             wire__crate__api__call_session__derive_media_key_impl(port, ptr, rust_vec_len, data_len)
         }
-        27 => wire__crate__api__state__destroy_state_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__relay__disconnect_relays_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__group__download_group_image_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__media__download_media_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__media__encrypt_file_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__call_signaling__end_call_impl(port, ptr, rust_vec_len, data_len),
-        33 => wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
+        45 => wire__crate__api__state__destroy_state_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__relay__disconnect_relays_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__group__download_group_image_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__media__download_media_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__message__edit_message_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__media__encrypt_file_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__media__encrypt_file_chunked_impl(port, ptr, rust_vec_len, data_len),
+        52 => {
+            wire__crate__api__media__encrypt_file_from_path_impl(port, ptr, rust_vec_len, data_len)
+        }
+        53 => wire__crate__api__media__encrypt_thumbnail_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__call_signaling__end_call_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__ephemeral__ephemeral_key_strategy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => {
+            wire__crate__api__contacts__export_contacts_list_impl(port, ptr, rust_vec_len, data_len)
+        }
+        57 => wire__crate__api__meeting_intelligence__export_meeting_markdown_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__identity__export_npub_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__identity__export_nsec_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__identity__export_pubkey_hex_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__backup__export_state_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__link_preview__extract_urls_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__transcription__feed_audio_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__invite__fetch_key_package_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__link_preview__fetch_og_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__identity__fetch_profile_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__identity__fetch_user_relays_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__transcription__filter_transcript_by_confidence_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__meeting_intelligence__finalize_live_meeting_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__group__find_dm_with_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__message__flush_outbox_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__contacts__follow_contact_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__group__forget_group_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__keypackage__generate_key_package_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => wire__crate__api__identity__export_npub_impl(port, ptr, rust_vec_len, data_len),
-        35 => wire__crate__api__identity__export_nsec_impl(port, ptr, rust_vec_len, data_len),
-        36 => wire__crate__api__identity__export_pubkey_hex_impl(port, ptr, rust_vec_len, data_len),
-        37 => wire__crate__api__transcription__feed_audio_impl(port, ptr, rust_vec_len, data_len),
-        38 => wire__crate__api__invite__fetch_key_package_impl(port, ptr, rust_vec_len, data_len),
-        39 => wire__crate__api__identity__fetch_profile_impl(port, ptr, rust_vec_len, data_len),
-        40 => wire__crate__api__identity__fetch_user_relays_impl(port, ptr, rust_vec_len, data_len),
-        41 => wire__crate__api__contacts__follow_contact_impl(port, ptr, rust_vec_len, data_len),
-        42 => wire__crate__api__keypackage__generate_key_package_impl(
+        75 => wire__crate__api__meeting_intelligence__generate_live_meeting_notes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => wire__crate__api__meeting_intelligence__generate_meeting_notes_impl(
+        76 => wire__crate__api__meeting_intelligence__generate_meeting_notes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__crate__api__call_webrtc__generate_webrtc_config_impl(
+        77 => wire__crate__api__call_webrtc__generate_webrtc_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => {
+        78 => {
             wire__crate__api__call_session__get_active_calls_impl(port, ptr, rust_vec_len, data_len)
         }
-        46 => wire__crate__api__call_quality__get_adaptive_bitrate_config_impl(
+        79 => wire__crate__api__call_quality__get_adaptive_bitrate_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__crate__api__app_state__get_archived_group_ids_impl(
+        80 => wire__crate__api__app_state__get_archived_group_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__crate__api__call_quality__get_audio_constraints_impl(
+        81 => wire__crate__api__call_quality__get_audio_constraints_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        49 => {
+        82 => {
             wire__crate__api__contacts__get_cached_contacts_impl(port, ptr, rust_vec_len, data_len)
         }
-        50 => {
+        83 => {
             wire__crate__api__identity__get_cached_profile_impl(port, ptr, rust_vec_len, data_len)
         }
-        51 => wire__crate__api__call_webrtc__get_call_participants_impl(
+        84 => wire__crate__api__call_webrtc__get_call_participants_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        52 => wire__crate__api__call_quality__get_codec_preferences_impl(
+        85 => wire__crate__api__call_quality__get_codec_preferences_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => {
+        87 => {
             wire__crate__api__account__get_current_account_impl(port, ptr, rust_vec_len, data_len)
         }
-        54 => wire__crate__api__group__get_group_impl(port, ptr, rust_vec_len, data_len),
-        55 => wire__crate__api__group__get_group_members_impl(port, ptr, rust_vec_len, data_len),
-        56 => wire__crate__api__group__get_group_relays_impl(port, ptr, rust_vec_len, data_len),
-        57 => wire__crate__api__app_state__get_group_state_impl(port, ptr, rust_vec_len, data_len),
-        58 => {
+        89 => wire__crate__api__group__get_group_impl(port, ptr, rust_vec_len, data_len),
+        90 => {
+            wire__crate__api__group__get_group_epoch_events_impl(port, ptr, rust_vec_len, data_len)
+        }
+        91 => {
+            wire__crate__api__group__get_group_epoch_history_impl(port, ptr, rust_vec_len, data_len)
+        }
+        92 => wire__crate__api__group__get_group_members_impl(port, ptr, rust_vec_len, data_len),
+        93 => {
+            wire__crate__api__group__get_group_message_ttl_impl(port, ptr, rust_vec_len, data_len)
+        }
+        94 => {
+            wire__crate__api__group__get_group_raw_extension_impl(port, ptr, rust_vec_len, data_len)
+        }
+        95 => wire__crate__api__group__get_group_relays_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__app_state__get_group_state_impl(port, ptr, rust_vec_len, data_len),
+        97 => {
             wire__crate__api__app_state__get_group_summary_impl(port, ptr, rust_vec_len, data_len)
         }
-        59 => wire__crate__api__contacts__get_last_contacts_sync_impl(
+        99 => wire__crate__api__contacts__get_last_contacts_sync_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__api__app_state__get_last_read_timestamp_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => {
+            wire__crate__api__media__get_media_cache_usage_impl(port, ptr, rust_vec_len, data_len)
+        }
+        105 => wire__crate__api__meeting_intelligence__get_meeting_archive_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__api__app_state__get_last_read_timestamp_impl(
+        106 => wire__crate__api__meeting_intelligence__get_meeting_notes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        61 => wire__crate__api__meeting_intelligence__get_meeting_archive_impl(
+        107 => wire__crate__api__message__get_message_impl(port, ptr, rust_vec_len, data_len),
+        108 => {
+            wire__crate__api__message__get_message_reactions_impl(port, ptr, rust_vec_len, data_len)
+        }
+        109 => {
+            wire__crate__api__message__get_message_receipts_impl(port, ptr, rust_vec_len, data_len)
+        }
+        110 => wire__crate__api__message__get_messages_impl(port, ptr, rust_vec_len, data_len),
+        112 => wire__crate__api__meeting_intelligence__get_open_action_items_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__meeting_intelligence__get_meeting_notes_impl(
+        113 => {
+            wire__crate__api__app_state__get_outbox_count_impl(port, ptr, rust_vec_len, data_len)
+        }
+        114 => {
+            wire__crate__api__call_webrtc__get_peer_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        115 => wire__crate__api__meeting_intelligence__get_redact_patterns_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => wire__crate__api__message__get_message_impl(port, ptr, rust_vec_len, data_len),
-        64 => wire__crate__api__message__get_messages_impl(port, ptr, rust_vec_len, data_len),
-        65 => wire__crate__api__call_webrtc__get_peer_stats_impl(port, ptr, rust_vec_len, data_len),
-        66 => wire__crate__api__call_session__get_session_impl(port, ptr, rust_vec_len, data_len),
-        67 => wire__crate__api__call_webrtc__get_sfu_config_impl(port, ptr, rust_vec_len, data_len),
-        68 => wire__crate__api__call_quality__get_simulcast_config_impl(
+        116 => wire__crate__api__relay__get_relay_roles_impl(port, ptr, rust_vec_len, data_len),
+        117 => wire__crate__api__call_session__get_session_impl(port, ptr, rust_vec_len, data_len),
+        118 => {
+            wire__crate__api__call_webrtc__get_sfu_config_impl(port, ptr, rust_vec_len, data_len)
+        }
+        119 => wire__crate__api__account__get_signer_kind_impl(port, ptr, rust_vec_len, data_len),
+        120 => wire__crate__api__call_quality__get_simulcast_config_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__transcription__get_transcript_segments_impl(
+        121 => wire__crate__api__transcription__get_transcript_segments_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__transcription__get_transcript_text_impl(
+        122 => wire__crate__api__transcription__get_transcript_text_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__api__transcription__get_transcription_status_impl(
+        123 => wire__crate__api__transcription__get_transcription_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__crate__api__call_quality__get_video_constraints_impl(
+        124 => wire__crate__api__call_quality__get_video_constraints_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__invite__gift_wrap_welcome_impl(port, ptr, rust_vec_len, data_len),
-        75 => {
+        125 => wire__crate__api__invite__gift_wrap_welcome_impl(port, ptr, rust_vec_len, data_len),
+        127 => {
             wire__crate__api__message__group_message_filter_impl(port, ptr, rust_vec_len, data_len)
         }
-        76 => {
+        128 => {
             wire__crate__api__account__has_keyring_account_impl(port, ptr, rust_vec_len, data_len)
         }
-        77 => wire__crate__api__app_state__import_archived_groups_impl(
+        129 => wire__crate__api__app_state__import_archived_groups_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        79 => wire__crate__api__state__init_state_impl(port, ptr, rust_vec_len, data_len),
-        80 => wire__crate__api__transcription__init_transcription_impl(
+        130 => {
+            wire__crate__api__contacts__import_contacts_list_impl(port, ptr, rust_vec_len, data_len)
+        }
+        131 => wire__crate__api__backup__import_state_impl(port, ptr, rust_vec_len, data_len),
+        132 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__state__init_state_impl(port, ptr, rust_vec_len, data_len),
+        134 => wire__crate__api__transcription__init_transcription_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        81 => wire__crate__api__state__initialize_keyring_store_impl(
+        135 => wire__crate__api__state__initialize_keyring_store_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => {
+        136 => {
             wire__crate__api__call_signaling__initiate_call_impl(port, ptr, rust_vec_len, data_len)
         }
-        83 => {
+        137 => wire__crate__api__call_signaling__initiate_call_fragments_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        138 => {
+            wire__crate__api__invite__invite_members_full_impl(port, ptr, rust_vec_len, data_len)
+        }
+        139 => {
             wire__crate__api__app_state__is_group_archived_impl(port, ptr, rust_vec_len, data_len)
         }
-        84 => wire__crate__api__state__is_initialized_impl(port, ptr, rust_vec_len, data_len),
-        85 => wire__crate__api__account__is_logged_in_impl(port, ptr, rust_vec_len, data_len),
-        86 => wire__crate__api__group__leave_group_impl(port, ptr, rust_vec_len, data_len),
-        87 => wire__crate__api__group__list_groups_impl(port, ptr, rust_vec_len, data_len),
-        88 => {
+        140 => wire__crate__api__state__is_initialized_impl(port, ptr, rust_vec_len, data_len),
+        141 => wire__crate__api__account__is_logged_in_impl(port, ptr, rust_vec_len, data_len),
+        143 => wire__crate__api__group__leave_group_impl(port, ptr, rust_vec_len, data_len),
+        144 => wire__crate__api__group__list_groups_impl(port, ptr, rust_vec_len, data_len),
+        145 => {
+            wire__crate__api__group__list_inactive_groups_impl(port, ptr, rust_vec_len, data_len)
+        }
+        146 => {
             wire__crate__api__invite__list_pending_welcomes_impl(port, ptr, rust_vec_len, data_len)
         }
-        89 => wire__crate__api__relay__list_relays_impl(port, ptr, rust_vec_len, data_len),
-        90 => wire__crate__api__call_signaling__listen_for_call_events_impl(
+        147 => wire__crate__api__relay__list_relays_impl(port, ptr, rust_vec_len, data_len),
+        148 => wire__crate__api__call_signaling__listen_for_call_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        149 => wire__crate__api__message__listen_for_group_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        150 => wire__crate__api__message__listen_for_local_sends_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__message__listen_for_group_messages_impl(
+        151 => wire__crate__api__account__load_account_from_keyring_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        92 => wire__crate__api__account__load_account_from_keyring_impl(
+        152 => wire__crate__api__account__login_impl(port, ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__account__login_with_bunker_impl(port, ptr, rust_vec_len, data_len),
+        154 => wire__crate__api__account__logout_impl(port, ptr, rust_vec_len, data_len),
+        155 => wire__crate__api__app_state__mark_group_read_impl(port, ptr, rust_vec_len, data_len),
+        156 => {
+            wire__crate__api__group__merge_pending_commit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__message__outbox_flush_result_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__account__login_impl(port, ptr, rust_vec_len, data_len),
-        94 => wire__crate__api__account__logout_impl(port, ptr, rust_vec_len, data_len),
-        95 => wire__crate__api__app_state__mark_group_read_impl(port, ptr, rust_vec_len, data_len),
-        96 => wire__crate__api__group__merge_pending_commit_impl(port, ptr, rust_vec_len, data_len),
-        97 => wire__crate__api__media__parse_imeta_tag_impl(port, ptr, rust_vec_len, data_len),
-        98 => {
+        158 => wire__crate__api__media__parse_imeta_tag_impl(port, ptr, rust_vec_len, data_len),
+        159 => {
             wire__crate__api__call_webrtc__parse_sdp_answer_impl(port, ptr, rust_vec_len, data_len)
         }
-        99 => {
+        160 => {
             wire__crate__api__call_webrtc__parse_sdp_offer_impl(port, ptr, rust_vec_len, data_len)
         }
-        100 => wire__crate__api__transcription__pause_transcription_impl(
+        161 => wire__crate__api__transcription__pause_transcription_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__crate__api__call_signaling__process_call_event_impl(
+        162 => wire__crate__api__call_signaling__process_call_event_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__crate__api__message__process_message_impl(port, ptr, rust_vec_len, data_len),
-        103 => wire__crate__api__invite__process_welcome_impl(port, ptr, rust_vec_len, data_len),
-        104 => {
+        163 => wire__crate__api__message__process_message_impl(port, ptr, rust_vec_len, data_len),
+        164 => wire__crate__api__invite__process_welcome_impl(port, ptr, rust_vec_len, data_len),
+        165 => {
             wire__crate__api__identity__profile_data_default_impl(port, ptr, rust_vec_len, data_len)
         }
-        105 => wire__crate__api__relay__publish_event_json_impl(port, ptr, rust_vec_len, data_len),
-        106 => wire__crate__api__relay__publish_event_json_to_relay_impl(
+        166 => wire__crate__api__group__promote_admin_impl(port, ptr, rust_vec_len, data_len),
+        167 => wire__crate__api__relay__publish_event_json_impl(port, ptr, rust_vec_len, data_len),
+        168 => wire__crate__api__relay__publish_event_json_to_relay_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        169 => {
+            wire__crate__api__group__publish_evolution_event_impl(port, ptr, rust_vec_len, data_len)
+        }
+        170 => wire__crate__api__keypackage__publish_key_package_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        171 => wire__crate__api__keypackage__publish_key_package_relays_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        172 => wire__crate__api__message__purge_expired_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__crate__api__keypackage__publish_key_package_impl(
+        173 => wire__crate__api__meeting_intelligence__push_transcript_segment_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__crate__api__keypackage__publish_key_package_relays_impl(
+        174 => {
+            wire__crate__api__call_webrtc__reap_stale_peers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        177 => wire__crate__api__call_quality__recommend_jitter_buffer_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__call_quality__recommend_quality_preset_impl(
+        178 => wire__crate__api__call_quality__recommend_quality_preset_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__transcription__register_speaker_impl(
+        179 => wire__crate__api__group__reconcile_groups_impl(port, ptr, rust_vec_len, data_len),
+        180 => wire__crate__api__transcription__register_speaker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        111 => {
+        181 => {
             wire__crate__api__call_signaling__reject_call_impl(port, ptr, rust_vec_len, data_len)
         }
-        112 => {
+        182 => {
+            wire__crate__api__group__relays_for_evolution_impl(port, ptr, rust_vec_len, data_len)
+        }
+        183 => {
             wire__crate__api__call_webrtc__remove_call_peers_impl(port, ptr, rust_vec_len, data_len)
         }
-        113 => wire__crate__api__group__remove_group_image_impl(port, ptr, rust_vec_len, data_len),
-        114 => wire__crate__api__invite__remove_members_impl(port, ptr, rust_vec_len, data_len),
-        115 => wire__crate__api__relay__remove_relay_impl(port, ptr, rust_vec_len, data_len),
-        116 => {
+        184 => wire__crate__api__group__remove_group_image_impl(port, ptr, rust_vec_len, data_len),
+        185 => wire__crate__api__invite__remove_members_impl(port, ptr, rust_vec_len, data_len),
+        186 => wire__crate__api__relay__remove_relay_impl(port, ptr, rust_vec_len, data_len),
+        187 => {
             wire__crate__api__call_session__remove_session_impl(port, ptr, rust_vec_len, data_len)
         }
-        117 => {
+        188 => {
             wire__crate__api__call_webrtc__report_peer_stats_impl(port, ptr, rust_vec_len, data_len)
         }
-        118 => wire__crate__api__transcription__resume_transcription_impl(
+        190 => wire__crate__api__transcription__resume_transcription_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        191 => {
+            wire__crate__api__call_webrtc__rotate_frame_key_impl(port, ptr, rust_vec_len, data_len)
+        }
+        193 => wire__crate__api__account__save_secret_key_to_keyring_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => {
-            wire__crate__api__call_webrtc__rotate_frame_key_impl(port, ptr, rust_vec_len, data_len)
-        }
-        121 => wire__crate__api__account__save_secret_key_to_keyring_impl(
+        194 => wire__crate__api__contacts__search_contacts_impl(port, ptr, rust_vec_len, data_len),
+        195 => wire__crate__api__meeting_intelligence__search_meetings_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        196 => wire__crate__api__message__search_messages_impl(port, ptr, rust_vec_len, data_len),
+        197 => wire__crate__api__transcription__search_transcript_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__crate__api__meeting_intelligence__search_meetings_impl(
+        198 => wire__crate__api__message__send_impl(port, ptr, rust_vec_len, data_len),
+        199 => wire__crate__api__call_signaling__send_call_state_update_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__crate__api__transcription__search_transcript_impl(
+        200 => wire__crate__api__call_signaling__send_ice_candidate_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => wire__crate__api__call_signaling__send_call_state_update_impl(
+        201 => wire__crate__api__message__send_long_message_impl(port, ptr, rust_vec_len, data_len),
+        202 => wire__crate__api__message__send_message_impl(port, ptr, rust_vec_len, data_len),
+        203 => wire__crate__api__message__send_message_with_media_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        125 => wire__crate__api__call_signaling__send_ice_candidate_impl(
+        204 => wire__crate__api__message__send_poll_impl(port, ptr, rust_vec_len, data_len),
+        205 => wire__crate__api__message__send_poll_vote_impl(port, ptr, rust_vec_len, data_len),
+        206 => wire__crate__api__message__send_reaction_impl(port, ptr, rust_vec_len, data_len),
+        207 => wire__crate__api__message__send_read_receipt_impl(port, ptr, rust_vec_len, data_len),
+        208 => wire__crate__api__call_signaling__send_renegotiation_answer_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__crate__api__message__send_message_impl(port, ptr, rust_vec_len, data_len),
-        127 => wire__crate__api__message__send_message_with_media_impl(
+        209 => wire__crate__api__call_signaling__send_renegotiation_offer_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        128 => wire__crate__api__message__send_poll_impl(port, ptr, rust_vec_len, data_len),
-        129 => wire__crate__api__message__send_poll_vote_impl(port, ptr, rust_vec_len, data_len),
-        130 => wire__crate__api__message__send_reaction_impl(port, ptr, rust_vec_len, data_len),
-        131 => {
+        210 => wire__crate__api__message__send_reply_impl(port, ptr, rust_vec_len, data_len),
+        211 => {
             wire__crate__api__message__send_typing_indicator_impl(port, ptr, rust_vec_len, data_len)
         }
-        132 => wire__crate__api__state__set_data_dir_impl(port, ptr, rust_vec_len, data_len),
-        133 => wire__crate__api__app_state__set_group_state_impl(port, ptr, rust_vec_len, data_len),
-        134 => wire__crate__api__call_session__set_muted_impl(port, ptr, rust_vec_len, data_len),
-        135 => wire__crate__api__identity__set_profile_impl(port, ptr, rust_vec_len, data_len),
-        136 => wire__crate__api__call_session__set_video_enabled_impl(
+        213 => wire__crate__api__state__set_data_dir_impl(port, ptr, rust_vec_len, data_len),
+        215 => {
+            wire__crate__api__group__set_group_message_ttl_impl(port, ptr, rust_vec_len, data_len)
+        }
+        216 => wire__crate__api__app_state__set_group_state_impl(port, ptr, rust_vec_len, data_len),
+        221 => wire__crate__api__call_session__set_muted_impl(port, ptr, rust_vec_len, data_len),
+        223 => wire__crate__api__identity__set_profile_impl(port, ptr, rust_vec_len, data_len),
+        224 => wire__crate__api__relay__set_relay_roles_impl(port, ptr, rust_vec_len, data_len),
+        225 => wire__crate__api__call_session__set_video_enabled_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        137 => {
+        227 => {
             wire__crate__api__call_webrtc__should_use_sfu_impl(port, ptr, rust_vec_len, data_len)
         }
-        138 => wire__crate__api__transcription__start_transcription_impl(
+        229 => wire__crate__api__transcription__start_transcription_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        139 => wire__crate__api__transcription__stop_transcription_impl(
+        230 => wire__crate__api__transcription__stop_transcription_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => wire__crate__api__call_signaling__subscribe_call_events_impl(
+        231 => wire__crate__api__call_signaling__subscribe_call_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        141 => wire__crate__api__contacts__sync_contacts_impl(port, ptr, rust_vec_len, data_len),
-        142 => {
+        232 => wire__crate__api__contacts__sync_contacts_impl(port, ptr, rust_vec_len, data_len),
+        233 => {
             wire__crate__api__message__sync_group_messages_impl(port, ptr, rust_vec_len, data_len)
         }
-        143 => wire__crate__api__invite__sync_welcomes_impl(port, ptr, rust_vec_len, data_len),
-        144 => wire__crate__api__meeting_intelligence__toggle_action_item_impl(
+        234 => wire__crate__api__invite__sync_welcomes_impl(port, ptr, rust_vec_len, data_len),
+        235 => wire__crate__api__meeting_intelligence__toggle_action_item_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        145 => wire__crate__api__transcription__transcription_config_default_impl(
+        236 => wire__crate__api__transcription__transcription_config_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        146 => wire__crate__api__app_state__unarchive_group_impl(port, ptr, rust_vec_len, data_len),
-        147 => wire__crate__api__contacts__unfollow_contact_impl(port, ptr, rust_vec_len, data_len),
-        148 => wire__crate__api__group__update_group_description_impl(
+        237 => wire__crate__api__app_state__unarchive_group_impl(port, ptr, rust_vec_len, data_len),
+        238 => wire__crate__api__contacts__unfollow_contact_impl(port, ptr, rust_vec_len, data_len),
+        239 => wire__crate__api__group__update_group_description_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__crate__api__group__update_group_name_impl(port, ptr, rust_vec_len, data_len),
-        150 => wire__crate__api__group__update_group_relays_impl(port, ptr, rust_vec_len, data_len),
-        151 => {
+        240 => wire__crate__api__group__update_group_name_impl(port, ptr, rust_vec_len, data_len),
+        241 => wire__crate__api__group__update_group_relays_impl(port, ptr, rust_vec_len, data_len),
+        242 => {
             wire__crate__api__call_webrtc__update_peer_state_impl(port, ptr, rust_vec_len, data_len)
         }
-        152 => wire__crate__api__call_session__update_session_state_impl(
+        243 => wire__crate__api__call_session__update_session_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        153 => wire__crate__api__group__upload_group_image_impl(port, ptr, rust_vec_len, data_len),
-        154 => wire__crate__api__media__upload_media_impl(port, ptr, rust_vec_len, data_len),
-        155 => {
+        244 => wire__crate__api__group__upload_group_image_impl(port, ptr, rust_vec_len, data_len),
+        245 => wire__crate__api__media__upload_media_impl(port, ptr, rust_vec_len, data_len),
+        246 => wire__crate__api__media__upload_media_batch_impl(port, ptr, rust_vec_len, data_len),
+        247 => {
             wire__crate__api__identity__upload_profile_photo_impl(port, ptr, rust_vec_len, data_len)
         }
-        156 => {
+        248 => wire__crate__api__message__validate_reaction_impl(port, ptr, rust_vec_len, data_len),
+        249 => {
             wire__crate__api__relay__verify_event_published_impl(port, ptr, rust_vec_len, data_len)
         }
         _ => unreachable!(),
@@ -7715,9 +12026,54 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        22 => wire__crate__api__relay__default_relay_urls_impl(ptr, rust_vec_len, data_len),
-        74 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        120 => wire__crate__api__simple__rust_lib_version_impl(ptr, rust_vec_len, data_len),
+        1 => wire__crate__api__diagnostics__DiagnosticsReport_to_json_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__relay__default_relay_urls_impl(ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__contacts__get_contacts_staleness_impl(ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__ephemeral__get_ephemeral_key_strategy_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__api__invite__get_key_package_max_age_impl(ptr, rust_vec_len, data_len),
+        101 => {
+            wire__crate__api__state__get_max_concurrent_relay_ops_impl(ptr, rust_vec_len, data_len)
+        }
+        102 => wire__crate__api__message__get_max_message_bytes_impl(ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__media__get_media_cache_limit_impl(ptr, rust_vec_len, data_len),
+        111 => wire__crate__api__contacts__get_nip05_verification_impl(ptr, rust_vec_len, data_len),
+        126 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        142 => wire__crate__api__message__is_message_expired_impl(ptr, rust_vec_len, data_len),
+        175 => wire__crate__api__message__reassemble_message_impl(ptr, rust_vec_len, data_len),
+        176 => {
+            wire__crate__api__call_webrtc__recommend_force_relay_impl(ptr, rust_vec_len, data_len)
+        }
+        189 => wire__crate__api__call_signaling__reset_call_signaling_state_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        192 => wire__crate__api__simple__rust_lib_version_impl(ptr, rust_vec_len, data_len),
+        212 => wire__crate__api__contacts__set_contacts_staleness_impl(ptr, rust_vec_len, data_len),
+        214 => wire__crate__api__ephemeral__set_ephemeral_key_strategy_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        217 => wire__crate__api__invite__set_key_package_max_age_impl(ptr, rust_vec_len, data_len),
+        218 => {
+            wire__crate__api__state__set_max_concurrent_relay_ops_impl(ptr, rust_vec_len, data_len)
+        }
+        219 => wire__crate__api__message__set_max_message_bytes_impl(ptr, rust_vec_len, data_len),
+        220 => wire__crate__api__media__set_media_cache_limit_impl(ptr, rust_vec_len, data_len),
+        222 => wire__crate__api__contacts__set_nip05_verification_impl(ptr, rust_vec_len, data_len),
+        226 => {
+            wire__crate__api__media__should_use_chunked_encryption_impl(ptr, rust_vec_len, data_len)
+        }
+        228 => wire__crate__api__message__split_message_content_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -7869,6 +12225,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::AudioMode>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::BatchUploadItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.filename.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::BatchUploadItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::BatchUploadItem>
+    for crate::api::media::BatchUploadItem
+{
+    fn into_into_dart(self) -> crate::api::media::BatchUploadItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::error::BurrowError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.message.into_into_dart().into_dart()].into_dart()
@@ -7948,6 +12326,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::call_signaling::CallSignaling
             self.call_type.into_into_dart().into_dart(),
             self.content.into_into_dart().into_dart(),
             self.created_at.into_into_dart().into_dart(),
+            self.renegotiate.into_into_dart().into_dart(),
+            self.sequence.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8012,6 +12392,35 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_session::CallType>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ChunkedEncryptResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_path.into_into_dart().into_dart(),
+            self.original_hash_hex.into_into_dart().into_dart(),
+            self.encrypted_hash_hex.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.filename.into_into_dart().into_dart(),
+            self.original_size.into_into_dart().into_dart(),
+            self.encrypted_size.into_into_dart().into_dart(),
+            self.nonce_hex.into_into_dart().into_dart(),
+            self.frame_size.into_into_dart().into_dart(),
+            self.scheme_version.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ChunkedEncryptResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ChunkedEncryptResult>
+    for crate::api::media::ChunkedEncryptResult
+{
+    fn into_into_dart(self) -> crate::api::media::ChunkedEncryptResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::call_quality::CodecPreferences {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8021,112 +12430,333 @@ impl flutter_rust_bridge::IntoDart for crate::api::call_quality::CodecPreference
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::call_quality::CodecPreferences
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::CodecPreferences>
-    for crate::api::call_quality::CodecPreferences
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::call_quality::CodecPreferences
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::CodecPreferences>
+    for crate::api::call_quality::CodecPreferences
+{
+    fn into_into_dart(self) -> crate::api::call_quality::CodecPreferences {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pubkey_hex.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.picture.into_into_dart().into_dart(),
+            self.nip05.into_into_dart().into_dart(),
+            self.nip05_verified.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::contacts::ContactInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactInfo>
+    for crate::api::contacts::ContactInfo
+{
+    fn into_into_dart(self) -> crate::api::contacts::ContactInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactsSyncDebug {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.connected_relays.into_into_dart().into_dart(),
+            self.follow_count.into_into_dart().into_dart(),
+            self.key_package_count.into_into_dart().into_dart(),
+            self.db_follow_count.into_into_dart().into_dart(),
+            self.db_kp_count.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::contacts::ContactsSyncDebug
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactsSyncDebug>
+    for crate::api::contacts::ContactsSyncDebug
+{
+    fn into_into_dart(self) -> crate::api::contacts::ContactsSyncDebug {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::group::CreateGroupResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.group.into_into_dart().into_dart(),
+            self.welcome_rumors_json.into_into_dart().into_dart(),
+            self.mls_group_id_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::group::CreateGroupResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::CreateGroupResult>
+    for crate::api::group::CreateGroupResult
+{
+    fn into_into_dart(self) -> crate::api::group::CreateGroupResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::group::CreateOrGetDmResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.group.into_into_dart().into_dart(),
+            self.welcome_rumors_json.into_into_dart().into_dart(),
+            self.mls_group_id_hex.into_into_dart().into_dart(),
+            self.reused.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::group::CreateOrGetDmResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::CreateOrGetDmResult>
+    for crate::api::group::CreateOrGetDmResult
+{
+    fn into_into_dart(self) -> crate::api::group::CreateOrGetDmResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::DiagnosticsReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.generated_at.into_into_dart().into_dart(),
+            self.core_version.into_into_dart().into_dart(),
+            self.initialized.into_into_dart().into_dart(),
+            self.pubkey_hex.into_into_dart().into_dart(),
+            self.relays.into_into_dart().into_dart(),
+            self.group_count.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+            self.pending_welcome_count.into_into_dart().into_dart(),
+            self.last_contacts_sync.into_into_dart().into_dart(),
+            self.app_db_initialized.into_into_dart().into_dart(),
+            self.app_db_schema_version.into_into_dart().into_dart(),
+            self.error_counts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::DiagnosticsReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::DiagnosticsReport>
+    for crate::api::diagnostics::DiagnosticsReport
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::DiagnosticsReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::EncryptedFileOnDisk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.encrypted_path.into_into_dart().into_dart(),
+            self.original_hash_hex.into_into_dart().into_dart(),
+            self.encrypted_hash_hex.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.filename.into_into_dart().into_dart(),
+            self.original_size.into_into_dart().into_dart(),
+            self.encrypted_size.into_into_dart().into_dart(),
+            self.dimensions.into_into_dart().into_dart(),
+            self.blurhash.into_into_dart().into_dart(),
+            self.nonce_hex.into_into_dart().into_dart(),
+            self.scheme_version.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::EncryptedFileOnDisk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::EncryptedFileOnDisk>
+    for crate::api::media::EncryptedFileOnDisk
+{
+    fn into_into_dart(self) -> crate::api::media::EncryptedFileOnDisk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::EncryptedFileResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.encrypted_data.into_into_dart().into_dart(),
+            self.original_hash_hex.into_into_dart().into_dart(),
+            self.encrypted_hash_hex.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.filename.into_into_dart().into_dart(),
+            self.original_size.into_into_dart().into_dart(),
+            self.encrypted_size.into_into_dart().into_dart(),
+            self.dimensions.into_into_dart().into_dart(),
+            self.blurhash.into_into_dart().into_dart(),
+            self.nonce_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::EncryptedFileResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::EncryptedFileResult>
+    for crate::api::media::EncryptedFileResult
+{
+    fn into_into_dart(self) -> crate::api::media::EncryptedFileResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ephemeral::EphemeralKeyStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::PerEvent => 0.into_dart(),
+            Self::PerSession => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ephemeral::EphemeralKeyStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ephemeral::EphemeralKeyStrategy>
+    for crate::api::ephemeral::EphemeralKeyStrategy
+{
+    fn into_into_dart(self) -> crate::api::ephemeral::EphemeralKeyStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::group::EpochInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.epoch.into_into_dart().into_dart(),
+            self.recorded_at.into_into_dart().into_dart(),
+            self.committer_pubkey_hex.into_into_dart().into_dart(),
+            self.commit_event_id_hex.into_into_dart().into_dart(),
+            self.added_pubkeys.into_into_dart().into_dart(),
+            self.removed_pubkeys.into_into_dart().into_dart(),
+            self.metadata_changed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::group::EpochInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::EpochInfo>
+    for crate::api::group::EpochInfo
 {
-    fn into_into_dart(self) -> crate::api::call_quality::CodecPreferences {
+    fn into_into_dart(self) -> crate::api::group::EpochInfo {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::ErrorCount {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.pubkey_hex.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.picture.into_into_dart().into_dart(),
+            self.subsystem.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.last_error_at.into_into_dart().into_dart(),
+            self.last_error_kind.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::contacts::ContactInfo
+    for crate::api::diagnostics::ErrorCount
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactInfo>
-    for crate::api::contacts::ContactInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::ErrorCount>
+    for crate::api::diagnostics::ErrorCount
 {
-    fn into_into_dart(self) -> crate::api::contacts::ContactInfo {
+    fn into_into_dart(self) -> crate::api::diagnostics::ErrorCount {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::contacts::ContactsSyncDebug {
+impl flutter_rust_bridge::IntoDart for crate::api::media::FileInput {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.connected_relays.into_into_dart().into_dart(),
-            self.follow_count.into_into_dart().into_dart(),
-            self.key_package_count.into_into_dart().into_dart(),
-            self.db_follow_count.into_into_dart().into_dart(),
-            self.db_kp_count.into_into_dart().into_dart(),
-            self.error.into_into_dart().into_dart(),
+            self.file_data.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.filename.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::contacts::ContactsSyncDebug
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::contacts::ContactsSyncDebug>
-    for crate::api::contacts::ContactsSyncDebug
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::media::FileInput {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::FileInput>
+    for crate::api::media::FileInput
 {
-    fn into_into_dart(self) -> crate::api::contacts::ContactsSyncDebug {
+    fn into_into_dart(self) -> crate::api::media::FileInput {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::group::CreateGroupResult {
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::GroupDiagnostics {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.group.into_into_dart().into_dart(),
-            self.welcome_rumors_json.into_into_dart().into_dart(),
             self.mls_group_id_hex.into_into_dart().into_dart(),
+            self.nostr_group_id_hex.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.member_count.into_into_dart().into_dart(),
+            self.relay_count.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::group::CreateGroupResult
+    for crate::api::diagnostics::GroupDiagnostics
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::group::CreateGroupResult>
-    for crate::api::group::CreateGroupResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::GroupDiagnostics>
+    for crate::api::diagnostics::GroupDiagnostics
 {
-    fn into_into_dart(self) -> crate::api::group::CreateGroupResult {
+    fn into_into_dart(self) -> crate::api::diagnostics::GroupDiagnostics {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::media::EncryptedFileResult {
+impl flutter_rust_bridge::IntoDart for crate::api::group::GroupEpochEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.encrypted_data.into_into_dart().into_dart(),
-            self.original_hash_hex.into_into_dart().into_dart(),
-            self.encrypted_hash_hex.into_into_dart().into_dart(),
-            self.mime_type.into_into_dart().into_dart(),
-            self.filename.into_into_dart().into_dart(),
-            self.original_size.into_into_dart().into_dart(),
-            self.encrypted_size.into_into_dart().into_dart(),
-            self.dimensions.into_into_dart().into_dart(),
-            self.blurhash.into_into_dart().into_dart(),
-            self.nonce_hex.into_into_dart().into_dart(),
+            self.epoch.into_into_dart().into_dart(),
+            self.change_type.into_into_dart().into_dart(),
+            self.pubkeys.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.committer_pubkey_hex.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::media::EncryptedFileResult
+    for crate::api::group::GroupEpochEvent
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::media::EncryptedFileResult>
-    for crate::api::media::EncryptedFileResult
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::GroupEpochEvent>
+    for crate::api::group::GroupEpochEvent
 {
-    fn into_into_dart(self) -> crate::api::media::EncryptedFileResult {
+    fn into_into_dart(self) -> crate::api::group::GroupEpochEvent {
         self
     }
 }
@@ -8148,6 +12778,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::group::GroupInfo {
             self.dm_peer_pubkey_hex.into_into_dart().into_dart(),
             self.image_hash_hex.into_into_dart().into_dart(),
             self.has_image.into_into_dart().into_dart(),
+            self.default_message_ttl_secs.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8173,6 +12804,10 @@ impl flutter_rust_bridge::IntoDart for crate::api::message::GroupMessage {
             self.tags.into_into_dart().into_dart(),
             self.wrapper_event_id_hex.into_into_dart().into_dart(),
             self.epoch.into_into_dart().into_dart(),
+            self.mentions_me.into_into_dart().into_dart(),
+            self.edited_from.into_into_dart().into_dart(),
+            self.reply_to_event_id_hex.into_into_dart().into_dart(),
+            self.reply_to_author_hex.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8195,6 +12830,9 @@ impl flutter_rust_bridge::IntoDart for crate::api::message::GroupNotification {
             self.notification_type.into_into_dart().into_dart(),
             self.message.into_into_dart().into_dart(),
             self.mls_group_id_hex.into_into_dart().into_dart(),
+            self.added_pubkeys.into_into_dart().into_dart(),
+            self.removed_pubkeys.into_into_dart().into_dart(),
+            self.sender_pubkey_hex.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8256,6 +12894,52 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_webrtc::IceServer>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::invite::InviteResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mls_group_id_hex.into_into_dart().into_dart(),
+            self.evolution_event_id_hex.into_into_dart().into_dart(),
+            self.welcome_event_ids_hex.into_into_dart().into_dart(),
+            self.failed_welcome_pubkeys_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::invite::InviteResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::invite::InviteResult>
+    for crate::api::invite::InviteResult
+{
+    fn into_into_dart(self) -> crate::api::invite::InviteResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::call_quality::JitterBufferConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.target_ms.into_into_dart().into_dart(),
+            self.min_ms.into_into_dart().into_dart(),
+            self.max_ms.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::call_quality::JitterBufferConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::JitterBufferConfig>
+    for crate::api::call_quality::JitterBufferConfig
+{
+    fn into_into_dart(self) -> crate::api::call_quality::JitterBufferConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::keypackage::KeyPackageData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8287,6 +12971,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::media::MediaReferenceInfo {
             self.dimensions.into_into_dart().into_dart(),
             self.scheme_version.into_into_dart().into_dart(),
             self.nonce_hex.into_into_dart().into_dart(),
+            self.thumb.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8322,6 +13007,100 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::group::MemberInfo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::message::MessageReaction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id_hex.into_into_dart().into_dart(),
+            self.reactor_pubkey_hex.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.custom_emoji_url.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::message::MessageReaction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::message::MessageReaction>
+    for crate::api::message::MessageReaction
+{
+    fn into_into_dart(self) -> crate::api::message::MessageReaction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::message::MessageReader {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reader_pubkey_hex.into_into_dart().into_dart(),
+            self.read_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::message::MessageReader
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::message::MessageReader>
+    for crate::api::message::MessageReader
+{
+    fn into_into_dart(self) -> crate::api::message::MessageReader {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::link_preview::OgMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.image_url.into_into_dart().into_dart(),
+            self.site_name.into_into_dart().into_dart(),
+            self.domain.into_into_dart().into_dart(),
+            self.og_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::link_preview::OgMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::link_preview::OgMetadata>
+    for crate::api::link_preview::OgMetadata
+{
+    fn into_into_dart(self) -> crate::api::link_preview::OgMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::message::OutboxFlushResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.published.into_into_dart().into_dart(),
+            self.reencrypted.into_into_dart().into_dart(),
+            self.dropped.into_into_dart().into_dart(),
+            self.remaining.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::message::OutboxFlushResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::message::OutboxFlushResult>
+    for crate::api::message::OutboxFlushResult
+{
+    fn into_into_dart(self) -> crate::api::message::OutboxFlushResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::call_webrtc::PeerConnectionState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -8497,11 +13276,80 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::QualityScore>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::message::ReceiptSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.event_id_hex.into_into_dart().into_dart(),
+            self.readers.into_into_dart().into_dart(),
+            self.recipient_count.into_into_dart().into_dart(),
+            self.read_by_all.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::message::ReceiptSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::message::ReceiptSummary>
+    for crate::api::message::ReceiptSummary
+{
+    fn into_into_dart(self) -> crate::api::message::ReceiptSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::group::ReconcileReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mdk_group_count.into_into_dart().into_dart(),
+            self.restored_group_ids.into_into_dart().into_dart(),
+            self.orphaned_group_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::group::ReconcileReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::ReconcileReport>
+    for crate::api::group::ReconcileReport
+{
+    fn into_into_dart(self) -> crate::api::group::ReconcileReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::diagnostics::RelayDiagnostics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.connected.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::diagnostics::RelayDiagnostics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::diagnostics::RelayDiagnostics>
+    for crate::api::diagnostics::RelayDiagnostics
+{
+    fn into_into_dart(self) -> crate::api::diagnostics::RelayDiagnostics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::relay::RelayInfo {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.url.into_into_dart().into_dart(),
             self.connected.into_into_dart().into_dart(),
+            self.read.into_into_dart().into_dart(),
+            self.write.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8524,6 +13372,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::call_webrtc::SdpInfo {
             self.media_count.into_into_dart().into_dart(),
             self.ice_ufrag.into_into_dart().into_dart(),
             self.codecs.into_into_dart().into_dart(),
+            self.bandwidth_kbps.into_into_dart().into_dart(),
+            self.opus_params.into_into_dart().into_dart(),
             self.is_valid.into_into_dart().into_dart(),
             self.error.into_into_dart().into_dart(),
         ]
@@ -8585,6 +13435,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_webrtc::SfuConfig>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::signer::SignerKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Local => 0.into_dart(),
+            Self::Nip46 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::signer::SignerKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::signer::SignerKind>
+    for crate::api::signer::SignerKind
+{
+    fn into_into_dart(self) -> crate::api::signer::SignerKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::call_quality::SimulcastConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8631,6 +13502,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::call_quality::SimulcastLayer>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::media::ThumbnailReference {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.original_hash_hex.into_into_dart().into_dart(),
+            self.nonce_hex.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::media::ThumbnailReference
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::media::ThumbnailReference>
+    for crate::api::media::ThumbnailReference
+{
+    fn into_into_dart(self) -> crate::api::media::ThumbnailReference {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::transcription::TranscriptSegment {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8690,6 +13583,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::group::UpdateGroupResult {
             self.evolution_event_json.into_into_dart().into_dart(),
             self.welcome_rumors_json.into_into_dart().into_dart(),
             self.mls_group_id_hex.into_into_dart().into_dart(),
+            self.publish_relays.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8805,6 +13699,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::call_webrtc::WebRtcConfig {
             self.ice_servers.into_into_dart().into_dart(),
             self.sdp_semantics.into_into_dart().into_dart(),
             self.bundle_policy.into_into_dart().into_dart(),
+            self.ice_transport_policy.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -8886,6 +13781,15 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<crate::api::message::GroupMessage, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::message::GroupNotification,
@@ -8980,6 +13884,15 @@ impl SseEncode for crate::api::call_quality::AudioMode {
     }
 }
 
+impl SseEncode for crate::api::media::BatchUploadItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.filename, serializer);
+        <Option<crate::api::media::UploadMediaResult>>::sse_encode(self.result, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9038,6 +13951,8 @@ impl SseEncode for crate::api::call_signaling::CallSignalingEvent {
         <Option<String>>::sse_encode(self.call_type, serializer);
         <String>::sse_encode(self.content, serializer);
         <u64>::sse_encode(self.created_at, serializer);
+        <bool>::sse_encode(self.renegotiate, serializer);
+        <u64>::sse_encode(self.sequence, serializer);
     }
 }
 
@@ -9079,6 +13994,22 @@ impl SseEncode for crate::api::call_session::CallType {
     }
 }
 
+impl SseEncode for crate::api::media::ChunkedEncryptResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.output_path, serializer);
+        <String>::sse_encode(self.original_hash_hex, serializer);
+        <String>::sse_encode(self.encrypted_hash_hex, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.filename, serializer);
+        <u64>::sse_encode(self.original_size, serializer);
+        <u64>::sse_encode(self.encrypted_size, serializer);
+        <String>::sse_encode(self.nonce_hex, serializer);
+        <u32>::sse_encode(self.frame_size, serializer);
+        <String>::sse_encode(self.scheme_version, serializer);
+    }
+}
+
 impl SseEncode for crate::api::call_quality::CodecPreferences {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9093,6 +14024,8 @@ impl SseEncode for crate::api::contacts::ContactInfo {
         <String>::sse_encode(self.pubkey_hex, serializer);
         <Option<String>>::sse_encode(self.display_name, serializer);
         <Option<String>>::sse_encode(self.picture, serializer);
+        <Option<String>>::sse_encode(self.nip05, serializer);
+        <bool>::sse_encode(self.nip05_verified, serializer);
     }
 }
 
@@ -9117,6 +14050,51 @@ impl SseEncode for crate::api::group::CreateGroupResult {
     }
 }
 
+impl SseEncode for crate::api::group::CreateOrGetDmResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::group::GroupInfo>::sse_encode(self.group, serializer);
+        <Vec<String>>::sse_encode(self.welcome_rumors_json, serializer);
+        <String>::sse_encode(self.mls_group_id_hex, serializer);
+        <bool>::sse_encode(self.reused, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::DiagnosticsReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.generated_at, serializer);
+        <String>::sse_encode(self.core_version, serializer);
+        <bool>::sse_encode(self.initialized, serializer);
+        <Option<String>>::sse_encode(self.pubkey_hex, serializer);
+        <Vec<crate::api::diagnostics::RelayDiagnostics>>::sse_encode(self.relays, serializer);
+        <u32>::sse_encode(self.group_count, serializer);
+        <Vec<crate::api::diagnostics::GroupDiagnostics>>::sse_encode(self.groups, serializer);
+        <u32>::sse_encode(self.pending_welcome_count, serializer);
+        <Option<i64>>::sse_encode(self.last_contacts_sync, serializer);
+        <bool>::sse_encode(self.app_db_initialized, serializer);
+        <Option<u32>>::sse_encode(self.app_db_schema_version, serializer);
+        <Vec<crate::api::diagnostics::ErrorCount>>::sse_encode(self.error_counts, serializer);
+    }
+}
+
+impl SseEncode for crate::api::media::EncryptedFileOnDisk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.encrypted_path, serializer);
+        <String>::sse_encode(self.original_hash_hex, serializer);
+        <String>::sse_encode(self.encrypted_hash_hex, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.filename, serializer);
+        <u64>::sse_encode(self.original_size, serializer);
+        <u64>::sse_encode(self.encrypted_size, serializer);
+        <Option<String>>::sse_encode(self.dimensions, serializer);
+        <Option<String>>::sse_encode(self.blurhash, serializer);
+        <String>::sse_encode(self.nonce_hex, serializer);
+        <String>::sse_encode(self.scheme_version, serializer);
+    }
+}
+
 impl SseEncode for crate::api::media::EncryptedFileResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9133,6 +14111,45 @@ impl SseEncode for crate::api::media::EncryptedFileResult {
     }
 }
 
+impl SseEncode for crate::api::ephemeral::EphemeralKeyStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::ephemeral::EphemeralKeyStrategy::PerEvent => 0,
+                crate::api::ephemeral::EphemeralKeyStrategy::PerSession => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::group::EpochInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.epoch, serializer);
+        <Option<u64>>::sse_encode(self.recorded_at, serializer);
+        <Option<String>>::sse_encode(self.committer_pubkey_hex, serializer);
+        <Option<String>>::sse_encode(self.commit_event_id_hex, serializer);
+        <Vec<String>>::sse_encode(self.added_pubkeys, serializer);
+        <Vec<String>>::sse_encode(self.removed_pubkeys, serializer);
+        <bool>::sse_encode(self.metadata_changed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::ErrorCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.subsystem, serializer);
+        <u32>::sse_encode(self.count, serializer);
+        <u64>::sse_encode(self.last_error_at, serializer);
+        <String>::sse_encode(self.last_error_kind, serializer);
+    }
+}
+
 impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9147,6 +14164,38 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::media::FileInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.file_data, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <String>::sse_encode(self.filename, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::GroupDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mls_group_id_hex, serializer);
+        <String>::sse_encode(self.nostr_group_id_hex, serializer);
+        <u64>::sse_encode(self.epoch, serializer);
+        <String>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.member_count, serializer);
+        <u32>::sse_encode(self.relay_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::group::GroupEpochEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.epoch, serializer);
+        <String>::sse_encode(self.change_type, serializer);
+        <Vec<String>>::sse_encode(self.pubkeys, serializer);
+        <u64>::sse_encode(self.timestamp, serializer);
+        <Option<String>>::sse_encode(self.committer_pubkey_hex, serializer);
+    }
+}
+
 impl SseEncode for crate::api::group::GroupInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9164,6 +14213,7 @@ impl SseEncode for crate::api::group::GroupInfo {
         <Option<String>>::sse_encode(self.dm_peer_pubkey_hex, serializer);
         <Option<String>>::sse_encode(self.image_hash_hex, serializer);
         <bool>::sse_encode(self.has_image, serializer);
+        <Option<u64>>::sse_encode(self.default_message_ttl_secs, serializer);
     }
 }
 
@@ -9179,6 +14229,10 @@ impl SseEncode for crate::api::message::GroupMessage {
         <Vec<Vec<String>>>::sse_encode(self.tags, serializer);
         <String>::sse_encode(self.wrapper_event_id_hex, serializer);
         <u64>::sse_encode(self.epoch, serializer);
+        <bool>::sse_encode(self.mentions_me, serializer);
+        <Option<String>>::sse_encode(self.edited_from, serializer);
+        <Option<String>>::sse_encode(self.reply_to_event_id_hex, serializer);
+        <Option<String>>::sse_encode(self.reply_to_author_hex, serializer);
     }
 }
 
@@ -9188,6 +14242,9 @@ impl SseEncode for crate::api::message::GroupNotification {
         <String>::sse_encode(self.notification_type, serializer);
         <Option<crate::api::message::GroupMessage>>::sse_encode(self.message, serializer);
         <String>::sse_encode(self.mls_group_id_hex, serializer);
+        <Vec<String>>::sse_encode(self.added_pubkeys, serializer);
+        <Vec<String>>::sse_encode(self.removed_pubkeys, serializer);
+        <Option<String>>::sse_encode(self.sender_pubkey_hex, serializer);
     }
 }
 
@@ -9224,6 +14281,26 @@ impl SseEncode for crate::api::call_webrtc::IceServer {
     }
 }
 
+impl SseEncode for crate::api::invite::InviteResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mls_group_id_hex, serializer);
+        <String>::sse_encode(self.evolution_event_id_hex, serializer);
+        <Vec<String>>::sse_encode(self.welcome_event_ids_hex, serializer);
+        <Vec<String>>::sse_encode(self.failed_welcome_pubkeys_hex, serializer);
+    }
+}
+
+impl SseEncode for crate::api::call_quality::JitterBufferConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.target_ms, serializer);
+        <u32>::sse_encode(self.min_ms, serializer);
+        <u32>::sse_encode(self.max_ms, serializer);
+        <String>::sse_encode(self.reason, serializer);
+    }
+}
+
 impl SseEncode for crate::api::keypackage::KeyPackageData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9242,6 +14319,16 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::media::BatchUploadItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::BatchUploadItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::call_session::CallSession> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9262,6 +14349,56 @@ impl SseEncode for Vec<crate::api::contacts::ContactInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::group::EpochInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::group::EpochInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::diagnostics::ErrorCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::diagnostics::ErrorCount>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::media::FileInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::media::FileInput>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::diagnostics::GroupDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::diagnostics::GroupDiagnostics>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::group::GroupEpochEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::group::GroupEpochEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::group::GroupInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9312,6 +14449,26 @@ impl SseEncode for Vec<crate::api::group::MemberInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::message::MessageReaction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::message::MessageReaction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::message::MessageReader> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::message::MessageReader>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::call_webrtc::PeerEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9342,6 +14499,16 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::diagnostics::RelayDiagnostics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::diagnostics::RelayDiagnostics>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::relay::RelayInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9352,6 +14519,16 @@ impl SseEncode for Vec<crate::api::relay::RelayInfo> {
     }
 }
 
+impl SseEncode for Vec<crate::api::message::SendMessageResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::message::SendMessageResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::call_quality::SimulcastLayer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9392,6 +14569,7 @@ impl SseEncode for crate::api::media::MediaReferenceInfo {
         <Option<String>>::sse_encode(self.dimensions, serializer);
         <String>::sse_encode(self.scheme_version, serializer);
         <String>::sse_encode(self.nonce_hex, serializer);
+        <Option<crate::api::media::ThumbnailReference>>::sse_encode(self.thumb, serializer);
     }
 }
 
@@ -9404,6 +14582,38 @@ impl SseEncode for crate::api::group::MemberInfo {
     }
 }
 
+impl SseEncode for crate::api::message::MessageReaction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id_hex, serializer);
+        <String>::sse_encode(self.reactor_pubkey_hex, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.custom_emoji_url, serializer);
+        <u64>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::message::MessageReader {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.reader_pubkey_hex, serializer);
+        <u64>::sse_encode(self.read_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::link_preview::OgMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.image_url, serializer);
+        <Option<String>>::sse_encode(self.site_name, serializer);
+        <String>::sse_encode(self.domain, serializer);
+        <Option<String>>::sse_encode(self.og_type, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9454,6 +14664,16 @@ impl SseEncode for Option<f64> {
     }
 }
 
+impl SseEncode for Option<crate::api::group::GroupInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::group::GroupInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::message::GroupMessage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9484,6 +14704,16 @@ impl SseEncode for Option<crate::api::call_webrtc::PeerStats> {
     }
 }
 
+impl SseEncode for Option<crate::api::media::ThumbnailReference> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::ThumbnailReference>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9504,6 +14734,26 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<crate::api::media::UploadMediaResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::media::UploadMediaResult>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::message::OutboxFlushResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.published, serializer);
+        <u32>::sse_encode(self.reencrypted, serializer);
+        <u32>::sse_encode(self.dropped, serializer);
+        <u32>::sse_encode(self.remaining, serializer);
+    }
+}
+
 impl SseEncode for crate::api::call_webrtc::PeerConnectionState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9599,11 +14849,41 @@ impl SseEncode for crate::api::call_quality::QualityScore {
     }
 }
 
+impl SseEncode for crate::api::message::ReceiptSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.event_id_hex, serializer);
+        <Vec<crate::api::message::MessageReader>>::sse_encode(self.readers, serializer);
+        <u32>::sse_encode(self.recipient_count, serializer);
+        <bool>::sse_encode(self.read_by_all, serializer);
+    }
+}
+
+impl SseEncode for crate::api::group::ReconcileReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.mdk_group_count, serializer);
+        <Vec<String>>::sse_encode(self.restored_group_ids, serializer);
+        <Vec<String>>::sse_encode(self.orphaned_group_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::diagnostics::RelayDiagnostics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <bool>::sse_encode(self.connected, serializer);
+    }
+}
+
 impl SseEncode for crate::api::relay::RelayInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.url, serializer);
         <bool>::sse_encode(self.connected, serializer);
+        <bool>::sse_encode(self.read, serializer);
+        <bool>::sse_encode(self.write, serializer);
     }
 }
 
@@ -9616,6 +14896,8 @@ impl SseEncode for crate::api::call_webrtc::SdpInfo {
         <u32>::sse_encode(self.media_count, serializer);
         <Option<String>>::sse_encode(self.ice_ufrag, serializer);
         <Vec<String>>::sse_encode(self.codecs, serializer);
+        <Option<u32>>::sse_encode(self.bandwidth_kbps, serializer);
+        <Option<String>>::sse_encode(self.opus_params, serializer);
         <bool>::sse_encode(self.is_valid, serializer);
         <Option<String>>::sse_encode(self.error, serializer);
     }
@@ -9638,6 +14920,22 @@ impl SseEncode for crate::api::call_webrtc::SfuConfig {
     }
 }
 
+impl SseEncode for crate::api::signer::SignerKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::signer::SignerKind::Local => 0,
+                crate::api::signer::SignerKind::Nip46 => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::call_quality::SimulcastConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9658,6 +14956,15 @@ impl SseEncode for crate::api::call_quality::SimulcastLayer {
     }
 }
 
+impl SseEncode for crate::api::media::ThumbnailReference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.original_hash_hex, serializer);
+        <String>::sse_encode(self.nonce_hex, serializer);
+    }
+}
+
 impl SseEncode for crate::api::transcription::TranscriptSegment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9717,6 +15024,7 @@ impl SseEncode for crate::api::group::UpdateGroupResult {
         <String>::sse_encode(self.evolution_event_json, serializer);
         <Vec<String>>::sse_encode(self.welcome_rumors_json, serializer);
         <String>::sse_encode(self.mls_group_id_hex, serializer);
+        <Vec<String>>::sse_encode(self.publish_relays, serializer);
     }
 }
 
@@ -9785,6 +15093,7 @@ impl SseEncode for crate::api::call_webrtc::WebRtcConfig {
         <Vec<crate::api::call_webrtc::IceServer>>::sse_encode(self.ice_servers, serializer);
         <String>::sse_encode(self.sdp_semantics, serializer);
         <String>::sse_encode(self.bundle_policy, serializer);
+        <String>::sse_encode(self.ice_transport_policy, serializer);
     }
 }
 
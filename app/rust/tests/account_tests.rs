@@ -1,6 +1,7 @@
 use nostr_sdk::prelude::ToBech32;
 use rust_lib_burrow_app::api::error::BurrowError;
 use rust_lib_burrow_app::api::{account, state};
+use rust_lib_burrow_app::api::signer::SignerKind;
 
 /// Set up a temporary data directory for tests that need init_state.
 fn setup_test_data_dir() {
@@ -97,3 +98,34 @@ async fn login_with_nsec_key() {
 
 // Note: save/load via keyring requires platform-specific keyring backends
 // and cannot be reliably tested in a headless CI environment.
+
+#[tokio::test]
+async fn login_with_invalid_bunker_uri_fails() {
+    state::destroy_state().await;
+
+    let result: Result<account::AccountInfo, BurrowError> =
+        account::login_with_bunker("https://example.com".to_string(), None).await;
+    assert!(result.is_err());
+    assert!(!account::is_logged_in().await);
+}
+
+#[tokio::test]
+async fn local_account_signs_as_user() {
+    state::destroy_state().await;
+    setup_test_data_dir();
+
+    let created: account::AccountInfo = account::create_account().await.unwrap();
+    assert_eq!(account::get_signer_kind().await.unwrap(), SignerKind::Local);
+
+    let signer = state::with_state_read(|s| Ok(s.signer.clone())).await.unwrap();
+    let event = signer
+        .sign(nostr_sdk::prelude::EventBuilder::text_note("hello"))
+        .await
+        .unwrap();
+    assert_eq!(event.pubkey.to_hex(), created.pubkey_hex);
+    assert!(event.verify().is_ok());
+    assert!(signer.secret_keys().is_ok());
+    assert!(signer.nip46_session().is_none());
+
+    state::destroy_state().await;
+}
@@ -0,0 +1,32 @@
+use rust_lib_burrow_app::api::backup::{open_bundle, seal_bundle};
+
+#[test]
+fn bundle_roundtrip() {
+    let sealed = seal_bundle(b"{\"version\":1}", "correct horse battery").unwrap();
+    assert!(sealed.starts_with(b"BURROWSTATE1"));
+    let opened = open_bundle(&sealed, "correct horse battery").unwrap();
+    assert_eq!(opened, b"{\"version\":1}");
+}
+
+#[test]
+fn bundle_rejects_wrong_passphrase() {
+    let sealed = seal_bundle(b"secret state", "correct horse battery").unwrap();
+    assert!(open_bundle(&sealed, "wrong horse battery").is_err());
+}
+
+#[test]
+fn bundle_rejects_tampering_and_garbage() {
+    let mut sealed = seal_bundle(b"secret state", "correct horse battery").unwrap();
+    let last = sealed.len() - 1;
+    sealed[last] ^= 1;
+    assert!(open_bundle(&sealed, "correct horse battery").is_err());
+    assert!(open_bundle(b"not a bundle", "correct horse battery").is_err());
+    assert!(open_bundle(b"", "correct horse battery").is_err());
+}
+
+#[test]
+fn bundle_uses_fresh_salt_and_nonce() {
+    let a = seal_bundle(b"same", "correct horse battery").unwrap();
+    let b = seal_bundle(b"same", "correct horse battery").unwrap();
+    assert_ne!(a, b);
+}
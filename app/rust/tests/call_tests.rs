@@ -4,6 +4,8 @@ use rust_lib_burrow_app::api::call_session::*;
 use rust_lib_burrow_app::api::call_webrtc::*;
 use rust_lib_burrow_app::api::call_quality::*;
 
+mod common;
+
 // ── Call Session Tests ─────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -145,26 +147,47 @@ async fn test_derive_media_key_deterministic() {
 
 #[test]
 fn test_generate_webrtc_config() {
-    let config = generate_webrtc_config("test-call-id".into()).unwrap();
+    let config = generate_webrtc_config("test-call-id".into(), false).unwrap();
 
     assert_eq!(config.sdp_semantics, "unified-plan");
     assert_eq!(config.bundle_policy, "max-bundle");
-    assert!(config.ice_servers.len() >= 2); // STUN + TURN
+    if std::env::var(TURN_SECRET_ENV).is_err() {
+        // No TURN secret configured: STUN only.
+        assert_eq!(config.ice_servers.len(), 1);
+    }
     assert!(config.ice_servers[0].urls[0].starts_with("stun:"));
-    assert!(config.ice_servers[1].urls[0].starts_with("turn:"));
-    assert!(config.ice_servers[1].username.is_some());
-    assert!(config.ice_servers[1].credential.is_some());
+    assert!(config.ice_servers[0].username.is_none());
 }
 
 #[test]
-fn test_webrtc_config_unique_turn_credentials() {
-    let c1 = generate_webrtc_config("call-a".into()).unwrap();
-    let c2 = generate_webrtc_config("call-b".into()).unwrap();
+fn test_turn_credentials() {
+    let (username, credential) = turn_credentials("turn-secret", "call-abc", 3600, 1_700_000_000);
+    assert_eq!(username, "1700003600:call-abc");
+    assert_eq!(credential, "ET6AbJbNkxc+z2leZSNrEp2IfbY=");
 
-    assert_ne!(
-        c1.ice_servers[1].credential,
-        c2.ice_servers[1].credential
-    );
+    let (long_username, _) = turn_credentials("turn-secret", &"f".repeat(64), DEFAULT_TURN_TTL_SECS, 0);
+    assert_eq!(long_username, format!("3600:{}", "f".repeat(16)));
+
+    let (_, other) = turn_credentials("turn-secret", "call-xyz", 3600, 1_700_000_000);
+    assert_ne!(credential, other);
+}
+
+#[test]
+fn test_webrtc_config_force_relay() {
+    assert_eq!(ice_transport_policy(true, true), "relay");
+    assert_eq!(ice_transport_policy(false, true), "all");
+    // Relay-only without a TURN server would leave ICE nothing to try.
+    assert_eq!(ice_transport_policy(true, false), "all");
+
+    let config = generate_webrtc_config("call-relay".into(), true).unwrap();
+    let has_turn = config.ice_servers.iter().any(|s| s.credential.is_some());
+    assert_eq!(config.ice_transport_policy, if has_turn { "relay" } else { "all" });
+    let config = generate_webrtc_config("call-direct".into(), false).unwrap();
+    assert_eq!(config.ice_transport_policy, "all");
+
+    assert!(!recommend_force_relay(0));
+    assert!(recommend_force_relay(1));
+    assert!(recommend_force_relay(3));
 }
 
 // ── SDP Parsing Tests ──────────────────────────────────────────────────────
@@ -184,6 +207,33 @@ fn test_parse_sdp_offer_valid() {
     assert_eq!(info.ice_ufrag, Some("abc123".to_string()));
     assert!(info.codecs.contains(&"opus".to_string()));
     assert!(info.codecs.contains(&"VP8".to_string()));
+    assert_eq!(info.bandwidth_kbps, None);
+    assert_eq!(info.opus_params, None);
+}
+
+#[test]
+fn test_parse_sdp_bandwidth_and_opus_params() {
+    let sdp = "v=0\r\no=- 4611731400430051336 2 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n\
+               a=group:BUNDLE 0 1\r\n\
+               m=audio 9 UDP/TLS/RTP/SAVPF 111 63 9\r\nc=IN IP4 0.0.0.0\r\nb=AS:64\r\n\
+               a=ice-ufrag:F7gI\r\na=mid:0\r\n\
+               a=rtpmap:111 opus/48000/2\r\n\
+               a=fmtp:111 minptime=10;useinbandfec=1;stereo=1;maxaveragebitrate=128000\r\n\
+               a=rtpmap:63 red/48000/2\r\na=fmtp:63 111/111\r\n\
+               a=rtpmap:9 G722/8000\r\n\
+               m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\nc=IN IP4 0.0.0.0\r\nb=AS:2500\r\n\
+               a=mid:1\r\n\
+               a=rtpmap:96 VP8/90000\r\n\
+               a=rtpmap:97 rtx/90000\r\na=fmtp:97 apt=96\r\n";
+
+    let info = parse_sdp_offer(sdp.into()).unwrap();
+    assert!(info.is_valid);
+    assert_eq!(info.bandwidth_kbps, Some(2500));
+    assert_eq!(
+        info.opus_params.as_deref(),
+        Some("minptime=10;useinbandfec=1;stereo=1;maxaveragebitrate=128000")
+    );
+    assert!(info.codecs.contains(&"VP8".to_string()));
 }
 
 #[test]
@@ -227,6 +277,22 @@ async fn test_peer_lifecycle() {
     remove_call_peers(call_id).await.unwrap();
 }
 
+#[tokio::test]
+async fn test_reap_keeps_active_peers() {
+    let call_id = "peer-reap-001".to_string();
+    let pubkey = "reap-peer-001".to_string();
+    create_peer_entry(call_id.clone(), pubkey.clone(), true, false)
+        .await
+        .unwrap();
+
+    reap_stale_peers(3600).await;
+    let participants = get_call_participants(call_id.clone()).await.unwrap();
+    assert_eq!(participants.len(), 1);
+    assert_eq!(participants[0].participant_pubkey_hex, pubkey);
+
+    remove_call_peers(call_id).await.unwrap();
+}
+
 #[tokio::test]
 async fn test_peer_stats() {
     let pubkey = "stats-peer-001".to_string();
@@ -249,32 +315,35 @@ async fn test_peer_stats() {
 
 // ── Frame Encryption Tests ─────────────────────────────────────────────────
 
+const EPOCH_SECRET: &str = "0011223344556677889900aabbccddeeff0011223344556677889900aabbccddeeff";
+
 #[test]
 fn test_derive_frame_encryption_key() {
-    let key = derive_frame_encryption_key(
-        "0011223344556677889900aabbccddeeff0011223344556677889900aabbccddeeff".into(),
-        "frame-key-test".into(),
-    )
-    .unwrap();
-
+    let key = derive_frame_encryption_key(EPOCH_SECRET.into(), 1, "frame-key-test".into()).unwrap();
     assert_eq!(key.len(), 32); // 16 bytes = 32 hex chars
+
+    // Two members deriving independently from the same epoch agree.
+    let other_member = derive_frame_encryption_key(EPOCH_SECRET.to_string(), 1, "frame-key-test".to_string()).unwrap();
+    assert_eq!(key, other_member);
+
+    // The epoch and call are part of the key.
+    assert_ne!(key, derive_frame_encryption_key(EPOCH_SECRET.into(), 2, "frame-key-test".into()).unwrap());
+    assert_ne!(key, derive_frame_encryption_key(EPOCH_SECRET.into(), 1, "other-call".into()).unwrap());
 }
 
 #[test]
 fn test_rotate_frame_key() {
-    let initial = derive_frame_encryption_key(
-        "abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789".into(),
-        "rotate-test".into(),
-    )
-    .unwrap();
+    let next_secret = "abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789";
+    let initial = derive_frame_encryption_key(EPOCH_SECRET.into(), 1, "rotate-test".into()).unwrap();
 
-    let rotated = rotate_frame_key(initial.clone(), 2, "rotate-test".into()).unwrap();
+    let rotated = rotate_frame_key(next_secret.into(), 2, "rotate-test".into()).unwrap();
     assert_ne!(initial, rotated);
     assert_eq!(rotated.len(), 32);
 
-    // Same epoch produces same result
-    let rotated2 = rotate_frame_key(initial, 2, "rotate-test".into()).unwrap();
-    assert_eq!(rotated, rotated2);
+    // A member who missed epoch 1 (or joined at epoch 2) gets the same key
+    // from the epoch 2 group state alone.
+    let late_joiner = derive_frame_encryption_key(next_secret.into(), 2, "rotate-test".into()).unwrap();
+    assert_eq!(rotated, late_joiner);
 }
 
 // ── Topology Tests ─────────────────────────────────────────────────────────
@@ -318,6 +387,34 @@ fn test_quality_score_audio_vs_video() {
     assert!(audio.bitrate_score > video.bitrate_score);
 }
 
+#[test]
+fn test_jitter_buffer_low_jitter() {
+    let cfg = recommend_jitter_buffer(5.0, 40.0);
+    assert_eq!(cfg.target_ms, 30);
+    assert_eq!(cfg.min_ms, 20);
+    assert!(cfg.max_ms >= cfg.target_ms);
+}
+
+#[test]
+fn test_jitter_buffer_grows_with_jitter_and_rtt() {
+    let low = recommend_jitter_buffer(5.0, 40.0);
+    let high = recommend_jitter_buffer(60.0, 40.0);
+    let high_rtt = recommend_jitter_buffer(60.0, 500.0);
+    assert!(high.target_ms > low.target_ms);
+    assert!(high_rtt.target_ms > high.target_ms);
+    assert!(high.min_ms <= high.target_ms && high.target_ms <= high.max_ms);
+}
+
+#[test]
+fn test_jitter_buffer_bounds() {
+    let extreme = recommend_jitter_buffer(10_000.0, 10_000.0);
+    assert_eq!(extreme.target_ms, 400);
+    assert!(extreme.max_ms <= 1000);
+    let garbage = recommend_jitter_buffer(f64::NAN, -5.0);
+    assert_eq!(garbage.target_ms, 20);
+    assert_eq!(garbage.min_ms, 20);
+}
+
 // ── Audio/Video Constraints Tests ──────────────────────────────────────────
 
 #[test]
@@ -383,3 +480,112 @@ fn test_codec_preferences() {
     assert_eq!(prefs.audio_codecs[0], "opus");
     assert_eq!(prefs.video_codecs[0], "H264");
 }
+
+// ── Signaling Payload Tests ────────────────────────────────────────────────
+
+#[test]
+fn test_renegotiation_flag() {
+    use rust_lib_burrow_app::api::call_signaling::is_renegotiation;
+
+    assert!(!is_renegotiation(25050, r#"{"sdp":"v=0","call_type":"audio"}"#));
+    assert!(is_renegotiation(25050, r#"{"sdp":"v=0","call_type":"video","renegotiate":true}"#));
+    assert!(is_renegotiation(25051, r#"{"sdp":"v=0","renegotiate":true}"#));
+    assert!(!is_renegotiation(25052, r#"{"renegotiate":true}"#));
+    assert!(!is_renegotiation(25050, "not json"));
+}
+
+#[tokio::test]
+async fn test_duplicate_ice_candidates_dropped() {
+    use nostr_sdk::prelude::*;
+    use rust_lib_burrow_app::api::call_signaling::{process_call_event, reset_call_signaling_state};
+
+    let keys = Keys::generate();
+    let call_id = "0d6f4a52-3c1e-4b8a-9f2d-7e5c1a9b3d40";
+    let candidate_event = |candidate: &str, created_at: u64| {
+        EventBuilder::new(
+            Kind::Custom(25052),
+            format!(r#"{{"candidate":"{candidate}","sdp_mid":"0","sdp_m_line_index":0}}"#),
+        )
+        .tag(Tag::parse(["call-id", call_id]).unwrap())
+        .custom_created_at(Timestamp::from(created_at))
+        .sign_with_keys(&keys)
+        .unwrap()
+        .as_json()
+    };
+    let host = "candidate:1 1 udp 2122260223 192.168.1.2 54321 typ host";
+    let srflx = "candidate:2 1 udp 1686052607 203.0.113.7 54321 typ srflx";
+
+    let first = process_call_event(candidate_event(host, 1_700_000_000)).await.unwrap().unwrap();
+    // A relay redelivers the same candidate (possibly re-wrapped, so a new event).
+    assert!(process_call_event(candidate_event(host, 1_700_000_000)).await.unwrap().is_none());
+    assert!(process_call_event(candidate_event(host, 1_700_000_001)).await.unwrap().is_none());
+
+    let second = process_call_event(candidate_event(srflx, 1_700_000_000)).await.unwrap().unwrap();
+    assert!(second.sequence > first.sequence);
+    let earlier = process_call_event(candidate_event("candidate:3 1 udp 1 10.0.0.1 1 typ host", 1_699_999_999))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(earlier.sequence < first.sequence);
+
+    // After the call ends, the same ID starts fresh.
+    reset_call_signaling_state(call_id.to_string());
+    assert!(process_call_event(candidate_event(host, 1_700_000_000)).await.unwrap().is_some());
+    reset_call_signaling_state(call_id.to_string());
+}
+
+#[tokio::test]
+async fn test_malformed_call_tags_rejected() {
+    use nostr_sdk::prelude::*;
+    use rust_lib_burrow_app::api::call_signaling::process_call_event;
+
+    let keys = Keys::generate();
+    let offer = |call_id: &str, call_type: &str| {
+        EventBuilder::new(Kind::Custom(25050), r#"{"sdp":"v=0","call_type":"audio"}"#)
+            .tag(Tag::parse(["call-id", call_id]).unwrap())
+            .tag(Tag::parse(["call-type", call_type]).unwrap())
+            .sign_with_keys(&keys)
+            .unwrap()
+            .as_json()
+    };
+    let call_id = "3f2b8c1d-9a4e-4d6f-b1c2-5e7a8d9f0a1b";
+
+    let ok = process_call_event(offer(call_id, "video")).await.unwrap().unwrap();
+    assert_eq!(ok.call_type.as_deref(), Some("video"));
+
+    assert!(process_call_event(offer(call_id, "<script>")).await.unwrap().is_none());
+    assert!(process_call_event(offer(call_id, "Audio")).await.unwrap().is_none());
+    assert!(process_call_event(offer("not-a-uuid", "audio")).await.unwrap().is_none());
+    assert!(process_call_event(offer("3f2b8c1d9a4e4d6fb1c25e7a8d9f0a1b", "audio")).await.unwrap().is_none());
+    assert!(process_call_event(offer("3f2b8c1d-9a4e-4d6f-b1c2-5e7a8d9f0a1z", "audio")).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_app_call_id_round_trips() {
+    use nostr_sdk::prelude::*;
+    use rust_lib_burrow_app::api::call_signaling::{initiate_call, process_call_event, reset_call_signaling_state};
+    use rust_lib_burrow_app::api::state;
+
+    let _lock = common::STATE_LOCK.lock().await;
+    let (caller, callee) = (Keys::generate(), Keys::generate());
+    common::init_test_state(caller.clone()).await;
+
+    // The shape lib/utils/call_id.dart's newCallId() produces.
+    let call_id = "9b2e4f1a-7c3d-4e8f-a5b6-0c1d2e3f4a5b";
+    let wrap = initiate_call("v=0".into(), call_id.into(), "audio".into(), callee.public_key().to_hex())
+        .await
+        .unwrap();
+
+    let rumor = nip59::extract_rumor(&callee, &Event::from_json(&wrap).unwrap()).await.unwrap().rumor;
+    let event = rumor.sign_with_keys(&caller).unwrap();
+    let received = process_call_event(event.as_json()).await.unwrap().expect("offer accepted");
+    assert_eq!(received.call_id, call_id);
+    assert_eq!(received.call_type.as_deref(), Some("audio"));
+    reset_call_signaling_state(call_id.to_string());
+
+    // The old base-36 timestamp IDs would be dropped by the peer, so they
+    // are refused up front.
+    let old_style = initiate_call("v=0".into(), "lz8k2m3n".into(), "audio".into(), callee.public_key().to_hex()).await;
+    assert!(old_style.is_err());
+    state::destroy_state().await;
+}
@@ -0,0 +1,35 @@
+//! Helpers shared by the integration tests that drive the global app state.
+#![allow(dead_code)]
+
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::{keypackage, state};
+
+/// Tests that use the global state must hold this so they don't overlap.
+pub static STATE_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+pub const RELAY: &str = "wss://relay.example.com";
+
+/// Replace the global state with a fresh one for `keys`.
+pub async fn init_test_state(keys: Keys) {
+    state::destroy_state().await;
+    let tmp = std::env::temp_dir().join("burrow_tests");
+    std::fs::create_dir_all(&tmp).unwrap();
+    state::set_data_dir(tmp.to_string_lossy().to_string());
+    state::init_state(keys).await.unwrap();
+}
+
+/// A kind 443 KeyPackage event for `keys`, generated in their own state.
+pub async fn key_package_for(keys: &Keys) -> String {
+    init_test_state(keys.clone()).await;
+    let kp = keypackage::generate_key_package(vec![RELAY.to_string()]).await.unwrap();
+    let tags: Vec<Tag> = kp
+        .tags
+        .iter()
+        .map(|t| Tag::custom(TagKind::from(t[0].as_str()), t[1..].to_vec()))
+        .collect();
+    EventBuilder::new(Kind::MlsKeyPackage, &kp.key_package_base64)
+        .tags(tags)
+        .sign_with_keys(keys)
+        .unwrap()
+        .as_json()
+}
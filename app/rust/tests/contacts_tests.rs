@@ -0,0 +1,167 @@
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::app_state;
+use rust_lib_burrow_app::api::contacts::{
+    contacts_list_builder, key_package_check_due, new_follows_from_list, key_package_recheck_interval, nip05_check_due, nip05_names_pubkey, parse_nip05,
+    search_contacts, DEFAULT_CONTACTS_STALENESS_SECS, MAX_KEY_PACKAGE_BACKOFF_SECS, MAX_NIP05_BACKOFF_SECS,
+    NIP05_RETRY_SECS,
+};
+
+const DAY: u64 = 86400;
+
+#[test]
+fn key_package_recheck_backs_off_on_misses() {
+    let staleness = DEFAULT_CONTACTS_STALENESS_SECS;
+    let intervals: Vec<u64> = (0..7)
+        .map(|misses| key_package_recheck_interval(misses, staleness))
+        .collect();
+    assert_eq!(
+        intervals,
+        vec![DAY, DAY, 2 * DAY, 4 * DAY, 8 * DAY, 14 * DAY, 14 * DAY]
+    );
+    assert_eq!(key_package_recheck_interval(u32::MAX, staleness), MAX_KEY_PACKAGE_BACKOFF_SECS);
+}
+
+#[test]
+fn key_package_check_due_follows_backoff() {
+    let now = 1_700_000_000i64;
+    let staleness = DEFAULT_CONTACTS_STALENESS_SECS;
+    let three_days_ago = Some(now - 3 * DAY as i64);
+
+    assert!(key_package_check_due(None, 0, now, staleness));
+    // A contact with a key package is back on the daily cadence.
+    assert!(key_package_check_due(three_days_ago, 0, now, staleness));
+    assert!(key_package_check_due(three_days_ago, 2, now, staleness));
+    assert!(!key_package_check_due(three_days_ago, 3, now, staleness));
+    assert!(!key_package_check_due(Some(now - 1), 0, now, staleness));
+}
+
+#[test]
+fn staleness_above_cap_is_not_shortened() {
+    assert_eq!(key_package_recheck_interval(0, 30 * DAY), 30 * DAY);
+    assert_eq!(key_package_recheck_interval(4, 30 * DAY), 30 * DAY);
+}
+
+#[test]
+fn nip05_identifiers_parse() {
+    assert_eq!(
+        parse_nip05("Alice@Example.com"),
+        Some(("alice".to_string(), "example.com".to_string()))
+    );
+    assert_eq!(parse_nip05("example.com"), Some(("_".to_string(), "example.com".to_string())));
+    assert_eq!(parse_nip05("alice@localhost"), None);
+    assert_eq!(parse_nip05("al ice@example.com"), None);
+    assert_eq!(parse_nip05("alice@example.com/evil?x="), None);
+}
+
+#[test]
+fn nip05_document_must_name_pubkey() {
+    let pubkey = "b0635d6a9851d3aed0cd6c495b282167acf761729078d975fc341b22650b07b9";
+    let doc = format!(r#"{{"names": {{"alice": "{pubkey}"}}}}"#);
+
+    assert!(nip05_names_pubkey(&doc, "alice", pubkey));
+    assert!(!nip05_names_pubkey(&doc, "bob", pubkey));
+    assert!(!nip05_names_pubkey(&doc, "alice", &"0".repeat(64)));
+    assert!(!nip05_names_pubkey("not json", "alice", pubkey));
+}
+
+#[test]
+fn nip05_lookups_back_off_after_failures() {
+    let now = 1_750_000_000;
+    let hour = NIP05_RETRY_SECS as i64;
+    assert!(nip05_check_due(None, 0, now));
+    // Never failed (or a changed NIP-05): look it up every sync.
+    assert!(nip05_check_due(Some(now), 0, now));
+
+    assert!(!nip05_check_due(Some(now - hour + 1), 1, now));
+    assert!(nip05_check_due(Some(now - hour), 1, now));
+    assert!(!nip05_check_due(Some(now - 3 * hour), 3, now));
+    assert!(nip05_check_due(Some(now - 4 * hour), 3, now));
+
+    let cap = MAX_NIP05_BACKOFF_SECS as i64;
+    assert!(!nip05_check_due(Some(now - cap + 1), u32::MAX, now));
+    assert!(nip05_check_due(Some(now - cap), u32::MAX, now));
+}
+
+#[tokio::test]
+async fn search_contacts_matches_name_prefix_and_pubkey() {
+    let dir = std::env::temp_dir().join(format!("burrow_contacts_search_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    app_state::init_app_state_db(&dir).unwrap();
+
+    let alice = format!("aa{}", "1".repeat(62));
+    let alicia = format!("ab{}", "2".repeat(62));
+    let bob = format!("bb{}beef{}", "3".repeat(30), "3".repeat(28));
+    let carol_no_kp = format!("cc{}", "4".repeat(62));
+    let conn = rusqlite::Connection::open(dir.join("app_state.db")).unwrap();
+    for (pubkey, name, nip05, has_kp) in [
+        (&alice, "Alice", Some("alice@example.com"), 1),
+        (&alicia, "alicia", None, 1),
+        (&bob, "Bob", None, 1),
+        (&carol_no_kp, "Alice Two", None, 0),
+    ] {
+        conn.execute(
+            "INSERT INTO follows (pubkey_hex, display_name, nip05, has_key_package)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![pubkey, name, nip05, has_kp],
+        )
+        .unwrap();
+    }
+
+    let names = |contacts: Vec<rust_lib_burrow_app::api::contacts::ContactInfo>| -> Vec<String> {
+        contacts.into_iter().filter_map(|c| c.display_name).collect()
+    };
+
+    assert_eq!(names(search_contacts("ALI".into(), None).await.unwrap()), vec!["Alice", "alicia"]);
+    assert_eq!(names(search_contacts("beef".into(), None).await.unwrap()), vec!["Bob"]);
+    assert_eq!(names(search_contacts("example.com".into(), None).await.unwrap()), vec!["Alice"]);
+    assert_eq!(names(search_contacts("".into(), Some(2)).await.unwrap()), vec!["Alice", "alicia"]);
+    assert!(search_contacts("%".into(), None).await.unwrap().is_empty());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn contacts_list_round_trips() {
+    let me = Keys::generate();
+    let keys: Vec<String> = (0..3).map(|_| Keys::generate().public_key().to_hex()).collect();
+    let exported = vec![
+        keys[0].clone(),
+        keys[1].clone(),
+        "not a pubkey".to_string(),
+        keys[2].clone(),
+        me.public_key().to_hex(),
+    ];
+    let json = contacts_list_builder(&exported).unwrap().sign_with_keys(&me).unwrap().as_json();
+
+    let event = Event::from_json(&json).unwrap();
+    let none = std::collections::HashSet::new();
+    assert_eq!(new_follows_from_list(&event, &me.public_key().to_hex(), &none).unwrap(), keys);
+
+    // Already followed, or listed twice: only the new ones, once.
+    let known = std::collections::HashSet::from([keys[1].clone()]);
+    let doubled = contacts_list_builder(&[keys[0].clone(), keys[0].clone(), keys[1].clone()])
+        .unwrap()
+        .sign_with_keys(&me)
+        .unwrap();
+    assert_eq!(new_follows_from_list(&doubled, &me.public_key().to_hex(), &known).unwrap(), vec![keys[0].clone()]);
+}
+
+#[test]
+fn contacts_list_import_rejects_forgeries() {
+    let me = Keys::generate();
+    let other = Keys::generate().public_key().to_hex();
+    let none = std::collections::HashSet::new();
+    let event = contacts_list_builder(&[other.clone()]).unwrap().sign_with_keys(&me).unwrap();
+
+    // Swap in a different pubkey without re-signing.
+    let mut forged: serde_json::Value = serde_json::from_str(&event.as_json()).unwrap();
+    forged["tags"][2][1] = serde_json::Value::String(Keys::generate().public_key().to_hex());
+    let forged = Event::from_json(forged.to_string()).unwrap();
+    let err = new_follows_from_list(&forged, &me.public_key().to_hex(), &none).unwrap_err();
+    assert!(err.message.contains("signature"));
+
+    let wrong_kind = EventBuilder::new(Kind::ContactList, "").sign_with_keys(&me).unwrap();
+    let err = new_follows_from_list(&wrong_kind, &me.public_key().to_hex(), &none).unwrap_err();
+    assert!(err.message.contains("kind 30000"));
+}
@@ -0,0 +1,39 @@
+use rust_lib_burrow_app::api::diagnostics::collect_diagnostics;
+use rust_lib_burrow_app::api::state;
+
+fn setup_test_data_dir() {
+    let tmp = std::env::temp_dir().join("burrow_diagnostics_tests");
+    std::fs::create_dir_all(&tmp).unwrap();
+    state::set_data_dir(tmp.to_string_lossy().to_string());
+}
+
+#[tokio::test]
+async fn diagnostics_before_login() {
+    state::destroy_state().await;
+    let report = collect_diagnostics().await.unwrap();
+    assert!(!report.initialized);
+    assert!(report.pubkey_hex.is_none());
+    assert!(report.groups.is_empty());
+    assert!(report.to_json().contains("\"initialized\": false"));
+}
+
+#[tokio::test]
+async fn diagnostics_never_include_secret_key() {
+    state::destroy_state().await;
+    setup_test_data_dir();
+    let keys = nostr_sdk::prelude::Keys::generate();
+    let secret_hex = keys.secret_key().to_secret_hex();
+    let pubkey_hex = keys.public_key().to_hex();
+
+    state::init_state(keys).await.unwrap();
+    let report = collect_diagnostics().await.unwrap();
+    let json = report.to_json();
+
+    assert!(report.initialized);
+    assert_eq!(report.pubkey_hex.as_deref(), Some(pubkey_hex.as_str()));
+    assert!(json.contains(&pubkey_hex));
+    assert!(!json.contains(&secret_hex));
+    assert!(!json.contains("nsec1"));
+
+    state::destroy_state().await;
+}
@@ -0,0 +1,15 @@
+use rust_lib_burrow_app::api::ephemeral::{
+    get_ephemeral_key_strategy, set_ephemeral_key_strategy, EphemeralKeyStrategy,
+};
+
+// Single test: the strategy is process-global, so parallel tests would race.
+#[test]
+fn strategy_defaults_to_per_event_and_can_be_changed() {
+    assert_eq!(get_ephemeral_key_strategy(), EphemeralKeyStrategy::PerEvent);
+
+    set_ephemeral_key_strategy(EphemeralKeyStrategy::PerSession);
+    assert_eq!(get_ephemeral_key_strategy(), EphemeralKeyStrategy::PerSession);
+
+    set_ephemeral_key_strategy(EphemeralKeyStrategy::PerEvent);
+    assert_eq!(get_ephemeral_key_strategy(), EphemeralKeyStrategy::PerEvent);
+}
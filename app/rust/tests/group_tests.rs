@@ -0,0 +1,352 @@
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::group::{
+    self, demoted_admins, epoch_events_from_history, promoted_admins, union_relays,
+    validate_admins_are_members, EpochInfo,
+};
+use rust_lib_burrow_app::api::error::BurrowError;
+use rust_lib_burrow_app::api::{app_state, invite, message, state};
+
+mod common;
+use common::{init_test_state, key_package_for, RELAY, STATE_LOCK};
+
+fn key_package_event(keys: &Keys) -> Event {
+    EventBuilder::new(Kind::MlsKeyPackage, "")
+        .sign_with_keys(keys)
+        .unwrap()
+}
+
+#[test]
+fn admins_may_be_creator_or_invitees() {
+    let creator = Keys::generate();
+    let invitee = Keys::generate();
+    let kps = vec![key_package_event(&invitee)];
+
+    let admins = vec![creator.public_key(), invitee.public_key()];
+    assert!(validate_admins_are_members(&admins, &creator.public_key(), &kps).is_ok());
+    assert!(validate_admins_are_members(&[], &creator.public_key(), &kps).is_ok());
+}
+
+#[test]
+fn admin_outside_group_is_rejected() {
+    let creator = Keys::generate();
+    let invitee = Keys::generate();
+    let stranger = Keys::generate();
+    let kps = vec![key_package_event(&invitee)];
+
+    let admins = vec![creator.public_key(), stranger.public_key()];
+    let err = validate_admins_are_members(&admins, &creator.public_key(), &kps).unwrap_err();
+    assert!(err.message.contains(&stranger.public_key().to_hex()));
+}
+
+#[test]
+fn relay_update_publishes_to_old_and_new_relays() {
+    let current = vec!["wss://old.example".to_string(), "wss://both.example/".to_string()];
+    let proposed = vec!["wss://both.example".to_string(), "wss://new.example".to_string()];
+    assert_eq!(
+        union_relays(&current, &proposed),
+        vec!["wss://old.example", "wss://both.example/", "wss://new.example"]
+    );
+    assert_eq!(union_relays(&current, &[]), current);
+}
+
+#[test]
+fn last_admin_cannot_be_demoted() {
+    let alice = Keys::generate().public_key();
+    let bob = Keys::generate().public_key();
+
+    let err = demoted_admins(&[alice], &alice).unwrap_err();
+    assert!(err.to_string().contains("last admin"));
+
+    assert_eq!(demoted_admins(&[alice, bob], &alice).unwrap(), vec![bob]);
+    assert!(demoted_admins(&[alice], &bob).is_err());
+}
+
+#[test]
+fn only_members_can_be_promoted() {
+    let alice = Keys::generate().public_key();
+    let bob = Keys::generate().public_key();
+    let outsider = Keys::generate().public_key();
+
+    assert!(promoted_admins(&[alice], &[alice, bob], &outsider).is_err());
+    assert!(promoted_admins(&[alice], &[alice, bob], &alice).is_err());
+    assert_eq!(promoted_admins(&[alice], &[alice, bob], &bob).unwrap(), vec![alice, bob]);
+}
+
+fn epoch(epoch: u64, added: Vec<String>, removed: Vec<String>) -> EpochInfo {
+    EpochInfo {
+        epoch,
+        recorded_at: Some(1_700_000_000 + epoch),
+        committer_pubkey_hex: None,
+        commit_event_id_hex: None,
+        added_pubkeys: added,
+        removed_pubkeys: removed,
+        metadata_changed: false,
+    }
+}
+
+#[test]
+fn add_then_remove_yields_two_epoch_events() {
+    let bob = Keys::generate().public_key().to_hex();
+    let history = vec![
+        epoch(1, vec![bob.clone()], vec![]),
+        epoch(2, vec![], vec![bob.clone()]),
+        EpochInfo {
+            recorded_at: None,
+            ..epoch(2, vec![], vec![])
+        },
+    ];
+
+    let events = epoch_events_from_history(&history);
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].epoch, events[0].change_type.as_str()), (1, "add"));
+    assert_eq!(events[0].pubkeys, vec![bob.clone()]);
+    assert_eq!(events[0].timestamp, 1_700_000_001);
+    assert_eq!((events[1].epoch, events[1].change_type.as_str()), (2, "remove"));
+    assert_eq!(events[1].pubkeys, vec![bob]);
+}
+
+#[tokio::test]
+async fn create_or_get_dm_creates_then_reuses() {
+    let _lock = STATE_LOCK.lock().await;
+    let peer = Keys::generate();
+    let peer_hex = peer.public_key().to_hex();
+    let kp_json = key_package_for(&peer).await;
+    init_test_state(Keys::generate()).await;
+
+    let created = group::create_or_get_dm_using(peer_hex.clone(), vec![RELAY.to_string()], |_| async {
+        Ok(kp_json)
+    })
+    .await
+    .unwrap();
+    assert!(!created.reused);
+    assert_eq!(created.welcome_rumors_json.len(), 1);
+    group::merge_pending_commit(created.mls_group_id_hex.clone()).await.unwrap();
+
+    let reused = group::create_or_get_dm_using(peer_hex, vec![RELAY.to_string()], |_| async {
+        Err(BurrowError::from("KeyPackage should not be fetched".to_string()))
+    })
+    .await
+    .unwrap();
+    assert!(reused.reused);
+    assert!(reused.welcome_rumors_json.is_empty());
+    assert_eq!(reused.mls_group_id_hex, created.mls_group_id_hex);
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn create_dm_reuses_an_existing_dm() {
+    let _lock = STATE_LOCK.lock().await;
+    let peer = Keys::generate();
+    let peer_hex = peer.public_key().to_hex();
+    let first_kp = key_package_for(&peer).await;
+    let second_kp = key_package_for(&peer).await;
+    init_test_state(Keys::generate()).await;
+
+    assert!(group::find_dm_with(peer_hex.clone()).await.unwrap().is_none());
+
+    let relays = vec![RELAY.to_string()];
+    let created = group::create_dm(peer_hex.clone(), first_kp, relays.clone()).await.unwrap();
+    assert_eq!(created.welcome_rumors_json.len(), 1);
+    group::merge_pending_commit(created.mls_group_id_hex.clone()).await.unwrap();
+
+    let found = group::find_dm_with(peer_hex.clone()).await.unwrap().unwrap();
+    assert_eq!(found.mls_group_id_hex, created.mls_group_id_hex);
+
+    let again = group::create_dm(peer_hex, second_kp, relays).await.unwrap();
+    assert!(again.welcome_rumors_json.is_empty());
+    assert_eq!(again.mls_group_id_hex, created.mls_group_id_hex);
+    assert_eq!(group::list_groups().await.unwrap().len(), 1);
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn create_dm_rejects_a_dm_with_yourself() {
+    let _lock = STATE_LOCK.lock().await;
+    let me = Keys::generate();
+    let my_hex = me.public_key().to_hex();
+    // Leaves the global state set up for `me`.
+    let kp_json = key_package_for(&me).await;
+
+    let err = group::create_dm(my_hex.clone(), kp_json, vec![RELAY.to_string()]).await.unwrap_err();
+    assert!(err.message.contains("yourself"));
+    assert!(group::find_dm_with(my_hex).await.unwrap().is_none());
+    assert!(group::list_groups().await.unwrap().is_empty());
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn create_dm_rejects_missing_or_invalid_key_packages() {
+    let _lock = STATE_LOCK.lock().await;
+    let peer = Keys::generate();
+    let peer_hex = peer.public_key().to_hex();
+    let stranger_kp = key_package_for(&Keys::generate()).await;
+    init_test_state(Keys::generate()).await;
+
+    let relays = vec![RELAY.to_string()];
+    let not_json = group::create_dm(peer_hex.clone(), "not json".into(), relays.clone()).await;
+    assert!(not_json.is_err());
+
+    let wrong_owner = group::create_dm(peer_hex.clone(), stranger_kp, relays.clone()).await;
+    assert!(wrong_owner.unwrap_err().message.contains("does not belong to peer"));
+
+    let garbage = EventBuilder::new(Kind::MlsKeyPackage, "not a key package")
+        .sign_with_keys(&peer)
+        .unwrap()
+        .as_json();
+    assert!(group::create_dm(peer_hex.clone(), garbage, relays.clone()).await.is_err());
+
+    let missing = group::create_or_get_dm_using(peer_hex.clone(), relays, |_| async {
+        Err(BurrowError::from("No KeyPackage found".to_string()))
+    })
+    .await
+    .unwrap_err();
+    assert!(missing.message.contains("No KeyPackage found"));
+
+    assert!(group::find_dm_with(peer_hex).await.unwrap().is_none());
+    assert!(group::list_groups().await.unwrap().is_empty());
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn reconcile_repairs_forgotten_groups_and_reports_orphans() {
+    let _lock = STATE_LOCK.lock().await;
+    init_test_state(Keys::generate()).await;
+
+    let created = group::create_group(
+        "Drifted".into(),
+        String::new(),
+        vec![],
+        vec![],
+        vec![RELAY.to_string()],
+    )
+    .await
+    .unwrap();
+    let id = created.mls_group_id_hex;
+    let orphan = "ab".repeat(32);
+    // An active group marked forgotten, and state for a group MDK never had.
+    app_state::set_group_state(id.clone(), "forgotten".into(), "true".into()).await.unwrap();
+    app_state::set_group_state(orphan.clone(), "muted".into(), "true".into()).await.unwrap();
+    assert!(group::list_groups().await.unwrap().is_empty());
+
+    let report = group::reconcile_groups().await.unwrap();
+    assert_eq!(report.mdk_group_count, 1);
+    assert_eq!(report.restored_group_ids, vec![id.clone()]);
+    assert_eq!(report.orphaned_group_ids, vec![orphan]);
+    let listed = group::list_groups().await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].mls_group_id_hex, id);
+
+    // Nothing left to restore on a second pass.
+    assert!(group::reconcile_groups().await.unwrap().restored_group_ids.is_empty());
+
+    state::destroy_state().await;
+}
+
+/// A group Alice created with Bob and then removed him from, with one
+/// message Bob received and `bob_sends` he sent before. Leaves Bob's state
+/// loaded; returns the group ID.
+async fn group_bob_was_removed_from(alice: &Keys, bob: &Keys, bob_sends: usize) -> String {
+    let bob_kp = key_package_for(bob).await;
+    init_test_state(alice.clone()).await;
+    let created = group::create_group("Churn".into(), String::new(), vec![], vec![bob_kp], vec![RELAY.to_string()])
+        .await
+        .unwrap();
+    let id = created.mls_group_id_hex.clone();
+    group::merge_pending_commit(id.clone()).await.unwrap();
+    let sent = message::send_message(id.clone(), "before removal".into(), Some(0)).await.unwrap();
+
+    init_test_state(bob.clone()).await;
+    let welcome = invite::process_welcome("cd".repeat(32), created.welcome_rumors_json[0].clone())
+        .await
+        .unwrap();
+    invite::accept_welcome(welcome.welcome_event_id).await.unwrap();
+    message::process_message(sent.event_json).await.unwrap();
+    for i in 0..bob_sends {
+        message::send_message(id.clone(), format!("from bob {i}"), Some(0)).await.unwrap();
+    }
+
+    init_test_state(alice.clone()).await;
+    let removal = invite::remove_members(id.clone(), vec![bob.public_key().to_hex()]).await.unwrap();
+    group::merge_pending_commit(id.clone()).await.unwrap();
+
+    init_test_state(bob.clone()).await;
+    message::process_message(removal.evolution_event_json).await.unwrap();
+    id
+}
+
+#[tokio::test]
+async fn forgotten_groups_are_purged_and_stay_forgotten() {
+    let _lock = STATE_LOCK.lock().await;
+    let id = group_bob_was_removed_from(&Keys::generate(), &Keys::generate(), 0).await;
+
+    let inactive = group::list_inactive_groups().await.unwrap();
+    assert_eq!(inactive.len(), 1);
+    assert_eq!(inactive[0].mls_group_id_hex, id);
+    assert_eq!(message::get_messages(id.clone(), None, None, None).await.unwrap().len(), 1);
+
+    group::forget_group(id.clone()).await.unwrap();
+    assert!(group::list_groups().await.unwrap().is_empty());
+    assert!(group::list_inactive_groups().await.unwrap().is_empty());
+    assert!(message::get_messages(id.clone(), None, None, None).await.unwrap().is_empty());
+    assert_eq!(group::cleanup_inactive_groups().await.unwrap(), 0);
+
+    let report = group::reconcile_groups().await.unwrap();
+    assert!(report.restored_group_ids.is_empty());
+    assert!(group::list_groups().await.unwrap().is_empty());
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn cleanup_forgets_only_inactive_groups() {
+    let _lock = STATE_LOCK.lock().await;
+    let id = group_bob_was_removed_from(&Keys::generate(), &Keys::generate(), 0).await;
+    let active = group::create_group("Still here".into(), String::new(), vec![], vec![], vec![RELAY.to_string()])
+        .await
+        .unwrap();
+
+    let err = group::forget_group(active.mls_group_id_hex.clone()).await.unwrap_err();
+    assert!(err.message.contains("inactive"));
+
+    assert_eq!(group::cleanup_inactive_groups().await.unwrap(), 1);
+    assert_eq!(group::cleanup_inactive_groups().await.unwrap(), 0);
+    let listed: Vec<String> = group::list_groups().await.unwrap().into_iter().map(|g| g.mls_group_id_hex).collect();
+    assert_eq!(listed, vec![active.mls_group_id_hex]);
+    assert!(!listed.contains(&id));
+
+    state::destroy_state().await;
+}
+
+/// More than MDK's default page of 1000 messages.
+const PAST_FIRST_PAGE: usize = 1050;
+
+#[tokio::test]
+async fn forget_erases_history_past_the_first_page() {
+    let _lock = STATE_LOCK.lock().await;
+    let id = group_bob_was_removed_from(&Keys::generate(), &Keys::generate(), PAST_FIRST_PAGE).await;
+    let all = message::get_messages(id.clone(), None, None, None).await.unwrap();
+    assert_eq!(all.len(), PAST_FIRST_PAGE + 1);
+
+    group::forget_group(id.clone()).await.unwrap();
+    assert!(message::get_messages(id.clone(), None, None, Some(true)).await.unwrap().is_empty());
+    for msg in &all {
+        assert!(message::get_message(id.clone(), msg.event_id_hex.clone()).await.unwrap().content.is_empty());
+    }
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn cleanup_erases_history_past_the_first_page() {
+    let _lock = STATE_LOCK.lock().await;
+    let id = group_bob_was_removed_from(&Keys::generate(), &Keys::generate(), PAST_FIRST_PAGE).await;
+
+    assert_eq!(group::cleanup_inactive_groups().await.unwrap(), 1);
+    assert!(message::get_messages(id, None, None, Some(true)).await.unwrap().is_empty());
+
+    state::destroy_state().await;
+}
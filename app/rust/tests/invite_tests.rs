@@ -0,0 +1,142 @@
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::error::BurrowError;
+use rust_lib_burrow_app::api::invite::{
+    check_key_package_event, validate_key_packages, DEFAULT_KEY_PACKAGE_MAX_AGE_SECS,
+};
+use rust_lib_burrow_app::api::{group, invite, state};
+
+mod common;
+use common::{init_test_state, key_package_for, RELAY, STATE_LOCK};
+
+#[tokio::test]
+async fn unacked_commit_is_not_merged() {
+    let _lock = STATE_LOCK.lock().await;
+    let kp_json = key_package_for(&Keys::generate()).await;
+    init_test_state(Keys::generate()).await;
+
+    let created = group::create_group(
+        "Team".to_string(),
+        String::new(),
+        vec![],
+        vec![],
+        vec![RELAY.to_string()],
+    )
+    .await
+    .unwrap();
+    let before = group::get_group(created.mls_group_id_hex.clone()).await.unwrap();
+
+    let err = invite::invite_members_full_with(
+        created.mls_group_id_hex.clone(),
+        vec![kp_json],
+        |_, _| async { Err(BurrowError::from("no relay acked".to_string())) },
+    )
+    .await
+    .unwrap_err();
+    assert!(err.message.contains("not merged"));
+    assert!(err.message.contains("no relay acked"));
+
+    let after = group::get_group(created.mls_group_id_hex).await.unwrap();
+    assert_eq!(after.epoch, before.epoch);
+    assert_eq!(after.member_count, before.member_count);
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn invite_can_be_retried_after_failed_publish() {
+    let _lock = STATE_LOCK.lock().await;
+    let invitee = Keys::generate();
+    let kp_json = key_package_for(&invitee).await;
+    init_test_state(Keys::generate()).await;
+
+    let created = group::create_group(
+        "Team".to_string(),
+        String::new(),
+        vec![],
+        vec![],
+        vec![RELAY.to_string()],
+    )
+    .await
+    .unwrap();
+    let before = group::get_group(created.mls_group_id_hex.clone()).await.unwrap();
+
+    invite::invite_members_full_with(created.mls_group_id_hex.clone(), vec![kp_json.clone()], |_, _| async {
+        Err(BurrowError::from("no relay acked".to_string()))
+    })
+    .await
+    .unwrap_err();
+
+    // No merge from the caller in between: the failed attempt cleaned up.
+    let result = invite::invite_members_full_with(created.mls_group_id_hex.clone(), vec![kp_json], |_, _| async {
+        Ok("ab".repeat(32))
+    })
+    .await
+    .unwrap();
+    assert_eq!(result.evolution_event_id_hex, "ab".repeat(32));
+
+    let after = group::get_group(created.mls_group_id_hex).await.unwrap();
+    assert_eq!(after.epoch, before.epoch + 1);
+    assert_eq!(after.member_count, before.member_count + 1);
+
+    state::destroy_state().await;
+}
+
+const NOW: u64 = 1_750_000_000;
+const DAY: u64 = 86400;
+
+/// A kind 443 event with the given age and ciphersuite; the content isn't
+/// a real KeyPackage, which validation doesn't look at.
+fn key_package_event(keys: &Keys, age_secs: u64, ciphersuite: &str) -> Event {
+    EventBuilder::new(Kind::MlsKeyPackage, "")
+        .tags([
+            Tag::parse(["mls_protocol_version", "1.0"]).unwrap(),
+            Tag::parse(["mls_ciphersuite", ciphersuite]).unwrap(),
+            Tag::parse(["relays", RELAY]).unwrap(),
+        ])
+        .custom_created_at(Timestamp::from(NOW - age_secs))
+        .sign_with_keys(keys)
+        .unwrap()
+}
+
+#[test]
+fn fresh_key_package_is_accepted() {
+    let event = key_package_event(&Keys::generate(), DAY, "0x0001");
+    assert!(check_key_package_event(&event, Timestamp::from(NOW), DEFAULT_KEY_PACKAGE_MAX_AGE_SECS).is_ok());
+    let decimal = key_package_event(&Keys::generate(), DAY, "1");
+    assert!(check_key_package_event(&decimal, Timestamp::from(NOW), DEFAULT_KEY_PACKAGE_MAX_AGE_SECS).is_ok());
+}
+
+#[test]
+fn expired_key_package_is_rejected() {
+    let stale = Keys::generate();
+    let fresh = Keys::generate();
+    let events = vec![
+        key_package_event(&fresh, DAY, "0x0001"),
+        key_package_event(&stale, 45 * DAY, "0x0001"),
+    ];
+
+    let err = validate_key_packages(
+        &events,
+        &[RELAY.to_string()],
+        Timestamp::from(NOW),
+        DEFAULT_KEY_PACKAGE_MAX_AGE_SECS,
+    )
+    .unwrap_err();
+    assert!(err.message.contains(&stale.public_key().to_hex()));
+    assert!(err.message.contains("expired"));
+    assert!(!err.message.contains(&fresh.public_key().to_hex()));
+}
+
+#[test]
+fn ciphersuite_mismatch_is_rejected() {
+    let keys = Keys::generate();
+    let event = key_package_event(&keys, DAY, "0x0003");
+
+    let reason =
+        check_key_package_event(&event, Timestamp::from(NOW), DEFAULT_KEY_PACKAGE_MAX_AGE_SECS).unwrap_err();
+    assert!(reason.contains("ciphersuite 0x0003"));
+
+    let err = validate_key_packages(&[event], &[], Timestamp::from(NOW), DEFAULT_KEY_PACKAGE_MAX_AGE_SECS)
+        .unwrap_err();
+    assert!(err.message.contains(&keys.public_key().to_hex()));
+}
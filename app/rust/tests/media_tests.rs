@@ -1,4 +1,18 @@
-use rust_lib_burrow_app::api::media::{build_imeta_tag, parse_imeta_tag};
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::media::{
+    blossom_hash_from_url, build_chunked_imeta_tag, build_imeta_tag, decrypt_file_chunked,
+    downscale_to_jpeg, encrypt_file_chunked, encrypt_file_from_path, media_cache_get,
+    media_cache_put, media_cache_usage, mirror_urls, parse_imeta_tag,
+    should_use_chunked_encryption, sniff_mime_type, upload_media_batch, verify_blob_hash,
+    ChunkedEncryptResult, FileInput, ThumbnailReference, CHUNKED_SCHEME_VERSION,
+    CHUNKED_THRESHOLD_BYTES,
+};
+use rust_lib_burrow_app::api::{group, state};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+mod common;
+use common::{init_test_state, RELAY, STATE_LOCK};
 
 #[test]
 fn build_imeta_tag_basic() {
@@ -10,6 +24,7 @@ fn build_imeta_tag_basic() {
         "b".repeat(24),
         Some("1920x1080".to_string()),
         Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj".to_string()),
+        None,
     )
     .unwrap();
 
@@ -33,6 +48,7 @@ fn build_imeta_tag_no_optional_fields() {
         "b".repeat(24),
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -54,6 +70,7 @@ fn parse_imeta_tag_roundtrip() {
         nonce.clone(),
         Some("800x600".to_string()),
         None,
+        None,
     )
     .unwrap();
 
@@ -223,6 +240,7 @@ fn build_and_parse_no_dimensions() {
         nonce.clone(),
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -230,3 +248,462 @@ fn build_and_parse_no_dimensions() {
     assert!(parsed.dimensions.is_none());
     assert_eq!(parsed.mime_type, "audio/mp3");
 }
+
+#[test]
+fn chunked_threshold() {
+    assert!(!should_use_chunked_encryption(0));
+    assert!(!should_use_chunked_encryption(CHUNKED_THRESHOLD_BYTES - 1));
+    assert!(should_use_chunked_encryption(CHUNKED_THRESHOLD_BYTES));
+}
+
+#[test]
+fn chunked_imeta_tag_roundtrip() {
+    let tag = build_chunked_imeta_tag(
+        "https://blossom.example.com/big".to_string(),
+        "video/mp4".to_string(),
+        "clip.mp4".to_string(),
+        "c".repeat(64),
+        "d".repeat(24),
+    )
+    .unwrap();
+    assert!(tag.iter().any(|v| v == &format!("v {}", CHUNKED_SCHEME_VERSION)));
+    assert!(!tag.iter().any(|v| v == "v mip04-v2"));
+
+    let parsed = parse_imeta_tag(tag).unwrap();
+    assert_eq!(parsed.scheme_version, CHUNKED_SCHEME_VERSION);
+    assert_eq!(parsed.mime_type, "video/mp4");
+}
+
+fn cache_test_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("burrow-media-cache-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn hash_of(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+#[test]
+fn media_cache_round_trip_and_rejects_tampering() {
+    let dir = cache_test_dir("roundtrip");
+    let data = b"cached attachment".to_vec();
+    let hash = hash_of(&data);
+
+    assert!(media_cache_get(&dir, &hash).is_none());
+    media_cache_put(&dir, &hash, &data, 1024).unwrap();
+    assert_eq!(media_cache_get(&dir, &hash), Some(data.clone()));
+    assert_eq!(media_cache_usage(&dir).unwrap(), data.len() as u64);
+
+    // A corrupted entry is dropped instead of served
+    std::fs::write(dir.join(&hash), b"tampered").unwrap();
+    assert!(media_cache_get(&dir, &hash).is_none());
+    assert_eq!(media_cache_usage(&dir).unwrap(), 0);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn media_cache_evicts_least_recently_used() {
+    let dir = cache_test_dir("lru");
+    let a = vec![b'a'; 400];
+    let b = vec![b'b'; 400];
+    let c = vec![b'c'; 400];
+
+    media_cache_put(&dir, &hash_of(&a), &a, 1000).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    media_cache_put(&dir, &hash_of(&b), &b, 1000).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    // Touch `a` so `b` becomes the oldest
+    assert!(media_cache_get(&dir, &hash_of(&a)).is_some());
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    media_cache_put(&dir, &hash_of(&c), &c, 1000).unwrap();
+
+    assert!(media_cache_get(&dir, &hash_of(&a)).is_some());
+    assert!(media_cache_get(&dir, &hash_of(&b)).is_none());
+    assert!(media_cache_get(&dir, &hash_of(&c)).is_some());
+    assert!(media_cache_usage(&dir).unwrap() <= 1000);
+
+    // Bigger than the whole cache: not stored
+    let big = vec![b'x'; 2000];
+    media_cache_put(&dir, &hash_of(&big), &big, 1000).unwrap();
+    assert!(media_cache_get(&dir, &hash_of(&big)).is_none());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn upload_batch_reports_each_file_in_order() {
+    let files = vec![
+        FileInput {
+            file_data: b"first".to_vec(),
+            mime_type: "text/plain".into(),
+            filename: "a.txt".into(),
+        },
+        FileInput {
+            file_data: b"second".to_vec(),
+            mime_type: "text/plain".into(),
+            filename: "b.txt".into(),
+        },
+    ];
+    // Not logged in: every file fails, but each still gets its own outcome.
+    let items = upload_media_batch("00".repeat(32), files, "https://blossom.invalid".into())
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].filename, "a.txt");
+    assert_eq!(items[1].filename, "b.txt");
+    assert!(items.iter().all(|i| i.result.is_none() && i.error.is_some()));
+
+    let empty = upload_media_batch("00".repeat(32), vec![], "https://blossom.invalid".into())
+        .await
+        .unwrap();
+    assert!(empty.is_empty());
+}
+
+#[tokio::test]
+async fn encrypt_from_path_reports_missing_file() {
+    let missing = std::env::temp_dir().join(format!("burrow-missing-{}.bin", std::process::id()));
+    let result = encrypt_file_from_path(
+        "00".repeat(32),
+        missing.to_string_lossy().into_owned(),
+        "video/mp4".into(),
+        "clip.mp4".into(),
+        false,
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[test]
+fn mirror_urls_use_blob_hash() {
+    let blob = b"encrypted blob";
+    let hash = hash_of(blob);
+    let url = format!("https://cdn.example.com/{}.bin", hash.to_uppercase());
+    assert_eq!(blossom_hash_from_url(&url).as_deref(), Some(hash.as_str()));
+    assert_eq!(blossom_hash_from_url("https://cdn.example.com/file/abc"), None);
+
+    let mirrors = vec!["https://m1.example.com/".to_string(), " ".to_string(), "https://m2.example.com".to_string()];
+    assert_eq!(
+        mirror_urls(&hash, &mirrors),
+        vec![format!("https://m1.example.com/{hash}"), format!("https://m2.example.com/{hash}")]
+    );
+}
+
+#[test]
+fn integrity_check_rejects_wrong_mirror_bytes() {
+    let blob = b"the real ciphertext";
+    let hash = hash_of(blob);
+    assert!(verify_blob_hash(blob, &hash).is_ok());
+    assert!(verify_blob_hash(b"a mirror serving something else", &hash).is_err());
+    assert!(verify_blob_hash(blob, &hash.to_uppercase()).is_ok());
+}
+
+#[test]
+fn parse_imeta_tag_thumbnail_roundtrip() {
+    let thumb = ThumbnailReference {
+        url: "https://blossom.example.com/thumb;v=1".to_string(),
+        original_hash_hex: "c".repeat(64),
+        nonce_hex: "d".repeat(24),
+    };
+    let tag: Vec<String> = build_imeta_tag(
+        "https://blossom.example.com/full".to_string(),
+        "image/jpeg".to_string(),
+        "photo.jpg".to_string(),
+        "a".repeat(64),
+        "b".repeat(24),
+        Some("4032x3024".to_string()),
+        None,
+        Some(thumb.clone()),
+    )
+    .unwrap();
+    assert!(tag.iter().any(|v| v.starts_with("thumb https://blossom.example.com/thumb;v=1;")));
+
+    let parsed = parse_imeta_tag(tag.clone()).unwrap();
+    assert_eq!(parsed.thumb, Some(thumb));
+
+    // A malformed thumb field drops the preview, not the attachment
+    let mut bad = tag;
+    for v in bad.iter_mut() {
+        if v.starts_with("thumb ") {
+            *v = "thumb https://blossom.example.com/thumb;nothex;dd".to_string();
+        }
+    }
+    let parsed = parse_imeta_tag(bad).unwrap();
+    assert!(parsed.thumb.is_none());
+    assert_eq!(parsed.url, "https://blossom.example.com/full");
+}
+
+#[test]
+fn downscale_thumbnail_fits_max_dimension() {
+    let mut png = Vec::new();
+    image::DynamicImage::new_rgba8(800, 400)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .unwrap();
+
+    let jpeg = downscale_to_jpeg(&png, 200).unwrap();
+    let thumb = image::load_from_memory(&jpeg).unwrap();
+    assert_eq!((thumb.width(), thumb.height()), (200, 100));
+    assert!(downscale_to_jpeg(b"not an image", 200).is_err());
+}
+
+fn tiny_png() -> Vec<u8> {
+    let mut png = Vec::new();
+    image::DynamicImage::new_rgb8(4, 4)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .unwrap();
+    png
+}
+
+#[test]
+fn sniff_mime_type_png_declared_as_pdf() {
+    let png = tiny_png();
+
+    assert_eq!(sniff_mime_type(&png, "application/pdf", false).unwrap(), "image/png");
+    let err = sniff_mime_type(&png, "application/pdf", true).unwrap_err();
+    assert!(err.to_string().contains("application/pdf"));
+    assert!(err.to_string().contains("image/png"));
+
+    assert_eq!(sniff_mime_type(&png, "image/png", true).unwrap(), "image/png");
+    // Unrecognized content passes through as declared
+    assert_eq!(sniff_mime_type(b"plain notes", "text/plain", true).unwrap(), "text/plain");
+}
+
+/// A fresh group to encrypt for, and a scratch dir for `test`.
+async fn chunked_setup(test: &str) -> (String, PathBuf) {
+    init_test_state(Keys::generate()).await;
+    let created = group::create_group("Media".into(), String::new(), vec![], vec![], vec![RELAY.to_string()])
+        .await
+        .unwrap();
+    let dir = std::env::temp_dir().join(format!("burrow-chunked-{}-{}", test, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    (created.mls_group_id_hex, dir)
+}
+
+/// Encrypt `plain` from a file in `dir`; returns the result and the blob.
+async fn encrypt_chunked(group_id: &str, dir: &std::path::Path, plain: &[u8]) -> (ChunkedEncryptResult, Vec<u8>) {
+    let input = dir.join("plain.bin");
+    let output = dir.join("blob.bin");
+    std::fs::write(&input, plain).unwrap();
+    let result = encrypt_file_chunked(
+        group_id.to_string(),
+        input.to_string_lossy().to_string(),
+        output.to_string_lossy().to_string(),
+        "video/mp4".into(),
+        "clip.mp4".into(),
+        false,
+    )
+    .await
+    .unwrap();
+    let blob = std::fs::read(&output).unwrap();
+    (result, blob)
+}
+
+/// Decrypt `blob` via a file in `dir`; the plaintext on success.
+async fn decrypt_chunked(
+    group_id: &str,
+    dir: &std::path::Path,
+    result: &ChunkedEncryptResult,
+    blob: &[u8],
+) -> Result<Vec<u8>, String> {
+    let input = dir.join("received.bin");
+    let output = dir.join("decrypted.bin");
+    let _ = std::fs::remove_file(&output);
+    std::fs::write(&input, blob).unwrap();
+    let outcome = decrypt_file_chunked(
+        group_id.to_string(),
+        input.to_string_lossy().to_string(),
+        output.to_string_lossy().to_string(),
+        result.filename.clone(),
+        result.original_hash_hex.clone(),
+    )
+    .await;
+    match outcome {
+        Ok(written) => {
+            let plain = std::fs::read(&output).unwrap();
+            assert_eq!(written, plain.len() as u64);
+            Ok(plain)
+        }
+        Err(e) => {
+            // A failed decrypt leaves neither the output nor its temp file.
+            assert!(!output.exists() && !dir.join("decrypted.bin.part").exists());
+            Err(e.message)
+        }
+    }
+}
+
+/// Offset of the first frame, and the sealed size of a full frame.
+fn frame_layout(blob: &[u8], result: &ChunkedEncryptResult) -> (usize, usize) {
+    let wrapped_len = u16::from_be_bytes([blob[64], blob[65]]) as usize;
+    (66 + wrapped_len, result.frame_size as usize + 16)
+}
+
+fn patterned(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+#[tokio::test]
+async fn chunked_round_trip() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("round-trip").await;
+
+    for len in [0, 1, 1024 * 1024 + 17, 3 * 1024 * 1024 - 1] {
+        let plain = patterned(len);
+        let (result, blob) = encrypt_chunked(&group_id, &dir, &plain).await;
+        assert_eq!(result.original_size, len as u64);
+        assert_eq!(result.encrypted_size, blob.len() as u64);
+        assert_eq!(result.scheme_version, CHUNKED_SCHEME_VERSION);
+        assert_eq!(decrypt_chunked(&group_id, &dir, &result, &blob).await.unwrap(), plain);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn chunked_final_frame_exactly_at_boundary() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("boundary").await;
+
+    let (probe, _) = encrypt_chunked(&group_id, &dir, b"probe").await;
+    let plain = patterned(2 * probe.frame_size as usize);
+    let (result, blob) = encrypt_chunked(&group_id, &dir, &plain).await;
+    let (start, sealed) = frame_layout(&blob, &result);
+    // Exactly two full frames, no empty trailing one.
+    assert_eq!(blob.len(), start + 2 * sealed);
+    assert_eq!(decrypt_chunked(&group_id, &dir, &result, &blob).await.unwrap(), plain);
+
+    // Without the second frame the first isn't marked last, so it fails.
+    let err = decrypt_chunked(&group_id, &dir, &result, &blob[..start + sealed]).await.unwrap_err();
+    assert!(err.contains("Frame 0 failed authentication"), "{err}");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn chunked_rejects_tampered_reordered_and_truncated_blobs() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("tamper").await;
+
+    let (probe, _) = encrypt_chunked(&group_id, &dir, b"probe").await;
+    let plain = patterned(2 * probe.frame_size as usize + 100);
+    let (result, blob) = encrypt_chunked(&group_id, &dir, &plain).await;
+    let (start, sealed) = frame_layout(&blob, &result);
+    assert_eq!(blob.len(), start + 2 * sealed + 100 + 16);
+
+    let mut tampered = blob.clone();
+    tampered[start + sealed + 5] ^= 1;
+    let err = decrypt_chunked(&group_id, &dir, &result, &tampered).await.unwrap_err();
+    assert!(err.contains("Frame 1 failed authentication"), "{err}");
+
+    let mut reordered = blob[..start].to_vec();
+    reordered.extend_from_slice(&blob[start + sealed..start + 2 * sealed]);
+    reordered.extend_from_slice(&blob[start..start + sealed]);
+    reordered.extend_from_slice(&blob[start + 2 * sealed..]);
+    let err = decrypt_chunked(&group_id, &dir, &result, &reordered).await.unwrap_err();
+    assert!(err.contains("Frame 0 failed authentication"), "{err}");
+
+    // Cut at a frame boundary, inside a frame, and inside the header.
+    for cut in [start + 2 * sealed, blob.len() - 1, start + sealed + 8, 10] {
+        assert!(decrypt_chunked(&group_id, &dir, &result, &blob[..cut]).await.is_err(), "cut at {cut}");
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn encrypt_from_path_sniffs_declared_mime() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("from-path-mime").await;
+    let input = dir.join("photo.bin");
+    std::fs::write(&input, tiny_png()).unwrap();
+    let path = input.to_string_lossy().to_string();
+
+    let result = encrypt_file_from_path(group_id.clone(), path.clone(), "application/pdf".into(), "doc.pdf".into(), false)
+        .await
+        .unwrap();
+    assert_eq!(result.mime_type, "image/png");
+    std::fs::remove_file(&result.encrypted_path).unwrap();
+
+    let err = encrypt_file_from_path(group_id, path, "application/pdf".into(), "doc.pdf".into(), true)
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("application/pdf") && err.message.contains("image/png"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn chunked_sniffs_declared_mime() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("mime").await;
+    let input = dir.join("photo.bin");
+    let output = dir.join("blob.bin");
+    std::fs::write(&input, tiny_png()).unwrap();
+    let encrypt = |strict| {
+        encrypt_file_chunked(
+            group_id.clone(),
+            input.to_string_lossy().to_string(),
+            output.to_string_lossy().to_string(),
+            "application/pdf".into(),
+            "doc.pdf".into(),
+            strict,
+        )
+    };
+
+    assert_eq!(encrypt(false).await.unwrap().mime_type, "image/png");
+    let err = encrypt(true).await.unwrap_err();
+    assert!(err.message.contains("application/pdf") && err.message.contains("image/png"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn encrypt_from_path_streams_large_files() {
+    let _lock = STATE_LOCK.lock().await;
+    let (group_id, dir) = chunked_setup("from-path-large").await;
+    let encrypt = |path: PathBuf| {
+        encrypt_file_from_path(
+            group_id.clone(),
+            path.to_string_lossy().to_string(),
+            "video/mp4".into(),
+            "clip.mp4".into(),
+            false,
+        )
+    };
+
+    let small = dir.join("small.bin");
+    std::fs::write(&small, patterned(1024)).unwrap();
+    let on_disk = encrypt(small).await.unwrap();
+    assert_eq!(on_disk.scheme_version, "mip04-v2");
+    std::fs::remove_file(&on_disk.encrypted_path).unwrap();
+
+    let large = dir.join("large.bin");
+    let plain = patterned(CHUNKED_THRESHOLD_BYTES as usize + 17);
+    std::fs::write(&large, &plain).unwrap();
+    let on_disk = encrypt(large).await.unwrap();
+    assert_eq!(on_disk.scheme_version, CHUNKED_SCHEME_VERSION);
+    assert_eq!(on_disk.original_size, plain.len() as u64);
+    assert_eq!(on_disk.encrypted_hash_hex, hash_of(&std::fs::read(&on_disk.encrypted_path).unwrap()));
+
+    let output = dir.join("decrypted.bin");
+    decrypt_file_chunked(
+        group_id.clone(),
+        on_disk.encrypted_path.clone(),
+        output.to_string_lossy().to_string(),
+        on_disk.filename.clone(),
+        on_disk.original_hash_hex.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(std::fs::read(&output).unwrap(), plain);
+
+    std::fs::remove_file(&on_disk.encrypted_path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    state::destroy_state().await;
+}
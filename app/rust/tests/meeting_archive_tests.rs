@@ -0,0 +1,65 @@
+//! Persistence of the meeting archive across `set_data_dir`. Kept out of
+//! meeting_intelligence_tests.rs because the data dir can only be set once
+//! per process.
+
+use rust_lib_burrow_app::api::meeting_intelligence::*;
+use rust_lib_burrow_app::api::state;
+use rust_lib_burrow_app::api::transcription::TranscriptSegment;
+
+fn segments_json(text: &str) -> String {
+    serde_json::to_string(&[TranscriptSegment {
+        id: "seg_0".to_string(),
+        speaker_id: "alice".to_string(),
+        speaker_name: "Alice".to_string(),
+        text: text.to_string(),
+        start_ms: 0,
+        end_ms: 3000,
+        confidence: 0.9,
+        language: "en".to_string(),
+        is_final: true,
+    }])
+    .unwrap()
+}
+
+#[tokio::test]
+async fn archive_touched_before_set_data_dir_is_not_overwritten() {
+    let dir = std::env::temp_dir().join(format!("burrow-meeting-archive-lazy-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("meeting_notes.json");
+
+    // An archive left by a previous run.
+    let previous = MeetingNotes {
+        meeting_id: "previous-run".to_string(),
+        title: "Earlier meeting".to_string(),
+        summary: String::new(),
+        key_points: vec![],
+        action_items: vec![],
+        decisions: vec![],
+        open_questions: vec![],
+        participants: vec![],
+        start_time_ms: 0,
+        end_time_ms: 60_000,
+        duration_seconds: 60,
+        generated_at_ms: 0,
+    };
+    std::fs::write(&path, serde_json::to_vec(&[previous]).unwrap()).unwrap();
+
+    // Before the data dir is known, notes live in memory only.
+    generate_meeting_notes("early".to_string(), segments_json("I need to call Bob"), "[]".to_string(), 0, 3000)
+        .await
+        .unwrap();
+    assert!(get_meeting_notes("previous-run".to_string()).is_err());
+
+    state::set_data_dir(dir.to_string_lossy().to_string());
+    assert!(get_meeting_notes("previous-run".to_string()).is_ok());
+    generate_meeting_notes("late".to_string(), segments_json("I need to email Carol"), "[]".to_string(), 0, 3000)
+        .await
+        .unwrap();
+
+    let on_disk: Vec<MeetingNotes> = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    let mut ids: Vec<&str> = on_disk.iter().map(|n| n.meeting_id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, ["early", "late", "previous-run"]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
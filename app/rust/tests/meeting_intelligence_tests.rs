@@ -1,6 +1,19 @@
 use rust_lib_burrow_app::api::meeting_intelligence::*;
 use rust_lib_burrow_app::api::transcription::TranscriptSegment;
 
+/// Tests that generate notes or change the AI backend share one global
+/// engine; hold this so a backend switch can't leak into note generation.
+static BACKEND_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Puts the rule-based backend back when dropped, even if the test panics.
+struct RestoreRuleBased;
+
+impl Drop for RestoreRuleBased {
+    fn drop(&mut self) {
+        configure_ai_backend(r#""RuleBased""#.to_string()).unwrap();
+    }
+}
+
 fn make_segment(speaker: &str, text: &str, start_ms: i64) -> TranscriptSegment {
     TranscriptSegment {
         id: format!("seg_{}", start_ms),
@@ -15,8 +28,9 @@ fn make_segment(speaker: &str, text: &str, start_ms: i64) -> TranscriptSegment {
     }
 }
 
-#[test]
-fn test_generate_notes_from_transcript() {
+#[tokio::test]
+async fn test_generate_notes_from_transcript() {
+    let _backend = BACKEND_LOCK.lock().await;
     let segments = vec![
         make_segment("Alice", "Let's discuss the Phase 4 plan", 0),
         make_segment("Bob", "I need to review the design doc by Friday", 3000),
@@ -34,7 +48,8 @@ fn test_generate_notes_from_transcript() {
         participants_json,
         0,
         12_000,
-    );
+    )
+    .await;
 
     assert!(result.is_ok());
     let notes_json = result.unwrap();
@@ -49,15 +64,17 @@ fn test_generate_notes_from_transcript() {
     assert_eq!(notes.participants.len(), 2);
 }
 
-#[test]
-fn test_generate_notes_empty_transcript() {
+#[tokio::test]
+async fn test_generate_notes_empty_transcript() {
+    let _backend = BACKEND_LOCK.lock().await;
     let result = generate_meeting_notes(
         "empty-meeting".to_string(),
         "[]".to_string(),
         "[\"alice\"]".to_string(),
         0,
         60_000,
-    );
+    )
+    .await;
 
     assert!(result.is_ok());
     let notes: MeetingNotes = serde_json::from_str(&result.unwrap()).unwrap();
@@ -65,8 +82,9 @@ fn test_generate_notes_empty_transcript() {
     assert!(notes.summary.contains("No transcript content"));
 }
 
-#[test]
-fn test_action_item_priority() {
+#[tokio::test]
+async fn test_action_item_priority() {
+    let _backend = BACKEND_LOCK.lock().await;
     let segments = vec![
         make_segment("Alice", "This is urgent, I need to fix this ASAP", 0),
         make_segment("Bob", "I should also look at the docs when I get a chance", 3000),
@@ -79,7 +97,8 @@ fn test_action_item_priority() {
         "[\"alice\", \"bob\"]".to_string(),
         0,
         6000,
-    );
+    )
+    .await;
 
     let notes: MeetingNotes = serde_json::from_str(&result.unwrap()).unwrap();
     assert!(notes.action_items.len() >= 2);
@@ -94,8 +113,9 @@ fn test_build_prompt() {
     assert!(prompt.contains("key_points"));
 }
 
-#[test]
-fn test_export_markdown() {
+#[tokio::test]
+async fn test_export_markdown() {
+    let _backend = BACKEND_LOCK.lock().await;
     // First generate notes to populate archive.
     let segments = vec![
         make_segment("Alice", "I need to write the tests", 0),
@@ -107,7 +127,8 @@ fn test_export_markdown() {
         "[\"alice\"]".to_string(),
         0,
         30_000,
-    );
+    )
+    .await;
 
     let md = export_meeting_markdown("md-export-test".to_string());
     assert!(md.is_ok());
@@ -116,8 +137,9 @@ fn test_export_markdown() {
     assert!(content.contains("Action Items"));
 }
 
-#[test]
-fn test_search_meetings() {
+#[tokio::test]
+async fn test_search_meetings() {
+    let _backend = BACKEND_LOCK.lock().await;
     // Generate a meeting with known content.
     let segments = vec![
         make_segment("Alice", "We discussed the quantum computing roadmap", 0),
@@ -129,7 +151,8 @@ fn test_search_meetings() {
         "[\"alice\"]".to_string(),
         0,
         10_000,
-    );
+    )
+    .await;
 
     let result = search_meetings("quantum".to_string());
     assert!(result.is_ok());
@@ -140,6 +163,8 @@ fn test_search_meetings() {
 
 #[test]
 fn test_configure_ai_backend() {
+    let _backend = BACKEND_LOCK.blocking_lock();
+    let _restore = RestoreRuleBased;
     let result = configure_ai_backend(r#""RuleBased""#.to_string());
     assert!(result.is_ok());
 
@@ -160,3 +185,98 @@ fn test_get_meeting_archive() {
     let result = get_meeting_archive();
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_open_action_items_across_meetings() {
+    let _backend = BACKEND_LOCK.lock().await;
+    let segments = vec![
+        make_segment("dash_alice", "I should update the wiki", 0),
+        make_segment("dash_bob", "This is urgent, I need to patch the server ASAP", 3000),
+        make_segment("dash_alice", "I need to email the vendor", 6000),
+    ];
+    let notes_json = generate_meeting_notes(
+        "dashboard-test".to_string(),
+        serde_json::to_string(&segments).unwrap(),
+        "[\"dash_alice\", \"dash_bob\"]".to_string(),
+        0,
+        9000,
+    )
+    .await
+    .unwrap();
+    let notes: MeetingNotes = serde_json::from_str(&notes_json).unwrap();
+    let done = notes
+        .action_items
+        .iter()
+        .find(|a| a.description.contains("vendor"))
+        .unwrap();
+    toggle_action_item("dashboard-test".to_string(), done.id.clone()).unwrap();
+
+    let all: Vec<ActionItemWithContext> =
+        serde_json::from_str(&get_open_action_items(None).unwrap()).unwrap();
+    let ours: Vec<&ActionItemWithContext> =
+        all.iter().filter(|a| a.meeting_id == "dashboard-test").collect();
+    assert_eq!(ours.len(), 2);
+    assert!(ours.iter().all(|a| !a.item.completed));
+    // Same (empty) deadline, so high priority sorts first
+    assert_eq!(ours[0].item.assignee_pubkey, "dash_bob");
+    assert_eq!(ours[0].meeting_title, notes.title);
+
+    let alice: Vec<ActionItemWithContext> =
+        serde_json::from_str(&get_open_action_items(Some("dash_alice".to_string())).unwrap())
+            .unwrap();
+    assert_eq!(alice.len(), 1);
+    assert!(alice[0].item.description.contains("wiki"));
+}
+
+#[tokio::test]
+async fn test_notes_redact_secrets_before_archiving() {
+    let _backend = BACKEND_LOCK.lock().await;
+    let segments = vec![
+        make_segment("redact_alice", "I need to charge card 4111 1111 1111 1111 for the renewal", 0),
+        make_segment("redact_bob", "I will rotate the key sk-proj-abcdefghijklmnop1234 tonight", 3000),
+        make_segment("redact_alice", "Agreed, the password is hunter2 until then", 6000),
+    ];
+    let notes_json = generate_meeting_notes(
+        "redaction-test".to_string(),
+        serde_json::to_string(&segments).unwrap(),
+        "[\"redact_alice\", \"redact_bob\"]".to_string(),
+        0,
+        9000,
+    )
+    .await
+    .unwrap();
+    assert!(!notes_json.contains("4111 1111"));
+    assert!(!notes_json.contains("abcdefghijklmnop1234"));
+    assert!(!notes_json.contains("hunter2"));
+
+    let notes: MeetingNotes = serde_json::from_str(&notes_json).unwrap();
+    assert!(notes.action_items.iter().any(|a| a.description.contains(REDACTED)));
+
+    let archived = get_meeting_notes("redaction-test".to_string()).unwrap();
+    assert!(!archived.contains("hunter2"));
+}
+
+#[test]
+fn test_configure_redaction_rejects_invalid_pattern() {
+    assert!(configure_redaction("[\"(unclosed\"]".to_string()).is_err());
+    assert!(configure_redaction("not json".to_string()).is_err());
+}
+
+#[tokio::test]
+async fn test_delete_meeting_notes() {
+    let _backend = BACKEND_LOCK.lock().await;
+    let segments = vec![make_segment("delete_alice", "I need to book the room", 0)];
+    generate_meeting_notes(
+        "delete-test".to_string(),
+        serde_json::to_string(&segments).unwrap(),
+        "[\"delete_alice\"]".to_string(),
+        0,
+        3000,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(delete_meeting_notes("delete-test".to_string()), Ok(true));
+    assert!(get_meeting_notes("delete-test".to_string()).is_err());
+    assert_eq!(delete_meeting_notes("delete-test".to_string()), Ok(false));
+}
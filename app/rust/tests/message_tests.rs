@@ -0,0 +1,450 @@
+use std::collections::BTreeSet;
+
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::message::{
+    aggregate_receipts, diff_member_sets, edit_target, filter_messages_by_query, flush_outbox, is_message_expired, mentions_pubkey,
+    reassemble_message, reply_target, split_message_content, validate_reaction, GroupMessage, ReadReceipt,
+    SendRequest,
+};
+use rust_lib_burrow_app::api::{group, invite, message, state};
+
+mod common;
+use common::{init_test_state, key_package_for, RELAY, STATE_LOCK};
+
+#[test]
+fn reaction_accepts_like_and_dislike() {
+    assert!(validate_reaction("+".to_string(), None).is_ok());
+    assert!(validate_reaction("-".to_string(), None).is_ok());
+}
+
+#[test]
+fn reaction_accepts_single_emoji() {
+    for emoji in ["👍", "❤️", "🔥", "👨‍👩‍👧", "👍🏽", "🇩🇪", "1️⃣", "⭐"] {
+        assert!(
+            validate_reaction(emoji.to_string(), None).is_ok(),
+            "expected {:?} to be accepted",
+            emoji
+        );
+    }
+}
+
+#[test]
+fn reaction_rejects_text_and_multiple_emoji() {
+    for content in ["", "lol", "👍👍", "👍 ", "a👍", "🇩🇪🇫🇷", "\u{200D}👍"] {
+        assert!(
+            validate_reaction(content.to_string(), None).is_err(),
+            "expected {:?} to be rejected",
+            content
+        );
+    }
+}
+
+#[test]
+fn reaction_custom_emoji_requires_url() {
+    assert!(validate_reaction(":soapbox:".to_string(), None).is_err());
+    assert!(validate_reaction(
+        ":soapbox:".to_string(),
+        Some("https://example.com/soapbox.png".to_string())
+    )
+    .is_ok());
+}
+
+#[test]
+fn reaction_custom_emoji_validates_shortcode_and_url() {
+    let url = Some("https://example.com/e.png".to_string());
+    assert!(validate_reaction(":bad-code:".to_string(), url.clone()).is_err());
+    assert!(validate_reaction("::".to_string(), url.clone()).is_err());
+    assert!(validate_reaction(":ok_1:".to_string(), Some("ftp://x/e.png".to_string())).is_err());
+    // URL without a shortcode is rejected
+    assert!(validate_reaction("👍".to_string(), url).is_err());
+}
+
+fn expiration_tags(exp: &str) -> Vec<Vec<String>> {
+    vec![
+        vec!["p".to_string(), "a".repeat(64)],
+        vec!["expiration".to_string(), exp.to_string()],
+    ]
+}
+
+#[test]
+fn expiration_boundary() {
+    let tags = expiration_tags("1700000000");
+    assert!(!is_message_expired(tags.clone(), 1_699_999_999));
+    assert!(is_message_expired(tags.clone(), 1_700_000_000));
+    assert!(is_message_expired(tags, 1_700_000_001));
+}
+
+#[test]
+fn message_without_expiration_is_kept() {
+    assert!(!is_message_expired(vec![], u64::MAX));
+    assert!(!is_message_expired(
+        vec![vec!["e".to_string(), "b".repeat(64)]],
+        u64::MAX
+    ));
+}
+
+#[test]
+fn malformed_expiration_is_kept() {
+    assert!(!is_message_expired(expiration_tags("soon"), u64::MAX));
+    assert!(!is_message_expired(
+        vec![vec!["expiration".to_string()]],
+        u64::MAX
+    ));
+}
+
+fn part(content: &str, index: usize, total: usize, id: &str) -> GroupMessage {
+    GroupMessage {
+        event_id_hex: format!("{:064x}", index),
+        author_pubkey_hex: "a".repeat(64),
+        content: content.to_string(),
+        created_at: 1_700_000_000,
+        mls_group_id_hex: "ab".repeat(16),
+        kind: 1,
+        tags: vec![vec![
+            "part".to_string(),
+            index.to_string(),
+            total.to_string(),
+            id.to_string(),
+        ]],
+        wrapper_event_id_hex: "c".repeat(64),
+        epoch: 1,
+        mentions_me: false,
+        edited_from: None,
+        reply_to_event_id_hex: None,
+        reply_to_author_hex: None,
+    }
+}
+
+#[test]
+fn split_respects_limit_and_char_boundaries() {
+    let content = "héllo wörld 👋 ".repeat(200);
+    let chunks = split_message_content(content.clone(), 256);
+    assert!(chunks.len() > 1);
+    assert!(chunks.iter().all(|c| c.len() <= 256));
+    assert_eq!(chunks.concat(), content);
+}
+
+#[test]
+fn split_short_content_is_single_chunk() {
+    assert_eq!(split_message_content("hi".to_string(), 256), vec!["hi".to_string()]);
+    assert_eq!(split_message_content(String::new(), 256), vec![String::new()]);
+}
+
+#[test]
+fn reassemble_out_of_order_parts() {
+    let parts = vec![part("c", 3, 3, "x"), part("a", 1, 3, "x"), part("b", 2, 3, "x")];
+    assert_eq!(reassemble_message(parts).unwrap(), "abc");
+}
+
+#[test]
+fn reassemble_rejects_missing_or_mixed_parts() {
+    assert!(reassemble_message(vec![part("a", 1, 3, "x"), part("c", 3, 3, "x")]).is_err());
+    assert!(reassemble_message(vec![part("a", 1, 2, "x"), part("b", 2, 2, "y")]).is_err());
+    assert!(reassemble_message(vec![]).is_err());
+}
+
+#[test]
+fn member_diff_reports_joins_and_removals() {
+    let alice = Keys::generate().public_key();
+    let bob = Keys::generate().public_key();
+    let carol = Keys::generate().public_key();
+    let before: BTreeSet<PublicKey> = [alice, bob].into_iter().collect();
+    let after: BTreeSet<PublicKey> = [alice, carol].into_iter().collect();
+
+    let (added, removed) = diff_member_sets(&before, &after);
+    assert_eq!(added, vec![carol.to_hex()]);
+    assert_eq!(removed, vec![bob.to_hex()]);
+
+    let (added, removed) = diff_member_sets(&before, &before);
+    assert!(added.is_empty() && removed.is_empty());
+}
+
+#[test]
+fn send_request_parses_tagged_union() {
+    let req: SendRequest = serde_json::from_str(
+        r#"{"type":"text","mls_group_id_hex":"ab","content":"hi","future_field":1}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        req,
+        SendRequest::Text {
+            mls_group_id_hex: "ab".to_string(),
+            content: "hi".to_string(),
+            ttl_secs: None,
+        }
+    );
+
+    let req: SendRequest = serde_json::from_str(
+        r#"{"type":"reaction","mls_group_id_hex":"ab","target_event_id_hex":"cd","emoji":"+"}"#,
+    )
+    .unwrap();
+    assert!(matches!(req, SendRequest::Reaction { custom_emoji_url: None, .. }));
+
+    let req: SendRequest = serde_json::from_str(
+        r#"{"type":"media","mls_group_id_hex":"ab","content":"","imeta_tags":[["url https://x"]]}"#,
+    )
+    .unwrap();
+    assert!(matches!(req, SendRequest::Media { ref imeta_tags, .. } if imeta_tags.len() == 1));
+
+    let req: SendRequest = serde_json::from_str(
+        r#"{"type":"edit","mls_group_id_hex":"ab","target_event_id_hex":"cd","content":"fixed"}"#,
+    )
+    .unwrap();
+    assert!(matches!(req, SendRequest::Edit { ref content, .. } if content == "fixed"));
+
+    let req: SendRequest = serde_json::from_str(
+        r#"{"type":"delete","mls_group_id_hex":"ab","target_event_id_hex":"cd"}"#,
+    )
+    .unwrap();
+    assert!(matches!(req, SendRequest::Delete { ref target_event_id_hex, .. } if target_event_id_hex == "cd"));
+
+    assert!(serde_json::from_str::<SendRequest>(r#"{"type":"sticker","mls_group_id_hex":"ab"}"#).is_err());
+}
+
+fn receipt(reader: &str, read_at: u64, ids: &[&str]) -> ReadReceipt {
+    ReadReceipt {
+        reader_pubkey_hex: reader.to_string(),
+        read_at,
+        message_event_ids: ids.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn receipts_aggregate_first_read_per_member() {
+    let members = vec!["author".to_string(), "bob".to_string(), "carol".to_string()];
+    let receipts = vec![
+        receipt("carol", 300, &["msg1", "msg2"]),
+        receipt("bob", 200, &["msg1"]),
+        receipt("bob", 100, &["msg1"]),
+        receipt("author", 50, &["msg1"]),
+        receipt("carol", 10, &["other"]),
+    ];
+
+    let summary = aggregate_receipts("msg1", "author", &members, &receipts);
+    assert_eq!(summary.recipient_count, 2);
+    assert!(summary.read_by_all);
+    let readers: Vec<(&str, u64)> = summary
+        .readers
+        .iter()
+        .map(|r| (r.reader_pubkey_hex.as_str(), r.read_at))
+        .collect();
+    assert_eq!(readers, vec![("bob", 100), ("carol", 300)]);
+
+    let summary = aggregate_receipts("msg2", "author", &members, &receipts);
+    assert_eq!(summary.readers.len(), 1);
+    assert!(!summary.read_by_all);
+}
+
+#[tokio::test]
+async fn flush_with_empty_outbox_is_a_no_op() {
+    let _lock = STATE_LOCK.lock().await;
+    init_test_state(Keys::generate()).await;
+    let result = flush_outbox().await.unwrap();
+    assert_eq!(result.published, 0);
+    assert_eq!(result.dropped, 0);
+    assert_eq!(result.remaining, 0);
+    state::destroy_state().await;
+}
+
+#[test]
+fn mentions_via_p_tag_or_npub_but_not_own_messages() {
+    let me = Keys::generate().public_key();
+    let bob = Keys::generate().public_key();
+    let npub = me.to_bech32().unwrap();
+
+    let tagged = Tags::new(vec![Tag::public_key(me)]);
+    assert!(mentions_pubkey(&bob, &tagged, "hey", &me));
+    assert!(mentions_pubkey(&bob, &Tags::new(vec![]), &format!("ping nostr:{npub}"), &me));
+    assert!(!mentions_pubkey(&bob, &Tags::new(vec![Tag::public_key(bob)]), "hey", &me));
+    assert!(!mentions_pubkey(&me, &tagged, "note to self", &me));
+}
+
+#[test]
+fn edit_target_reads_only_edit_marked_e_tags() {
+    let original = "a".repeat(64);
+    let edit = Tags::new(vec![Tag::parse(["e", original.as_str(), "", "edit"]).unwrap()]);
+    assert_eq!(edit_target(&edit), Some(original.clone()));
+
+    let reply = Tags::new(vec![Tag::parse(["e", original.as_str(), "", "reply"]).unwrap()]);
+    assert_eq!(edit_target(&reply), None);
+    assert_eq!(edit_target(&Tags::new(vec![])), None);
+}
+
+#[test]
+fn reply_target_prefers_marked_reply_then_last_positional() {
+    let root = "1".repeat(64);
+    let parent = "2".repeat(64);
+    let e = |id: &str, marker: &str| Tag::parse(["e", id, "", marker]).unwrap();
+
+    let marked = Tags::new(vec![e(&root, "root"), e(&parent, "reply")]);
+    assert_eq!(reply_target(&marked), Some(parent.clone()));
+
+    let root_only = Tags::new(vec![e(&root, "root")]);
+    assert_eq!(reply_target(&root_only), Some(root.clone()));
+
+    let positional = Tags::new(vec![
+        Tag::parse(["e", root.as_str()]).unwrap(),
+        Tag::parse(["e", parent.as_str()]).unwrap(),
+    ]);
+    assert_eq!(reply_target(&positional), Some(parent.clone()));
+
+    let mention_and_edit = Tags::new(vec![e(&root, "mention"), e(&parent, "edit")]);
+    assert_eq!(reply_target(&mention_and_edit), None);
+}
+
+fn stored(group: &str, id: &str, created_at: u64, content: &str) -> GroupMessage {
+    GroupMessage {
+        event_id_hex: id.to_string(),
+        created_at,
+        mls_group_id_hex: group.to_string(),
+        ..part(content, 1, 1, "x")
+    }
+}
+
+#[test]
+fn search_matches_case_insensitively_newest_first() {
+    let messages = vec![
+        stored("g1", "a", 100, "Lunch at noon?"),
+        stored("g1", "b", 300, "no LUNCH today"),
+        stored("g2", "c", 200, "lunchtime in the other group"),
+        stored("g2", "d", 400, "unrelated"),
+    ];
+
+    let hits = filter_messages_by_query(messages.clone(), "lunch", None);
+    let ids: Vec<&str> = hits.iter().map(|m| m.event_id_hex.as_str()).collect();
+    assert_eq!(ids, ["b", "c", "a"]);
+    // Results span both groups when no group filter was applied upstream
+    assert!(hits.iter().any(|m| m.mls_group_id_hex == "g2"));
+
+    assert_eq!(filter_messages_by_query(messages.clone(), "LUNCH", Some(1)).len(), 1);
+    assert!(filter_messages_by_query(messages, "dinner", None).is_empty());
+}
+
+#[tokio::test]
+async fn search_requires_initialized_state() {
+    let _lock = STATE_LOCK.lock().await;
+    state::destroy_state().await;
+    let result = message::search_messages(None, "hi".to_string(), None).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn expired_messages_are_filtered_before_paging_and_purged() {
+    let _lock = STATE_LOCK.lock().await;
+    init_test_state(Keys::generate()).await;
+    let id = group::create_group("Ephemeral".into(), String::new(), vec![], vec![], vec![RELAY.to_string()])
+        .await
+        .unwrap()
+        .mls_group_id_hex;
+
+    let kept = message::send_message(id.clone(), "kept".into(), Some(0)).await.unwrap();
+    for i in 0..3 {
+        message::send_message(id.clone(), format!("gone {i}"), Some(1)).await.unwrap();
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(2100)).await;
+
+    // The expired messages are newest, but don't use up the page.
+    let page = message::get_messages(id.clone(), Some(1), None, None).await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].event_id_hex, kept.message.event_id_hex);
+    assert!(message::get_messages(id.clone(), Some(1), Some(1), None).await.unwrap().is_empty());
+    assert_eq!(message::get_messages(id.clone(), None, None, Some(true)).await.unwrap().len(), 4);
+    assert_eq!(message::get_messages(id.clone(), Some(2), Some(1), Some(true)).await.unwrap().len(), 2);
+
+    assert_eq!(message::purge_expired_messages().await.unwrap(), 3);
+    assert_eq!(message::purge_expired_messages().await.unwrap(), 0);
+    let remaining = message::get_messages(id.clone(), None, None, Some(true)).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].content, "kept");
+    assert!(message::search_messages(Some(id), "gone".into(), None).await.unwrap().is_empty());
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn purge_reaches_expired_messages_past_the_first_page() {
+    let _lock = STATE_LOCK.lock().await;
+    init_test_state(Keys::generate()).await;
+    let id = group::create_group("Long history".into(), String::new(), vec![], vec![], vec![RELAY.to_string()])
+        .await
+        .unwrap()
+        .mls_group_id_hex;
+
+    for i in 0..3 {
+        message::send_message(id.clone(), format!("gone {i}"), Some(1)).await.unwrap();
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(2100)).await;
+    // More than MDK's default page of 1000, all newer than the expired ones.
+    for i in 0..1050 {
+        message::send_message(id.clone(), format!("kept {i}"), Some(0)).await.unwrap();
+    }
+
+    assert_eq!(message::purge_expired_messages().await.unwrap(), 3);
+    let remaining = message::get_messages(id, None, None, Some(true)).await.unwrap();
+    assert_eq!(remaining.len(), 1050);
+    assert!(remaining.iter().all(|m| m.content.starts_with("kept")));
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn reactions_are_found_past_the_first_page() {
+    let _lock = STATE_LOCK.lock().await;
+    init_test_state(Keys::generate()).await;
+    let id = group::create_group("Long history".into(), String::new(), vec![], vec![], vec![RELAY.to_string()])
+        .await
+        .unwrap()
+        .mls_group_id_hex;
+
+    let target = message::send_message(id.clone(), "react to me".into(), Some(0)).await.unwrap();
+    let target_id = target.message.event_id_hex;
+    message::send_reaction(id.clone(), target_id.clone(), "🔥".into(), None).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    // More than MDK's default page of 1000, all newer than the reaction.
+    for i in 0..1050 {
+        message::send_message(id.clone(), format!("later {i}"), Some(0)).await.unwrap();
+    }
+
+    let reactions = message::get_message_reactions(id, target_id).await.unwrap();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].content, "🔥");
+
+    state::destroy_state().await;
+}
+
+#[tokio::test]
+async fn receipts_are_found_past_the_first_page() {
+    let _lock = STATE_LOCK.lock().await;
+    let alice = Keys::generate();
+    let bob = Keys::generate();
+    let bob_kp = key_package_for(&bob).await;
+
+    init_test_state(alice.clone()).await;
+    let created = group::create_group("Receipts".into(), String::new(), vec![], vec![bob_kp], vec![RELAY.to_string()])
+        .await
+        .unwrap();
+    let id = created.mls_group_id_hex.clone();
+    group::merge_pending_commit(id.clone()).await.unwrap();
+    let sent = message::send_message(id.clone(), "read me".into(), Some(0)).await.unwrap();
+
+    init_test_state(bob.clone()).await;
+    let welcome = invite::process_welcome("cd".repeat(32), created.welcome_rumors_json[0].clone())
+        .await
+        .unwrap();
+    invite::accept_welcome(welcome.welcome_event_id).await.unwrap();
+    message::process_message(sent.event_json).await.unwrap();
+    let target_id = sent.message.event_id_hex;
+    message::send_read_receipt(id.clone(), vec![target_id.clone()]).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    // More than MDK's default page of 1000, all newer than the receipt.
+    for i in 0..1050 {
+        message::send_message(id.clone(), format!("later {i}"), Some(0)).await.unwrap();
+    }
+
+    let summary = message::get_message_receipts(id, target_id).await.unwrap();
+    let readers: Vec<String> = summary.readers.into_iter().map(|r| r.reader_pubkey_hex).collect();
+    assert_eq!(readers, vec![bob.public_key().to_hex()]);
+    assert!(summary.read_by_all);
+
+    state::destroy_state().await;
+}
@@ -0,0 +1,44 @@
+use nostr_sdk::prelude::*;
+use rust_lib_burrow_app::api::relay::{ensure_group_relays, parse_relay_roles};
+
+#[test]
+fn relay_roles_parse_read_and_write_lists() {
+    let roles = parse_relay_roles(
+        r#"{"read": ["wss://inbox.example"], "write": ["wss://outbox.example", "wss://inbox.example"]}"#,
+    )
+    .unwrap();
+    assert_eq!(roles.read, vec!["wss://inbox.example"]);
+    assert_eq!(roles.write.len(), 2);
+}
+
+#[test]
+fn relay_roles_empty_object_clears() {
+    let roles = parse_relay_roles("{}").unwrap();
+    assert!(roles.read.is_empty());
+    assert!(roles.write.is_empty());
+}
+
+#[test]
+fn relay_roles_reject_bad_urls() {
+    assert!(parse_relay_roles(r#"{"read": ["not a url"]}"#).is_err());
+    assert!(parse_relay_roles("[]").is_err());
+}
+
+#[tokio::test]
+async fn group_relays_keep_existing_roles() {
+    let client = Client::default();
+    let outbox = "wss://outbox.example";
+    let fresh = "wss://group.example";
+    client.add_write_relay(outbox).await.unwrap();
+
+    let usable = ensure_group_relays(&client, &[outbox.to_string(), fresh.to_string(), "not a url".to_string()]).await;
+    assert_eq!(usable.len(), 2);
+
+    let outbox_relay = client.relay(outbox).await.unwrap();
+    assert!(outbox_relay.flags().has_write());
+    assert!(!outbox_relay.flags().has_read());
+
+    let fresh_relay = client.relay(fresh).await.unwrap();
+    assert!(fresh_relay.flags().has_read() && fresh_relay.flags().has_write());
+    client.disconnect().await;
+}
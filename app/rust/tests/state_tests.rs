@@ -14,9 +14,9 @@ async fn state_not_initialized_after_destroy() {
 }
 
 #[tokio::test]
-async fn with_state_errors_when_not_initialized() {
+async fn with_state_read_errors_when_not_initialized() {
     state::destroy_state().await;
-    let result: Result<i32, BurrowError> = state::with_state(|_s| Ok(42)).await;
+    let result: Result<i32, BurrowError> = state::with_state_read(|_s| Ok(42)).await;
     assert!(result.is_err());
     assert!(result.unwrap_err().message.contains("not initialized"));
 }
@@ -40,7 +40,7 @@ async fn init_and_use_state() {
     assert!(state::is_initialized().await);
 
     let result: Result<String, BurrowError> =
-        state::with_state(|s| Ok(s.keys.public_key().to_hex())).await;
+        state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await;
     assert_eq!(result.unwrap(), pubkey_hex);
 
     state::destroy_state().await;
@@ -54,14 +54,25 @@ async fn destroy_then_reinit() {
 
     let keys1 = nostr_sdk::prelude::Keys::generate();
     let _: () = state::init_state(keys1).await.unwrap();
-    let pk1: String = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await.unwrap();
+    let pk1: String = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await.unwrap();
 
     state::destroy_state().await;
 
     let keys2 = nostr_sdk::prelude::Keys::generate();
     let _: () = state::init_state(keys2).await.unwrap();
-    let pk2: String = state::with_state(|s| Ok(s.keys.public_key().to_hex())).await.unwrap();
+    let pk2: String = state::with_state_read(|s| Ok(s.signer.public_key().to_hex())).await.unwrap();
 
     assert_ne!(pk1, pk2);
     state::destroy_state().await;
 }
+
+#[test]
+fn relay_ops_limit_is_clamped_to_one() {
+    state::set_max_concurrent_relay_ops(0);
+    assert_eq!(state::get_max_concurrent_relay_ops(), 1);
+    state::set_max_concurrent_relay_ops(state::DEFAULT_MAX_CONCURRENT_RELAY_OPS as u32);
+    assert_eq!(
+        state::get_max_concurrent_relay_ops(),
+        state::DEFAULT_MAX_CONCURRENT_RELAY_OPS as u32
+    );
+}
@@ -62,3 +62,25 @@ fn test_get_transcript_text_empty() {
     let result = get_transcript_text();
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_filter_transcript_by_confidence() {
+    let segment = |id: &str, confidence: f64| TranscriptSegment {
+        id: id.to_string(),
+        speaker_id: "abc".to_string(),
+        speaker_name: "Alice".to_string(),
+        text: "hello".to_string(),
+        start_ms: 0,
+        end_ms: 1000,
+        confidence,
+        language: "en".to_string(),
+        is_final: true,
+    };
+    let json = serde_json::to_string(&vec![segment("a", 0.9), segment("b", 0.2)]).unwrap();
+
+    let filtered = filter_transcript_by_confidence(json, 0.5).unwrap();
+    let kept: Vec<TranscriptSegment> = serde_json::from_str(&filtered).unwrap();
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].id, "a");
+    assert!(filter_transcript_by_confidence("nope".to_string(), 0.5).is_err());
+}
@@ -0,0 +1,20 @@
+import 'package:flutter_test/flutter_test.dart';
+import 'package:burrow_app/utils/call_id.dart';
+
+void main() {
+  group('newCallId', () {
+    test('is a hyphenated version 4 UUID', () {
+      final uuid = RegExp(
+        r'^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$',
+      );
+      for (var i = 0; i < 100; i++) {
+        expect(newCallId(), matches(uuid));
+      }
+    });
+
+    test('is unique per call', () {
+      final ids = List.generate(1000, (_) => newCallId()).toSet();
+      expect(ids, hasLength(1000));
+    });
+  });
+}
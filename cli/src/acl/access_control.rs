@@ -1,7 +1,13 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Window over which per-contact rate limits count messages.
+pub const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OwnerInfo {
@@ -29,6 +35,30 @@ impl Default for AclSettings {
     }
 }
 
+/// An `allowedContacts` entry. A plain pubkey string is allowed everywhere;
+/// an object with `groups` is only allowed in those Nostr group IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContactRule {
+    Global(String),
+    Scoped { pubkey: String, groups: Vec<String> },
+}
+
+impl ContactRule {
+    pub fn pubkey(&self) -> &str {
+        match self {
+            ContactRule::Global(pubkey) | ContactRule::Scoped { pubkey, .. } => pubkey,
+        }
+    }
+
+    pub fn allows_group(&self, group_id: &str) -> bool {
+        match self {
+            ContactRule::Global(_) => true,
+            ContactRule::Scoped { groups, .. } => groups.iter().any(|g| g == group_id),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AclConfig {
     #[serde(default = "default_version")]
@@ -37,9 +67,13 @@ pub struct AclConfig {
     #[serde(default = "default_policy", rename = "defaultPolicy")]
     pub default_policy: String,
     #[serde(default, rename = "allowedContacts")]
-    pub allowed_contacts: Vec<String>,
+    pub allowed_contacts: Vec<ContactRule>,
     #[serde(default, rename = "allowedGroups")]
     pub allowed_groups: Vec<String>,
+    /// Max messages per rolling minute, by contact hex. Contacts not listed
+    /// are unlimited.
+    #[serde(default, rename = "rateLimits", skip_serializing_if = "BTreeMap::is_empty")]
+    pub rate_limits: BTreeMap<String, u32>,
     #[serde(default)]
     pub settings: AclSettings,
 }
@@ -47,9 +81,45 @@ pub struct AclConfig {
 fn default_version() -> u32 { 1 }
 fn default_policy() -> String { "ignore".into() }
 
+/// Outcome of an access check, with the rule that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclDecision {
+    /// No owner set, so access control is off.
+    NoOwnerConfigured,
+    /// Sender is the owner.
+    Owner,
+    /// Sender is in `allowedContacts`, globally or for this group.
+    AllowedContact,
+    /// Group is in `allowedGroups`.
+    AllowedGroup,
+    /// No rule matched.
+    Denied,
+    /// Allowed by a rule, but over the sender's rate limit.
+    RateLimited,
+}
+
+impl AclDecision {
+    pub fn is_allowed(self) -> bool {
+        !matches!(self, AclDecision::Denied | AclDecision::RateLimited)
+    }
+
+    pub fn reason(self) -> &'static str {
+        match self {
+            AclDecision::NoOwnerConfigured => "no owner configured — access control is off",
+            AclDecision::Owner => "sender is the owner",
+            AclDecision::AllowedContact => "sender is an allowlisted contact",
+            AclDecision::AllowedGroup => "group is allowlisted",
+            AclDecision::Denied => "sender is not the owner or an allowlisted contact, and the group is not allowlisted",
+            AclDecision::RateLimited => "sender is over their per-minute rate limit",
+        }
+    }
+}
+
 pub struct AccessControl {
     config_path: PathBuf,
     pub config: AclConfig,
+    /// Times of recently allowed messages from rate-limited contacts.
+    recent: Mutex<HashMap<String, VecDeque<Instant>>>,
 }
 
 impl AccessControl {
@@ -66,10 +136,11 @@ impl AccessControl {
                 default_policy: "ignore".into(),
                 allowed_contacts: vec![],
                 allowed_groups: vec![],
+                rate_limits: BTreeMap::new(),
                 settings: AclSettings::default(),
             }
         };
-        Ok(Self { config_path, config })
+        Ok(Self { config_path, config, recent: Mutex::new(HashMap::new()) })
     }
 
     fn save(&self) -> Result<()> {
@@ -91,31 +162,129 @@ impl AccessControl {
         self.config.owner.hex.clone()
     }
 
-    /// Check if a sender is allowed to send messages in a group.
+    /// Check if a sender is allowed to send a message in a group now. The
+    /// message counts against the sender's rate limit.
     pub fn is_allowed(&self, sender_hex: &str, group_id: &str) -> bool {
+        self.check_message(sender_hex, group_id, Instant::now()).is_allowed()
+    }
+
+    /// Decide on a message arriving at `now`, applying the sender's rate
+    /// limit on top of `evaluate`. Allowed messages are counted; denied ones
+    /// are not, so a blocked burst doesn't extend the block.
+    pub fn check_message(&self, sender_hex: &str, group_id: &str, now: Instant) -> AclDecision {
+        let decision = self.evaluate(sender_hex, group_id);
+        if !decision.is_allowed() {
+            return decision;
+        }
+        let Some(&limit) = self.config.rate_limits.get(sender_hex) else {
+            return decision;
+        };
+        let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        let times = recent.entry(sender_hex.to_string()).or_default();
+        while times.front().is_some_and(|t| now.duration_since(*t) >= RATE_LIMIT_WINDOW) {
+            times.pop_front();
+        }
+        if times.len() >= limit as usize {
+            return AclDecision::RateLimited;
+        }
+        times.push_back(now);
+        decision
+    }
+
+    /// Decide whether a sender may send in a group, and why.
+    pub fn evaluate(&self, sender_hex: &str, group_id: &str) -> AclDecision {
+        let owner = self.owner_hex();
+        if owner.is_empty() {
+            return AclDecision::NoOwnerConfigured;
+        }
+        if sender_hex == owner {
+            return AclDecision::Owner;
+        }
+        if self.config.allowed_contacts.iter().any(|c| c.pubkey() == sender_hex && c.allows_group(group_id)) {
+            return AclDecision::AllowedContact;
+        }
+        if self.config.allowed_groups.iter().any(|g| g == group_id) {
+            return AclDecision::AllowedGroup;
+        }
+        AclDecision::Denied
+    }
+
+    /// Decide whether a sender may add us to a group. The sender's identity
+    /// decides: the inviter picks the welcome's group ID, so an allowlisted
+    /// group ID proves nothing about who is behind it. A contact scoped to
+    /// some groups may only invite us into those; `group_id` is the
+    /// welcome's Nostr group ID, and if it's unknown they are denied.
+    pub fn evaluate_welcome(&self, sender_hex: &str, group_id: Option<&str>) -> AclDecision {
         let owner = self.owner_hex();
         if owner.is_empty() {
-            return true; // No ACL configured
+            return AclDecision::NoOwnerConfigured;
         }
         if sender_hex == owner {
-            return true; // Owner always allowed
+            return AclDecision::Owner;
+        }
+        let in_scope = |c: &ContactRule| match c {
+            ContactRule::Global(_) => true,
+            ContactRule::Scoped { .. } => group_id.is_some_and(|g| c.allows_group(g)),
+        };
+        if self.config.allowed_contacts.iter().any(|c| c.pubkey() == sender_hex && in_scope(c)) {
+            return AclDecision::AllowedContact;
+        }
+        AclDecision::Denied
+    }
+
+    /// Where the effective owner comes from: an env var or the config file.
+    pub fn owner_source(&self) -> &'static str {
+        if std::env::var("BURROW_OWNER_HEX").is_ok() {
+            "BURROW_OWNER_HEX"
+        } else if std::env::var("BURROW_OWNER_NPUB").ok().and_then(|n| npub_to_hex(&n)).is_some() {
+            "BURROW_OWNER_NPUB"
+        } else {
+            "access-control.json"
+        }
+    }
+
+    fn has_contact(&self, hex: &str) -> bool {
+        self.config.allowed_contacts.iter().any(|c| c.pubkey() == hex)
+    }
+
+    /// Allow a contact in `groups` only, or everywhere if `groups` is empty.
+    /// Replaces any existing rule for the contact.
+    pub fn add_contact(&mut self, hex: &str, groups: &[String]) -> Result<()> {
+        let rule = if groups.is_empty() {
+            ContactRule::Global(hex.to_string())
+        } else {
+            ContactRule::Scoped { pubkey: hex.to_string(), groups: groups.to_vec() }
+        };
+        if let Some(existing) = self.config.allowed_contacts.iter_mut().find(|c| c.pubkey() == hex) {
+            if *existing == rule {
+                return Ok(());
+            }
+            *existing = rule;
+        } else {
+            self.config.allowed_contacts.push(rule);
         }
-        let contact_ok = self.config.allowed_contacts.iter().any(|c| c == sender_hex);
-        let group_ok = self.config.allowed_groups.iter().any(|g| g == group_id);
-        contact_ok || group_ok
+        self.save()
     }
 
-    pub fn add_contact(&mut self, hex: &str) -> Result<()> {
-        if !self.config.allowed_contacts.contains(&hex.to_string()) {
-            self.config.allowed_contacts.push(hex.to_string());
+    /// Add several global contacts with a single save. Returns how many were
+    /// new; contacts that already have a rule are left as they are.
+    pub fn add_contacts(&mut self, hexes: &[String]) -> Result<usize> {
+        let before = self.config.allowed_contacts.len();
+        for hex in hexes {
+            if !self.has_contact(hex) {
+                self.config.allowed_contacts.push(ContactRule::Global(hex.clone()));
+            }
+        }
+        let added = self.config.allowed_contacts.len() - before;
+        if added > 0 {
             self.save()?;
         }
-        Ok(())
+        Ok(added)
     }
 
     pub fn remove_contact(&mut self, hex: &str) -> Result<bool> {
         let before = self.config.allowed_contacts.len();
-        self.config.allowed_contacts.retain(|c| c != hex);
+        self.config.allowed_contacts.retain(|c| c.pubkey() != hex);
         if self.config.allowed_contacts.len() < before {
             self.save()?;
             Ok(true)
@@ -132,6 +301,16 @@ impl AccessControl {
         Ok(())
     }
 
+    /// Limit a contact to `per_minute` messages per rolling minute, or
+    /// remove their limit with None.
+    pub fn set_rate_limit(&mut self, hex: &str, per_minute: Option<u32>) -> Result<()> {
+        match per_minute {
+            Some(limit) => self.config.rate_limits.insert(hex.to_string(), limit),
+            None => self.config.rate_limits.remove(hex),
+        };
+        self.save()
+    }
+
     pub fn remove_group(&mut self, group_id: &str) -> Result<bool> {
         let before = self.config.allowed_groups.len();
         self.config.allowed_groups.retain(|g| g != group_id);
@@ -172,3 +351,134 @@ pub fn resolve_to_hex(input: &str) -> Result<String> {
     }
     anyhow::bail!("Invalid pubkey: {}. Provide 64-char hex or npub1...", input)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    const FRIEND: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+    const STRANGER: &str = "3333333333333333333333333333333333333333333333333333333333333333";
+    const GUEST: &str = "4444444444444444444444444444444444444444444444444444444444444444";
+
+    fn acl(owner: &str) -> AccessControl {
+        AccessControl {
+            config_path: PathBuf::new(),
+            config: AclConfig {
+                version: 1,
+                owner: OwnerInfo { npub: String::new(), hex: owner.into(), note: String::new() },
+                default_policy: "ignore".into(),
+                allowed_contacts: vec![
+                    ContactRule::Global(FRIEND.into()),
+                    ContactRule::Scoped { pubkey: GUEST.into(), groups: vec!["guest-group".into()] },
+                ],
+                allowed_groups: vec!["open-group".into()],
+                rate_limits: BTreeMap::from([(FRIEND.to_string(), 3)]),
+                settings: AclSettings::default(),
+            },
+            recent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn welcomes_are_gated_on_sender() {
+        let acl = acl(OWNER);
+        assert_eq!(acl.evaluate_welcome(OWNER, Some("some-group")), AclDecision::Owner);
+        assert_eq!(acl.evaluate_welcome(FRIEND, Some("some-group")), AclDecision::AllowedContact);
+        assert_eq!(acl.evaluate_welcome(FRIEND, None), AclDecision::AllowedContact);
+        assert_eq!(acl.evaluate_welcome(STRANGER, Some("some-group")), AclDecision::Denied);
+    }
+
+    #[test]
+    fn scoped_contact_may_only_invite_into_its_groups() {
+        let acl = acl(OWNER);
+        assert_eq!(acl.evaluate_welcome(GUEST, Some("guest-group")), AclDecision::AllowedContact);
+        assert_eq!(acl.evaluate_welcome(GUEST, Some("some-group")), AclDecision::Denied);
+        assert_eq!(acl.evaluate_welcome(GUEST, Some("open-group")), AclDecision::Denied);
+        assert_eq!(acl.evaluate_welcome(GUEST, None), AclDecision::Denied);
+    }
+
+    #[test]
+    fn allowlisted_group_id_does_not_admit_unknown_inviter() {
+        let acl = acl(OWNER);
+        // Messages in an allowlisted group are fine from anyone...
+        assert!(acl.evaluate(STRANGER, "open-group").is_allowed());
+        // ...but a stranger presenting that group ID in a welcome is not.
+        assert!(!acl.evaluate_welcome(STRANGER, Some("open-group")).is_allowed());
+    }
+
+    #[test]
+    fn rate_limit_window_slides() {
+        let acl = acl(OWNER);
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+
+        for secs in [0, 10, 20] {
+            assert_eq!(acl.check_message(FRIEND, "some-group", at(secs)), AclDecision::AllowedContact);
+        }
+        assert_eq!(acl.check_message(FRIEND, "some-group", at(30)), AclDecision::RateLimited);
+        assert_eq!(acl.check_message(FRIEND, "some-group", at(59)), AclDecision::RateLimited);
+
+        // The first message leaves the window at 60s, freeing one slot.
+        assert_eq!(acl.check_message(FRIEND, "some-group", at(60)), AclDecision::AllowedContact);
+        assert_eq!(acl.check_message(FRIEND, "some-group", at(61)), AclDecision::RateLimited);
+
+        // After a quiet minute the full allowance is back.
+        for secs in [200, 201, 202] {
+            assert_eq!(acl.check_message(FRIEND, "some-group", at(secs)), AclDecision::AllowedContact);
+        }
+    }
+
+    #[test]
+    fn rate_limits_only_apply_to_listed_contacts() {
+        let acl = acl(OWNER);
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert_eq!(acl.check_message(OWNER, "some-group", now), AclDecision::Owner);
+        }
+        assert_eq!(acl.check_message(STRANGER, "some-group", now), AclDecision::Denied);
+    }
+
+    #[test]
+    fn scoped_contact_is_allowed_in_listed_groups() {
+        assert_eq!(acl(OWNER).evaluate(GUEST, "guest-group"), AclDecision::AllowedContact);
+    }
+
+    #[test]
+    fn scoped_contact_is_denied_elsewhere() {
+        let acl = acl(OWNER);
+        assert_eq!(acl.evaluate(GUEST, "some-group"), AclDecision::Denied);
+        // Allowlisted groups still admit anyone.
+        assert_eq!(acl.evaluate(GUEST, "open-group"), AclDecision::AllowedGroup);
+    }
+
+    #[test]
+    fn global_contact_is_allowed_everywhere() {
+        let acl = acl(OWNER);
+        assert_eq!(acl.evaluate(FRIEND, "some-group"), AclDecision::AllowedContact);
+        assert_eq!(acl.evaluate(FRIEND, "guest-group"), AclDecision::AllowedContact);
+    }
+
+    #[test]
+    fn contact_entries_parse_as_strings_or_scoped_objects() {
+        let json = format!(
+            r#"{{"owner": {{"hex": "{OWNER}"}}, "allowedContacts": ["{FRIEND}", {{"pubkey": "{GUEST}", "groups": ["g1"]}}]}}"#
+        );
+        let config: AclConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            config.allowed_contacts,
+            vec![
+                ContactRule::Global(FRIEND.into()),
+                ContactRule::Scoped { pubkey: GUEST.into(), groups: vec!["g1".into()] },
+            ]
+        );
+        // Plain entries are written back as plain strings.
+        let out = serde_json::to_value(&config).unwrap();
+        assert_eq!(out["allowedContacts"][0], serde_json::json!(FRIEND));
+    }
+
+    #[test]
+    fn no_owner_allows_every_welcome() {
+        assert!(acl("").evaluate_welcome(STRANGER, None).is_allowed());
+    }
+}
@@ -49,6 +49,20 @@ pub fn log_message(data_dir: &Path, sender: &str, group_id: &str, allowed: bool,
     let _ = log_entry(data_dir, &entry);
 }
 
+/// Record a message dropped for exceeding the sender's rate limit, apart
+/// from ordinary ACL denials.
+pub fn log_rate_limited(data_dir: &Path, sender: &str, group_id: &str) {
+    let entry = AuditEntry {
+        timestamp: Local::now().to_rfc3339(),
+        entry_type: "rate_limited".into(),
+        sender_pubkey: Some(sender.into()),
+        group_id: Some(group_id.into()),
+        allowed: false,
+        details: None,
+    };
+    let _ = log_entry(data_dir, &entry);
+}
+
 pub fn log_access_change(data_dir: &Path, details: &str) {
     let entry = AuditEntry {
         timestamp: Local::now().to_rfc3339(),
@@ -81,3 +95,97 @@ pub fn read_audit_log(data_dir: &Path, days: u32) -> Result<Vec<String>> {
     lines.sort();
     Ok(lines)
 }
+
+/// Which audit entries to keep when querying the log.
+#[derive(Debug, Default)]
+pub struct AuditFilter {
+    /// Only entries from this sender (hex).
+    pub sender: Option<String>,
+    /// Only entries where access was denied (including rate limits).
+    pub denied_only: bool,
+}
+
+impl AuditFilter {
+    pub fn is_empty(&self) -> bool {
+        self.sender.is_none() && !self.denied_only
+    }
+
+    pub fn matches(&self, entry: &serde_json::Value) -> bool {
+        if let Some(sender) = &self.sender {
+            if entry["senderPubkey"].as_str() != Some(sender.as_str()) {
+                return false;
+            }
+        }
+        !(self.denied_only && entry["allowed"].as_bool().unwrap_or(false))
+    }
+}
+
+/// Parse audit lines and keep those matching `filter`. Lines that aren't
+/// valid JSON are skipped.
+pub fn filter_entries(lines: &[String], filter: &AuditFilter) -> Vec<serde_json::Value> {
+    lines
+        .iter()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|entry| filter.matches(entry))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const BOB: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    /// Write a few entries to a fresh log named after the calling test, and
+    /// read them back.
+    fn synthetic_log(test: &str) -> Vec<String> {
+        let dir = std::env::temp_dir().join(format!("burrow-audit-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        log_message(&dir, ALICE, "g1", true, None);
+        log_message(&dir, BOB, "g1", false, None);
+        log_rate_limited(&dir, ALICE, "g2");
+        log_access_change(&dir, "Added contact");
+        fs::OpenOptions::new().append(true).open(today_file(&dir)).unwrap()
+            .write_all(b"not json\n").unwrap();
+        let lines = read_audit_log(&dir, 1).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        lines
+    }
+
+    fn types(entries: &[serde_json::Value]) -> Vec<(&str, Option<&str>)> {
+        entries.iter().map(|e| (e["type"].as_str().unwrap(), e["senderPubkey"].as_str())).collect()
+    }
+
+    #[test]
+    fn empty_filter_keeps_every_parsed_entry() {
+        let lines = synthetic_log("empty_filter_keeps_every_parsed_entry");
+        assert_eq!(lines.len(), 5);
+        assert_eq!(filter_entries(&lines, &AuditFilter::default()).len(), 4);
+    }
+
+    #[test]
+    fn filters_by_sender() {
+        let lines = synthetic_log("filters_by_sender");
+        let filter = AuditFilter { sender: Some(ALICE.into()), denied_only: false };
+        let mut got = types(&filter_entries(&lines, &filter));
+        got.sort();
+        assert_eq!(got, vec![("message", Some(ALICE)), ("rate_limited", Some(ALICE))]);
+    }
+
+    #[test]
+    fn filters_denied_only() {
+        let lines = synthetic_log("filters_denied_only");
+        let filter = AuditFilter { sender: None, denied_only: true };
+        let mut got = types(&filter_entries(&lines, &filter));
+        got.sort();
+        assert_eq!(got, vec![("message", Some(BOB)), ("rate_limited", Some(ALICE))]);
+    }
+
+    #[test]
+    fn combines_sender_and_denied_filters() {
+        let lines = synthetic_log("combines_sender_and_denied_filters");
+        let filter = AuditFilter { sender: Some(BOB.into()), denied_only: true };
+        assert_eq!(types(&filter_entries(&lines, &filter)), vec![("message", Some(BOB))]);
+    }
+}
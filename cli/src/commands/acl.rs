@@ -1,8 +1,11 @@
 use anyhow::Result;
+use nostr_sdk::prelude::*;
+use std::io::BufRead;
 
-use crate::acl::access_control::{self, AccessControl};
+use crate::acl::access_control::{self, AccessControl, ContactRule};
 use crate::acl::audit;
 use crate::config;
+use crate::storage::file_store::FileStore;
 
 pub fn show(data_dir: Option<String>) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
@@ -22,7 +25,12 @@ pub fn show(data_dir: Option<String>) -> Result<()> {
         println!("  (none — only owner can send messages)");
     } else {
         for contact in &c.allowed_contacts {
-            println!("  • {}", contact);
+            match contact {
+                ContactRule::Global(pubkey) => println!("  • {}", pubkey),
+                ContactRule::Scoped { pubkey, groups } => {
+                    println!("  • {} (only in: {})", pubkey, groups.join(", "))
+                }
+            }
         }
     }
     println!("\nAllowed Groups ({}):", c.allowed_groups.len());
@@ -33,19 +41,120 @@ pub fn show(data_dir: Option<String>) -> Result<()> {
             println!("  • {}", g);
         }
     }
+    if !c.rate_limits.is_empty() {
+        println!("\nRate Limits ({}):", c.rate_limits.len());
+        for (contact, limit) in &c.rate_limits {
+            println!("  • {} — {}/min", contact, limit);
+        }
+    }
     println!("\nSettings:");
     println!("  Log rejected content: {}", c.settings.log_rejected_content);
     println!("  Audit enabled: {}", c.settings.audit_enabled);
     Ok(())
 }
 
-pub fn add_contact(pubkey: String, data_dir: Option<String>) -> Result<()> {
+/// Print the decision the daemon would make for `pubkey` sending in
+/// `group_id`, and the rule behind it.
+pub fn test(pubkey: String, group_id: String, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let sender = access_control::resolve_to_hex(&pubkey)?;
+    let acl = AccessControl::load(&data)?;
+
+    // The daemon checks against the Nostr group ID (h tag); accept any
+    // prefix the other commands accept and map it.
+    let group = FileStore::new(&data)?.find_group_by_prefix(&group_id)?;
+    let nostr_group_id = group
+        .as_ref()
+        .map(|g| g.nostr_group_id_hex.clone())
+        .unwrap_or_else(|| group_id.clone());
+
+    let decision = acl.evaluate(&sender, &nostr_group_id);
+    println!("🔐 ACL test");
+    println!("   Sender: {}", sender);
+    match &group {
+        Some(g) => println!("   Group:  {} ({})", g.name, nostr_group_id),
+        None => println!("   Group:  {} (⚠️ not in local store, checked as given)", nostr_group_id),
+    }
+    let owner = acl.owner_hex();
+    if owner.is_empty() {
+        println!("   Owner:  (none)");
+    } else {
+        println!("   Owner:  {} (from {})", owner, acl.owner_source());
+    }
+    if decision.is_allowed() {
+        println!("✅ ALLOW — {}", decision.reason());
+    } else {
+        println!("🚫 DENY ({}) — {}", acl.config.default_policy, decision.reason());
+    }
+    Ok(())
+}
+
+/// Allowlist a contact everywhere, or only in `groups` when any are given.
+/// Groups are matched like elsewhere (ID prefix or name) and stored as their
+/// Nostr group ID, which is what the daemon checks.
+pub fn add_contact(pubkey: String, groups: Vec<String>, data_dir: Option<String>) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let hex = access_control::resolve_to_hex(&pubkey)?;
+    let store = FileStore::new(&data)?;
+    let mut scope = Vec::new();
+    for group in &groups {
+        let nostr_group_id = match store.find_group_by_prefix(group)? {
+            Some(g) => g.nostr_group_id_hex,
+            None => group.clone(),
+        };
+        if !scope.contains(&nostr_group_id) {
+            scope.push(nostr_group_id);
+        }
+    }
+    let mut acl = AccessControl::load(&data)?;
+    acl.add_contact(&hex, &scope)?;
+    if scope.is_empty() {
+        audit::log_access_change(&data, &format!("Added contact: {}", hex));
+        println!("✅ Added contact: {}", hex);
+    } else {
+        audit::log_access_change(&data, &format!("Added contact: {} (groups: {})", hex, scope.join(", ")));
+        println!("✅ Added contact: {} (only in {} group(s))", hex, scope.len());
+    }
+    Ok(())
+}
+
+/// Bulk-add contacts from newline-delimited pubkeys (hex or npub) on stdin.
+///
+/// Blank lines and `#` comments are ignored. Invalid lines are reported with
+/// their line number and skipped; the rest are still imported.
+pub fn import(data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
     let mut acl = AccessControl::load(&data)?;
-    acl.add_contact(&hex)?;
-    audit::log_access_change(&data, &format!("Added contact: {}", hex));
-    println!("✅ Added contact: {}", hex);
+
+    let mut valid: Vec<String> = Vec::new();
+    let mut invalid = 0usize;
+    for (i, line) in std::io::stdin().lock().lines().enumerate() {
+        let line = line?;
+        let input = line.trim();
+        if input.is_empty() || input.starts_with('#') {
+            continue;
+        }
+        match PublicKey::from_hex(input).or_else(|_| PublicKey::from_bech32(input)) {
+            Ok(pk) => valid.push(pk.to_hex()),
+            Err(e) => {
+                eprintln!("⚠️ Line {}: invalid pubkey '{}': {}", i + 1, input, e);
+                invalid += 1;
+            }
+        }
+    }
+
+    let mut unique: Vec<String> = Vec::new();
+    for hex in valid.iter() {
+        if !unique.contains(hex) {
+            unique.push(hex.clone());
+        }
+    }
+    let added = acl.add_contacts(&unique)?;
+    let skipped = valid.len() - added;
+    if added > 0 {
+        audit::log_access_change(&data, &format!("Imported {} contact(s)", added));
+    }
+    println!("✅ Imported contacts: {} added, {} skipped (already allowed or duplicate), {} invalid", added, skipped, invalid);
     Ok(())
 }
 
@@ -62,6 +171,24 @@ pub fn remove_contact(pubkey: String, data_dir: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Limit a contact to `per_minute` messages per rolling minute; 0 removes
+/// the limit.
+pub fn rate_limit(pubkey: String, per_minute: u32, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let hex = access_control::resolve_to_hex(&pubkey)?;
+    let mut acl = AccessControl::load(&data)?;
+    if per_minute == 0 {
+        acl.set_rate_limit(&hex, None)?;
+        audit::log_access_change(&data, &format!("Removed rate limit: {}", hex));
+        println!("✅ Removed rate limit for {}", hex);
+    } else {
+        acl.set_rate_limit(&hex, Some(per_minute))?;
+        audit::log_access_change(&data, &format!("Set rate limit: {} at {}/min", hex, per_minute));
+        println!("✅ Limited {} to {} message(s) per minute", hex, per_minute);
+    }
+    Ok(())
+}
+
 pub fn add_group(group_id: String, data_dir: Option<String>) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let mut acl = AccessControl::load(&data)?;
@@ -83,11 +210,39 @@ pub fn remove_group(group_id: String, data_dir: Option<String>) -> Result<()> {
     Ok(())
 }
 
-pub fn show_audit(data_dir: Option<String>, days: u32) -> Result<()> {
+/// Print the audit log for the last `days` days, optionally narrowed to one
+/// sender and/or to denials, as a table or a JSON array.
+pub fn show_audit(
+    data_dir: Option<String>,
+    days: u32,
+    sender: Option<String>,
+    denied_only: bool,
+    json: bool,
+) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
+    let filter = audit::AuditFilter {
+        sender: sender.as_deref().map(access_control::resolve_to_hex).transpose()?,
+        denied_only,
+    };
     let lines = audit::read_audit_log(&data, days)?;
+
+    if json {
+        let entries = audit::filter_entries(&lines, &filter);
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    // Unparseable lines are shown as-is, unless a filter is narrowing the view.
+    let lines: Vec<String> = if filter.is_empty() {
+        lines
+    } else {
+        lines
+            .into_iter()
+            .filter(|l| serde_json::from_str(l).is_ok_and(|e| filter.matches(&e)))
+            .collect()
+    };
     if lines.is_empty() {
-        println!("No audit entries in the last {} day(s).", days);
+        println!("No matching audit entries in the last {} day(s).", days);
         return Ok(());
     }
     println!("📋 Audit log (last {} day(s), {} entries):", days, lines.len());
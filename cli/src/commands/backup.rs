@@ -0,0 +1,397 @@
+//! Passphrase-encrypted backups of the identity and local state.
+//!
+//! A backup is a tar archive of the secret key, the MLS database and the
+//! file store, zstd-compressed and sealed as
+//! `"BURROWBAK1" | salt [16] | nonce [24] | ciphertext`, where the key is
+//! Argon2id(passphrase, salt) and the cipher is XChaCha20-Poly1305.
+//!
+//! The MLS database is copied as-is, so export and import refuse to run
+//! while a daemon is live on the same data dir.
+
+use anyhow::{Context, Result};
+use nostr_sdk::prelude::*;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::commands::daemon;
+use crate::config;
+
+const BACKUP_MAGIC: &[u8; 10] = b"BURROWBAK1";
+const MIN_PASSPHRASE_LEN: usize = 8;
+/// Archive entry holding the secret key; it's restored to the key path,
+/// not the data dir.
+const KEY_ENTRY: &str = "secret.key";
+/// What gets backed up from the data dir. Media and the audit log are left
+/// out: media can be re-downloaded and the audit log is per-machine.
+const DATA_ENTRIES: &[&str] = &[
+    "mls.sqlite",
+    "mls.sqlite-wal",
+    "mls.sqlite-shm",
+    "groups",
+    "messages",
+    "read-receipts",
+    "access-control.json",
+    config::SETTINGS_FILE,
+];
+
+pub fn export(out: String, key_path: Option<String>, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let kp = key_path.map(PathBuf::from).unwrap_or_else(config::default_key_path);
+    let passphrase = read_passphrase(true)?;
+    export_to(&data, &kp, Path::new(&out), &passphrase)?;
+    println!("✅ Backup written to {}", out);
+    println!("   Keep the passphrase safe — the backup can't be opened without it.");
+    Ok(())
+}
+
+pub fn import(input: String, key_path: Option<String>, data_dir: Option<String>, force: bool) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let kp = key_path.map(PathBuf::from).unwrap_or_else(config::default_key_path);
+    let passphrase = read_passphrase(false)?;
+    let npub = import_from(Path::new(&input), &data, &kp, force, &passphrase)?;
+    println!("✅ Restored {} into {}", npub, data.display());
+    println!("   Secret key: {}", kp.display());
+    Ok(())
+}
+
+/// Archive `data` and the key at `key_path` into an encrypted backup at `out`.
+pub fn export_to(data: &Path, key_path: &Path, out: &Path, passphrase: &str) -> Result<()> {
+    check_passphrase(passphrase)?;
+    ensure_daemon_stopped(data)?;
+    let secret = fs::read_to_string(key_path).context("Failed to read secret key")?;
+    if !data.join("mls.sqlite").exists() {
+        anyhow::bail!("No MLS database in {} — nothing to back up", data.display());
+    }
+
+    let mut builder = tar::Builder::new(Vec::new());
+    let mut header = tar::Header::new_gnu();
+    header.set_size(secret.len() as u64);
+    header.set_mode(0o600);
+    header.set_cksum();
+    builder.append_data(&mut header, KEY_ENTRY, secret.as_bytes())?;
+    for name in DATA_ENTRIES {
+        let path = data.join(name);
+        if path.is_dir() {
+            builder.append_dir_all(name, &path)?;
+        } else if path.is_file() {
+            builder.append_path_with_name(&path, name)?;
+        }
+    }
+    let archive = builder.into_inner()?;
+    let compressed = zstd::encode_all(&archive[..], 0)?;
+    let sealed = seal(&compressed, passphrase)?;
+
+    let mut opts = fs::OpenOptions::new();
+    opts.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+    opts.open(out)
+        .with_context(|| format!("Failed to create {}", out.display()))?
+        .write_all(&sealed)?;
+    Ok(())
+}
+
+/// Restore a backup into `data`, writing its secret key to `key_path`.
+/// Returns the restored npub.
+///
+/// Refuses a data dir that already holds files, or a key path with a
+/// different key, unless `force` is set; with `force`, the backed-up
+/// entries are replaced wholesale so stale WAL files can't survive.
+/// The archive is unpacked into a staging dir next to `data` first, so a
+/// bad archive leaves `data` untouched.
+pub fn import_from(input: &Path, data: &Path, key_path: &Path, force: bool, passphrase: &str) -> Result<String> {
+    let sealed = fs::read(input).with_context(|| format!("Failed to read {}", input.display()))?;
+    let compressed = open(&sealed, passphrase)?;
+    let archive = zstd::decode_all(&compressed[..]).context("Corrupt backup")?;
+
+    // Find and check the key before touching anything on disk.
+    let mut secret = None;
+    for entry in tar::Archive::new(&archive[..]).entries()? {
+        let mut entry = entry?;
+        if entry.path()?.as_ref() == Path::new(KEY_ENTRY) {
+            let mut s = String::new();
+            entry.read_to_string(&mut s)?;
+            secret = Some(s);
+        }
+    }
+    let secret = secret.context("Backup has no secret key")?;
+    let keys = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .map(Keys::new)
+        .context("Backup has an invalid secret key")?;
+
+    ensure_daemon_stopped(data)?;
+    if !force {
+        if has_files(data)? {
+            anyhow::bail!("{} is not empty; pass --force to overwrite it", data.display());
+        }
+        if let Ok(existing) = fs::read_to_string(key_path) {
+            if existing.trim() != secret.trim() {
+                anyhow::bail!("A different key exists at {}; pass --force to overwrite it", key_path.display());
+            }
+        }
+    }
+
+    fs::create_dir_all(data)?;
+    let staging = staging_dir(data);
+    let _ = fs::remove_dir_all(&staging);
+    fs::create_dir_all(&staging)?;
+    if let Err(e) = unpack(&archive, &staging) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    for name in DATA_ENTRIES {
+        let path = data.join(name);
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else if path.exists() {
+            fs::remove_file(&path)?;
+        }
+        let staged = staging.join(name);
+        if staged.exists() {
+            fs::rename(&staged, &path)?;
+        }
+    }
+    fs::remove_dir_all(&staging)?;
+    config::write_secret_key(key_path, secret.trim())?;
+    Ok(keys.public_key().to_bech32()?)
+}
+
+/// Sibling of `data` to unpack into, on the same filesystem so the final
+/// moves are renames.
+fn staging_dir(data: &Path) -> PathBuf {
+    let name = data.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    data.with_file_name(format!(".{}.import-{}", name, std::process::id()))
+}
+
+/// Unpack the data entries of `archive` into `dir`, rejecting anything else.
+fn unpack(archive: &[u8], dir: &Path) -> Result<()> {
+    for entry in tar::Archive::new(archive).entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        if path == Path::new(KEY_ENTRY) {
+            continue;
+        }
+        let known = path
+            .components()
+            .next()
+            .is_some_and(|c| DATA_ENTRIES.iter().any(|n| c.as_os_str() == *n));
+        if !known || !entry.unpack_in(dir)? {
+            anyhow::bail!("Unexpected path in backup: {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Copying or replacing `mls.sqlite` under a running daemon could capture
+/// or leave a half-written database.
+fn ensure_daemon_stopped(data: &Path) -> Result<()> {
+    if daemon::is_running(data) {
+        anyhow::bail!("A burrow daemon is running on {} — stop it first", data.display());
+    }
+    Ok(())
+}
+
+/// Passphrase from `BURROW_BACKUP_PASSPHRASE`, else prompted for on the
+/// terminal without echo.
+fn read_passphrase(confirm: bool) -> Result<String> {
+    if let Ok(p) = std::env::var("BURROW_BACKUP_PASSPHRASE") {
+        return Ok(p);
+    }
+    let prompt = |label: &str| -> Result<String> {
+        rpassword::prompt_password(format!("{}: ", label)).context("Failed to read passphrase")
+    };
+    let passphrase = prompt("Backup passphrase")?;
+    if confirm && prompt("Repeat passphrase")? != passphrase {
+        anyhow::bail!("Passphrases don't match");
+    }
+    Ok(passphrase)
+}
+
+fn check_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        anyhow::bail!("Passphrase must be at least {} characters", MIN_PASSPHRASE_LEN);
+    }
+    Ok(())
+}
+
+/// Whether `dir` holds any regular file, at any depth. The empty
+/// directories `FileStore` creates don't count.
+fn has_files(dir: &Path) -> Result<bool> {
+    if !dir.exists() {
+        return Ok(false);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() || has_files(&path)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn seal(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::XChaCha20Poly1305;
+
+    let salt: [u8; 16] = rand::random();
+    let nonce: [u8; 24] = rand::random();
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, &salt)?.into());
+    let ciphertext = cipher
+        .encrypt(&nonce.into(), plaintext)
+        .map_err(|e| anyhow::anyhow!("Backup encryption failed: {}", e))?;
+
+    let mut out = Vec::with_capacity(BACKUP_MAGIC.len() + 40 + ciphertext.len());
+    out.extend_from_slice(BACKUP_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn open(sealed: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::XChaCha20Poly1305;
+
+    let header_len = BACKUP_MAGIC.len() + 16 + 24;
+    if sealed.len() < header_len || &sealed[..BACKUP_MAGIC.len()] != BACKUP_MAGIC {
+        anyhow::bail!("Not a Burrow backup");
+    }
+    let salt = &sealed[BACKUP_MAGIC.len()..BACKUP_MAGIC.len() + 16];
+    let nonce: [u8; 24] = sealed[BACKUP_MAGIC.len() + 16..header_len].try_into()?;
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, salt)?.into());
+    cipher
+        .decrypt(&nonce.into(), &sealed[header_len..])
+        .map_err(|_| anyhow::anyhow!("Wrong passphrase or corrupted backup"))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    argon2::Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow::anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const PASSPHRASE: &str = "correct horse battery";
+
+    fn temp(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("burrow-backup-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// A data dir with an MLS DB, a group, a message and an ACL, plus
+    /// media that should be left out.
+    fn populate(data: &Path) {
+        fs::create_dir_all(data.join("groups")).unwrap();
+        fs::create_dir_all(data.join("messages/abcd")).unwrap();
+        fs::create_dir_all(data.join("media")).unwrap();
+        fs::write(data.join("mls.sqlite"), b"sqlite bytes").unwrap();
+        fs::write(data.join("groups/g1.json"), b"{\"name\":\"g1\"}").unwrap();
+        fs::write(data.join("messages/abcd/e1.json"), b"{\"content\":\"hi\"}").unwrap();
+        fs::write(data.join("access-control.json"), b"{\"owner\":{}}").unwrap();
+        fs::write(data.join("media/big.bin"), b"media").unwrap();
+    }
+
+    #[test]
+    fn round_trip_restores_key_and_state() {
+        let root = temp("round-trip");
+        let (src, dst) = (root.join("src"), root.join("dst"));
+        populate(&src);
+        fs::write(root.join("src.key"), SECRET).unwrap();
+        let backup = root.join("backup.tar.zst");
+
+        export_to(&src, &root.join("src.key"), &backup, PASSPHRASE).unwrap();
+        import_from(&backup, &dst, &root.join("dst.key"), false, PASSPHRASE).unwrap();
+
+        assert_eq!(fs::read_to_string(root.join("dst.key")).unwrap(), SECRET);
+        for file in ["mls.sqlite", "groups/g1.json", "messages/abcd/e1.json", "access-control.json"] {
+            assert_eq!(fs::read(src.join(file)).unwrap(), fs::read(dst.join(file)).unwrap(), "{file}");
+        }
+        assert!(!dst.join("media").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn import_refuses_non_empty_dir_without_force() {
+        let root = temp("force");
+        let src = root.join("src");
+        populate(&src);
+        fs::write(root.join("key"), SECRET).unwrap();
+        let backup = root.join("backup.tar.zst");
+        export_to(&src, &root.join("key"), &backup, PASSPHRASE).unwrap();
+
+        let dst = root.join("dst");
+        fs::create_dir_all(&dst).unwrap();
+        fs::write(dst.join("mls.sqlite-wal"), b"stale").unwrap();
+        let err = import_from(&backup, &dst, &root.join("key"), false, PASSPHRASE).unwrap_err();
+        assert!(err.to_string().contains("--force"), "{err}");
+
+        import_from(&backup, &dst, &root.join("key"), true, PASSPHRASE).unwrap();
+        assert!(dst.join("mls.sqlite").exists());
+        assert!(!dst.join("mls.sqlite-wal").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn failed_forced_import_leaves_data_untouched() {
+        let root = temp("atomic");
+        let dst = root.join("dst");
+        populate(&dst);
+        fs::write(root.join("key"), SECRET).unwrap();
+
+        // A backup whose last entry is outside the data entries.
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, body) in [(KEY_ENTRY, SECRET.as_bytes()), ("groups/new.json", &b"{}"[..]), ("evil.txt", &b"x"[..])] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(body.len() as u64);
+            header.set_mode(0o600);
+            header.set_cksum();
+            builder.append_data(&mut header, name, body).unwrap();
+        }
+        let compressed = zstd::encode_all(&builder.into_inner().unwrap()[..], 0).unwrap();
+        let backup = root.join("bad.tar.zst");
+        fs::write(&backup, seal(&compressed, PASSPHRASE).unwrap()).unwrap();
+
+        let err = import_from(&backup, &dst, &root.join("key"), true, PASSPHRASE).unwrap_err();
+        assert!(err.to_string().contains("evil.txt"), "{err}");
+        assert_eq!(fs::read(dst.join("groups/g1.json")).unwrap(), b"{\"name\":\"g1\"}");
+        assert_eq!(fs::read(dst.join("mls.sqlite")).unwrap(), b"sqlite bytes");
+        assert!(!dst.join("groups/new.json").exists());
+        assert!(!staging_dir(&dst).exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn export_refuses_while_daemon_is_running() {
+        let root = temp("live");
+        let src = root.join("src");
+        populate(&src);
+        fs::write(root.join("key"), SECRET).unwrap();
+        let status = serde_json::json!({ "status": "running", "updatedAt": chrono::Utc::now().to_rfc3339() });
+        fs::write(src.join(".daemon-status.json"), status.to_string()).unwrap();
+
+        let err = export_to(&src, &root.join("key"), &root.join("backup.tar.zst"), PASSPHRASE).unwrap_err();
+        assert!(err.to_string().contains("daemon is running"), "{err}");
+        assert!(!root.join("backup.tar.zst").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn wrong_passphrase_is_rejected() {
+        let sealed = seal(b"payload", PASSPHRASE).unwrap();
+        assert_eq!(open(&sealed, PASSPHRASE).unwrap(), b"payload");
+        assert!(open(&sealed, "not the passphrase").is_err());
+    }
+}
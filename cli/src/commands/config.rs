@@ -0,0 +1,36 @@
+use anyhow::Result;
+
+use crate::config::{self, Settings};
+
+pub fn show(data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let settings = Settings::load(&data)?;
+    println!("⚙️  Burrow config: {}", data.join(config::SETTINGS_FILE).display());
+    for (key, description) in config::SETTING_KEYS {
+        let value = settings.get(key)?.unwrap_or_else(|| "(default)".into());
+        println!("  {:<18} {:<40} # {}", key, value, description);
+    }
+    Ok(())
+}
+
+pub fn get(key: String, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let settings = Settings::load(&data)?;
+    match settings.get(&key)? {
+        Some(value) => println!("{}", value),
+        None => println!("(default)"),
+    }
+    Ok(())
+}
+
+pub fn set(key: String, value: String, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let mut settings = Settings::load(&data)?;
+    settings.set(&key, &value)?;
+    settings.save(&data)?;
+    match settings.get(&key)? {
+        Some(value) => println!("✅ {} = {}", key, value),
+        None => println!("✅ {} reset to default", key),
+    }
+    Ok(())
+}
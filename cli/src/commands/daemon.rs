@@ -3,22 +3,34 @@ use mdk_core::MDK;
 use mdk_storage_traits::welcomes::types::WelcomeState;
 use nostr_sdk::prelude::*;
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-use crate::acl::access_control::AccessControl;
+use crate::acl::access_control::{AccessControl, AclDecision};
 use crate::acl::audit;
 use crate::config;
 use crate::keyring;
+use crate::metrics::{self, Metrics};
 use crate::relay::pool;
 use crate::storage::file_store::{FileStore, StoredGroup, StoredMessage};
 
 /// Kind 15 — Read receipt (inside MLS-encrypted rumor).
 const READ_RECEIPT_KIND: u16 = 15;
 
+/// Longest wait between reconnect attempts.
+const MAX_RECONNECT_DELAY_MS: u64 = 5 * 60 * 1000;
+
+/// A connection that lasted this long resets the reconnect backoff.
+const STABLE_CONNECTION: Duration = Duration::from_secs(60);
+
+/// How often `--retention-days` pruning runs.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[derive(Serialize)]
 struct DaemonLogEntry {
     #[serde(rename = "type")]
@@ -38,8 +50,25 @@ struct DaemonLogEntry {
     message_ids: Option<Vec<String>>,
 }
 
-fn write_jsonl(log_file: &Option<PathBuf>, entry: &DaemonLogEntry) {
+/// Periodic liveness line, so a consumer can tell a hung daemon from an
+/// idle one.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HeartbeatEntry {
+    #[serde(rename = "type")]
+    entry_type: &'static str,
+    timestamp: String,
+    connected_relays: usize,
+    groups: usize,
+}
+
+/// Serializes JSONL writers (the notification handler and the heartbeat
+/// task) so their lines never interleave.
+static JSONL_LOCK: Mutex<()> = Mutex::new(());
+
+fn write_jsonl<T: Serialize>(log_file: &Option<PathBuf>, entry: &T) {
     let json = serde_json::to_string(entry).unwrap_or_default();
+    let _guard = JSONL_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     println!("{}", json);
     if let Some(path) = log_file {
         if let Ok(mut f) = OpenOptions::new().create(true).append(true).open(path) {
@@ -48,18 +77,166 @@ fn write_jsonl(log_file: &Option<PathBuf>, entry: &DaemonLogEntry) {
     }
 }
 
+/// Liveness snapshot written to `data_dir/.daemon-status.json` on every
+/// processed event and every `STATUS_REFRESH`, so supervisors (systemd,
+/// Kubernetes, the bridge) can detect a stalled daemon by a stale
+/// `updatedAt` even when the groups are quiet.
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct DaemonStatus {
+    status: String,
+    pid: u32,
+    started_at: String,
+    updated_at: String,
+    last_activity: String,
+    connected_relays: usize,
+    /// Byte length of the JSONL log — the offset a tailing consumer has to reach.
+    offset: u64,
+    events_handled: u64,
+    messages_handled: u64,
+}
+
+const STATUS_FILE: &str = ".daemon-status.json";
+
+/// How often the status file is rewritten when no events arrive.
+const STATUS_REFRESH: Duration = Duration::from_secs(30);
+
+/// Env var enabling the HTTP health endpoint on `127.0.0.1:<port>`.
+const HTTP_PORT_ENV: &str = "BURROW_DAEMON_HTTP_PORT";
+
+fn write_status(data_dir: &std::path::Path, status: &DaemonStatus) {
+    // Write-then-rename so readers never see a partial file
+    let path = data_dir.join(STATUS_FILE);
+    let tmp = data_dir.join(format!("{}.tmp", STATUS_FILE));
+    if let Ok(json) = serde_json::to_string_pretty(status) {
+        if fs::write(&tmp, json).is_ok() {
+            let _ = fs::rename(&tmp, &path);
+        }
+    }
+}
+
+/// Whether a daemon looks alive on `data_dir`: its status file was refreshed
+/// within the last two `STATUS_REFRESH` periods.
+pub fn is_running(data_dir: &std::path::Path) -> bool {
+    let Ok(data) = fs::read_to_string(data_dir.join(STATUS_FILE)) else {
+        return false;
+    };
+    let Ok(status) = serde_json::from_str::<serde_json::Value>(&data) else {
+        return false;
+    };
+    status["updatedAt"]
+        .as_str()
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        // A timestamp in the future fails to_std; err on the side of running.
+        .is_some_and(|t| (chrono::Utc::now() - t.to_utc()).to_std().map_or(true, |age| age < 2 * STATUS_REFRESH))
+}
+
+fn record_activity(
+    status: &Mutex<DaemonStatus>,
+    metrics: &Metrics,
+    data_dir: &std::path::Path,
+    log_file: &Option<PathBuf>,
+    is_message: bool,
+) {
+    let mut st = status.lock().unwrap();
+    st.last_activity = chrono::Utc::now().to_rfc3339();
+    st.updated_at = st.last_activity.clone();
+    if is_message {
+        st.messages_handled += 1;
+    } else {
+        st.events_handled += 1;
+    }
+    if let Some(path) = log_file {
+        st.offset = fs::metadata(path).map(|m| m.len()).unwrap_or(st.offset);
+    }
+    metrics.log_offset.store(st.offset, std::sync::atomic::Ordering::Relaxed);
+    write_status(data_dir, &st);
+}
+
+/// Every `STATUS_REFRESH`, record the connected relay count and rewrite the
+/// status file, so an idle but healthy daemon does not look stalled.
+async fn refresh_status(client: Client, status: Arc<Mutex<DaemonStatus>>, data_dir: PathBuf) {
+    let mut ticks = tokio::time::interval(STATUS_REFRESH);
+    ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticks.tick().await;
+        let connected = connected_relays(&client).await;
+        let mut st = status.lock().unwrap();
+        st.connected_relays = connected;
+        st.updated_at = chrono::Utc::now().to_rfc3339();
+        write_status(&data_dir, &st);
+    }
+}
+
+async fn connected_relays(client: &Client) -> usize {
+    client.relays().await.values().filter(|relay| relay.is_connected()).count()
+}
+
+/// Status code and JSON body for the health endpoint: 503 while no relay is
+/// connected, since the daemon cannot receive anything then.
+fn health_response(st: &DaemonStatus) -> (u16, String) {
+    let healthy = st.connected_relays > 0;
+    let body = serde_json::json!({
+        "status": if healthy { st.status.as_str() } else { "disconnected" },
+        "offset": st.offset,
+        "lastActivity": st.last_activity,
+        "updatedAt": st.updated_at,
+        "connectedRelays": st.connected_relays,
+    })
+    .to_string();
+    (if healthy { 200 } else { 503 }, body)
+}
+
+/// Serve `GET /` (any path) with the current status as JSON.
+async fn serve_health(port: u16, status: Arc<Mutex<DaemonStatus>>) {
+    let listener = match tokio::net::TcpListener::bind(("127.0.0.1", port)).await {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("⚠️ Health endpoint failed to bind port {}: {}", port, e);
+            return;
+        }
+    };
+    eprintln!("ℹ️ Health endpoint on http://127.0.0.1:{}/", port);
+    loop {
+        let Ok((mut sock, _)) = listener.accept().await else { continue };
+        let (code, body) = health_response(&status.lock().unwrap());
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let _ = sock.read(&mut buf).await;
+            let reason = if code == 200 { "OK" } else { "Service Unavailable" };
+            let resp = format!(
+                "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                code,
+                reason,
+                body.len(),
+                body
+            );
+            let _ = sock.write_all(resp.as_bytes()).await;
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     key_path: Option<String>,
     data_dir: Option<String>,
     log_file: Option<String>,
-    _reconnect_delay: u64,
+    reconnect_delay: Option<u64>,
     no_access_control: bool,
+    welcome_greeting: Option<String>,
+    metrics_addr: Option<(String, u16)>,
+    heartbeat_secs: u64,
+    retention_days: Option<u64>,
 ) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let store = FileStore::new(&data)?;
     let log_path = log_file.map(PathBuf::from);
+    let settings = config::settings(&data);
+    let reconnect_delay = settings.reconnect_delay_ms(reconnect_delay);
+    let auto_accept = settings.auto_accept_welcomes();
 
     let kp = key_path.map(PathBuf::from).unwrap_or_else(config::default_key_path);
+    config::warn_if_key_exposed(&kp);
     let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
     let sk = SecretKey::from_hex(secret.trim())
         .or_else(|_| SecretKey::from_bech32(secret.trim()))
@@ -72,13 +249,34 @@ pub async fn run(
         Some(AccessControl::load(&data)?)
     };
 
+    let mls_db_path = data.join("mls.sqlite");
+
+    // Check if this is a fresh install BEFORE opening the DB (which creates the file).
+    let is_fresh_install = !mls_db_path.exists() || std::fs::metadata(&mls_db_path).map(|m| m.len() == 0).unwrap_or(true);
+
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+    let mdk = MDK::new(mdk_storage);
+
+    let default_relays = settings.relays(None);
+    match active_mls_groups(&mdk, &default_relays).and_then(|g| store.reconcile_groups(&g)) {
+        Ok(report) => {
+            for id in &report.restored {
+                eprintln!("🔧 Restored group {} missing from local storage", id);
+            }
+            for id in &report.orphaned {
+                eprintln!("⚠️ Stored group {} is unknown to MLS", id);
+            }
+        }
+        Err(e) => eprintln!("⚠️ Failed to reconcile groups: {}", e),
+    }
+
     let groups = store.load_groups()?;
     if groups.is_empty() {
         eprintln!("ℹ️ No groups yet — listening for invites only.");
     }
 
     // Collect all relay URLs
-    let mut all_relays: Vec<String> = config::default_relays();
+    let mut all_relays: Vec<String> = default_relays.clone();
     for g in &groups {
         for r in &g.relay_urls {
             if !all_relays.contains(r) {
@@ -88,13 +286,6 @@ pub async fn run(
     }
 
     let client = pool::connect(&keys, &all_relays).await?;
-    let mls_db_path = data.join("mls.sqlite");
-
-    // Check if this is a fresh install BEFORE opening the DB (which creates the file).
-    let is_fresh_install = !mls_db_path.exists() || std::fs::metadata(&mls_db_path).map(|m| m.len() == 0).unwrap_or(true);
-
-    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
-    let mdk = MDK::new(mdk_storage);
     if is_fresh_install {
         let relay_parsed: Vec<RelayUrl> = all_relays.iter()
             .filter_map(|u| RelayUrl::parse(u).ok())
@@ -141,12 +332,7 @@ pub async fn run(
     }
 
     // Subscribe to kind 445 for all groups (only new events from now)
-    let mut filter = Filter::new()
-        .kind(Kind::MlsGroupMessage)
-        .since(Timestamp::now());
-    for g in &groups {
-        filter = filter.custom_tag(SingleLetterTag::lowercase(Alphabet::H), g.nostr_group_id_hex.clone());
-    }
+    let filter = group_message_filter(groups.iter().map(|g| &g.nostr_group_id_hex));
 
     // Subscribe to kind 1059 (NIP-59 gift wraps) tagged with our pubkey for welcomes
     let gift_wrap_filter = Filter::new()
@@ -165,329 +351,743 @@ pub async fn run(
     };
     write_jsonl(&log_path, &startup);
 
-    client.subscribe(filter, None).await?;
-    client.subscribe(gift_wrap_filter, None).await?;
+    let now = chrono::Utc::now().to_rfc3339();
+    let status = Arc::new(Mutex::new(DaemonStatus {
+        status: "running".into(),
+        pid: std::process::id(),
+        started_at: now.clone(),
+        updated_at: now.clone(),
+        last_activity: now,
+        connected_relays: connected_relays(&client).await,
+        offset: log_path.as_ref().and_then(|p| fs::metadata(p).ok()).map(|m| m.len()).unwrap_or(0),
+        events_handled: 0,
+        messages_handled: 0,
+    }));
+    write_status(&data, &status.lock().unwrap());
+    tokio::spawn(refresh_status(client.clone(), status.clone(), data.clone()));
+
+    if let Some(port) = std::env::var(HTTP_PORT_ENV).ok().and_then(|p| p.parse::<u16>().ok()) {
+        tokio::spawn(serve_health(port, status.clone()));
+    }
+
+    let metrics = Arc::new(Metrics::default());
+    if let Some((bind, port)) = metrics_addr {
+        tokio::spawn(metrics::serve(bind, port, metrics.clone(), client.clone()));
+    }
+
+    // With no groups the filter has no h tags and would match every group's traffic
+    if !groups.is_empty() {
+        client.subscribe(filter, None).await?;
+    }
+    client.subscribe(gift_wrap_filter.clone(), None).await?;
+
+    // MLS group ID -> Nostr group ID of every group we listen on; grows as
+    // welcomes are auto-accepted.
+    let group_ids: Arc<Mutex<HashMap<String, String>>> = Arc::new(Mutex::new(
+        groups
+            .iter()
+            .map(|g| (g.mls_group_id_hex.clone(), g.nostr_group_id_hex.clone()))
+            .collect(),
+    ));
+
+    if heartbeat_secs > 0 {
+        tokio::spawn(heartbeat(
+            Duration::from_secs(heartbeat_secs),
+            client.clone(),
+            group_ids.clone(),
+            log_path.clone(),
+        ));
+    }
 
     let data_clone = data.clone();
     let log_path_clone = log_path.clone();
     let keys_clone = keys.clone();
     let store_clone = Arc::new(store);
+    if let Some(days) = retention_days.filter(|d| *d > 0) {
+        tokio::spawn(prune_messages(
+            Duration::from_secs(days * 86_400),
+            store_clone.clone(),
+            group_ids.clone(),
+            log_path.clone(),
+        ));
+    }
     let seen_events: Arc<Mutex<HashSet<EventId>>> = Arc::new(Mutex::new(HashSet::new()));
+    let welcome_greeting = welcome_greeting.filter(|g| !g.trim().is_empty());
 
-    client
-        .handle_notifications(|notification| async {
-            if let RelayPoolNotification::Event { event, .. } = notification {
-                // Deduplicate: skip events already seen from other relays
-                {
-                    let mut seen = seen_events.lock().unwrap();
-                    if !seen.insert(event.id) {
-                        return Ok(false);
-                    }
-                    // Cap at 10k to prevent unbounded growth on long-running daemons
-                    if seen.len() > 10_000 {
-                        seen.clear();
+    // Keep listening across relay failures: when the notification stream
+    // errors or ends, back off, reconnect and resubscribe.
+    let mut backoff_ms = reconnect_delay;
+    loop {
+        let connected_at = Instant::now();
+        let result = client
+            .handle_notifications(|notification| async {
+                if let RelayPoolNotification::Event { event, .. } = notification {
+                    // Deduplicate: skip events already seen from other relays
+                    {
+                        let mut seen = seen_events.lock().unwrap();
+                        if !seen.insert(event.id) {
+                            return Ok(false);
+                        }
+                        // Cap at 10k to prevent unbounded growth on long-running daemons
+                        if seen.len() > 10_000 {
+                            seen.clear();
+                        }
                     }
-                }
-                // Handle NIP-59 gift wraps (kind 1059) — Welcome messages
-                if event.kind == Kind::GiftWrap {
-                    match nip59::extract_rumor(&keys_clone, &event).await {
-                        Ok(unwrapped) => {
-                            if unwrapped.rumor.kind == Kind::Custom(444) {
-                                let entry = DaemonLogEntry {
-                                    entry_type: "gift_wrap_received".into(),
-                                    timestamp: chrono::Utc::now().to_rfc3339(),
-                                    group_id: None,
-                                    sender_pubkey: Some(unwrapped.sender.to_hex()),
-                                    content: Some("Kind 444 Welcome rumor received".into()),
-                                    allowed: None,
-                                    error: None,
-                                    message_ids: None,
-                                };
-                                write_jsonl(&log_path_clone, &entry);
+                    record_activity(&status, &metrics, &data_clone, &log_path_clone, false);
+                    metrics::inc(&metrics.events_received);
+
+                    // Handle NIP-59 gift wraps (kind 1059) — Welcome messages.
+                    // Wraps not addressed to us are expected and skipped silently.
+                    if event.kind == Kind::GiftWrap {
+                        if !is_addressed_to(&event, &keys_clone.public_key()) {
+                            return Ok(false);
+                        }
+                        match nip59::extract_rumor(&keys_clone, &event).await {
+                            Ok(unwrapped) => {
+                                if unwrapped.rumor.kind == Kind::Custom(444) {
+                                    let entry = DaemonLogEntry {
+                                        entry_type: "gift_wrap_received".into(),
+                                        timestamp: chrono::Utc::now().to_rfc3339(),
+                                        group_id: None,
+                                        sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                        content: Some("Kind 444 Welcome rumor received".into()),
+                                        allowed: None,
+                                        error: None,
+                                        message_ids: None,
+                                    };
+                                    write_jsonl(&log_path_clone, &entry);
 
-                                // Process welcome via MDK
-                                match mdk.process_welcome(&event.id, &unwrapped.rumor) {
-                                    Ok(welcome) => {
-                                        // Skip already-accepted welcomes (re-delivered by relays after restart)
-                                        if welcome.state == WelcomeState::Accepted {
-                                            let skip_entry = DaemonLogEntry {
-                                                entry_type: "welcome_skipped".into(),
+                                    // Process welcome via MDK
+                                    match mdk.process_welcome(&event.id, &unwrapped.rumor) {
+                                        Ok(welcome) => {
+                                            // Skip already-accepted welcomes (re-delivered by relays after restart)
+                                            if welcome.state == WelcomeState::Accepted {
+                                                let skip_entry = DaemonLogEntry {
+                                                    entry_type: "welcome_skipped".into(),
+                                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                                    group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                    sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                                    content: Some(format!(
+                                                        "Already accepted welcome to '{}', skipping",
+                                                        welcome.group_name
+                                                    )),
+                                                    allowed: None,
+                                                    error: None,
+                                        message_ids: None,
+                                                };
+                                                write_jsonl(&log_path_clone, &skip_entry);
+                                                metrics::inc(&metrics.welcomes_skipped);
+                                            } else {
+                                            let welcome_entry = DaemonLogEntry {
+                                                entry_type: "welcome_processed".into(),
                                                 timestamp: chrono::Utc::now().to_rfc3339(),
                                                 group_id: Some(hex::encode(&welcome.nostr_group_id)),
                                                 sender_pubkey: Some(unwrapped.sender.to_hex()),
                                                 content: Some(format!(
-                                                    "Already accepted welcome to '{}', skipping",
-                                                    welcome.group_name
+                                                    "Welcome to group '{}' ({} members)",
+                                                    welcome.group_name, welcome.member_count
                                                 )),
                                                 allowed: None,
                                                 error: None,
-                                    message_ids: None,
+                                        message_ids: None,
                                             };
-                                            write_jsonl(&log_path_clone, &skip_entry);
-                                        } else {
-                                        let welcome_entry = DaemonLogEntry {
-                                            entry_type: "welcome_processed".into(),
-                                            timestamp: chrono::Utc::now().to_rfc3339(),
-                                            group_id: Some(hex::encode(&welcome.nostr_group_id)),
-                                            sender_pubkey: Some(unwrapped.sender.to_hex()),
-                                            content: Some(format!(
-                                                "Welcome to group '{}' ({} members)",
-                                                welcome.group_name, welcome.member_count
-                                            )),
-                                            allowed: None,
-                                            error: None,
-                                    message_ids: None,
-                                        };
-                                        write_jsonl(&log_path_clone, &welcome_entry);
-
-                                        // Auto-accept: use the welcome ID from process_welcome result
-                                        let welcome_id = welcome.id;
-                                        match mdk.get_welcome(&welcome_id) {
-                                            Ok(Some(w)) => {
-                                                match mdk.accept_welcome(&w) {
-                                                    Ok(()) => {
-                                                        // Save the new group
-                                                        let group = StoredGroup {
-                                                            mls_group_id_hex: hex::encode(welcome.mls_group_id.as_slice()),
-                                                            nostr_group_id_hex: hex::encode(&welcome.nostr_group_id),
-                                                            name: welcome.group_name.clone(),
-                                                            description: welcome.group_description.clone(),
-                                                            admin_pubkeys: vec![unwrapped.sender.to_hex()],
-                                                            relay_urls: config::default_relays(),
-                                                            created_at: chrono::Utc::now().timestamp() as u64,
-                                                        };
-                                                        let _ = store_clone.save_group(&group);
-
-                                                        let accepted_entry = DaemonLogEntry {
-                                                            entry_type: "welcome_accepted".into(),
-                                                            timestamp: chrono::Utc::now().to_rfc3339(),
-                                                            group_id: Some(hex::encode(&welcome.nostr_group_id)),
-                                                            sender_pubkey: Some(unwrapped.sender.to_hex()),
-                                                            content: Some(format!(
-                                                                "Auto-accepted welcome to '{}'. Restart daemon to listen on new group.",
-                                                                welcome.group_name
-                                                            )),
-                                                            allowed: None,
-                                                            error: None,
-                                    message_ids: None,
-                                                        };
-                                                        write_jsonl(&log_path_clone, &accepted_entry);
-                                                    }
-                                                    Err(e) => {
-                                                        let err_entry = DaemonLogEntry {
-                                                            entry_type: "welcome_accept_error".into(),
-                                                            timestamp: chrono::Utc::now().to_rfc3339(),
-                                                            group_id: Some(hex::encode(&welcome.nostr_group_id)),
-                                                            sender_pubkey: None,
-                                                            content: None,
-                                                            allowed: None,
-                                                            error: Some(format!("accept_welcome failed: {}", e)),
-                                    message_ids: None,
-                                                        };
-                                                        write_jsonl(&log_path_clone, &err_entry);
-                                                    }
-                                                }
-                                            }
-                                            Ok(None) => {
-                                                let err_entry = DaemonLogEntry {
-                                                    entry_type: "welcome_accept_error".into(),
+                                            write_jsonl(&log_path_clone, &welcome_entry);
+
+                                            if !auto_accept {
+                                                // welcomePolicy=manual: leave it for `burrow welcome accept`
+                                                write_jsonl(&log_path_clone, &DaemonLogEntry {
+                                                    entry_type: "welcome_pending".into(),
                                                     timestamp: chrono::Utc::now().to_rfc3339(),
-                                                    group_id: None,
-                                                    sender_pubkey: None,
-                                                    content: None,
+                                                    group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                    sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                                    content: Some(format!("Welcome to '{}' left pending (welcomePolicy=manual)", welcome.group_name)),
                                                     allowed: None,
-                                                    error: Some("Welcome not found after processing".into()),
-                                    message_ids: None,
+                                                    error: None,
+                                                    message_ids: None,
+                                                });
+                                            } else if !acl.as_ref()
+                                                .map(|a| {
+                                                    let group_id = hex::encode(&welcome.nostr_group_id);
+                                                    a.evaluate_welcome(&unwrapped.sender.to_hex(), Some(&group_id)).is_allowed()
+                                                })
+                                                .unwrap_or(true)
+                                            {
+                                                // Only the owner and allowlisted contacts (within their
+                                                // scope) may add us to groups
+                                                let declined = match mdk.get_welcome(&welcome.id) {
+                                                    Ok(Some(w)) => mdk.decline_welcome(&w).map_err(|e| e.to_string()),
+                                                    Ok(None) => Err("Welcome not found after processing".to_string()),
+                                                    Err(e) => Err(e.to_string()),
                                                 };
-                                                write_jsonl(&log_path_clone, &err_entry);
-                                            }
-                                            Err(e) => {
-                                                let err_entry = DaemonLogEntry {
-                                                    entry_type: "welcome_accept_error".into(),
+                                                write_jsonl(&log_path_clone, &DaemonLogEntry {
+                                                    entry_type: "welcome_denied".into(),
                                                     timestamp: chrono::Utc::now().to_rfc3339(),
-                                                    group_id: None,
-                                                    sender_pubkey: None,
-                                                    content: None,
-                                                    allowed: None,
-                                                    error: Some(format!("get_welcome failed: {}", e)),
-                                    message_ids: None,
-                                                };
-                                                write_jsonl(&log_path_clone, &err_entry);
+                                                    group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                    sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                                    content: Some(format!("Declined welcome to '{}': sender not allowed by ACL", welcome.group_name)),
+                                                    allowed: Some(false),
+                                                    error: declined.err().map(|e| format!("decline_welcome failed: {}", e)),
+                                                    message_ids: None,
+                                                });
+                                                metrics::inc(&metrics.welcomes_denied);
+                                            } else {
+                                            // Auto-accept: use the welcome ID from process_welcome result
+                                            let welcome_id = welcome.id;
+                                            match mdk.get_welcome(&welcome_id) {
+                                                Ok(Some(w)) => {
+                                                    match mdk.accept_welcome(&w) {
+                                                        Ok(()) => {
+                                                            // Save the new group
+                                                            let group = StoredGroup {
+                                                                mls_group_id_hex: hex::encode(welcome.mls_group_id.as_slice()),
+                                                                nostr_group_id_hex: hex::encode(&welcome.nostr_group_id),
+                                                                name: welcome.group_name.clone(),
+                                                                description: welcome.group_description.clone(),
+                                                                admin_pubkeys: vec![unwrapped.sender.to_hex()],
+                                                                relay_urls: default_relays.clone(),
+                                                                created_at: chrono::Utc::now().timestamp() as u64,
+                                                            };
+                                                            let _ = store_clone.save_group(&group);
+
+                                                            // Listen on the new group right away. A group
+                                                            // accepted again (re-invite) is already subscribed.
+                                                            let newly_joined = group_ids
+                                                                .lock()
+                                                                .unwrap()
+                                                                .insert(group.mls_group_id_hex.clone(), group.nostr_group_id_hex.clone())
+                                                                .is_none();
+                                                            let (content, error) = if !newly_joined {
+                                                                (format!("Auto-accepted welcome to '{}' (already listening)", welcome.group_name), None)
+                                                            } else {
+                                                                let new_filter = group_message_filter([&group.nostr_group_id_hex]);
+                                                                match client.subscribe(new_filter, None).await {
+                                                                    Ok(_) => (format!("Auto-accepted welcome to '{}'; now listening on it", welcome.group_name), None),
+                                                                    Err(e) => (
+                                                                        format!("Auto-accepted welcome to '{}'. Restart daemon to listen on new group.", welcome.group_name),
+                                                                        Some(format!("subscribe failed: {}", e)),
+                                                                    ),
+                                                                }
+                                                            };
+
+                                                            let accepted_entry = DaemonLogEntry {
+                                                                entry_type: "welcome_accepted".into(),
+                                                                timestamp: chrono::Utc::now().to_rfc3339(),
+                                                                group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                                sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                                                content: Some(content),
+                                                                allowed: None,
+                                                                error,
+                                        message_ids: None,
+                                                            };
+                                                            write_jsonl(&log_path_clone, &accepted_entry);
+                                                            metrics::inc(&metrics.welcomes_accepted);
+
+                                                            if let Some(greeting) = &welcome_greeting {
+                                                                let rumor = EventBuilder::new(Kind::TextNote, greeting)
+                                                                    .build(keys_clone.public_key());
+                                                                let sent = match mdk.create_message(&welcome.mls_group_id, rumor) {
+                                                                    Ok(greeting_event) => client
+                                                                        .send_event(&greeting_event)
+                                                                        .await
+                                                                        .map(|output| output.id().to_hex())
+                                                                        .map_err(|e| format!("publish failed: {}", e)),
+                                                                    Err(e) => Err(format!("create_message failed: {}", e)),
+                                                                };
+                                                                let (entry_type, content, error) = match sent {
+                                                                    Ok(id) => ("welcome_greeting", Some(format!("Greeting sent: {}", id)), None),
+                                                                    Err(e) => ("welcome_greeting_error", None, Some(e)),
+                                                                };
+                                                                write_jsonl(&log_path_clone, &DaemonLogEntry {
+                                                                    entry_type: entry_type.into(),
+                                                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                                                    group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                                    sender_pubkey: None,
+                                                                    content,
+                                                                    allowed: None,
+                                                                    error,
+                                                                    message_ids: None,
+                                                                });
+                                                            }
+                                                        }
+                                                        Err(e) => {
+                                                            let err_entry = DaemonLogEntry {
+                                                                entry_type: "welcome_accept_error".into(),
+                                                                timestamp: chrono::Utc::now().to_rfc3339(),
+                                                                group_id: Some(hex::encode(&welcome.nostr_group_id)),
+                                                                sender_pubkey: None,
+                                                                content: None,
+                                                                allowed: None,
+                                                                error: Some(format!("accept_welcome failed: {}", e)),
+                                        message_ids: None,
+                                                            };
+                                                            write_jsonl(&log_path_clone, &err_entry);
+                                                            metrics::inc(&metrics.welcome_errors);
+                                                        }
+                                                    }
+                                                }
+                                                Ok(None) => {
+                                                    let err_entry = DaemonLogEntry {
+                                                        entry_type: "welcome_accept_error".into(),
+                                                        timestamp: chrono::Utc::now().to_rfc3339(),
+                                                        group_id: None,
+                                                        sender_pubkey: None,
+                                                        content: None,
+                                                        allowed: None,
+                                                        error: Some("Welcome not found after processing".into()),
+                                        message_ids: None,
+                                                    };
+                                                    write_jsonl(&log_path_clone, &err_entry);
+                                                    metrics::inc(&metrics.welcome_errors);
+                                                }
+                                                Err(e) => {
+                                                    let err_entry = DaemonLogEntry {
+                                                        entry_type: "welcome_accept_error".into(),
+                                                        timestamp: chrono::Utc::now().to_rfc3339(),
+                                                        group_id: None,
+                                                        sender_pubkey: None,
+                                                        content: None,
+                                                        allowed: None,
+                                                        error: Some(format!("get_welcome failed: {}", e)),
+                                        message_ids: None,
+                                                    };
+                                                    write_jsonl(&log_path_clone, &err_entry);
+                                                    metrics::inc(&metrics.welcome_errors);
+                                                }
                                             }
+                                            } // end else (auto-accept)
+                                            } // end else (not already accepted)
+                                        }
+                                        Err(e) => {
+                                            let err_entry = DaemonLogEntry {
+                                                entry_type: "welcome_process_error".into(),
+                                                timestamp: chrono::Utc::now().to_rfc3339(),
+                                                group_id: None,
+                                                sender_pubkey: Some(unwrapped.sender.to_hex()),
+                                                content: None,
+                                                allowed: None,
+                                                error: Some(format!("process_welcome failed: {}", e)),
+                                        message_ids: None,
+                                            };
+                                            write_jsonl(&log_path_clone, &err_entry);
+                                            metrics::inc(&metrics.welcome_errors);
                                         }
-                                        } // end else (not already accepted)
-                                    }
-                                    Err(e) => {
-                                        let err_entry = DaemonLogEntry {
-                                            entry_type: "welcome_process_error".into(),
-                                            timestamp: chrono::Utc::now().to_rfc3339(),
-                                            group_id: None,
-                                            sender_pubkey: Some(unwrapped.sender.to_hex()),
-                                            content: None,
-                                            allowed: None,
-                                            error: Some(format!("process_welcome failed: {}", e)),
-                                    message_ids: None,
-                                        };
-                                        write_jsonl(&log_path_clone, &err_entry);
                                     }
                                 }
                             }
-                        }
-                        Err(e) => {
-                            // Silently ignore unwrap failures (not all 1059s are for us / valid)
-                            let entry = DaemonLogEntry {
-                                entry_type: "gift_wrap_error".into(),
-                                timestamp: chrono::Utc::now().to_rfc3339(),
-                                group_id: None,
-                                sender_pubkey: None,
-                                content: None,
-                                allowed: None,
-                                error: Some(format!("NIP-59 unwrap failed: {}", e)),
-                                    message_ids: None,
-                            };
-                            write_jsonl(&log_path_clone, &entry);
+                            Err(e) => {
+                                // Addressed to us but undecryptable or malformed — worth logging
+                                let entry = DaemonLogEntry {
+                                    entry_type: "gift_wrap_error".into(),
+                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                    group_id: None,
+                                    sender_pubkey: None,
+                                    content: None,
+                                    allowed: None,
+                                    error: Some(format!("NIP-59 unwrap failed: {}", e)),
+                                        message_ids: None,
+                                };
+                                write_jsonl(&log_path_clone, &entry);
+                                metrics::inc(&metrics.gift_wrap_errors);
+                            }
                         }
                     }
-                }
-                else if event.kind == Kind::MlsGroupMessage {
-                    // Skip our own messages to prevent echo/feedback loops
-                    if event.pubkey == keys_clone.public_key() {
-                        return Ok(false);
-                    }
+                    else if event.kind == Kind::MlsGroupMessage {
+                        // Skip our own messages to prevent echo/feedback loops
+                        if event.pubkey == keys_clone.public_key() {
+                            return Ok(false);
+                        }
 
-                    match mdk.process_message(&event) {
-                        Ok(mdk_core::messages::MessageProcessingResult::ApplicationMessage(msg)) => {
-                            let sender_hex = msg.pubkey.to_hex();
+                        match mdk.process_message(&event) {
+                            Ok(mdk_core::messages::MessageProcessingResult::ApplicationMessage(msg)) => {
+                                let sender_hex = msg.pubkey.to_hex();
 
-                            // Skip our own messages to prevent feedback loops with
-                            // downstream consumers (e.g. OpenClaw MLS plugin)
-                            if sender_hex == keys_clone.public_key().to_hex() {
-                                return Ok(false);
-                            }
+                                // Skip our own messages to prevent feedback loops with
+                                // downstream consumers (e.g. OpenClaw MLS plugin)
+                                if sender_hex == keys_clone.public_key().to_hex() {
+                                    return Ok(false);
+                                }
 
-                            let group_hex = hex::encode(msg.mls_group_id.as_slice());
+                                let group_hex = hex::encode(msg.mls_group_id.as_slice());
 
-                            // Find nostr group id for ACL check
-                            let nostr_gid = groups.iter()
-                                .find(|g| g.mls_group_id_hex == group_hex)
-                                .map(|g| g.nostr_group_id_hex.as_str())
-                                .unwrap_or("");
+                                // Find nostr group id for ACL check
+                                let nostr_gid = group_ids.lock().unwrap()
+                                    .get(&group_hex)
+                                    .cloned()
+                                    .unwrap_or_default();
+                                let nostr_gid = nostr_gid.as_str();
 
-                            let allowed = acl.as_ref()
-                                .map(|a| a.is_allowed(&sender_hex, nostr_gid))
-                                .unwrap_or(true);
+                                let decision = acl.as_ref()
+                                    .map(|a| a.check_message(&sender_hex, nostr_gid, std::time::Instant::now()));
+                                let allowed = decision.map(|d| d.is_allowed()).unwrap_or(true);
+                                if let Some(epoch) = msg.epoch {
+                                    metrics.set_epoch(nostr_gid, epoch);
+                                }
 
-                            // Audit
-                            if acl.as_ref().map(|a| a.config.settings.audit_enabled).unwrap_or(false) {
-                                audit::log_message(&data_clone, &sender_hex, nostr_gid, allowed, None);
-                            }
+                                // Audit
+                                if acl.as_ref().map(|a| a.config.settings.audit_enabled).unwrap_or(false) {
+                                    if decision == Some(AclDecision::RateLimited) {
+                                        audit::log_rate_limited(&data_clone, &sender_hex, nostr_gid);
+                                    } else {
+                                        audit::log_message(&data_clone, &sender_hex, nostr_gid, allowed, None);
+                                    }
+                                }
 
-                            // Handle read receipts (kind 15) separately
-                            if msg.kind == Kind::Custom(READ_RECEIPT_KIND) {
-                                if allowed {
-                                    let read_msg_ids: Vec<String> = msg.tags.iter()
-                                        .filter_map(|t| {
-                                            let s = t.as_slice();
-                                            if s.len() >= 2 && s[0] == "e" {
-                                                Some(s[1].clone())
-                                            } else {
-                                                None
-                                            }
-                                        })
-                                        .collect();
+                                // Handle read receipts (kind 15) separately
+                                if msg.kind == Kind::Custom(READ_RECEIPT_KIND) {
+                                    if allowed {
+                                        let read_msg_ids: Vec<String> = msg.tags.iter()
+                                            .filter_map(|t| {
+                                                let s = t.as_slice();
+                                                if s.len() >= 2 && s[0] == "e" {
+                                                    Some(s[1].clone())
+                                                } else {
+                                                    None
+                                                }
+                                            })
+                                            .collect();
 
-                                    // Store read receipt
-                                    let _ = store_clone.save_read_receipt(
-                                        &group_hex,
-                                        &sender_hex,
-                                        &read_msg_ids,
-                                        msg.created_at.as_secs(),
-                                    );
+                                        // Store read receipt
+                                        let _ = store_clone.save_read_receipt(
+                                            &group_hex,
+                                            &sender_hex,
+                                            &read_msg_ids,
+                                            msg.created_at.as_secs(),
+                                        );
 
-                                    let entry = DaemonLogEntry {
-                                        entry_type: "read_receipt".into(),
-                                        timestamp: chrono::Utc::now().to_rfc3339(),
-                                        group_id: Some(nostr_gid.to_string()),
-                                        sender_pubkey: Some(sender_hex),
-                                        content: None,
-                                        allowed: Some(true),
-                                        error: None,
-                                        message_ids: Some(read_msg_ids),
-                                    };
-                                    write_jsonl(&log_path_clone, &entry);
+                                        let entry = DaemonLogEntry {
+                                            entry_type: "read_receipt".into(),
+                                            timestamp: chrono::Utc::now().to_rfc3339(),
+                                            group_id: Some(nostr_gid.to_string()),
+                                            sender_pubkey: Some(sender_hex),
+                                            content: None,
+                                            allowed: Some(true),
+                                            error: None,
+                                            message_ids: Some(read_msg_ids),
+                                        };
+                                        write_jsonl(&log_path_clone, &entry);
+                                        metrics::inc(&metrics.read_receipts);
+                                    }
+                                    return Ok(false);
                                 }
-                                return Ok(false);
-                            }
-
-                            let tags: Vec<Vec<String>> = msg.tags.iter()
-                                .map(|t| t.as_slice().to_vec())
-                                .collect();
-                            let media_dir = data_clone.join("media");
 
-                            // Auto-download encrypted media attachments
-                            if allowed {
-                                crate::media::auto_download_attachments(
-                                    &mdk, &msg.mls_group_id, &tags, &media_dir,
-                                ).await;
-                            }
-
-                            let display_content = if allowed {
-                                Some(crate::media::format_message_with_media(
-                                    &msg.content, &tags, Some(&media_dir),
-                                ))
-                            } else {
-                                None
-                            };
-
-                            let entry = DaemonLogEntry {
-                                entry_type: "message".into(),
-                                timestamp: chrono::Utc::now().to_rfc3339(),
-                                group_id: Some(nostr_gid.to_string()),
-                                sender_pubkey: Some(sender_hex.clone()),
-                                content: display_content,
-                                allowed: Some(allowed),
-                                error: None,
-                                message_ids: None,
-                            };
-                            write_jsonl(&log_path_clone, &entry);
-
-                            if allowed {
                                 let tags: Vec<Vec<String>> = msg.tags.iter()
                                     .map(|t| t.as_slice().to_vec())
                                     .collect();
-                                let stored = StoredMessage {
-                                    event_id_hex: msg.id.to_hex(),
-                                    author_pubkey_hex: sender_hex,
-                                    content: msg.content.clone(),
-                                    created_at: msg.created_at.as_secs(),
-                                    mls_group_id_hex: group_hex,
-                                    wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
-                                    epoch: msg.epoch.unwrap_or(0),
-                                    tags,
+                                let media_dir = data_clone.join("media");
+
+                                // Auto-download encrypted media attachments
+                                if allowed {
+                                    crate::media::auto_download_attachments(
+                                        &mdk, &msg.mls_group_id, &tags, &media_dir,
+                                    ).await;
+                                }
+
+                                let display_content = if allowed {
+                                    Some(crate::media::format_message_with_media(
+                                        &msg.content, &tags, Some(&media_dir),
+                                    ))
+                                } else {
+                                    None
                                 };
-                                let _ = store_clone.save_message(&stored);
-                            }
-                        }
-                        Ok(_) => {} // commit/proposal — silent
-                        Err(e) => {
-                            let entry = DaemonLogEntry {
-                                entry_type: "decrypt_error".into(),
-                                timestamp: chrono::Utc::now().to_rfc3339(),
-                                group_id: None,
-                                sender_pubkey: None,
-                                content: None,
-                                allowed: None,
-                                error: Some(e.to_string()),
+
+                                let entry = DaemonLogEntry {
+                                    entry_type: "message".into(),
+                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                    group_id: Some(nostr_gid.to_string()),
+                                    sender_pubkey: Some(sender_hex.clone()),
+                                    content: display_content,
+                                    allowed: Some(allowed),
+                                    error: None,
                                     message_ids: None,
-                            };
-                            write_jsonl(&log_path_clone, &entry);
+                                };
+                                write_jsonl(&log_path_clone, &entry);
+                                metrics::inc(if allowed { &metrics.messages_processed } else { &metrics.messages_denied });
+                                record_activity(&status, &metrics, &data_clone, &log_path_clone, true);
+
+                                if allowed {
+                                    let tags: Vec<Vec<String>> = msg.tags.iter()
+                                        .map(|t| t.as_slice().to_vec())
+                                        .collect();
+                                    let stored = StoredMessage {
+                                        event_id_hex: msg.id.to_hex(),
+                                        author_pubkey_hex: sender_hex,
+                                        content: msg.content.clone(),
+                                        kind: Some(msg.kind.as_u16()),
+                                        created_at: msg.created_at.as_secs(),
+                                        mls_group_id_hex: group_hex,
+                                        wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
+                                        epoch: msg.epoch.unwrap_or(0),
+                                        tags,
+                                    };
+                                    let _ = store_clone.save_message(&stored);
+                                }
+                            }
+                            Ok(mdk_core::messages::MessageProcessingResult::Commit { mls_group_id }) => {
+                                if let Ok(Some(group)) = mdk.get_group(&mls_group_id) {
+                                    metrics.set_epoch(&hex::encode(group.nostr_group_id), group.epoch);
+                                }
+                            }
+                            Ok(_) => {} // proposal — silent
+                            Err(e) => {
+                                let entry = DaemonLogEntry {
+                                    entry_type: "decrypt_error".into(),
+                                    timestamp: chrono::Utc::now().to_rfc3339(),
+                                    group_id: None,
+                                    sender_pubkey: None,
+                                    content: None,
+                                    allowed: None,
+                                    error: Some(e.to_string()),
+                                        message_ids: None,
+                                };
+                                write_jsonl(&log_path_clone, &entry);
+                                metrics::inc(&metrics.decrypt_errors);
+                            }
                         }
                     }
                 }
+                Ok(false) // keep listening
+            })
+            .await;
+
+        if connected_at.elapsed() >= STABLE_CONNECTION {
+            backoff_ms = reconnect_delay;
+        }
+        let delay_ms = with_jitter(backoff_ms);
+        let reason = match result {
+            Ok(()) => "notification stream ended".to_string(),
+            Err(e) => e.to_string(),
+        };
+        write_jsonl(&log_path, &DaemonLogEntry {
+            entry_type: "reconnect".into(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            group_id: None,
+            sender_pubkey: None,
+            content: Some(format!("Reconnecting in {} ms", delay_ms)),
+            allowed: None,
+            error: Some(reason),
+            message_ids: None,
+        });
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        backoff_ms = next_backoff(backoff_ms);
+
+        // Catch up on what was sent while we were away; seen_events drops
+        // anything already handled.
+        let since = Timestamp::from(Timestamp::now().as_secs().saturating_sub(delay_ms / 1000 + 60));
+        client.connect().await;
+        client.unsubscribe_all().await;
+        let nostr_group_ids: Vec<String> = group_ids.lock().unwrap().values().cloned().collect();
+        if !nostr_group_ids.is_empty() {
+            if let Err(e) = client.subscribe(group_message_filter(&nostr_group_ids).since(since), None).await {
+                eprintln!("⚠️ Resubscribe to groups failed: {}", e);
+            }
+        }
+        if let Err(e) = client.subscribe(gift_wrap_filter.clone(), None).await {
+            eprintln!("⚠️ Resubscribe to gift wraps failed: {}", e);
+        }
+    }
+}
+
+/// Active MLS groups as stored group metadata, for reconciling the store.
+fn active_mls_groups<S: mdk_storage_traits::MdkStorageProvider>(
+    mdk: &MDK<S>,
+    default_relays: &[String],
+) -> Result<Vec<StoredGroup>> {
+    let groups = mdk.get_groups().context("Failed to list MLS groups")?;
+    Ok(groups
+        .iter()
+        .filter(|g| matches!(g.state, mdk_core::prelude::group_types::GroupState::Active))
+        .map(|g| {
+            let relay_urls: Vec<String> = mdk
+                .get_relays(&g.mls_group_id)
+                .map(|r| r.iter().map(|u| u.to_string()).collect())
+                .unwrap_or_default();
+            StoredGroup {
+                mls_group_id_hex: hex::encode(g.mls_group_id.as_slice()),
+                nostr_group_id_hex: hex::encode(g.nostr_group_id),
+                name: g.name.clone(),
+                description: g.description.clone(),
+                admin_pubkeys: g.admin_pubkeys.iter().map(|pk| pk.to_hex()).collect(),
+                relay_urls: if relay_urls.is_empty() { default_relays.to_vec() } else { relay_urls },
+                created_at: chrono::Utc::now().timestamp() as u64,
             }
-            Ok(false) // keep listening
         })
-        .await?;
+        .collect())
+}
+
+/// Write a heartbeat line every `period` with the connected relay and group
+/// counts.
+async fn heartbeat(
+    period: Duration,
+    client: Client,
+    group_ids: Arc<Mutex<HashMap<String, String>>>,
+    log_path: Option<PathBuf>,
+) {
+    let mut ticks = tokio::time::interval(period);
+    ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    ticks.tick().await; // the first tick is immediate
+    loop {
+        ticks.tick().await;
+        let connected_relays = connected_relays(&client).await;
+        let groups = group_ids.lock().unwrap().len();
+        write_jsonl(&log_path, &HeartbeatEntry {
+            entry_type: "heartbeat",
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            connected_relays,
+            groups,
+        });
+    }
+}
 
-    Ok(())
+/// Every `PRUNE_INTERVAL`, starting now, delete stored messages older than
+/// `retention` from every group we listen on. Group records are kept.
+async fn prune_messages(
+    retention: Duration,
+    store: Arc<FileStore>,
+    group_ids: Arc<Mutex<HashMap<String, String>>>,
+    log_path: Option<PathBuf>,
+) {
+    let mut ticks = tokio::time::interval(PRUNE_INTERVAL);
+    ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticks.tick().await;
+        let groups: Vec<(String, String)> =
+            group_ids.lock().unwrap().iter().map(|(m, n)| (m.clone(), n.clone())).collect();
+        for (mls_group_id_hex, nostr_group_id_hex) in groups {
+            let (content, error) = match store.prune_messages(&mls_group_id_hex, retention.as_secs()) {
+                Ok(0) => continue,
+                Ok(n) => (Some(format!("Pruned {} message(s) past retention", n)), None),
+                Err(e) => (None, Some(format!("Pruning failed: {}", e))),
+            };
+            write_jsonl(&log_path, &DaemonLogEntry {
+                entry_type: "prune".into(),
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                group_id: Some(nostr_group_id_hex),
+                sender_pubkey: None,
+                content,
+                allowed: None,
+                error,
+                message_ids: None,
+            });
+        }
+    }
+}
+
+/// The reconnect delay after `backoff_ms`: doubled, up to
+/// `MAX_RECONNECT_DELAY_MS`.
+fn next_backoff(backoff_ms: u64) -> u64 {
+    backoff_ms.saturating_mul(2).min(MAX_RECONNECT_DELAY_MS)
+}
+
+/// `delay_ms` plus up to 25% random jitter, so many daemons dropped by the
+/// same relay don't all reconnect at once.
+fn with_jitter(delay_ms: u64) -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    delay_ms + nanos % (delay_ms / 4 + 1)
+}
+
+/// Kind 445 filter for the given Nostr group IDs, from now on.
+fn group_message_filter<'a>(nostr_group_ids: impl IntoIterator<Item = &'a String>) -> Filter {
+    nostr_group_ids.into_iter().fold(
+        Filter::new().kind(Kind::MlsGroupMessage).since(Timestamp::now()),
+        |filter, id| filter.custom_tag(SingleLetterTag::lowercase(Alphabet::H), id.clone()),
+    )
+}
+
+/// Whether a gift wrap names `pubkey` as its recipient in a `p` tag.
+fn is_addressed_to(event: &Event, pubkey: &PublicKey) -> bool {
+    let hex = pubkey.to_hex();
+    event.tags.iter().any(|t| {
+        let t = t.as_slice();
+        t.first().map(|k| k == "p").unwrap_or(false) && t.get(1) == Some(&hex)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jitter_adds_at_most_a_quarter() {
+        for delay in [100, 5_000, MAX_RECONNECT_DELAY_MS] {
+            for _ in 0..100 {
+                let jittered = with_jitter(delay);
+                assert!(jittered >= delay && jittered <= delay + delay / 4, "{delay} -> {jittered}");
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let mut backoff = config::MIN_RECONNECT_DELAY_MS;
+        let mut steps = vec![backoff];
+        while backoff < MAX_RECONNECT_DELAY_MS {
+            backoff = next_backoff(backoff);
+            steps.push(backoff);
+        }
+        assert_eq!(&steps[..4], &[100, 200, 400, 800]);
+        assert_eq!(next_backoff(MAX_RECONNECT_DELAY_MS), MAX_RECONNECT_DELAY_MS);
+        assert_eq!(next_backoff(u64::MAX), MAX_RECONNECT_DELAY_MS);
+    }
+
+    fn status(connected_relays: usize) -> DaemonStatus {
+        DaemonStatus {
+            status: "running".into(),
+            pid: 1,
+            started_at: "2024-01-01T00:00:00+00:00".into(),
+            updated_at: "2024-01-01T00:05:00+00:00".into(),
+            last_activity: "2024-01-01T00:00:00+00:00".into(),
+            connected_relays,
+            offset: 42,
+            events_handled: 0,
+            messages_handled: 0,
+        }
+    }
+
+    #[test]
+    fn health_is_ok_with_a_connected_relay() {
+        let (code, body) = health_response(&status(2));
+        assert_eq!(code, 200);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["status"], "running");
+        assert_eq!(json["connectedRelays"], 2);
+        assert_eq!(json["updatedAt"], "2024-01-01T00:05:00+00:00");
+    }
+
+    #[test]
+    fn health_is_unavailable_without_relays() {
+        let (code, body) = health_response(&status(0));
+        assert_eq!(code, 503);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["status"], "disconnected");
+        assert_eq!(json["offset"], 42);
+    }
+
+    #[test]
+    fn is_running_follows_status_freshness() {
+        let dir = std::env::temp_dir().join(format!("burrow-daemon-status-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        assert!(!is_running(&dir));
+
+        let mut st = status(1);
+        st.updated_at = chrono::Utc::now().to_rfc3339();
+        write_status(&dir, &st);
+        assert!(is_running(&dir));
+
+        st.updated_at = (chrono::Utc::now() - chrono::Duration::minutes(10)).to_rfc3339();
+        write_status(&dir, &st);
+        assert!(!is_running(&dir));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reconnect_delay_is_never_zero() {
+        let settings = config::Settings { reconnect_delay_ms: Some(0), ..Default::default() };
+        assert_eq!(settings.reconnect_delay_ms(None), config::MIN_RECONNECT_DELAY_MS);
+        assert_eq!(settings.reconnect_delay_ms(Some(0)), config::MIN_RECONNECT_DELAY_MS);
+        assert_eq!(config::Settings::default().reconnect_delay_ms(None), config::DEFAULT_RECONNECT_DELAY_MS);
+    }
 }
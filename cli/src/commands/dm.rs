@@ -0,0 +1,178 @@
+use anyhow::{Context, Result};
+use mdk_core::MDK;
+use nostr_sdk::prelude::*;
+use std::fs;
+
+use crate::acl::access_control::resolve_to_hex;
+use crate::commands::send;
+use crate::config;
+use crate::keyring;
+use crate::relay::pool;
+use crate::storage::file_store::{FileStore, StoredGroup};
+
+/// Send a direct message, creating the 1:1 group on first contact.
+///
+/// Looks for an existing 2-member group with the peer and sends into it.
+/// Otherwise creates a new DM group, gift-wraps the Welcome to the peer and
+/// sends the message into the new group; relays hold it until the peer
+/// accepts the invite and syncs.
+pub async fn run(
+    recipient: String,
+    message: String,
+    key_path: Option<String>,
+    data_dir: Option<String>,
+    relays: Option<Vec<String>>,
+) -> Result<()> {
+    let peer_hex = if recipient.contains('@') {
+        println!("🔍 Resolving {}...", recipient);
+        resolve_nip05(&recipient).await?
+    } else {
+        resolve_to_hex(&recipient)?
+    };
+    let peer_pk = PublicKey::from_hex(&peer_hex)?;
+
+    let data = config::data_dir(data_dir.as_deref());
+    let store = FileStore::new(&data)?;
+
+    let kp = key_path.clone().map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+    if peer_pk == keys.public_key() {
+        anyhow::bail!("Cannot DM yourself");
+    }
+
+    let existing = {
+        let mls_db_path = data.join("mls.sqlite");
+        let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+        let mdk = MDK::new(mdk_storage);
+        find_dm_group(&store, &mdk, &peer_pk)?
+    };
+
+    let group_id = match existing {
+        Some(group) => {
+            println!("💬 Using existing DM group {} ({})", group.name, &group.mls_group_id_hex[..12]);
+            group.mls_group_id_hex
+        }
+        None => {
+            println!("🆕 No DM with {} yet — creating one...", &peer_hex[..12]);
+            create_dm_group(&store, &data, &keys, &peer_pk, relays).await?
+        }
+    };
+
+    send::run(group_id, message, key_path, data_dir, None, None).await
+}
+
+/// Most recently created 2-member group containing `peer`.
+fn find_dm_group<S: mdk_storage_traits::MdkStorageProvider>(
+    store: &FileStore,
+    mdk: &MDK<S>,
+    peer: &PublicKey,
+) -> Result<Option<StoredGroup>> {
+    let mut candidates: Vec<StoredGroup> = store
+        .load_groups()?
+        .into_iter()
+        .filter(|g| {
+            let Ok(bytes) = hex::decode(&g.mls_group_id_hex) else { return false };
+            let gid = mdk_core::prelude::GroupId::from_slice(&bytes);
+            mdk.get_members(&gid)
+                .map(|members| members.len() == 2 && members.contains(peer))
+                .unwrap_or(false)
+        })
+        .collect();
+    candidates.sort_by_key(|g| std::cmp::Reverse(g.created_at));
+    Ok(candidates.into_iter().next())
+}
+
+/// Create a DM group with `peer` and send them the Welcome. Returns the MLS group ID.
+async fn create_dm_group(
+    store: &FileStore,
+    data: &std::path::Path,
+    keys: &Keys,
+    peer: &PublicKey,
+    relays: Option<Vec<String>>,
+) -> Result<String> {
+    let peer_hex = peer.to_hex();
+    let settings = config::settings(data);
+    let relay_urls = settings.relays(relays);
+    let relay_parsed: Vec<RelayUrl> = relay_urls.iter()
+        .filter_map(|u| RelayUrl::parse(u).ok())
+        .collect();
+
+    let client = pool::connect(keys, &relay_urls).await?;
+
+    let filter = Filter::new()
+        .author(*peer)
+        .kind(Kind::MlsKeyPackage)
+        .limit(1);
+    println!("🔍 Fetching KeyPackage for {}...", &peer_hex[..12]);
+    let events = client.fetch_events(filter, settings.fetch_timeout(10)).await
+        .context("Failed to fetch KeyPackage")?;
+    let kp_event = events.into_iter().next()
+        .context(format!("No KeyPackage found for {} — they need to run `burrow init` or open the app first", peer_hex))?;
+
+    let mls_db_path = data.join("mls.sqlite");
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, keys)?;
+    let mdk = MDK::new(mdk_storage);
+
+    let name = format!("DM {}", &peer_hex[..12]);
+    let group_config = mdk_core::groups::NostrGroupConfigData::new(
+        name.clone(),
+        String::new(),
+        None, None, None,
+        relay_parsed,
+        vec![keys.public_key()],
+    );
+    let result = mdk.create_group(&keys.public_key(), vec![kp_event], group_config)
+        .context("Failed to create DM group")?;
+    let mls_group_id = result.group.mls_group_id.clone();
+    mdk.merge_pending_commit(&mls_group_id)?;
+
+    let mls_id_hex = hex::encode(mls_group_id.as_slice());
+    let stored = StoredGroup {
+        mls_group_id_hex: mls_id_hex.clone(),
+        nostr_group_id_hex: hex::encode(result.group.nostr_group_id),
+        name: name.clone(),
+        description: String::new(),
+        admin_pubkeys: vec![keys.public_key().to_hex()],
+        relay_urls: relay_urls.clone(),
+        created_at: chrono::Utc::now().timestamp() as u64,
+    };
+    store.save_group(&stored)?;
+    println!("✅ Group created: {}", name);
+
+    for rumor in result.welcome_rumors {
+        let gift_wrap = EventBuilder::gift_wrap(keys, peer, rumor, Vec::<Tag>::new())
+            .await
+            .context("Failed to gift-wrap welcome rumor")?;
+        let output = client.send_event(&gift_wrap).await
+            .context("Failed to publish gift-wrapped welcome")?;
+        println!("📨 Welcome sent to {} ({})", &peer_hex[..12], output.id().to_hex());
+    }
+    client.disconnect().await;
+
+    println!("⏳ They'll see the message once they accept the invite.");
+    Ok(mls_id_hex)
+}
+
+/// Resolve a NIP-05 identifier (`name@domain`) to a hex pubkey.
+async fn resolve_nip05(identifier: &str) -> Result<String> {
+    let (name, domain) = identifier
+        .split_once('@')
+        .context("NIP-05 identifier must be name@domain")?;
+    let name = if name.is_empty() { "_" } else { name };
+    let url = format!("https://{}/.well-known/nostr.json?name={}", domain, name);
+
+    let body = reqwest::get(&url).await
+        .context("NIP-05 lookup failed")?
+        .text().await?;
+    let json: serde_json::Value = serde_json::from_str(&body)
+        .context("Invalid NIP-05 response")?;
+    let hex = json.get("names")
+        .and_then(|n| n.get(name))
+        .and_then(|v| v.as_str())
+        .context(format!("{} not found at {}", name, domain))?;
+    resolve_to_hex(hex)
+}
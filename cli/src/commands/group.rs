@@ -5,6 +5,7 @@ use std::fs;
 
 use crate::config;
 use crate::keyring;
+use crate::relay::pool;
 use crate::storage::file_store::{FileStore, StoredGroup};
 
 pub async fn create(
@@ -26,7 +27,7 @@ pub async fn create(
     let keys = Keys::new(sk);
     let pubkey = keys.public_key();
 
-    let relay_urls = relays.unwrap_or_else(config::default_relays);
+    let relay_urls = config::settings(&data).relays(relays);
     let relay_parsed: Vec<RelayUrl> = relay_urls.iter()
         .filter_map(|u| RelayUrl::parse(u).ok())
         .collect();
@@ -69,11 +70,40 @@ pub async fn create(
     Ok(())
 }
 
-pub fn list(data_dir: Option<String>) -> Result<()> {
+/// One entry of `burrow groups --json`.
+#[derive(serde::Serialize)]
+struct GroupJson<'a> {
+    mls_group_id_hex: &'a str,
+    nostr_group_id_hex: &'a str,
+    name: &'a str,
+    description: &'a str,
+    /// `null` when the MLS database can't be opened (no secret key).
+    member_count: Option<usize>,
+    relay_urls: &'a [String],
+}
+
+pub fn list(data_dir: Option<String>, json: bool) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let store = FileStore::new(&data)?;
     let groups = store.load_groups()?;
 
+    if json {
+        let counts = member_counts(&data, &groups).unwrap_or_default();
+        let entries: Vec<GroupJson> = groups
+            .iter()
+            .map(|g| GroupJson {
+                mls_group_id_hex: &g.mls_group_id_hex,
+                nostr_group_id_hex: &g.nostr_group_id_hex,
+                name: &g.name,
+                description: &g.description,
+                member_count: counts.get(&g.mls_group_id_hex).copied(),
+                relay_urls: &g.relay_urls,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
     if groups.is_empty() {
         println!("No groups found. Create one with: burrow group create <name>");
         return Ok(());
@@ -86,3 +116,159 @@ pub fn list(data_dir: Option<String>) -> Result<()> {
     }
     Ok(())
 }
+
+/// Member count per MLS group ID, read from the local MLS database with the
+/// default identity.
+fn member_counts(data: &std::path::Path, groups: &[StoredGroup]) -> Result<std::collections::HashMap<String, usize>> {
+    let secret = fs::read_to_string(config::default_key_path()).context("no secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let mdk_storage = keyring::open_mls_storage(&data.join("mls.sqlite"), &keys)?;
+    let mdk = MDK::new(mdk_storage);
+    let mut counts = std::collections::HashMap::new();
+    for g in groups {
+        let gid = mdk_core::prelude::GroupId::from_slice(&hex::decode(&g.mls_group_id_hex)?);
+        if let Ok(members) = mdk.get_members(&gid) {
+            counts.insert(g.mls_group_id_hex.clone(), members.len());
+        }
+    }
+    Ok(counts)
+}
+
+/// Leave a group: publish an MLS leave proposal and drop local metadata.
+///
+/// The proposal must be committed by an admin before the member list
+/// changes for everyone else, so the only admin of a group with other
+/// members can't leave. Stored messages are kept unless `purge` is set.
+pub async fn leave(
+    group_id: String,
+    key_path: Option<String>,
+    data_dir: Option<String>,
+    purge: bool,
+    yes: bool,
+) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let store = FileStore::new(&data)?;
+
+    let Some(group) = store.find_group_by_prefix(&group_id)? else {
+        if store.has_left(&group_id) {
+            anyhow::bail!("Already left group {}", group_id);
+        }
+        anyhow::bail!("Group not found");
+    };
+
+    let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let mls_db_path = data.join("mls.sqlite");
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+    let mdk = MDK::new(mdk_storage);
+    let mls_group_id = mdk_core::prelude::GroupId::from_slice(
+        &hex::decode(&group.mls_group_id_hex)?
+    );
+
+    let mls_group = mdk.get_group(&mls_group_id)
+        .context("Failed to load MLS group")?
+        .context("Group not found in MLS storage")?;
+    let me = keys.public_key();
+    let active = matches!(mls_group.state, mdk_core::prelude::group_types::GroupState::Active);
+    let members = if active {
+        mdk.get_members(&mls_group_id).context("Failed to load group members")?
+    } else {
+        Default::default()
+    };
+    if !active || !members.contains(&me) {
+        // Removed by an admin, or an earlier leave was committed; only the
+        // local metadata is left to drop.
+        store.delete_group(&group.nostr_group_id_hex)?;
+        store.mark_left(&group.mls_group_id_hex)?;
+        println!("ℹ️ Already left group {} (not a member any more); removed it locally.", group.name);
+        return Ok(());
+    }
+    let admins: Vec<PublicKey> = mls_group.admin_pubkeys.into_iter().collect();
+    if let Some(reason) = leave_blocker(&me, &admins, members.len()) {
+        anyhow::bail!("Can't leave {}: {}", group.name, reason);
+    }
+
+    if !yes && !confirm(&format!("Leave group {}?", group.name), std::io::stdin().lock())? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let result = mdk.leave_group(&mls_group_id)
+        .context("Failed to create leave proposal")?;
+
+    // Publish the leave proposal (kind 445) so an admin can commit it
+    let client = pool::connect(&keys, &group.relay_urls).await?;
+    let evolution_json = serde_json::to_string(&result.evolution_event)?;
+    let evolution_event: Event = serde_json::from_str(&evolution_json)?;
+    let output = client.send_event(&evolution_event).await
+        .context("Failed to publish leave proposal")?;
+    println!("📤 Leave proposal published: {}", output.id().to_hex());
+    client.disconnect().await;
+
+    store.delete_group(&group.nostr_group_id_hex)?;
+    store.mark_left(&group.mls_group_id_hex)?;
+    if purge {
+        store.delete_messages(&group.mls_group_id_hex)?;
+    }
+
+    println!("👋 Left group {}", group.name);
+    if !purge {
+        println!("   Message history kept (use --purge to delete it).");
+    }
+    Ok(())
+}
+
+/// Why `me` can't leave a group with these admins and `member_count`
+/// members, if anything stands in the way.
+fn leave_blocker(me: &PublicKey, admins: &[PublicKey], member_count: usize) -> Option<&'static str> {
+    let only_admin = admins.len() == 1 && admins.contains(me);
+    if only_admin && member_count > 1 {
+        return Some("you're the only admin; promote another member first");
+    }
+    None
+}
+
+/// Ask a yes/no question on stdout and read the answer from `input`.
+/// Anything but "y" or "yes" is a no.
+fn confirm(question: &str, mut input: impl std::io::BufRead) -> Result<bool> {
+    print!("{} [y/N] ", question);
+    std::io::Write::flush(&mut std::io::stdout())?;
+    let mut answer = String::new();
+    input.read_line(&mut answer).context("Failed to read confirmation")?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_admin_cannot_leave_while_others_remain() {
+        let me = Keys::generate().public_key();
+        let other = Keys::generate().public_key();
+        let only_me = [me];
+        assert!(leave_blocker(&me, &only_me, 2).is_some());
+        // Alone in the group, or with another admin, leaving is fine.
+        assert!(leave_blocker(&me, &only_me, 1).is_none());
+        assert!(leave_blocker(&me, &[me, other], 2).is_none());
+        assert!(leave_blocker(&me, &[other], 2).is_none());
+    }
+
+    #[test]
+    fn confirm_accepts_only_yes() {
+        assert!(confirm("Leave?", &b"y\n"[..]).unwrap());
+        assert!(confirm("Leave?", &b"YES\n"[..]).unwrap());
+        assert!(!confirm("Leave?", &b"\n"[..]).unwrap());
+        assert!(!confirm("Leave?", &b"no\n"[..]).unwrap());
+        assert!(!confirm("Leave?", &b""[..]).unwrap());
+    }
+}
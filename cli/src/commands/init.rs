@@ -2,45 +2,58 @@ use anyhow::{Context, Result};
 use mdk_core::MDK;
 use nostr_sdk::prelude::*;
 use std::fs;
+use std::io::Read;
 
 use crate::config;
 use crate::keyring;
 use crate::relay::pool;
 use crate::storage::file_store::FileStore;
 
-pub async fn run(key_path: Option<String>, data_dir: Option<String>, relays: Option<Vec<String>>, generate: bool) -> Result<()> {
+pub async fn run(
+    key_path: Option<String>,
+    data_dir: Option<String>,
+    relays: Option<Vec<String>>,
+    generate: bool,
+    import_stdin: bool,
+) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     fs::create_dir_all(&data)?;
     let store = FileStore::new(&data)?;
 
-    // Load or generate keys
+    // Load, import or generate keys
     let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
-    let keys = if kp.exists() {
-        let secret = fs::read_to_string(&kp)
-            .context("Failed to read secret key")?
-            .trim()
-            .to_string();
-        // Try hex first, then nsec
-        if let Ok(sk) = SecretKey::from_hex(&secret) {
-            Keys::new(sk)
-        } else {
-            let sk = SecretKey::from_bech32(&secret)
-                .context("Invalid secret key (not hex or nsec)")?;
-            Keys::new(sk)
+    let keys = if import_stdin {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input).context("Failed to read stdin")?;
+        let keys = parse_secret(input.trim())?;
+        if kp.exists() {
+            let existing = parse_secret(fs::read_to_string(&kp)?.trim()).ok();
+            if existing.map(|k| k.public_key()) != Some(keys.public_key()) {
+                anyhow::bail!(
+                    "A different key already exists at {}. Move it away before importing.",
+                    kp.display()
+                );
+            }
         }
+        config::write_secret_key(&kp, &keys.secret_key().to_secret_hex())?;
+        println!("🔑 Imported identity: {}", keys.public_key().to_bech32()?);
+        println!("   Saved to {} (0600)", kp.display());
+        keys
+    } else if kp.exists() {
+        config::warn_if_key_exposed(&kp);
+        let secret = fs::read_to_string(&kp)
+            .context("Failed to read secret key")?;
+        parse_secret(secret.trim())?
     } else if generate {
         let keys = Keys::generate();
-        fs::create_dir_all(kp.parent().unwrap())?;
-        fs::write(&kp, keys.secret_key().to_secret_hex())?;
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            fs::set_permissions(&kp, fs::Permissions::from_mode(0o600))?;
-        }
+        config::write_secret_key(&kp, &keys.secret_key().to_secret_hex())?;
         println!("🔑 Generated new identity: {}", keys.public_key().to_bech32()?);
         keys
     } else {
-        anyhow::bail!("No secret key found at {}. Use --generate to create one.", kp.display());
+        anyhow::bail!(
+            "No secret key found at {}. Use --generate to create one or --import-stdin to import one.",
+            kp.display()
+        );
     };
 
     let pubkey = keys.public_key();
@@ -53,7 +66,7 @@ pub async fn run(key_path: Option<String>, data_dir: Option<String>, relays: Opt
     let mdk = MDK::new(mdk_storage);
 
     // Generate KeyPackage
-    let relay_urls = relays.unwrap_or_else(config::default_relays);
+    let relay_urls = config::settings(&data).relays(relays);
     let relay_parsed: Vec<RelayUrl> = relay_urls.iter()
         .filter_map(|u| RelayUrl::parse(u).ok())
         .collect();
@@ -87,3 +100,12 @@ pub async fn run(key_path: Option<String>, data_dir: Option<String>, relays: Opt
     client.disconnect().await;
     Ok(())
 }
+
+/// Parse a secret key given as hex or nsec.
+fn parse_secret(secret: &str) -> Result<Keys> {
+    // Try hex first, then nsec
+    let sk = SecretKey::from_hex(secret)
+        .or_else(|_| SecretKey::from_bech32(secret))
+        .context("Invalid secret key (not hex or nsec)")?;
+    Ok(Keys::new(sk))
+}
@@ -41,7 +41,7 @@ pub async fn run(
         .limit(1);
 
     println!("🔍 Fetching KeyPackage for {}...", &invitee_hex[..12]);
-    let events = client.fetch_events(filter, std::time::Duration::from_secs(10)).await
+    let events = client.fetch_events(filter, config::settings(&data).fetch_timeout(10)).await
         .context("Failed to fetch KeyPackage")?;
 
     let kp_event = events.into_iter().next()
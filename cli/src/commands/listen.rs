@@ -10,10 +10,12 @@ use crate::keyring;
 use crate::relay::pool;
 use crate::storage::file_store::{FileStore, StoredMessage};
 
+/// Stream new messages in a group, optionally after the last `replay` stored ones.
 pub async fn run(
     group_id: String,
     key_path: Option<String>,
     data_dir: Option<String>,
+    replay: usize,
 ) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let store = FileStore::new(&data)?;
@@ -43,8 +45,31 @@ pub async fn run(
     println!("👂 Listening for messages in '{}' ({}..)", group.name, &nostr_gid[..12]);
     println!("   Press Ctrl+C to stop.");
 
+    // Scrollback: already-printed messages are remembered by rumor ID (and
+    // their wrappers skipped) so one that also arrives live isn't shown twice.
+    let mut seen_wrappers: HashSet<EventId> = HashSet::new();
+    let mut printed: HashSet<String> = HashSet::new();
+    if replay > 0 {
+        let media_dir = data.join("media");
+        let history = store.load_messages(&group.mls_group_id_hex, replay)?;
+        if !history.is_empty() {
+            println!("── Last {} message(s) ──", history.len());
+        }
+        for m in &history {
+            print_message(m.created_at, m.kind, &m.author_pubkey_hex, &m.content, &m.tags, &media_dir);
+            printed.insert(m.event_id_hex.clone());
+            if let Ok(id) = EventId::from_hex(&m.wrapper_event_id_hex) {
+                seen_wrappers.insert(id);
+            }
+        }
+        if !history.is_empty() {
+            println!("── Live ──");
+        }
+    }
+
     client.subscribe(filter, None).await?;
-    let seen_events: Arc<Mutex<HashSet<EventId>>> = Arc::new(Mutex::new(HashSet::new()));
+    let seen_events: Arc<Mutex<HashSet<EventId>>> = Arc::new(Mutex::new(seen_wrappers));
+    let printed = Mutex::new(printed);
 
     // Process events
     client
@@ -63,10 +88,9 @@ pub async fn run(
                 if event.kind == Kind::MlsGroupMessage {
                     match mdk.process_message(&event) {
                         Ok(mdk_core::messages::MessageProcessingResult::ApplicationMessage(msg)) => {
-                            let time = chrono::DateTime::from_timestamp(msg.created_at.as_secs() as i64, 0)
-                                .map(|t| t.format("%H:%M:%S").to_string())
-                                .unwrap_or_else(|| "?".into());
-                            let sender = &msg.pubkey.to_hex()[..12];
+                            if !printed.lock().unwrap().insert(msg.id.to_hex()) {
+                                return Ok(false);
+                            }
                             let tags: Vec<Vec<String>> = msg.tags.iter()
                                 .map(|t| t.as_slice().to_vec())
                                 .collect();
@@ -77,16 +101,16 @@ pub async fn run(
                                 &mdk, &msg.mls_group_id, &tags, &media_dir,
                             ).await;
 
-                            let display = crate::media::format_message_with_media(
-                                &msg.content, &tags, Some(&media_dir),
+                            print_message(
+                                msg.created_at.as_secs(), Some(msg.kind.as_u16()), &msg.pubkey.to_hex(), &msg.content, &tags, &media_dir,
                             );
-                            println!("[{}] {}.. : {}", time, sender, display);
 
                             // Persist
                             let stored = StoredMessage {
                                 event_id_hex: msg.id.to_hex(),
                                 author_pubkey_hex: msg.pubkey.to_hex(),
                                 content: msg.content.clone(),
+                                kind: Some(msg.kind.as_u16()),
                                 created_at: msg.created_at.as_secs(),
                                 mls_group_id_hex: hex::encode(msg.mls_group_id.as_slice()),
                                 wrapper_event_id_hex: msg.wrapper_event_id.to_hex(),
@@ -106,3 +130,18 @@ pub async fn run(
 
     Ok(())
 }
+
+fn print_message(
+    created_at: u64,
+    kind: Option<u16>,
+    author_hex: &str,
+    content: &str,
+    tags: &[Vec<String>],
+    media_dir: &std::path::Path,
+) {
+    let time = chrono::DateTime::from_timestamp(created_at as i64, 0)
+        .map(|t| t.format("%H:%M:%S").to_string())
+        .unwrap_or_else(|| "?".into());
+    let line = crate::display::format_message(kind, author_hex, content, tags, Some(media_dir));
+    println!("[{}] {}", time, line);
+}
@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+use mdk_core::MDK;
+use nostr_sdk::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config;
+use crate::keyring;
+use crate::media;
+use crate::storage::file_store::FileStore;
+
+/// Download and decrypt every attachment of a stored message.
+///
+/// Useful when auto-download was skipped (ACL, network failure) or the
+/// decrypted copy was deleted. Files already present with the correct
+/// hash are left untouched.
+pub async fn download(
+    group_id: String,
+    event_id: String,
+    out: Option<String>,
+    key_path: Option<String>,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let store = FileStore::new(&data)?;
+
+    let group = store
+        .find_group_by_prefix(&group_id)?
+        .context("Group not found")?;
+
+    let msg = store
+        .find_message(&group.mls_group_id_hex, &event_id)?
+        .context("Message not found in local store")?;
+
+    let attachments = media::parse_imeta_tags(&msg.tags);
+    if attachments.is_empty() {
+        println!("No media attachments in message {}.", msg.event_id_hex);
+        return Ok(());
+    }
+
+    let kp = key_path.map(PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let mls_db_path = data.join("mls.sqlite");
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+    let mdk = MDK::new(mdk_storage);
+    let mls_group_id = mdk_core::prelude::GroupId::from_slice(
+        &hex::decode(&group.mls_group_id_hex)?
+    );
+
+    let out_dir = out.map(PathBuf::from).unwrap_or_else(|| data.join("media"));
+    fs::create_dir_all(&out_dir)?;
+
+    let mut failed = 0;
+    for att in &attachments {
+        let path = out_dir.join(att.local_filename());
+        if path.exists() {
+            let existing = fs::read(&path)?;
+            if media::hash_matches(&existing, &att.original_hash_hex) {
+                println!("✅ {} (already downloaded) -> {}", att.filename, path.display());
+            } else {
+                eprintln!(
+                    "⚠️ {} exists with different contents, not overwriting: {}",
+                    att.filename,
+                    path.display()
+                );
+                failed += 1;
+            }
+            continue;
+        }
+
+        match media::download_and_decrypt(&mdk, &mls_group_id, att, &out_dir).await {
+            Ok(saved) => println!("✅ {} ({}) -> {}", att.filename, att.mime_type, saved.display()),
+            Err(e) => {
+                eprintln!("⚠️ {} failed: {}", att.filename, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} attachments could not be downloaded", failed, attachments.len());
+    }
+    Ok(())
+}
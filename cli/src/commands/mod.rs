@@ -2,6 +2,7 @@ pub mod init;
 pub mod group;
 pub mod invite;
 pub mod send;
+pub mod reply;
 pub mod read;
 pub mod read_receipt;
 pub mod listen;
@@ -9,3 +10,10 @@ pub mod daemon;
 pub mod acl;
 pub mod welcome;
 pub mod call;
+pub mod media;
+pub mod dm;
+pub mod verify;
+pub mod stats;
+pub mod config;
+pub mod whoami;
+pub mod backup;
@@ -4,11 +4,14 @@ use nostr_sdk::prelude::*;
 use std::fs;
 
 use crate::config;
+use crate::display;
 use crate::keyring;
 use crate::media;
 use crate::storage::file_store::FileStore;
 
-pub async fn run(group_id: String, limit: usize, data_dir: Option<String>) -> Result<()> {
+/// Print stored messages for a group. With `json`, stdout is a JSON array of
+/// `StoredMessage` and nothing else is printed (media isn't auto-downloaded).
+pub async fn run(group_id: String, limit: usize, data_dir: Option<String>, json: bool) -> Result<()> {
     let data = config::data_dir(data_dir.as_deref());
     let store = FileStore::new(&data)?;
 
@@ -18,6 +21,11 @@ pub async fn run(group_id: String, limit: usize, data_dir: Option<String>) -> Re
 
     let messages = store.load_messages(&group.mls_group_id_hex, limit)?;
 
+    if json {
+        println!("{}", serde_json::to_string_pretty(&messages)?);
+        return Ok(());
+    }
+
     if messages.is_empty() {
         println!("No messages in group '{}'.", group.name);
         return Ok(());
@@ -52,9 +60,8 @@ pub async fn run(group_id: String, limit: usize, data_dir: Option<String>) -> Re
         let time = chrono::DateTime::from_timestamp(msg.created_at as i64, 0)
             .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
             .unwrap_or_else(|| "?".into());
-        let sender = &msg.author_pubkey_hex[..12.min(msg.author_pubkey_hex.len())];
-        let display = media::format_message_with_media(&msg.content, &msg.tags, Some(&media_dir));
-        println!("[{}] {}.. : {}", time, sender, display);
+        let line = display::format_message(msg.kind, &msg.author_pubkey_hex, &msg.content, &msg.tags, Some(&media_dir));
+        println!("[{}] {}", time, line);
     }
     Ok(())
 }
@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use mdk_core::MDK;
+use nostr_sdk::prelude::*;
+use std::fs;
+
+use crate::acl::access_control::AccessControl;
+use crate::config;
+use crate::keyring;
+use crate::relay::pool;
+use crate::storage::file_store::FileStore;
+
+/// Send a threaded reply to a message in a group.
+///
+/// The rumor carries a NIP-10 marked `e` tag ("reply") for the parent and a
+/// `p` tag for its author. The parent is looked up in the local message store
+/// (full ID or unique prefix); if it isn't stored, the full event ID is
+/// required and only the `e` tag is added.
+pub async fn run(
+    group_id: String,
+    parent_event_id: String,
+    message: String,
+    key_path: Option<String>,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let store = FileStore::new(&data)?;
+
+    let group = store.find_group_by_prefix(&group_id)?
+        .context("Group not found")?;
+
+    let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let acl = AccessControl::load(&data)?;
+    if !acl.is_allowed(&keys.public_key().to_hex(), &group.nostr_group_id_hex) {
+        anyhow::bail!("ACL: not allowed to send to this group");
+    }
+
+    let (parent_id, parent_author) = match store.find_message(&group.mls_group_id_hex, &parent_event_id)? {
+        Some(parent) => {
+            let id = EventId::from_hex(&parent.event_id_hex)
+                .context("Stored parent has an invalid event ID")?;
+            let author = PublicKey::from_hex(&parent.author_pubkey_hex).ok();
+            (id, author)
+        }
+        None => {
+            let id = EventId::from_hex(&parent_event_id)
+                .context("Parent not found locally; pass its full event ID")?;
+            eprintln!("⚠️ Parent {} not found in local store; replying without a p tag", parent_event_id);
+            (id, None)
+        }
+    };
+
+    let relay_hint = group.relay_urls.first().cloned().unwrap_or_default();
+    let mut builder = EventBuilder::new(Kind::TextNote, &message)
+        .tag(Tag::parse(["e".to_string(), parent_id.to_hex(), relay_hint, "reply".to_string()])
+            .map_err(|e| anyhow::anyhow!("Failed to build e tag: {}", e))?);
+    if let Some(author) = parent_author {
+        builder = builder.tag(Tag::public_key(author));
+    }
+    let rumor = builder.build(keys.public_key());
+
+    let mls_db_path = data.join("mls.sqlite");
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+    let mdk = MDK::new(mdk_storage);
+    let mls_group_id = mdk_core::prelude::GroupId::from_slice(
+        &hex::decode(&group.mls_group_id_hex)?
+    );
+
+    let event = mdk.create_message(&mls_group_id, rumor)
+        .context("Failed to encrypt reply")?;
+
+    let client = pool::connect(&keys, &group.relay_urls).await?;
+    let output = client.send_event(&event).await
+        .context("Failed to publish reply")?;
+
+    println!("✅ Replied to {} in {} ({})", &parent_id.to_hex()[..16], group.name, output.id().to_hex());
+    client.disconnect().await;
+    Ok(())
+}
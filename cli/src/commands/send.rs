@@ -3,6 +3,7 @@ use mdk_core::MDK;
 use nostr_sdk::prelude::*;
 // sha2 available for future hash verification if needed
 use std::fs;
+use std::io::Read;
 use std::path::Path;
 
 use crate::acl::access_control::AccessControl;
@@ -11,15 +12,41 @@ use crate::keyring;
 use crate::relay::pool;
 use crate::storage::file_store::FileStore;
 
+/// Work out the message text: the positional argument, `input` (stdin) when
+/// that is "-" or `stdin` is set, or the contents of `file`. Text read from
+/// stdin or a file loses its trailing newlines. No message at all is an
+/// empty body, which only makes sense with `--media`.
+pub fn message_body(
+    message: Option<String>,
+    stdin: bool,
+    file: Option<&str>,
+    mut input: impl Read,
+) -> Result<String> {
+    let body = if let Some(path) = file {
+        fs::read_to_string(path).with_context(|| format!("Failed to read message file {}", path))?
+    } else if stdin || message.as_deref() == Some("-") {
+        let mut body = String::new();
+        input.read_to_string(&mut body).context("Failed to read message from stdin")?;
+        body
+    } else {
+        return Ok(message.unwrap_or_default());
+    };
+    Ok(body.trim_end_matches(['\n', '\r']).to_string())
+}
+
 pub async fn run(
     group_id: String,
     message: String,
     key_path: Option<String>,
     data_dir: Option<String>,
     media_path: Option<String>,
-    blossom_url: String,
+    blossom_url: Option<String>,
 ) -> Result<()> {
+    if message.is_empty() && media_path.is_none() {
+        anyhow::bail!("No message given: pass it as an argument, `-`/--stdin, or --file");
+    }
     let data = config::data_dir(data_dir.as_deref());
+    let blossom_url = config::settings(&data).blossom_url(blossom_url);
     let store = FileStore::new(&data)?;
 
     let group = store.find_group_by_prefix(&group_id)?
@@ -151,8 +178,7 @@ pub async fn run(
             .context("Failed to encrypt media message")?
     } else {
         // Plain text message
-        let rumor = EventBuilder::new(Kind::TextNote, &message)
-            .build(keys.public_key());
+        let rumor = text_rumor(keys.public_key(), &message);
 
         mdk.create_message(&mls_group_id, rumor)
             .context("Failed to encrypt message")?
@@ -170,6 +196,11 @@ pub async fn run(
     Ok(())
 }
 
+/// The unsigned rumor for a plain text message.
+fn text_rumor(author: PublicKey, message: &str) -> UnsignedEvent {
+    EventBuilder::new(Kind::TextNote, message).build(author)
+}
+
 /// Send a typing indicator (kind 10000 ephemeral MLS message).
 pub async fn typing(
     group_id: String,
@@ -230,3 +261,39 @@ fn guess_mime_type(filename: &str) -> String {
         _ => "application/octet-stream".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn stdin_fixture_becomes_rumor_content() {
+        let fixture = Cursor::new("line one\nline \"two\" with $quotes\n\n");
+        let body = message_body(Some("-".into()), false, None, fixture).unwrap();
+        let rumor = text_rumor(Keys::generate().public_key(), &body);
+        assert_eq!(rumor.content, "line one\nline \"two\" with $quotes");
+        assert_eq!(rumor.kind, Kind::TextNote);
+    }
+
+    #[test]
+    fn stdin_flag_reads_stdin() {
+        let body = message_body(None, true, None, Cursor::new("hello\n")).unwrap();
+        assert_eq!(body, "hello");
+    }
+
+    #[test]
+    fn positional_message_is_used_verbatim() {
+        let body = message_body(Some("hi there\n".into()), false, None, Cursor::new("ignored")).unwrap();
+        assert_eq!(body, "hi there\n");
+    }
+
+    #[test]
+    fn file_contents_become_the_message() {
+        let path = std::env::temp_dir().join(format!("burrow-send-test-{}.txt", std::process::id()));
+        fs::write(&path, "from a file\r\n").unwrap();
+        let body = message_body(None, false, path.to_str(), Cursor::new("ignored")).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(body, "from a file");
+    }
+}
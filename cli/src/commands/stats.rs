@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+use mdk_core::MDK;
+use nostr_sdk::prelude::*;
+use std::fs;
+use std::path::Path;
+
+use crate::acl::access_control::AccessControl;
+use crate::config;
+use crate::keyring;
+use crate::storage::file_store::FileStore;
+
+/// Summarize what's stored in the data directory. Works offline.
+///
+/// Pending welcomes live in the encrypted MLS database, so they're only
+/// counted when the secret key is available.
+pub fn run(key_path: Option<String>, data_dir: Option<String>) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    if !data.exists() {
+        anyhow::bail!("No data directory at {} — run `burrow init` first", data.display());
+    }
+    let store = FileStore::new(&data)?;
+
+    let mut groups = store.load_groups()?;
+    groups.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut per_group = Vec::with_capacity(groups.len());
+    for g in &groups {
+        let count = store.load_messages(&g.mls_group_id_hex, usize::MAX)?.len();
+        per_group.push((g, count));
+    }
+    let total_messages: usize = per_group.iter().map(|(_, n)| n).sum();
+
+    println!("📊 Burrow data: {}", data.display());
+    println!("   Groups:   {}", groups.len());
+    println!("   Messages: {}", total_messages);
+    for (g, count) in &per_group {
+        println!("     {:<24} {:>6}  ({})", g.name, count, &g.mls_group_id_hex[..12.min(g.mls_group_id_hex.len())]);
+    }
+
+    let (media_files, media_bytes) = dir_usage(&data.join("media"))?;
+    println!("   Media:    {} files, {}", media_files, format_bytes(media_bytes));
+
+    match pending_welcomes(&data, key_path) {
+        Ok(n) => println!("   Pending welcomes: {}", n),
+        Err(e) => println!("   Pending welcomes: unavailable ({})", e),
+    }
+
+    let acl = AccessControl::load(&data)?;
+    println!(
+        "   ACL:      {} contacts, {} groups (default policy: {})",
+        acl.config.allowed_contacts.len(),
+        acl.config.allowed_groups.len(),
+        acl.config.default_policy
+    );
+
+    let mls_bytes: u64 = ["mls.sqlite", "mls.sqlite-wal", "mls.sqlite-shm"]
+        .iter()
+        .filter_map(|f| fs::metadata(data.join(f)).ok())
+        .map(|m| m.len())
+        .sum();
+    println!("   MLS DB:   {}", format_bytes(mls_bytes));
+
+    let (_, total_bytes) = dir_usage(&data)?;
+    println!("   Total:    {}", format_bytes(total_bytes));
+    Ok(())
+}
+
+/// Count pending (not yet accepted) welcomes in the local MLS database.
+fn pending_welcomes(data: &Path, key_path: Option<String>) -> Result<usize> {
+    let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("no secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let mdk_storage = keyring::open_mls_storage(&data.join("mls.sqlite"), &keys)?;
+    let mdk = MDK::new(mdk_storage);
+    Ok(mdk.get_pending_welcomes(None)?.len())
+}
+
+/// (file count, total bytes) of everything under `dir`, recursively.
+fn dir_usage(dir: &Path) -> Result<(u64, u64)> {
+    let mut files = 0;
+    let mut bytes = 0;
+    if !dir.exists() {
+        return Ok((0, 0));
+    }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+        if meta.is_dir() {
+            let (f, b) = dir_usage(&entry.path())?;
+            files += f;
+            bytes += b;
+        } else {
+            files += 1;
+            bytes += meta.len();
+        }
+    }
+    Ok((files, bytes))
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use mdk_core::messages::MessageProcessingResult;
+use mdk_core::MDK;
+use nostr_sdk::prelude::*;
+use std::fs;
+use std::io::Read;
+
+use crate::config;
+use crate::keyring;
+use crate::storage::file_store::FileStore;
+
+/// Validate a kind 445 event and run it through `process_message`.
+///
+/// Reads the event JSON from `input` (a file path) or stdin when `input` is
+/// None or "-". Goes through the same MDK path as the daemon, so a commit
+/// processed here is applied to the local MLS state just as if the daemon
+/// had received it.
+pub async fn run(
+    input: Option<String>,
+    key_path: Option<String>,
+    data_dir: Option<String>,
+) -> Result<()> {
+    let json = match input.as_deref() {
+        None | Some("-") => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf).context("Failed to read stdin")?;
+            buf
+        }
+        Some(path) => fs::read_to_string(path).context(format!("Failed to read {}", path))?,
+    };
+
+    let event = Event::from_json(json.trim()).context("Invalid event JSON")?;
+    println!("🔎 Event {}", event.id.to_hex());
+    println!("   Kind:   {}", event.kind.as_u16());
+    println!("   Author: {}", event.pubkey.to_hex());
+
+    match event.verify() {
+        Ok(()) => println!("   ✅ Signature valid"),
+        Err(e) => anyhow::bail!("Invalid event signature: {}", e),
+    }
+    if event.kind != Kind::MlsGroupMessage {
+        anyhow::bail!("Expected kind 445 (MLS group message), got kind {}", event.kind.as_u16());
+    }
+
+    let data = config::data_dir(data_dir.as_deref());
+    let store = FileStore::new(&data)?;
+    let h_tag = event.tags.iter()
+        .map(|t| t.as_slice())
+        .find(|t| t.first().map(|k| k == "h").unwrap_or(false))
+        .and_then(|t| t.get(1).cloned());
+    match &h_tag {
+        Some(nostr_gid) => {
+            let known = store.load_groups()?.into_iter()
+                .find(|g| &g.nostr_group_id_hex == nostr_gid);
+            match known {
+                Some(g) => println!("   Group:  {} ({})", g.name, nostr_gid),
+                None => println!("   Group:  {} (⚠️ not in local store)", nostr_gid),
+            }
+        }
+        None => println!("   Group:  ⚠️ missing h tag"),
+    }
+
+    let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp).context("Failed to read secret key")?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+
+    let mls_db_path = data.join("mls.sqlite");
+    let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
+    let mdk = MDK::new(mdk_storage);
+
+    match mdk.process_message(&event) {
+        Ok(MessageProcessingResult::ApplicationMessage(msg)) => {
+            println!("📨 Application message");
+            println!("   Rumor ID: {}", msg.id.to_hex());
+            println!("   Sender:   {}", msg.pubkey.to_hex());
+            println!("   Kind:     {}", msg.kind.as_u16());
+            println!("   Epoch:    {}", msg.epoch.unwrap_or(0));
+            for tag in msg.tags.iter() {
+                println!("   Tag:      {:?}", tag.as_slice());
+            }
+            println!("   Content:  {}", msg.content);
+        }
+        Ok(MessageProcessingResult::Commit { mls_group_id }) => {
+            println!("🔁 Commit applied to group {}", hex::encode(mls_group_id.as_slice()));
+        }
+        Ok(MessageProcessingResult::Proposal(_)) => {
+            println!("📝 Proposal (auto-committed)");
+        }
+        Ok(MessageProcessingResult::PendingProposal { mls_group_id }) => {
+            println!("📝 Proposal pending admin commit in group {}", hex::encode(mls_group_id.as_slice()));
+        }
+        Ok(MessageProcessingResult::IgnoredProposal { mls_group_id, .. }) => {
+            println!("📝 Proposal ignored in group {}", hex::encode(mls_group_id.as_slice()));
+        }
+        Ok(MessageProcessingResult::ExternalJoinProposal { mls_group_id }) => {
+            println!("📝 External join proposal in group {}", hex::encode(mls_group_id.as_slice()));
+        }
+        Ok(MessageProcessingResult::Unprocessable { mls_group_id }) => {
+            println!("❌ Unprocessable in group {} (wrong epoch, unknown sender, or not for us)", hex::encode(mls_group_id.as_slice()));
+        }
+        Ok(MessageProcessingResult::PreviouslyFailed) => {
+            println!("❌ Previously failed — MDK already rejected this event");
+        }
+        Err(e) => {
+            anyhow::bail!("process_message failed: {}", e);
+        }
+    }
+    Ok(())
+}
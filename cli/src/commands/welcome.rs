@@ -23,7 +23,8 @@ pub async fn list(
         .context("Invalid secret key")?;
     let keys = Keys::new(sk);
 
-    let relays = config::default_relays();
+    let settings = config::settings(&data);
+    let relays = settings.relays(None);
     let client = pool::connect(&keys, &relays).await?;
 
     println!("🔍 Fetching NIP-59 gift wraps (kind 1059) for our pubkey...");
@@ -34,7 +35,7 @@ pub async fn list(
         .limit(50);
 
     let events = client
-        .fetch_events(filter, std::time::Duration::from_secs(15))
+        .fetch_events(filter, settings.fetch_timeout(15))
         .await
         .context("Failed to fetch gift wrap events")?;
 
@@ -112,7 +113,8 @@ pub async fn accept(
         .context("Invalid secret key")?;
     let keys = Keys::new(sk);
 
-    let relays = config::default_relays();
+    let settings = config::settings(&data);
+    let relays = settings.relays(None);
     let client = pool::connect(&keys, &relays).await?;
     let mls_db_path = data.join("mls.sqlite");
     let mdk_storage = keyring::open_mls_storage(&mls_db_path, &keys)?;
@@ -128,7 +130,7 @@ pub async fn accept(
 
     println!("🔍 Fetching gift wrap event {}...", &event_id_hex[..12]);
     let events = client
-        .fetch_events(filter, std::time::Duration::from_secs(15))
+        .fetch_events(filter, settings.fetch_timeout(15))
         .await
         .context("Failed to fetch gift wrap event")?;
 
@@ -169,7 +171,7 @@ pub async fn accept(
         name: welcome.group_name.clone(),
         description: welcome.group_description.clone(),
         admin_pubkeys: vec![unwrapped.sender.to_hex()],
-        relay_urls: config::default_relays(),
+        relay_urls: relays.clone(),
         created_at: chrono::Utc::now().timestamp() as u64,
     };
     store.save_group(&group)?;
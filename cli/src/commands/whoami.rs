@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+use nostr_sdk::prelude::*;
+use std::fs;
+
+use crate::config;
+use crate::relay::pool;
+
+/// Print our identity, the configured relays and, unless `offline`, the
+/// newest KeyPackage (kind 443) we have on those relays.
+pub async fn run(key_path: Option<String>, data_dir: Option<String>, offline: bool) -> Result<()> {
+    let data = config::data_dir(data_dir.as_deref());
+    let kp = key_path.map(std::path::PathBuf::from).unwrap_or_else(config::default_key_path);
+    let secret = fs::read_to_string(&kp)
+        .with_context(|| format!("No secret key at {} — run `burrow init` first", kp.display()))?;
+    let sk = SecretKey::from_hex(secret.trim())
+        .or_else(|_| SecretKey::from_bech32(secret.trim()))
+        .context("Invalid secret key")?;
+    let keys = Keys::new(sk);
+    config::warn_if_key_exposed(&kp);
+
+    let pubkey = keys.public_key();
+    let settings = config::settings(&data);
+    let relay_urls = settings.relays(None);
+
+    println!("🦫 Identity: {}", pubkey.to_bech32()?);
+    println!("   Hex:      {}", pubkey.to_hex());
+    println!("   Key file: {}", kp.display());
+    println!("   Data dir: {}", data.display());
+    println!("   Relays:   {}", relay_urls.join(", "));
+
+    if offline {
+        return Ok(());
+    }
+
+    let client = pool::connect(&keys, &relay_urls).await?;
+    let filter = Filter::new().author(pubkey).kind(Kind::MlsKeyPackage);
+    let events = client
+        .fetch_events(filter, settings.fetch_timeout(10))
+        .await
+        .context("Failed to fetch KeyPackages")?;
+    client.disconnect().await;
+
+    match events.into_iter().max_by_key(|e| e.created_at) {
+        Some(event) => {
+            let age = Timestamp::now().as_secs().saturating_sub(event.created_at.as_secs());
+            println!("📦 KeyPackage: {}", event.id.to_hex());
+            println!("   Published: {} ago", format_age(age));
+        }
+        None => println!("📦 KeyPackage: none found — run `burrow init`"),
+    }
+    Ok(())
+}
+
+/// Coarse human-readable duration, e.g. "3d 4h" or "12m".
+fn format_age(secs: u64) -> String {
+    let (days, hours, mins) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else if mins > 0 {
+        format!("{}m", mins)
+    } else {
+        format!("{}s", secs)
+    }
+}
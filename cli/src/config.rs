@@ -1,4 +1,17 @@
-use std::path::PathBuf;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Persisted settings, in the data directory.
+pub const SETTINGS_FILE: &str = "config.json";
+
+pub const DEFAULT_BLOSSOM_URL: &str = "https://blossom.primal.net";
+pub const DEFAULT_RECONNECT_DELAY_MS: u64 = 5000;
+/// Shortest reconnect delay allowed; anything less spins on a dead relay.
+pub const MIN_RECONNECT_DELAY_MS: u64 = 100;
 
 /// Default relays for Marmot/Burrow.
 pub fn default_relays() -> Vec<String> {
@@ -10,6 +23,152 @@ pub fn default_relays() -> Vec<String> {
     ]
 }
 
+/// What the daemon does with incoming welcomes.
+pub const WELCOME_POLICIES: [&str; 2] = ["auto", "manual"];
+
+/// Settings persisted in `config.json`. Unset fields fall back to the
+/// built-in defaults; an explicit command-line flag beats both.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relays: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blossom_url: Option<String>,
+    /// "auto" accepts every welcome, "manual" leaves them pending for
+    /// `burrow welcome accept`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub welcome_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconnect_delay_ms: Option<u64>,
+}
+
+/// Setting keys accepted by `burrow config get/set`, with a description.
+pub const SETTING_KEYS: [(&str, &str); 5] = [
+    ("relays", "Default relays, comma-separated"),
+    ("blossomUrl", "Blossom server for media uploads"),
+    ("welcomePolicy", "auto | manual — whether the daemon accepts welcomes"),
+    ("fetchTimeoutSecs", "Timeout for relay queries, in seconds"),
+    ("reconnectDelayMs", "Daemon reconnect delay, in milliseconds"),
+];
+
+impl Settings {
+    /// Load `config.json` from the data directory; missing means all defaults.
+    pub fn load(data_dir: &Path) -> Result<Self> {
+        let path = data_dir.join(SETTINGS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = fs::read_to_string(&path).context("Failed to read config.json")?;
+        serde_json::from_str(&data).context("Failed to parse config.json")
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<()> {
+        fs::create_dir_all(data_dir)?;
+        let data = serde_json::to_string_pretty(self)?;
+        fs::write(data_dir.join(SETTINGS_FILE), data)?;
+        Ok(())
+    }
+
+    /// The stored value of `key`, formatted as `set` accepts it.
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(match key {
+            "relays" => self.relays.as_ref().map(|r| r.join(",")),
+            "blossomUrl" => self.blossom_url.clone(),
+            "welcomePolicy" => self.welcome_policy.clone(),
+            "fetchTimeoutSecs" => self.fetch_timeout_secs.map(|v| v.to_string()),
+            "reconnectDelayMs" => self.reconnect_delay_ms.map(|v| v.to_string()),
+            _ => anyhow::bail!("Unknown setting '{}'", key),
+        })
+    }
+
+    /// Validate and store `value` for `key`. An empty value unsets it.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let value = value.trim();
+        let unset = value.is_empty();
+        match key {
+            "relays" => {
+                let relays: Vec<String> = value
+                    .split(',')
+                    .map(|r| r.trim().to_string())
+                    .filter(|r| !r.is_empty())
+                    .collect();
+                if let Some(bad) = relays.iter().find(|r| !r.starts_with("wss://") && !r.starts_with("ws://")) {
+                    anyhow::bail!("Invalid relay URL '{}'", bad);
+                }
+                self.relays = (!relays.is_empty()).then_some(relays);
+            }
+            "blossomUrl" => {
+                if !unset && !value.starts_with("https://") && !value.starts_with("http://") {
+                    anyhow::bail!("Invalid Blossom URL '{}'", value);
+                }
+                self.blossom_url = (!unset).then(|| value.to_string());
+            }
+            "welcomePolicy" => {
+                if !unset && !WELCOME_POLICIES.contains(&value) {
+                    anyhow::bail!("welcomePolicy must be one of: {}", WELCOME_POLICIES.join(", "));
+                }
+                self.welcome_policy = (!unset).then(|| value.to_string());
+            }
+            "fetchTimeoutSecs" => {
+                self.fetch_timeout_secs = if unset { None } else { Some(parse_positive(key, value)?) };
+            }
+            "reconnectDelayMs" => {
+                self.reconnect_delay_ms = if unset { None } else { Some(parse_positive(key, value)?) };
+            }
+            _ => anyhow::bail!("Unknown setting '{}'", key),
+        }
+        Ok(())
+    }
+
+    /// Relays: `flag` > config file > built-in default.
+    pub fn relays(&self, flag: Option<Vec<String>>) -> Vec<String> {
+        flag.or_else(|| self.relays.clone()).unwrap_or_else(default_relays)
+    }
+
+    /// Blossom server: `flag` > config file > built-in default.
+    pub fn blossom_url(&self, flag: Option<String>) -> String {
+        flag.or_else(|| self.blossom_url.clone())
+            .unwrap_or_else(|| DEFAULT_BLOSSOM_URL.to_string())
+    }
+
+    /// Whether the daemon should accept welcomes on its own.
+    pub fn auto_accept_welcomes(&self) -> bool {
+        self.welcome_policy.as_deref() != Some("manual")
+    }
+
+    /// Relay query timeout: config file > the command's own default.
+    pub fn fetch_timeout(&self, default_secs: u64) -> Duration {
+        Duration::from_secs(self.fetch_timeout_secs.unwrap_or(default_secs))
+    }
+
+    /// Reconnect delay: `flag` > config file > built-in default, and never
+    /// below `MIN_RECONNECT_DELAY_MS`.
+    pub fn reconnect_delay_ms(&self, flag: Option<u64>) -> u64 {
+        flag.or(self.reconnect_delay_ms)
+            .unwrap_or(DEFAULT_RECONNECT_DELAY_MS)
+            .max(MIN_RECONNECT_DELAY_MS)
+    }
+}
+
+fn parse_positive(key: &str, value: &str) -> Result<u64> {
+    match value.parse::<u64>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => anyhow::bail!("{} must be a positive integer", key),
+    }
+}
+
+/// Load settings for `data_dir`, warning and falling back to defaults if
+/// `config.json` is unreadable so a bad edit never blocks every command.
+pub fn settings(data_dir: &Path) -> Settings {
+    Settings::load(data_dir).unwrap_or_else(|e| {
+        eprintln!("⚠️ Ignoring {}: {:#}", data_dir.join(SETTINGS_FILE).display(), e);
+        Settings::default()
+    })
+}
+
 /// Resolve the data directory (~/.burrow by default).
 pub fn data_dir(custom: Option<&str>) -> PathBuf {
     if let Some(d) = custom {
@@ -28,3 +187,50 @@ pub fn default_key_path() -> PathBuf {
         .join(".clawstr")
         .join("secret.key")
 }
+
+/// Write a secret key file readable only by the owner (0600 on Unix).
+///
+/// The file is created with restrictive permissions up front, so the key is
+/// never briefly on disk with the default umask.
+pub fn write_secret_key(path: &Path, secret: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut opts = fs::OpenOptions::new();
+    opts.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(0o600);
+    }
+    let mut file = opts.open(path)?;
+    file.write_all(secret.as_bytes())?;
+    // `mode` only applies on creation; tighten a pre-existing file too.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+/// Warn on stderr if a secret key file is readable by group or others.
+pub fn warn_if_key_exposed(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(meta) = fs::metadata(path) {
+            let mode = meta.permissions().mode() & 0o777;
+            if mode & 0o077 != 0 {
+                eprintln!(
+                    "⚠️  Secret key {} has permissions {:o} — run `chmod 600 {}`",
+                    path.display(),
+                    mode,
+                    path.display()
+                );
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
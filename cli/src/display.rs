@@ -0,0 +1,119 @@
+//! Rendering of decrypted messages for `burrow listen` and `burrow read`.
+
+use std::path::Path;
+
+/// NIP-25 reaction.
+const REACTION_KIND: u16 = 7;
+
+/// Render a message as `<sender>.. : <text>`, the part of a line after the
+/// timestamp. Reactions become `↩ <sender>.. reacted <emoji> to <target>`
+/// and replies get a `↳ re <parent>:` prefix, so neither shows up as bare
+/// `+` or out-of-context text.
+pub fn format_message(
+    kind: Option<u16>,
+    author_hex: &str,
+    content: &str,
+    tags: &[Vec<String>],
+    media_dir: Option<&Path>,
+) -> String {
+    let sender = short(author_hex);
+    if kind == Some(REACTION_KIND) {
+        let target = reaction_target(tags).map(short).unwrap_or("?");
+        return format!("↩ {}.. reacted {} to {}", sender, reaction_emoji(content), target);
+    }
+    let text = crate::media::format_message_with_media(content, tags, media_dir);
+    match reply_parent(tags) {
+        Some(parent) => format!("{}.. : ↳ re {}: {}", sender, short(parent), text),
+        None => format!("{}.. : {}", sender, text),
+    }
+}
+
+/// The first 12 characters of an id. Tag values come from other members'
+/// rumors and need not be hex, so this cuts on a char boundary.
+fn short(hex: &str) -> &str {
+    hex.char_indices().nth(12).map_or(hex, |(i, _)| &hex[..i])
+}
+
+/// NIP-25: "+" (or empty) is a like, "-" a dislike; anything else is the
+/// emoji itself.
+fn reaction_emoji(content: &str) -> &str {
+    match content.trim() {
+        "" | "+" => "👍",
+        "-" => "👎",
+        other => other,
+    }
+}
+
+/// The reacted-to event: the last `e` tag (NIP-25).
+fn reaction_target(tags: &[Vec<String>]) -> Option<&str> {
+    tags.iter().rev().find(|t| t.len() >= 2 && t[0] == "e").map(|t| t[1].as_str())
+}
+
+/// The parent of a reply (NIP-10): the `e` tag marked "reply", else one
+/// marked "root", else the last unmarked `e` tag.
+fn reply_parent(tags: &[Vec<String>]) -> Option<&str> {
+    let e_tags: Vec<&Vec<String>> = tags.iter().filter(|t| t.len() >= 2 && t[0] == "e").collect();
+    let marked = |marker: &str| e_tags.iter().find(|t| t.get(3).map(String::as_str) == Some(marker));
+    marked("reply")
+        .or_else(|| marked("root"))
+        .or_else(|| e_tags.iter().rev().find(|t| t.get(3).is_none_or(|m| m.is_empty())))
+        .copied()
+        .map(|t| t[1].as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1";
+    const PARENT: &str = "beefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeefbeef";
+    const ROOT: &str = "0000111122223333444455556666777788889999aaaabbbbccccddddeeeeffff";
+
+    fn tag(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn reaction_renders_emoji_and_target() {
+        let tags = vec![tag(&["e", PARENT]), tag(&["p", ALICE])];
+        assert_eq!(
+            format_message(Some(7), ALICE, "🔥", &tags, None),
+            "↩ a1a1a1a1a1a1.. reacted 🔥 to beefbeefbeef"
+        );
+    }
+
+    #[test]
+    fn plus_reaction_is_a_like() {
+        let tags = vec![tag(&["e", PARENT])];
+        assert_eq!(format_message(Some(7), ALICE, "+", &tags, None), "↩ a1a1a1a1a1a1.. reacted 👍 to beefbeefbeef");
+    }
+
+    #[test]
+    fn marked_reply_is_prefixed_with_its_parent() {
+        let tags = vec![tag(&["e", ROOT, "", "root"]), tag(&["e", PARENT, "wss://relay", "reply"])];
+        assert_eq!(
+            format_message(Some(1), ALICE, "agreed", &tags, None),
+            "a1a1a1a1a1a1.. : ↳ re beefbeefbeef: agreed"
+        );
+    }
+
+    #[test]
+    fn unmarked_e_tag_counts_as_reply() {
+        let tags = vec![tag(&["e", PARENT])];
+        assert_eq!(format_message(None, ALICE, "yes", &tags, None), "a1a1a1a1a1a1.. : ↳ re beefbeefbeef: yes");
+    }
+
+    #[test]
+    fn plain_message_is_unchanged() {
+        assert_eq!(format_message(Some(1), ALICE, "hello", &[], None), "a1a1a1a1a1a1.. : hello");
+    }
+
+    #[test]
+    fn non_ascii_tag_values_do_not_panic() {
+        let target = "ééééééééééééé";
+        let tags = vec![tag(&["e", target])];
+        assert_eq!(format_message(Some(7), "ñ", "+", &tags, None), "↩ ñ.. reacted 👍 to éééééééééééé");
+        let tags = vec![tag(&["e", "a€bcdefghijklmnop", "", "reply"])];
+        assert_eq!(format_message(Some(1), ALICE, "hi", &tags, None), "a1a1a1a1a1a1.. : ↳ re a€bcdefghijk: hi");
+    }
+}
@@ -1,8 +1,10 @@
 mod acl;
 mod commands;
 mod config;
+mod display;
 mod keyring;
 pub mod media;
+mod metrics;
 #[cfg(feature = "webrtc")]
 pub mod webrtc;
 mod relay;
@@ -30,6 +32,19 @@ enum Commands {
         relay: Option<Vec<String>>,
         #[arg(short = 'g', long)]
         generate: bool,
+        /// Import an existing secret key (nsec or hex) from stdin
+        #[arg(long, conflicts_with = "generate")]
+        import_stdin: bool,
+    },
+    /// Show your identity, relays, and latest published KeyPackage
+    Whoami {
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+        /// Skip querying relays for the KeyPackage
+        #[arg(long)]
+        offline: bool,
     },
     /// Group management
     #[command(subcommand)]
@@ -38,6 +53,9 @@ enum Commands {
     Groups {
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
+        /// Print a JSON array instead of text
+        #[arg(long)]
+        json: bool,
     },
     /// Invite a user to a group
     Invite {
@@ -51,7 +69,14 @@ enum Commands {
     /// Send an encrypted message
     Send {
         group_id: String,
-        message: String,
+        /// Message text; "-" reads it from stdin
+        message: Option<String>,
+        /// Read the message from stdin until EOF
+        #[arg(long, conflicts_with = "message")]
+        stdin: bool,
+        /// Send the contents of a text file as the message
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["message", "stdin"])]
+        file: Option<String>,
         #[arg(short = 'k', long)]
         key_path: Option<String>,
         #[arg(short = 'd', long)]
@@ -59,9 +84,71 @@ enum Commands {
         /// Attach a file (MIP-04 encrypted, uploaded to Blossom)
         #[arg(long)]
         media: Option<String>,
-        /// Blossom server URL for media uploads
-        #[arg(long, default_value = "https://blossom.primal.net")]
-        blossom_url: String,
+        /// Blossom server URL for media uploads [default: config, else https://blossom.primal.net]
+        #[arg(long)]
+        blossom_url: Option<String>,
+    },
+    /// Reply to a message in a group (NIP-10 threaded)
+    Reply {
+        group_id: String,
+        /// Event ID of the message being replied to (full hex or stored prefix)
+        parent_event_id: String,
+        message: String,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Send a direct message, creating the 1:1 group if needed
+    Dm {
+        /// Recipient: npub, hex pubkey, or NIP-05 (name@domain)
+        recipient: String,
+        message: String,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+        /// Relays for a newly created DM group
+        #[arg(short = 'r', long, num_args = 1..)]
+        relay: Option<Vec<String>>,
+    },
+    /// Write the secret key and local state to a passphrase-encrypted backup
+    Export {
+        /// Backup file to write
+        #[arg(short = 'o', long)]
+        out: String,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Restore a backup made with `burrow export`
+    Import {
+        /// Backup file to read
+        input: String,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+        /// Overwrite an existing data dir and key
+        #[arg(long)]
+        force: bool,
+    },
+    /// Validate and decrypt a kind 445 event (from a file, or stdin)
+    Verify {
+        /// Path to the event JSON; reads stdin if omitted or "-"
+        input: Option<String>,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Summarize stored groups, messages, media, and databases (offline)
+    Stats {
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
     },
     /// Read stored messages
     Read {
@@ -70,6 +157,9 @@ enum Commands {
         limit: usize,
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
+        /// Print the stored messages as a JSON array instead of text
+        #[arg(long)]
+        json: bool,
     },
     /// Listen for real-time messages in a group
     Listen {
@@ -78,6 +168,9 @@ enum Commands {
         key_path: Option<String>,
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
+        /// Print the last N stored messages before streaming
+        #[arg(long, default_value_t = 0)]
+        replay: usize,
     },
     /// Run persistent daemon on all groups (JSONL output)
     Daemon {
@@ -87,10 +180,26 @@ enum Commands {
         data_dir: Option<String>,
         #[arg(short = 'l', long)]
         log_file: Option<String>,
-        #[arg(long, default_value = "5000")]
-        reconnect_delay: u64,
+        /// Reconnect delay in ms, at least 100 [default: config, else 5000]
+        #[arg(long, value_parser = clap::value_parser!(u64).range(100..))]
+        reconnect_delay: Option<u64>,
         #[arg(long)]
         no_access_control: bool,
+        /// Message to send into a group right after auto-accepting its welcome
+        #[arg(long)]
+        welcome_greeting: Option<String>,
+        /// Serve Prometheus metrics on this port
+        #[arg(long)]
+        metrics_port: Option<u16>,
+        /// Address for the metrics endpoint (only change this behind a firewall)
+        #[arg(long, default_value = "127.0.0.1")]
+        metrics_bind: String,
+        /// Seconds between heartbeat JSONL lines (0 disables)
+        #[arg(long, default_value_t = 60)]
+        heartbeat_secs: u64,
+        /// Delete stored messages older than this many days (checked hourly)
+        #[arg(long)]
+        retention_days: Option<u64>,
     },
     /// Send read receipt(s) for messages in a group
     ReadReceipt {
@@ -133,6 +242,12 @@ enum Commands {
     /// Access control management
     #[command(subcommand)]
     Acl(AclCommands),
+    /// Encrypted media attachments
+    #[command(subcommand)]
+    Media(MediaCommands),
+    /// View and change persisted settings (config.json in the data dir)
+    #[command(subcommand)]
+    Config(ConfigCommands),
 }
 
 #[derive(Subcommand)]
@@ -149,6 +264,20 @@ enum GroupCommands {
         #[arg(short = 'r', long, num_args = 1..)]
         relay: Option<Vec<String>>,
     },
+    /// Leave a group (publishes an MLS leave proposal)
+    Leave {
+        group_id: String,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+        /// Also delete stored messages and read receipts
+        #[arg(long)]
+        purge: bool,
+        /// Don't ask for confirmation
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -171,6 +300,45 @@ enum WelcomeCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum MediaCommands {
+    /// Download and decrypt the attachments of a stored message
+    Download {
+        group_id: String,
+        /// Event ID (or unique prefix) of the message
+        event_id: String,
+        /// Output directory (defaults to <data_dir>/media)
+        #[arg(short = 'o', long)]
+        out: Option<String>,
+        #[arg(short = 'k', long)]
+        key_path: Option<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Show every setting and its value
+    Show {
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Print one setting
+    Get {
+        key: String,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Change a setting; an empty value resets it to the default
+    Set {
+        key: String,
+        value: String,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+}
+
 #[derive(Subcommand)]
 enum AclCommands {
     /// Display access control config
@@ -181,6 +349,14 @@ enum AclCommands {
     /// Add contact to allowlist
     AddContact {
         pubkey: String,
+        /// Only allow the contact in this group (ID prefix or name); repeatable
+        #[arg(short = 'g', long = "group")]
+        groups: Vec<String>,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
+    /// Add newline-delimited pubkeys (hex or npub) from stdin to the allowlist
+    Import {
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
     },
@@ -190,6 +366,15 @@ enum AclCommands {
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
     },
+    /// Limit how many messages a contact may send per rolling minute
+    RateLimit {
+        /// Contact npub or hex pubkey
+        pubkey: String,
+        /// Messages per minute; 0 removes the limit
+        per_minute: u32,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
     /// Add group to allowlist
     AddGroup {
         group_id: String,
@@ -202,10 +387,28 @@ enum AclCommands {
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
     },
+    /// Show whether a sender would be allowed in a group, and why
+    Test {
+        /// Sender npub or hex pubkey
+        pubkey: String,
+        /// Group ID (prefix) or name
+        group_id: String,
+        #[arg(short = 'd', long)]
+        data_dir: Option<String>,
+    },
     /// Show audit log
     Audit {
         #[arg(long, default_value = "7")]
         days: u32,
+        /// Only entries from this sender (npub or hex)
+        #[arg(long)]
+        sender: Option<String>,
+        /// Only entries where access was denied
+        #[arg(long)]
+        denied_only: bool,
+        /// Print a JSON array instead of text
+        #[arg(long)]
+        json: bool,
         #[arg(short = 'd', long)]
         data_dir: Option<String>,
     },
@@ -216,31 +419,57 @@ async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init { key_path, data_dir, relay, generate } => {
-            commands::init::run(key_path, data_dir, relay, generate).await?;
+        Commands::Init { key_path, data_dir, relay, generate, import_stdin } => {
+            commands::init::run(key_path, data_dir, relay, generate, import_stdin).await?;
+        }
+        Commands::Whoami { key_path, data_dir, offline } => {
+            commands::whoami::run(key_path, data_dir, offline).await?;
         }
         Commands::Group(sub) => match sub {
             GroupCommands::Create { name, description, key_path, data_dir, relay } => {
                 commands::group::create(name, description, key_path, data_dir, relay).await?;
             }
+            GroupCommands::Leave { group_id, key_path, data_dir, purge, yes } => {
+                commands::group::leave(group_id, key_path, data_dir, purge, yes).await?;
+            }
         },
-        Commands::Groups { data_dir } => {
-            commands::group::list(data_dir)?;
+        Commands::Groups { data_dir, json } => {
+            commands::group::list(data_dir, json)?;
         }
         Commands::Invite { group_id, pubkey, key_path, data_dir } => {
             commands::invite::run(group_id, pubkey, key_path, data_dir).await?;
         }
-        Commands::Send { group_id, message, key_path, data_dir, media, blossom_url } => {
+        Commands::Send { group_id, message, stdin, file, key_path, data_dir, media, blossom_url } => {
+            let message = commands::send::message_body(message, stdin, file.as_deref(), std::io::stdin().lock())?;
             commands::send::run(group_id, message, key_path, data_dir, media, blossom_url).await?;
         }
-        Commands::Read { group_id, limit, data_dir } => {
-            commands::read::run(group_id, limit, data_dir).await?;
+        Commands::Reply { group_id, parent_event_id, message, key_path, data_dir } => {
+            commands::reply::run(group_id, parent_event_id, message, key_path, data_dir).await?;
+        }
+        Commands::Dm { recipient, message, key_path, data_dir, relay } => {
+            commands::dm::run(recipient, message, key_path, data_dir, relay).await?;
+        }
+        Commands::Export { out, key_path, data_dir } => {
+            commands::backup::export(out, key_path, data_dir)?;
+        }
+        Commands::Import { input, key_path, data_dir, force } => {
+            commands::backup::import(input, key_path, data_dir, force)?;
         }
-        Commands::Listen { group_id, key_path, data_dir } => {
-            commands::listen::run(group_id, key_path, data_dir).await?;
+        Commands::Verify { input, key_path, data_dir } => {
+            commands::verify::run(input, key_path, data_dir).await?;
         }
-        Commands::Daemon { key_path, data_dir, log_file, reconnect_delay, no_access_control } => {
-            commands::daemon::run(key_path, data_dir, log_file, reconnect_delay, no_access_control).await?;
+        Commands::Stats { key_path, data_dir } => {
+            commands::stats::run(key_path, data_dir)?;
+        }
+        Commands::Read { group_id, limit, data_dir, json } => {
+            commands::read::run(group_id, limit, data_dir, json).await?;
+        }
+        Commands::Listen { group_id, key_path, data_dir, replay } => {
+            commands::listen::run(group_id, key_path, data_dir, replay).await?;
+        }
+        Commands::Daemon { key_path, data_dir, log_file, reconnect_delay, no_access_control, welcome_greeting, metrics_port, metrics_bind, heartbeat_secs, retention_days } => {
+            let metrics_addr = metrics_port.map(|port| (metrics_bind, port));
+            commands::daemon::run(key_path, data_dir, log_file, reconnect_delay, no_access_control, welcome_greeting, metrics_addr, heartbeat_secs, retention_days).await?;
         }
         Commands::ReadReceipt { group_id, message_ids, key_path, data_dir } => {
             commands::read_receipt::run(group_id, message_ids, key_path, data_dir).await?;
@@ -261,11 +490,26 @@ async fn main() -> anyhow::Result<()> {
         },
         Commands::Acl(sub) => match sub {
             AclCommands::Show { data_dir } => commands::acl::show(data_dir)?,
-            AclCommands::AddContact { pubkey, data_dir } => commands::acl::add_contact(pubkey, data_dir)?,
+            AclCommands::AddContact { pubkey, groups, data_dir } => commands::acl::add_contact(pubkey, groups, data_dir)?,
+            AclCommands::Import { data_dir } => commands::acl::import(data_dir)?,
             AclCommands::RemoveContact { pubkey, data_dir } => commands::acl::remove_contact(pubkey, data_dir)?,
+            AclCommands::RateLimit { pubkey, per_minute, data_dir } => commands::acl::rate_limit(pubkey, per_minute, data_dir)?,
             AclCommands::AddGroup { group_id, data_dir } => commands::acl::add_group(group_id, data_dir)?,
             AclCommands::RemoveGroup { group_id, data_dir } => commands::acl::remove_group(group_id, data_dir)?,
-            AclCommands::Audit { days, data_dir } => commands::acl::show_audit(data_dir, days)?,
+            AclCommands::Test { pubkey, group_id, data_dir } => commands::acl::test(pubkey, group_id, data_dir)?,
+            AclCommands::Audit { days, sender, denied_only, json, data_dir } => {
+                commands::acl::show_audit(data_dir, days, sender, denied_only, json)?
+            },
+        },
+        Commands::Media(sub) => match sub {
+            MediaCommands::Download { group_id, event_id, out, key_path, data_dir } => {
+                commands::media::download(group_id, event_id, out, key_path, data_dir).await?;
+            }
+        },
+        Commands::Config(sub) => match sub {
+            ConfigCommands::Show { data_dir } => commands::config::show(data_dir)?,
+            ConfigCommands::Get { key, data_dir } => commands::config::get(key, data_dir)?,
+            ConfigCommands::Set { key, value, data_dir } => commands::config::set(key, value, data_dir)?,
         },
     }
 
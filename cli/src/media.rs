@@ -6,6 +6,7 @@
 use anyhow::{Context, Result};
 use mdk_core::encrypted_media::types::MediaReference;
 use mdk_core::prelude::*;
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -21,6 +22,32 @@ pub struct MediaAttachment {
     pub dimensions: Option<String>,
 }
 
+impl MediaAttachment {
+    /// Name to save the decrypted file under inside the media directory.
+    ///
+    /// `filename` comes from the sender, so only its final path component
+    /// is kept; names that would escape the directory (empty, `.`, `..`)
+    /// fall back to the content hash.
+    pub fn local_filename(&self) -> String {
+        Path::new(&self.filename)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .filter(|n| !n.is_empty() && *n != "." && *n != "..")
+            .map(str::to_string)
+            .unwrap_or_else(|| hash_filename(&self.original_hash_hex))
+    }
+}
+
+/// The content hash as a file name, or a fixed name if the hash itself is
+/// not plain hex (it is sender-controlled too).
+fn hash_filename(hash_hex: &str) -> String {
+    if !hash_hex.is_empty() && hash_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        hash_hex.to_lowercase()
+    } else {
+        "attachment".to_string()
+    }
+}
+
 /// Parse imeta tags from a message's tags list.
 pub fn parse_imeta_tags(tags: &[Vec<String>]) -> Vec<MediaAttachment> {
     let mut attachments = Vec::new();
@@ -123,7 +150,7 @@ pub async fn download_and_decrypt<S: mdk_storage_traits::MdkStorageProvider>(
     media_dir: &Path,
 ) -> Result<PathBuf> {
     // Check cache first
-    let out_path = media_dir.join(&attachment.filename);
+    let out_path = media_dir.join(attachment.local_filename());
     if out_path.exists() {
         return Ok(out_path);
     }
@@ -151,6 +178,8 @@ pub async fn download_and_decrypt<S: mdk_storage_traits::MdkStorageProvider>(
         .decrypt_from_download(&encrypted_data, &media_ref)
         .map_err(|e| anyhow::anyhow!("Decryption failed: {}", e))?;
 
+    verify_download(attachment, &encrypted_data, &decrypted)?;
+
     // Save to disk
     fs::create_dir_all(media_dir)?;
     fs::write(&out_path, &decrypted)?;
@@ -158,6 +187,45 @@ pub async fn download_and_decrypt<S: mdk_storage_traits::MdkStorageProvider>(
     Ok(out_path)
 }
 
+/// Check a downloaded blob against the attachment's hashes.
+///
+/// The decrypted file must always match the imeta `x` hash. When the URL
+/// ends in a Blossom content address (`<sha256>[.ext]`), the ciphertext is
+/// checked against it as well, but that is only a bonus: URLs that don't
+/// carry a hash are still verified through `x`.
+pub fn verify_download(
+    attachment: &MediaAttachment,
+    encrypted: &[u8],
+    decrypted: &[u8],
+) -> Result<()> {
+    if let Some(url_hash) = url_content_hash(&attachment.url) {
+        let actual = hex::encode(Sha256::digest(encrypted));
+        if !actual.eq_ignore_ascii_case(url_hash) {
+            anyhow::bail!(
+                "Download integrity check failed: expected hash {}, got {}",
+                url_hash,
+                actual
+            );
+        }
+    }
+    if !hash_matches(decrypted, &attachment.original_hash_hex) {
+        anyhow::bail!("Decrypted file does not match original hash {}", attachment.original_hash_hex);
+    }
+    Ok(())
+}
+
+/// The SHA-256 a Blossom URL's last path segment names, if it has one.
+fn url_content_hash(url: &str) -> Option<&str> {
+    let segment = url.split(['?', '#']).next()?.rsplit('/').next()?;
+    let stem = segment.split('.').next()?;
+    (stem.len() == 64 && stem.chars().all(|c| c.is_ascii_hexdigit())).then_some(stem)
+}
+
+/// Check that `data` hashes (SHA-256) to the given hex digest.
+pub fn hash_matches(data: &[u8], expected_hex: &str) -> bool {
+    hex::encode(Sha256::digest(data)).eq_ignore_ascii_case(expected_hex)
+}
+
 /// Auto-download and decrypt all media attachments in a message's tags.
 /// Silently skips any attachments that fail to download.
 pub async fn auto_download_attachments<S: mdk_storage_traits::MdkStorageProvider>(
@@ -168,7 +236,7 @@ pub async fn auto_download_attachments<S: mdk_storage_traits::MdkStorageProvider
 ) {
     let attachments = parse_imeta_tags(tags);
     for att in &attachments {
-        let path = media_dir.join(&att.filename);
+        let path = media_dir.join(att.local_filename());
         if path.exists() {
             continue;
         }
@@ -197,7 +265,7 @@ pub fn format_message_with_media(
             content_is_filename = true;
         }
         if let Some(dir) = media_dir {
-            let path = dir.join(&att.filename);
+            let path = dir.join(att.local_filename());
             if path.exists() {
                 parts.push(format!("[📎 {} -> {}]", att.filename, path.display()));
             } else {
@@ -214,3 +282,65 @@ pub fn format_message_with_media(
         format!("{} {}", content, parts.join(" "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(url: &str, filename: &str, plaintext: &[u8]) -> MediaAttachment {
+        MediaAttachment {
+            url: url.to_string(),
+            mime_type: "image/png".to_string(),
+            filename: filename.to_string(),
+            original_hash_hex: hex::encode(Sha256::digest(plaintext)),
+            nonce_hex: "00".repeat(12),
+            scheme_version: "mip04-v2".to_string(),
+            dimensions: None,
+        }
+    }
+
+    #[test]
+    fn traversal_filenames_stay_in_media_dir() {
+        let hash = hex::encode(Sha256::digest(b"cat"));
+        for (name, expected) in [
+            ("../../.bashrc", ".bashrc"),
+            ("/etc/passwd", "passwd"),
+            ("photos/../../cat.png", "cat.png"),
+            ("..", hash.as_str()),
+            (".", hash.as_str()),
+            ("", hash.as_str()),
+            ("dir/..", hash.as_str()),
+        ] {
+            let att = attachment("https://blossom.example/x", name, b"cat");
+            let local = att.local_filename();
+            assert_eq!(local, expected, "filename {name:?}");
+            let dir = Path::new("/tmp/media");
+            assert_eq!(dir.join(&local).parent(), Some(dir), "filename {name:?}");
+        }
+    }
+
+    #[test]
+    fn non_hex_hash_does_not_become_a_path() {
+        let mut att = attachment("https://blossom.example/x", "..", b"cat");
+        att.original_hash_hex = "../../evil".to_string();
+        assert_eq!(att.local_filename(), "attachment");
+    }
+
+    #[test]
+    fn download_without_hash_url_is_still_verified() {
+        let att = attachment("https://files.example/download?id=42", "cat.png", b"cat");
+        assert!(verify_download(&att, b"ciphertext", b"cat").is_ok());
+        let err = verify_download(&att, b"ciphertext", b"dog").unwrap_err();
+        assert!(err.to_string().contains("does not match original hash"));
+    }
+
+    #[test]
+    fn hash_url_checks_ciphertext() {
+        let cipher_hash = hex::encode(Sha256::digest(b"ciphertext"));
+        let url = format!("https://blossom.example/{cipher_hash}.bin");
+        let att = attachment(&url, "cat.png", b"cat");
+        assert!(verify_download(&att, b"ciphertext", b"cat").is_ok());
+        let err = verify_download(&att, b"tampered", b"cat").unwrap_err();
+        assert!(err.to_string().contains("integrity check failed"));
+    }
+}
@@ -0,0 +1,126 @@
+//! Prometheus metrics for the daemon.
+//!
+//! Counters are bumped from the notification handler; relay connection
+//! state is read from the client at scrape time. Served in the text
+//! exposition format by `serve` when `burrow daemon --metrics-port` is set.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use nostr_sdk::prelude::*;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+#[derive(Default)]
+pub struct Metrics {
+    pub events_received: AtomicU64,
+    pub messages_processed: AtomicU64,
+    pub messages_denied: AtomicU64,
+    pub read_receipts: AtomicU64,
+    pub decrypt_errors: AtomicU64,
+    pub welcomes_accepted: AtomicU64,
+    pub welcomes_skipped: AtomicU64,
+    pub welcomes_denied: AtomicU64,
+    pub welcome_errors: AtomicU64,
+    pub gift_wrap_errors: AtomicU64,
+    /// Byte length of the JSONL log.
+    pub log_offset: AtomicU64,
+    /// Last seen MLS epoch per group, keyed by nostr group id hex.
+    epochs: Mutex<BTreeMap<String, u64>>,
+}
+
+/// Bump one of the `Metrics` counters.
+pub fn inc(counter: &AtomicU64) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+impl Metrics {
+    pub fn set_epoch(&self, nostr_group_id_hex: &str, epoch: u64) {
+        if let Ok(mut epochs) = self.epochs.lock() {
+            epochs.insert(nostr_group_id_hex.to_string(), epoch);
+        }
+    }
+
+    /// Render everything in the Prometheus text format.
+    pub async fn render(&self, client: &Client) -> String {
+        let mut out = String::new();
+        let counters: [(&str, &str, &AtomicU64); 7] = [
+            ("burrow_events_received_total", "Relay events received after deduplication", &self.events_received),
+            ("burrow_messages_processed_total", "Application messages decrypted and logged", &self.messages_processed),
+            ("burrow_messages_denied_total", "Application messages rejected by the ACL", &self.messages_denied),
+            ("burrow_read_receipts_total", "Read receipts received", &self.read_receipts),
+            ("burrow_decrypt_errors_total", "Kind 445 events MDK failed to process", &self.decrypt_errors),
+            ("burrow_gift_wrap_errors_total", "Gift wraps addressed to us that failed to unwrap", &self.gift_wrap_errors),
+            ("burrow_log_offset_bytes", "Byte length of the JSONL log", &self.log_offset),
+        ];
+        for (name, help, value) in counters {
+            let kind = if name.ends_with("_total") { "counter" } else { "gauge" };
+            let _ = writeln!(out, "# HELP {} {}\n# TYPE {} {}", name, help, name, kind);
+            let _ = writeln!(out, "{} {}", name, value.load(Ordering::Relaxed));
+        }
+
+        let _ = writeln!(out, "# HELP burrow_welcomes_total Welcomes handled, by outcome\n# TYPE burrow_welcomes_total counter");
+        for (result, value) in [
+            ("accepted", &self.welcomes_accepted),
+            ("skipped", &self.welcomes_skipped),
+            ("denied", &self.welcomes_denied),
+            ("error", &self.welcome_errors),
+        ] {
+            let _ = writeln!(out, "burrow_welcomes_total{{result=\"{}\"}} {}", result, value.load(Ordering::Relaxed));
+        }
+
+        let _ = writeln!(out, "# HELP burrow_group_epoch Last seen MLS epoch per group\n# TYPE burrow_group_epoch gauge");
+        if let Ok(epochs) = self.epochs.lock() {
+            for (group, epoch) in epochs.iter() {
+                let _ = writeln!(out, "burrow_group_epoch{{group=\"{}\"}} {}", group, epoch);
+            }
+        }
+
+        let relays = client.relays().await;
+        let _ = writeln!(out, "# HELP burrow_relay_connected Whether the relay is currently connected\n# TYPE burrow_relay_connected gauge");
+        for (url, relay) in relays.iter() {
+            let connected = relay.status() == RelayStatus::Connected;
+            let _ = writeln!(out, "burrow_relay_connected{{relay=\"{}\"}} {}", escape_label(url.as_str()), connected as u8);
+        }
+        let _ = writeln!(out, "# HELP burrow_relay_reconnects_total Reconnections per relay\n# TYPE burrow_relay_reconnects_total counter");
+        for (url, relay) in relays.iter() {
+            // Every successful connection after the first one is a reconnect.
+            let reconnects = relay.stats().success().saturating_sub(1);
+            let _ = writeln!(out, "burrow_relay_reconnects_total{{relay=\"{}\"}} {}", escape_label(url.as_str()), reconnects);
+        }
+        out
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Serve the metrics on `bind:port` (any path).
+pub async fn serve(bind: String, port: u16, metrics: std::sync::Arc<Metrics>, client: Client) {
+    let listener = match tokio::net::TcpListener::bind((bind.as_str(), port)).await {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("⚠️ Metrics endpoint failed to bind {}:{}: {}", bind, port, e);
+            return;
+        }
+    };
+    eprintln!("ℹ️ Metrics endpoint on http://{}:{}/metrics", bind, port);
+    loop {
+        let Ok((mut sock, _)) = listener.accept().await else { continue };
+        let metrics = metrics.clone();
+        let client = client.clone();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let _ = sock.read(&mut buf).await;
+            let body = metrics.render(&client).await;
+            let resp = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = sock.write_all(resp.as_bytes()).await;
+        });
+    }
+}
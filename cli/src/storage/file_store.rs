@@ -1,8 +1,15 @@
 use anyhow::Result;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Name of the groups index under `index/`.
+const GROUP_INDEX: &str = "groups";
+
+/// Advisory lock file under `index/` serializing index updates.
+const INDEX_LOCK: &str = ".lock";
+
 /// Stored group metadata (persisted to disk, separate from MLS state).
 /// Uses camelCase to match existing TypeScript CLI format.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +35,9 @@ pub struct StoredMessage {
     pub event_id_hex: String,
     pub author_pubkey_hex: String,
     pub content: String,
+    /// Rumor kind; absent for messages stored before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<u16>,
     pub created_at: u64,
     pub mls_group_id_hex: String,
     pub wrapper_event_id_hex: String,
@@ -51,6 +61,62 @@ pub struct StoredReadReceipt {
     pub read_event_ids: Vec<String>,
 }
 
+/// An index file: entries describing a directory, and the directory mtime
+/// they were taken at.
+#[derive(Serialize, Deserialize)]
+struct DirIndex<E> {
+    dir_mtime_ns: u128,
+    entries: E,
+}
+
+/// Groups index entry: the fields `find_group_by_prefix` matches on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GroupIndexEntry {
+    nostr_group_id_hex: String,
+    mls_group_id_hex: String,
+    name: String,
+}
+
+impl From<&StoredGroup> for GroupIndexEntry {
+    fn from(g: &StoredGroup) -> Self {
+        Self {
+            nostr_group_id_hex: g.nostr_group_id_hex.clone(),
+            mls_group_id_hex: g.mls_group_id_hex.clone(),
+            name: g.name.clone(),
+        }
+    }
+}
+
+/// Message index entry; entries sort oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct MessageIndexEntry {
+    created_at: u64,
+    event_id_hex: String,
+}
+
+fn message_index_name(mls_group_id_hex: &str) -> String {
+    format!("messages-{}", mls_group_id_hex)
+}
+
+/// A directory's mtime in nanoseconds, or None if it doesn't exist.
+/// Creating or deleting a file in the directory changes it.
+fn dir_mtime_ns(dir: &Path) -> Option<u128> {
+    let modified = fs::metadata(dir).ok()?.modified().ok()?;
+    Some(modified.duration_since(std::time::UNIX_EPOCH).ok()?.as_nanos())
+}
+
+/// Proof that the caller holds the exclusive index lock; released on drop.
+struct IndexLock(#[allow(dead_code)] fs::File);
+
+/// Outcome of `FileStore::reconcile_groups`, as MLS group IDs.
+#[derive(Debug, Default, PartialEq)]
+pub struct GroupReconcile {
+    /// Active MLS groups that had no stored metadata and were saved.
+    pub restored: Vec<String>,
+    /// Stored groups MLS doesn't know. Left in place.
+    pub orphaned: Vec<String>,
+}
+
 /// File-based persistence for groups, messages, and MLS state.
 pub struct FileStore {
     base: PathBuf,
@@ -69,11 +135,19 @@ impl FileStore {
     // --- Groups ---
 
     pub fn save_group(&self, group: &StoredGroup) -> Result<()> {
-        let path = self
-            .base
-            .join("groups")
-            .join(format!("{}.json", group.nostr_group_id_hex));
+        let dir = self.base.join("groups");
+        let lock = self.lock_indexes()?;
+        let index = self.load_index::<GroupIndexEntry>(GROUP_INDEX, &dir);
+        let path = dir.join(format!("{}.json", group.nostr_group_id_hex));
         fs::write(&path, serde_json::to_string_pretty(group)?)?;
+        // Saving a group again (e.g. re-invited) undoes an earlier leave.
+        let _ = fs::remove_file(self.left_marker(&group.mls_group_id_hex));
+        if let Some(mut entries) = index {
+            entries.retain(|e| e.nostr_group_id_hex != group.nostr_group_id_hex);
+            entries.push(GroupIndexEntry::from(group));
+            entries.sort_by(|a, b| a.nostr_group_id_hex.cmp(&b.nostr_group_id_hex));
+            self.save_index(&lock, GROUP_INDEX, dir_mtime_ns(&dir), &entries);
+        }
         Ok(())
     }
 
@@ -94,14 +168,108 @@ impl FileStore {
         Ok(groups)
     }
 
+    /// Remove a group's metadata file. Returns false if it was not stored.
+    pub fn delete_group(&self, nostr_group_id_hex: &str) -> Result<bool> {
+        let dir = self.base.join("groups");
+        let lock = self.lock_indexes()?;
+        let index = self.load_index::<GroupIndexEntry>(GROUP_INDEX, &dir);
+        let path = dir.join(format!("{}.json", nostr_group_id_hex));
+        if path.exists() {
+            fs::remove_file(&path)?;
+            if let Some(mut entries) = index {
+                entries.retain(|e| e.nostr_group_id_hex != nostr_group_id_hex);
+                self.save_index(&lock, GROUP_INDEX, dir_mtime_ns(&dir), &entries);
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Record that we left a group, so `reconcile_groups` doesn't bring it
+    /// back while MLS still lists it (until an admin commits the leave).
+    pub fn mark_left(&self, mls_group_id_hex: &str) -> Result<()> {
+        let path = self.left_marker(mls_group_id_hex);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, b"")?;
+        Ok(())
+    }
+
+    pub fn has_left(&self, mls_group_id_hex: &str) -> bool {
+        self.left_marker(mls_group_id_hex).exists()
+    }
+
+    fn left_marker(&self, mls_group_id_hex: &str) -> PathBuf {
+        self.base.join("groups").join("left").join(mls_group_id_hex)
+    }
+
+    /// Bring the stored groups in line with the active groups MLS holds.
+    ///
+    /// A crash between MLS accepting a welcome and `save_group` leaves a
+    /// group we are a member of but never listen on. Missing groups are
+    /// saved from `mls_groups` (unless we left them); stored groups MLS
+    /// doesn't know are only reported.
+    pub fn reconcile_groups(&self, mls_groups: &[StoredGroup]) -> Result<GroupReconcile> {
+        let stored = self.load_groups()?;
+        let mut restored = Vec::new();
+        for group in mls_groups {
+            let known = stored.iter().any(|g| g.mls_group_id_hex == group.mls_group_id_hex);
+            if !known && !self.has_left(&group.mls_group_id_hex) {
+                self.save_group(group)?;
+                restored.push(group.mls_group_id_hex.clone());
+            }
+        }
+        let mut orphaned: Vec<String> = stored
+            .iter()
+            .filter(|g| !mls_groups.iter().any(|m| m.mls_group_id_hex == g.mls_group_id_hex))
+            .map(|g| g.mls_group_id_hex.clone())
+            .collect();
+        restored.sort();
+        orphaned.sort();
+        Ok(GroupReconcile { restored, orphaned })
+    }
+
     pub fn find_group_by_prefix(&self, prefix: &str) -> Result<Option<StoredGroup>> {
-        let groups = self.load_groups()?;
         let lower = prefix.to_lowercase();
-        Ok(groups.into_iter().find(|g| {
-            g.nostr_group_id_hex.starts_with(&lower)
-                || g.mls_group_id_hex.starts_with(&lower)
-                || g.name.to_lowercase().contains(&lower)
-        }))
+        let dir = self.base.join("groups");
+        for rebuilt in [false, true] {
+            let entries = self.group_index(rebuilt)?;
+            let Some(found) = entries.iter().find(|g| {
+                g.nostr_group_id_hex.starts_with(&lower)
+                    || g.mls_group_id_hex.starts_with(&lower)
+                    || g.name.to_lowercase().contains(&lower)
+            }) else {
+                return Ok(None);
+            };
+            let path = dir.join(format!("{}.json", found.nostr_group_id_hex));
+            if let Ok(data) = fs::read_to_string(&path) {
+                if let Ok(group) = serde_json::from_str::<StoredGroup>(&data) {
+                    return Ok(Some(group));
+                }
+            }
+            // The index pointed at a file that's gone or changed; rebuild.
+        }
+        Ok(None)
+    }
+
+    /// Groups index, from disk if fresh, else rebuilt from the group files.
+    fn group_index(&self, force_rebuild: bool) -> Result<Vec<GroupIndexEntry>> {
+        let dir = self.base.join("groups");
+        let lock = self.lock_indexes()?;
+        if !force_rebuild {
+            if let Some(entries) = self.load_index(GROUP_INDEX, &dir) {
+                return Ok(entries);
+            }
+        }
+        // Stamp with the mtime from before the scan, so a change made while
+        // scanning leaves the index stale rather than silently incomplete.
+        let scanned_at = dir_mtime_ns(&dir);
+        let mut entries: Vec<GroupIndexEntry> = self.load_groups()?.iter().map(GroupIndexEntry::from).collect();
+        entries.sort_by(|a, b| a.nostr_group_id_hex.cmp(&b.nostr_group_id_hex));
+        self.save_index(&lock, GROUP_INDEX, scanned_at, &entries);
+        Ok(entries)
     }
 
     // --- Messages ---
@@ -109,8 +277,18 @@ impl FileStore {
     pub fn save_message(&self, msg: &StoredMessage) -> Result<()> {
         let dir = self.base.join("messages").join(&msg.mls_group_id_hex);
         fs::create_dir_all(&dir)?;
+        let name = message_index_name(&msg.mls_group_id_hex);
+        let lock = self.lock_indexes()?;
+        let index = self.load_index::<MessageIndexEntry>(&name, &dir);
         let path = dir.join(format!("{}.json", msg.event_id_hex));
         fs::write(&path, serde_json::to_string(msg)?)?;
+        if let Some(mut entries) = index {
+            entries.retain(|e| e.event_id_hex != msg.event_id_hex);
+            let entry = MessageIndexEntry { created_at: msg.created_at, event_id_hex: msg.event_id_hex.clone() };
+            let at = entries.partition_point(|e| *e < entry);
+            entries.insert(at, entry);
+            self.save_index(&lock, &name, dir_mtime_ns(&dir), &entries);
+        }
         Ok(())
     }
 
@@ -120,23 +298,212 @@ impl FileStore {
         limit: usize,
     ) -> Result<Vec<StoredMessage>> {
         let dir = self.base.join("messages").join(mls_group_id_hex);
-        let mut msgs = Vec::new();
+        'attempt: for rebuilt in [false, true] {
+            let entries = self.message_index(mls_group_id_hex, rebuilt)?;
+            let start = entries.len().saturating_sub(limit);
+            let mut msgs = Vec::with_capacity(entries.len() - start);
+            for entry in &entries[start..] {
+                let path = dir.join(format!("{}.json", entry.event_id_hex));
+                match fs::read_to_string(&path).ok().and_then(|d| serde_json::from_str::<StoredMessage>(&d).ok()) {
+                    Some(m) => msgs.push(m),
+                    None if !rebuilt => continue 'attempt,
+                    None => {}
+                }
+            }
+            return Ok(msgs);
+        }
+        unreachable!("the rebuilt attempt always returns")
+    }
+
+    /// Delete a group's stored messages created more than `older_than_secs`
+    /// ago. Returns how many were removed.
+    pub fn prune_messages(&self, mls_group_id_hex: &str, older_than_secs: u64) -> Result<usize> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let cutoff = now.saturating_sub(older_than_secs);
+        let lock = self.lock_indexes()?;
+        let entries = self.message_index_locked(&lock, mls_group_id_hex, false)?;
+        let old = entries.partition_point(|e| e.created_at < cutoff);
+        self.remove_oldest_messages(&lock, mls_group_id_hex, entries, old)
+    }
+
+    /// Delete all but the newest `keep` stored messages of a group. Returns
+    /// how many were removed.
+    pub fn prune_messages_keep_last(&self, mls_group_id_hex: &str, keep: usize) -> Result<usize> {
+        let lock = self.lock_indexes()?;
+        let entries = self.message_index_locked(&lock, mls_group_id_hex, false)?;
+        let excess = entries.len().saturating_sub(keep);
+        self.remove_oldest_messages(&lock, mls_group_id_hex, entries, excess)
+    }
+
+    /// Delete the first `count` messages of `entries` (the group's index,
+    /// oldest first) and save the rest as the new index.
+    fn remove_oldest_messages(
+        &self,
+        lock: &IndexLock,
+        mls_group_id_hex: &str,
+        mut entries: Vec<MessageIndexEntry>,
+        count: usize,
+    ) -> Result<usize> {
+        if count == 0 {
+            return Ok(0);
+        }
+        let dir = self.base.join("messages").join(mls_group_id_hex);
+        for entry in entries.drain(..count) {
+            match fs::remove_file(dir.join(format!("{}.json", entry.event_id_hex))) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
+        self.save_index(lock, &message_index_name(mls_group_id_hex), dir_mtime_ns(&dir), &entries);
+        Ok(count)
+    }
+
+    /// A group's message index (oldest first), from disk if fresh, else
+    /// rebuilt from the message files. Files that don't parse are left out.
+    fn message_index(&self, mls_group_id_hex: &str, force_rebuild: bool) -> Result<Vec<MessageIndexEntry>> {
+        let lock = self.lock_indexes()?;
+        self.message_index_locked(&lock, mls_group_id_hex, force_rebuild)
+    }
+
+    fn message_index_locked(
+        &self,
+        lock: &IndexLock,
+        mls_group_id_hex: &str,
+        force_rebuild: bool,
+    ) -> Result<Vec<MessageIndexEntry>> {
+        let dir = self.base.join("messages").join(mls_group_id_hex);
+        let name = message_index_name(mls_group_id_hex);
+        if !force_rebuild {
+            if let Some(entries) = self.load_index(&name, &dir) {
+                return Ok(entries);
+            }
+        }
+        let scanned_at = dir_mtime_ns(&dir);
+        let mut entries = Vec::new();
         if dir.exists() {
             for entry in fs::read_dir(&dir)? {
-                let entry = entry?;
-                if entry.path().extension().map_or(false, |e| e == "json") {
-                    let data = fs::read_to_string(entry.path())?;
+                let path = entry?.path();
+                if path.extension().map_or(false, |e| e == "json") {
+                    let data = fs::read_to_string(&path)?;
                     if let Ok(m) = serde_json::from_str::<StoredMessage>(&data) {
-                        msgs.push(m);
+                        entries.push(MessageIndexEntry { created_at: m.created_at, event_id_hex: m.event_id_hex });
                     }
                 }
             }
         }
-        msgs.sort_by_key(|m| m.created_at);
-        if msgs.len() > limit {
-            msgs = msgs.split_off(msgs.len() - limit);
+        entries.sort();
+        self.save_index(lock, &name, scanned_at, &entries);
+        Ok(entries)
+    }
+
+    /// Delete all stored messages and read receipts for a group.
+    pub fn delete_messages(&self, mls_group_id_hex: &str) -> Result<()> {
+        let _lock = self.lock_indexes()?;
+        for sub in ["messages", "read-receipts"] {
+            let dir = self.base.join(sub).join(mls_group_id_hex);
+            if dir.exists() {
+                fs::remove_dir_all(&dir)?;
+            }
+        }
+        let _ = fs::remove_file(self.index_path(&message_index_name(mls_group_id_hex)));
+        Ok(())
+    }
+
+    /// Find a stored message in a group by event ID (full hex or unique prefix).
+    pub fn find_message(
+        &self,
+        mls_group_id_hex: &str,
+        event_id_prefix: &str,
+    ) -> Result<Option<StoredMessage>> {
+        let dir = self.base.join("messages").join(mls_group_id_hex);
+        let lower = event_id_prefix.to_lowercase();
+
+        let exact = dir.join(format!("{}.json", lower));
+        if exact.exists() {
+            let data = fs::read_to_string(&exact)?;
+            return Ok(Some(serde_json::from_str(&data)?));
+        }
+
+        for rebuilt in [false, true] {
+            let entries = self.message_index(mls_group_id_hex, rebuilt)?;
+            let matches: Vec<&MessageIndexEntry> =
+                entries.iter().filter(|e| e.event_id_hex.starts_with(&lower)).collect();
+            match matches.len() {
+                0 => return Ok(None),
+                1 => {
+                    let path = dir.join(format!("{}.json", matches[0].event_id_hex));
+                    if let Ok(data) = fs::read_to_string(&path) {
+                        return Ok(Some(serde_json::from_str(&data)?));
+                    }
+                    // Stale index entry; rebuild and look again.
+                }
+                n if rebuilt => {
+                    anyhow::bail!("Event ID prefix '{}' is ambiguous ({} matches)", event_id_prefix, n)
+                }
+                _ => {} // Confirm against a fresh index before calling it ambiguous.
+            }
+        }
+        Ok(None)
+    }
+
+    // --- Indexes ---
+    //
+    // Sidecar indexes under `index/` let lookups skip reading every group or
+    // message file. The per-record files stay the source of truth: an index
+    // is only trusted while its directory's mtime matches the one recorded
+    // with it, is updated in place by our own writes, and is rebuilt when it
+    // is missing, stale, or points at a file that is gone.
+    //
+    // Every load-modify-save of an index runs under an exclusive advisory
+    // lock on `index/.lock`, so the daemon, its prune task and a concurrent
+    // `burrow send`/`read` cannot stamp a stale snapshot with a fresh mtime.
+    // The lock is taken per call on a new file handle, so it also excludes
+    // other threads of the same process.
+    //
+    // The mtime check only sees files created or removed, not rewritten in
+    // place, so a record edited behind our back keeps its old index entry
+    // until the file goes missing. It is also only as fine as the
+    // filesystem's timestamps: on coarse-grained filesystems an outside
+    // change in the same tick as our own write can go unnoticed.
+
+    fn index_path(&self, name: &str) -> PathBuf {
+        self.base.join("index").join(format!("{}.json", name))
+    }
+
+    /// Take the exclusive index lock, blocking until other writers are done.
+    fn lock_indexes(&self) -> Result<IndexLock> {
+        let dir = self.base.join("index");
+        fs::create_dir_all(&dir)?;
+        let file = fs::OpenOptions::new().create(true).truncate(false).write(true).open(dir.join(INDEX_LOCK))?;
+        file.lock()?;
+        Ok(IndexLock(file))
+    }
+
+    /// The entries of index `name` over `dir`, if the index is fresh.
+    fn load_index<T: DeserializeOwned>(&self, name: &str, dir: &Path) -> Option<Vec<T>> {
+        let data = fs::read_to_string(self.index_path(name)).ok()?;
+        let index: DirIndex<Vec<T>> = serde_json::from_str(&data).ok()?;
+        (Some(index.dir_mtime_ns) == dir_mtime_ns(dir)).then_some(index.entries)
+    }
+
+    /// Save index `name`, stamped with `dir_mtime_ns` — the mtime of its
+    /// directory that `entries` reflect. Written via a temp file so readers
+    /// never see a partial index. Best-effort: a failed write just means a
+    /// rebuild next time.
+    fn save_index<T: Serialize>(&self, _lock: &IndexLock, name: &str, dir_mtime_ns: Option<u128>, entries: &[T]) {
+        let Some(dir_mtime_ns) = dir_mtime_ns else {
+            return;
+        };
+        let path = self.index_path(name);
+        let tmp = path.with_extension("json.tmp");
+        if let Ok(data) = serde_json::to_string(&DirIndex { dir_mtime_ns, entries }) {
+            if fs::write(&tmp, data).is_ok() {
+                let _ = fs::rename(&tmp, &path);
+            }
         }
-        Ok(msgs)
     }
 
     // --- Read receipts ---
@@ -241,3 +608,201 @@ impl FileStore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GROUP: &str = "aa11";
+
+    fn now() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    /// A store in a fresh temp dir holding one group and a message per
+    /// entry of `ages` (seconds before now).
+    fn store_with_messages(test: &str, ages: &[u64]) -> (PathBuf, FileStore) {
+        let dir = std::env::temp_dir().join(format!("burrow-store-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let store = FileStore::new(&dir).unwrap();
+        store
+            .save_group(&StoredGroup {
+                mls_group_id_hex: GROUP.into(),
+                nostr_group_id_hex: "bb22".into(),
+                name: "test".into(),
+                description: String::new(),
+                admin_pubkeys: vec![],
+                relay_urls: vec![],
+                created_at: 0,
+            })
+            .unwrap();
+        let now = now();
+        for (i, age) in ages.iter().enumerate() {
+            store
+                .save_message(&StoredMessage {
+                    event_id_hex: format!("{:064x}", i),
+                    author_pubkey_hex: String::new(),
+                    content: format!("message {}", i),
+                    kind: Some(1),
+                    created_at: now - age,
+                    mls_group_id_hex: GROUP.into(),
+                    wrapper_event_id_hex: String::new(),
+                    epoch: 0,
+                    tags: vec![],
+                })
+                .unwrap();
+        }
+        (dir, store)
+    }
+
+    fn contents(store: &FileStore) -> Vec<String> {
+        store.load_messages(GROUP, usize::MAX).unwrap().into_iter().map(|m| m.content).collect()
+    }
+
+    #[test]
+    fn prune_by_age_removes_only_old_messages() {
+        let day = 86_400;
+        let (dir, store) = store_with_messages("age", &[40 * day, 10 * day, 2 * day, 60]);
+        assert_eq!(store.prune_messages(GROUP, 7 * day).unwrap(), 2);
+        assert_eq!(contents(&store), vec!["message 2", "message 3"]);
+        assert_eq!(store.prune_messages(GROUP, 7 * day).unwrap(), 0);
+        assert_eq!(store.load_groups().unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn prune_keep_last_keeps_the_newest() {
+        let (dir, store) = store_with_messages("keep-last", &[50, 40, 30, 20, 10]);
+        assert_eq!(store.prune_messages_keep_last(GROUP, 2).unwrap(), 3);
+        assert_eq!(contents(&store), vec!["message 3", "message 4"]);
+        assert_eq!(store.prune_messages_keep_last(GROUP, 10).unwrap(), 0);
+        assert_eq!(store.prune_messages_keep_last(GROUP, 0).unwrap(), 2);
+        assert!(contents(&store).is_empty());
+        assert_eq!(store.load_groups().unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn message_index_is_rebuilt_after_manual_deletion() {
+        let (dir, store) = store_with_messages("index-delete", &[30, 20, 10]);
+        // Build the index, then delete a message behind the store's back.
+        assert_eq!(contents(&store).len(), 3);
+        assert!(dir.join("index").join("messages-aa11.json").exists());
+        fs::remove_file(dir.join("messages").join(GROUP).join(format!("{:064x}.json", 1))).unwrap();
+
+        assert_eq!(contents(&store), vec!["message 0", "message 2"]);
+        assert!(store.find_message(GROUP, &format!("{:063x}", 0)).unwrap_err().to_string().contains("ambiguous"));
+        assert!(store.find_message(GROUP, &format!("{:063x}1", 0)).unwrap().is_none());
+        assert_eq!(store.message_index(GROUP, false).unwrap().len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn message_index_tracks_saves_and_prunes() {
+        let (dir, store) = store_with_messages("index-writes", &[30, 20]);
+        assert_eq!(store.message_index(GROUP, false).unwrap().len(), 2);
+        let mut msg = store.load_messages(GROUP, 1).unwrap().remove(0);
+        msg.event_id_hex = format!("{:064x}", 9);
+        msg.created_at += 5;
+        msg.content = "newest".into();
+        store.save_message(&msg).unwrap();
+
+        let indexed = store.message_index(GROUP, false).unwrap();
+        assert_eq!(indexed, store.message_index(GROUP, true).unwrap());
+        assert_eq!(store.load_messages(GROUP, 1).unwrap()[0].content, "newest");
+
+        store.prune_messages_keep_last(GROUP, 1).unwrap();
+        assert_eq!(store.message_index(GROUP, false).unwrap(), store.message_index(GROUP, true).unwrap());
+        assert_eq!(contents(&store), vec!["newest"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_saves_and_prunes_keep_the_index_consistent() {
+        let (dir, store) = store_with_messages("index-race", &[]);
+        let store = std::sync::Arc::new(store);
+        let now = now();
+        let writer = {
+            let store = store.clone();
+            std::thread::spawn(move || {
+                for i in 0..200u64 {
+                    // Even messages are past retention, odd ones are fresh.
+                    let age = if i % 2 == 0 { 86_400 } else { 0 };
+                    store
+                        .save_message(&StoredMessage {
+                            event_id_hex: format!("{:064x}", i),
+                            author_pubkey_hex: String::new(),
+                            content: format!("message {}", i),
+                            kind: Some(1),
+                            created_at: now - age,
+                            mls_group_id_hex: GROUP.into(),
+                            wrapper_event_id_hex: String::new(),
+                            epoch: 0,
+                            tags: vec![],
+                        })
+                        .unwrap();
+                }
+            })
+        };
+        while !writer.is_finished() {
+            store.prune_messages(GROUP, 3_600).unwrap();
+        }
+        writer.join().unwrap();
+        store.prune_messages(GROUP, 3_600).unwrap();
+
+        let indexed = store.message_index(GROUP, false).unwrap();
+        assert_eq!(indexed, store.message_index(GROUP, true).unwrap());
+        assert_eq!(indexed.len(), 100);
+        assert_eq!(contents(&store).len(), 100);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn group_index_is_rebuilt_after_manual_deletion() {
+        let (dir, store) = store_with_messages("index-groups", &[]);
+        assert_eq!(store.find_group_by_prefix("bb").unwrap().unwrap().mls_group_id_hex, GROUP);
+        fs::remove_file(dir.join("groups").join("bb22.json")).unwrap();
+        assert!(store.find_group_by_prefix("bb").unwrap().is_none());
+        assert!(store.group_index(false).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reconcile_restores_missing_groups_and_reports_orphans() {
+        let (dir, store) = store_with_messages("reconcile", &[]);
+        let group = |mls: &str, nostr: &str| StoredGroup {
+            mls_group_id_hex: mls.into(),
+            nostr_group_id_hex: nostr.into(),
+            name: mls.into(),
+            description: String::new(),
+            admin_pubkeys: vec![],
+            relay_urls: vec![],
+            created_at: 0,
+        };
+        store.mark_left("dd44").unwrap();
+        let mls_groups = [group("cc33", "cc34"), group("dd44", "dd45")];
+
+        let report = store.reconcile_groups(&mls_groups).unwrap();
+        assert_eq!(report.restored, vec!["cc33".to_string()]);
+        assert_eq!(report.orphaned, vec![GROUP.to_string()]);
+        assert!(store.find_group_by_prefix("cc34").unwrap().is_some());
+        assert!(store.find_group_by_prefix("dd45").unwrap().is_none());
+
+        // Nothing left to restore; a re-invite clears the leave.
+        assert!(store.reconcile_groups(&mls_groups).unwrap().restored.is_empty());
+        store.save_group(&mls_groups[1]).unwrap();
+        assert!(!store.has_left("dd44"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn pruning_an_unknown_group_is_a_no_op() {
+        let (dir, store) = store_with_messages("unknown", &[100]);
+        assert_eq!(store.prune_messages("ffff", 0).unwrap(), 0);
+        assert_eq!(store.prune_messages_keep_last("ffff", 0).unwrap(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
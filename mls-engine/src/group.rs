@@ -11,6 +11,13 @@ use crate::storage::DaemonState;
 
 impl DaemonState {
     /// Create a new MLS group (MIP-01)
+    ///
+    /// `{"cmd":"create_group","name":..,"description":..,"relays":[..],
+    /// "member_key_packages":[..],"admin_pubkeys":[..]}`. Key packages are
+    /// kind 443 events, as JSON strings or objects (`member_key_package_events`
+    /// is accepted as an older alias). Admins default to us and must be us or
+    /// invited members. The result mirrors the app's `CreateGroupResult`:
+    /// `group`, `welcome_rumors_json` and `mls_group_id_hex`.
     pub fn cmd_create_group(&self, cmd: &Value) -> Result<Value> {
         let name = cmd["name"].as_str().unwrap_or("Unnamed Group").to_string();
         let description = cmd["description"].as_str().unwrap_or("").to_string();
@@ -18,9 +25,11 @@ impl DaemonState {
         let admin_pubkeys: Vec<PublicKey> = match cmd["admin_pubkeys"].as_array() {
             Some(arr) => arr
                 .iter()
-                .filter_map(|v| v.as_str())
-                .filter_map(|h| PublicKey::from_hex(h).ok())
-                .collect(),
+                .map(|v| {
+                    let hex = v.as_str().unwrap_or_default();
+                    PublicKey::from_hex(hex).map_err(|e| anyhow::anyhow!("Invalid admin pubkey {hex}: {e}"))
+                })
+                .collect::<Result<Vec<_>>>()?,
             None => vec![self.keys.public_key()],
         };
 
@@ -33,18 +42,41 @@ impl DaemonState {
             None => vec![],
         };
 
-        let kp_events: Vec<Event> = match cmd["member_key_package_events"].as_array() {
+        let kp_values = cmd["member_key_packages"]
+            .as_array()
+            .or_else(|| cmd["member_key_package_events"].as_array());
+        let kp_events: Vec<Event> = match kp_values {
             Some(arr) => arr
                 .iter()
-                .filter_map(|v| v.as_str())
-                .filter_map(|j| Event::from_json(j).ok())
-                .collect(),
+                .enumerate()
+                .map(|(i, v)| {
+                    let parsed = match v.as_str() {
+                        Some(json) => Event::from_json(json),
+                        None => Event::from_json(v.to_string()),
+                    };
+                    parsed.map_err(|e| anyhow::anyhow!("Invalid key package at index {i}: {e}"))
+                })
+                .collect::<Result<Vec<_>>>()?,
             None => vec![],
         };
 
+        let creator = self.keys.public_key();
+        let not_members: Vec<String> = admin_pubkeys
+            .iter()
+            .filter(|admin| **admin != creator && !kp_events.iter().any(|kp| kp.pubkey == **admin))
+            .map(|admin| admin.to_hex())
+            .collect();
+        if !not_members.is_empty() {
+            anyhow::bail!(
+                "Admins must be the creator or invited members; not invited: {}",
+                not_members.join(", ")
+            );
+        }
+
+        let relay_strings: Vec<String> = relay_urls.iter().map(|u| u.to_string()).collect();
         let config = NostrGroupConfigData::new(
             name.clone(),
-            description,
+            description.clone(),
             None,
             None,
             None,
@@ -53,11 +85,15 @@ impl DaemonState {
         );
 
         let result = self.mdk()
-            .create_group(&self.keys.public_key(), kp_events, config)
+            .create_group(&creator, kp_events, config)
             .map_err(|e| anyhow::anyhow!("MDK create_group error: {e}"))?;
 
         let mls_group_id_hex = hex::encode(result.group.mls_group_id.as_slice());
         let nostr_group_id_hex = hex::encode(result.group.nostr_group_id);
+        let member_count = self.mdk()
+            .get_members(&result.group.mls_group_id)
+            .map(|m| m.len())
+            .unwrap_or_default();
 
         let welcome_jsons: Vec<String> = result
             .welcome_rumors
@@ -67,6 +103,16 @@ impl DaemonState {
 
         Ok(serde_json::json!({
             "type": "create_group_result",
+            "group": {
+                "mls_group_id_hex": mls_group_id_hex,
+                "nostr_group_id_hex": nostr_group_id_hex,
+                "name": name,
+                "description": description,
+                "admin_pubkeys": result.group.admin_pubkeys.iter().map(|pk| pk.to_hex()).collect::<Vec<_>>(),
+                "epoch": result.group.epoch,
+                "member_count": member_count,
+                "relay_urls": relay_strings,
+            },
             "mls_group_id_hex": mls_group_id_hex,
             "nostr_group_id_hex": nostr_group_id_hex,
             "name": name,
@@ -11,8 +11,21 @@
 //! 2. **Daemon mode** — keeps MDK state in memory, reads JSON commands from stdin,
 //!    writes JSON responses to stdout (one per line). This is how the Node CLI
 //!    communicates with it for stateful operations.
+//!
+//! ## Daemon protocol
+//!
+//! Each command is one JSON object naming the operation in `command` (or `cmd`):
+//! `keygen`, `create_group`, `add_members`, `merge_pending_commit`,
+//! `list_groups`, `process_welcome`, `accept_welcome`, `send_message`,
+//! `process_message`, `export_secret` and `ping`. For example:
+//!
+//! ```text
+//! {"cmd":"create_group","name":"Team","description":"","relays":["wss://nos.lol"],"member_key_packages":["{...kind 443...}"]}
+//! ```
+//!
+//! Failures never stop the loop; they come back as `{"type":"error","error":"..."}`.
 
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, Read, Write};
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
@@ -57,9 +70,19 @@ enum Commands {
         /// State directory for persisting MLS state between restarts
         #[arg(long, default_value_t = default_state_dir())]
         state_dir: String,
+
+        /// Longest accepted command line in bytes; longer lines are skipped
+        #[arg(long, env = "BURROW_MLS_MAX_LINE_BYTES", default_value_t = DEFAULT_MAX_LINE_BYTES)]
+        max_line_bytes: usize,
     },
 }
 
+/// Default cap on a single stdin command line (4 MiB).
+const DEFAULT_MAX_LINE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Consecutive stdin read errors tolerated before giving up.
+const MAX_CONSECUTIVE_READ_ERRORS: u32 = 16;
+
 fn default_state_dir() -> String {
     let home = std::env::var("HOME").unwrap_or_else(|_| "~".to_string());
     format!("{home}/.burrow/mls-state")
@@ -73,8 +96,8 @@ fn main() -> Result<()> {
             let result = keygen::generate_key_package(&secret_key, &relay)?;
             println!("{}", serde_json::to_string(&result)?);
         }
-        Commands::Daemon { secret_key, state_dir } => {
-            daemon_loop(&secret_key, &state_dir)?;
+        Commands::Daemon { secret_key, state_dir, max_line_bytes } => {
+            daemon_loop(&secret_key, &state_dir, max_line_bytes)?;
         }
     }
 
@@ -82,7 +105,11 @@ fn main() -> Result<()> {
 }
 
 /// Daemon mode: keeps MDK in memory, processes JSON commands from stdin.
-fn daemon_loop(secret_key: &str, state_dir: &str) -> Result<()> {
+///
+/// Survives a misbehaving parent: oversized lines, invalid UTF-8 and invalid
+/// JSON each get an error response and are skipped, and transient read
+/// errors are logged and retried. Only EOF or a run of read errors ends the loop.
+fn daemon_loop(secret_key: &str, state_dir: &str, max_line_bytes: usize) -> Result<()> {
     use nostr_sdk::prelude::*;
     use mdk_core::MDK;
     use mdk_memory_storage::MdkMemoryStorage;
@@ -103,21 +130,45 @@ fn daemon_loop(secret_key: &str, state_dir: &str) -> Result<()> {
     io::stdout().flush()?;
 
     let stdin = io::stdin();
-    for line in stdin.lock().lines() {
-        let line = line.context("Failed to read stdin")?;
+    let mut reader = stdin.lock();
+    let mut buf = Vec::new();
+    let mut read_errors = 0u32;
+    loop {
+        let line = match read_bounded_line(&mut reader, max_line_bytes, &mut buf) {
+            Ok(StdinLine::Eof) => break,
+            Ok(StdinLine::TooLong(len)) => {
+                read_errors = 0;
+                respond_error(&format!("Line too long: {len} bytes (max {max_line_bytes})"))?;
+                continue;
+            }
+            Ok(StdinLine::Line) => {
+                read_errors = 0;
+                match std::str::from_utf8(&buf) {
+                    Ok(line) => line,
+                    Err(e) => {
+                        respond_error(&format!("Invalid UTF-8: {e}"))?;
+                        continue;
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                read_errors += 1;
+                eprintln!("Warning: failed to read stdin: {e}");
+                if read_errors >= MAX_CONSECUTIVE_READ_ERRORS {
+                    return Err(e).context("Failed to read stdin");
+                }
+                continue;
+            }
+        };
         if line.trim().is_empty() {
             continue;
         }
 
-        let cmd: serde_json::Value = match serde_json::from_str(&line) {
+        let cmd: serde_json::Value = match serde_json::from_str(line) {
             Ok(v) => v,
             Err(e) => {
-                let err = serde_json::json!({
-                    "type": "error",
-                    "error": format!("Invalid JSON: {e}"),
-                });
-                println!("{}", err);
-                io::stdout().flush()?;
+                respond_error(&format!("Invalid JSON: {e}"))?;
                 continue;
             }
         };
@@ -136,3 +187,68 @@ fn daemon_loop(secret_key: &str, state_dir: &str) -> Result<()> {
     state.save(state_dir)?;
     Ok(())
 }
+
+/// Outcome of reading one line from stdin.
+enum StdinLine {
+    /// A complete line is in the buffer (without the trailing newline).
+    Line,
+    /// The line exceeded the limit and was discarded; carries its length.
+    TooLong(usize),
+    Eof,
+}
+
+/// Read one line into `buf`, holding at most `max` bytes in memory.
+///
+/// An oversized line is drained up to its newline without being buffered,
+/// so the next read starts cleanly at the following command.
+fn read_bounded_line<R: BufRead>(reader: &mut R, max: usize, buf: &mut Vec<u8>) -> io::Result<StdinLine> {
+    buf.clear();
+    let n = reader.by_ref().take(max as u64 + 1).read_until(b'\n', buf)?;
+    if n == 0 {
+        return Ok(StdinLine::Eof);
+    }
+    if buf.last() == Some(&b'\n') {
+        buf.pop();
+        if buf.last() == Some(&b'\r') {
+            buf.pop();
+        }
+        return Ok(StdinLine::Line);
+    }
+    if buf.len() <= max {
+        // Last line without a trailing newline
+        return Ok(StdinLine::Line);
+    }
+
+    // Over the limit: skip the rest of the line
+    let mut len = buf.len();
+    buf.clear();
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            break;
+        }
+        match chunk.iter().position(|&b| b == b'\n') {
+            Some(pos) => {
+                len += pos;
+                reader.consume(pos + 1);
+                break;
+            }
+            None => {
+                let consumed = chunk.len();
+                len += consumed;
+                reader.consume(consumed);
+            }
+        }
+    }
+    Ok(StdinLine::TooLong(len))
+}
+
+fn respond_error(message: &str) -> Result<()> {
+    let err = serde_json::json!({
+        "type": "error",
+        "error": message,
+    });
+    println!("{}", err);
+    io::stdout().flush()?;
+    Ok(())
+}
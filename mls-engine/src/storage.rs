@@ -6,16 +6,39 @@
 //! (re-processing welcomes, etc.) if the daemon dies.
 //!
 //! Future improvement: implement a file-backed MdkStorageProvider.
+//!
+//! The state directory does carry a versioned `state.json` manifest. A
+//! binary refuses to start on a directory written by a newer state format,
+//! and older formats are migrated forward in `migrate`. Loading MLS state the
+//! binary doesn't understand would fork the groups.
 
 use std::fs;
+use std::path::Path;
 
 use anyhow::{Context, Result};
 use mdk_core::MDK;
 use mdk_memory_storage::MdkMemoryStorage;
 use nostr_sdk::prelude::*;
 use openmls::prelude::OpenMlsProvider;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Version of the on-disk state format written by this binary.
+pub const STATE_VERSION: u32 = 1;
+
+/// Manifest file in the state directory.
+const STATE_FILE: &str = "state.json";
+
+/// Contents of `state.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct StateManifest {
+    /// Missing in files written before versioning, which count as version 0.
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    pubkey: String,
+}
+
 /// Daemon state holding the MDK instance and keys.
 /// Uses interior mutability since MDK methods take &self but mutate internal state.
 pub struct DaemonState {
@@ -33,6 +56,8 @@ impl DaemonState {
         fs::create_dir_all(state_dir)
             .with_context(|| format!("Failed to create state dir: {state_dir}"))?;
 
+        check_state_version(Path::new(state_dir), &keys)?;
+
         Ok(Self { mdk, keys })
     }
 
@@ -47,13 +72,16 @@ impl DaemonState {
     /// Save state to disk (placeholder for future file-backed storage)
     pub fn save(&self, _state_dir: &str) -> Result<()> {
         // Currently a no-op — MdkMemoryStorage snapshots are not serializable.
-        // State lives in memory for the daemon's lifetime.
+        // State lives in memory for the daemon's lifetime. The manifest is
+        // written once by `load_or_new`.
         Ok(())
     }
 
     /// Handle a JSON command and return a JSON response.
+    ///
+    /// The command name is read from `command`, or `cmd` as a shorthand.
     pub fn handle_command(&self, cmd: &Value) -> String {
-        let cmd_type = cmd["command"].as_str().unwrap_or("");
+        let cmd_type = cmd["command"].as_str().or_else(|| cmd["cmd"].as_str()).unwrap_or("");
 
         let result = match cmd_type {
             "create_group" => self.cmd_create_group(cmd),
@@ -100,3 +128,59 @@ impl DaemonState {
         }
     }
 }
+
+/// Validate the state directory's format version, migrating older ones.
+///
+/// A directory written by a newer binary is refused rather than loaded.
+fn check_state_version(state_dir: &Path, keys: &Keys) -> Result<()> {
+    let path = state_dir.join(STATE_FILE);
+    if !path.exists() {
+        return write_manifest(state_dir, keys);
+    }
+    let data = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let manifest: StateManifest = serde_json::from_str(&data)
+        .with_context(|| format!("Unreadable state manifest {}", path.display()))?;
+
+    if manifest.version > STATE_VERSION {
+        anyhow::bail!(
+            "State in {} has format version {} but this burrow-mls supports up to {}; \
+             upgrade burrow-mls or point --state-dir elsewhere",
+            state_dir.display(),
+            manifest.version,
+            STATE_VERSION
+        );
+    }
+    if manifest.version < STATE_VERSION {
+        migrate(state_dir, manifest.version)
+            .with_context(|| format!("Failed to migrate state from version {} to {}", manifest.version, STATE_VERSION))?;
+        eprintln!("Migrated state from version {} to {}", manifest.version, STATE_VERSION);
+        write_manifest(state_dir, keys)?;
+    }
+    Ok(())
+}
+
+/// Bring a state directory from `from` up to `STATE_VERSION`, one step at a time.
+fn migrate(_state_dir: &Path, from: u32) -> Result<()> {
+    for version in from..STATE_VERSION {
+        match version {
+            // 0 -> 1: only the manifest was added; nothing else to convert.
+            0 => {}
+            other => anyhow::bail!("No migration from state version {other}"),
+        }
+    }
+    Ok(())
+}
+
+fn write_manifest(state_dir: &Path, keys: &Keys) -> Result<()> {
+    let manifest = StateManifest {
+        version: STATE_VERSION,
+        pubkey: keys.public_key().to_hex(),
+    };
+    let path = state_dir.join(STATE_FILE);
+    let tmp = state_dir.join(format!("{STATE_FILE}.tmp"));
+    fs::write(&tmp, serde_json::to_vec_pretty(&manifest)?)
+        .with_context(|| format!("Failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, &path).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}